@@ -6,13 +6,19 @@ import { useTauri } from './hooks/useTauri';
 import { listen } from '@tauri-apps/api/event';
 import { useAppStore } from './store';
 import { Sidebar } from './components/Layout/Sidebar';
+import { RepoChangeBanner } from './components/Layout/RepoChangeBanner';
 import { createQueryClient } from './lib/query-client';
 import { ErrorBoundary } from './components/Common/ErrorBoundary';
 import { TooltipProvider } from './components/Common/Tooltip';
 import { ICONS } from './constants/icons';
 import { GenerationProvider } from './contexts/GenerationContext';
 import { useUpdateCheck } from './hooks/useUpdateCheck';
+import { useAutoRefresh } from './hooks/useAutoRefresh';
+import { useAppearance } from './hooks/useAppearance';
+import { useRateLimitNotices } from './hooks/useRateLimitNotices';
+import { useBatchReviewEvents } from './hooks/useBatchReviews';
 import { UpdateModal } from './components/Common/UpdateModal';
+import { UnlockDatabaseModal } from './components/Common/UnlockDatabaseModal';
 
 import { SettingsPageSkeleton } from './components/Settings/SettingsPageSkeleton';
 import { ReviewViewSkeleton } from './components/Review/ReviewViewSkeleton';
@@ -53,9 +59,28 @@ const LearningView = lazy(() =>
     default: module.LearningView,
   }))
 );
+const DiffWindow = lazy(() =>
+  import('./components/Review/DiffWindow').then(module => ({
+    default: module.DiffWindow,
+  }))
+);
 
 const queryClient = createQueryClient();
 
+/** Mounted inside the query provider so polling can invalidate queries. */
+const AutoRefresh = () => {
+  useAutoRefresh();
+  useBatchReviewEvents();
+  useRateLimitNotices();
+  return null;
+};
+
+/** Applies the UI theme; every window mounts one. */
+const Appearance = () => {
+  useAppearance();
+  return null;
+};
+
 type View = 'generate' | 'review' | 'repos' | 'rules' | 'learning' | 'settings';
 
 function App() {
@@ -142,7 +167,7 @@ function App() {
         case 'review':
           return (
             <ErrorBoundary>
-              <ReviewView />
+              <ReviewView onNavigate={setCurrentView} />
             </ErrorBoundary>
           );
         case 'repos':
@@ -175,6 +200,8 @@ function App() {
   return (
     <QueryClientProvider client={queryClient}>
       <GenerationProvider>
+        <AutoRefresh />
+        <Appearance />
         <TooltipProvider>
           <div className="flex h-screen flex-col overflow-hidden bg-gray-900 text-white">
             <Toaster
@@ -198,6 +225,7 @@ function App() {
               }}
             />
 
+            <RepoChangeBanner onNavigate={setCurrentView} />
             {error && (
               <div className="z-50 flex items-center justify-between bg-red-500 px-4 py-2 text-white">
                 <span>{error}</span>
@@ -223,6 +251,7 @@ function App() {
               </main>
             </div>
           </div>
+          <UnlockDatabaseModal />
           {updateAvailable && currentVersion && (
             <UpdateModal
               isOpen={showUpdateModal}
@@ -237,4 +266,19 @@ function App() {
   );
 }
 
+/** Root of the detached diff window: the diff alone, without the app chrome. */
+export function DiffWindowApp() {
+  return (
+    <QueryClientProvider client={queryClient}>
+      <TooltipProvider>
+        <Appearance />
+        <Toaster position="bottom-right" theme="dark" closeButton />
+        <Suspense fallback={<PageSkeleton />}>
+          <DiffWindow />
+        </Suspense>
+      </TooltipProvider>
+    </QueryClientProvider>
+  );
+}
+
 export default App;
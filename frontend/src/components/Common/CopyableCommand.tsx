@@ -0,0 +1,44 @@
+import React from 'react';
+import { toast } from 'sonner';
+import clsx from 'clsx';
+import { ICONS } from '../../constants/icons';
+import { useTauri } from '../../hooks/useTauri';
+
+interface CopyableCommandProps {
+  command: string;
+  /** Toast description shown after copying. */
+  description?: string;
+  className?: string;
+}
+
+/** A shell command in a code box with a button that copies it. */
+export const CopyableCommand: React.FC<CopyableCommandProps> = ({
+  command,
+  description = 'Paste the command in your terminal.',
+  className,
+}) => {
+  const { copyToClipboard } = useTauri();
+
+  const handleCopy = async () => {
+    await copyToClipboard(command);
+    toast('Copied to clipboard', { description });
+  };
+
+  return (
+    <div
+      className={clsx(
+        'bg-bg-tertiary border-border/50 flex items-center justify-between rounded-lg border px-3 py-2',
+        className
+      )}
+    >
+      <code className="text-text-primary text-xs">{command}</code>
+      <button
+        onClick={handleCopy}
+        className="text-text-tertiary hover:text-text-primary hover:bg-bg-secondary rounded p-1 transition-all"
+        aria-label="Copy command"
+      >
+        <ICONS.ACTION_COPY size={14} />
+      </button>
+    </div>
+  );
+};
@@ -0,0 +1,62 @@
+import React, { useState } from 'react';
+import { LockKey } from '@phosphor-icons/react';
+import { useEncryptionStatus } from '../../hooks/useSettings';
+
+/**
+ * Blocks the app while the encrypted database waits for its passphrase,
+ * i.e. when the OS keychain didn't have it at startup.
+ */
+export const UnlockDatabaseModal: React.FC = () => {
+  const { status, unlock, isUnlocking } = useEncryptionStatus();
+  const [passphrase, setPassphrase] = useState('');
+  const [error, setError] = useState<string | null>(null);
+
+  if (!status?.locked) return null;
+
+  const handleSubmit = async (event: React.FormEvent) => {
+    event.preventDefault();
+    setError(null);
+    try {
+      await unlock(passphrase);
+      setPassphrase('');
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 z-[70] flex items-center justify-center bg-black/70 p-4 backdrop-blur-sm">
+      <form
+        onSubmit={handleSubmit}
+        className="bg-bg-primary border-border/50 flex w-full max-w-sm flex-col gap-4 rounded-xl border p-6 shadow-2xl"
+      >
+        <div className="flex items-center gap-2.5">
+          <div className="bg-brand/10 text-brand rounded-md p-1.5">
+            <LockKey size={18} />
+          </div>
+          <h3 className="text-text-primary text-sm font-semibold">Unlock your reviews</h3>
+        </div>
+        <p className="text-text-tertiary text-xs leading-relaxed">
+          The review database is encrypted and its passphrase isn't in your keychain. Enter it to
+          continue; it will be saved to the keychain for next time.
+        </p>
+        <input
+          type="password"
+          autoFocus
+          value={passphrase}
+          onChange={e => setPassphrase(e.target.value)}
+          placeholder="Passphrase"
+          className="bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand rounded-md border px-3 py-2 font-mono text-xs focus:outline-none"
+        />
+        {error && <p className="text-status-ignored text-xs">{error}</p>}
+        <button
+          type="submit"
+          disabled={isUnlocking || !passphrase}
+          className="bg-brand text-bg-primary hover:bg-brand/90 rounded-md px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+        >
+          {isUnlocking ? 'Unlocking…' : 'Unlock'}
+        </button>
+      </form>
+    </div>
+  );
+};
@@ -2,10 +2,12 @@ import React from 'react';
 import { toast } from 'sonner';
 import { ICONS } from '../../constants/icons';
 import { MarkdownRenderer } from '../ui/MarkdownRenderer';
+import { CopyableCommand } from './CopyableCommand';
 import { useTauri } from '../../hooks/useTauri';
 import type { UpdateInfo } from '../../hooks/useUpdateCheck';
 
 const UPDATE_COMMAND = 'brew upgrade --cask lareview';
+const COPY_DESCRIPTION = 'Paste the command in your terminal to update.';
 
 interface UpdateModalProps {
   isOpen: boolean;
@@ -26,9 +28,7 @@ export const UpdateModal: React.FC<UpdateModalProps> = ({
 
   const handleCopyCommand = async () => {
     await copyToClipboard(UPDATE_COMMAND);
-    toast('Copied to clipboard', {
-      description: 'Paste the command in your terminal to update.',
-    });
+    toast('Copied to clipboard', { description: COPY_DESCRIPTION });
   };
 
   return (
@@ -76,16 +76,7 @@ export const UpdateModal: React.FC<UpdateModalProps> = ({
           {/* How to Update */}
           <div>
             <h4 className="text-text-primary mb-2 text-xs font-semibold">How to Update</h4>
-            <div className="bg-bg-tertiary border-border/50 flex items-center justify-between rounded-lg border px-3 py-2">
-              <code className="text-text-primary text-xs">{UPDATE_COMMAND}</code>
-              <button
-                onClick={handleCopyCommand}
-                className="text-text-tertiary hover:text-text-primary hover:bg-bg-secondary rounded p-1 transition-all"
-                aria-label="Copy command"
-              >
-                <ICONS.ACTION_COPY size={14} />
-              </button>
-            </div>
+            <CopyableCommand command={UPDATE_COMMAND} description={COPY_DESCRIPTION} />
           </div>
         </div>
 
@@ -1,10 +1,23 @@
 import React, { useState, useMemo } from 'react';
-import { Chat, CaretDown, CaretRight, FileCode, ArrowSquareOut } from '@phosphor-icons/react';
+import {
+  Chat,
+  CaretDown,
+  CaretRight,
+  FileCode,
+  ArrowSquareOut,
+  GitCommit,
+  CheckSquare,
+  Square,
+} from '@phosphor-icons/react';
 import { DiffEditor } from '@monaco-editor/react';
-import type { DiffFile } from '../../types';
+import type { DiffFile, SubmoduleChange } from '../../types';
 import { useTauri } from '../../hooks/useTauri';
+import { useViewedFiles } from '../../hooks/useViewedFiles';
 import { getLanguageFromPath } from '../../utils/languages';
 import { GutterMenu } from './GutterMenu';
+import { buildDiffSides } from '../../lib/diff-sides';
+import { DIFF_FONT_SIZE, monacoTheme } from '../../lib/appearance';
+import { useAppearanceConfig } from '../../hooks/useSettings';
 
 interface DiffViewerProps {
   files: DiffFile[];
@@ -20,8 +33,12 @@ interface DiffViewerProps {
   viewMode?: 'unified' | 'split';
   onAddFeedback?: (file: DiffFile, line: number, side: 'old' | 'new') => void;
   repoRoot?: string | null;
+  /** Run the files belong to; enables the "Viewed" checkbox. */
+  runId?: string | null;
 }
 
+const filePath = (file: DiffFile) => file.name || file.new_path || 'unknown';
+
 export const DiffViewer: React.FC<DiffViewerProps> = ({
   files,
   selectedFile,
@@ -30,13 +47,25 @@ export const DiffViewer: React.FC<DiffViewerProps> = ({
   viewMode = 'split',
   onAddFeedback,
   repoRoot,
+  runId,
 }) => {
+  const { viewedFiles, setViewed } = useViewedFiles(runId);
+  const viewed = useMemo(() => new Set(viewedFiles), [viewedFiles]);
+
   return (
     <div className="bg-bg-primary flex h-full">
-      <FileList files={files} selectedFile={selectedFile} onSelectFile={onSelectFile} />
+      <FileList
+        files={files}
+        selectedFile={selectedFile}
+        onSelectFile={onSelectFile}
+        viewed={viewed}
+      />
       <div className="flex flex-1 flex-col">
-        {selectedFile ? (
+        {selectedFile?.submodule ? (
+          <SubmoduleContent submodule={selectedFile.submodule} />
+        ) : selectedFile ? (
           <DiffContent
+            key={selectedFile.name || selectedFile.new_path}
             file={selectedFile}
             highlightedHunks={highlightedHunks.filter(
               h => h.file === selectedFile.name || h.file === selectedFile.new_path
@@ -44,6 +73,12 @@ export const DiffViewer: React.FC<DiffViewerProps> = ({
             viewMode={viewMode}
             onAddFeedback={onAddFeedback}
             repoRoot={repoRoot}
+            viewed={viewed.has(filePath(selectedFile))}
+            onToggleViewed={
+              runId
+                ? () => setViewed(filePath(selectedFile), !viewed.has(filePath(selectedFile)))
+                : undefined
+            }
           />
         ) : (
           <div className="text-text-disabled flex flex-1 items-center justify-center">
@@ -62,10 +97,18 @@ interface FileListProps {
   files: DiffFile[];
   selectedFile: DiffFile | null;
   onSelectFile: (file: DiffFile | null) => void;
+  viewed: Set<string>;
 }
 
-const FileList: React.FC<FileListProps> = ({ files, selectedFile, onSelectFile }) => {
+const FileList: React.FC<FileListProps> = ({ files, selectedFile, onSelectFile, viewed }) => {
   const [expanded, setExpanded] = useState(true);
+  // Generated files (lockfiles, build output) start collapsed at the bottom.
+  const [showGenerated, setShowGenerated] = useState(false);
+  const handwrittenFiles = files.filter(file => !file.generated);
+  const generatedFiles = files.filter(file => file.generated);
+  const isSelected = (file: DiffFile) =>
+    selectedFile?.name === file.name || selectedFile?.new_path === file.new_path;
+  const viewedCount = files.filter(file => viewed.has(filePath(file))).length;
 
   return (
     <div className="border-border bg-bg-secondary/30 flex w-64 flex-col border-r">
@@ -76,22 +119,47 @@ const FileList: React.FC<FileListProps> = ({ files, selectedFile, onSelectFile }
         <span className="text-text-secondary text-xs font-bold tracking-wider uppercase">
           Changed Files ({files.length})
         </span>
+        {viewedCount > 0 && (
+          <span className="text-text-disabled ml-auto mr-2 text-[10px]">
+            {viewedCount}/{files.length} viewed
+          </span>
+        )}
         <div className="text-text-disabled">
           {expanded ? <CaretDown size={14} /> : <CaretRight size={14} />}
         </div>
       </div>
       {expanded && (
         <div className="custom-scrollbar flex-1 overflow-y-auto">
-          {files.map(file => (
+          {handwrittenFiles.map(file => (
             <FileListItem
               key={file.name || file.new_path}
               file={file}
-              isSelected={
-                selectedFile?.name === file.name || selectedFile?.new_path === file.new_path
-              }
+              isSelected={isSelected(file)}
+              isViewed={viewed.has(filePath(file))}
               onClick={() => onSelectFile(file)}
             />
           ))}
+          {generatedFiles.length > 0 && (
+            <>
+              <button
+                onClick={() => setShowGenerated(!showGenerated)}
+                className="border-border/50 hover:bg-bg-secondary text-text-disabled flex w-full items-center gap-1.5 border-b px-3 py-2 text-left text-[10px] font-bold tracking-wider uppercase"
+              >
+                {showGenerated ? <CaretDown size={12} /> : <CaretRight size={12} />}
+                Generated ({generatedFiles.length})
+              </button>
+              {showGenerated &&
+                generatedFiles.map(file => (
+                  <FileListItem
+                    key={file.name || file.new_path}
+                    file={file}
+                    isSelected={isSelected(file)}
+                    isViewed={viewed.has(filePath(file))}
+                    onClick={() => onSelectFile(file)}
+                  />
+                ))}
+            </>
+          )}
         </div>
       )}
     </div>
@@ -101,28 +169,34 @@ const FileList: React.FC<FileListProps> = ({ files, selectedFile, onSelectFile }
 interface FileListItemProps {
   file: DiffFile;
   isSelected: boolean;
+  isViewed: boolean;
   onClick: () => void;
 }
 
-const FileListItem: React.FC<FileListItemProps> = ({ file, isSelected, onClick }) => {
+const FileListItem: React.FC<FileListItemProps> = ({ file, isSelected, isViewed, onClick }) => {
   const additions = file.hunks.reduce((sum, h) => sum + h.new_lines, 0);
   const deletions = file.hunks.reduce((sum, h) => sum + h.old_lines, 0);
-  const path = file.name || file.new_path || 'unknown';
+  const path = filePath(file);
 
   return (
     <button
       onClick={onClick}
       className={`group border-border/50 hover:bg-bg-secondary w-full border-b px-3 py-2 text-left transition-colors ${
         isSelected ? 'bg-bg-secondary border-l-brand border-l-2' : ''
-      }`}
+      } ${isViewed && !isSelected ? 'opacity-50' : ''}`}
     >
       <div className="flex items-center gap-2">
-        <FileCode size={14} className={isSelected ? 'text-brand' : 'text-text-tertiary'} />
+        {file.submodule ? (
+          <GitCommit size={14} className={isSelected ? 'text-brand' : 'text-text-tertiary'} />
+        ) : (
+          <FileCode size={14} className={isSelected ? 'text-brand' : 'text-text-tertiary'} />
+        )}
         <span
           className={`flex-1 truncate text-xs ${isSelected ? 'text-text-primary' : 'text-text-secondary group-hover:text-text-primary'}`}
         >
           {path.split('/').pop()}
         </span>
+        {isViewed && <CheckSquare size={12} className="text-status-done" weight="fill" />}
         <span className="text-status-added text-[10px]">+{additions}</span>
         <span className="text-status-deleted text-[10px]">-{deletions}</span>
       </div>
@@ -133,6 +207,44 @@ const FileListItem: React.FC<FileListItemProps> = ({ file, isSelected, onClick }
   );
 };
 
+const shortSha = (sha: string | null) => (sha ? sha.slice(0, 12) : 'none');
+
+/** A submodule's diff is only its commit moving, so show that instead of text. */
+const SubmoduleContent: React.FC<{ submodule: SubmoduleChange }> = ({ submodule }) => {
+  const { openUrl } = useTauri();
+  const link = submodule.link;
+  return (
+    <div className="bg-bg-primary flex flex-1 flex-col overflow-hidden">
+      <div className="border-border bg-bg-secondary/50 flex items-center gap-3 border-b px-4 py-2">
+        <span className="text-text-primary font-mono text-xs">{submodule.path}</span>
+        <span className="text-text-tertiary border-border rounded border px-1.5 text-[10px] font-medium">
+          SUBMODULE
+        </span>
+      </div>
+      <div className="flex flex-1 flex-col items-center justify-center gap-3 p-6 text-center">
+        <GitCommit size={32} className="text-text-disabled" />
+        <p className="text-text-secondary font-mono text-xs">
+          submodule {submodule.path}: {shortSha(submodule.old_commit)} →{' '}
+          {shortSha(submodule.new_commit)}
+        </p>
+        <p className="text-text-disabled max-w-sm text-xs">
+          This change only moves the commit the submodule points to. Its code changes aren't in
+          this diff.
+        </p>
+        {link && (
+          <button
+            onClick={() => openUrl(link)}
+            className="bg-bg-tertiary hover:bg-bg-secondary text-text-secondary hover:text-text-primary border-border flex items-center gap-1.5 rounded border px-2 py-1 transition-colors"
+          >
+            <ArrowSquareOut size={12} />
+            <span className="text-[10px] font-medium">View changes</span>
+          </button>
+        )}
+      </div>
+    </div>
+  );
+};
+
 interface DiffContentProps {
   file: DiffFile;
   highlightedHunks: Array<{
@@ -144,6 +256,8 @@ interface DiffContentProps {
   viewMode: 'unified' | 'split';
   onAddFeedback?: (file: DiffFile, line: number, side: 'old' | 'new') => void;
   repoRoot?: string | null;
+  viewed: boolean;
+  onToggleViewed?: () => void;
 }
 
 const DiffContent: React.FC<DiffContentProps> = ({
@@ -151,9 +265,15 @@ const DiffContent: React.FC<DiffContentProps> = ({
   highlightedHunks,
   onAddFeedback,
   repoRoot,
+  viewed,
+  onToggleViewed,
 }) => {
   const { openInEditor } = useTauri();
-  const path = file.name || file.new_path || 'unknown';
+  const { config: appearance } = useAppearanceConfig();
+  const path = filePath(file);
+  // Viewed files stay collapsed unless reopened while on them.
+  const [expandViewed, setExpandViewed] = useState(false);
+  const collapsed = viewed && !expandViewed;
   const language = getLanguageFromPath(path);
   // Ref to store diff editor instance
   const diffEditorRef = React.useRef<import('monaco-editor').editor.IDiffEditor | null>(null);
@@ -205,55 +325,16 @@ const DiffContent: React.FC<DiffContentProps> = ({
 
   // Build original and modified content strings along with line number mappings
   // originalLineMap[i] = actual file line number for Monaco line i+1
-  const { original, modified, originalLineMap, modifiedLineMap } = useMemo(() => {
-    const originalLines: string[] = [];
-    const modifiedLines: string[] = [];
-    const origLineMap: number[] = [];
-    const modLineMap: number[] = [];
-
-    file.hunks.forEach(hunk => {
-      const content = hunk.content || '';
-      const contentLines = content.split('\n');
-      let origLine = hunk.old_start;
-      let modLine = hunk.new_start;
-
-      for (let i = 0; i < contentLines.length; i++) {
-        const line = contentLines[i];
-
-        if (line.startsWith('@@')) {
-          continue;
-        }
-
-        if (line.startsWith('-') && !line.startsWith('---')) {
-          // Deletion: add to original only
-          originalLines.push(line.slice(1));
-          origLineMap.push(origLine);
-          origLine++;
-        } else if (line.startsWith('+') && !line.startsWith('+++')) {
-          // Addition: add to modified only
-          modifiedLines.push(line.slice(1));
-          modLineMap.push(modLine);
-          modLine++;
-        } else {
-          // Context line: add to both (strip leading space if present)
-          const contextLine = line.startsWith(' ') ? line.slice(1) : line;
-          originalLines.push(contextLine);
-          modifiedLines.push(contextLine);
-          origLineMap.push(origLine);
-          modLineMap.push(modLine);
-          origLine++;
-          modLine++;
-        }
-      }
-    });
-
-    return {
-      original: originalLines.join('\n'),
-      modified: modifiedLines.join('\n'),
-      originalLineMap: origLineMap,
-      modifiedLineMap: modLineMap,
-    };
-  }, [file.hunks]);
+  const { original, modified, originalLineMap, modifiedLineMap, truncatedLines } = useMemo(
+    () => buildDiffSides(file.hunks),
+    [file.hunks]
+  );
+  // Minified files: the editor shows shortened lines; raw shows them whole, wrapped.
+  const [showRaw, setShowRaw] = useState(false);
+  const rawDiff = useMemo(
+    () => (showRaw ? file.hunks.map(hunk => hunk.content || '').join('\n') : ''),
+    [showRaw, file.hunks]
+  );
 
   // Ensure line numbers are updated when maps or editor changes
   React.useEffect(() => {
@@ -376,6 +457,23 @@ const DiffContent: React.FC<DiffContentProps> = ({
           )}
         </div>
         <div className="flex items-center gap-2">
+          {onToggleViewed && (
+            <button
+              onClick={() => {
+                setExpandViewed(false);
+                onToggleViewed();
+              }}
+              className={`border-border flex items-center gap-1.5 rounded border px-2 py-1 transition-colors ${
+                viewed
+                  ? 'bg-status-done/10 text-status-done'
+                  : 'bg-bg-tertiary hover:bg-bg-secondary text-text-secondary hover:text-text-primary'
+              }`}
+              title="Mark this file as reviewed; cleared if its changes differ in a later run"
+            >
+              {viewed ? <CheckSquare size={12} weight="fill" /> : <Square size={12} />}
+              <span className="text-[10px] font-medium">Viewed</span>
+            </button>
+          )}
           <button
             onClick={handleOpenInEditor}
             className="bg-bg-tertiary hover:bg-bg-secondary text-text-secondary hover:text-text-primary border-border flex items-center gap-1.5 rounded border px-2 py-1 transition-colors"
@@ -386,69 +484,103 @@ const DiffContent: React.FC<DiffContentProps> = ({
           </button>
         </div>
       </div>
-      <div className="flex-1 overflow-hidden">
-        <DiffEditor
-          key={path}
-          height="100%"
-          language={language}
-          theme="lareview-dark"
-          original={original}
-          modified={modified}
-          onMount={handleEditorDidMount}
-          originalLanguage={language}
-          modifiedLanguage={language}
-          options={{
-            readOnly: true,
-            minimap: {
-              enabled: true,
-              renderCharacters: false,
-              scale: 2,
-              showSlider: 'always',
-            },
-            fontSize: 12,
-            lineHeight: 20,
-            fontFamily: "'GeistMono', 'Monaco', monospace",
-            scrollBeyondLastLine: false,
-            padding: { top: 16, bottom: 16 },
-            renderSideBySide: true, // Keep split view even for new files as it's often preferred for consistency, but improved minimap helps
-            automaticLayout: true,
-            originalEditable: false,
-            renderLineHighlight: 'none',
-            diffWordWrap: 'off' as const,
-            ignoreTrimWhitespace: false,
-            codeLens: false,
-            folding: true,
-            glyphMargin: true,
-            // lineNumbers handled by useEffect/onMount to allow custom function
-
-            renderIndicators: true,
-
-            // Premium DX/UX enhancements
-            diffAlgorithm: 'advanced',
-            experimental: {
-              showMoves: true,
-            },
-            hideUnchangedRegions: {
-              enabled: true,
-              contextLineCount: 3,
+      {collapsed && (
+        <div className="text-text-disabled flex flex-1 flex-col items-center justify-center gap-2 text-xs">
+          <span>You marked this file as viewed.</span>
+          <button
+            onClick={() => setExpandViewed(true)}
+            className="text-brand font-medium hover:underline"
+          >
+            Show diff
+          </button>
+        </div>
+      )}
+      {!collapsed && truncatedLines > 0 && (
+        <div className="border-border bg-bg-secondary/30 text-text-tertiary flex items-center justify-between gap-3 border-b px-4 py-1.5 text-[10px]">
+          <span>
+            {truncatedLines} {truncatedLines === 1 ? 'line is' : 'lines are'} too long to diff
+            and {truncatedLines === 1 ? 'was' : 'were'} shortened.
+          </span>
+          <button
+            onClick={() => setShowRaw(!showRaw)}
+            className="text-brand shrink-0 font-medium hover:underline"
+          >
+            {showRaw ? 'Show diff' : 'Show raw'}
+          </button>
+        </div>
+      )}
+      {collapsed ? null : showRaw ? (
+        <pre className="text-text-secondary flex-1 overflow-auto p-4 font-mono text-xs break-all whitespace-pre-wrap">
+          {rawDiff}
+        </pre>
+      ) : (
+        <div className="flex-1 overflow-hidden">
+          <DiffEditor
+            key={path}
+            height="100%"
+            language={language}
+            theme={monacoTheme(appearance.highContrast)}
+            original={original}
+            modified={modified}
+            onMount={handleEditorDidMount}
+            originalLanguage={language}
+            modifiedLanguage={language}
+            options={{
+              readOnly: true,
+              minimap: {
+                enabled: true,
+                renderCharacters: false,
+                scale: 2,
+                showSlider: 'always',
+              },
+              fontSize: DIFF_FONT_SIZE,
+              lineHeight: 20,
+              fontFamily: "'GeistMono', 'Monaco', monospace",
+              scrollBeyondLastLine: false,
+              padding: { top: 16, bottom: 16 },
+              renderSideBySide: true, // Keep split view even for new files as it's often preferred for consistency, but improved minimap helps
+              automaticLayout: true,
+              originalEditable: false,
+              renderLineHighlight: 'none',
+              diffWordWrap: 'off' as const,
+              ignoreTrimWhitespace: false,
+              codeLens: false,
+              folding: true,
+              glyphMargin: true,
+              // lineNumbers handled by useEffect/onMount to allow custom function
+
+              renderIndicators: true,
+
+              // Premium DX/UX enhancements
+              diffAlgorithm: truncatedLines > 0 ? 'legacy' : 'advanced',
+              // Give up on character-level diffs of shortened minified lines quickly.
+              maxComputationTime: truncatedLines > 0 ? 500 : 5000,
+              stopRenderingLineAfter: 10000,
+              experimental: {
+                showMoves: true,
+              },
+              hideUnchangedRegions: {
+                enabled: true,
+                contextLineCount: 3,
 
-              minimumLineCount: 15, // Slightly lowered for better hiding
-              revealLineCount: 5,
-            },
-            stickyScroll: {
-              enabled: true,
-              maxLineCount: 5,
-            },
-            bracketPairColorization: {
-              enabled: true,
-            },
-            renderWhitespace: 'boundary',
-            smoothScrolling: true,
-            cursorSmoothCaretAnimation: 'on',
-            cursorBlinking: 'smooth',
-          }}
-        />
-      </div>
+                minimumLineCount: 15, // Slightly lowered for better hiding
+                revealLineCount: 5,
+              },
+              stickyScroll: {
+                enabled: true,
+                maxLineCount: 5,
+              },
+              bracketPairColorization: {
+                enabled: true,
+              },
+              renderWhitespace: 'boundary',
+              smoothScrolling: true,
+              cursorSmoothCaretAnimation: 'on',
+              cursorBlinking: 'smooth',
+            }}
+          />
+        </div>
+      )}
       <GutterMenu
         position={menuState ? { x: menuState.x, y: menuState.y } : null}
         onClose={closeMenu}
@@ -1,6 +1,6 @@
 import React from 'react';
 import { motion } from 'framer-motion';
-import { Database } from '@phosphor-icons/react';
+import { Article, Database, Spinner } from '@phosphor-icons/react';
 import { AgentSelector } from './AgentSelector';
 import { Tooltip } from '../Common/Tooltip';
 import type { Agent, LinkedRepo } from '../../types';
@@ -11,11 +11,17 @@ interface AgentConfigPanelProps {
   selectedAgentId: string;
   selectedRepoId: string;
   onAgentSelect: (agentId: string) => void;
+  onConfigureAgent?: (agentId: string) => void;
   onRepoSelect: (repoId: string) => void;
   isGenerating: boolean;
   onGenerate: () => void;
   onStop: () => void;
+  /** Asks for the PR summary alone, without tasks. */
+  onSummarize?: () => void;
+  isSummarizing?: boolean;
   isDiffValid: boolean;
+  /** Set when the selected agent can't be launched; disables generation. */
+  agentUnavailableReason?: string | null;
 }
 
 export const AgentConfigPanel: React.FC<AgentConfigPanelProps> = ({
@@ -24,16 +30,35 @@ export const AgentConfigPanel: React.FC<AgentConfigPanelProps> = ({
   selectedAgentId,
   selectedRepoId,
   onAgentSelect,
+  onConfigureAgent,
   onRepoSelect,
   isGenerating,
   onGenerate,
   onStop,
+  onSummarize,
+  isSummarizing = false,
   isDiffValid,
+  agentUnavailableReason,
 }) => {
+  const disabledReason = !isDiffValid
+    ? 'Please add a valid git diff to generate a review'
+    : isSummarizing
+      ? 'Waiting for the summary to finish'
+      : agentUnavailableReason;
+  // The selector already explains agents that weren't found.
+  const showUnavailableReason =
+    !!agentUnavailableReason &&
+    agents.find(agent => agent.id === selectedAgentId)?.available !== false;
+
   return (
     <div className="border-border space-y-6 border-b p-5">
       <div className="space-y-4">
-        <AgentSelector agents={agents} selectedAgentId={selectedAgentId} onSelect={onAgentSelect} />
+        <AgentSelector
+          agents={agents}
+          selectedAgentId={selectedAgentId}
+          onSelect={onAgentSelect}
+          onConfigureAgent={onConfigureAgent}
+        />
 
         <div className="space-y-1.5">
           <label className="text-text-disabled flex items-center gap-1.5 text-[10px] font-bold tracking-wider uppercase">
@@ -62,8 +87,14 @@ export const AgentConfigPanel: React.FC<AgentConfigPanelProps> = ({
         </div>
       </div>
 
-      {!isDiffValid && !isGenerating ? (
-        <Tooltip content="Please add a valid git diff to generate a review">
+      {showUnavailableReason && !isGenerating && (
+        <p className="text-status-ignored -mt-2 text-[11px] leading-snug" role="alert">
+          {agentUnavailableReason}
+        </p>
+      )}
+
+      {disabledReason && !isGenerating ? (
+        <Tooltip content={disabledReason}>
           <motion.button
             disabled={true}
             className="bg-bg-tertiary border-border/50 text-text-disabled shadow-custom relative flex w-full cursor-not-allowed items-center justify-center gap-2 overflow-hidden rounded-md border py-2.5 text-xs font-bold transition-all"
@@ -139,6 +170,18 @@ export const AgentConfigPanel: React.FC<AgentConfigPanelProps> = ({
           {isGenerating && <Sparkles />}
         </motion.button>
       )}
+
+      {onSummarize && !isGenerating && (
+        <button
+          onClick={onSummarize}
+          disabled={!isDiffValid || !!agentUnavailableReason || isSummarizing}
+          title="Write a quick overview of the change (intent, key changes, risks) without tasks"
+          className="border-border text-text-secondary hover:bg-bg-tertiary hover:text-text-primary disabled:hover:text-text-secondary -mt-3 flex w-full items-center justify-center gap-2 rounded-md border py-2 text-xs font-medium transition-colors disabled:cursor-not-allowed disabled:opacity-50 disabled:hover:bg-transparent"
+        >
+          {isSummarizing ? <Spinner size={14} className="animate-spin" /> : <Article size={14} />}
+          <span>{isSummarizing ? 'Summarizing…' : 'Summarize only'}</span>
+        </button>
+      )}
     </div>
   );
 };
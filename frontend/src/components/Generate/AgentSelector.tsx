@@ -2,6 +2,9 @@ import React from 'react';
 import * as Select from '@radix-ui/react-select';
 import { Check, CaretDown, Robot } from '@phosphor-icons/react';
 import clsx from 'clsx';
+import { ICONS } from '../../constants/icons';
+import { CopyableCommand } from '../Common/CopyableCommand';
+import type { Agent } from '../../types';
 
 // Import Icons
 import geminiIcon from '../../assets/icons/gemini.svg';
@@ -26,22 +29,19 @@ const AGENT_ICONS: Record<string, string> = {
   codex: codexIcon,
 };
 
-interface Agent {
-  id: string;
-  name: string;
-  available?: boolean;
-}
-
 interface AgentSelectorProps {
   agents: Agent[];
   selectedAgentId: string;
   onSelect: (id: string) => void;
+  /** Opens the agent's settings to set its executable path */
+  onConfigureAgent?: (id: string) => void;
 }
 
 export const AgentSelector: React.FC<AgentSelectorProps> = ({
   agents,
   selectedAgentId,
   onSelect,
+  onConfigureAgent,
 }) => {
   const selectedAgent = agents.find(a => a.id === selectedAgentId);
 
@@ -87,11 +87,10 @@ export const AgentSelector: React.FC<AgentSelectorProps> = ({
                 <Select.Item
                   key={agent.id}
                   value={agent.id}
-                  disabled={agent.available === false}
                   className={clsx(
                     'text-text-primary relative flex cursor-pointer items-center gap-2 rounded-sm py-2 pr-8 pl-2 text-xs outline-none select-none',
                     'data-[highlighted]:text-text-primary data-[highlighted]:bg-white/10',
-                    'data-[disabled]:pointer-events-none data-[disabled]:opacity-50'
+                    agent.available === false && 'opacity-60'
                   )}
                 >
                   {AGENT_ICONS[agent.id] ? (
@@ -103,10 +102,14 @@ export const AgentSelector: React.FC<AgentSelectorProps> = ({
                   ) : (
                     <Robot size={16} weight="fill" className="text-brand" />
                   )}
-                  <div className="flex items-center gap-2">
+                  <div className="flex min-w-0 flex-col">
                     <Select.ItemText>{agent.name}</Select.ItemText>
                     {agent.available === false && (
-                      <span className="text-[10px] font-medium text-red-400">(Unavailable)</span>
+                      <span className="truncate text-[10px] font-medium text-red-400">
+                        {agent.expected_command
+                          ? `${agent.expected_command} not found`
+                          : 'Unavailable'}
+                      </span>
                     )}
                   </div>
                   <div className="absolute right-2 flex w-4 items-center justify-center">
@@ -120,6 +123,39 @@ export const AgentSelector: React.FC<AgentSelectorProps> = ({
           </Select.Content>
         </Select.Portal>
       </Select.Root>
+
+      {selectedAgent?.available === false && (
+        <div className="border-status-ignored/20 bg-status-ignored/5 space-y-2 rounded-md border p-2.5">
+          <p className="text-text-secondary text-[11px] leading-snug">
+            {selectedAgent.expected_command ? (
+              <>
+                <code className="text-text-primary">{selectedAgent.expected_command}</code> was
+                not found on your PATH.
+              </>
+            ) : (
+              'This agent has no command configured.'
+            )}{' '}
+            {selectedAgent.install_command
+              ? 'Install it, or point LaReview at an existing binary.'
+              : 'Point LaReview at its binary to use it.'}
+          </p>
+          {selectedAgent.install_command && (
+            <CopyableCommand
+              command={selectedAgent.install_command}
+              description="Paste the command in your terminal to install the agent."
+            />
+          )}
+          {onConfigureAgent && (
+            <button
+              onClick={() => onConfigureAgent(selectedAgent.id)}
+              className="text-brand flex items-center gap-1 text-[11px] font-medium hover:underline"
+            >
+              <ICONS.VIEW_SETTINGS size={12} />
+              Configure path
+            </button>
+          )}
+        </div>
+      )}
     </div>
   );
 };
@@ -1,10 +1,12 @@
-import React, { useState, useMemo, useEffect } from 'react';
+import React, { useState, useMemo, useEffect, useDeferredValue } from 'react';
 import Editor, { DiffEditor } from '@monaco-editor/react';
 import { FileCode, CaretDown, CaretRight } from '@phosphor-icons/react';
 import { AnimatePresence, motion } from 'framer-motion';
 import { ICONS } from '../../constants/icons';
 import { parseDiffLocally } from '../../utils/diffParser';
 import { getLanguageFromPath } from '../../utils/languages';
+import { DIFF_FONT_SIZE, monacoTheme } from '../../lib/appearance';
+import { useAppearanceConfig } from '../../hooks/useSettings';
 
 interface DiffEditorPanelProps {
   diffText: string;
@@ -21,11 +23,15 @@ export const DiffEditorPanel: React.FC<DiffEditorPanelProps> = ({
 }) => {
   const [selectedFileName, setSelectedFileName] = useState<string | null>(null);
   const [isFileListExpanded, setIsFileListExpanded] = useState(true);
+  const { config: appearance } = useAppearanceConfig();
+
+  // Parse against a deferred copy so pasting a huge diff doesn't block typing.
+  const deferredDiffText = useDeferredValue(diffText);
 
   const parsedDiff = useMemo(() => {
-    if (viewMode !== 'diff' || !diffText.trim()) return null;
-    return parseDiffLocally(diffText);
-  }, [diffText, viewMode]);
+    if (viewMode !== 'diff' || !deferredDiffText.trim()) return null;
+    return parseDiffLocally(deferredDiffText);
+  }, [deferredDiffText, viewMode]);
 
   useEffect(() => {
     if (parsedDiff && parsedDiff.files && parsedDiff.files.length > 0 && !selectedFileName) {
@@ -48,7 +54,7 @@ export const DiffEditorPanel: React.FC<DiffEditorPanelProps> = ({
         'editor.lineHighlightBackground': '#313244',
       },
     });
-    monaco.editor.setTheme('lareview-dark');
+    monaco.editor.setTheme(monacoTheme(appearance.highContrast));
   };
 
   const diffModels = useMemo(() => {
@@ -144,7 +150,7 @@ export const DiffEditorPanel: React.FC<DiffEditorPanelProps> = ({
         {viewMode === 'diff' ? (
           <DiffEditor
             height="100%"
-            theme="lareview-dark"
+            theme={monacoTheme(appearance.highContrast)}
             onMount={handleEditorDidMount}
             original={diffModels.original}
             modified={diffModels.modified}
@@ -153,7 +159,7 @@ export const DiffEditorPanel: React.FC<DiffEditorPanelProps> = ({
             options={{
               readOnly: true,
               minimap: { enabled: false },
-              fontSize: 12,
+              fontSize: DIFF_FONT_SIZE,
               lineHeight: 20,
               fontFamily: "'GeistMono', 'Monaco', monospace",
               scrollBeyondLastLine: false,
@@ -166,13 +172,13 @@ export const DiffEditorPanel: React.FC<DiffEditorPanelProps> = ({
           <Editor
             height="100%"
             defaultLanguage="diff"
-            theme="lareview-dark"
+            theme={monacoTheme(appearance.highContrast)}
             onMount={handleEditorDidMount}
             value={diffText}
             onChange={value => onDiffTextChange(value || '')}
             options={{
               minimap: { enabled: false },
-              fontSize: 12,
+              fontSize: DIFF_FONT_SIZE,
               lineHeight: 20,
               fontFamily: "'GeistMono', 'Monaco', monospace",
               scrollBeyondLastLine: false,
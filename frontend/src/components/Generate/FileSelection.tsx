@@ -0,0 +1,137 @@
+import React, { useDeferredValue, useEffect, useMemo, useState } from 'react';
+import { CaretDown, CaretRight, Files } from '@phosphor-icons/react';
+import { useTauri } from '../../hooks/useTauri';
+import type { DiffFile } from '../../types';
+
+interface FileSelectionProps {
+  diffText: string;
+  /** Linked repo, so files its `.gitattributes` marks generated are tagged. */
+  repoRoot?: string;
+  excludedPaths: string[];
+  onExcludedPathsChange: (paths: string[]) => void;
+  disabled?: boolean;
+}
+
+/** Lets the user uncheck files the agent should leave out of the review. */
+export const FileSelection: React.FC<FileSelectionProps> = ({
+  diffText,
+  repoRoot,
+  excludedPaths,
+  onExcludedPathsChange,
+  disabled,
+}) => {
+  const { parseDiff } = useTauri();
+  const [files, setFiles] = useState<DiffFile[]>([]);
+  const [isExpanded, setIsExpanded] = useState(false);
+
+  const deferredDiffText = useDeferredValue(diffText);
+
+  useEffect(() => {
+    if (!deferredDiffText.trim()) {
+      setFiles([]);
+      return;
+    }
+    let cancelled = false;
+    parseDiff(deferredDiffText, repoRoot)
+      .then(parsed => {
+        if (!cancelled) setFiles(parsed.files ?? []);
+      })
+      .catch(() => {
+        if (!cancelled) setFiles([]);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [deferredDiffText, repoRoot, parseDiff]);
+
+  // Forget exclusions for files that are no longer in the diff.
+  useEffect(() => {
+    const names = new Set(files.map(file => file.name));
+    const kept = excludedPaths.filter(path => names.has(path));
+    if (kept.length !== excludedPaths.length) {
+      onExcludedPathsChange(kept);
+    }
+  }, [files, excludedPaths, onExcludedPathsChange]);
+
+  const excluded = useMemo(() => new Set(excludedPaths), [excludedPaths]);
+  const generatedPaths = files.filter(file => file.generated).map(file => file.name);
+
+  if (files.length < 2) return null;
+
+  const selectedCount = files.length - excluded.size;
+
+  const toggle = (path: string) => {
+    onExcludedPathsChange(
+      excluded.has(path) ? excludedPaths.filter(p => p !== path) : [...excludedPaths, path]
+    );
+  };
+
+  return (
+    <div className="border-border border-b">
+      <button
+        onClick={() => setIsExpanded(!isExpanded)}
+        className="hover:bg-bg-tertiary/40 flex w-full items-center gap-2 px-5 py-3 text-left transition-colors"
+      >
+        {isExpanded ? <CaretDown size={12} /> : <CaretRight size={12} />}
+        <Files size={13} className="text-text-tertiary" />
+        <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
+          Files
+        </span>
+        <span className="text-text-tertiary ml-auto text-[10px]">
+          {selectedCount} of {files.length} selected
+        </span>
+      </button>
+
+      {isExpanded && (
+        <div className="px-5 pb-4">
+          <div className="mb-2 flex items-center gap-3 text-[10px]">
+            <button
+              onClick={() => onExcludedPathsChange([])}
+              disabled={disabled || excluded.size === 0}
+              className="text-text-secondary hover:text-text-primary font-medium disabled:opacity-50"
+            >
+              Select all
+            </button>
+            {generatedPaths.length > 0 && generatedPaths.length < files.length && (
+              <button
+                onClick={() => onExcludedPathsChange(generatedPaths)}
+                disabled={disabled}
+                className="text-text-secondary hover:text-text-primary font-medium disabled:opacity-50"
+              >
+                Skip generated
+              </button>
+            )}
+          </div>
+          <ul className="max-h-64 space-y-0.5 overflow-y-auto">
+            {files.map(file => (
+              <li key={file.name}>
+                <label className="hover:bg-bg-tertiary/40 flex cursor-pointer items-center gap-2 rounded px-1.5 py-1">
+                  <input
+                    type="checkbox"
+                    checked={!excluded.has(file.name)}
+                    onChange={() => toggle(file.name)}
+                    disabled={disabled || (selectedCount === 1 && !excluded.has(file.name))}
+                    className="accent-brand"
+                  />
+                  <span
+                    className={`truncate font-mono text-[11px] ${
+                      excluded.has(file.name) ? 'text-text-disabled line-through' : 'text-text-secondary'
+                    }`}
+                    title={file.name}
+                  >
+                    {file.name}
+                  </span>
+                  {file.generated && (
+                    <span className="text-text-disabled ml-auto flex-shrink-0 text-[9px] uppercase">
+                      generated
+                    </span>
+                  )}
+                </label>
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
+    </div>
+  );
+};
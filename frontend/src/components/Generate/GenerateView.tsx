@@ -1,35 +1,55 @@
-import React, { useState, useEffect, useMemo, useCallback } from 'react';
-import { Trash, Spinner } from '@phosphor-icons/react';
+import React, { useState, useEffect, useMemo, useCallback, useRef } from 'react';
+import { getCurrentWebview } from '@tauri-apps/api/webview';
+import { Trash, Spinner, FileText } from '@phosphor-icons/react';
+import { toast } from 'sonner';
+import { useQueryClient } from '@tanstack/react-query';
 import { useTauri } from '../../hooks/useTauri';
 import { useAppStore } from '../../store';
-import { useAgents } from '../../hooks/useAgents';
+import { useAgents, useAgentProbe } from '../../hooks/useAgents';
 import { useRepos } from '../../hooks/useRepos';
-import type { ReviewSource, ViewType } from '../../types';
+import { useOfflineMode, useQuickReviewMaxLines } from '../../hooks/useSettings';
+import { useGenerateDraft } from '../../hooks/useGenerateDraft';
+import type {
+  GenerateDraft,
+  PendingReviewState,
+  PrDetails,
+  ReviewMode,
+  ReviewSource,
+  ViewType,
+} from '../../types';
 import { useGeneration } from '../../contexts/useGeneration';
 import { DiffEditorPanel } from './DiffEditorPanel';
 import { AgentConfigPanel } from './AgentConfigPanel';
 import { PlanOverview } from './PlanOverview';
 import { LiveActivityFeed } from './LiveActivityFeed';
 import { VcsInputCard } from './VcsInputCard';
+import { RecentDiffsMenu } from './RecentDiffsMenu';
 import { ViewModeToggle } from './ViewModeToggle';
 import { DiffStats } from './DiffStats';
+import { PrDetailsBar } from './PrDetailsBar';
+import { FileSelection } from './FileSelection';
+import { ReviewModeToggle } from './ReviewModeToggle';
 import { countAdditions, countDeletions } from './DiffEditorPanel';
+import { fileContextIssue } from '../../lib/review-source';
+import { validateDiffInput } from '../../lib/diff-input';
 
 interface GenerateViewProps {
   onNavigate: (view: ViewType) => void;
 }
 
 interface RepoLinkCallout {
-  provider: 'github' | 'gitlab';
+  provider: 'github' | 'gitlab' | 'gitea';
   repo: string;
   host?: string;
   label: string;
 }
 
-const isVcsSource = (
-  source: ReviewSource | null
-): source is Extract<ReviewSource, { type: 'github_pr' | 'gitlab_mr' }> =>
-  !!source && (source.type === 'github_pr' || source.type === 'gitlab_mr');
+type VcsSource = Exclude<ReviewSource, { type: 'diff_paste' }>;
+
+const isPatchPath = (path: string) => /\.(patch|diff)$/i.test(path);
+
+const isVcsSource = (source: ReviewSource | null): source is VcsSource =>
+  !!source && source.type !== 'diff_paste';
 
 export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavigate }) => {
   const [diffText, setDiffText] = useState('');
@@ -37,19 +57,32 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
   const hasAutoExpandedRef = React.useRef(false);
 
   const [isLoadingPr, setIsLoadingPr] = useState(false);
+  // The fetched pull request's title and details, shown under the link.
+  const [fetchedPr, setFetchedPr] = useState<{ title: string | null; details: PrDetails } | null>(
+    null
+  );
 
   const [validationError, setValidationError] = useState<string | null>(null);
-
-  const { fetchRemotePr } = useTauri();
+  const [excludedPaths, setExcludedPaths] = useState<string[]>([]);
+  // Null until the user picks one; the diff's size decides meanwhile.
+  const [chosenMode, setChosenMode] = useState<ReviewMode | null>(null);
+
+  const { fetchRemotePr, cancelPrFetch, loadPatchFiles, parseDiff, summarizeReview } = useTauri();
+  const queryClient = useQueryClient();
+  const { offline: isOffline } = useOfflineMode();
+  const { quickMaxLines } = useQuickReviewMaxLines();
   const { startGeneration, stopGeneration } = useGeneration();
   const { data: agents = [] } = useAgents();
-  const { data: repos = [], addRepo, cloneRepo, selectRepoFolder } = useRepos();
+  const { data: repos = [], addRepo, cloneRepo, allowSnapshots, selectRepoFolder } = useRepos();
 
   const setDiffTextStore = useAppStore(state => state.setDiffText);
   const agentId = useAppStore(state => state.agentId);
   const setAgentIdStore = useAppStore(state => state.setAgentId);
+  const setConfiguringAgentId = useAppStore(state => state.setConfiguringAgentId);
   const setParsedDiff = useAppStore(state => state.setParsedDiff);
   const isGenerating = useAppStore(state => state.isGenerating);
+  const setReviewId = useAppStore(state => state.setReviewId);
+  const [isSummarizing, setIsSummarizing] = useState(false);
   const plan = useAppStore(state => state.plan);
 
   const progressMessages = useAppStore(state => state.progressMessages);
@@ -64,10 +97,33 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
   const isPlanExpanded = useAppStore(state => state.isPlanExpanded);
   const setIsPlanExpanded = useAppStore(state => state.setIsPlanExpanded);
 
-  const [repoLinkCallout, setRepoLinkCallout] = useState<RepoLinkCallout | null>(null);
+  const { data: agentProbe } = useAgentProbe(agentId);
+  const agentUnavailableReason =
+    agentProbe && !agentProbe.available
+      ? agentProbe.error || 'The selected agent could not be started.'
+      : null;
+
+  // The source whose missing-file-context warning the user dismissed.
+  const [dismissedWarningFor, setDismissedWarningFor] = useState<ReviewSource | null>(null);
 
   const globalDiffText = useAppStore(state => state.diffText);
 
+  const handleRestoreDraft = useCallback(
+    (draft: GenerateDraft) => {
+      // A generation's diff is already loaded; don't replace it.
+      if (globalDiffText) return;
+      setDiffText(current => current || draft.diff_text);
+      if (draft.agent_id) setAgentIdStore(draft.agent_id);
+      if (draft.repo_id) setSelectedRepoId(draft.repo_id);
+      if (draft.pr_ref) setPrRef(draft.pr_ref);
+    },
+    [globalDiffText, setAgentIdStore, setSelectedRepoId, setPrRef]
+  );
+  const { markStarted } = useGenerateDraft(
+    { diffText, agentId, repoId: selectedRepoId, prRef },
+    handleRestoreDraft
+  );
+
   useEffect(() => {
     if (globalDiffText) {
       setDiffText(globalDiffText);
@@ -80,29 +136,37 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
     }
   }, [agents, agentId, setAgentIdStore]);
 
-  const validateDiff = useCallback((text: string): string | null => {
-    const trimmed = text.trim();
-    if (!trimmed) {
-      return 'Please enter a diff to review';
-    }
-    if (trimmed.length < 10) {
-      return 'Diff is too short. Please paste a valid git diff';
+  // Pre-select the repo's default agent once per repo selection, so a manual
+  // pick made afterwards sticks.
+  const appliedRepoDefaultsFor = useRef<string | null>(null);
+  useEffect(() => {
+    if (!selectedRepoId) {
+      appliedRepoDefaultsFor.current = null;
+      return;
     }
-    const lines = trimmed.split('\n');
-    const hasHeader = lines.some(l => l.startsWith('---')) && lines.some(l => l.startsWith('+++'));
-    if (!hasHeader && !trimmed.startsWith('diff ')) {
-      return 'Invalid diff format. Expected a git diff with --- and +++ headers';
+    if (appliedRepoDefaultsFor.current === selectedRepoId || agents.length === 0) return;
+    const repo = repos.find(r => r.id === selectedRepoId);
+    if (!repo) return;
+
+    appliedRepoDefaultsFor.current = selectedRepoId;
+    const defaultAgentId = repo.default_agent_id;
+    if (defaultAgentId && agents.some(a => a.id === defaultAgentId)) {
+      setAgentIdStore(defaultAgentId);
     }
-    return null;
-  }, []);
+  }, [selectedRepoId, repos, agents, setAgentIdStore]);
 
   const diffValidationError = useMemo(() => {
     if (!diffText.trim()) return null;
-    return validateDiff(diffText);
-  }, [diffText, validateDiff]);
+    return validateDiffInput(diffText);
+  }, [diffText]);
 
   const isDiffValid = diffText.trim().length > 0 && !diffValidationError;
 
+  const additions = useMemo(() => countAdditions(diffText), [diffText]);
+  const deletions = useMemo(() => countDeletions(diffText), [diffText]);
+  const reviewMode: ReviewMode =
+    chosenMode ?? (additions + deletions <= quickMaxLines ? 'quick' : 'thorough');
+
   // Auto-switch to diff mode on valid pasting
   useEffect(() => {
     if (viewMode === 'raw' && isDiffValid && diffText !== lastAutoSwitchedTextRef.current) {
@@ -112,8 +176,8 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
   }, [diffText, viewMode, isDiffValid, setViewMode]);
 
   const findMatchingRepo = useCallback(
-    (source: Extract<ReviewSource, { type: 'github_pr' | 'gitlab_mr' }>) => {
-      if (source.type === 'github_pr') {
+    (source: VcsSource) => {
+      if (source.type === 'github_pr' || source.type === 'github_compare') {
         const target = `${source.owner}/${source.repo}`.toLowerCase();
         let matchingRepo = repos.find(r =>
           r.remotes.some((url: string) => url.toLowerCase().includes(target))
@@ -126,6 +190,19 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
         return matchingRepo || null;
       }
 
+      if (source.type === 'gitea_pr') {
+        const target = `${source.owner}/${source.repo}`.toLowerCase();
+        const host = source.host.toLowerCase();
+        const matchingRepo =
+          repos.find(r =>
+            r.remotes.some((url: string) => {
+              const lower = url.toLowerCase();
+              return lower.includes(host) && lower.includes(target);
+            })
+          ) ?? repos.find(r => r.name.toLowerCase() === source.repo.toLowerCase());
+        return matchingRepo || null;
+      }
+
       const targetHost = source.host.toLowerCase();
       const targetPath = source.project_path.toLowerCase();
       let matchingRepo = repos.find(r =>
@@ -146,8 +223,8 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
   );
 
   const buildRepoLinkCallout = useCallback(
-    (source: Extract<ReviewSource, { type: 'github_pr' | 'gitlab_mr' }>): RepoLinkCallout => {
-      if (source.type === 'github_pr') {
+    (source: VcsSource): RepoLinkCallout => {
+      if (source.type === 'github_pr' || source.type === 'github_compare') {
         const repo = `${source.owner}/${source.repo}`;
         return {
           provider: 'github',
@@ -156,6 +233,16 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
         };
       }
 
+      if (source.type === 'gitea_pr') {
+        const repo = `${source.owner}/${source.repo}`;
+        return {
+          provider: 'gitea',
+          repo,
+          host: source.host,
+          label: `${source.host}/${repo}`,
+        };
+      }
+
       const label = `${source.host}/${source.project_path}`;
       return {
         provider: 'gitlab',
@@ -167,6 +254,16 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
     []
   );
 
+  const selectedRepo = repos.find(r => r.id === selectedRepoId) ?? null;
+  const contextIssue =
+    pendingSource && pendingSource !== dismissedWarningFor
+      ? fileContextIssue(pendingSource, selectedRepo)
+      : null;
+  const repoLinkCallout: RepoLinkCallout | null =
+    contextIssue === 'no_repo' && isVcsSource(pendingSource)
+      ? buildRepoLinkCallout(pendingSource)
+      : null;
+
   const isRepoLinking = addRepo.isPending || cloneRepo.isPending;
 
   const handleCloneAndLink = useCallback(async () => {
@@ -182,7 +279,6 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
         destDir,
       });
       setSelectedRepoId(linked.id);
-      setRepoLinkCallout(null);
     } catch (error) {
       console.error('Failed to clone repo:', error);
     }
@@ -195,7 +291,6 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
     try {
       const linked = await addRepo.mutateAsync(path);
       setSelectedRepoId(linked.id);
-      setRepoLinkCallout(null);
     } catch (error) {
       console.error('Failed to link repo:', error);
     }
@@ -204,11 +299,19 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
   const handleGenerate = useCallback(async () => {
     setValidationError(null);
 
-    const error = validateDiff(diffText);
+    const error = validateDiffInput(diffText);
     if (error) {
       setValidationError(error);
       return;
     }
+    // The backend's parse is the real check; surface its message here rather
+    // than as a failed generation.
+    try {
+      await parseDiff(diffText);
+    } catch (err) {
+      setValidationError(String(err));
+      return;
+    }
 
     setDiffTextStore(diffText);
     setAgentIdStore(agentId);
@@ -217,57 +320,101 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
       agentId,
       repoId: selectedRepoId || undefined,
       source: pendingSource,
+      excludedPaths,
+      mode: reviewMode,
     });
     if (ok) {
+      markStarted(diffText);
       _onNavigate('review');
     }
   }, [
+    markStarted,
     diffText,
     agentId,
-    validateDiff,
+    parseDiff,
     startGeneration,
     setDiffTextStore,
     setAgentIdStore,
     _onNavigate,
     pendingSource,
     selectedRepoId,
+    excludedPaths,
+    reviewMode,
+  ]);
+
+  const handleSummarize = useCallback(async () => {
+    setValidationError(null);
+
+    const error = validateDiffInput(diffText);
+    if (error) {
+      setValidationError(error);
+      return;
+    }
+
+    setIsSummarizing(true);
+    try {
+      const result = await summarizeReview(diffText, agentId, pendingSource ?? undefined);
+      setReviewId(result.review_id);
+      queryClient.invalidateQueries({ queryKey: ['reviews'] });
+      markStarted(diffText);
+      _onNavigate('review');
+    } catch (err) {
+      toast.error('Failed to summarize the review', { description: String(err) });
+    } finally {
+      setIsSummarizing(false);
+    }
+  }, [
+    diffText,
+    agentId,
+    pendingSource,
+    summarizeReview,
+    setReviewId,
+    queryClient,
+    markStarted,
+    _onNavigate,
   ]);
 
+  // Bumped whenever the link changes, so a slow fetch for an older link can't
+  // overwrite the one the user is looking at now.
+  const prFetchGeneration = useRef(0);
+  useEffect(() => {
+    prFetchGeneration.current += 1;
+    setIsLoadingPr(false);
+  }, [prRef]);
+
   const handleFetchPr = useCallback(async () => {
     if (!prRef.trim()) return;
 
+    const generation = prFetchGeneration.current;
+    const isStale = () => generation !== prFetchGeneration.current;
     setIsLoadingPr(true);
     setValidationError(null);
 
     await Promise.resolve();
     try {
       const diff = await fetchRemotePr(prRef, null);
+      if (isStale()) return;
       setDiffText(diff.diff_text);
+      setPatchProvenance(null);
+      setFetchedPr(
+        diff.pr_details ? { title: diff.title ?? null, details: diff.pr_details } : null
+      );
       if (diff.source) {
         setPendingSource(diff.source);
 
         if (isVcsSource(diff.source)) {
-          const matchingRepo = findMatchingRepo(diff.source);
-
-          if (matchingRepo) {
-            setSelectedRepoId(matchingRepo.id);
-            setRepoLinkCallout(null);
-          } else {
-            setRepoLinkCallout(buildRepoLinkCallout(diff.source));
-          }
-        } else {
-          setRepoLinkCallout(null);
+          // A repo selected for another source can't provide this one's files.
+          setSelectedRepoId(findMatchingRepo(diff.source)?.id ?? '');
         }
-      } else {
-        setRepoLinkCallout(null);
       }
       setParsedDiff(diff);
       setViewMode('diff');
     } catch (error) {
+      if (isStale()) return;
       console.error('Failed to fetch remote review:', error);
       setValidationError(`Failed to fetch remote review: ${error}`);
     } finally {
-      setIsLoadingPr(false);
+      if (!isStale()) setIsLoadingPr(false);
     }
   }, [
     prRef,
@@ -276,10 +423,78 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
     setPendingSource,
     setViewMode,
     findMatchingRepo,
-    buildRepoLinkCallout,
     setSelectedRepoId,
   ]);
 
+  const handleCancelFetchPr = useCallback(() => {
+    // Drop the result even if it arrives before the backend sees the cancel.
+    prFetchGeneration.current += 1;
+    setIsLoadingPr(false);
+    cancelPrFetch().catch(console.error);
+  }, [cancelPrFetch]);
+
+  const [isDraggingPatch, setIsDraggingPatch] = useState(false);
+  // File name(s) the current diff was dropped from.
+  const [patchProvenance, setPatchProvenance] = useState<string | null>(null);
+
+  const handlePatchDrop = useCallback(
+    async (paths: string[]) => {
+      const patches = paths.filter(isPatchPath);
+      const rejected = paths.filter(path => !isPatchPath(path));
+      if (rejected.length > 0) {
+        toast.error('Only .patch and .diff files can be dropped', {
+          description: rejected.map(path => path.split(/[\\/]/).pop()).join(', '),
+        });
+      }
+      if (patches.length === 0) return;
+
+      setValidationError(null);
+      try {
+        const diff = await loadPatchFiles(patches);
+        setDiffText(diff.diff_text);
+        setPendingSource(null);
+        setPatchProvenance(diff.title ?? null);
+        setParsedDiff(diff);
+        setViewMode('diff');
+      } catch (error) {
+        console.error('Failed to load patch files:', error);
+        toast.error('Failed to load patch files', { description: String(error) });
+      }
+    },
+    [loadPatchFiles, setParsedDiff, setPendingSource, setViewMode]
+  );
+
+  useEffect(() => {
+    const unlisten = getCurrentWebview().onDragDropEvent(event => {
+      const { payload } = event;
+      if (payload.type === 'enter') {
+        setIsDraggingPatch(payload.paths.some(isPatchPath));
+      } else if (payload.type === 'leave') {
+        setIsDraggingPatch(false);
+      } else if (payload.type === 'drop') {
+        setIsDraggingPatch(false);
+        if (payload.paths.length > 0 && !isGenerating) {
+          void handlePatchDrop(payload.paths);
+        }
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn()).catch(console.error);
+    };
+  }, [handlePatchDrop, isGenerating]);
+
+  const handleRestorePending = useCallback(
+    (pending: PendingReviewState) => {
+      setValidationError(null);
+      setDiffText(pending.diff);
+      setPendingSource(pending.review_source);
+      setPatchProvenance(null);
+      setParsedDiff(null);
+      setViewMode('raw');
+    },
+    [setPendingSource, setParsedDiff, setViewMode]
+  );
+
   const handleClear = useCallback(() => {
     setDiffText('');
     setDiffTextStore('');
@@ -290,7 +505,10 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
     setViewMode('raw');
     setIsPlanExpanded(false);
     hasAutoExpandedRef.current = false;
-    setRepoLinkCallout(null);
+    setPatchProvenance(null);
+    setFetchedPr(null);
+    setExcludedPaths([]);
+    setChosenMode(null);
   }, [setDiffTextStore, setParsedDiff, setPendingSource, setPrRef, setViewMode, setIsPlanExpanded]);
 
   const planItemsToRender = useMemo(() => {
@@ -315,15 +533,35 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
         <div className="border-border bg-bg-primary relative flex min-w-0 flex-1 flex-col border-r">
           <div className="flex flex-col gap-2 p-4 pb-0">
             <div className="flex items-center gap-3">
-              <VcsInputCard
-                pendingSource={pendingSource}
-                prRef={prRef}
-                onPrRefChange={setPrRef}
-                onFetch={handleFetchPr}
-                isLoading={isLoadingPr}
-                disabled={isGenerating}
-                onClear={handleClear}
-              />
+              {isOffline ? (
+                <span
+                  className="border-border bg-bg-secondary/90 text-text-tertiary flex h-8 items-center rounded-md border px-3 text-[10px] font-medium"
+                  title="Remote reviews are disabled in offline mode"
+                >
+                  Offline mode
+                </span>
+              ) : (
+                <VcsInputCard
+                  pendingSource={pendingSource}
+                  prRef={prRef}
+                  onPrRefChange={setPrRef}
+                  onFetch={handleFetchPr}
+                  onCancelFetch={handleCancelFetchPr}
+                  isLoading={isLoadingPr}
+                  disabled={isGenerating}
+                  onClear={handleClear}
+                />
+              )}
+
+              {patchProvenance && (
+                <span
+                  className="border-border bg-bg-secondary/90 text-text-secondary flex h-8 max-w-[240px] items-center gap-1.5 rounded-md border px-3 font-mono text-[10px]"
+                  title={`Loaded from ${patchProvenance}`}
+                >
+                  <FileText size={13} className="flex-shrink-0" />
+                  <span className="truncate">{patchProvenance}</span>
+                </span>
+              )}
 
               <div className="flex-1" />
 
@@ -338,6 +576,8 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
                   </button>
                 )}
 
+                <RecentDiffsMenu onRestore={handleRestorePending} disabled={isGenerating} />
+
                 <ViewModeToggle
                   mode={viewMode}
                   onChange={setViewMode}
@@ -346,12 +586,22 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
               </div>
             </div>
 
+            {fetchedPr && pendingSource && (
+              <PrDetailsBar
+                title={fetchedPr.title}
+                details={fetchedPr.details}
+                additions={additions}
+                deletions={deletions}
+              />
+            )}
+
             {repoLinkCallout && (
               <div className="pointer-events-auto flex items-center justify-between gap-3 rounded-md border border-amber-500/30 bg-amber-500/10 px-3 py-2 text-[11px] text-amber-200 shadow-sm">
                 <div className="min-w-0">
                   <div className="font-medium text-amber-100">No linked repo found</div>
-                  <div className="truncate text-amber-200/80">
-                    Link or clone {repoLinkCallout.label} to enable snapshots.
+                  <div className="text-amber-200/80">
+                    The agent will only see the diff. Link or clone {repoLinkCallout.label} so it
+                    can read the surrounding files.
                   </div>
                 </div>
                 <div className="flex flex-shrink-0 items-center gap-2">
@@ -371,7 +621,7 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
                     Link Existing
                   </button>
                   <button
-                    onClick={() => setRepoLinkCallout(null)}
+                    onClick={() => setDismissedWarningFor(pendingSource)}
                     disabled={isRepoLinking}
                     className="text-text-tertiary hover:text-text-primary px-1 text-[10px] font-semibold transition-colors disabled:opacity-60"
                   >
@@ -380,8 +630,42 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
                 </div>
               </div>
             )}
+
+            {contextIssue === 'snapshot_disabled' && selectedRepo && reviewMode !== 'quick' && (
+              <div className="pointer-events-auto flex items-center justify-between gap-3 rounded-md border border-amber-500/30 bg-amber-500/10 px-3 py-2 text-[11px] text-amber-200 shadow-sm">
+                <div className="min-w-0">
+                  <div className="font-medium text-amber-100">Snapshots are off</div>
+                  <div className="text-amber-200/80">
+                    Unless you allow a snapshot of {selectedRepo.name}, the agent will only see the
+                    diff.
+                  </div>
+                </div>
+                <div className="flex flex-shrink-0 items-center gap-2">
+                  <button
+                    onClick={() => allowSnapshots.mutate(selectedRepo.id)}
+                    disabled={isGenerating || allowSnapshots.isPending}
+                    className="flex items-center gap-1 rounded bg-amber-500/20 px-2 py-1 text-[10px] font-semibold text-amber-100 transition-colors hover:bg-amber-500/30 disabled:opacity-60"
+                  >
+                    {allowSnapshots.isPending ? <Spinner size={12} className="animate-spin" /> : null}
+                    <span>Allow Snapshots</span>
+                  </button>
+                  <button
+                    onClick={() => setDismissedWarningFor(pendingSource)}
+                    className="text-text-tertiary hover:text-text-primary px-1 text-[10px] font-semibold transition-colors"
+                  >
+                    Dismiss
+                  </button>
+                </div>
+              </div>
+            )}
           </div>
 
+          {isDraggingPatch && !isGenerating && (
+            <div className="border-brand/60 bg-bg-primary/80 text-text-secondary pointer-events-none absolute inset-2 z-20 flex items-center justify-center rounded-lg border-2 border-dashed text-xs font-medium backdrop-blur-sm">
+              Drop .patch or .diff files to review them together
+            </div>
+          )}
+
           <DiffEditorPanel
             diffText={diffText}
             viewMode={viewMode}
@@ -391,8 +675,8 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
 
           <DiffStats
             charCount={diffText.length}
-            additions={countAdditions(diffText)}
-            deletions={countDeletions(diffText)}
+            additions={additions}
+            deletions={deletions}
           />
         </div>
 
@@ -403,13 +687,40 @@ export const GenerateView: React.FC<GenerateViewProps> = ({ onNavigate: _onNavig
             selectedAgentId={agentId}
             selectedRepoId={selectedRepoId}
             onAgentSelect={setAgentIdStore}
+            onConfigureAgent={id => {
+              setConfiguringAgentId(id);
+              _onNavigate('settings');
+            }}
             onRepoSelect={setSelectedRepoId}
             isGenerating={isGenerating}
             onGenerate={handleGenerate}
             onStop={stopGeneration}
+            onSummarize={handleSummarize}
+            isSummarizing={isSummarizing}
             isDiffValid={isDiffValid}
+            agentUnavailableReason={agentUnavailableReason}
           />
 
+          {isDiffValid && (
+            <ReviewModeToggle
+              mode={reviewMode}
+              isAuto={chosenMode === null}
+              quickMaxLines={quickMaxLines}
+              onChange={setChosenMode}
+              disabled={isGenerating}
+            />
+          )}
+
+          {isDiffValid && (
+            <FileSelection
+              diffText={diffText}
+              repoRoot={selectedRepo?.path}
+              excludedPaths={excludedPaths}
+              onExcludedPathsChange={setExcludedPaths}
+              disabled={isGenerating}
+            />
+          )}
+
           <PlanOverview
             items={planItemsToRender}
             isExpanded={isPlanExpanded}
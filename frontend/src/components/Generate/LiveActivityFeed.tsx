@@ -1,13 +1,16 @@
-import React from 'react';
-import { TerminalWindow } from '@phosphor-icons/react';
+import React, { useMemo, useRef, useState } from 'react';
+import { useVirtualizer } from '@tanstack/react-virtual';
+import { Copy, TerminalWindow } from '@phosphor-icons/react';
 import clsx from 'clsx';
-
-interface ProgressMessage {
-  type: string;
-  message: string;
-  data?: Record<string, unknown>;
-  timestamp: number;
-}
+import { toast } from 'sonner';
+import { useTauri } from '../../hooks/useTauri';
+import {
+  TIMELINE_FILTERS,
+  filterTimeline,
+  timelineText,
+  type TimelineFilter,
+  type TimelineMessage as ProgressMessage,
+} from '../../lib/timeline';
 
 interface LiveActivityFeedProps {
   messages: ProgressMessage[];
@@ -15,6 +18,30 @@ interface LiveActivityFeedProps {
 }
 
 export const LiveActivityFeed: React.FC<LiveActivityFeedProps> = ({ messages, isRunning }) => {
+  const { copyToClipboard } = useTauri();
+  // Logs are noise unless debugging, so they start hidden.
+  const [hidden, setHidden] = useState<Set<TimelineFilter>>(() => new Set(['logs']));
+  const visible = useMemo(() => filterTimeline(messages, hidden), [messages, hidden]);
+
+  const toggleFilter = (filter: TimelineFilter) =>
+    setHidden(prev => {
+      const next = new Set(prev);
+      if (next.has(filter)) next.delete(filter);
+      else next.add(filter);
+      return next;
+    });
+
+  const handleCopyAll = async () => {
+    try {
+      await copyToClipboard(timelineText(visible));
+      toast('Activity copied');
+    } catch (err) {
+      toast('Failed to copy', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+    }
+  };
+
   return (
     <div className="bg-bg-primary/50 flex min-h-[150px] flex-1 flex-col">
       <div className="border-border bg-bg-secondary flex items-center justify-between border-b px-4 py-2">
@@ -22,22 +49,48 @@ export const LiveActivityFeed: React.FC<LiveActivityFeedProps> = ({ messages, is
           <TerminalWindow size={14} />
           Activity
         </h2>
-        {isRunning && (
-          <span className="flex items-center gap-1.5">
-            <span className="bg-success h-1.5 w-1.5 animate-pulse rounded-full" />
-            <span className="text-success font-mono text-[10px]">Running</span>
-          </span>
-        )}
+        <div className="flex items-center gap-3">
+          {isRunning && (
+            <span className="flex items-center gap-1.5">
+              <span className="bg-success h-1.5 w-1.5 animate-pulse rounded-full" />
+              <span className="text-success font-mono text-[10px]">Running</span>
+            </span>
+          )}
+          <button
+            onClick={handleCopyAll}
+            disabled={visible.length === 0}
+            className="text-text-disabled hover:text-text-primary flex items-center gap-1 text-[10px] transition-colors disabled:opacity-40"
+            title="Copy the shown activity as text"
+          >
+            <Copy size={12} />
+            Copy all
+          </button>
+        </div>
       </div>
-      <div className="custom-scrollbar flex-1 overflow-y-auto">
-        {messages.length === 0 ? (
-          <div className="text-text-disabled p-8 text-center text-xs opacity-50">
-            No activity yet
-          </div>
-        ) : (
-          <Timeline messages={messages} />
-        )}
+      <div className="border-border/50 flex items-center gap-1 border-b px-3 py-1.5">
+        {TIMELINE_FILTERS.map(({ filter, label }) => (
+          <button
+            key={filter}
+            onClick={() => toggleFilter(filter)}
+            aria-pressed={!hidden.has(filter)}
+            className={clsx(
+              'rounded px-2 py-0.5 text-[10px] font-medium transition-colors',
+              hidden.has(filter)
+                ? 'text-text-disabled hover:text-text-secondary line-through'
+                : 'bg-bg-tertiary text-text-primary'
+            )}
+          >
+            {label}
+          </button>
+        ))}
       </div>
+      {visible.length === 0 ? (
+        <div className="text-text-disabled p-8 text-center text-xs opacity-50">
+          {messages.length === 0 ? 'No activity yet' : 'All activity is filtered out'}
+        </div>
+      ) : (
+        <Timeline messages={visible} />
+      )}
     </div>
   );
 };
@@ -46,12 +99,41 @@ interface TimelineProps {
   messages: ProgressMessage[];
 }
 
+/** Virtualized so runs with thousands of events stay responsive. */
 const Timeline: React.FC<TimelineProps> = ({ messages }) => {
+  const parentRef = useRef<HTMLDivElement>(null);
+
+  // useVirtualizer is incompatible with React 19's strict purity rules
+  // eslint-disable-next-line react-hooks/incompatible-library
+  const rowVirtualizer = useVirtualizer({
+    count: messages.length,
+    getScrollElement: () => parentRef.current,
+    estimateSize: () => 56,
+    overscan: 10,
+    measureElement: element => element.getBoundingClientRect().height,
+  });
+
   return (
-    <div className="space-y-1 p-3">
-      {messages.map(msg => (
-        <TimelineItem key={`${msg.timestamp}-${msg.type}`} message={msg} />
-      ))}
+    <div ref={parentRef} className="custom-scrollbar flex-1 overflow-y-auto">
+      <div
+        className="relative w-full"
+        style={{ height: `${rowVirtualizer.getTotalSize()}px` }}
+      >
+        {rowVirtualizer.getVirtualItems().map(virtualRow => {
+          const msg = messages[virtualRow.index];
+          return (
+            <div
+              key={`${msg.timestamp}-${msg.type}-${virtualRow.index}`}
+              data-index={virtualRow.index}
+              ref={rowVirtualizer.measureElement}
+              className="absolute top-0 left-0 w-full px-3 py-0.5"
+              style={{ transform: `translateY(${virtualRow.start}px)` }}
+            >
+              <TimelineItem message={msg} />
+            </div>
+          );
+        })}
+      </div>
     </div>
   );
 };
@@ -76,8 +158,49 @@ const TimelineItem: React.FC<{ message: ProgressMessage }> = ({ message }) => {
     }
   };
 
+  // Each kind reads differently: thoughts are muted asides, tool calls and
+  // logs are monospace, messages are the agent talking to the reviewer.
+  const getContentStyles = () => {
+    switch (message.type) {
+      case 'agent_thought':
+        return 'text-text-tertiary italic';
+      case 'tool_call':
+        return 'text-text-secondary font-mono text-[11px]';
+      case 'log':
+      case 'system':
+      case 'debug':
+        return 'text-text-disabled font-mono text-[10px]';
+      case 'error':
+        return 'text-red-400';
+      default:
+        return 'text-text-secondary';
+    }
+  };
+
+  const getBorderStyles = () => {
+    switch (message.type) {
+      case 'agent_message':
+        return 'border-blue-400/40';
+      case 'agent_thought':
+        return 'border-purple-400/40';
+      case 'tool_call':
+        return 'border-yellow-400/40';
+      case 'error':
+        return 'border-red-400/60';
+      case 'completed':
+        return 'border-green-400/60';
+      default:
+        return 'border-transparent';
+    }
+  };
+
   return (
-    <div className="animate-fade-in group flex flex-col gap-1.5 rounded-md p-2 transition-colors hover:bg-white/5">
+    <div
+      className={clsx(
+        'animate-fade-in group flex flex-col gap-1.5 rounded-md border-l-2 p-2 transition-colors hover:bg-white/5',
+        getBorderStyles()
+      )}
+    >
       {/* Header row: Tag and Timestamp */}
       <div className="flex items-center justify-between border-b border-white/5 pb-1">
         <span
@@ -94,9 +217,25 @@ const TimelineItem: React.FC<{ message: ProgressMessage }> = ({ message }) => {
       </div>
 
       {/* Content row */}
-      <div className="text-text-secondary pl-1 text-xs leading-relaxed break-words whitespace-pre-wrap">
+      <div
+        className={clsx(
+          'pl-1 text-xs leading-relaxed break-words whitespace-pre-wrap',
+          getContentStyles()
+        )}
+      >
         {message.message}
       </div>
+
+      {message.type === 'error' && typeof message.data?.details === 'string' && (
+        <details className="pl-1">
+          <summary className="text-text-tertiary hover:text-text-secondary cursor-pointer text-[10px] font-medium select-none">
+            Details
+          </summary>
+          <pre className="bg-bg-secondary border-border text-text-secondary custom-scrollbar mt-1 max-h-60 overflow-auto rounded border p-2 font-mono text-[10px] leading-relaxed whitespace-pre-wrap">
+            {message.data.details}
+          </pre>
+        </details>
+      )}
     </div>
   );
 };
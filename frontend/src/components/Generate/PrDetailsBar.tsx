@@ -0,0 +1,47 @@
+import React from 'react';
+import { GitBranch, GitCommit, User } from '@phosphor-icons/react';
+import type { PrDetails } from '../../types';
+
+interface PrDetailsBarProps {
+  title: string | null;
+  details: PrDetails;
+  /** Line counts of the fetched diff, for providers that don't report them. */
+  additions: number;
+  deletions: number;
+}
+
+/** Who opened the fetched pull request and what it merges, to confirm it's the right one. */
+export const PrDetailsBar: React.FC<PrDetailsBarProps> = ({
+  title,
+  details,
+  additions,
+  deletions,
+}) => (
+  <div className="text-text-tertiary flex min-w-0 flex-wrap items-center gap-x-4 gap-y-1 px-1 text-[10px]">
+    {title && <span className="text-text-primary truncate text-xs font-medium">{title}</span>}
+    {details.author && (
+      <span className="flex items-center gap-1">
+        <User size={11} />
+        {details.author}
+      </span>
+    )}
+    {details.base_ref && details.head_ref && (
+      <span className="flex min-w-0 items-center gap-1 font-mono" title="base ← head">
+        <GitBranch size={11} className="flex-shrink-0" />
+        <span className="truncate">
+          {details.base_ref} ← {details.head_ref}
+        </span>
+      </span>
+    )}
+    {details.commit_count !== null && (
+      <span className="flex items-center gap-1">
+        <GitCommit size={11} />
+        {details.commit_count} {details.commit_count === 1 ? 'commit' : 'commits'}
+      </span>
+    )}
+    <span className="font-mono">
+      <span className="text-green-400">+{details.additions ?? additions}</span>{' '}
+      <span className="text-red-400">-{details.deletions ?? deletions}</span>
+    </span>
+  </div>
+);
@@ -1,11 +1,12 @@
-import React, { useMemo } from 'react';
-import { GitMerge, GithubLogo, GitlabLogo, Spinner } from '@phosphor-icons/react';
+import React, { useEffect, useMemo, useState } from 'react';
+import { GitMerge, GithubLogo, GitlabLogo, Spinner, X } from '@phosphor-icons/react';
 import { AnimatePresence, motion } from 'framer-motion';
 
 interface PrInputProps {
   prRef: string;
   onPrRefChange: (value: string) => void;
   onFetch: () => void;
+  onCancel: () => void;
   isLoading: boolean;
   disabled: boolean;
 }
@@ -14,9 +15,20 @@ export const PrInput: React.FC<PrInputProps> = ({
   prRef,
   onPrRefChange,
   onFetch,
+  onCancel,
   isLoading,
   disabled,
 }) => {
+  // Seconds since the fetch started, so a slow network doesn't look stuck.
+  const [elapsed, setElapsed] = useState(0);
+  useEffect(() => {
+    setElapsed(0);
+    if (!isLoading) return;
+    const started = Date.now();
+    const timer = setInterval(() => setElapsed(Math.floor((Date.now() - started) / 1000)), 1000);
+    return () => clearInterval(timer);
+  }, [isLoading]);
+
   const detectedProvider = useMemo(() => {
     const value = prRef.trim();
     if (!value) return 'generic';
@@ -24,15 +36,26 @@ export const PrInput: React.FC<PrInputProps> = ({
     const githubUrl = /https?:\/\/github\.com\/[^/\s]+\/[^/\s]+\/pull\/\d+/i;
     const githubShort = /^[^/\s]+\/[^/\s]+#\d+$/i;
     const githubLegacy = /^[^/\s]+\/[^/\s]+\/\d+$/i;
+    const githubCompare = /github\.com\/[^/\s]+\/[^/\s]+\/compare\/\S+\.\.\S+/i;
     const gitlabUrl = /https?:\/\/[^/\s]+\/.+\/-\/merge_requests\/\d+/i;
+    const gitlabCompare = /[^/\s]+\/.+\/-\/compare\/\S+\.\.\S+/i;
     const gitlabShort = /^[^\s!#]+(?:\/[^\s!#]+)*!\d+$/i;
+    const giteaUrl = /https?:\/\/[^/\s]+\/[^/\s]+\/[^/\s]+\/pulls\/\d+/i;
 
-    if (githubUrl.test(value) || githubShort.test(value) || githubLegacy.test(value)) {
+    if (
+      githubUrl.test(value) ||
+      githubShort.test(value) ||
+      githubLegacy.test(value) ||
+      githubCompare.test(value)
+    ) {
       return 'github';
     }
-    if (gitlabUrl.test(value) || gitlabShort.test(value)) {
+    if (gitlabUrl.test(value) || gitlabShort.test(value) || gitlabCompare.test(value)) {
       return 'gitlab';
     }
+    if (giteaUrl.test(value)) {
+      return 'gitea';
+    }
     return 'generic';
   }, [prRef]);
 
@@ -80,13 +103,31 @@ export const PrInput: React.FC<PrInputProps> = ({
         </span>
       </div>
       <div className="flex h-[14px] flex-shrink-0 items-center border-l border-white/5"></div>
-      <button
-        type="submit"
-        disabled={!prRef.trim() || isLoading || disabled}
-        className="flex min-w-[48px] flex-shrink-0 items-center justify-center gap-1 px-3 py-1.5 text-[10px] font-medium opacity-80 transition-colors hover:opacity-100 disabled:opacity-50"
-      >
-        {isLoading ? <Spinner size={14} className="text-text-primary animate-spin" /> : 'Fetch'}
-      </button>
+      {isLoading ? (
+        <>
+          <span className="text-text-secondary flex flex-shrink-0 items-center gap-1.5 px-3 py-1.5 font-mono text-[10px]">
+            <Spinner size={14} className="text-text-primary animate-spin" />
+            {elapsed}s
+          </span>
+          <button
+            type="button"
+            onClick={onCancel}
+            title="Cancel fetch"
+            aria-label="Cancel fetch"
+            className="flex flex-shrink-0 items-center justify-center px-2 py-1.5 opacity-80 transition-colors hover:opacity-100"
+          >
+            <X size={12} />
+          </button>
+        </>
+      ) : (
+        <button
+          type="submit"
+          disabled={!prRef.trim() || disabled}
+          className="flex min-w-[48px] flex-shrink-0 items-center justify-center gap-1 px-3 py-1.5 text-[10px] font-medium opacity-80 transition-colors hover:opacity-100 disabled:opacity-50"
+        >
+          Fetch
+        </button>
+      )}
     </form>
   );
 };
@@ -0,0 +1,90 @@
+import React, { useState } from 'react';
+import * as Popover from '@radix-ui/react-popover';
+import { ClockCounterClockwise } from '@phosphor-icons/react';
+import { useTauri } from '../../hooks/useTauri';
+import type { PendingReviewState, RecentPendingDiff } from '../../types';
+
+interface RecentDiffsMenuProps {
+  onRestore: (pending: PendingReviewState) => void;
+  disabled?: boolean;
+}
+
+const describe = (entry: RecentPendingDiff) => {
+  const files = `${entry.files} file${entry.files === 1 ? '' : 's'}`;
+  const repo = entry.repo_root?.split(/[\\/]/).pop();
+  return repo ? `${files} · ${repo}` : files;
+};
+
+/** Lists diffs received earlier and reopens the one picked. */
+export const RecentDiffsMenu: React.FC<RecentDiffsMenuProps> = ({ onRestore, disabled }) => {
+  const { getRecentPendingDiffs, restorePendingDiff } = useTauri();
+  const [open, setOpen] = useState(false);
+  const [entries, setEntries] = useState<RecentPendingDiff[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleOpenChange = (next: boolean) => {
+    setOpen(next);
+    if (!next) return;
+    // Refetched on every open: diffs can arrive while the view is mounted.
+    setError(null);
+    getRecentPendingDiffs()
+      .then(setEntries)
+      .catch(err => setError(String(err)));
+  };
+
+  const handleSelect = async (id: string) => {
+    try {
+      const pending = await restorePendingDiff(id);
+      setOpen(false);
+      onRestore(pending);
+    } catch (err) {
+      setError(String(err));
+    }
+  };
+
+  return (
+    <Popover.Root open={open} onOpenChange={handleOpenChange}>
+      <Popover.Trigger asChild>
+        <button
+          disabled={disabled}
+          className="bg-bg-secondary/90 hover:bg-bg-tertiary text-text-secondary hover:text-text-primary ring-border flex h-8 items-center gap-1.5 rounded-md px-3 text-[10px] font-medium shadow-sm ring-1 backdrop-blur-sm transition-all disabled:opacity-50"
+          title="Reopen a diff received earlier"
+        >
+          <ClockCounterClockwise size={13} />
+          <span>Recent</span>
+        </button>
+      </Popover.Trigger>
+      <Popover.Portal>
+        <Popover.Content
+          className="bg-bg-elevated border-border shadow-custom z-50 w-[280px] rounded-lg border p-1"
+          side="bottom"
+          align="end"
+          sideOffset={5}
+        >
+          {error ? (
+            <div className="px-2 py-1.5 text-[11px] text-red-400">{error}</div>
+          ) : entries === null ? (
+            <div className="text-text-tertiary px-2 py-1.5 text-[11px]">Loading…</div>
+          ) : entries.length === 0 ? (
+            <div className="text-text-tertiary px-2 py-1.5 text-[11px]">No recent diffs</div>
+          ) : (
+            <div className="flex flex-col gap-0.5">
+              {entries.map(entry => (
+                <button
+                  key={entry.id}
+                  onClick={() => void handleSelect(entry.id)}
+                  className="hover:bg-bg-tertiary flex flex-col rounded px-2 py-1.5 text-left transition-colors"
+                >
+                  <span className="text-text-primary truncate text-xs">{entry.source}</span>
+                  <span className="text-text-tertiary truncate text-[10px]">
+                    {describe(entry)} · {new Date(entry.created_at).toLocaleString()}
+                  </span>
+                </button>
+              ))}
+            </div>
+          )}
+        </Popover.Content>
+      </Popover.Portal>
+    </Popover.Root>
+  );
+};
@@ -0,0 +1,64 @@
+import React from 'react';
+import { Lightning, MagnifyingGlass } from '@phosphor-icons/react';
+import type { ReviewMode } from '../../types';
+
+interface ReviewModeToggleProps {
+  mode: ReviewMode;
+  /** Whether `mode` was picked from the diff's size rather than by the user. */
+  isAuto: boolean;
+  quickMaxLines: number;
+  onChange: (mode: ReviewMode) => void;
+  disabled: boolean;
+}
+
+const MODES: { mode: ReviewMode; label: string; icon: React.ReactNode }[] = [
+  { mode: 'quick', label: 'Quick', icon: <Lightning size={12} /> },
+  { mode: 'thorough', label: 'Thorough', icon: <MagnifyingGlass size={12} /> },
+];
+
+export const ReviewModeToggle: React.FC<ReviewModeToggleProps> = ({
+  mode,
+  isAuto,
+  quickMaxLines,
+  onChange,
+  disabled,
+}) => {
+  const hint =
+    mode === 'quick'
+      ? 'Lighter prompt, no snapshot, a few tasks at most.'
+      : 'Full prompt, with the repo snapshot when one is allowed.';
+  const autoNote = isAuto
+    ? ` Picked for diffs ${mode === 'quick' ? 'up to' : 'over'} ${quickMaxLines} changed lines.`
+    : '';
+
+  return (
+    <div className="border-border border-b px-5 py-3">
+      <div className="flex items-center gap-2">
+        <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
+          Review
+        </span>
+        <div className="bg-bg-tertiary/60 ring-border ml-auto flex rounded-md p-0.5 ring-1">
+          {MODES.map(option => (
+            <button
+              key={option.mode}
+              onClick={() => onChange(option.mode)}
+              disabled={disabled}
+              aria-pressed={mode === option.mode}
+              className={`flex items-center gap-1.5 rounded-[4px] px-2.5 py-1 text-[10px] font-medium transition-all disabled:opacity-50 ${
+                mode === option.mode
+                  ? 'bg-bg-primary text-text-primary shadow-sm'
+                  : 'text-text-disabled hover:text-text-secondary'
+              }`}
+            >
+              {option.icon} {option.label}
+            </button>
+          ))}
+        </div>
+      </div>
+      <p className="text-text-tertiary mt-1.5 text-[10px]">
+        {hint}
+        {autoNote}
+      </p>
+    </div>
+  );
+};
@@ -1,5 +1,6 @@
 import { GithubLogo, GitlabLogo, X } from '@phosphor-icons/react';
 import type { ReviewSource } from '../../types';
+import { formatSourceRef, isGitLabSource } from '../../lib/review-source';
 import { PrInput } from './PrInput';
 
 interface VcsInputCardProps {
@@ -7,6 +8,7 @@ interface VcsInputCardProps {
   prRef: string;
   onPrRefChange: (value: string) => void;
   onFetch: () => void;
+  onCancelFetch: () => void;
   isLoading: boolean;
   disabled: boolean;
   onClear: () => void;
@@ -17,22 +19,16 @@ export const VcsInputCard: React.FC<VcsInputCardProps> = ({
   prRef,
   onPrRefChange,
   onFetch,
+  onCancelFetch,
   isLoading,
   disabled,
   onClear,
 }) => {
-  const vcsSource =
-    pendingSource && (pendingSource.type === 'github_pr' || pendingSource.type === 'gitlab_mr')
-      ? pendingSource
-      : null;
+  const vcsSource = pendingSource && pendingSource.type !== 'diff_paste' ? pendingSource : null;
   const hasRemoteSource = Boolean(vcsSource);
-  const vcsSourceLabel = vcsSource
-    ? vcsSource.type === 'gitlab_mr'
-      ? `${vcsSource.project_path}!${vcsSource.number}`
-      : `${vcsSource.owner}/${vcsSource.repo}#${vcsSource.number}`
-    : '';
+  const vcsSourceLabel = vcsSource ? (formatSourceRef(vcsSource) ?? '') : '';
   const vcsSourceIcon = vcsSource ? (
-    vcsSource.type === 'gitlab_mr' ? (
+    isGitLabSource(vcsSource) ? (
       <GitlabLogo size={14} weight="fill" />
     ) : (
       <GithubLogo size={14} weight="fill" />
@@ -69,6 +65,7 @@ export const VcsInputCard: React.FC<VcsInputCardProps> = ({
           prRef={prRef}
           onPrRefChange={onPrRefChange}
           onFetch={onFetch}
+          onCancel={onCancelFetch}
           isLoading={isLoading}
           disabled={disabled}
         />
@@ -0,0 +1,48 @@
+import { describe, it, expect, vi } from 'vitest';
+import { render, screen, fireEvent } from '@testing-library/react';
+import { AgentSelector } from '../AgentSelector';
+import type { Agent } from '../../../types';
+
+vi.mock('../../../hooks/useTauri', () => ({
+  useTauri: () => ({ copyToClipboard: vi.fn().mockResolvedValue(undefined) }),
+}));
+
+const gemini: Agent = {
+  id: 'gemini',
+  name: 'Gemini',
+  available: false,
+  expected_command: 'gemini',
+  install_command: 'brew install gemini-cli',
+};
+
+describe('AgentSelector', () => {
+  it('explains a missing agent with its install command', () => {
+    const onConfigureAgent = vi.fn();
+    render(
+      <AgentSelector
+        agents={[gemini]}
+        selectedAgentId="gemini"
+        onSelect={vi.fn()}
+        onConfigureAgent={onConfigureAgent}
+      />
+    );
+
+    expect(screen.getByText(/was not found on your PATH/)).toBeInTheDocument();
+    expect(screen.getByText('brew install gemini-cli')).toBeInTheDocument();
+
+    fireEvent.click(screen.getByText('Configure path'));
+    expect(onConfigureAgent).toHaveBeenCalledWith('gemini');
+  });
+
+  it('shows no hint for an available agent', () => {
+    render(
+      <AgentSelector
+        agents={[{ ...gemini, available: true }]}
+        selectedAgentId="gemini"
+        onSelect={vi.fn()}
+      />
+    );
+
+    expect(screen.queryByText(/was not found on your PATH/)).not.toBeInTheDocument();
+  });
+});
@@ -1,5 +1,5 @@
 import { describe, it, expect, vi, beforeEach } from 'vitest';
-import { render, screen, fireEvent } from '@testing-library/react';
+import { render, screen, fireEvent, act } from '@testing-library/react';
 import React from 'react';
 import { GenerateView } from '../GenerateView';
 import { useAppStore } from '../../../store';
@@ -8,16 +8,51 @@ import { GenerationProvider } from '../../../contexts/GenerationContext';
 
 // Mock the hooks used in GenerateView
 vi.mock('../../../store');
-vi.mock('../../../hooks/useTauri', () => ({
-  useTauri: () => ({
-    fetchRemotePr: vi.fn(),
+const tauri = vi.hoisted(() => ({
+  fetchRemotePr: vi.fn(),
+  cancelPrFetch: vi.fn(),
+  loadPatchFiles: vi.fn(),
+  parseDiff: vi.fn(),
+  loadGenerateDraft: vi.fn(),
+  saveGenerateDraft: vi.fn(),
+  clearGenerateDraft: vi.fn(),
+  getRecentPendingDiffs: vi.fn(),
+  restorePendingDiff: vi.fn(),
+}));
+const webview = vi.hoisted(() => ({ onDragDrop: null as null | ((event: any) => void) }));
+const toastMock = vi.hoisted(() => ({ error: vi.fn() }));
+vi.mock('sonner', () => ({ toast: toastMock }));
+vi.mock('@tauri-apps/api/webview', () => ({
+  getCurrentWebview: () => ({
+    onDragDropEvent: (handler: (event: any) => void) => {
+      webview.onDragDrop = handler;
+      return Promise.resolve(() => {});
+    },
   }),
 }));
+vi.mock('../../../hooks/useTauri', () => ({
+  useTauri: () => tauri,
+}));
 vi.mock('../../../hooks/useAgents', () => ({
   useAgents: () => ({ data: [] }),
+  useAgentProbe: () => ({ data: undefined }),
 }));
 vi.mock('../../../hooks/useRepos', () => ({
-  useRepos: () => ({ data: [], addRepo: { isPending: false }, cloneRepo: { isPending: false } }),
+  useRepos: () => ({
+    data: [],
+    addRepo: { isPending: false },
+    cloneRepo: { isPending: false },
+    allowSnapshots: { isPending: false },
+  }),
+}));
+const offlineMode = vi.hoisted(() => ({ offline: false }));
+vi.mock('../../../hooks/useSettings', () => ({
+  useOfflineMode: () => ({ ...offlineMode, setOffline: vi.fn(), isUpdating: false }),
+  useQuickReviewMaxLines: () => ({
+    quickMaxLines: 50,
+    isLoading: false,
+    setQuickMaxLines: vi.fn(),
+  }),
 }));
 vi.mock('../../../contexts/useGeneration', () => ({
   useGeneration: () => ({
@@ -46,15 +81,24 @@ vi.mock('../PlanOverview', () => ({
     </div>
   ),
 }));
+vi.mock('../FileSelection', () => ({
+  FileSelection: () => <div data-testid="file-selection" />,
+}));
 vi.mock('../LiveActivityFeed', () => ({
   LiveActivityFeed: () => <div data-testid="live-activity-feed" />,
 }));
 vi.mock('../VcsInputCard', () => ({
-  VcsInputCard: ({ onClear }: any) => (
+  VcsInputCard: ({ onClear, onFetch, onCancelFetch }: any) => (
     <div data-testid="vcs-input-card">
       <button data-testid="clear-button" onClick={onClear}>
         Clear
       </button>
+      <button data-testid="fetch-button" onClick={onFetch}>
+        Fetch
+      </button>
+      <button data-testid="cancel-fetch-button" onClick={onCancelFetch}>
+        Cancel
+      </button>
     </div>
   ),
 }));
@@ -82,6 +126,7 @@ describe('GenerateView Plan Expansion', () => {
 
   beforeEach(() => {
     vi.clearAllMocks();
+    offlineMode.offline = false;
     store = {
       diffText: '',
       setDiffText: vi.fn(),
@@ -105,6 +150,10 @@ describe('GenerateView Plan Expansion', () => {
       setAgentId: vi.fn(),
     };
     (useAppStore as any).mockImplementation((selector: any) => selector(store));
+    tauri.loadGenerateDraft.mockResolvedValue(null);
+    tauri.saveGenerateDraft.mockResolvedValue(undefined);
+    tauri.clearGenerateDraft.mockResolvedValue(undefined);
+    tauri.getRecentPendingDiffs.mockResolvedValue([]);
   });
 
   it('automatically expands the plan panel when the first plan item arrives', () => {
@@ -174,4 +223,122 @@ describe('GenerateView Plan Expansion', () => {
     // Should auto-expand again
     expect(store.setIsPlanExpanded).toHaveBeenCalledWith(true);
   });
+
+  it('hides the remote link input in offline mode', () => {
+    offlineMode.offline = true;
+    render(<GenerateView onNavigate={vi.fn()} />, { wrapper });
+
+    expect(screen.queryByTestId('vcs-input-card')).toBeNull();
+    expect(screen.getByText('Offline mode')).toBeTruthy();
+  });
+
+  it('drops a remote fetch that finishes after the link changed', async () => {
+    let resolveFetch: (value: unknown) => void = () => {};
+    tauri.fetchRemotePr.mockReturnValue(new Promise(resolve => (resolveFetch = resolve)));
+    store.prRef = 'puemos/lareview#1';
+    const { rerender } = render(<GenerateView onNavigate={vi.fn()} />, { wrapper });
+
+    fireEvent.click(screen.getByTestId('fetch-button'));
+    store.prRef = 'puemos/lareview#2';
+    rerender(<GenerateView onNavigate={vi.fn()} />);
+
+    await act(async () => {
+      resolveFetch({ diff_text: 'stale', files: [], source: null });
+    });
+
+    expect(tauri.fetchRemotePr).toHaveBeenCalledWith('puemos/lareview#1', null);
+    expect(store.setParsedDiff).not.toHaveBeenCalled();
+  });
+
+  it('ignores a remote fetch that finishes after it was cancelled', async () => {
+    let resolveFetch: (value: unknown) => void = () => {};
+    tauri.fetchRemotePr.mockReturnValue(new Promise(resolve => (resolveFetch = resolve)));
+    tauri.cancelPrFetch.mockResolvedValue(undefined);
+    store.prRef = 'puemos/lareview#1';
+    render(<GenerateView onNavigate={vi.fn()} />, { wrapper });
+
+    fireEvent.click(screen.getByTestId('fetch-button'));
+    fireEvent.click(screen.getByTestId('cancel-fetch-button'));
+
+    await act(async () => {
+      resolveFetch({ diff_text: 'late', files: [], source: null });
+    });
+
+    expect(tauri.cancelPrFetch).toHaveBeenCalled();
+    expect(store.setParsedDiff).not.toHaveBeenCalled();
+  });
+
+  it('loads dropped patch files as one diff', async () => {
+    const parsed = { diff_text: 'diff --git a/x b/x', files: [], title: '2 patches' };
+    tauri.loadPatchFiles.mockResolvedValue(parsed);
+    render(<GenerateView onNavigate={vi.fn()} />, { wrapper });
+
+    await act(async () => {
+      webview.onDragDrop?.({
+        payload: { type: 'drop', paths: ['/tmp/a.patch', '/tmp/notes.txt', '/tmp/b.DIFF'] },
+      });
+    });
+
+    expect(tauri.loadPatchFiles).toHaveBeenCalledWith(['/tmp/a.patch', '/tmp/b.DIFF']);
+    expect(store.setParsedDiff).toHaveBeenCalledWith(parsed);
+    expect(store.setPendingSource).toHaveBeenCalledWith(null);
+    expect(screen.getByText('2 patches')).toBeTruthy();
+    expect(toastMock.error).toHaveBeenCalledWith(
+      'Only .patch and .diff files can be dropped',
+      expect.objectContaining({ description: 'notes.txt' })
+    );
+  });
+
+  it('restores the generate input saved before a restart', async () => {
+    tauri.loadGenerateDraft.mockResolvedValue({
+      diff_text: 'diff --git a/x b/x',
+      agent_id: 'codex',
+      repo_id: 'repo-1',
+      pr_ref: null,
+    });
+
+    await act(async () => {
+      render(<GenerateView onNavigate={vi.fn()} />, { wrapper });
+    });
+
+    expect(store.setAgentId).toHaveBeenCalledWith('codex');
+    expect(store.setSelectedRepoId).toHaveBeenCalledWith('repo-1');
+    expect(store.setPrRef).not.toHaveBeenCalled();
+  });
+
+  it('reopens a diff from the recent list', async () => {
+    const source = { type: 'github_pr', owner: 'o', repo: 'r', number: 7 };
+    tauri.getRecentPendingDiffs.mockResolvedValue([
+      {
+        id: 'pd-1',
+        source: 'gh pr 7',
+        repo_root: '/src/r',
+        agent: null,
+        created_at: '2024-01-01T00:00:00Z',
+        review_source: source,
+        files: 2,
+      },
+    ]);
+    tauri.restorePendingDiff.mockResolvedValue({
+      id: 'pd-1',
+      diff: 'diff --git a/x b/x',
+      repo_root: '/src/r',
+      agent: null,
+      source: 'gh pr 7',
+      created_at: '2024-01-02T00:00:00Z',
+      review_source: source,
+    });
+
+    render(<GenerateView onNavigate={vi.fn()} />, { wrapper });
+    await act(async () => {
+      fireEvent.click(screen.getByText('Recent'));
+    });
+    expect(screen.getByText(/2 files · r/)).toBeInTheDocument();
+
+    await act(async () => {
+      fireEvent.click(screen.getByText('gh pr 7'));
+    });
+    expect(tauri.restorePendingDiff).toHaveBeenCalledWith('pd-1');
+    expect(store.setPendingSource).toHaveBeenCalledWith(source);
+  });
 });
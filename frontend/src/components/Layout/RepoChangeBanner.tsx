@@ -0,0 +1,92 @@
+import React, { useEffect, useState } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { GitCommit, X } from '@phosphor-icons/react';
+import { toast } from 'sonner';
+import { useTauri } from '../../hooks/useTauri';
+import { useRepos } from '../../hooks/useRepos';
+import { useAppStore } from '../../store';
+import type { RepoHeadChange, ViewType } from '../../types';
+
+interface RepoChangeBannerProps {
+  onNavigate: (view: ViewType) => void;
+}
+
+/** Offers to review the new commits of a watched repo. */
+export const RepoChangeBanner: React.FC<RepoChangeBannerProps> = ({ onNavigate }) => {
+  const { getRepoCommitDiff } = useTauri();
+  const { data: repos = [] } = useRepos();
+  const isGenerating = useAppStore(state => state.isGenerating);
+  const setDiffText = useAppStore(state => state.setDiffText);
+  const setSelectedRepoId = useAppStore(state => state.setSelectedRepoId);
+  const setPendingSource = useAppStore(state => state.setPendingSource);
+  const [change, setChange] = useState<RepoHeadChange | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+
+  useEffect(() => {
+    const unlisten = listen<RepoHeadChange>('lareview:repo-head-changed', event => {
+      // Consecutive commits before the banner is acted on collapse into one range.
+      setChange(prev =>
+        prev && prev.repo_id === event.payload.repo_id
+          ? { ...event.payload, previous: prev.previous }
+          : event.payload
+      );
+    });
+    return () => {
+      unlisten.then(fn => fn()).catch(console.error);
+    };
+  }, []);
+
+  if (!change || isGenerating) return null;
+
+  const repoName = repos.find(repo => repo.id === change.repo_id)?.name ?? 'A watched repository';
+
+  const handleReview = async () => {
+    setIsLoading(true);
+    try {
+      const diff = await getRepoCommitDiff(change.repo_id, change.previous, change.current);
+      if (!diff.trim()) {
+        toast('No changes to review', {
+          description: 'The new commits did not change any files.',
+        });
+      } else {
+        setDiffText(diff);
+        setPendingSource(null);
+        setSelectedRepoId(change.repo_id);
+        onNavigate('generate');
+      }
+      setChange(null);
+    } catch (error) {
+      toast('Failed to load new commits', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  return (
+    <div className="bg-brand/10 border-brand/20 flex items-center gap-3 border-b px-4 py-2 text-xs">
+      <GitCommit size={14} className="text-brand shrink-0" />
+      <span className="text-text-secondary truncate">
+        <span className="text-text-primary font-medium">{repoName}</span> has new commits (
+        <span className="font-mono">{change.previous.slice(0, 7)}</span>
+        {' → '}
+        <span className="font-mono">{change.current.slice(0, 7)}</span>).
+      </span>
+      <button
+        onClick={handleReview}
+        disabled={isLoading}
+        className="bg-brand text-bg-primary ml-auto shrink-0 rounded-md px-3 py-1 text-[10px] font-bold transition-all hover:brightness-110 disabled:opacity-50"
+      >
+        Review changes
+      </button>
+      <button
+        onClick={() => setChange(null)}
+        className="text-text-tertiary hover:text-text-primary shrink-0 rounded p-1 transition-colors"
+        title="Dismiss"
+      >
+        <X size={12} />
+      </button>
+    </div>
+  );
+};
@@ -1,14 +1,24 @@
-import React, { useCallback, useState } from 'react';
+import React, { useCallback, useEffect, useMemo, useState } from 'react';
 import { toast } from 'sonner';
 import { useQueryClient } from '@tanstack/react-query';
 import { useAppStore } from '../../store';
 import { ConfirmationModal } from '../Common/ConfirmationModal';
 import { Brain } from '@phosphor-icons/react';
 import { ICONS } from '../../constants/icons';
-import type { ViewType } from '../../types';
-import { useReviews } from '../../hooks/useReviews';
+import type { Review, ViewType } from '../../types';
+import { useReviews, useReviewSearch } from '../../hooks/useReviews';
 import { useTauri } from '../../hooks/useTauri';
+import { useI18n } from '../../hooks/useI18n';
 import { queryKeys } from '../../lib/query-keys';
+import {
+  filterReviewsByStatus,
+  groupReviewsByRepo,
+  sortReviews,
+  REVIEW_SORT_LABELS,
+  REVIEW_STATUS_FILTER_LABELS,
+  type ReviewSort,
+  type ReviewStatusFilter,
+} from '../../lib/review-list';
 import type { UpdateInfo } from '../../hooks/useUpdateCheck';
 
 interface SidebarProps {
@@ -27,11 +37,48 @@ export const Sidebar: React.FC<SidebarProps> = ({
   onUpdateClick,
 }) => {
   const queryClient = useQueryClient();
-  const { setReviewId, reviewId } = useAppStore();
-  const { data: reviews = [], isLoading, invalidate } = useReviews();
-  const { deleteReview, getReviewRuns } = useTauri();
+  const { setReviewId, reviewId, reviewListSort, setReviewListSort } = useAppStore();
+  const {
+    data: reviews = [],
+    total: reviewTotal,
+    hasMore,
+    loadMore,
+    isFetchingNextPage,
+    isLoading,
+    invalidate,
+  } = useReviews();
+  const { deleteReview, mergeReviews, getReviewRuns, stop_generation } = useTauri();
   const [error, setError] = useState<string | null>(null);
   const [reviewToDelete, setReviewToDelete] = useState<string | null>(null);
+  const [reviewToMerge, setReviewToMerge] = useState<string | null>(null);
+  const [tagFilter, setTagFilter] = useState<string | null>(null);
+  const [statusFilter, setStatusFilter] = useState<ReviewStatusFilter>('all');
+  const [groupByRepo, setGroupByRepo] = useState(false);
+  const [searchInput, setSearchInput] = useState('');
+  const [searchQuery, setSearchQuery] = useState('');
+
+  useEffect(() => {
+    const handle = setTimeout(() => setSearchQuery(searchInput.trim()), 200);
+    return () => clearTimeout(handle);
+  }, [searchInput]);
+
+  const { data: searchResults } = useReviewSearch(searchQuery);
+
+  const allTags = useMemo(
+    () => Array.from(new Set(reviews.flatMap(review => review.tags ?? []))).sort(),
+    [reviews]
+  );
+  // A tag that no longer exists (removed from its last review) stops filtering.
+  const activeTag = tagFilter && allTags.includes(tagFilter) ? tagFilter : null;
+  // Search results stay in relevance order.
+  const matchingReviews = searchQuery
+    ? (searchResults ?? [])
+    : sortReviews(reviews, reviewListSort);
+  const taggedReviews = activeTag
+    ? matchingReviews.filter(review => review.tags?.includes(activeTag))
+    : matchingReviews;
+  const visibleReviews = filterReviewsByStatus(taggedReviews, statusFilter);
+  const hasRepoMatches = reviews.some(review => review.repo_id);
 
   const prefetchReview = useCallback(
     (reviewId: string) => {
@@ -78,6 +125,129 @@ export const Sidebar: React.FC<SidebarProps> = ({
     }
   };
 
+  const handleStopGeneration = async (e: React.MouseEvent, review: Review) => {
+    e.stopPropagation();
+    if (!review.active_run_id) return;
+    try {
+      await stop_generation(review.active_run_id);
+      invalidate();
+    } catch (err) {
+      console.error('Failed to stop generation:', err);
+      toast('Failed to stop generation', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+    }
+  };
+
+  const handleMergeReview = (e: React.MouseEvent, id: string) => {
+    e.stopPropagation();
+    setError(null);
+    setReviewToMerge(id);
+  };
+
+  const confirmMergeReview = async () => {
+    if (!reviewToMerge || !reviewId) return;
+    try {
+      const outcome = await mergeReviews(reviewId, reviewToMerge);
+      invalidate();
+      queryClient.invalidateQueries({ queryKey: queryKeys.reviewRuns(reviewId) });
+      queryClient.invalidateQueries({ queryKey: queryKeys.feedbackByReview(reviewId) });
+      setReviewToMerge(null);
+      toast('Reviews Merged', {
+        description:
+          outcome.feedback_deduplicated > 0
+            ? `Moved ${outcome.runs_moved} run(s) and ${outcome.feedback_moved} feedback item(s); ${outcome.feedback_deduplicated} duplicate(s) dropped.`
+            : `Moved ${outcome.runs_moved} run(s) and ${outcome.feedback_moved} feedback item(s).`,
+      });
+    } catch (err) {
+      console.error('Failed to merge reviews:', err);
+      toast('Failed to merge reviews', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+      setReviewToMerge(null);
+    }
+  };
+
+  const renderReview = (review: Review) => (
+    <div
+      key={review.id}
+      role="button"
+      tabIndex={0}
+      className={`group relative ml-2 flex cursor-pointer items-center gap-2 rounded-md px-3 py-1.5 text-left text-xs transition-all ${
+        reviewId === review.id && currentView === 'review'
+          ? 'bg-blue-500/10 text-blue-400'
+          : 'text-gray-500 hover:bg-white/5 hover:text-gray-300'
+      }`}
+      onClick={() => handleReviewClick(review.id)}
+      onKeyDown={e => e.key === 'Enter' && handleReviewClick(review.id)}
+      onMouseEnter={() => prefetchReview(review.id)}
+      aria-label={`Review: ${review.title}`}
+      aria-current={reviewId === review.id && currentView === 'review' ? 'page' : undefined}
+    >
+      <span className="flex h-4 w-4 shrink-0 items-center justify-center" aria-hidden="true">
+        {isGenerating(review) ? (
+          <ICONS.ACTION_LOADING
+            size={10}
+            className="animate-spin text-blue-400"
+            aria-hidden="true"
+          />
+        ) : (
+          <span
+            className={`h-1.5 w-1.5 rounded-full ${review.status === 'done' ? 'bg-green-500/50' : 'bg-blue-500/50'}`}
+          />
+        )}
+      </span>
+      <span className="flex-1 truncate">{review.title}</span>
+      {!groupByRepo && review.repo_name && (
+        <span
+          className="max-w-[72px] shrink-0 truncate rounded bg-white/5 px-1 text-[9px] text-gray-500"
+          title={`Repository: ${review.repo_name}`}
+        >
+          {review.repo_name}
+        </span>
+      )}
+      {isGenerating(review) && review.active_run_id && (
+        <>
+          <span className="shrink-0 text-[10px] text-blue-400/70">
+            {review.active_run_status === 'queued' ? 'Queued' : 'Generating…'}
+          </span>
+          <button
+            onClick={e => handleStopGeneration(e, review)}
+            className="z-10 shrink-0 rounded-md p-1 text-gray-500 transition-all hover:bg-red-400/10 hover:text-red-400"
+            title="Cancel generation"
+            type="button"
+            aria-label={`Cancel generation: ${review.title}`}
+          >
+            <ICONS.ACTION_STOP size={12} aria-hidden="true" />
+          </button>
+        </>
+      )}
+      {reviewId && reviewId !== review.id && (
+        <button
+          onClick={e => handleMergeReview(e, review.id)}
+          className="z-10 shrink-0 rounded-md p-1 text-gray-500/50 opacity-0 transition-all group-hover:opacity-100 hover:bg-blue-400/10 hover:text-blue-400"
+          title="Merge into current review"
+          type="button"
+          aria-label={`Merge review into current review: ${review.title}`}
+        >
+          <ICONS.ACTION_MERGE size={14} aria-hidden="true" />
+        </button>
+      )}
+      <button
+        onClick={e => handleDeleteReview(e, review.id)}
+        className="z-10 shrink-0 rounded-md p-1 text-gray-500/50 opacity-0 transition-all group-hover:opacity-100 hover:bg-red-400/10 hover:text-red-400"
+        title="Delete Review"
+        type="button"
+        aria-label={`Delete review: ${review.title}`}
+      >
+        <ICONS.ACTION_DELETE size={14} aria-hidden="true" />
+      </button>
+    </div>
+  );
+
+  const mergeTitle = reviews.find(review => review.id === reviewToMerge)?.title;
+  const primaryTitle = reviews.find(review => review.id === reviewId)?.title;
+
   return (
     <aside
       className="border-border flex max-h-screen w-64 flex-col gap-0.5 overflow-hidden border-r bg-gray-950/50 pb-2"
@@ -92,16 +262,16 @@ export const Sidebar: React.FC<SidebarProps> = ({
       )}
       <SidebarItem
         icon={<ICONS.VIEW_GENERATE size={16} />}
-        label="Generate Review"
+        label={t('nav.generate')}
         isActive={currentView === 'generate'}
         onClick={() => onViewChange('generate')}
-        ariaLabel="Navigate to Generate Review"
+        ariaLabel={t('nav.goTo', { page: t('nav.generate') })}
       />
 
       <div className="flex flex-col gap-0.5">
         <SidebarItem
           icon={<ICONS.VIEW_REVIEW size={16} />}
-          label="Reviews"
+          label={t('nav.reviews')}
           isActive={currentView === 'review' && !reviewId}
           onClick={() => {
             onViewChange('review');
@@ -109,59 +279,112 @@ export const Sidebar: React.FC<SidebarProps> = ({
               setReviewId(reviews[0].id);
             }
           }}
-          ariaLabel="Navigate to Reviews"
+          ariaLabel={t('nav.goTo', { page: t('nav.reviews') })}
         />
         {reviews.length > 0 && !isLoading && (
           <div className="relative my-1 flex flex-col gap-0.5 pl-4">
             <div className="bg-border/50 absolute top-0 bottom-0 left-6 w-px" />
-            <div className="custom-scrollbar flex max-h-[300px] flex-col gap-0.5 overflow-y-auto pr-1">
-              {reviews.map(review => (
-                <div
-                  key={review.id}
-                  role="button"
-                  tabIndex={0}
-                  className={`group relative ml-2 flex cursor-pointer items-center gap-2 rounded-md px-3 py-1.5 text-left text-xs transition-all ${
-                    reviewId === review.id && currentView === 'review'
+            <div className="ml-2 px-3 pb-1">
+              <input
+                type="search"
+                value={searchInput}
+                onChange={e => setSearchInput(e.target.value)}
+                onKeyDown={e => e.key === 'Escape' && setSearchInput('')}
+                placeholder="Search reviews..."
+                aria-label="Search reviews"
+                className="w-full rounded-md border border-white/5 bg-white/5 px-2 py-1 text-[11px] text-gray-300 placeholder-gray-600 focus:border-blue-500/30 focus:outline-none"
+              />
+            </div>
+            <div className="ml-2 flex items-center gap-1 px-3 pb-1">
+              <select
+                value={statusFilter}
+                onChange={e => setStatusFilter(e.target.value as ReviewStatusFilter)}
+                aria-label="Filter reviews by status"
+                className="min-w-0 flex-1 rounded-md border border-white/5 bg-white/5 px-1 py-0.5 text-[10px] text-gray-400 focus:border-blue-500/30 focus:outline-none"
+              >
+                {Object.entries(REVIEW_STATUS_FILTER_LABELS).map(([value, label]) => (
+                  <option key={value} value={value}>
+                    {t(label)}
+                  </option>
+                ))}
+              </select>
+              <select
+                value={reviewListSort}
+                onChange={e => setReviewListSort(e.target.value as ReviewSort)}
+                aria-label="Sort reviews"
+                className="min-w-0 flex-1 rounded-md border border-white/5 bg-white/5 px-1 py-0.5 text-[10px] text-gray-400 focus:border-blue-500/30 focus:outline-none"
+              >
+                {Object.entries(REVIEW_SORT_LABELS).map(([value, label]) => (
+                  <option key={value} value={value}>
+                    {t(label)}
+                  </option>
+                ))}
+              </select>
+              {hasRepoMatches && (
+                <button
+                  type="button"
+                  onClick={() => setGroupByRepo(!groupByRepo)}
+                  aria-pressed={groupByRepo}
+                  title="Group by repository"
+                  className={`shrink-0 rounded-md p-1 transition-colors ${
+                    groupByRepo
                       ? 'bg-blue-500/10 text-blue-400'
                       : 'text-gray-500 hover:bg-white/5 hover:text-gray-300'
                   }`}
-                  onClick={() => handleReviewClick(review.id)}
-                  onKeyDown={e => e.key === 'Enter' && handleReviewClick(review.id)}
-                  onMouseEnter={() => prefetchReview(review.id)}
-                  aria-label={`Review: ${review.title}`}
-                  aria-current={
-                    reviewId === review.id && currentView === 'review' ? 'page' : undefined
-                  }
                 >
-                  <span
-                    className="flex h-4 w-4 shrink-0 items-center justify-center"
-                    aria-hidden="true"
-                  >
-                    {review.active_run_status === 'running' ||
-                    review.active_run_status === 'queued' ? (
-                      <ICONS.ACTION_LOADING
-                        size={10}
-                        className="animate-spin text-blue-400"
-                        aria-hidden="true"
-                      />
-                    ) : (
-                      <span
-                        className={`h-1.5 w-1.5 rounded-full ${review.status === 'done' ? 'bg-green-500/50' : 'bg-blue-500/50'}`}
-                      />
-                    )}
-                  </span>
-                  <span className="flex-1 truncate">{review.title}</span>
+                  <ICONS.VIEW_REPOS size={12} aria-hidden="true" />
+                </button>
+              )}
+            </div>
+            {allTags.length > 0 && (
+              <div
+                className="ml-2 flex flex-wrap gap-1 px-3 pb-1"
+                role="group"
+                aria-label="Filter reviews by tag"
+              >
+                {allTags.map(tag => (
                   <button
-                    onClick={e => handleDeleteReview(e, review.id)}
-                    className="z-10 shrink-0 rounded-md p-1 text-gray-500/50 opacity-0 transition-all group-hover:opacity-100 hover:bg-red-400/10 hover:text-red-400"
-                    title="Delete Review"
+                    key={tag}
                     type="button"
-                    aria-label={`Delete review: ${review.title}`}
+                    onClick={() => setTagFilter(activeTag === tag ? null : tag)}
+                    aria-pressed={activeTag === tag}
+                    className={`rounded px-1.5 py-0.5 text-[10px] transition-colors ${
+                      activeTag === tag
+                        ? 'bg-blue-500/10 text-blue-400'
+                        : 'text-gray-500 hover:bg-white/5 hover:text-gray-300'
+                    }`}
                   >
-                    <ICONS.ACTION_DELETE size={14} aria-hidden="true" />
+                    #{tag}
                   </button>
-                </div>
-              ))}
+                ))}
+              </div>
+            )}
+            <div className="custom-scrollbar flex max-h-[300px] flex-col gap-0.5 overflow-y-auto pr-1">
+              {(searchQuery || statusFilter !== 'all') && visibleReviews.length === 0 && (
+                <span className="ml-2 px-3 py-1.5 text-[11px] text-gray-600">No matching reviews</span>
+              )}
+              {groupByRepo
+                ? groupReviewsByRepo(visibleReviews).map(group => (
+                    <div key={group.repoId ?? 'unmatched'} className="flex flex-col gap-0.5">
+                      <span className="ml-2 truncate px-3 pt-1 text-[10px] font-medium text-gray-600">
+                        {group.repoName ?? 'Other'}
+                      </span>
+                      {group.reviews.map(renderReview)}
+                    </div>
+                  ))
+                : visibleReviews.map(renderReview)}
+              {hasMore && !searchQuery && (
+                <button
+                  type="button"
+                  onClick={() => loadMore()}
+                  disabled={isFetchingNextPage}
+                  className="ml-2 px-3 py-1.5 text-left text-[11px] text-gray-500 transition-colors hover:text-gray-300 disabled:opacity-50"
+                >
+                  {isFetchingNextPage
+                    ? 'Loading...'
+                    : `Load more (${reviews.length} of ${reviewTotal})`}
+                </button>
+              )}
             </div>
           </div>
         )}
@@ -169,32 +392,32 @@ export const Sidebar: React.FC<SidebarProps> = ({
 
       <SidebarItem
         icon={<ICONS.VIEW_REPOS size={16} />}
-        label="Repositories"
+        label={t('nav.repos')}
         isActive={currentView === 'repos'}
         onClick={() => onViewChange('repos')}
-        ariaLabel="Navigate to Repositories"
+        ariaLabel={t('nav.goTo', { page: t('nav.repos') })}
       />
       <SidebarItem
         icon={<ICONS.VIEW_RULES size={16} />}
-        label="Rules"
+        label={t('nav.rules')}
         isActive={currentView === 'rules'}
         onClick={() => onViewChange('rules')}
-        ariaLabel="Navigate to Rules"
+        ariaLabel={t('nav.goTo', { page: t('nav.rules') })}
       />
       <SidebarItem
         icon={<Brain size={16} weight="fill" />}
-        label="Learning"
+        label={t('nav.learning')}
         isActive={currentView === 'learning'}
         onClick={() => onViewChange('learning')}
-        ariaLabel="Navigate to Learning"
+        ariaLabel={t('nav.goTo', { page: t('nav.learning') })}
       />
       <div className="flex-1" />
       <SidebarItem
         icon={<ICONS.VIEW_SETTINGS size={16} />}
-        label="Settings"
+        label={t('nav.settings')}
         isActive={currentView === 'settings'}
         onClick={() => onViewChange('settings')}
-        ariaLabel="Navigate to Settings"
+        ariaLabel={t('nav.goTo', { page: t('nav.settings') })}
       />
 
       <div className="h-2" />
@@ -228,10 +451,22 @@ export const Sidebar: React.FC<SidebarProps> = ({
         confirmLabel="Delete"
         confirmVariant="danger"
       />
+      <ConfirmationModal
+        isOpen={!!reviewToMerge}
+        onClose={() => setReviewToMerge(null)}
+        onConfirm={confirmMergeReview}
+        title="Merge Reviews"
+        message={`Move the runs, tasks and feedback of "${mergeTitle ?? 'this review'}" into "${primaryTitle ?? 'the current review'}"? Feedback on lines that already have feedback is dropped, and the merged review is deleted.`}
+        confirmLabel="Merge"
+        confirmVariant="brand"
+      />
     </aside>
   );
 };
 
+const isGenerating = (review: Review) =>
+  review.active_run_status === 'running' || review.active_run_status === 'queued';
+
 interface SidebarItemProps {
   icon: React.ReactNode;
   label: string;
@@ -4,17 +4,21 @@ import { QueryClient, QueryClientProvider } from '@tanstack/react-query';
 import { Sidebar } from '../Sidebar';
 import { useAppStore } from '../../../store';
 
-const { mockGetReviewRuns, mockDeleteReview, mockOpenUrl, mockUseTauri } = vi.hoisted(() => {
-  const mockGetReviewRuns = vi.fn().mockResolvedValue([]);
-  const mockDeleteReview = vi.fn().mockResolvedValue(undefined);
-  const mockOpenUrl = vi.fn().mockResolvedValue(undefined);
-  const mockUseTauri = vi.fn(() => ({
-    getReviewRuns: mockGetReviewRuns,
-    deleteReview: mockDeleteReview,
-    openUrl: mockOpenUrl,
-  }));
-  return { mockGetReviewRuns, mockDeleteReview, mockOpenUrl, mockUseTauri };
-});
+const { mockGetReviewRuns, mockDeleteReview, mockOpenUrl, mockStopGeneration, mockUseTauri } =
+  vi.hoisted(() => {
+    const mockGetReviewRuns = vi.fn().mockResolvedValue([]);
+    const mockDeleteReview = vi.fn().mockResolvedValue(undefined);
+    const mockOpenUrl = vi.fn().mockResolvedValue(undefined);
+    const mockStopGeneration = vi.fn().mockResolvedValue(undefined);
+    const mockUseTauri = vi.fn(() => ({
+      getReviewRuns: mockGetReviewRuns,
+      deleteReview: mockDeleteReview,
+      openUrl: mockOpenUrl,
+      stop_generation: mockStopGeneration,
+      getLanguage: vi.fn().mockResolvedValue(null),
+    }));
+    return { mockGetReviewRuns, mockDeleteReview, mockOpenUrl, mockStopGeneration, mockUseTauri };
+  });
 
 vi.mock('../../../hooks/useTauri', async () => {
   const actual =
@@ -25,24 +29,48 @@ vi.mock('../../../hooks/useTauri', async () => {
   };
 });
 
+const { mockReviews } = vi.hoisted(() => ({
+  mockReviews: [
+    {
+      id: 'review-1',
+      title: 'Review 1',
+      summary: null,
+      agent_id: null,
+      task_count: 0,
+      created_at: new Date().toISOString(),
+      source: { type: 'diff_paste', diff_hash: 'mock-hash' },
+      status: 'todo',
+      active_run_id: 'run-1',
+      active_run_status: 'running',
+      tags: ['backend'],
+    },
+    {
+      id: 'review-2',
+      title: 'Review 2',
+      summary: null,
+      agent_id: null,
+      task_count: 0,
+      created_at: new Date().toISOString(),
+      source: { type: 'diff_paste', diff_hash: 'other-hash' },
+      status: 'todo',
+      active_run_id: 'run-2',
+      active_run_status: null,
+      tags: ['frontend'],
+    },
+  ],
+}));
+
 vi.mock('../../../hooks/useReviews', () => ({
   useReviews: () => ({
-    data: [
-      {
-        id: 'review-1',
-        title: 'Review 1',
-        summary: null,
-        agent_id: null,
-        task_count: 0,
-        created_at: new Date().toISOString(),
-        source: { type: 'diff_paste', diff_hash: 'mock-hash' },
-        status: 'todo',
-        active_run_status: 'running',
-      },
-    ],
+    data: mockReviews,
     isLoading: false,
     invalidate: vi.fn(),
   }),
+  useReviewSearch: (query: string) => ({
+    data: query
+      ? mockReviews.filter(review => review.title.toLowerCase().includes(query.toLowerCase()))
+      : undefined,
+  }),
 }));
 
 const renderSidebar = () => {
@@ -72,6 +100,7 @@ describe('Sidebar', () => {
     mockGetReviewRuns.mockClear();
     mockDeleteReview.mockClear();
     mockOpenUrl.mockClear();
+    mockStopGeneration.mockClear();
     mockUseTauri.mockClear();
   });
 
@@ -87,4 +116,44 @@ describe('Sidebar', () => {
 
     expect(mockUseTauri).toHaveBeenCalledTimes(1);
   });
+
+  it('filters the review list by tag', async () => {
+    renderSidebar();
+
+    expect(await screen.findByLabelText('Review: Review 1')).toBeInTheDocument();
+    expect(screen.getByLabelText('Review: Review 2')).toBeInTheDocument();
+
+    fireEvent.click(screen.getByRole('button', { name: '#frontend' }));
+    expect(screen.queryByLabelText('Review: Review 1')).not.toBeInTheDocument();
+    expect(screen.getByLabelText('Review: Review 2')).toBeInTheDocument();
+
+    fireEvent.click(screen.getByRole('button', { name: '#frontend' }));
+    expect(screen.getByLabelText('Review: Review 1')).toBeInTheDocument();
+  });
+
+  it('shows only reviews matching the search query', async () => {
+    renderSidebar();
+
+    fireEvent.change(await screen.findByLabelText('Search reviews'), {
+      target: { value: 'review 2' },
+    });
+
+    await waitFor(() => {
+      expect(screen.queryByLabelText('Review: Review 1')).not.toBeInTheDocument();
+    });
+    expect(screen.getByLabelText('Review: Review 2')).toBeInTheDocument();
+  });
+
+  it('cancels a running generation from the review list', async () => {
+    renderSidebar();
+
+    expect(await screen.findByText('Generating…')).toBeInTheDocument();
+    expect(screen.queryByLabelText('Cancel generation: Review 2')).not.toBeInTheDocument();
+
+    fireEvent.click(screen.getByLabelText('Cancel generation: Review 1'));
+
+    await waitFor(() => {
+      expect(mockStopGeneration).toHaveBeenCalledWith('run-1');
+    });
+  });
 });
@@ -11,6 +11,7 @@ const { mockOpenUrl, mockUseTauri } = vi.hoisted(() => {
     getReviewRuns: vi.fn().mockResolvedValue([]),
     deleteReview: vi.fn().mockResolvedValue(undefined),
     openUrl: mockOpenUrl,
+    getLanguage: vi.fn().mockResolvedValue(null),
   }));
   return { mockOpenUrl, mockUseTauri };
 });
@@ -30,6 +31,7 @@ vi.mock('../../../hooks/useReviews', () => ({
     isLoading: false,
     invalidate: vi.fn(),
   }),
+  useReviewSearch: () => ({ data: undefined }),
 }));
 
 const mockOnUpdateClick = vi.fn();
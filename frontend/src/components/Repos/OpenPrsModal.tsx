@@ -0,0 +1,153 @@
+import React, { useEffect, useState } from 'react';
+import { GitPullRequest, X } from '@phosphor-icons/react';
+import { toast } from 'sonner';
+import { ICONS } from '../../constants/icons';
+import { useTauri } from '../../hooks/useTauri';
+import { useBatchReviewProgress } from '../../hooks/useBatchReviews';
+import type { Agent, BatchReviewStatus, OpenPullRequest } from '../../types';
+
+interface OpenPrsModalProps {
+  repo: { id: string; name: string; default_agent_id?: string | null };
+  agents: Pick<Agent, 'id' | 'name' | 'available'>[];
+  onClose: () => void;
+}
+
+const STATUS_LABELS: Record<BatchReviewStatus, string> = {
+  fetching: 'Fetching…',
+  generating: 'In review list',
+  done: 'Reviewed',
+  failed: 'Failed',
+};
+
+/** Lists a repo's open pull requests and starts a review for each one picked. */
+export const OpenPrsModal: React.FC<OpenPrsModalProps> = ({ repo, agents, onClose }) => {
+  const { listOpenPrs, generateReviewsForPrs } = useTauri();
+  const progress = useBatchReviewProgress(repo.id);
+  const [prs, setPrs] = useState<OpenPullRequest[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [selected, setSelected] = useState<Set<number>>(new Set());
+  const [agentId, setAgentId] = useState(
+    repo.default_agent_id ?? agents.find(agent => agent.available !== false)?.id ?? ''
+  );
+  const [isStarting, setIsStarting] = useState(false);
+
+  useEffect(() => {
+    listOpenPrs(repo.id)
+      .then(open => {
+        setPrs(open);
+        setSelected(new Set(open.filter(pr => !pr.draft).map(pr => pr.number)));
+      })
+      .catch(err => setError(String(err)));
+  }, [listOpenPrs, repo.id]);
+
+  const toggle = (number: number) => {
+    const next = new Set(selected);
+    if (next.has(number)) next.delete(number);
+    else next.add(number);
+    setSelected(next);
+  };
+
+  const handleStart = async () => {
+    setIsStarting(true);
+    try {
+      const started = await generateReviewsForPrs(repo.id, Array.from(selected), agentId);
+      toast(`Reviewing ${started.length} pull request${started.length === 1 ? '' : 's'}`, {
+        description: 'Each review shows up in the review list as it starts.',
+      });
+      setSelected(new Set());
+    } catch (err) {
+      toast.error('Failed to start reviews', { description: String(err) });
+    } finally {
+      setIsStarting(false);
+    }
+  };
+
+  return (
+    <div className="animate-in fade-in fixed inset-0 z-50 flex items-center justify-center bg-black/60 p-4 backdrop-blur-sm duration-200">
+      <div className="bg-bg-primary border-border/50 flex max-h-[80vh] w-full max-w-xl flex-col rounded-xl border shadow-2xl">
+        <div className="border-border/50 flex items-center justify-between border-b px-5 py-3">
+          <h3 className="text-text-primary flex items-center gap-2 text-sm font-medium">
+            <GitPullRequest size={16} className="text-brand" />
+            Open pull requests · {repo.name}
+          </h3>
+          <button
+            onClick={onClose}
+            className="text-text-tertiary hover:text-text-primary rounded p-1 transition-colors"
+            aria-label="Close"
+          >
+            <X size={14} />
+          </button>
+        </div>
+
+        <div className="custom-scrollbar flex-1 overflow-y-auto p-3">
+          {error ? (
+            <p className="px-2 py-4 text-xs text-red-400">{error}</p>
+          ) : prs === null ? (
+            <div className="text-text-tertiary flex items-center gap-2 px-2 py-4 text-xs">
+              <ICONS.ACTION_LOADING size={12} className="animate-spin" />
+              Loading pull requests…
+            </div>
+          ) : prs.length === 0 ? (
+            <p className="text-text-tertiary px-2 py-4 text-xs">No open pull requests</p>
+          ) : (
+            prs.map(pr => {
+              const status = progress[pr.number];
+              return (
+                <label
+                  key={pr.number}
+                  className="hover:bg-bg-secondary flex cursor-pointer items-center gap-3 rounded-md px-2 py-2 transition-colors select-none"
+                >
+                  <input
+                    type="checkbox"
+                    checked={selected.has(pr.number)}
+                    onChange={() => toggle(pr.number)}
+                    className="accent-brand"
+                  />
+                  <span className="text-text-tertiary font-mono text-[11px]">#{pr.number}</span>
+                  <span className="text-text-primary min-w-0 flex-1 truncate text-xs">{pr.title}</span>
+                  {pr.draft && <span className="text-text-disabled text-[10px]">Draft</span>}
+                  {pr.author && (
+                    <span className="text-text-tertiary max-w-[96px] truncate text-[10px]">
+                      {pr.author}
+                    </span>
+                  )}
+                  {status && (
+                    <span
+                      className={`shrink-0 text-[10px] ${status.status === 'failed' ? 'text-red-400' : 'text-blue-400/70'}`}
+                      title={status.error ?? undefined}
+                    >
+                      {STATUS_LABELS[status.status]}
+                    </span>
+                  )}
+                </label>
+              );
+            })
+          )}
+        </div>
+
+        <div className="border-border/50 flex items-center justify-between gap-3 border-t px-5 py-3">
+          <select
+            value={agentId}
+            onChange={e => setAgentId(e.target.value)}
+            className="bg-bg-tertiary border-border text-text-primary focus:border-brand rounded border px-2 py-1 text-xs focus:outline-none"
+            aria-label="Agent"
+          >
+            {agents.map(agent => (
+              <option key={agent.id} value={agent.id}>
+                {agent.name}
+              </option>
+            ))}
+          </select>
+          <button
+            onClick={handleStart}
+            disabled={isStarting || selected.size === 0 || !agentId}
+            className="bg-brand text-bg-primary hover:bg-brand/90 flex items-center gap-1.5 rounded-md px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+          >
+            {isStarting && <ICONS.ACTION_LOADING size={12} className="animate-spin" />}
+            Review {selected.size} PR{selected.size === 1 ? '' : 's'}
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+};
@@ -1,4 +1,4 @@
-import React, { useState, useEffect } from 'react';
+import React, { useState, useEffect, useMemo } from 'react';
 import {
   Folder,
   Plus,
@@ -6,10 +6,19 @@ import {
   ArrowSquareOut,
   Trash,
   Asterisk,
+  Eye,
+  GitPullRequest,
 } from '@phosphor-icons/react';
 import { toast } from 'sonner';
-import type { ViewType } from '../../types';
+import type { Agent, ViewType } from '../../types';
 import { useRepos } from '../../hooks/useRepos';
+import { useAgents } from '../../hooks/useAgents';
+import { useRules } from '../../hooks/useRules';
+import { useRepoWatchInterval } from '../../hooks/useSettings';
+import { OpenPrsModal } from './OpenPrsModal';
+
+const WATCH_INTERVAL_OPTIONS = [15, 30, 60, 300];
+const DEFAULT_WATCH_INTERVAL_SECS = 30;
 
 interface ReposViewProps {
   onNavigate: (view: ViewType) => void;
@@ -17,7 +26,29 @@ interface ReposViewProps {
 
 export const ReposView: React.FC<ReposViewProps> = ({ onNavigate }) => {
   const [searchQuery, setSearchQuery] = useState('');
-  const { data: repos = [], isLoading, addRepo, removeRepo, selectRepoFolder } = useRepos();
+  const [prRepo, setPrRepo] = useState<RepoCardProps['repo'] | null>(null);
+  const {
+    data: repos = [],
+    isLoading,
+    addRepo,
+    removeRepo,
+    setDefaults,
+    setWatched,
+    selectRepoFolder,
+  } = useRepos();
+  const { intervalSecs: watchInterval, setIntervalSecs: setWatchInterval } =
+    useRepoWatchInterval();
+  const anyWatched = repos.some(repo => repo.watched);
+  const { data: agents = [] } = useAgents();
+  const { data: rules = [] } = useRules();
+
+  const ruleCategories = useMemo(
+    () =>
+      Array.from(
+        new Set(rules.map(rule => rule.category).filter((c): c is string => !!c))
+      ).sort(),
+    [rules]
+  );
 
   useEffect(() => {
     if (addRepo.isError) {
@@ -61,18 +92,37 @@ export const ReposView: React.FC<ReposViewProps> = ({ onNavigate }) => {
 
       <div className="flex-1 overflow-y-auto p-8">
         <div className="mx-auto max-w-5xl space-y-6">
-          <div className="group relative max-w-md">
-            <MagnifyingGlass
-              size={14}
-              className="text-text-disabled group-focus-within:text-brand absolute top-1/2 left-3 -translate-y-1/2 transition-colors"
-            />
-            <input
-              type="text"
-              value={searchQuery}
-              onChange={e => setSearchQuery(e.target.value)}
-              placeholder="Search repositories..."
-              className="bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand focus:ring-brand/20 w-full rounded-md border py-2 pr-4 pl-9 text-xs shadow-sm transition-all focus:ring-1 focus:outline-none"
-            />
+          <div className="flex items-center gap-4">
+            <div className="group relative max-w-md flex-1">
+              <MagnifyingGlass
+                size={14}
+                className="text-text-disabled group-focus-within:text-brand absolute top-1/2 left-3 -translate-y-1/2 transition-colors"
+              />
+              <input
+                type="text"
+                value={searchQuery}
+                onChange={e => setSearchQuery(e.target.value)}
+                placeholder="Search repositories..."
+                className="bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand focus:ring-brand/20 w-full rounded-md border py-2 pr-4 pl-9 text-xs shadow-sm transition-all focus:ring-1 focus:outline-none"
+              />
+            </div>
+            {anyWatched && (
+              <label className="text-text-tertiary ml-auto flex items-center gap-2 text-[10px]">
+                <Eye size={12} />
+                Check watched repos every
+                <select
+                  value={watchInterval ?? DEFAULT_WATCH_INTERVAL_SECS}
+                  onChange={e => setWatchInterval(Number(e.target.value))}
+                  className="bg-bg-tertiary border-border text-text-primary focus:border-brand rounded border px-1.5 py-0.5 text-[10px] focus:outline-none"
+                >
+                  {WATCH_INTERVAL_OPTIONS.map(secs => (
+                    <option key={secs} value={secs}>
+                      {secs < 60 ? `${secs}s` : `${secs / 60}m`}
+                    </option>
+                  ))}
+                </select>
+              </label>
+            )}
           </div>
 
           {isLoading ? (
@@ -103,12 +153,20 @@ export const ReposView: React.FC<ReposViewProps> = ({ onNavigate }) => {
                   repo={repo}
                   onNavigate={onNavigate}
                   onRemove={removeRepo.mutate}
+                  agents={agents}
+                  ruleCategories={ruleCategories}
+                  onDefaultsChange={(agentId, categories) =>
+                    setDefaults.mutate({ repoId: repo.id, agentId, ruleCategories: categories })
+                  }
+                  onWatchChange={enabled => setWatched.mutate({ repoId: repo.id, enabled })}
+                  onReviewOpenPrs={() => setPrRepo(repo)}
                 />
               ))}
             </div>
           )}
         </div>
       </div>
+      {prRepo && <OpenPrsModal repo={prRepo} agents={agents} onClose={() => setPrRepo(null)} />}
     </div>
   );
 };
@@ -120,54 +178,149 @@ interface RepoCardProps {
     path: string;
     review_count: number;
     linked_at: string;
+    default_agent_id?: string | null;
+    default_rule_categories?: string[];
+    watched?: boolean;
   };
   onNavigate: (view: ViewType) => void;
   onRemove: (repoId: string) => void;
+  agents: Pick<Agent, 'id' | 'name'>[];
+  ruleCategories: string[];
+  onDefaultsChange: (agentId: string | null, ruleCategories: string[]) => void;
+  onWatchChange: (enabled: boolean) => void;
+  onReviewOpenPrs: () => void;
 }
 
-const RepoCard: React.FC<RepoCardProps> = ({ repo, onNavigate, onRemove }) => (
-  <div className="group bg-bg-secondary/40 hover:bg-bg-secondary hover:border-border relative rounded-lg border border-transparent p-4 transition-all">
-    <div className="mb-3 flex items-start justify-between">
-      <div className="bg-bg-tertiary text-brand rounded-md p-2">
-        <Folder size={20} weight="fill" />
-      </div>
-      <div className="flex items-center gap-1 opacity-0 transition-opacity group-hover:opacity-100">
-        <button
-          onClick={() => onNavigate('review')}
-          className="text-text-tertiary hover:text-brand hover:bg-bg-tertiary rounded-md p-1.5 transition-colors"
-          title="Review"
-        >
-          <ArrowSquareOut size={14} />
-        </button>
-        <button
-          onClick={() => onRemove(repo.id)}
-          className="text-text-tertiary hover:text-status-ignored hover:bg-bg-tertiary rounded-md p-1.5 transition-colors"
-          title="Remove"
-        >
-          <Trash size={14} />
-        </button>
+const RepoCard: React.FC<RepoCardProps> = ({
+  repo,
+  onNavigate,
+  onRemove,
+  agents,
+  ruleCategories,
+  onDefaultsChange,
+  onWatchChange,
+  onReviewOpenPrs,
+}) => {
+  const defaultAgentId = repo.default_agent_id ?? null;
+  const defaultCategories = repo.default_rule_categories ?? [];
+
+  const toggleCategory = (category: string) => {
+    const next = defaultCategories.includes(category)
+      ? defaultCategories.filter(c => c !== category)
+      : [...defaultCategories, category];
+    onDefaultsChange(defaultAgentId, next);
+  };
+
+  return (
+    <div className="group bg-bg-secondary/40 hover:bg-bg-secondary hover:border-border relative rounded-lg border border-transparent p-4 transition-all">
+      <div className="mb-3 flex items-start justify-between">
+        <div className="bg-bg-tertiary text-brand rounded-md p-2">
+          <Folder size={20} weight="fill" />
+        </div>
+        <div className="flex items-center gap-1 opacity-0 transition-opacity group-hover:opacity-100">
+          <button
+            onClick={() => onNavigate('review')}
+            className="text-text-tertiary hover:text-brand hover:bg-bg-tertiary rounded-md p-1.5 transition-colors"
+            title="Review"
+          >
+            <ArrowSquareOut size={14} />
+          </button>
+          <button
+            onClick={onReviewOpenPrs}
+            className="text-text-tertiary hover:text-brand hover:bg-bg-tertiary rounded-md p-1.5 transition-colors"
+            title="Review open pull requests"
+          >
+            <GitPullRequest size={14} />
+          </button>
+          <button
+            onClick={() => onRemove(repo.id)}
+            className="text-text-tertiary hover:text-status-ignored hover:bg-bg-tertiary rounded-md p-1.5 transition-colors"
+            title="Remove"
+          >
+            <Trash size={14} />
+          </button>
+        </div>
       </div>
-    </div>
 
-    <div>
-      <h3 className="text-text-primary group-hover:text-brand mb-1 truncate text-sm font-medium transition-colors">
-        {repo.name}
-      </h3>
-      <p className="text-text-tertiary bg-bg-primary/50 border-border/50 mb-3 w-fit max-w-full truncate rounded border px-1.5 py-0.5 font-mono text-[10px]">
-        {repo.path}
-      </p>
-
-      <div className="border-border/50 mt-2 flex w-full items-center gap-3 border-t pt-3">
-        <div className="flex items-center gap-1.5">
-          <span
-            className={`h-1.5 w-1.5 rounded-full ${repo.review_count > 0 ? 'bg-status-done' : 'bg-status-todo'}`}
-          />
-          <span className="text-text-secondary text-[10px]">{repo.review_count} reviews</span>
+      <div>
+        <h3 className="text-text-primary group-hover:text-brand mb-1 truncate text-sm font-medium transition-colors">
+          {repo.name}
+        </h3>
+        <p className="text-text-tertiary bg-bg-primary/50 border-border/50 mb-3 w-fit max-w-full truncate rounded border px-1.5 py-0.5 font-mono text-[10px]">
+          {repo.path}
+        </p>
+
+        <div className="border-border/50 mt-2 flex w-full items-center gap-3 border-t pt-3">
+          <div className="flex items-center gap-1.5">
+            <span
+              className={`h-1.5 w-1.5 rounded-full ${repo.review_count > 0 ? 'bg-status-done' : 'bg-status-todo'}`}
+            />
+            <span className="text-text-secondary text-[10px]">{repo.review_count} reviews</span>
+          </div>
+          <span className="text-text-disabled ml-auto text-[10px]">
+            Added {new Date(repo.linked_at).toLocaleDateString()}
+          </span>
+        </div>
+
+        <div className="border-border/50 mt-3 space-y-2 border-t pt-3">
+          <label className="text-text-disabled flex items-center justify-between gap-2 text-[10px] font-bold tracking-wider uppercase">
+            Watch for new commits
+            <input
+              type="checkbox"
+              checked={!!repo.watched}
+              onChange={e => onWatchChange(e.target.checked)}
+              className="accent-brand"
+            />
+          </label>
+
+          <label className="text-text-disabled flex items-center justify-between gap-2 text-[10px] font-bold tracking-wider uppercase">
+            Default agent
+            <select
+              value={defaultAgentId ?? ''}
+              onChange={e => onDefaultsChange(e.target.value || null, defaultCategories)}
+              className="bg-bg-tertiary border-border text-text-primary focus:border-brand rounded border px-1.5 py-0.5 text-[10px] font-normal tracking-normal normal-case focus:outline-none"
+            >
+              <option value="">None</option>
+              {agents.map(agent => (
+                <option key={agent.id} value={agent.id}>
+                  {agent.name}
+                </option>
+              ))}
+            </select>
+          </label>
+
+          {ruleCategories.length > 0 && (
+            <div className="space-y-1">
+              <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
+                Default rules
+              </span>
+              <div className="flex flex-wrap gap-1">
+                {ruleCategories.map(category => {
+                  const active = defaultCategories.includes(category);
+                  return (
+                    <button
+                      key={category}
+                      onClick={() => toggleCategory(category)}
+                      className={`rounded border px-1.5 py-0.5 text-[10px] transition-colors ${
+                        active
+                          ? 'border-brand/40 bg-brand/10 text-brand'
+                          : 'border-border text-text-tertiary hover:text-text-secondary'
+                      }`}
+                    >
+                      {category}
+                    </button>
+                  );
+                })}
+              </div>
+              <p className="text-text-disabled text-[10px]">
+                {defaultCategories.length > 0
+                  ? 'Only the selected categories of global rules apply to this repo.'
+                  : 'All enabled rules apply.'}
+              </p>
+            </div>
+          )}
         </div>
-        <span className="text-text-disabled ml-auto text-[10px]">
-          Added {new Date(repo.linked_at).toLocaleDateString()}
-        </span>
       </div>
     </div>
-  </div>
-);
+  );
+};
@@ -0,0 +1,142 @@
+import React, { useEffect, useMemo, useState } from 'react';
+import { useQuery } from '@tanstack/react-query';
+import { emitTo, listen } from '@tauri-apps/api/event';
+import { DiffViewer } from '../DiffViewer/DiffViewer';
+import { AddFeedbackModal } from './AddFeedbackModal';
+import { DiffSkeleton } from './DiffSkeleton';
+import { useReview } from '../../hooks/useReview';
+import { useTasks } from '../../hooks/useTasks';
+import { useParsedDiff } from '../../hooks/useParsedDiff';
+import { useFeedback } from '../../hooks/useFeedback';
+import { useTauri } from '../../hooks/useTauri';
+import {
+  DIFF_WINDOW_FEEDBACK_EVENT,
+  DIFF_WINDOW_READY_EVENT,
+  DIFF_WINDOW_SYNC_EVENT,
+  type DiffWindowSelection,
+} from '../../lib/diff-window';
+import type { DiffFile } from '../../types';
+
+interface LineContext {
+  file: string;
+  line: number;
+  side: 'old' | 'new';
+}
+
+const filePath = (file: DiffFile) => file.name || file.new_path;
+
+/**
+ * The full diff of the main window's review, in a window of its own. Follows
+ * the main window's task selection: the task's hunks are highlighted and its
+ * first file is opened.
+ */
+export const DiffWindow: React.FC = () => {
+  const { getRepoRootForReview } = useTauri();
+  const [selection, setSelection] = useState<DiffWindowSelection>({
+    reviewId: null,
+    taskId: null,
+  });
+  const [selectedFile, setSelectedFile] = useState<DiffFile | null>(null);
+  const [lineContext, setLineContext] = useState<LineContext | null>(null);
+
+  useEffect(() => {
+    const unlisten = listen<DiffWindowSelection>(DIFF_WINDOW_SYNC_EVENT, event =>
+      setSelection(event.payload)
+    );
+    unlisten.then(() => emitTo('main', DIFF_WINDOW_READY_EVENT)).catch(console.error);
+    return () => {
+      unlisten.then(fn => fn()).catch(console.error);
+    };
+  }, []);
+
+  const { reviewId, taskId } = selection;
+  const { runId } = useReview(reviewId);
+  const { data: tasks = [] } = useTasks(runId);
+  const { createFeedback, isCreating } = useFeedback(reviewId);
+  const { data: repoRoot } = useQuery<string | null>({
+    queryKey: ['repo-root', reviewId],
+    queryFn: () => (reviewId ? getRepoRootForReview(reviewId) : Promise.resolve(null)),
+    enabled: !!reviewId,
+    staleTime: Infinity,
+  });
+  const { data: parsedDiff } = useParsedDiff(runId, repoRoot);
+
+  const task = tasks.find(t => t.id === taskId);
+  const highlightedHunks = useMemo(
+    () =>
+      (task?.diff_refs ?? []).flatMap(ref =>
+        ref.hunks.map(h => ({
+          file: ref.file,
+          oldStart: h.old_start,
+          oldLines: h.old_lines,
+          newStart: h.new_start,
+          newLines: h.new_lines,
+        }))
+      ),
+    [task]
+  );
+
+  // Open the selected task's first file whenever the task changes.
+  useEffect(() => {
+    const files = parsedDiff?.files ?? [];
+    const first = task?.diff_refs[0]?.file;
+    setSelectedFile(files.find(file => filePath(file) === first) ?? files[0] ?? null);
+  }, [task, parsedDiff]);
+
+  const handleAdd = (
+    title: string,
+    impact: 'blocking' | 'nice_to_have' | 'nitpick',
+    content: string
+  ) => {
+    if (!reviewId || !lineContext) return;
+    createFeedback(
+      {
+        review_id: reviewId,
+        title,
+        content,
+        impact,
+        file_path: lineContext.file,
+        line_number: lineContext.line,
+        side: lineContext.side,
+      },
+      { onSuccess: () => void emitTo('main', DIFF_WINDOW_FEEDBACK_EVENT) }
+    );
+    setLineContext(null);
+  };
+
+  return (
+    <div className="bg-bg-primary flex h-screen flex-col text-white">
+      <div className="border-border text-text-secondary flex h-10 shrink-0 items-center border-b px-4 text-xs">
+        <span className="truncate">{task ? task.title : 'All changes'}</span>
+      </div>
+      <div className="relative flex-1 overflow-hidden">
+        {parsedDiff ? (
+          <DiffViewer
+            files={parsedDiff.files || []}
+            selectedFile={selectedFile}
+            onSelectFile={setSelectedFile}
+            highlightedHunks={highlightedHunks}
+            onAddFeedback={(file, line, side) =>
+              setLineContext({ file: filePath(file) || 'unknown', line, side })
+            }
+            repoRoot={repoRoot}
+            runId={runId}
+          />
+        ) : reviewId ? (
+          <DiffSkeleton />
+        ) : (
+          <div className="text-text-tertiary flex h-full items-center justify-center text-sm">
+            Open a review in the main window to show its diff here.
+          </div>
+        )}
+      </div>
+      <AddFeedbackModal
+        isOpen={lineContext !== null}
+        onClose={() => setLineContext(null)}
+        onAdd={handleAdd}
+        context={lineContext ? { type: 'line', ...lineContext } : { type: 'global' }}
+        isAdding={isCreating}
+      />
+    </div>
+  );
+};
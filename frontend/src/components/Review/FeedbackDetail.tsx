@@ -1,9 +1,25 @@
 import React, { useState, useEffect } from 'react';
-import type { Feedback, Comment, ReviewRule, DefaultIssueCategory } from '../../types';
+import type {
+  Feedback,
+  FeedbackAnchor,
+  Comment,
+  ReviewRule,
+  DefaultIssueCategory,
+  SuggestedReplacement,
+} from '../../types';
 import { ICONS } from '../../constants/icons';
 import { MarkdownRenderer } from '../ui/MarkdownRenderer';
 import { useQuery } from '@tanstack/react-query';
 import { useTauri } from '../../hooks/useTauri';
+import {
+  useExplainFeedback,
+  useFeedbackAttachments,
+  useToggleFeedbackPin,
+} from '../../hooks/useFeedback';
+import { useFeedbackContextLines, useUserIdentity } from '../../hooks/useSettings';
+import { authorName } from '../../lib/author';
+import { feedbackLocations, snippetForAnchor } from '../../lib/feedback-locations';
+import { useAppStore } from '../../store';
 
 import { Select } from '../Common/Select';
 
@@ -20,7 +36,12 @@ interface FeedbackDetailProps {
   isUpdatingImpact: boolean;
   isAddingComment: boolean;
   onPushToRemote?: () => void;
+  onCopyMarkdown?: () => void;
   remoteProviderName?: string | null;
+  /** Opens the rules view at the rule that produced this finding */
+  onJumpToRule?: (ruleId: string) => void;
+  /** Shows a file of the diff, for jumping to one of the finding's locations */
+  onOpenFile?: (path: string) => void;
 }
 
 
@@ -51,26 +72,68 @@ function formatTimestamp(isoString: string): string {
   return date.toLocaleString();
 }
 
+interface HighlightSpan {
+  text: string;
+  color: string | null;
+}
+
 interface DiffSnippetLine {
   line_number: number;
   content: string;
   prefix: string;
   is_addition: boolean;
   is_deletion: boolean;
+  spans?: HighlightSpan[];
 }
 
 interface DiffSnippet {
   file_path: string;
+  language?: string;
   hunk_header: string;
   lines: DiffSnippetLine[];
   highlighted_line: number | null;
 }
 
-const DiffSnippetViewer: React.FC<{ snippet: DiffSnippet }> = ({ snippet }) => {
+/** Matches the backend's cap on context lines around an anchor. */
+const MAX_CONTEXT_LINES = 20;
+
+interface DiffSnippetViewerProps {
+  snippet: DiffSnippet;
+  contextLines: number;
+  onChangeContextLines: (lines: number) => void;
+}
+
+const DiffSnippetViewer: React.FC<DiffSnippetViewerProps> = ({
+  snippet,
+  contextLines,
+  onChangeContextLines,
+}) => {
   return (
     <div className="bg-bg-tertiary border-border/50 overflow-hidden rounded border font-mono text-xs">
-      <div className="bg-bg-secondary/50 border-border/50 text-text-tertiary border-b px-2 py-1 text-[10px]">
-        {snippet.hunk_header}
+      <div className="bg-bg-secondary/50 border-border/50 text-text-tertiary flex items-center justify-between border-b px-2 py-1 text-[10px]">
+        <span>{snippet.hunk_header}</span>
+        <div className="flex items-center gap-2">
+          {snippet.language && <span className="text-text-disabled">{snippet.language}</span>}
+          <div className="flex items-center gap-1" title="Context lines">
+            <button
+              onClick={() => onChangeContextLines(contextLines - 1)}
+              disabled={contextLines <= 0}
+              className="hover:text-text-primary rounded p-0.5 transition-colors disabled:opacity-30"
+              aria-label="Show less context"
+            >
+              <ICONS.ICON_MINUS size={10} />
+            </button>
+            <span className="w-4 text-center">{contextLines}</span>
+            <button
+              onClick={() => onChangeContextLines(contextLines + 1)}
+              disabled={contextLines >= MAX_CONTEXT_LINES}
+              className="hover:text-text-primary rounded p-0.5 transition-colors disabled:opacity-30"
+              aria-label="Show more context"
+            >
+              <ICONS.ICON_PLUS size={10} />
+            </button>
+          </div>
+        </div>
       </div>
       <div className="max-h-48 space-y-0.5 overflow-y-auto p-2">
         {snippet.lines.map((line, idx) => {
@@ -97,7 +160,13 @@ const DiffSnippetViewer: React.FC<{ snippet: DiffSnippet }> = ({ snippet }) => {
                 {line.prefix}
               </span>
               <span className="text-text-primary flex-1 break-all whitespace-pre-wrap">
-                {line.content}
+                {line.spans && line.spans.length > 0
+                  ? line.spans.map((span, spanIdx) => (
+                      <span key={spanIdx} style={span.color ? { color: span.color } : undefined}>
+                        {span.text}
+                      </span>
+                    ))
+                  : line.content}
               </span>
             </div>
           );
@@ -107,6 +176,27 @@ const DiffSnippetViewer: React.FC<{ snippet: DiffSnippet }> = ({ snippet }) => {
   );
 };
 
+const SuggestedChangeViewer: React.FC<{ replacement: SuggestedReplacement }> = ({
+  replacement,
+}) => (
+  <div className="bg-bg-tertiary border-border/50 overflow-hidden rounded border font-mono text-xs">
+    <div className="max-h-48 space-y-0.5 overflow-y-auto p-2">
+      {replacement.old_lines.map((line, idx) => (
+        <div key={`old-${idx}`} className="flex gap-1">
+          <span className="text-status-ignored select-none">-</span>
+          <span className="text-text-primary flex-1 break-all whitespace-pre-wrap">{line}</span>
+        </div>
+      ))}
+      {replacement.new_lines.map((line, idx) => (
+        <div key={`new-${idx}`} className="flex gap-1">
+          <span className="text-status-added select-none">+</span>
+          <span className="text-text-primary flex-1 break-all whitespace-pre-wrap">{line}</span>
+        </div>
+      ))}
+    </div>
+  </div>
+);
+
 const DiffSnippetSkeleton: React.FC = () => (
   <div className="bg-bg-secondary/50 border-border/50 rounded-md border p-3">
     <div className="bg-bg-tertiary mb-2 h-4 animate-pulse rounded" />
@@ -114,6 +204,112 @@ const DiffSnippetSkeleton: React.FC = () => (
   </div>
 );
 
+interface RationaleProps {
+  rationale: string | null | undefined;
+  isExplaining: boolean;
+  onExplain: () => void;
+}
+
+/** Why the finding was flagged, folded away; fetched from the agent when it gave none. */
+const Rationale: React.FC<RationaleProps> = ({ rationale, isExplaining, onExplain }) => {
+  const [isOpen, setIsOpen] = useState(false);
+
+  if (!rationale) {
+    return (
+      <button
+        onClick={() => {
+          setIsOpen(true);
+          onExplain();
+        }}
+        disabled={isExplaining}
+        className="text-text-tertiary hover:text-text-primary flex items-center gap-1.5 text-[10px] transition-colors disabled:opacity-50"
+        title="Ask the agent why it flagged this"
+      >
+        <ICONS.LIGHTBULB size={12} />
+        <span>{isExplaining ? 'Explaining...' : 'Explain this finding'}</span>
+      </button>
+    );
+  }
+
+  return (
+    <div className="bg-bg-secondary/50 border-border/50 rounded-md border">
+      <button
+        onClick={() => setIsOpen(open => !open)}
+        className="text-text-tertiary hover:text-text-primary flex w-full items-center gap-1.5 px-3 py-2 text-[10px] transition-colors"
+        aria-expanded={isOpen}
+      >
+        {isOpen ? <ICONS.CHEVRON_DOWN size={12} /> : <ICONS.CHEVRON_RIGHT size={12} />}
+        <span>Why?</span>
+      </button>
+      {isOpen && (
+        <MarkdownRenderer className="prose prose-invert prose-sm text-text-secondary max-w-none px-3 pb-3">
+          {rationale}
+        </MarkdownRenderer>
+      )}
+    </div>
+  );
+};
+
+const locationId = (index: number) => `feedback-location-${index}`;
+
+interface LocationSnippetProps {
+  index: number;
+  anchor: FeedbackAnchor;
+  snippet: DiffSnippet | null;
+  isLoading: boolean;
+  contextLines: number;
+  onChangeContextLines: (lines: number) => void;
+  onOpenFile?: (path: string) => void;
+}
+
+const LocationSnippet: React.FC<LocationSnippetProps> = ({
+  index,
+  anchor,
+  snippet,
+  isLoading,
+  contextLines,
+  onChangeContextLines,
+  onOpenFile,
+}) => (
+  <div id={locationId(index)} className="bg-bg-secondary/50 border-border/50 rounded-md border p-3">
+    <div className="mb-2 flex items-center gap-2">
+      <ICONS.TAB_CHANGES size={12} className="text-text-tertiary" />
+      <span className="text-text-tertiary font-mono text-[10px]">
+        {anchor.file_path}:{anchor.line_number}
+      </span>
+      <span className="text-text-disabled text-[10px]">
+        ({anchor.side === 'old' ? 'old' : 'new'})
+      </span>
+      {anchor.outdated && (
+        <span className="bg-bg-tertiary text-text-tertiary rounded px-1.5 py-0.5 text-[10px]">
+          Outdated: the line is no longer in the current diff
+        </span>
+      )}
+      {onOpenFile && (
+        <button
+          onClick={() => onOpenFile(anchor.file_path ?? '')}
+          className="text-brand ml-auto text-[10px] hover:underline"
+        >
+          Open in diff
+        </button>
+      )}
+    </div>
+    {isLoading ? (
+      <DiffSnippetSkeleton />
+    ) : snippet ? (
+      <DiffSnippetViewer
+        snippet={snippet}
+        contextLines={contextLines}
+        onChangeContextLines={onChangeContextLines}
+      />
+    ) : (
+      <div className="bg-bg-tertiary text-text-disabled flex h-20 items-center justify-center rounded text-xs">
+        Unable to load diff snippet
+      </div>
+    )}
+  </div>
+);
+
 export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
   feedback,
   rulesById,
@@ -127,25 +323,46 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
   isUpdatingImpact,
   isAddingComment,
   onPushToRemote,
+  onCopyMarkdown,
   remoteProviderName,
+  onJumpToRule,
+  onOpenFile,
 }) => {
-  const { getFeedbackDiffSnippet } = useTauri();
+  const { getFeedbackDiffSnippet, getFeedbackRule } = useTauri();
+  const defaultAgentId = useAppStore(state => state.agentId);
+  const explain = useExplainFeedback(feedback?.review_id ?? null);
+  const togglePin = useToggleFeedbackPin(feedback?.review_id ?? null);
+  const { attachments, addAttachment, pasteFromClipboard, deleteAttachment, isAttaching } =
+    useFeedbackAttachments(feedback?.id ?? null);
   const [replyText, setReplyText] = useState('');
   const [isTitleEditing, setIsTitleEditing] = useState(false);
   const [titleValue, setTitleValue] = useState('');
-  const rule = feedback?.rule_id ? rulesById[feedback.rule_id] : null;
+  const { data: triggeringRule } = useQuery<ReviewRule | null>({
+    queryKey: ['feedback-rule', feedback?.id, feedback?.rule_id],
+    queryFn: () => (feedback?.id ? getFeedbackRule(feedback.id) : Promise.resolve(null)),
+    enabled: !!feedback?.rule_id,
+  });
+  const rule =
+    triggeringRule ?? (feedback?.rule_id ? (rulesById[feedback.rule_id] ?? null) : null);
   const category = feedback?.category ? categoriesById[feedback.category] : null;
   const remoteLabel = remoteProviderName ?? 'remote';
   const RemoteIcon = remoteProviderName === 'GitLab' ? ICONS.ICON_GITLAB : ICONS.ICON_GITHUB;
 
-  const { data: diffSnippet, isLoading: isDiffLoading } = useQuery<DiffSnippet | null>({
-    queryKey: ['feedback-diff', feedback?.id],
+  const { contextLines, setContextLines } = useFeedbackContextLines();
+  const { identity } = useUserIdentity();
+  const { data: diffSnippets = [], isLoading: isDiffLoading } = useQuery<DiffSnippet[]>({
+    queryKey: ['feedback-diff', feedback?.id, contextLines],
     queryFn: () => {
-      if (!feedback?.id) return Promise.resolve(null);
-      return getFeedbackDiffSnippet(feedback.id, 3);
+      if (!feedback?.id) return Promise.resolve([]);
+      return getFeedbackDiffSnippet(feedback.id, contextLines);
     },
     enabled: !!feedback?.id && !!feedback.anchor?.file_path,
+    // Keep the current snippet on screen while more context loads.
+    placeholderData: (previous, previousQuery) =>
+      previousQuery?.queryKey[1] === feedback?.id ? previous : undefined,
   });
+  const changeContextLines = (lines: number) =>
+    setContextLines(Math.min(Math.max(lines, 0), MAX_CONTEXT_LINES));
 
   useEffect(() => {
     if (feedback) {
@@ -169,6 +386,15 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
     );
   }
 
+  const locations = feedbackLocations(feedback);
+
+  // The agent that raised the finding explains it; user and issue-check
+  // feedback goes to the selected agent.
+  const handleExplain = () => {
+    const authorAgent = feedback.author.startsWith('agent:') ? feedback.author.slice(6) : null;
+    explain.mutate({ feedbackId: feedback.id, agentId: authorAgent || defaultAgentId });
+  };
+
   const handleTitleEdit = () => {
     setTitleValue(feedback.title);
     setIsTitleEditing(true);
@@ -179,6 +405,15 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
     setIsTitleEditing(false);
   };
 
+  const handlePaste = (e: React.ClipboardEvent<HTMLTextAreaElement>) => {
+    const images = Array.from(e.clipboardData.files).filter(file =>
+      file.type.startsWith('image/')
+    );
+    if (images.length === 0) return;
+    e.preventDefault();
+    images.forEach(image => addAttachment(image));
+  };
+
   const handleAddComment = () => {
     if (replyText.trim()) {
       onAddComment(replyText.trim());
@@ -209,6 +444,28 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
             </h2>
           )}
           <div className="ml-2 flex items-center gap-1">
+            <button
+              onClick={() => togglePin.mutate(feedback.id)}
+              disabled={togglePin.isPending}
+              className={`rounded p-1.5 transition-colors hover:bg-white/5 disabled:opacity-50 ${
+                feedback.pinned ? 'text-brand' : 'text-text-tertiary hover:text-text-primary'
+              }`}
+              title={
+                feedback.pinned ? 'Unpin from the top of the list' : 'Pin to the top of the list'
+              }
+              aria-pressed={!!feedback.pinned}
+            >
+              <ICONS.ACTION_PIN size={14} weight={feedback.pinned ? 'fill' : 'regular'} />
+            </button>
+            {onCopyMarkdown && (
+              <button
+                onClick={onCopyMarkdown}
+                className="text-text-tertiary hover:text-text-primary rounded p-1.5 transition-colors hover:bg-white/5"
+                title="Copy as Markdown"
+              >
+                <ICONS.ACTION_COPY size={14} />
+              </button>
+            )}
             {remoteProviderName && onPushToRemote && feedback.status !== 'ignored' && (
               <button
                 onClick={onPushToRemote}
@@ -257,14 +514,27 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
             {feedback.anchor?.file_path ? (
               <span className="text-text-tertiary font-mono text-[10px]">
                 {feedback.anchor.file_path}:{feedback.anchor.line_number}
+                {locations.length > 1 && ` +${locations.length - 1} more`}
               </span>
             ) : (
               <span className="text-text-tertiary font-mono text-[10px]">General</span>
             )}
-            {feedback.rule_id && (
-              <span className="text-text-tertiary text-[10px]">
-                Rule: {rule?.text || feedback.rule_id}
-              </span>
+            {feedback.rule_id ? (
+              <div className="flex items-center gap-1.5 text-[10px]">
+                <span className="text-text-tertiary line-clamp-2">
+                  Triggered by rule: {rule?.text || feedback.rule_id}
+                </span>
+                {rule && onJumpToRule && (
+                  <button
+                    onClick={() => onJumpToRule(rule.id)}
+                    className="text-brand flex-shrink-0 hover:underline"
+                  >
+                    View rule
+                  </button>
+                )}
+              </div>
+            ) : (
+              <span className="text-text-tertiary block text-[10px]">Triggered by: General</span>
             )}
             {!feedback.rule_id && feedback.category && (
               <span className="text-text-tertiary text-[10px]">
@@ -284,26 +554,56 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
       </div>
 
       <div className="custom-scrollbar flex-1 space-y-4 overflow-y-auto p-4">
-        {feedback.anchor && feedback.anchor.file_path && (
+        <Rationale
+          key={feedback.id}
+          rationale={feedback.rationale}
+          isExplaining={explain.isPending}
+          onExplain={handleExplain}
+        />
+
+        {locations.length > 1 && (
+          <div className="space-y-1">
+            <h3 className="text-text-secondary text-xs font-medium">
+              {locations.length} locations
+            </h3>
+            <div className="flex flex-wrap gap-1.5">
+              {locations.map((anchor, idx) => (
+                <button
+                  key={idx}
+                  onClick={() =>
+                    document
+                      .getElementById(locationId(idx))
+                      ?.scrollIntoView({ behavior: 'smooth', block: 'nearest' })
+                  }
+                  className="bg-bg-tertiary text-text-secondary hover:text-text-primary rounded px-2 py-0.5 font-mono text-[10px] transition-colors"
+                >
+                  {anchor.file_path}:{anchor.line_number}
+                </button>
+              ))}
+            </div>
+          </div>
+        )}
+
+        {locations.map((anchor, idx) => (
+          <LocationSnippet
+            key={idx}
+            index={idx}
+            anchor={anchor}
+            snippet={snippetForAnchor(diffSnippets, anchor)}
+            isLoading={isDiffLoading}
+            contextLines={contextLines}
+            onChangeContextLines={changeContextLines}
+            onOpenFile={onOpenFile}
+          />
+        ))}
+
+        {feedback.suggested_replacement && (
           <div className="bg-bg-secondary/50 border-border/50 rounded-md border p-3">
             <div className="mb-2 flex items-center gap-2">
-              <ICONS.TAB_CHANGES size={12} className="text-text-tertiary" />
-              <span className="text-text-tertiary font-mono text-[10px]">
-                {feedback.anchor.file_path}:{feedback.anchor.line_number}
-              </span>
-              <span className="text-text-disabled text-[10px]">
-                ({feedback.anchor.side === 'old' ? 'old' : 'new'})
-              </span>
+              <ICONS.LIGHTBULB size={12} className="text-text-tertiary" />
+              <span className="text-text-tertiary text-[10px]">Suggested change</span>
             </div>
-            {isDiffLoading ? (
-              <DiffSnippetSkeleton />
-            ) : diffSnippet ? (
-              <DiffSnippetViewer snippet={diffSnippet} />
-            ) : (
-              <div className="bg-bg-tertiary text-text-disabled flex h-20 items-center justify-center rounded text-xs">
-                Unable to load diff snippet
-              </div>
-            )}
+            <SuggestedChangeViewer replacement={feedback.suggested_replacement} />
           </div>
         )}
 
@@ -317,12 +617,14 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
               <div key={comment.id} className="flex gap-3">
                 <div className="bg-brand/20 flex h-6 w-6 flex-shrink-0 items-center justify-center rounded-full">
                   <span className="text-brand text-[10px] font-medium">
-                    {comment.author.charAt(0).toUpperCase()}
+                    {authorName(comment.author).charAt(0).toUpperCase()}
                   </span>
                 </div>
                 <div className="min-w-0 flex-1">
                   <div className="mb-1 flex items-center gap-2">
-                    <span className="text-text-primary text-xs font-medium">{comment.author}</span>
+                    <span className="text-text-primary text-xs font-medium" title={comment.author}>
+                      {authorName(comment.author)}
+                    </span>
                     <span className="text-text-tertiary text-[10px]">
                       {formatTimestamp(comment.created_at)}
                     </span>
@@ -334,6 +636,32 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
               </div>
             ))
           )}
+
+          {attachments.length > 0 && (
+            <div className="flex flex-wrap gap-2">
+              {attachments.map((attachment, idx) => (
+                <div
+                  key={attachment.id}
+                  className="group border-border/50 relative overflow-hidden rounded border"
+                >
+                  <a href={attachment.data_url} target="_blank" rel="noreferrer">
+                    <img
+                      src={attachment.data_url}
+                      alt={`Attachment ${idx + 1}`}
+                      className="h-20 w-28 object-cover"
+                    />
+                  </a>
+                  <button
+                    onClick={() => deleteAttachment(attachment.id)}
+                    className="bg-bg-primary/80 text-text-tertiary hover:text-status-ignored absolute top-1 right-1 rounded p-0.5 opacity-0 transition-opacity group-hover:opacity-100"
+                    title="Remove image"
+                  >
+                    <ICONS.ACTION_CLOSE size={10} />
+                  </button>
+                </div>
+              ))}
+            </div>
+          )}
         </div>
       </div>
 
@@ -342,12 +670,13 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
           <textarea
             value={replyText}
             onChange={e => setReplyText(e.target.value)}
+            onPaste={handlePaste}
             onKeyDown={e => {
               if (e.key === 'Enter' && (e.metaKey || e.ctrlKey)) {
                 handleAddComment();
               }
             }}
-            placeholder="Write a reply..."
+            placeholder={`${identity ? `Reply as ${identity.name}` : 'Write a reply'}... (paste a screenshot to attach it)`}
             className="text-text-primary placeholder:text-text-disabled w-full resize-none bg-transparent px-3 py-2.5 text-xs focus:outline-none"
             rows={Math.max(1, Math.min(5, replyText.split('\n').length))}
             style={{ minHeight: '36px' }}
@@ -357,13 +686,23 @@ export const FeedbackDetail: React.FC<FeedbackDetailProps> = ({
             <span className="text-text-disabled px-1 text-[10px] opacity-0 transition-opacity group-focus-within:opacity-100 focus-within:opacity-100">
               {replyText.length > 0 ? '⌘ + Enter to send' : ''}
             </span>
-            <button
-              onClick={handleAddComment}
-              disabled={!replyText.trim() || isAddingComment}
-              className="bg-brand text-brand-fg hover:bg-brand/90 rounded px-2.5 py-1 text-[10px] font-medium transition-all disabled:opacity-50"
-            >
-              {isAddingComment ? 'Sending...' : 'Reply'}
-            </button>
+            <div className="flex items-center gap-1">
+              <button
+                onClick={() => pasteFromClipboard()}
+                disabled={isAttaching}
+                className="text-text-tertiary hover:text-text-primary rounded p-1 transition-colors hover:bg-white/5 disabled:opacity-50"
+                title="Attach image from clipboard"
+              >
+                <ICONS.ACTION_ATTACH_IMAGE size={14} />
+              </button>
+              <button
+                onClick={handleAddComment}
+                disabled={!replyText.trim() || isAddingComment}
+                className="bg-brand text-brand-fg hover:bg-brand/90 rounded px-2.5 py-1 text-[10px] font-medium transition-all disabled:opacity-50"
+              >
+                {isAddingComment ? 'Sending...' : 'Reply'}
+              </button>
+            </div>
           </div>
         </div>
       </div>
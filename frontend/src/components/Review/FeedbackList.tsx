@@ -1,8 +1,11 @@
-import React from 'react';
+import React, { useState } from 'react';
 import type { Feedback, ReviewRule, DefaultIssueCategory } from '../../types';
 import { ICONS } from '../../constants/icons';
 import { RulePopover } from './RulePopover';
 import { CategoryBadge } from './CategoryBadge';
+import { Select } from '../Common/Select';
+import { compareFeedback } from '../../lib/feedback-order';
+import { AUTHOR_KIND_LABELS, authorKind, type AuthorKind } from '../../lib/author';
 
 interface FeedbackListProps {
   feedbacks: Feedback[];
@@ -41,6 +44,17 @@ const IMPACT_CONFIG = {
   nitpick: { icon: ICONS.IMPACT_NITPICK, color: 'text-impact-nitpick' },
 };
 
+const isPinnedOpen = (feedback: Feedback) =>
+  !!feedback.pinned && feedback.status !== 'done' && feedback.status !== 'ignored';
+
+const ALL_CATEGORIES = 'all';
+const ALL_AUTHORS = 'all';
+
+const AUTHOR_BADGE_STYLES: Record<AuthorKind, string> = {
+  user: 'bg-brand/10 text-brand',
+  agent: 'bg-bg-tertiary text-text-tertiary',
+  imported: 'bg-blue-500/10 text-blue-400',
+};
 
 export const FeedbackList: React.FC<FeedbackListProps> = ({
   feedbacks,
@@ -51,6 +65,9 @@ export const FeedbackList: React.FC<FeedbackListProps> = ({
   isLoading = false,
   confidenceThreshold = null,
 }) => {
+  const [categoryFilter, setCategoryFilter] = useState(ALL_CATEGORIES);
+  const [authorFilter, setAuthorFilter] = useState<string>(ALL_AUTHORS);
+
   if (isLoading) {
     return (
       <div className="custom-scrollbar flex-1 overflow-y-auto">
@@ -67,8 +84,11 @@ export const FeedbackList: React.FC<FeedbackListProps> = ({
       ? feedbacks.filter(f => f.confidence >= confidenceThreshold)
       : feedbacks;
   const hiddenCount = feedbacks.length - filteredFeedbacks.length;
+  // Open pinned feedback stays on top whatever the filters; once resolved it
+  // drops back into the list with the other closed items.
+  const pinnedFeedbacks = feedbacks.filter(isPinnedOpen).sort(compareFeedback);
 
-  if (filteredFeedbacks.length === 0) {
+  if (filteredFeedbacks.length === 0 && pinnedFeedbacks.length === 0) {
     return (
       <div className="custom-scrollbar flex-1 overflow-y-auto">
         <div className="p-4 text-center">
@@ -84,59 +104,129 @@ export const FeedbackList: React.FC<FeedbackListProps> = ({
     );
   }
 
-  const sortedFeedbacks = [...filteredFeedbacks].sort((a, b) => {
-    const statusRank = { todo: 0, in_progress: 1, done: 2, ignored: 3 };
-    const rankA = statusRank[a.status] ?? 0;
-    const rankB = statusRank[b.status] ?? 0;
-    if (rankA !== rankB) return rankA - rankB;
-    return new Date(b.updated_at).getTime() - new Date(a.updated_at).getTime();
-  });
+  const categoryIds = [
+    ...new Set(filteredFeedbacks.flatMap(f => (f.category ? [f.category] : []))),
+  ].sort();
+  const activeCategory = categoryIds.includes(categoryFilter) ? categoryFilter : ALL_CATEGORIES;
+  const categoryOptions = [
+    { value: ALL_CATEGORIES, label: 'All categories' },
+    ...categoryIds.map(id => ({ value: id, label: categoriesById[id]?.name ?? id })),
+  ];
+  const authorKinds = (['user', 'agent', 'imported'] as const).filter(kind =>
+    filteredFeedbacks.some(f => authorKind(f.author) === kind)
+  );
+  // Only worth filtering (and badging) when both people and agents wrote some.
+  const mixedAuthors = authorKinds.length > 1;
+  const activeAuthor =
+    mixedAuthors && authorKinds.some(kind => kind === authorFilter) ? authorFilter : ALL_AUTHORS;
+  const authorOptions = [
+    { value: ALL_AUTHORS, label: 'All authors' },
+    ...authorKinds.map(kind => ({ value: kind, label: AUTHOR_KIND_LABELS[kind] })),
+  ];
+  const visibleFeedbacks = filteredFeedbacks.filter(
+    f =>
+      (activeCategory === ALL_CATEGORIES || f.category === activeCategory) &&
+      (activeAuthor === ALL_AUTHORS || authorKind(f.author) === activeAuthor)
+  );
+
+  const sortedFeedbacks = visibleFeedbacks.filter(f => !isPinnedOpen(f)).sort(compareFeedback);
 
-  return (
-    <div className="custom-scrollbar flex-1 overflow-y-auto">
-      {sortedFeedbacks.map(feedback => {
-        const isActive = selectedFeedbackId === feedback.id;
-        const impact =
-          IMPACT_CONFIG[feedback.impact as keyof typeof IMPACT_CONFIG] || IMPACT_CONFIG.nitpick;
-        const rule =
-          feedback.rule_id && rulesById[feedback.rule_id] ? rulesById[feedback.rule_id] : null;
-        const category =
-          feedback.category && categoriesById[feedback.category]
-            ? categoriesById[feedback.category]
-            : null;
-        const hasRuleBadge = feedback.rule_id;
-        const hasCategoryBadge = !hasRuleBadge && feedback.category;
+  const renderFeedback = (feedback: Feedback) => {
+    const isActive = selectedFeedbackId === feedback.id;
+    const impact =
+      IMPACT_CONFIG[feedback.impact as keyof typeof IMPACT_CONFIG] || IMPACT_CONFIG.nitpick;
+    const rule =
+      feedback.rule_id && rulesById[feedback.rule_id] ? rulesById[feedback.rule_id] : null;
+    const category =
+      feedback.category && categoriesById[feedback.category]
+        ? categoriesById[feedback.category]
+        : null;
+    const hasRuleBadge = feedback.rule_id;
+    const hasCategoryBadge = !hasRuleBadge && feedback.category;
+    const kind = authorKind(feedback.author);
 
-        return (
-          <button
-            key={feedback.id}
-            onClick={() => onSelectFeedback(feedback.id)}
-            className={`group border-border/50 hover:bg-bg-secondary/80 relative w-full border-b px-4 py-3 text-left transition-all ${
-              isActive ? 'bg-bg-secondary shadow-inner' : ''
-            }`}
+    return (
+      <button
+        key={feedback.id}
+        onClick={() => onSelectFeedback(feedback.id)}
+        className={`group border-border/50 hover:bg-bg-secondary/80 relative w-full border-b px-4 py-3 text-left transition-all ${
+          isActive ? 'bg-bg-secondary shadow-inner' : ''
+        }`}
+      >
+        {isActive && <div className="bg-brand absolute top-0 bottom-0 left-0 w-[2px]" />}
+        <div className="flex w-full min-w-0 items-center gap-2.5">
+          <div className="flex-shrink-0">
+            <impact.icon size={14} className={impact.color} />
+          </div>
+          <h3
+            className={`flex-1 truncate text-xs leading-relaxed font-medium ${
+              isActive ? 'text-text-primary' : 'text-text-secondary group-hover:text-text-primary'
+            } ${feedback.status === 'done' || feedback.status === 'ignored' ? 'text-text-disabled line-through opacity-50' : ''}`}
           >
-            {isActive && <div className="bg-brand absolute top-0 bottom-0 left-0 w-[2px]" />}
-            <div className="flex w-full min-w-0 items-center gap-2.5">
-              <div className="flex-shrink-0">
-                <impact.icon size={14} className={impact.color} />
-              </div>
-              <h3
-                className={`flex-1 truncate text-xs leading-relaxed font-medium ${
-                  isActive
-                    ? 'text-text-primary'
-                    : 'text-text-secondary group-hover:text-text-primary'
-                } ${feedback.status === 'done' || feedback.status === 'ignored' ? 'text-text-disabled line-through opacity-50' : ''}`}
-              >
-                {feedback.title || 'Untitled Feedback'}
-              </h3>
-              {hasRuleBadge && <RulePopover rule={rule} ruleId={feedback.rule_id || ''} />}
-              {hasCategoryBadge && (
-                <CategoryBadge category={category} categoryId={feedback.category || ''} />
-              )}
-            </div>
-          </button>
-        );
-      })}
+            {feedback.title || 'Untitled Feedback'}
+          </h3>
+          {feedback.pinned && (
+            <ICONS.ACTION_PIN
+              size={11}
+              weight="fill"
+              className="text-brand flex-shrink-0"
+              aria-label="Pinned"
+            />
+          )}
+          {mixedAuthors && (
+            <span
+              className={`flex-shrink-0 rounded px-1 py-0.5 text-[9px] font-medium ${AUTHOR_BADGE_STYLES[kind]}`}
+              title={feedback.author}
+            >
+              {AUTHOR_KIND_LABELS[kind]}
+            </span>
+          )}
+          {(feedback.extra_anchors?.length ?? 0) > 0 && (
+            <span
+              className="text-text-disabled flex-shrink-0 text-[10px]"
+              title="This finding applies to several places"
+            >
+              {(feedback.extra_anchors?.length ?? 0) + 1} places
+            </span>
+          )}
+          {feedback.anchor?.outdated && (
+            <span
+              className="text-text-disabled flex-shrink-0 text-[10px]"
+              title="The commented line is no longer in the diff"
+            >
+              Outdated
+            </span>
+          )}
+          {hasRuleBadge && <RulePopover rule={rule} ruleId={feedback.rule_id || ''} />}
+          {hasCategoryBadge && (
+            <CategoryBadge category={category} categoryId={feedback.category || ''} />
+          )}
+        </div>
+      </button>
+    );
+  };
+
+  return (
+    <div className="custom-scrollbar flex-1 overflow-y-auto">
+      {(categoryIds.length > 0 || mixedAuthors) && (
+        <div className="border-border/50 flex gap-2 border-b px-3 py-2">
+          {categoryIds.length > 0 && (
+            <Select value={activeCategory} onChange={setCategoryFilter} options={categoryOptions} />
+          )}
+          {mixedAuthors && (
+            <Select value={activeAuthor} onChange={setAuthorFilter} options={authorOptions} />
+          )}
+        </div>
+      )}
+      {pinnedFeedbacks.length > 0 && (
+        <div className="bg-bg-primary border-border sticky top-0 z-10 border-b shadow-sm">
+          <div className="text-text-disabled flex items-center gap-1.5 px-4 pt-2 pb-1 text-[10px] font-bold tracking-wider uppercase">
+            <ICONS.ACTION_PIN size={10} weight="fill" /> Pinned
+          </div>
+          {pinnedFeedbacks.map(renderFeedback)}
+        </div>
+      )}
+      {sortedFeedbacks.map(renderFeedback)}
       {hiddenCount > 0 && (
         <div className="text-text-tertiary px-4 py-2 text-center text-[10px]">
           {hiddenCount} low-confidence item{hiddenCount > 1 ? 's' : ''} hidden
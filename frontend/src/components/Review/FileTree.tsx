@@ -0,0 +1,110 @@
+import React, { useMemo, useState } from 'react';
+import type { FileReviewStatus } from '../../types';
+import { ICONS } from '../../constants/icons';
+import { buildFileTree, type FileTreeNode } from '../../lib/file-tree';
+
+interface FileTreeProps {
+  files: FileReviewStatus[];
+  selectedFile: string | null;
+  onSelectFile: (path: string) => void;
+  isLoading: boolean;
+}
+
+export const FileTree: React.FC<FileTreeProps> = ({
+  files,
+  selectedFile,
+  onSelectFile,
+  isLoading,
+}) => {
+  const tree = useMemo(() => buildFileTree(files), [files]);
+  const [collapsed, setCollapsed] = useState<Set<string>>(new Set());
+
+  if (isLoading) {
+    return <div className="text-text-disabled p-8 text-center text-xs opacity-50">Loading…</div>;
+  }
+
+  if (tree.length === 0) {
+    return (
+      <div className="text-text-disabled p-8 text-center text-xs opacity-50">No files changed</div>
+    );
+  }
+
+  const toggle = (path: string) =>
+    setCollapsed(prev => {
+      const next = new Set(prev);
+      if (next.has(path)) next.delete(path);
+      else next.add(path);
+      return next;
+    });
+
+  const renderNode = (node: FileTreeNode, depth: number): React.ReactNode => {
+    const indent = { paddingLeft: `${12 + depth * 12}px` };
+
+    if (!node.file) {
+      const isCollapsed = collapsed.has(node.path);
+      const Caret = isCollapsed ? ICONS.CHEVRON_RIGHT : ICONS.CHEVRON_DOWN;
+      return (
+        <div key={`dir:${node.path}`}>
+          <button
+            onClick={() => toggle(node.path)}
+            style={indent}
+            className="text-text-secondary hover:bg-bg-secondary/80 flex w-full items-center gap-1.5 py-1 pr-3 text-left text-[11px]"
+          >
+            <Caret size={10} className="flex-shrink-0" />
+            <ICONS.VIEW_REPOS size={12} className="flex-shrink-0 opacity-70" />
+            <span className="min-w-0 flex-1 truncate">{node.name}</span>
+            {isCollapsed && <Coverage node={node} />}
+          </button>
+          {!isCollapsed && node.children.map(child => renderNode(child, depth + 1))}
+        </div>
+      );
+    }
+
+    const isSelected = selectedFile === node.path;
+    return (
+      <button
+        key={node.path}
+        onClick={() => onSelectFile(node.path)}
+        style={indent}
+        title={node.file.in_diff ? node.path : `${node.path} (no longer in the diff)`}
+        className={`hover:bg-bg-secondary/80 relative flex w-full items-center gap-1.5 py-1 pr-3 text-left text-[11px] ${
+          isSelected ? 'bg-bg-secondary text-text-primary' : 'text-text-secondary'
+        } ${node.file.in_diff ? '' : 'opacity-50'}`}
+      >
+        {isSelected && <div className="bg-brand absolute top-0 bottom-0 left-0 w-[2px]" />}
+        <ICONS.FILE size={12} className="ml-[16px] flex-shrink-0 opacity-70" />
+        <span className="min-w-0 flex-1 truncate font-mono">{node.name}</span>
+        <Coverage node={node} />
+      </button>
+    );
+  };
+
+  return (
+    <div className="custom-scrollbar flex-1 overflow-y-auto py-1">
+      {tree.map(node => renderNode(node, 0))}
+    </div>
+  );
+};
+
+/** Open feedback and tasks done/total for a file or folder. */
+const Coverage: React.FC<{ node: FileTreeNode }> = ({ node }) => {
+  const { openFeedback, tasks, closedTasks } = node.counts;
+  return (
+    <span className="flex flex-shrink-0 items-center gap-2 text-[10px] tabular-nums">
+      {openFeedback > 0 && (
+        <span className="text-status-todo flex items-center gap-0.5" title="Open feedback">
+          <ICONS.ICON_FEEDBACK size={10} />
+          {openFeedback}
+        </span>
+      )}
+      {tasks > 0 && (
+        <span
+          className={closedTasks === tasks ? 'text-status-done' : 'text-text-disabled'}
+          title="Tasks done"
+        >
+          {closedTasks}/{tasks}
+        </span>
+      )}
+    </span>
+  );
+};
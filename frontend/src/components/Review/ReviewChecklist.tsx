@@ -0,0 +1,80 @@
+import React, { useState } from 'react';
+import { ICONS } from '../../constants/icons';
+import { useChecklist } from '../../hooks/useChecklist';
+
+interface ReviewChecklistProps {
+  reviewId: string | null;
+}
+
+/** Reviewer-written follow-ups to finish before merging, shown above the tasks. */
+export const ReviewChecklist: React.FC<ReviewChecklistProps> = ({ reviewId }) => {
+  const { data: items = [], addItem, toggleItem, removeItem } = useChecklist(reviewId);
+  const [draft, setDraft] = useState('');
+  const done = items.filter(item => item.done).length;
+
+  const handleAdd = () => {
+    const text = draft.trim();
+    if (!text) return;
+    addItem.mutate(text, { onSuccess: () => setDraft('') });
+  };
+
+  return (
+    <div className="border-border/50 border-b px-3 py-2">
+      <div className="mb-1 flex items-center justify-between">
+        <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
+          Before merge
+        </span>
+        {items.length > 0 && (
+          <span
+            className={`text-[10px] ${done === items.length ? 'text-status-done' : 'text-text-tertiary'}`}
+          >
+            {done}/{items.length}
+          </span>
+        )}
+      </div>
+
+      {items.map(item => (
+        <div key={item.id} className="group flex items-center gap-2 py-0.5">
+          <label className="flex min-w-0 flex-1 cursor-pointer items-center gap-2 select-none">
+            <input
+              type="checkbox"
+              checked={item.done}
+              onChange={() => toggleItem.mutate(item.id)}
+              className="accent-brand"
+            />
+            <span
+              className={`min-w-0 flex-1 truncate text-xs ${
+                item.done ? 'text-text-disabled line-through' : 'text-text-primary'
+              }`}
+              title={item.text}
+            >
+              {item.text}
+            </span>
+          </label>
+          <button
+            onClick={() => removeItem.mutate(item.id)}
+            className="text-text-disabled hover:text-text-primary cursor-pointer opacity-0 transition-opacity group-hover:opacity-100"
+            aria-label={`Remove "${item.text}"`}
+          >
+            <ICONS.ACTION_CLOSE size={10} />
+          </button>
+        </div>
+      ))}
+
+      <div className="mt-1 flex items-center gap-1.5">
+        <ICONS.ICON_PLUS size={10} className="text-text-disabled" />
+        <input
+          value={draft}
+          onChange={e => setDraft(e.target.value)}
+          onKeyDown={e => {
+            if (e.key === 'Enter') handleAdd();
+          }}
+          placeholder="Add a follow-up, e.g. update changelog"
+          disabled={!reviewId || addItem.isPending}
+          maxLength={200}
+          className="text-text-primary placeholder:text-text-disabled min-w-0 flex-1 bg-transparent text-xs outline-none"
+        />
+      </div>
+    </div>
+  );
+};
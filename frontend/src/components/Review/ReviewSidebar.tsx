@@ -2,17 +2,38 @@ import React, { Suspense } from 'react';
 import { motion } from 'framer-motion';
 import { ArrowLeft } from '@phosphor-icons/react';
 import { SHARED_LAYOUT_TRANSITION } from '../../constants/animations';
-import type { ReviewTask, Feedback, ReviewRule, DefaultIssueCategory } from '../../types';
+import type {
+  ReviewTask,
+  Feedback,
+  ReviewRule,
+  DefaultIssueCategory,
+  IssueCheckWithFindings,
+  ReviewStats,
+  FileReviewStatus,
+} from '../../types';
 import { TaskList, TaskListSkeleton } from './TaskList';
+import { FileTree } from './FileTree';
 import { FeedbackList } from './FeedbackList';
+import { IssueChecklist } from './ReviewSummary/IssueChecklist';
+import { ReviewStatsHeader } from './ReviewStatsHeader';
+import { ReviewChecklist } from './ReviewChecklist';
 import { ICONS } from '../../constants/icons';
 
-export type SidebarTab = 'tasks' | 'feedback';
+export type SidebarTab = 'tasks' | 'feedback' | 'checks';
 
 interface ReviewSidebarProps {
+  reviewId: string | null;
   sidebarTab: SidebarTab;
   tasks: ReviewTask[];
   feedbacks: Feedback[];
+  issueChecks: IssueCheckWithFindings[];
+  isChecksLoading: boolean;
+  stats?: ReviewStats;
+  fileStatuses: FileReviewStatus[];
+  isFileStatusLoading: boolean;
+  /** Show the tasks tab as a file tree instead of the task list. */
+  showFileTree: boolean;
+  selectedFile: string | null;
   rulesById: Record<string, ReviewRule>;
   categoriesById: Record<string, DefaultIssueCategory>;
   selectedTaskId: string | null;
@@ -24,15 +45,30 @@ interface ReviewSidebarProps {
   onSidebarTabChange: (tab: SidebarTab) => void;
   onSelectTask: (taskId: string | null) => void;
   onSelectFeedback: (feedbackId: string | null) => void;
+  /** Blocking feedback still open; the feedback tab offers to step through it. */
+  openBlockingCount?: number;
+  onNextBlocking?: () => void;
+  onShowFileTreeChange: (show: boolean) => void;
+  onSelectFile: (path: string) => void;
   onOpenExportModal: () => void;
+  onCopySummary: () => void;
   onAddGlobalFeedback: () => void;
+  onAddTask: () => void;
   onBackToSummary: () => void;
 }
 
 export const ReviewSidebar: React.FC<ReviewSidebarProps> = ({
+  reviewId,
   sidebarTab,
   tasks,
   feedbacks,
+  issueChecks,
+  isChecksLoading,
+  stats,
+  fileStatuses,
+  isFileStatusLoading,
+  showFileTree,
+  selectedFile,
   rulesById,
   categoriesById,
   selectedTaskId,
@@ -44,25 +80,56 @@ export const ReviewSidebar: React.FC<ReviewSidebarProps> = ({
   onSidebarTabChange,
   onSelectTask,
   onSelectFeedback,
+  openBlockingCount = 0,
+  onNextBlocking,
+  onShowFileTreeChange,
+  onSelectFile,
   onOpenExportModal,
+  onCopySummary,
   onAddGlobalFeedback,
+  onAddTask,
   onBackToSummary,
 }) => {
   const handleTabChange = (tab: SidebarTab) => {
     onSidebarTabChange(tab);
     if (tab === 'tasks') {
       onSelectFeedback(null);
-      if (tasks.length > 0 && !selectedTaskId) {
+      if (tasks.length > 0 && !selectedTaskId && !showFileTree) {
         onSelectTask(tasks[0].id);
       }
-    } else {
+    } else if (tab === 'feedback') {
       onSelectTask(null);
       if (feedbacks.length > 0 && !selectedFeedbackId) {
         onSelectFeedback(feedbacks[0].id);
       }
+    } else {
+      onSelectTask(null);
+      onSelectFeedback(null);
     }
   };
 
+  // Findings open in the feedback tab, where their detail is shown.
+  const handleSelectCheckFeedback = (feedbackId: string) => {
+    onSidebarTabChange('feedback');
+    onSelectTask(null);
+    onSelectFeedback(feedbackId);
+  };
+
+  const handleShowFileTree = (show: boolean) => {
+    onShowFileTreeChange(show);
+    if (show) {
+      onSelectTask(null);
+    } else if (tasks.length > 0) {
+      onSelectTask(tasks[0].id);
+    }
+  };
+
+  // Files open in the full diff, outside any one task.
+  const handleSelectFile = (path: string) => {
+    onSelectTask(null);
+    onSelectFile(path);
+  };
+
   return (
     <div className="border-border bg-bg-secondary/30 flex w-[300px] flex-col border-r">
       <div className="border-border bg-bg-secondary/50 border-b p-3">
@@ -75,20 +142,29 @@ export const ReviewSidebar: React.FC<ReviewSidebarProps> = ({
           <span>Summary</span>
         </button>
 
+        {stats && <ReviewStatsHeader stats={stats} onCopySummary={onCopySummary} />}
+
         <div className="mb-3 flex">
-          <div
-            className={`flex items-center overflow-hidden transition-all duration-300 ease-in-out ${
-              sidebarTab === 'feedback' ? 'mr-2 flex-1 opacity-100' : 'mr-0 flex-[0_0_0] opacity-0'
-            }`}
-          >
-            <button
-              onClick={onAddGlobalFeedback}
-              className="bg-brand text-brand-fg border-brand hover:bg-brand/90 flex w-full cursor-pointer items-center justify-center gap-1.5 rounded border px-3 py-1.5 text-[10px] font-medium whitespace-nowrap transition-all"
-              title="Add Global Feedback"
-            >
-              <ICONS.ICON_FEEDBACK size={12} weight="bold" />
-              Add Feedback
-            </button>
+          <div className="mr-2 flex flex-1 items-center overflow-hidden">
+            {sidebarTab === 'feedback' ? (
+              <button
+                onClick={onAddGlobalFeedback}
+                className="bg-brand text-brand-fg border-brand hover:bg-brand/90 flex w-full cursor-pointer items-center justify-center gap-1.5 rounded border px-3 py-1.5 text-[10px] font-medium whitespace-nowrap transition-all"
+                title="Add Global Feedback"
+              >
+                <ICONS.ICON_FEEDBACK size={12} weight="bold" />
+                Add Feedback
+              </button>
+            ) : (
+              <button
+                onClick={onAddTask}
+                className="bg-brand text-brand-fg border-brand hover:bg-brand/90 flex w-full cursor-pointer items-center justify-center gap-1.5 rounded border px-3 py-1.5 text-[10px] font-medium whitespace-nowrap transition-all"
+                title="Add a task of your own"
+              >
+                <ICONS.ICON_PLAN size={12} weight="bold" />
+                Add Task
+              </button>
+            )}
           </div>
           <button
             onClick={onOpenExportModal}
@@ -116,10 +192,44 @@ export const ReviewSidebar: React.FC<ReviewSidebarProps> = ({
             label="Feedback"
             count={feedbacks.length}
           />
+          <div className="bg-border/50 mx-0.5 h-4 w-px self-center" />
+          <TabButton
+            active={sidebarTab === 'checks'}
+            onClick={() => handleTabChange('checks')}
+            icon={ICONS.STATUS_ISSUES}
+            label="Checks"
+            count={issueChecks.filter(check => check.status === 'found').length}
+          />
         </div>
       </div>
 
+      {sidebarTab === 'tasks' && <ReviewChecklist reviewId={reviewId} />}
+
       {sidebarTab === 'tasks' && (
+        <div className="border-border/50 flex items-center gap-1 border-b px-3 py-1.5">
+          <ViewToggle
+            active={!showFileTree}
+            onClick={() => handleShowFileTree(false)}
+            label="Flows"
+          />
+          <ViewToggle
+            active={showFileTree}
+            onClick={() => handleShowFileTree(true)}
+            label="Files"
+          />
+        </div>
+      )}
+
+      {sidebarTab === 'tasks' && showFileTree && (
+        <FileTree
+          files={fileStatuses}
+          selectedFile={selectedFile}
+          onSelectFile={handleSelectFile}
+          isLoading={isFileStatusLoading}
+        />
+      )}
+
+      {sidebarTab === 'tasks' && !showFileTree && (
         <Suspense fallback={<TaskListSkeleton />}>
           <TaskList
             tasks={tasks}
@@ -131,6 +241,22 @@ export const ReviewSidebar: React.FC<ReviewSidebarProps> = ({
         </Suspense>
       )}
 
+      {sidebarTab === 'feedback' && onNextBlocking && openBlockingCount > 0 && (
+        <div className="border-border/50 flex items-center justify-between border-b px-3 py-1.5">
+          <span className="text-impact-blocking flex items-center gap-1.5 text-[10px] font-medium">
+            <ICONS.IMPACT_BLOCKING size={12} />
+            {openBlockingCount} open {openBlockingCount === 1 ? 'blocker' : 'blockers'}
+          </span>
+          <button
+            onClick={onNextBlocking}
+            className="text-text-disabled hover:text-text-secondary cursor-pointer rounded px-2 py-0.5 text-[10px] font-medium transition-colors"
+            title="Jump to the next unresolved blocking finding (B)"
+          >
+            Next blocker
+          </button>
+        </div>
+      )}
+
       {sidebarTab === 'feedback' && (
         <FeedbackList
           feedbacks={feedbacks}
@@ -142,6 +268,17 @@ export const ReviewSidebar: React.FC<ReviewSidebarProps> = ({
           confidenceThreshold={confidenceThreshold}
         />
       )}
+
+      {sidebarTab === 'checks' && (
+        <div className="flex-1 overflow-y-auto p-3">
+          <IssueChecklist
+            checks={issueChecks}
+            isLoading={isChecksLoading}
+            feedbacks={feedbacks}
+            onSelectFeedback={handleSelectCheckFeedback}
+          />
+        </div>
+      )}
     </div>
   );
 };
@@ -175,3 +312,18 @@ const TabButton: React.FC<TabButtonProps> = ({ active, onClick, icon: Icon, labe
     </div>
   </button>
 );
+
+const ViewToggle: React.FC<{ active: boolean; onClick: () => void; label: string }> = ({
+  active,
+  onClick,
+  label,
+}) => (
+  <button
+    onClick={onClick}
+    className={`cursor-pointer rounded px-2 py-0.5 text-[10px] font-medium transition-colors ${
+      active ? 'bg-bg-tertiary text-text-primary' : 'text-text-disabled hover:text-text-secondary'
+    }`}
+  >
+    {label}
+  </button>
+);
@@ -0,0 +1,75 @@
+import React from 'react';
+import { ICONS } from '../../constants/icons';
+import type { ReviewStats } from '../../types';
+
+interface ReviewStatsHeaderProps {
+  stats: ReviewStats;
+  /** Copies a one-line summary of the review, for standups. */
+  onCopySummary?: () => void;
+}
+
+const riskItems = [
+  { key: 'high', icon: ICONS.RISK_HIGH, color: 'text-risk-high', label: 'High risk' },
+  { key: 'medium', icon: ICONS.RISK_MEDIUM, color: 'text-risk-medium', label: 'Medium risk' },
+  { key: 'low', icon: ICONS.RISK_LOW, color: 'text-risk-low', label: 'Low risk' },
+] as const;
+
+const impactItems = [
+  { key: 'blocking', color: 'text-impact-blocking', label: 'MUST' },
+  { key: 'nice_to_have', color: 'text-impact-nice_to_have', label: 'NICE' },
+  { key: 'nitpick', color: 'text-impact-nitpick', label: 'NIT' },
+] as const;
+
+const OverallRisk: React.FC<{ risk: ReviewStats['overall_risk'] }> = ({ risk }) => {
+  const item = riskItems.find(({ key }) => key === risk) ?? riskItems[2];
+  return (
+    <span
+      className={`flex items-center gap-0.5 font-sans font-semibold uppercase ${item.color}`}
+      title={`Overall: ${item.label.toLowerCase()}`}
+    >
+      <item.icon size={11} weight="fill" />
+      {risk}
+    </span>
+  );
+};
+
+/** One-glance scope of the review: diff size, task risk mix and feedback impact mix. */
+export const ReviewStatsHeader: React.FC<ReviewStatsHeaderProps> = ({ stats, onCopySummary }) => (
+  <div className="mb-3 space-y-1.5 text-[10px]">
+    <div className="flex items-center gap-2 font-mono">
+      <OverallRisk risk={stats.overall_risk} />
+      <span className="text-text-secondary">
+        {stats.files} {stats.files === 1 ? 'file' : 'files'}
+      </span>
+      <span className="text-status-added">+{stats.additions}</span>
+      <span className="text-status-ignored">-{stats.deletions}</span>
+      {onCopySummary && (
+        <button
+          onClick={onCopySummary}
+          className="text-text-disabled hover:text-text-primary ml-auto transition-colors"
+          title="Copy a one-line summary"
+          aria-label="Copy summary"
+        >
+          <ICONS.ACTION_COPY size={12} />
+        </button>
+      )}
+    </div>
+    <div className="flex items-center justify-between">
+      <div className="flex items-center gap-2">
+        {riskItems.map(({ key, icon: Icon, color, label }) => (
+          <span key={key} className={`flex items-center gap-0.5 ${color}`} title={label}>
+            <Icon size={11} weight="fill" />
+            {stats.task_risk[key]}
+          </span>
+        ))}
+      </div>
+      <div className="flex items-center gap-2 font-medium">
+        {impactItems.map(({ key, color, label }) => (
+          <span key={key} className={color}>
+            {stats.feedback_impact[key]} {label}
+          </span>
+        ))}
+      </div>
+    </div>
+  </div>
+);
@@ -0,0 +1,131 @@
+import React, { useState } from 'react';
+import { ICONS } from '../../../constants/icons';
+import { useRunToolCalls } from '../../../hooks/useRunToolCalls';
+import type { RunToolCall, ToolTiming } from '../../../types';
+
+interface AgentActivityProps {
+  runId: string | undefined;
+}
+
+const statusClass = (status: string) => {
+  switch (status.toLowerCase()) {
+    case 'completed':
+      return 'text-green-500';
+    case 'failed':
+      return 'text-status-deleted';
+    default:
+      return 'text-text-disabled';
+  }
+};
+
+const formatRaw = (value: unknown) =>
+  typeof value === 'string' ? value : JSON.stringify(value, null, 2);
+
+const formatDuration = (ms: number) =>
+  ms < 1000 ? `${ms} ms` : `${(ms / 1000).toFixed(1)} s`;
+
+const ToolTimings: React.FC<{ timings: ToolTiming[] }> = ({ timings }) => {
+  const total = timings.reduce((sum, timing) => sum + timing.total_ms, 0);
+  return (
+    <div className="border-border/20 border-b px-4 py-2">
+      <p className="text-text-secondary mb-1 text-xs">
+        Time spent in tools: <span className="font-mono">{formatDuration(total)}</span>
+      </p>
+      {timings.map(timing => (
+        <div key={timing.tool} className="flex items-center gap-2 text-[10px]">
+          <span className="text-text-secondary min-w-0 flex-1 truncate font-mono">
+            {timing.tool}
+          </span>
+          <span className="text-text-disabled shrink-0">
+            {timing.calls} {timing.calls === 1 ? 'call' : 'calls'} · max{' '}
+            {formatDuration(timing.max_ms)}
+          </span>
+          <span className="text-text-secondary w-14 shrink-0 text-right font-mono">
+            {formatDuration(timing.total_ms)}
+          </span>
+        </div>
+      ))}
+    </div>
+  );
+};
+
+const ToolCallRow: React.FC<{ call: RunToolCall }> = ({ call }) => {
+  const hasRaw = call.raw_input != null || call.raw_output != null;
+  const summary = (
+    <div className="flex min-w-0 items-center gap-2 text-xs">
+      <span className="text-text-disabled w-14 shrink-0 font-mono text-[10px] uppercase">
+        {call.kind}
+      </span>
+      <span className="text-text-secondary min-w-0 flex-1 truncate font-mono">{call.title}</span>
+      <span className={`shrink-0 text-[10px] ${statusClass(call.status)}`}>{call.status}</span>
+    </div>
+  );
+
+  if (!hasRaw) {
+    return <div className="border-border/20 border-b px-4 py-2 last:border-b-0">{summary}</div>;
+  }
+
+  return (
+    <details className="border-border/20 border-b px-4 py-2 last:border-b-0">
+      <summary className="cursor-pointer list-none">{summary}</summary>
+      <div className="mt-2 space-y-2">
+        {call.raw_input != null && (
+          <pre className="bg-bg-tertiary/50 text-text-secondary max-h-48 overflow-auto rounded p-2 text-[10px]">
+            {formatRaw(call.raw_input)}
+          </pre>
+        )}
+        {call.raw_output != null && (
+          <pre className="bg-bg-tertiary/50 text-text-secondary max-h-48 overflow-auto rounded p-2 text-[10px]">
+            {formatRaw(call.raw_output)}
+          </pre>
+        )}
+      </div>
+    </details>
+  );
+};
+
+export const AgentActivity: React.FC<AgentActivityProps> = ({ runId }) => {
+  const [expanded, setExpanded] = useState(false);
+  const { data: log } = useRunToolCalls(runId);
+
+  const timings = log?.timings ?? [];
+  if (!log || (log.calls.length === 0 && timings.length === 0)) {
+    return null;
+  }
+
+  return (
+    <div className="bg-bg-secondary/30 border-border/50 rounded-lg border">
+      <button
+        onClick={() => setExpanded(!expanded)}
+        className="hover:bg-bg-tertiary/30 flex w-full items-center justify-between px-4 py-3 transition-colors"
+        aria-expanded={expanded}
+      >
+        <div className="flex items-center gap-2">
+          <ICONS.ICON_TOOL size={16} className="text-text-disabled" />
+          <h3 className="text-text-primary text-sm font-medium">Agent Activity</h3>
+          <span className="bg-bg-tertiary text-text-secondary rounded-full px-2 py-0.5 text-[10px] font-medium">
+            {log.calls.length + log.omitted}
+          </span>
+        </div>
+        <ICONS.CHEVRON_DOWN
+          size={14}
+          className={`text-text-disabled transition-transform ${expanded ? 'rotate-180' : ''}`}
+        />
+      </button>
+
+      {expanded && (
+        <div className="border-border/50 max-h-80 overflow-y-auto border-t">
+          {timings.length > 0 && <ToolTimings timings={timings} />}
+          {log.calls.map(call => (
+            <ToolCallRow key={call.tool_call_id} call={call} />
+          ))}
+          {log.omitted > 0 && (
+            <p className="text-text-disabled px-4 py-2 text-xs">
+              {log.omitted} more tool {log.omitted === 1 ? 'call' : 'calls'} not recorded.
+            </p>
+          )}
+        </div>
+      )}
+    </div>
+  );
+};
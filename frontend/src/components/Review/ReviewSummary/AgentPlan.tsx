@@ -0,0 +1,45 @@
+import React, { useState } from 'react';
+import { ICONS } from '../../../constants/icons';
+import { PlanSteps } from '../../Generate/PlanSteps';
+import { useRunPlan } from '../../../hooks/useRunPlan';
+
+interface AgentPlanProps {
+  runId: string | undefined;
+}
+
+export const AgentPlan: React.FC<AgentPlanProps> = ({ runId }) => {
+  const [expanded, setExpanded] = useState(false);
+  const { data: plan } = useRunPlan(runId);
+
+  if (!plan || plan.entries.length === 0) {
+    return null;
+  }
+
+  return (
+    <div className="bg-bg-secondary/30 border-border/50 rounded-lg border">
+      <button
+        onClick={() => setExpanded(!expanded)}
+        className="hover:bg-bg-tertiary/30 flex w-full items-center justify-between px-4 py-3 transition-colors"
+        aria-expanded={expanded}
+      >
+        <div className="flex items-center gap-2">
+          <ICONS.ICON_PLAN size={16} className="text-text-disabled" />
+          <h3 className="text-text-primary text-sm font-medium">Agent Plan</h3>
+          <span className="bg-bg-tertiary text-text-secondary rounded-full px-2 py-0.5 text-[10px] font-medium">
+            {plan.entries.length}
+          </span>
+        </div>
+        <ICONS.CHEVRON_DOWN
+          size={14}
+          className={`text-text-disabled transition-transform ${expanded ? 'rotate-180' : ''}`}
+        />
+      </button>
+
+      {expanded && (
+        <div className="border-border/50 border-t px-4 py-3">
+          <PlanSteps steps={plan.entries} />
+        </div>
+      )}
+    </div>
+  );
+};
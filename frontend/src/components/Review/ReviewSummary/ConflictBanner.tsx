@@ -0,0 +1,43 @@
+import React from 'react';
+import { ICONS } from '../../../constants/icons';
+import type { ConflictedFile } from '../../../types';
+
+interface ConflictBannerProps {
+  conflicts: ConflictedFile[];
+  onSelectFile: (fileName: string) => void;
+}
+
+export const ConflictBanner: React.FC<ConflictBannerProps> = ({ conflicts, onSelectFile }) => {
+  if (conflicts.length === 0) {
+    return null;
+  }
+
+  return (
+    <div
+      role="alert"
+      className="bg-status-error/5 border-status-error/30 rounded-lg border px-4 py-3"
+    >
+      <div className="flex items-center gap-2">
+        <ICONS.ICON_WARNING size={16} weight="fill" className="text-status-error" />
+        <p className="text-status-error text-sm font-medium">
+          Unresolved merge conflicts in {conflicts.length}{' '}
+          {conflicts.length === 1 ? 'file' : 'files'}
+        </p>
+      </div>
+      <p className="text-status-error/70 mt-1 text-xs">
+        The diff still contains conflict markers. Don&apos;t approve until they are resolved.
+      </p>
+      <div className="mt-2 flex flex-wrap gap-1.5">
+        {conflicts.map(conflict => (
+          <button
+            key={conflict.file_path}
+            onClick={() => onSelectFile(conflict.file_path)}
+            className="bg-status-error/10 text-status-error hover:bg-status-error/20 rounded px-2 py-0.5 font-mono text-[11px] transition-colors"
+          >
+            {conflict.file_path}:{conflict.line_number}
+          </button>
+        ))}
+      </div>
+    </div>
+  );
+};
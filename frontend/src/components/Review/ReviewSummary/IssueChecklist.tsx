@@ -117,7 +117,7 @@ export const IssueChecklist: React.FC<IssueChecklistProps> = ({
           No issue checks for this review.
           <br />
           <span className="text-xs">
-            Add checklist rules in Settings to enable systematic issue verification.
+            Add custom checks in Rules to enable systematic issue verification.
           </span>
         </div>
       </div>
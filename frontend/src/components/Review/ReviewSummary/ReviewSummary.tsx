@@ -6,8 +6,14 @@ import { IssueChecklist } from './IssueChecklist';
 import { KeyFeedback } from './KeyFeedback';
 import { FilesHeatmap } from './FilesHeatmap';
 import { UncoveredFiles } from './UncoveredFiles';
+import { ConflictBanner } from './ConflictBanner';
 import { MergeConfidenceBadge } from './MergeConfidenceBadge';
+import { ReviewTags } from './ReviewTags';
+import { AgentPlan } from './AgentPlan';
+import { AgentActivity } from './AgentActivity';
+import { RunHistory } from './RunHistory';
 import { useIssueChecks } from '../../../hooks/useIssueChecks';
+import { formatSourceRef, isGitLabSource } from '../../../lib/review-source';
 import type { ReviewTask, Feedback, ParsedDiff, Review, ReviewSource } from '../../../types';
 
 interface ReviewSummaryProps {
@@ -27,27 +33,17 @@ interface SourceBadgeProps {
 }
 
 const SourceBadge: React.FC<SourceBadgeProps> = ({ source }) => {
-  if (source.type === 'github_pr') {
-    return (
-      <span className="flex items-center gap-1.5 text-xs">
-        <ICONS.ICON_GITHUB size={14} className="text-text-secondary" />
-        <span className="text-text-secondary">
-          {source.owner}/{source.repo}#{source.number}
-        </span>
-      </span>
-    );
-  }
-  if (source.type === 'gitlab_mr') {
-    return (
-      <span className="flex items-center gap-1.5 text-xs">
-        <ICONS.ICON_GITLAB size={14} className="text-text-secondary" />
-        <span className="text-text-secondary">
-          {source.project_path}!{source.number}
-        </span>
-      </span>
-    );
+  const label = formatSourceRef(source);
+  if (!label) {
+    return <span className="text-text-disabled text-xs">Diff paste</span>;
   }
-  return <span className="text-text-disabled text-xs">Diff paste</span>;
+  const Icon = isGitLabSource(source) ? ICONS.ICON_GITLAB : ICONS.ICON_GITHUB;
+  return (
+    <span className="flex items-center gap-1.5 text-xs">
+      <Icon size={14} className="text-text-secondary" />
+      <span className="text-text-secondary">{label}</span>
+    </span>
+  );
 };
 
 export const ReviewSummary: React.FC<ReviewSummaryProps> = ({
@@ -86,6 +82,7 @@ export const ReviewSummary: React.FC<ReviewSummaryProps> = ({
           <h2 className="text-text-primary text-lg font-semibold">
             {review?.title || parsedDiff?.title || 'Review Summary'}
           </h2>
+          {review && <ReviewTags review={review} />}
         </div>
         <div className="flex items-center gap-3">
           <MergeConfidenceBadge runId={runId} />
@@ -101,6 +98,9 @@ export const ReviewSummary: React.FC<ReviewSummaryProps> = ({
 
       {/* Content */}
       <div className="flex-1 space-y-6 overflow-auto p-6">
+        {/* Merge conflicts */}
+        <ConflictBanner conflicts={parsedDiff?.conflicts ?? []} onSelectFile={onSelectFile} />
+
         {/* Blocking Issues */}
         {blockingCount > 0 && (
           <div className="bg-status-error/5 border-status-error/20 flex items-center gap-3 rounded-lg border px-4 py-3">
@@ -152,9 +152,18 @@ export const ReviewSummary: React.FC<ReviewSummaryProps> = ({
           </div>
         )}
 
+        <AgentPlan runId={runId} />
+        <AgentActivity runId={runId} />
+        <RunHistory reviewId={review?.id ?? null} />
+
         {/* Two-column layout for Task Flow and Files Heatmap */}
         <div className="grid grid-cols-2 items-start gap-6">
-          <TaskFlow tasks={tasks} onSelectTask={onSelectTask} />
+          <TaskFlow
+            runId={runId}
+            tasks={tasks}
+            hasFeedback={feedbacks.length > 0}
+            onSelectTask={onSelectTask}
+          />
           <FilesHeatmap tasks={tasks} onSelectFile={onSelectFile} />
         </div>
 
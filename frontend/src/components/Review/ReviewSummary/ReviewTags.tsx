@@ -0,0 +1,60 @@
+import React, { useState } from 'react';
+import { useReviews } from '../../../hooks/useReviews';
+import type { Review } from '../../../types';
+
+interface ReviewTagsProps {
+  review: Review;
+}
+
+export const ReviewTags: React.FC<ReviewTagsProps> = ({ review }) => {
+  const { addTag, removeTag } = useReviews();
+  const [draft, setDraft] = useState('');
+  const tags = review.tags ?? [];
+  const suggestions = (review.suggested_tags ?? []).filter(tag => !tags.includes(tag));
+
+  const submit = () => {
+    const tag = draft.trim();
+    if (!tag) return;
+    addTag.mutate({ reviewId: review.id, tag });
+    setDraft('');
+  };
+
+  return (
+    <div className="mt-2 flex flex-wrap items-center gap-1.5">
+      {tags.map(tag => (
+        <span
+          key={tag}
+          className="bg-bg-tertiary border-border text-text-secondary flex items-center gap-1 rounded border px-1.5 py-0.5 text-[10px]"
+        >
+          #{tag}
+          <button
+            onClick={() => removeTag.mutate({ reviewId: review.id, tag })}
+            className="text-text-disabled hover:text-status-ignored"
+            aria-label={`Remove tag ${tag}`}
+          >
+            ×
+          </button>
+        </span>
+      ))}
+      {suggestions.map(tag => (
+        <button
+          key={tag}
+          onClick={() => addTag.mutate({ reviewId: review.id, tag })}
+          className="border-border/60 text-text-disabled hover:text-text-secondary rounded border border-dashed px-1.5 py-0.5 text-[10px]"
+          title="Add suggested tag"
+        >
+          + {tag}
+        </button>
+      ))}
+      <input
+        value={draft}
+        onChange={e => setDraft(e.target.value)}
+        onKeyDown={e => e.key === 'Enter' && submit()}
+        onBlur={submit}
+        placeholder="Add tag"
+        aria-label="Add tag"
+        className="placeholder-text-disabled text-text-secondary w-20 bg-transparent text-[10px] focus:outline-none"
+      />
+    </div>
+  );
+};
@@ -0,0 +1,71 @@
+import React, { useState } from 'react';
+import { ICONS } from '../../../constants/icons';
+import { useReview } from '../../../hooks/useReview';
+import { useAgents } from '../../../hooks/useAgents';
+
+interface RunHistoryProps {
+  reviewId: string | null;
+}
+
+/** Every generation run of the review, newest first, with the agent and model behind it. */
+export const RunHistory: React.FC<RunHistoryProps> = ({ reviewId }) => {
+  const [expanded, setExpanded] = useState(false);
+  const { runs } = useReview(reviewId);
+  const { data: agents = [] } = useAgents();
+
+  if (runs.length === 0) {
+    return null;
+  }
+
+  const agentName = (agentId: string) =>
+    agents.find(agent => agent.id === agentId)?.name ?? agentId;
+
+  return (
+    <div className="bg-bg-secondary/30 border-border/50 rounded-lg border">
+      <button
+        onClick={() => setExpanded(!expanded)}
+        className="hover:bg-bg-tertiary/30 flex w-full items-center justify-between px-4 py-3 transition-colors"
+        aria-expanded={expanded}
+      >
+        <div className="flex items-center gap-2">
+          <ICONS.ICON_HISTORY size={16} className="text-text-disabled" />
+          <h3 className="text-text-primary text-sm font-medium">Runs</h3>
+          <span className="bg-bg-tertiary text-text-secondary rounded-full px-2 py-0.5 text-[10px] font-medium">
+            {runs.length}
+          </span>
+        </div>
+        <ICONS.CHEVRON_DOWN
+          size={14}
+          className={`text-text-disabled transition-transform ${expanded ? 'rotate-180' : ''}`}
+        />
+      </button>
+
+      {expanded && (
+        <div className="border-border/50 border-t">
+          {runs.map(run => (
+            <div
+              key={run.id}
+              className="border-border/20 flex items-center gap-2 border-b px-4 py-2 text-xs last:border-b-0"
+            >
+              <span className="text-text-disabled w-36 shrink-0">
+                {new Date(run.created_at).toLocaleString()}
+              </span>
+              <span className="text-text-secondary min-w-0 flex-1 truncate">
+                {agentName(run.agent_id)}
+                {run.agent_model && (
+                  <span className="text-text-disabled ml-1.5 font-mono text-[10px]">
+                    {run.agent_model}
+                  </span>
+                )}
+              </span>
+              <span className="text-text-disabled shrink-0 text-[10px]">
+                {run.task_count} {run.task_count === 1 ? 'task' : 'tasks'}
+                {run.status && ` · ${run.status}`}
+              </span>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
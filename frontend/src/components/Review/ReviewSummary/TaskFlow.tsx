@@ -1,9 +1,14 @@
 import React, { useState, useMemo } from 'react';
 import { ICONS } from '../../../constants/icons';
 import type { ReviewTask } from '../../../types';
+import { useSubFlows } from '../../../hooks/useTasks';
+import { moveSubFlow, orderSubFlows, UNGROUPED_SUB_FLOW } from '../../../lib/sub-flows';
 
 interface TaskFlowProps {
+  runId?: string;
   tasks: ReviewTask[];
+  /** The review has feedback, so an empty task list isn't an empty review */
+  hasFeedback?: boolean;
   onSelectTask: (taskId: string) => void;
 }
 
@@ -23,11 +28,17 @@ const statusConfig = {
 };
 
 interface TaskGroup {
+  /** Sub-flow name; `UNGROUPED_SUB_FLOW` for tasks without one. */
+  key: string;
   name: string;
   tasks: ReviewTask[];
   maxRisk: RiskLevel;
 }
 
+const UNGROUPED_LABEL = 'General';
+/** Select value for "move to a new sub-flow". */
+const NEW_SUB_FLOW_OPTION = '__new_sub_flow__';
+
 const getRiskOrder = (risk: RiskLevel): number => {
   const order: Record<RiskLevel, number> = { high: 0, medium: 1, low: 2 };
   return order[risk];
@@ -35,32 +46,111 @@ const getRiskOrder = (risk: RiskLevel): number => {
 
 interface TaskGroupSectionProps {
   group: TaskGroup;
+  groupKeys: string[];
+  isFirst: boolean;
+  isLast: boolean;
   onSelectTask: (taskId: string) => void;
+  onMove: (delta: -1 | 1) => void;
+  onRename: (newName: string) => void;
+  onMoveTask: (taskId: string, subFlow: string | null) => void;
   defaultExpanded?: boolean;
 }
 
 const TaskGroupSection: React.FC<TaskGroupSectionProps> = ({
   group,
+  groupKeys,
+  isFirst,
+  isLast,
   onSelectTask,
+  onMove,
+  onRename,
+  onMoveTask,
   defaultExpanded = false,
 }) => {
   const [expanded, setExpanded] = useState(defaultExpanded);
+  const [isRenaming, setIsRenaming] = useState(false);
+  const [draftName, setDraftName] = useState(group.name);
+  const [newFlowTaskId, setNewFlowTaskId] = useState<string | null>(null);
+  const [newFlowName, setNewFlowName] = useState('');
+
+  const submitRename = () => {
+    const name = draftName.trim();
+    setIsRenaming(false);
+    if (name && name !== group.name) onRename(name);
+  };
+
+  const submitNewFlow = (taskId: string) => {
+    const name = newFlowName.trim();
+    setNewFlowTaskId(null);
+    setNewFlowName('');
+    if (name) onMoveTask(taskId, name);
+  };
 
   return (
-    <div className="border-border/30 border-b last:border-b-0">
-      <button
-        onClick={() => setExpanded(!expanded)}
-        className="hover:bg-bg-tertiary/30 flex w-full items-center gap-2 px-3 py-2 text-left transition-colors"
-      >
-        <ICONS.CHEVRON_DOWN
-          size={12}
-          className={`text-text-disabled transition-transform ${expanded ? '' : '-rotate-90'}`}
-        />
-        <span className="text-text-primary flex-1 text-sm font-medium">{group.name}</span>
+    <div className="group/flow border-border/30 border-b last:border-b-0">
+      <div className="hover:bg-bg-tertiary/30 flex w-full items-center gap-2 px-3 py-2 transition-colors">
+        <button
+          onClick={() => setExpanded(!expanded)}
+          className="flex min-w-0 flex-1 items-center gap-2 text-left"
+        >
+          <ICONS.CHEVRON_DOWN
+            size={12}
+            className={`text-text-disabled transition-transform ${expanded ? '' : '-rotate-90'}`}
+          />
+          {isRenaming ? (
+            <input
+              autoFocus
+              value={draftName}
+              onClick={e => e.stopPropagation()}
+              onChange={e => setDraftName(e.target.value)}
+              onBlur={submitRename}
+              onKeyDown={e => {
+                if (e.key === 'Enter') submitRename();
+                if (e.key === 'Escape') {
+                  setDraftName(group.name);
+                  setIsRenaming(false);
+                }
+              }}
+              className="bg-bg-tertiary text-text-primary border-border/50 min-w-0 flex-1 rounded border px-1.5 py-0.5 text-sm"
+            />
+          ) : (
+            <span className="text-text-primary flex-1 truncate text-sm font-medium">
+              {group.name}
+            </span>
+          )}
+        </button>
+        <div className="flex items-center gap-0.5 opacity-0 transition-opacity group-hover/flow:opacity-100">
+          <button
+            onClick={() => {
+              setDraftName(group.name);
+              setIsRenaming(true);
+            }}
+            title="Rename sub-flow"
+            className="text-text-disabled hover:text-text-primary rounded p-0.5"
+          >
+            <ICONS.ACTION_EDIT size={12} />
+          </button>
+          <button
+            onClick={() => onMove(-1)}
+            disabled={isFirst}
+            title="Move up"
+            className="text-text-disabled hover:text-text-primary rounded p-0.5 disabled:opacity-30"
+          >
+            <ICONS.ARROW_UP size={12} />
+          </button>
+          <button
+            onClick={() => onMove(1)}
+            disabled={isLast}
+            title="Move down"
+            className="text-text-disabled hover:text-text-primary rounded p-0.5 disabled:opacity-30"
+          >
+            <ICONS.ARROW_DOWN size={12} />
+          </button>
+        </div>
         <span className="text-text-disabled text-xs">
           {group.tasks.length} {group.tasks.length === 1 ? 'task' : 'tasks'}
         </span>
-      </button>
+      </div>
 
       {expanded && (
         <div className="bg-bg-tertiary/10 border-border/20 border-t">
@@ -71,21 +161,66 @@ const TaskGroupSection: React.FC<TaskGroupSectionProps> = ({
             const StatusIcon = status.icon;
 
             return (
-              <button
+              <div
                 key={task.id}
-                onClick={() => onSelectTask(task.id)}
-                className="hover:bg-bg-tertiary/30 flex w-full items-center gap-2 px-4 py-2 text-left transition-colors"
+                className="group/task hover:bg-bg-tertiary/30 flex w-full items-center gap-2 px-4 py-2 transition-colors"
               >
-                <StatusIcon size={14} className={status.color} />
-                <span className="text-text-primary min-w-0 flex-1 truncate text-sm">
-                  {task.title}
-                </span>
+                <button
+                  onClick={() => onSelectTask(task.id)}
+                  className="flex min-w-0 flex-1 items-center gap-2 text-left"
+                >
+                  <StatusIcon size={14} className={status.color} />
+                  <span className="text-text-primary min-w-0 flex-1 truncate text-sm">
+                    {task.title}
+                  </span>
+                  {!task.ai_generated && (
+                    <ICONS.ICON_USER_TASK
+                      size={12}
+                      className="text-text-tertiary flex-shrink-0"
+                      aria-label="Added by you"
+                    />
+                  )}
+                </button>
+                {newFlowTaskId === task.id ? (
+                  <input
+                    autoFocus
+                    value={newFlowName}
+                    placeholder="New sub-flow"
+                    onChange={e => setNewFlowName(e.target.value)}
+                    onBlur={() => submitNewFlow(task.id)}
+                    onKeyDown={e => {
+                      if (e.key === 'Enter') submitNewFlow(task.id);
+                      if (e.key === 'Escape') setNewFlowTaskId(null);
+                    }}
+                    className="bg-bg-tertiary text-text-primary border-border/50 w-28 rounded border px-1.5 py-0.5 text-[10px]"
+                  />
+                ) : (
+                  <select
+                    value={group.key}
+                    title="Move to sub-flow"
+                    onChange={e => {
+                      if (e.target.value === NEW_SUB_FLOW_OPTION) {
+                        setNewFlowTaskId(task.id);
+                      } else {
+                        onMoveTask(task.id, e.target.value || null);
+                      }
+                    }}
+                    className="bg-bg-tertiary text-text-secondary border-border/50 w-24 rounded border px-1 py-0.5 text-[10px] opacity-0 transition-opacity group-hover/task:opacity-100 focus:opacity-100"
+                  >
+                    {groupKeys.map(key => (
+                      <option key={key} value={key}>
+                        {key === UNGROUPED_SUB_FLOW ? UNGROUPED_LABEL : key}
+                      </option>
+                    ))}
+                    <option value={NEW_SUB_FLOW_OPTION}>New sub-flow...</option>
+                  </select>
+                )}
                 <span
                   className={`${risk.bg} ${risk.color} flex-shrink-0 rounded px-1 py-0.5 text-[8px] font-bold`}
                 >
                   {risk.label}
                 </span>
-              </button>
+              </div>
             );
           })}
         </div>
@@ -94,19 +229,26 @@ const TaskGroupSection: React.FC<TaskGroupSectionProps> = ({
   );
 };
 
-export const TaskFlow: React.FC<TaskFlowProps> = ({ tasks, onSelectTask }) => {
+export const TaskFlow: React.FC<TaskFlowProps> = ({
+  runId,
+  tasks,
+  hasFeedback = false,
+  onSelectTask,
+}) => {
+  const { order: savedOrder, renameSubFlow, moveTask, reorder } = useSubFlows(runId ?? null);
+
   const taskGroups = useMemo(() => {
     const groupMap = new Map<string, ReviewTask[]>();
 
     // Group tasks by sub_flow
     tasks.forEach(task => {
-      const flowName = task.sub_flow || 'General';
-      const existing = groupMap.get(flowName) || [];
-      groupMap.set(flowName, [...existing, task]);
+      const key = task.sub_flow || UNGROUPED_SUB_FLOW;
+      const existing = groupMap.get(key) || [];
+      groupMap.set(key, [...existing, task]);
     });
 
     // Convert to array and calculate max risk for each group
-    const groups: TaskGroup[] = Array.from(groupMap.entries()).map(([name, groupTasks]) => {
+    const groups: TaskGroup[] = Array.from(groupMap.entries()).map(([key, groupTasks]) => {
       // Sort tasks within group by risk (high first)
       const sortedTasks = [...groupTasks].sort((a, b) => {
         const riskA = a.risk_level || a.stats?.risk || 'low';
@@ -120,14 +262,20 @@ export const TaskFlow: React.FC<TaskFlowProps> = ({ tasks, onSelectTask }) => {
         return getRiskOrder(taskRisk) < getRiskOrder(max) ? taskRisk : max;
       }, 'low' as RiskLevel);
 
-      return { name, tasks: sortedTasks, maxRisk };
+      const name = key === UNGROUPED_SUB_FLOW ? UNGROUPED_LABEL : key;
+      return { key, name, tasks: sortedTasks, maxRisk };
     });
 
-    // Sort groups by max risk (high risk groups first)
-    groups.sort((a, b) => getRiskOrder(a.maxRisk) - getRiskOrder(b.maxRisk));
+    // The reviewer's saved order wins; other groups follow, high risk first.
+    const byKey = new Map(groups.map(group => [group.key, group]));
+    return orderSubFlows(
+      groups.map(group => group.key),
+      savedOrder,
+      (a, b) => getRiskOrder(byKey.get(a)!.maxRisk) - getRiskOrder(byKey.get(b)!.maxRisk)
+    ).map(key => byKey.get(key)!);
+  }, [tasks, savedOrder]);
 
-    return groups;
-  }, [tasks]);
+  const groupKeys = taskGroups.map(group => group.key);
 
   const completedCount = tasks.filter(t => t.status === 'done').length;
 
@@ -138,7 +286,11 @@ export const TaskFlow: React.FC<TaskFlowProps> = ({ tasks, onSelectTask }) => {
           <ICONS.ICON_PLAN size={16} className="text-text-secondary" />
           <h3 className="text-text-primary text-sm font-medium">Review Tasks</h3>
         </div>
-        <div className="text-text-disabled px-4 py-6 text-center text-sm">No tasks generated.</div>
+        <div className="text-text-disabled px-4 py-6 text-center text-sm">
+          {hasFeedback
+            ? 'No tasks generated. The agent left its findings as feedback.'
+            : 'No tasks generated.'}
+        </div>
       </div>
     );
   }
@@ -158,9 +310,20 @@ export const TaskFlow: React.FC<TaskFlowProps> = ({ tasks, onSelectTask }) => {
       <div className="divide-border/30 flex-1 divide-y overflow-y-auto">
         {taskGroups.map((group, idx) => (
           <TaskGroupSection
-            key={group.name}
+            key={group.key}
             group={group}
+            groupKeys={groupKeys}
+            isFirst={idx === 0}
+            isLast={idx === taskGroups.length - 1}
             onSelectTask={onSelectTask}
+            onMove={delta => reorder(moveSubFlow(groupKeys, group.key, delta))}
+            onRename={newName =>
+              renameSubFlow({
+                oldName: group.key === UNGROUPED_SUB_FLOW ? null : group.key,
+                newName,
+              })
+            }
+            onMoveTask={(taskId, subFlow) => moveTask({ taskId, subFlow })}
             defaultExpanded={idx === 0}
           />
         ))}
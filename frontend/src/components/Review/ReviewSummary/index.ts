@@ -4,3 +4,8 @@ export { IssueChecklist } from './IssueChecklist';
 export { KeyFeedback } from './KeyFeedback';
 export { FilesHeatmap } from './FilesHeatmap';
 export { UncoveredFiles } from './UncoveredFiles';
+export { ConflictBanner } from './ConflictBanner';
+export { ReviewTags } from './ReviewTags';
+export { AgentPlan } from './AgentPlan';
+export { AgentActivity } from './AgentActivity';
+export { RunHistory } from './RunHistory';
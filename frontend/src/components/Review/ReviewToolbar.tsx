@@ -1,5 +1,6 @@
 import React from 'react';
 import { ICONS } from '../../constants/icons';
+import { useI18n } from '../../hooks/useI18n';
 import { Select } from '../Common/Select';
 
 interface ReviewToolbarProps {
@@ -21,28 +22,29 @@ export const ReviewToolbar: React.FC<ReviewToolbarProps> = ({
   onTabChange,
   isUpdatingStatus,
 }) => {
+  const { t } = useI18n();
   const STATUS_OPTIONS = [
     {
       value: 'pending',
-      label: 'Todo',
+      label: t('status.todo'),
       icon: ICONS.STATUS_TODO,
       color: 'text-status-todo',
     },
     {
       value: 'in_progress',
-      label: 'In Progress',
+      label: t('status.in_progress'),
       icon: ICONS.STATUS_IN_PROGRESS,
       color: 'text-status-in_progress',
     },
     {
       value: 'done',
-      label: 'Done',
+      label: t('status.done'),
       icon: ICONS.STATUS_DONE,
       color: 'text-status-done',
     },
     {
       value: 'ignored',
-      label: 'Ignored',
+      label: t('status.ignored'),
       icon: ICONS.STATUS_IGNORED,
       color: 'text-status-ignored',
     },
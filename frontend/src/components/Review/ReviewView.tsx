@@ -1,13 +1,19 @@
-import React, { useState, useEffect, Suspense, useMemo } from 'react';
+import React, { useState, useEffect, useCallback, Suspense, useMemo } from 'react';
 import { useQuery } from '@tanstack/react-query';
+import { toast } from 'sonner';
 import { useAppStore } from '../../store';
 
 import { DiffViewer } from '../DiffViewer/DiffViewer';
 import { useReview } from '../../hooks/useReview';
 import { useParsedDiff } from '../../hooks/useParsedDiff';
-import { useTasks } from '../../hooks/useTasks';
+import { useTasks, useTaskEditor, type TaskFormFields } from '../../hooks/useTasks';
 import { useFeedback, useFeedbackComments, useAddComment } from '../../hooks/useFeedback';
 import { useRules } from '../../hooks/useRules';
+import { useIssueChecks } from '../../hooks/useIssueChecks';
+import { useReviewStats } from '../../hooks/useReviewStats';
+import { useFileReviewStatus } from '../../hooks/useFileReviewStatus';
+import { useChecklist } from '../../hooks/useChecklist';
+import { useDiffWindowSync } from '../../hooks/useDiffWindowSync';
 import { FeedbackDetail } from './FeedbackDetail';
 import { ReviewSidebar } from './ReviewSidebar';
 import { TaskDetail } from './TaskDetail';
@@ -20,15 +26,23 @@ import { ConfirmationModal } from '../Common/ConfirmationModal';
 import { useReviews } from '../../hooks/useReviews';
 import { useTauri } from '../../hooks/useTauri';
 import { useDelayedLoading } from '../../hooks/useDelayedLoading';
-import { useFeedbackFilterConfig } from '../../hooks/useSettings';
+import { useFeedbackFilterConfig, useOfflineMode } from '../../hooks/useSettings';
 import type { ReviewTask, Feedback, ReviewRule, DefaultIssueCategory } from '../../types';
 import { ReviewViewSkeleton } from './ReviewViewSkeleton';
 import { AddFeedbackModal } from './AddFeedbackModal';
-import type { DiffFile } from '../../types';
+import { TaskFormModal } from './TaskFormModal';
+import { mergeCandidates } from '../../lib/task-merge';
+import { isOpenBlocking, nextBlockingFeedback } from '../../lib/feedback-order';
+import type { DiffFile, ViewType } from '../../types';
 import { ReviewSummary } from './ReviewSummary';
+import { ICONS } from '../../constants/icons';
 import type { SidebarTab } from './ReviewSidebar';
 
-export const ReviewView: React.FC = () => {
+interface ReviewViewProps {
+  onNavigate?: (view: ViewType) => void;
+}
+
+export const ReviewView: React.FC<ReviewViewProps> = ({ onNavigate }) => {
   const selectedFile = useAppStore(state => state.selectedFile);
   const selectFile = useAppStore(state => state.selectFile);
   const selectedTaskId = useAppStore(state => state.selectedTaskId);
@@ -38,9 +52,9 @@ export const ReviewView: React.FC = () => {
   const reviewId = useAppStore(state => state.reviewId);
   const reviewViewMode = useAppStore(state => state.reviewViewMode);
   const setReviewViewMode = useAppStore(state => state.setReviewViewMode);
+  const setFocusedRuleId = useAppStore(state => state.setFocusedRuleId);
 
-  const { runId, firstRun, error: reviewError, isLoading: isReviewLoading } = useReview(reviewId);
-  const { data: parsedDiff, error: diffError } = useParsedDiff(runId, firstRun?.diff_text ?? null);
+  const { runId, error: reviewError, isLoading: isReviewLoading } = useReview(reviewId);
   const {
     data: tasks = [],
     isLoading: isTasksLoading,
@@ -48,6 +62,14 @@ export const ReviewView: React.FC = () => {
     updateTaskStatus,
     isUpdatingStatus,
   } = useTasks(runId);
+  const {
+    createTask,
+    updateTask,
+    deleteTask,
+    mergeTasks,
+    isMerging: isMergingTasks,
+    isSaving: isSavingTask,
+  } = useTaskEditor(runId);
   const {
     data: feedbacks = [],
     isLoading: isFeedbacksLoading,
@@ -60,17 +82,35 @@ export const ReviewView: React.FC = () => {
     isCreating: isCreatingFeedback,
   } = useFeedback(reviewId);
   const { data: rules = [] } = useRules();
+  const { data: issueChecks = [], isLoading: isChecksLoading } = useIssueChecks(
+    runId ?? undefined
+  );
+  const { data: reviewStats, refetch: refetchReviewStats } = useReviewStats(reviewId);
+  const {
+    data: fileStatuses = [],
+    isLoading: isFileStatusLoading,
+    refetch: refetchFileStatuses,
+  } = useFileReviewStatus(reviewId);
+  const { data: checklist = [] } = useChecklist(reviewId);
+  useDiffWindowSync(reviewId, selectedTaskId);
   const { comments, isLoading: isCommentsLoading } = useFeedbackComments(selectedFeedbackId);
   const addCommentMutation = useAddComment();
   const {
     exportReviewMarkdown,
+    exportReviewGhPayload,
+    exportReview,
+    selectSavePath,
     pushRemoteReview,
     pushRemoteFeedback,
     copyToClipboard,
+    getTaskMarkdown,
+    getReviewSummaryLine,
+    getFeedbackMarkdown,
     getRepoRootForReview,
     getDefaultIssueCategories,
+    openDiffWindow,
   } = useTauri();
-  const { data: allReviews = [] } = useReviews();
+  const { data: allReviews = [], setDecision } = useReviews();
   const { config: filterConfig } = useFeedbackFilterConfig();
 
   // Fetch the local repository root for this review (matched via remote URL)
@@ -80,12 +120,17 @@ export const ReviewView: React.FC = () => {
     enabled: !!reviewId,
     staleTime: Infinity, // Repo root doesn't change during a session
   });
+  const { data: parsedDiff, error: diffError } = useParsedDiff(runId, repoRoot);
 
   const [activeTab, setActiveTab] = useState<'diff' | 'description' | 'diagram'>('description');
   const [sidebarTab, setSidebarTab] = useState<SidebarTab>('tasks');
+  const [showFileTree, setShowFileTree] = useState(false);
   const [isModalOpen, setIsModalOpen] = useState(false);
   const [isPushModalOpen, setIsPushModalOpen] = useState(false);
   const [isDeleteFeedbackModalOpen, setIsDeleteFeedbackModalOpen] = useState(false);
+  const [isDeleteTaskModalOpen, setIsDeleteTaskModalOpen] = useState(false);
+  // `task` is null when adding a new task.
+  const [taskForm, setTaskForm] = useState<{ task: ReviewTask | null } | null>(null);
 
   // Feedback Modal State
   const [isAddFeedbackModalOpen, setIsAddFeedbackModalOpen] = useState(false);
@@ -97,16 +142,41 @@ export const ReviewView: React.FC = () => {
   }>({ type: 'global' });
 
   useEffect(() => {
-    if (tasks.length > 0 && !selectedTaskId && !isTasksLoading && reviewViewMode === 'review' && sidebarTab === 'tasks') {
+    if (tasks.length > 0 && !selectedTaskId && !isTasksLoading && reviewViewMode === 'review' && sidebarTab === 'tasks' && !showFileTree) {
       selectTask(tasks[0].id);
     }
-  }, [tasks, selectedTaskId, isTasksLoading, selectTask, reviewViewMode, sidebarTab]);
+  }, [tasks, selectedTaskId, isTasksLoading, selectTask, reviewViewMode, sidebarTab, showFileTree]);
+
+  // Risk and impact counts follow task and feedback edits; diff totals are cached server-side.
+  const statsInputs = JSON.stringify([
+    tasks.map(task => task.stats.risk),
+    feedbacks.map(feedback => [feedback.impact, feedback.status]),
+  ]);
+  useEffect(() => {
+    if (reviewId) refetchReviewStats();
+  }, [statsInputs, reviewId, refetchReviewStats]);
+
+  // The file tree counts open/closed tasks and feedback per file.
+  const fileStatusInputs = JSON.stringify([
+    tasks.map(task => [task.status, task.diff_refs.map(ref => ref.file)]),
+    feedbacks.map(feedback => [feedback.status, feedback.anchor?.file_path]),
+  ]);
+  useEffect(() => {
+    if (reviewId) refetchFileStatuses();
+  }, [fileStatusInputs, reviewId, refetchFileStatuses]);
 
   const handleStartReview = () => {
     setReviewViewMode('review');
-    setSidebarTab('tasks');
     if (tasks.length > 0) {
+      setSidebarTab('tasks');
+      setShowFileTree(false);
       selectTask(tasks[0].id);
+    } else if (feedbacks.length > 0) {
+      // Feedback-only runs start on their feedback.
+      setSidebarTab('feedback');
+      selectFeedback(feedbacks[0].id);
+    } else {
+      setSidebarTab('tasks');
     }
   };
 
@@ -116,12 +186,23 @@ export const ReviewView: React.FC = () => {
     setSidebarTab('tasks');
   };
 
+  const handleJumpToRule = (ruleId: string) => {
+    setFocusedRuleId(ruleId);
+    onNavigate?.('rules');
+  };
+
   const handleBackToSummary = () => {
     setReviewViewMode('summary');
     selectTask(null);
     selectFeedback(null);
   };
 
+  const handleSelectFileFromTree = (fileName: string) => {
+    const file = parsedDiff?.files?.find(f => f.name === fileName || f.new_path === fileName);
+    // Files only stale feedback points at aren't in the diff any more.
+    if (file) selectFile(file);
+  };
+
   const handleSelectFileFromSummary = (fileName: string) => {
     const file = parsedDiff?.files?.find(f => f.name === fileName || f.new_path === fileName);
     if (file) {
@@ -137,6 +218,40 @@ export const ReviewView: React.FC = () => {
     setSidebarTab('feedback');
   };
 
+  const openBlockingCount = feedbacks.filter(isOpenBlocking).length;
+
+  // Steps through the open blockers, so none is missed before approving.
+  const handleNextBlocking = useCallback(() => {
+    const next = nextBlockingFeedback(feedbacks, selectedFeedbackId);
+    if (!next) return;
+    selectFeedback(next.id);
+    setReviewViewMode('review');
+    setSidebarTab('feedback');
+    const anchorPath = next.anchor?.file_path;
+    const file = anchorPath
+      ? parsedDiff?.files?.find(f => f.name === anchorPath || f.new_path === anchorPath)
+      : undefined;
+    if (file) selectFile(file);
+  }, [feedbacks, selectedFeedbackId, selectFeedback, setReviewViewMode, parsedDiff, selectFile]);
+
+  // `b` jumps to the next blocker, unless the user is typing.
+  useEffect(() => {
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.key !== 'b' || e.metaKey || e.ctrlKey || e.altKey || e.repeat) return;
+      const target = e.target as HTMLElement | null;
+      if (
+        target &&
+        (target.isContentEditable || ['INPUT', 'TEXTAREA', 'SELECT'].includes(target.tagName))
+      ) {
+        return;
+      }
+      e.preventDefault();
+      handleNextBlocking();
+    };
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [handleNextBlocking]);
+
   const selectedTask = tasks.find((t: ReviewTask) => t.id === selectedTaskId);
   const selectedFeedback: Feedback | null =
     feedbacks.find((f: Feedback) => f.id === selectedFeedbackId) || null;
@@ -196,6 +311,34 @@ export const ReviewView: React.FC = () => {
     }
   };
 
+  const handleSaveTask = async (fields: TaskFormFields) => {
+    if (!taskForm) return;
+    const saved = taskForm.task
+      ? await updateTask({ taskId: taskForm.task.id, fields })
+      : await createTask(fields);
+    setTaskForm(null);
+    selectTask(saved.id);
+  };
+
+  const handleMergeTask = async (other: ReviewTask) => {
+    if (!selectedTaskId) return;
+    try {
+      const merged = await mergeTasks([selectedTaskId, other.id]);
+      toast('Tasks merged', { description: merged.title });
+      selectTask(merged.id);
+    } catch {
+      // Reported by the mutation.
+    }
+  };
+
+  const confirmDeleteTask = () => {
+    if (selectedTaskId) {
+      deleteTask(selectedTaskId);
+      selectTask(null);
+      setIsDeleteTaskModalOpen(false);
+    }
+  };
+
   const handleAddComment = (body: string) => {
     if (selectedFeedbackId) {
       addCommentMutation.mutate({ feedbackId: selectedFeedbackId, body });
@@ -207,13 +350,18 @@ export const ReviewView: React.FC = () => {
   };
 
   const currentReview = allReviews.find(r => r.id === reviewId);
-  const remoteProviderName =
-    currentReview?.source?.type === 'gitlab_mr'
+  const { offline: isOffline } = useOfflineMode();
+  // Offline mode hides every push-to-remote affordance.
+  const remoteProviderName = isOffline
+    ? null
+    : currentReview?.source?.type === 'gitlab_mr'
       ? 'GitLab'
-      : currentReview?.source?.type === 'github_pr' ||
-          (currentReview?.source?.type as string) === 'git_hub_pr'
-        ? 'GitHub'
-        : null;
+      : currentReview?.source?.type === 'gitea_pr'
+        ? 'Gitea'
+        : currentReview?.source?.type === 'github_pr' ||
+            (currentReview?.source?.type as string) === 'git_hub_pr'
+          ? 'GitHub'
+          : null;
 
   const handleExport = async (
     format: ExportFormat,
@@ -227,8 +375,50 @@ export const ReviewView: React.FC = () => {
       await copyToClipboard(markdown);
       // Success alert is handled in SelectionModal or implied by modal closing
       // but we return nothing to keep it generic
+    } else if (format === 'markdown-file') {
+      const path = await selectSavePath(`review-${reviewId.slice(0, 8)}.md`, 'md');
+      if (!path) return;
+      return exportReviewMarkdown(reviewId, selectedTasks, selectedFeedbacks, path);
+    } else if (format === 'gh-json') {
+      const path = await selectSavePath(`review-${reviewId.slice(0, 8)}.json`, 'json');
+      if (!path) return;
+      const result = await exportReviewGhPayload(reviewId, selectedTasks, selectedFeedbacks, path);
+      const endpoint = result.endpoint ?? 'repos/OWNER/REPO/pulls/NUMBER/reviews';
+      const command = `gh api --method POST ${endpoint} --input ${path}`;
+      await copyToClipboard(command);
+      if (result.skipped.length > 0) {
+        toast(`${result.skipped.length} comment(s) left out`, {
+          description: result.skipped.map(s => `${s.title}: ${s.reason}`).join('\n'),
+        });
+      }
+      return `Saved to ${path}. The gh api command is on your clipboard.`;
+    } else if (format === 'pdf') {
+      const path = await selectSavePath(`review-${reviewId.slice(0, 8)}.pdf`, 'pdf');
+      if (!path) return;
+      return exportReview(reviewId, 'pdf', {
+        selectedTasks,
+        selectedFeedbacks,
+        outputPath: path,
+      });
     } else {
-      const url = await pushRemoteReview(reviewId, selectedTasks, selectedFeedbacks);
+      const { url, anchoring } = await pushRemoteReview(
+        reviewId,
+        selectedTasks,
+        selectedFeedbacks
+      );
+      if (anchoring.unplaced.length > 0) {
+        toast(`${anchoring.unplaced.length} comment(s) posted in the review summary`, {
+          description: 'Their lines changed on the pull request since the review.',
+        });
+      }
+      if (anchoring.moved > 0 || anchoring.unplaced_locations > 0) {
+        const notes = [
+          anchoring.moved > 0 && `${anchoring.moved} comment(s) moved to their new lines`,
+          anchoring.unplaced_locations > 0 &&
+            `${anchoring.unplaced_locations} other location(s) no longer match a line`,
+        ].filter(Boolean);
+        toast('Comments fitted to the current diff', { description: notes.join('\n') });
+      }
       return url;
     }
   };
@@ -243,11 +433,42 @@ export const ReviewView: React.FC = () => {
     return await pushRemoteFeedback(selectedFeedbackId);
   };
 
+  const copyItemMarkdown = async (render: () => Promise<string>) => {
+    try {
+      await copyToClipboard(await render());
+      toast('Copied as Markdown');
+    } catch (err) {
+      toast('Failed to copy', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+    }
+  };
+
+  const handleCopySummary = async () => {
+    if (!reviewId) return;
+    try {
+      await copyToClipboard(await getReviewSummaryLine(reviewId));
+      toast('Copied summary');
+    } catch (err) {
+      toast('Failed to copy', {
+        description: err instanceof Error ? err.message : String(err),
+      });
+    }
+  };
+
   const handleAddGlobalFeedback = () => {
     setAddFeedbackContext({ type: 'global' });
     setIsAddFeedbackModalOpen(true);
   };
 
+  const handleOpenDiffWindow = () => {
+    openDiffWindow().catch(error =>
+      toast('Failed to open diff window', {
+        description: error instanceof Error ? error.message : String(error),
+      })
+    );
+  };
+
   const handleAddLineFeedback = (file: DiffFile, line: number, side: 'old' | 'new') => {
     setAddFeedbackContext({
       type: 'line',
@@ -331,6 +552,7 @@ export const ReviewView: React.FC = () => {
           onConfirm={handleExport}
           tasks={tasks}
           feedbacks={feedbacks}
+          checklist={checklist}
           remoteProviderName={remoteProviderName}
         />
       </div>
@@ -341,9 +563,17 @@ export const ReviewView: React.FC = () => {
   return (
     <div className="bg-bg-primary flex h-full">
       <ReviewSidebar
+        reviewId={reviewId}
         sidebarTab={sidebarTab}
         tasks={tasks}
         feedbacks={feedbacks}
+        issueChecks={issueChecks}
+        isChecksLoading={isChecksLoading}
+        stats={reviewStats}
+        fileStatuses={fileStatuses}
+        isFileStatusLoading={isFileStatusLoading}
+        showFileTree={showFileTree}
+        selectedFile={selectedFile ? selectedFile.name || selectedFile.new_path : null}
         selectedTaskId={selectedTaskId}
         selectedFeedbackId={selectedFeedbackId}
         isTasksLoading={isTasksLoading}
@@ -353,8 +583,14 @@ export const ReviewView: React.FC = () => {
         onSidebarTabChange={setSidebarTab}
         onSelectTask={selectTask}
         onSelectFeedback={selectFeedback}
+        openBlockingCount={openBlockingCount}
+        onNextBlocking={handleNextBlocking}
+        onShowFileTreeChange={setShowFileTree}
+        onSelectFile={handleSelectFileFromTree}
         onOpenExportModal={() => setIsModalOpen(true)}
+        onCopySummary={handleCopySummary}
         onAddGlobalFeedback={handleAddGlobalFeedback}
+        onAddTask={() => setTaskForm({ task: null })}
         onBackToSummary={handleBackToSummary}
         rulesById={rulesById}
         categoriesById={categoriesById}
@@ -378,7 +614,14 @@ export const ReviewView: React.FC = () => {
               onDelete={handleDeleteFeedback}
               onAddComment={handleAddComment}
               onPushToRemote={handlePushFeedbackToRemote}
+              onCopyMarkdown={
+                selectedFeedback
+                  ? () => copyItemMarkdown(() => getFeedbackMarkdown(selectedFeedback.id))
+                  : undefined
+              }
               remoteProviderName={remoteProviderName}
+              onJumpToRule={onNavigate ? handleJumpToRule : undefined}
+              onOpenFile={handleSelectFileFromSummary}
               isUpdatingStatus={isUpdatingFeedbackStatus}
               isUpdatingImpact={isUpdatingFeedbackImpact}
               isAddingComment={addCommentMutation.isPending}
@@ -405,11 +648,26 @@ export const ReviewView: React.FC = () => {
                   onStatusChange={handleStatusChange}
                   isUpdatingStatus={isUpdatingStatus}
                   onAddFeedback={handleAddLineFeedback}
+                  onEditTask={() => setTaskForm({ task: selectedTask })}
+                  onDeleteTask={() => setIsDeleteTaskModalOpen(true)}
+                  mergeCandidates={mergeCandidates(selectedTask, tasks)}
+                  onMergeTask={other => void handleMergeTask(other)}
+                  isMerging={isMergingTasks}
+                  onCopyMarkdown={() => copyItemMarkdown(() => getTaskMarkdown(selectedTask.id))}
+                  onOpenDiffWindow={handleOpenDiffWindow}
                   repoRoot={repoRoot}
                 />
               ) : parsedDiff ? (
                 <div className="flex flex-1 flex-col">
-                  <div className="border-border bg-bg-primary h-10 border-b" />
+                  <div className="border-border bg-bg-primary flex h-10 items-center justify-end border-b px-3">
+                    <button
+                      onClick={handleOpenDiffWindow}
+                      title="Open diff in a new window"
+                      className="text-text-tertiary hover:text-text-primary hover:bg-bg-tertiary rounded p-1 transition-colors"
+                    >
+                      <ICONS.ACTION_OPEN_WINDOW size={14} />
+                    </button>
+                  </div>
                   <div className="relative flex-1">
                     <DiffViewer
                       files={parsedDiff.files || []}
@@ -417,6 +675,7 @@ export const ReviewView: React.FC = () => {
                       onSelectFile={selectFile}
                       onAddFeedback={handleAddLineFeedback}
                       repoRoot={repoRoot}
+                      runId={runId}
                     />
                   </div>
                 </div>
@@ -433,7 +692,10 @@ export const ReviewView: React.FC = () => {
         onConfirm={handleExport}
         tasks={tasks}
         feedbacks={feedbacks}
+        checklist={checklist}
         remoteProviderName={remoteProviderName}
+        decision={currentReview?.decision}
+        onDecisionChange={decision => reviewId && setDecision.mutate({ reviewId, decision })}
       />
 
       <PushToVcsModal
@@ -451,6 +713,29 @@ export const ReviewView: React.FC = () => {
         isAdding={isCreatingFeedback}
       />
 
+      <TaskFormModal
+        key={taskForm?.task?.id ?? 'new'}
+        isOpen={taskForm !== null}
+        onClose={() => setTaskForm(null)}
+        onSave={fields => void handleSaveTask(fields).catch(() => undefined)}
+        task={taskForm?.task}
+        availableFiles={(parsedDiff?.files || []).map(file => file.name)}
+        subFlows={Array.from(
+          new Set(tasks.map(task => task.sub_flow).filter((name): name is string => !!name))
+        )}
+        isSaving={isSavingTask}
+      />
+
+      <ConfirmationModal
+        isOpen={isDeleteTaskModalOpen}
+        onClose={() => setIsDeleteTaskModalOpen(false)}
+        onConfirm={confirmDeleteTask}
+        title="Delete Task"
+        message="Delete this task and the feedback attached to it? This action cannot be undone."
+        confirmLabel="Delete"
+        confirmVariant="danger"
+      />
+
       <ConfirmationModal
         isOpen={isDeleteFeedbackModalOpen}
         onClose={() => setIsDeleteFeedbackModalOpen(false)}
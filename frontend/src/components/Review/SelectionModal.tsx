@@ -1,10 +1,16 @@
 import React, { useState, useEffect } from 'react';
 import { ICONS } from '../../constants/icons';
 import { toast } from 'sonner';
-import type { ReviewTask, Feedback } from '../../types';
+import type { ReviewTask, Feedback, ReviewDecision, ChecklistItem } from '../../types';
 import { useTauri } from '../../hooks/useTauri';
+import {
+  DECISION_LABELS,
+  decisionMismatch,
+  suggestedDecision,
+} from '../../lib/review-decision';
+import { AUTHOR_KIND_LABELS, authorKind } from '../../lib/author';
 
-export type ExportFormat = 'markdown' | 'remote';
+export type ExportFormat = 'markdown' | 'markdown-file' | 'gh-json' | 'pdf' | 'remote';
 
 interface SelectionModalProps {
   isOpen: boolean;
@@ -16,7 +22,11 @@ interface SelectionModalProps {
   ) => Promise<string | void>;
   tasks: ReviewTask[];
   feedbacks: Feedback[];
+  checklist?: ChecklistItem[];
   remoteProviderName?: string | null;
+  /** Decision the user picked; null falls back to the suggested one. */
+  decision?: ReviewDecision | null;
+  onDecisionChange?: (decision: ReviewDecision) => void;
 }
 
 export const SelectionModal: React.FC<SelectionModalProps> = ({
@@ -25,7 +35,10 @@ export const SelectionModal: React.FC<SelectionModalProps> = ({
   onConfirm,
   tasks,
   feedbacks,
+  checklist = [],
   remoteProviderName,
+  decision,
+  onDecisionChange,
 }) => {
   const { openUrl } = useTauri();
   const [format, setFormat] = useState<ExportFormat>('markdown');
@@ -54,6 +67,13 @@ export const SelectionModal: React.FC<SelectionModalProps> = ({
 
   if (!isOpen) return null;
 
+  const authorKinds = (['user', 'agent', 'imported'] as const).filter(kind =>
+    feedbacks.some(f => authorKind(f.author) === kind)
+  );
+
+  const effectiveDecision = decision ?? suggestedDecision(feedbacks, checklist);
+  const mismatch = decisionMismatch(effectiveDecision, feedbacks, checklist);
+
   const toggleTask = (id: string) => {
     const next = new Set(selectedTasks);
     if (next.has(id)) next.delete(id);
@@ -84,6 +104,15 @@ export const SelectionModal: React.FC<SelectionModalProps> = ({
         toast('Copied to Clipboard', {
           description: 'Review markdown is ready to paste.',
         });
+      } else if (format === 'markdown-file' && result) {
+        onClose();
+        toast('Review Markdown Saved', { description: result });
+      } else if (format === 'gh-json' && result) {
+        onClose();
+        toast('Review JSON Saved', { description: result });
+      } else if (format === 'pdf' && result) {
+        onClose();
+        toast('Review PDF Saved', { description: result });
       }
     } catch (e) {
       setError(String(e));
@@ -180,6 +209,99 @@ export const SelectionModal: React.FC<SelectionModalProps> = ({
                 </div>
               </button>
 
+              <button
+                onClick={() => setFormat('markdown-file')}
+                className={`group relative flex flex-row items-center gap-3 rounded-lg border px-4 py-3 transition-all ${
+                  format === 'markdown-file'
+                    ? 'border-accent/30 bg-accent/5 text-text-primary shadow-sm'
+                    : 'border-border/30 bg-bg-secondary/30 text-text-secondary hover:border-border/50 hover:bg-bg-secondary'
+                }`}
+              >
+                {format === 'markdown-file' && (
+                  <div className="absolute top-2 right-2 flex items-center justify-center">
+                    <div className="bg-accent animate-in fade-in zoom-in-50 h-1.5 w-1.5 rounded-full duration-200" />
+                  </div>
+                )}
+                <div
+                  className={`rounded-md p-2 ${format === 'markdown-file' ? 'bg-accent/10' : 'bg-bg-tertiary'}`}
+                >
+                  <ICONS.ICON_FILES
+                    size={20}
+                    className={
+                      format === 'markdown-file'
+                        ? 'text-accent'
+                        : 'text-text-disabled group-hover:text-text-secondary transition-colors'
+                    }
+                  />
+                </div>
+                <div className="text-left">
+                  <p className="mb-0.5 text-sm font-medium">Markdown file</p>
+                  <p className="text-text-tertiary text-[10px]">Save with diagram sources</p>
+                </div>
+              </button>
+
+              <button
+                onClick={() => setFormat('gh-json')}
+                className={`group relative flex flex-row items-center gap-3 rounded-lg border px-4 py-3 transition-all ${
+                  format === 'gh-json'
+                    ? 'border-accent/30 bg-accent/5 text-text-primary shadow-sm'
+                    : 'border-border/30 bg-bg-secondary/30 text-text-secondary hover:border-border/50 hover:bg-bg-secondary'
+                }`}
+              >
+                {format === 'gh-json' && (
+                  <div className="absolute top-2 right-2 flex items-center justify-center">
+                    <div className="bg-accent animate-in fade-in zoom-in-50 h-1.5 w-1.5 rounded-full duration-200" />
+                  </div>
+                )}
+                <div
+                  className={`rounded-md p-2 ${format === 'gh-json' ? 'bg-accent/10' : 'bg-bg-tertiary'}`}
+                >
+                  <ICONS.ICON_GITHUB
+                    size={20}
+                    className={
+                      format === 'gh-json'
+                        ? 'text-accent'
+                        : 'text-text-disabled group-hover:text-text-secondary transition-colors'
+                    }
+                  />
+                </div>
+                <div className="text-left">
+                  <p className="mb-0.5 text-sm font-medium">gh api JSON</p>
+                  <p className="text-text-tertiary text-[10px]">Save a review payload file</p>
+                </div>
+              </button>
+
+              <button
+                onClick={() => setFormat('pdf')}
+                className={`group relative flex flex-row items-center gap-3 rounded-lg border px-4 py-3 transition-all ${
+                  format === 'pdf'
+                    ? 'border-accent/30 bg-accent/5 text-text-primary shadow-sm'
+                    : 'border-border/30 bg-bg-secondary/30 text-text-secondary hover:border-border/50 hover:bg-bg-secondary'
+                }`}
+              >
+                {format === 'pdf' && (
+                  <div className="absolute top-2 right-2 flex items-center justify-center">
+                    <div className="bg-accent animate-in fade-in zoom-in-50 h-1.5 w-1.5 rounded-full duration-200" />
+                  </div>
+                )}
+                <div
+                  className={`rounded-md p-2 ${format === 'pdf' ? 'bg-accent/10' : 'bg-bg-tertiary'}`}
+                >
+                  <ICONS.ICON_PDF
+                    size={20}
+                    className={
+                      format === 'pdf'
+                        ? 'text-accent'
+                        : 'text-text-disabled group-hover:text-text-secondary transition-colors'
+                    }
+                  />
+                </div>
+                <div className="text-left">
+                  <p className="mb-0.5 text-sm font-medium">PDF</p>
+                  <p className="text-text-tertiary text-[10px]">Save a shareable document</p>
+                </div>
+              </button>
+
               <button
                 disabled={!isRemoteAvailable}
                 onClick={() => setFormat('remote')}
@@ -222,6 +344,41 @@ export const SelectionModal: React.FC<SelectionModalProps> = ({
             </div>
           </section>
 
+          {/* Review Decision */}
+          {format === 'remote' && onDecisionChange && (
+            <section>
+              <h4 className="text-text-tertiary mb-3 px-1 text-[11px] font-bold tracking-wider uppercase">
+                Decision
+              </h4>
+              <div className="bg-bg-tertiary border-border/50 flex rounded-md border p-0.5">
+                {(Object.keys(DECISION_LABELS) as ReviewDecision[]).map(option => (
+                  <button
+                    key={option}
+                    onClick={() => onDecisionChange(option)}
+                    className={`flex-1 rounded-[3px] py-1.5 text-xs font-medium transition-colors ${
+                      effectiveDecision === option
+                        ? 'bg-bg-primary text-text-primary shadow-sm'
+                        : 'text-text-disabled hover:text-text-secondary'
+                    }`}
+                  >
+                    {DECISION_LABELS[option]}
+                  </button>
+                ))}
+              </div>
+              {!decision && (
+                <p className="text-text-tertiary mt-2 px-1 text-[10px]">
+                  Suggested from the review&apos;s blocking feedback.
+                </p>
+              )}
+              {mismatch && (
+                <div className="mt-2 flex items-center gap-2 rounded-lg border border-yellow-500/20 bg-yellow-500/10 p-2.5 text-xs text-yellow-500">
+                  <ICONS.ICON_WARNING size={14} />
+                  {mismatch}
+                </div>
+              )}
+            </section>
+          )}
+
           {/* Task Selection */}
           <section>
             <div className="mb-3 flex items-center justify-between px-1">
@@ -299,18 +456,35 @@ export const SelectionModal: React.FC<SelectionModalProps> = ({
               <h4 className="text-text-tertiary text-[11px] font-bold tracking-wider uppercase">
                 Feedback ({selectedFeedbacks.size}/{feedbacks.length})
               </h4>
-              <button
-                onClick={() =>
-                  setSelectedFeedbacks(
-                    selectedFeedbacks.size === feedbacks.length
-                      ? new Set()
-                      : new Set(feedbacks.map(f => f.id))
-                  )
-                }
-                className="text-accent hover:text-accent/80 text-[10px] font-medium transition-colors"
-              >
-                {selectedFeedbacks.size === feedbacks.length ? 'Deselect All' : 'Select All'}
-              </button>
+              <div className="flex items-center gap-3">
+                {authorKinds.length > 1 &&
+                  authorKinds.map(kind => (
+                    <button
+                      key={kind}
+                      onClick={() =>
+                        setSelectedFeedbacks(
+                          new Set(feedbacks.filter(f => authorKind(f.author) === kind).map(f => f.id))
+                        )
+                      }
+                      className="text-text-tertiary hover:text-text-primary text-[10px] font-medium transition-colors"
+                      title={`Select only ${kind === 'user' ? 'your' : AUTHOR_KIND_LABELS[kind]} feedback`}
+                    >
+                      Only {AUTHOR_KIND_LABELS[kind]}
+                    </button>
+                  ))}
+                <button
+                  onClick={() =>
+                    setSelectedFeedbacks(
+                      selectedFeedbacks.size === feedbacks.length
+                        ? new Set()
+                        : new Set(feedbacks.map(f => f.id))
+                    )
+                  }
+                  className="text-accent hover:text-accent/80 text-[10px] font-medium transition-colors"
+                >
+                  {selectedFeedbacks.size === feedbacks.length ? 'Deselect All' : 'Select All'}
+                </button>
+              </div>
             </div>
             <div className="grid grid-cols-1 gap-2">
               {feedbacks.length === 0 ? (
@@ -359,6 +533,11 @@ export const SelectionModal: React.FC<SelectionModalProps> = ({
                           >
                             {fb.impact}
                           </span>
+                          {authorKinds.length > 1 && (
+                            <span className="text-text-tertiary text-[9px]" title={fb.author}>
+                              {AUTHOR_KIND_LABELS[authorKind(fb.author)]}
+                            </span>
+                          )}
                         </div>
                       </div>
                     </label>
@@ -411,6 +590,21 @@ export const SelectionModal: React.FC<SelectionModalProps> = ({
                         <RemoteIcon size={14} weight="bold" />
                         Post to remote
                       </>
+                    ) : format === 'gh-json' ? (
+                      <>
+                        <ICONS.ACTION_EXPORT size={14} weight="bold" />
+                        Save JSON
+                      </>
+                    ) : format === 'pdf' ? (
+                      <>
+                        <ICONS.ACTION_EXPORT size={14} weight="bold" />
+                        Save PDF
+                      </>
+                    ) : format === 'markdown-file' ? (
+                      <>
+                        <ICONS.ACTION_EXPORT size={14} weight="bold" />
+                        Save Markdown
+                      </>
                     ) : (
                       <>
                         <ICONS.ACTION_COPY size={14} weight="bold" />
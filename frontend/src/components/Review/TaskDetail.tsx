@@ -7,6 +7,7 @@ import { DiffViewer } from '../DiffViewer/DiffViewer';
 import { Select } from '../Common/Select';
 import type { ReviewTask, ParsedDiff, DiffFile, DiffRef, HunkRef } from '../../types';
 import { ICONS } from '../../constants/icons';
+import { useI18n } from '../../hooks/useI18n';
 import { Tooltip } from '../Common/Tooltip';
 
 interface TaskDetailProps {
@@ -26,6 +27,15 @@ interface TaskDetailProps {
   onStatusChange?: (status: string) => void;
   isUpdatingStatus?: boolean;
   onAddFeedback?: (file: DiffFile, line: number, side: 'old' | 'new') => void;
+  onEditTask?: () => void;
+  onDeleteTask?: () => void;
+  onCopyMarkdown?: () => void;
+  /** Shows the diff in its own window, e.g. on a second monitor. */
+  onOpenDiffWindow?: () => void;
+  /** Tasks covering mostly the same files, offered for merging into this one. */
+  mergeCandidates?: ReviewTask[];
+  onMergeTask?: (other: ReviewTask) => void;
+  isMerging?: boolean;
   repoRoot?: string | null;
 }
 
@@ -41,8 +51,16 @@ export const TaskDetail: React.FC<TaskDetailProps> = ({
   onStatusChange,
   isUpdatingStatus,
   onAddFeedback,
+  onEditTask,
+  onDeleteTask,
+  onCopyMarkdown,
+  onOpenDiffWindow,
+  mergeCandidates = [],
+  onMergeTask,
+  isMerging,
   repoRoot,
 }) => {
+  const { t } = useI18n();
   if (!task) {
     return (
       <div className="text-text-disabled flex flex-1 items-center justify-center">
@@ -59,25 +77,25 @@ export const TaskDetail: React.FC<TaskDetailProps> = ({
   const STATUS_OPTIONS = [
     {
       value: 'todo',
-      label: 'Todo',
+      label: t('status.todo'),
       icon: ICONS.STATUS_TODO,
       color: 'text-status-todo',
     },
     {
       value: 'in_progress',
-      label: 'In Progress',
+      label: t('status.in_progress'),
       icon: ICONS.STATUS_IN_PROGRESS,
       color: 'text-status-in_progress',
     },
     {
       value: 'done',
-      label: 'Done',
+      label: t('status.done'),
       icon: ICONS.STATUS_DONE,
       color: 'text-status-done',
     },
     {
       value: 'ignored',
-      label: 'Ignored',
+      label: t('status.ignored'),
       icon: ICONS.STATUS_IGNORED,
       color: 'text-status-ignored',
     },
@@ -171,6 +189,50 @@ export const TaskDetail: React.FC<TaskDetailProps> = ({
           <h2 className="text-text-primary hover:text-brand flex-1 cursor-pointer truncate text-sm font-medium">
             {task.title}
           </h2>
+          {onCopyMarkdown && (
+            <button
+              onClick={onCopyMarkdown}
+              title="Copy as Markdown"
+              className="text-text-tertiary hover:text-text-primary hover:bg-bg-tertiary flex-shrink-0 rounded p-1 transition-colors"
+            >
+              <ICONS.ACTION_COPY size={14} />
+            </button>
+          )}
+          {onOpenDiffWindow && (
+            <button
+              onClick={onOpenDiffWindow}
+              title="Open diff in a new window"
+              className="text-text-tertiary hover:text-text-primary hover:bg-bg-tertiary flex-shrink-0 rounded p-1 transition-colors"
+            >
+              <ICONS.ACTION_OPEN_WINDOW size={14} />
+            </button>
+          )}
+          {!task.ai_generated && (
+            <div className="flex flex-shrink-0 items-center gap-1">
+              <span className="bg-bg-surface text-text-tertiary border-border/50 flex items-center gap-1 rounded-full border px-2 py-0.5 text-[10px] font-medium">
+                <ICONS.ICON_USER_TASK size={12} />
+                Added by you
+              </span>
+              {onEditTask && (
+                <button
+                  onClick={onEditTask}
+                  title="Edit task"
+                  className="text-text-tertiary hover:text-text-primary hover:bg-bg-tertiary rounded p-1 transition-colors"
+                >
+                  <ICONS.ACTION_EDIT size={14} />
+                </button>
+              )}
+              {onDeleteTask && (
+                <button
+                  onClick={onDeleteTask}
+                  title="Delete task"
+                  className="text-text-tertiary hover:bg-bg-tertiary rounded p-1 transition-colors hover:text-red-400"
+                >
+                  <ICONS.ACTION_TRASH size={14} />
+                </button>
+              )}
+            </div>
+          )}
         </div>
 
         <div className="flex w-full items-center justify-between gap-2">
@@ -212,6 +274,29 @@ export const TaskDetail: React.FC<TaskDetailProps> = ({
             disabled={isUpdatingStatus}
           />
         </div>
+
+        {onMergeTask && mergeCandidates.length > 0 && (
+          <div className="mt-2 flex flex-col gap-1">
+            {mergeCandidates.map(other => (
+              <div
+                key={other.id}
+                className="bg-bg-tertiary/40 border-border/50 flex items-center gap-2 rounded border px-2 py-1 text-[11px]"
+              >
+                <span className="text-text-tertiary min-w-0 flex-1 truncate">
+                  Covers mostly the same files as{' '}
+                  <span className="text-text-secondary">{other.title}</span>
+                </span>
+                <button
+                  onClick={() => onMergeTask(other)}
+                  disabled={isMerging}
+                  className="text-brand hover:bg-bg-tertiary shrink-0 rounded px-1.5 py-0.5 font-medium transition-colors disabled:opacity-50"
+                >
+                  Merge
+                </button>
+              </div>
+            ))}
+          </div>
+        )}
       </div>
 
       <div className="relative flex-1 overflow-hidden">
@@ -224,6 +309,7 @@ export const TaskDetail: React.FC<TaskDetailProps> = ({
               highlightedHunks={highlightedHunks}
               onAddFeedback={onAddFeedback}
               repoRoot={repoRoot}
+              runId={task?.run_id}
             />
           )}
 
@@ -0,0 +1,152 @@
+import React, { useState } from 'react';
+import { ICONS } from '../../constants/icons';
+import type { ReviewTask } from '../../types';
+import type { TaskFormFields } from '../../hooks/useTasks';
+
+interface TaskFormModalProps {
+  isOpen: boolean;
+  onClose: () => void;
+  onSave: (fields: TaskFormFields) => void;
+  /** Task being edited; a new task is created when absent. */
+  task?: ReviewTask | null;
+  availableFiles: string[];
+  subFlows: string[];
+  isSaving: boolean;
+}
+
+export const TaskFormModal: React.FC<TaskFormModalProps> = ({
+  isOpen,
+  onClose,
+  onSave,
+  task,
+  availableFiles,
+  subFlows,
+  isSaving,
+}) => {
+  const [title, setTitle] = useState(task?.title ?? '');
+  const [description, setDescription] = useState(task?.description ?? '');
+  const [files, setFiles] = useState<Set<string>>(new Set(task?.files ?? []));
+  const [subFlow, setSubFlow] = useState(task?.sub_flow ?? '');
+
+  if (!isOpen) return null;
+
+  const isEditing = Boolean(task);
+  // Keep files the task already references even if they left the diff.
+  const fileOptions = Array.from(new Set([...availableFiles, ...(task?.files ?? [])]));
+
+  const toggleFile = (file: string) => {
+    const next = new Set(files);
+    if (next.has(file)) next.delete(file);
+    else next.add(file);
+    setFiles(next);
+  };
+
+  const handleSubmit = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!title.trim()) return;
+    onSave({
+      title,
+      description,
+      files: fileOptions.filter(file => files.has(file)),
+      subFlow: subFlow.trim() || null,
+    });
+  };
+
+  return (
+    <div className="fixed inset-0 z-50 flex items-center justify-center bg-black/50 backdrop-blur-sm">
+      <div className="bg-bg-primary border-border w-full max-w-lg rounded-xl border shadow-xl">
+        <div className="border-border flex items-center justify-between border-b px-4 py-3">
+          <h2 className="text-text-primary text-sm font-medium">
+            {isEditing ? 'Edit Task' : 'Add Task'}
+          </h2>
+          <button
+            onClick={onClose}
+            className="text-text-tertiary hover:text-text-primary cursor-pointer transition-colors"
+          >
+            <ICONS.ACTION_CLOSE size={16} />
+          </button>
+        </div>
+
+        <form onSubmit={handleSubmit} className="space-y-4 p-4">
+          <div className="space-y-1">
+            <label className="text-text-secondary text-xs font-medium">Title</label>
+            <input
+              type="text"
+              value={title}
+              onChange={e => setTitle(e.target.value)}
+              placeholder="e.g. Verify the rollback plan"
+              className="border-border bg-bg-tertiary text-text-primary placeholder:text-text-disabled focus:border-brand w-full rounded border px-3 py-2 text-sm focus:outline-none"
+              autoFocus
+            />
+          </div>
+
+          <div className="space-y-1">
+            <label className="text-text-secondary text-xs font-medium">Description</label>
+            <textarea
+              value={description}
+              onChange={e => setDescription(e.target.value)}
+              placeholder="What needs checking (Markdown supported)"
+              className="border-border bg-bg-tertiary text-text-primary placeholder:text-text-disabled focus:border-brand min-h-[100px] w-full resize-y rounded border px-3 py-2 text-sm focus:outline-none"
+            />
+          </div>
+
+          <div className="space-y-1">
+            <label className="text-text-secondary text-xs font-medium">Sub-flow</label>
+            <input
+              type="text"
+              list="task-form-sub-flows"
+              value={subFlow}
+              onChange={e => setSubFlow(e.target.value)}
+              placeholder="General"
+              className="border-border bg-bg-tertiary text-text-primary placeholder:text-text-disabled focus:border-brand w-full rounded border px-3 py-2 text-sm focus:outline-none"
+            />
+            <datalist id="task-form-sub-flows">
+              {subFlows.map(name => (
+                <option key={name} value={name} />
+              ))}
+            </datalist>
+          </div>
+
+          {fileOptions.length > 0 && (
+            <div className="space-y-1">
+              <label className="text-text-secondary text-xs font-medium">Files</label>
+              <div className="border-border bg-bg-tertiary custom-scrollbar max-h-40 overflow-y-auto rounded border p-1">
+                {fileOptions.map(file => (
+                  <label
+                    key={file}
+                    className="hover:bg-bg-secondary flex cursor-pointer items-center gap-2 rounded px-2 py-1"
+                  >
+                    <input
+                      type="checkbox"
+                      checked={files.has(file)}
+                      onChange={() => toggleFile(file)}
+                      className="accent-brand"
+                    />
+                    <span className="text-text-secondary truncate font-mono text-xs">{file}</span>
+                  </label>
+                ))}
+              </div>
+            </div>
+          )}
+
+          <div className="flex justify-end gap-2 pt-2">
+            <button
+              type="button"
+              onClick={onClose}
+              className="text-text-secondary hover:text-text-primary hover:bg-bg-secondary cursor-pointer rounded px-3 py-1.5 text-xs font-medium transition-colors"
+            >
+              Cancel
+            </button>
+            <button
+              type="submit"
+              disabled={!title.trim() || isSaving}
+              className="text-brand-fg bg-brand hover:bg-brand/90 flex cursor-pointer items-center gap-2 rounded px-3 py-1.5 text-xs font-medium transition-colors disabled:cursor-not-allowed disabled:opacity-50"
+            >
+              {isSaving ? 'Saving...' : isEditing ? 'Save Task' : 'Add Task'}
+            </button>
+          </div>
+        </form>
+      </div>
+    </div>
+  );
+};
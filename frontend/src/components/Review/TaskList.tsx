@@ -88,6 +88,11 @@ export const TaskList: React.FC<TaskListProps> = ({
               >
                 {task.title}
               </h3>
+              {!task.ai_generated && (
+                <Tooltip content="Added by you">
+                  <ICONS.ICON_USER_TASK size={14} className="text-text-tertiary flex-shrink-0" />
+                </Tooltip>
+              )}
             </div>
           </button>
         );
@@ -0,0 +1,169 @@
+import React, { useState } from 'react';
+import { PencilSimple, Trash } from '@phosphor-icons/react';
+import { ICONS } from '../../constants/icons';
+import { useIssueCheckDefinitions } from '../../hooks/useIssueChecks';
+import type { IssueCheckDefinition, IssueCheckDefinitionInput } from '../../types';
+
+const emptyDraft: IssueCheckDefinitionInput = {
+  name: '',
+  category: '',
+  prompt: '',
+  enabled: true,
+};
+
+const inputClassName =
+  'bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand focus:ring-brand/20 w-full rounded-md border px-3 py-2 text-xs transition-all focus:ring-1 focus:outline-none';
+
+/** Checklist items the agent must report a pass/fail result for on every run. */
+export const CustomChecksSection: React.FC = () => {
+  const { data: checks = [], isLoading, createCheck, updateCheck, removeCheck } =
+    useIssueCheckDefinitions();
+  const [draft, setDraft] = useState<IssueCheckDefinitionInput>(emptyDraft);
+  const [editingId, setEditingId] = useState<string | null>(null);
+
+  const canSubmit = draft.name.trim() && draft.category.trim() && draft.prompt.trim();
+
+  const resetForm = () => {
+    setDraft(emptyDraft);
+    setEditingId(null);
+  };
+
+  const handleSubmit = () => {
+    if (!canSubmit) return;
+    if (editingId) {
+      updateCheck.mutate({ id: editingId, input: draft }, { onSuccess: resetForm });
+    } else {
+      createCheck.mutate(draft, { onSuccess: resetForm });
+    }
+  };
+
+  const startEdit = (check: IssueCheckDefinition) => {
+    setEditingId(check.id);
+    setDraft({
+      name: check.name,
+      category: check.category,
+      prompt: check.prompt,
+      enabled: check.enabled,
+    });
+  };
+
+  const toggleCheck = (check: IssueCheckDefinition) => {
+    updateCheck.mutate({
+      id: check.id,
+      input: {
+        name: check.name,
+        category: check.category,
+        prompt: check.prompt,
+        enabled: !check.enabled,
+      },
+    });
+  };
+
+  return (
+    <div>
+      <div className="mb-3 flex items-center justify-between">
+        <h3 className="text-text-primary text-sm font-semibold">Custom Checks</h3>
+        {isLoading && <span className="text-text-tertiary text-[10px]">Loading...</span>}
+      </div>
+      <p className="text-text-tertiary mb-3 text-xs leading-relaxed">
+        Checks are reported as pass or fail in the review's Checks tab, alongside the built-in
+        categories.
+      </p>
+
+      <div className="bg-bg-secondary/40 mb-3 space-y-2 rounded-lg p-4">
+        <div className="grid grid-cols-2 gap-2">
+          <input
+            value={draft.name}
+            onChange={e => setDraft({ ...draft, name: e.target.value })}
+            placeholder="Name (e.g. Accessibility)"
+            className={inputClassName}
+          />
+          <input
+            value={draft.category}
+            onChange={e => setDraft({ ...draft, category: e.target.value })}
+            placeholder="Category ID (e.g. accessibility)"
+            className={inputClassName}
+          />
+        </div>
+        <textarea
+          value={draft.prompt}
+          onChange={e => setDraft({ ...draft, prompt: e.target.value })}
+          placeholder="What should the agent verify?"
+          rows={2}
+          className={`${inputClassName} resize-none`}
+        />
+        <div className="flex justify-end gap-2">
+          {editingId && (
+            <button
+              onClick={resetForm}
+              className="text-text-tertiary hover:text-text-primary px-3 py-1.5 text-[10px] font-bold transition-colors"
+            >
+              Cancel
+            </button>
+          )}
+          <button
+            onClick={handleSubmit}
+            disabled={!canSubmit || createCheck.isPending || updateCheck.isPending}
+            className="bg-brand text-bg-primary flex items-center gap-1.5 rounded-md px-3 py-1.5 text-[10px] font-bold transition-all hover:brightness-110 disabled:opacity-50"
+          >
+            <ICONS.ICON_PLUS size={12} weight="bold" />
+            {editingId ? 'Save Check' : 'Add Check'}
+          </button>
+        </div>
+      </div>
+
+      {checks.length === 0 ? (
+        <div className="text-text-tertiary bg-bg-secondary/40 border-border rounded-lg border p-4 text-xs">
+          No custom checks yet.
+        </div>
+      ) : (
+        <div className="space-y-2">
+          {checks.map(check => (
+            <div
+              key={check.id}
+              className="group bg-bg-secondary/40 hover:bg-bg-secondary flex items-start gap-4 rounded-lg p-4 transition-all"
+            >
+              <div className="min-w-0 flex-1 space-y-1">
+                <div className="flex items-center gap-2">
+                  <span className="text-text-primary text-sm font-medium">{check.name}</span>
+                  <span className="bg-bg-tertiary text-accent border-accent/20 rounded-md border px-2 py-0.5 text-[10px] font-medium">
+                    {check.category}
+                  </span>
+                </div>
+                <p className="text-text-secondary text-xs leading-relaxed">{check.prompt}</p>
+              </div>
+              <div className="flex shrink-0 items-center gap-1.5">
+                <div className="flex items-center gap-1.5 opacity-0 transition-opacity group-hover:opacity-100">
+                  <button
+                    onClick={() => startEdit(check)}
+                    className="text-text-tertiary hover:text-text-primary hover:bg-bg-tertiary rounded-md p-1.5 transition-colors"
+                    title="Edit check"
+                  >
+                    <PencilSimple size={14} />
+                  </button>
+                  <button
+                    onClick={() => removeCheck.mutate(check.id)}
+                    className="text-text-tertiary hover:text-status-ignored hover:bg-status-ignored/10 rounded-md p-1.5 transition-colors"
+                    title="Delete check"
+                  >
+                    <Trash size={14} />
+                  </button>
+                </div>
+                <button
+                  onClick={() => toggleCheck(check)}
+                  className={`rounded-md px-2.5 py-1 text-[10px] font-semibold transition-colors ${
+                    check.enabled
+                      ? 'bg-status-done/10 text-status-done border-status-done/20 border'
+                      : 'bg-bg-tertiary text-text-tertiary border-border border'
+                  }`}
+                >
+                  {check.enabled ? 'Enabled' : 'Disabled'}
+                </button>
+              </div>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+};
@@ -1,9 +1,11 @@
-import React, { useMemo, useState } from 'react';
+import React, { useEffect, useMemo, useState } from 'react';
 import { Asterisk, Check, PencilSimple, Trash, Warning, ChartBar } from '@phosphor-icons/react';
 import { ICONS } from '../../constants/icons';
 import { useRules, useRuleRejectionStats, type ReviewRuleInput, type RuleRejectionStats } from '../../hooks/useRules';
 import { useRepos } from '../../hooks/useRepos';
 import { RuleLibraryModal } from './RuleLibraryModal';
+import { CustomChecksSection } from './CustomChecksSection';
+import { useAppStore } from '../../store';
 import type { LinkedRepo, ReviewRule, RuleScope } from '../../types';
 
 interface RuleDraft {
@@ -35,6 +37,19 @@ export const RulesView: React.FC = () => {
   const [editingId, setEditingId] = useState<string | null>(null);
   const [editingDraft, setEditingDraft] = useState<RuleDraft>(emptyDraft);
 
+  const focusedRuleId = useAppStore(state => state.focusedRuleId);
+  const setFocusedRuleId = useAppStore(state => state.setFocusedRuleId);
+
+  // Scroll to a rule opened from a finding, then drop the highlight.
+  useEffect(() => {
+    if (!focusedRuleId || !rules.some(rule => rule.id === focusedRuleId)) return;
+    document
+      .getElementById(`rule-${focusedRuleId}`)
+      ?.scrollIntoView?.({ block: 'center', behavior: 'smooth' });
+    const timer = setTimeout(() => setFocusedRuleId(null), 2000);
+    return () => clearTimeout(timer);
+  }, [focusedRuleId, rules, setFocusedRuleId]);
+
   const globalRules = useMemo(() => rules.filter(rule => rule.scope === 'global'), [rules]);
   const repoRules = useMemo(() => rules.filter(rule => rule.scope === 'repo'), [rules]);
 
@@ -166,6 +181,7 @@ export const RulesView: React.FC = () => {
             onDelete={removeRule.mutate}
             repoName={repoName}
             isLoading={isLoading}
+            focusedRuleId={focusedRuleId}
           />
           <RuleSection
             title="Repository Rules"
@@ -182,7 +198,9 @@ export const RulesView: React.FC = () => {
             onDelete={removeRule.mutate}
             repoName={repoName}
             isLoading={isLoading}
+            focusedRuleId={focusedRuleId}
           />
+          <CustomChecksSection />
         </div>
       </div>
 
@@ -416,6 +434,7 @@ interface RuleSectionProps {
   onDelete: (id: string) => void;
   repoName: (repoId?: string | null) => string;
   isLoading: boolean;
+  focusedRuleId: string | null;
 }
 
 const RuleSection: React.FC<RuleSectionProps> = ({
@@ -433,6 +452,7 @@ const RuleSection: React.FC<RuleSectionProps> = ({
   onDelete,
   repoName,
   isLoading,
+  focusedRuleId,
 }) => {
   return (
     <div>
@@ -450,7 +470,10 @@ const RuleSection: React.FC<RuleSectionProps> = ({
           {rules.map(rule => (
             <div
               key={rule.id}
-              className="group bg-bg-secondary/40 hover:bg-bg-secondary hover:border-border relative rounded-lg border border-transparent transition-all"
+              id={`rule-${rule.id}`}
+              className={`group bg-bg-secondary/40 hover:bg-bg-secondary hover:border-border relative rounded-lg border transition-all ${
+                focusedRuleId === rule.id ? 'border-brand' : 'border-transparent'
+              }`}
             >
               {editingId === rule.id ? (
                 <div className="p-4">
@@ -22,6 +22,12 @@ import {
   Trash,
   Robot,
   Timer,
+  Stack,
+  Lightning,
+  Copy,
+  HardDrives,
+  FolderOpen,
+  TextAa,
 } from '@phosphor-icons/react';
 import type {
   ViewType,
@@ -30,19 +36,47 @@ import type {
   EditorCandidate,
   EditorConfig,
   CliStatus,
+  DiagnosticCheck,
+  DiagnosticsReport,
 } from '../../types';
 import { toast } from 'sonner';
 import { useTauri } from '../../hooks/useTauri';
+import { useI18n } from '../../hooks/useI18n';
 import { useDelayedLoading } from '../../hooks/useDelayedLoading';
-import { useFeedbackFilterConfig, useTimeoutConfig } from '../../hooks/useSettings';
+import { DEFAULT_REFRESH_INTERVAL_SECS } from '../../hooks/useAutoRefresh';
+import { UI_SCALE_OPTIONS } from '../../lib/appearance';
+import { LANGUAGES } from '../../lib/i18n';
+import {
+  useAppearanceConfig,
+  useAutoRefreshConfig,
+  useCompletionNotifications,
+  useFeedbackFilterConfig,
+  useGeneratedFilePatterns,
+  useGitHubEnterpriseHosts,
+  useVcsCliConfig,
+  useDataDir,
+  useEncryptionStatus,
+  useGenerationConcurrency,
+  useLanguage,
+  useOfflineMode,
+  usePrFetchTimeout,
+  useQuickReviewMaxLines,
+  useRejectionExamples,
+  useTimeoutConfig,
+  useUserIdentity,
+} from '../../hooks/useSettings';
 import { VcsSkeleton, CliSkeleton, EditorSkeleton, AgentsSkeleton } from './SettingsSkeleton';
+import { useAppStore } from '../../store';
 
 interface SettingsViewProps {
   onNavigate: (view: ViewType) => void;
 }
 
 export const SettingsView: React.FC<SettingsViewProps> = () => {
-  const [activeTab, setActiveTab] = useState<'vcs' | 'cli' | 'editor' | 'feedback' | 'agents'>('vcs');
+  const { t } = useI18n();
+  const [activeTab, setActiveTab] = useState<
+    'vcs' | 'cli' | 'editor' | 'appearance' | 'feedback' | 'storage' | 'agents' | 'diagnostics'
+  >(() => (useAppStore.getState().configuringAgentId ? 'agents' : 'vcs'));
 
   return (
     <div className="bg-bg-primary flex h-full flex-col">
@@ -50,7 +84,7 @@ export const SettingsView: React.FC<SettingsViewProps> = () => {
       <div className="border-border flex h-12 shrink-0 items-center gap-3 border-b px-6">
         <Asterisk size={18} weight="fill" className="text-brand" />
         <h1 className="font-display text-text-primary text-sm font-medium tracking-wide">
-          Settings
+          {t('settings.title')}
         </h1>
       </div>
 
@@ -60,38 +94,56 @@ export const SettingsView: React.FC<SettingsViewProps> = () => {
           <nav className="flex-1 space-y-1 px-3">
             <TabButton
               icon={<Gear size={14} />}
-              label="VCS Integration"
+              label={t('settings.tab.vcs')}
               isActive={activeTab === 'vcs'}
               onClick={() => setActiveTab('vcs')}
             />
             <TabButton
               icon={<Terminal size={14} />}
-              label="CLI Tools"
+              label={t('settings.tab.cli')}
               isActive={activeTab === 'cli'}
               onClick={() => setActiveTab('cli')}
             />
 
             <TabButton
               icon={<Database size={14} />}
-              label="Editor"
+              label={t('settings.tab.editor')}
               isActive={activeTab === 'editor'}
               onClick={() => setActiveTab('editor')}
             />
+            <TabButton
+              icon={<TextAa size={14} />}
+              label={t('settings.tab.appearance')}
+              isActive={activeTab === 'appearance'}
+              onClick={() => setActiveTab('appearance')}
+            />
             <TabButton
               icon={<Funnel size={14} />}
-              label="Feedback Filters"
+              label={t('settings.tab.feedback')}
               isActive={activeTab === 'feedback'}
               onClick={() => setActiveTab('feedback')}
             />
+            <TabButton
+              icon={<HardDrives size={14} />}
+              label={t('settings.tab.storage')}
+              isActive={activeTab === 'storage'}
+              onClick={() => setActiveTab('storage')}
+            />
             <div className="pt-3 pb-1">
               <div className="bg-border/50 mx-2 h-px" />
             </div>
             <TabButton
               icon={<Robot size={14} />}
-              label="Agents"
+              label={t('settings.tab.agents')}
               isActive={activeTab === 'agents'}
               onClick={() => setActiveTab('agents')}
             />
+            <TabButton
+              icon={<ShieldCheck size={14} />}
+              label={t('settings.tab.diagnostics')}
+              isActive={activeTab === 'diagnostics'}
+              onClick={() => setActiveTab('diagnostics')}
+            />
           </nav>
         </div>
 
@@ -101,8 +153,17 @@ export const SettingsView: React.FC<SettingsViewProps> = () => {
             {activeTab === 'vcs' && <VcsSettings />}
             {activeTab === 'cli' && <CliSettings />}
             {activeTab === 'editor' && <EditorSettings />}
-            {activeTab === 'feedback' && <FeedbackFilterSettings />}
+            {activeTab === 'appearance' && <AppearanceSettings />}
+            {activeTab === 'feedback' && (
+              <>
+                <IdentitySettings />
+                <FeedbackFilterSettings />
+                <GeneratedFilesSettings />
+              </>
+            )}
+            {activeTab === 'storage' && <DataStorageSettings />}
             {activeTab === 'agents' && <AgentsSettings />}
+            {activeTab === 'diagnostics' && <DiagnosticsSettings />}
           </div>
         </div>
       </div>
@@ -151,6 +212,9 @@ const SectionHeader: React.FC<{ title: string; description: string }> = ({
 
 const VcsSettings: React.FC = () => {
   const { getVcsStatus, getSingleVcsStatus } = useTauri();
+  const { offline, setOffline, isUpdating: isUpdatingOffline } = useOfflineMode();
+  const { prFetchTimeout, setPrFetchTimeout } = usePrFetchTimeout();
+  const [localFetchTimeout, setLocalFetchTimeout] = useState('');
   const [status, setStatus] = useState<VcsStatusType[]>([]);
   const [isChecking, setIsChecking] = useState(true);
   const [loadingMap, setLoadingMap] = useState<Record<string, boolean>>({});
@@ -190,7 +254,45 @@ const VcsSettings: React.FC = () => {
 
   useEffect(() => {
     checkStatus();
-  }, [checkStatus]);
+  }, [checkStatus, offline]);
+
+  const handleOfflineToggle = () => {
+    const next = !offline;
+    setOffline(next, {
+      onSuccess: () => {
+        toast(next ? 'Offline Mode Enabled' : 'Offline Mode Disabled', {
+          description: next
+            ? 'Remote fetch, push and clone are disabled.'
+            : 'Remote VCS operations are available again.',
+        });
+      },
+      onError: (error: Error) => {
+        toast.error('Failed to update offline mode', { description: error.message });
+      },
+    });
+  };
+
+  useEffect(() => {
+    setLocalFetchTimeout(String(prFetchTimeout));
+  }, [prFetchTimeout]);
+
+  const handleFetchTimeoutSave = () => {
+    const secs = parseInt(localFetchTimeout, 10);
+    if (isNaN(secs) || secs < 5 || secs > 600) {
+      toast.error('Invalid timeout', { description: 'Must be between 5 and 600 seconds.' });
+      return;
+    }
+    setPrFetchTimeout(secs, {
+      onSuccess: () => {
+        toast('Fetch Timeout Updated', {
+          description: `Remote reviews that take longer than ${secs}s to download are abandoned.`,
+        });
+      },
+      onError: (error: Error) => {
+        toast.error('Failed to update fetch timeout', { description: error.message });
+      },
+    });
+  };
 
   const shouldShowSkeleton = useDelayedLoading(isChecking && status.length === 0);
 
@@ -223,6 +325,59 @@ const VcsSettings: React.FC = () => {
         </div>
       </div>
 
+      <div className="bg-bg-secondary/40 border-border mb-6 flex items-center justify-between gap-4 rounded-lg border p-4">
+        <div>
+          <h3 className="text-text-primary text-sm font-medium">Offline mode</h3>
+          <p className="text-text-tertiary text-xs">
+            Block all network calls to VCS providers. Pasted diffs and local reviews keep working.
+          </p>
+        </div>
+        <button
+          role="switch"
+          aria-checked={offline}
+          aria-label="Offline mode"
+          onClick={handleOfflineToggle}
+          disabled={isUpdatingOffline}
+          className={`relative h-5 w-9 flex-shrink-0 rounded-full transition-colors disabled:opacity-50 ${
+            offline ? 'bg-brand' : 'bg-bg-tertiary'
+          }`}
+        >
+          <span
+            className={`absolute top-0.5 left-0.5 h-4 w-4 rounded-full bg-white transition-transform ${
+              offline ? 'translate-x-4' : ''
+            }`}
+          />
+        </button>
+      </div>
+
+      <div className="mb-6 flex items-center gap-3">
+        <label
+          htmlFor="prFetchTimeout"
+          className="text-text-tertiary flex items-center gap-1.5 text-xs whitespace-nowrap"
+        >
+          <Timer size={12} />
+          Fetch timeout
+        </label>
+        <input
+          id="prFetchTimeout"
+          type="number"
+          min="5"
+          max="600"
+          value={localFetchTimeout}
+          onChange={e => setLocalFetchTimeout(e.target.value)}
+          className="bg-bg-tertiary border-border text-text-primary focus:border-brand w-20 rounded-md border px-2 py-1.5 font-mono text-xs transition-all focus:outline-none"
+        />
+        <span className="text-text-disabled text-[10px]">
+          sec to download a remote review before giving up
+        </span>
+        <button
+          onClick={handleFetchTimeoutSave}
+          className="bg-brand text-bg-primary hover:bg-brand/90 ml-auto rounded-md px-3 py-1.5 text-xs font-medium transition-all"
+        >
+          Save
+        </button>
+      </div>
+
       {shouldShowSkeleton ? (
         <VcsSkeleton />
       ) : (
@@ -251,7 +406,11 @@ const VcsSettings: React.FC = () => {
                     <p className="text-text-tertiary text-xs">
                       {item.id === 'github' && 'Integration via gh CLI'}
                       {item.id === 'gitlab' && 'Integration via glab CLI'}
-                      {item.id !== 'github' && item.id !== 'gitlab' && 'VCS Provider'}
+                      {item.id === 'gitea' && 'Integration via API token'}
+                      {item.id !== 'github' &&
+                        item.id !== 'gitlab' &&
+                        item.id !== 'gitea' &&
+                        'VCS Provider'}
                     </p>
                   </div>
                 </div>
@@ -266,6 +425,8 @@ const VcsSettings: React.FC = () => {
                         <ArrowsClockwise size={14} className="animate-spin" />
                         <span className="text-xs">Checking status...</span>
                       </div>
+                    ) : item.offline ? (
+                      <span className="text-text-tertiary text-xs font-medium">Offline</span>
                     ) : item.login ? (
                       <div className="flex items-center gap-2">
                         <span className="text-status-done text-xs font-medium">Connected</span>
@@ -279,7 +440,7 @@ const VcsSettings: React.FC = () => {
                           Disconnected
                         </span>
                         {item.error && (
-                          <span className="text-text-tertiary text-[10px]">
+                          <span className="text-text-tertiary text-[10px] whitespace-pre-line">
                             (Error: {item.error})
                           </span>
                         )}
@@ -298,6 +459,39 @@ const VcsSettings: React.FC = () => {
                     </>
                   )}
 
+                  {item.cliVersion && (
+                    <>
+                      <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
+                        Version
+                      </span>
+                      <span className="text-text-secondary truncate font-mono text-xs">
+                        {item.cliVersion}
+                      </span>
+                    </>
+                  )}
+
+                  {item.host && (
+                    <>
+                      <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
+                        Host
+                      </span>
+                      <span className="text-text-secondary truncate font-mono text-xs">
+                        {item.host}
+                      </span>
+                    </>
+                  )}
+
+                  {item.authMethod && (
+                    <>
+                      <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
+                        Auth
+                      </span>
+                      <span className="text-text-secondary truncate font-mono text-xs">
+                        {item.authMethod}
+                      </span>
+                    </>
+                  )}
+
                   <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
                     Status
                   </span>
@@ -320,6 +514,21 @@ const VcsSettings: React.FC = () => {
                     Refresh Status
                   </button>
                 </div>
+
+                {(item.id === 'github' || item.id === 'gitlab') && (
+                  <VcsCliOverrides
+                    providerId={item.id}
+                    onSaved={() => refreshSingle(item.id, item.name)}
+                  />
+                )}
+
+                {item.id === 'github' && (
+                  <GitHubEnterpriseHosts onSaved={() => refreshSingle(item.id, item.name)} />
+                )}
+
+                {item.id === 'gitea' && (
+                  <GiteaConnection onSaved={() => refreshSingle(item.id, item.name)} />
+                )}
               </div>
             );
           })}
@@ -329,6 +538,140 @@ const VcsSettings: React.FC = () => {
   );
 };
 
+const DIAGNOSTIC_LEVEL_ICON: Record<DiagnosticCheck['level'], React.ReactNode> = {
+  ok: <CheckCircle size={14} weight="fill" className="text-status-done" />,
+  warn: <Warning size={14} weight="fill" className="text-status-in_progress" />,
+  error: <X size={14} weight="bold" className="text-status-ignored" />,
+};
+
+const DiagnosticsSettings: React.FC = () => {
+  const { runDiagnostics, exportBugReport, selectSavePath, copyToClipboard } = useTauri();
+  const [report, setReport] = useState<DiagnosticsReport | null>(null);
+  const [isRunning, setIsRunning] = useState(true);
+  const [isExporting, setIsExporting] = useState(false);
+
+  const run = useCallback(async () => {
+    setIsRunning(true);
+    try {
+      setReport(await runDiagnostics());
+    } catch (error) {
+      toast('Diagnostics failed', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    } finally {
+      setIsRunning(false);
+    }
+  }, [runDiagnostics]);
+
+  useEffect(() => {
+    run();
+  }, [run]);
+
+  const handleCopy = async () => {
+    if (!report) return;
+    try {
+      await copyToClipboard(report.text);
+      toast('Report copied', { description: 'Paste it into your bug report.' });
+    } catch (error) {
+      toast.error('Failed to copy report', { description: String(error) });
+    }
+  };
+
+  const handleExport = async () => {
+    const date = new Date().toISOString().slice(0, 10);
+    const path = await selectSavePath(`lareview-bug-report-${date}.zip`, 'zip');
+    if (!path) return;
+    setIsExporting(true);
+    try {
+      const saved = await exportBugReport(path);
+      toast('Bug report saved', {
+        description: `Attach ${saved} to your issue. Credentials are redacted.`,
+      });
+    } catch (error) {
+      toast.error('Failed to export bug report', { description: String(error) });
+    } finally {
+      setIsExporting(false);
+    }
+  };
+
+  const sections = (report?.checks ?? []).reduce<Record<string, DiagnosticCheck[]>>(
+    (acc, check) => {
+      (acc[check.section] ??= []).push(check);
+      return acc;
+    },
+    {}
+  );
+  const shouldShowSkeleton = useDelayedLoading(isRunning && !report);
+
+  return (
+    <div>
+      <div className="mb-6 flex items-center justify-between">
+        <SectionHeader
+          title="Diagnostics"
+          description="Check the database, VCS tools, agents and editor, and copy the results into a bug report."
+        />
+        <div className="flex items-center gap-2">
+          <button
+            onClick={handleCopy}
+            disabled={!report || isRunning}
+            className="border-border text-text-secondary hover:text-text-primary hover:bg-bg-tertiary flex items-center gap-1.5 rounded-md border px-3 py-1.5 text-xs font-medium transition-colors disabled:opacity-50"
+          >
+            <Copy size={12} />
+            Copy report
+          </button>
+          <button
+            onClick={handleExport}
+            disabled={isExporting}
+            className="border-border text-text-secondary hover:text-text-primary hover:bg-bg-tertiary flex items-center gap-1.5 rounded-md border px-3 py-1.5 text-xs font-medium transition-colors disabled:opacity-50"
+          >
+            <FloppyDisk size={12} />
+            {isExporting ? 'Exporting...' : 'Export bug report'}
+          </button>
+          <button
+            onClick={run}
+            disabled={isRunning}
+            className="bg-bg-tertiary text-text-primary hover:bg-bg-tertiary/80 flex items-center gap-1.5 rounded-md px-3 py-1.5 text-xs font-medium transition-colors disabled:opacity-50"
+          >
+            <ArrowsClockwise size={12} className={isRunning ? 'animate-spin' : ''} />
+            {isRunning ? 'Running...' : 'Run again'}
+          </button>
+        </div>
+      </div>
+
+      {shouldShowSkeleton ? (
+        <CliSkeleton />
+      ) : report ? (
+        <div className="space-y-4">
+          {Object.entries(sections).map(([section, checks]) => (
+            <div key={section} className="bg-bg-secondary/40 border-border rounded-lg border p-4">
+              <h3 className="text-text-disabled mb-3 text-[10px] font-bold tracking-wider uppercase">
+                {section}
+              </h3>
+              <ul className="space-y-3">
+                {checks.map(check => (
+                  <li key={`${check.section}-${check.name}`} className="flex gap-3">
+                    <span className="mt-0.5 flex-shrink-0">{DIAGNOSTIC_LEVEL_ICON[check.level]}</span>
+                    <div className="min-w-0">
+                      <div className="text-text-primary text-xs font-medium">{check.name}</div>
+                      <div className="text-text-tertiary text-xs break-words">{check.detail}</div>
+                      {check.fix && (
+                        <div className="text-text-secondary mt-1 text-xs">{check.fix}</div>
+                      )}
+                    </div>
+                  </li>
+                ))}
+              </ul>
+            </div>
+          ))}
+          <p className="text-text-disabled text-[10px]">
+            LaReview {report.appVersion} on {report.platform}
+          </p>
+        </div>
+      ) : null}
+    </div>
+  );
+};
+
 const CliSettings: React.FC = () => {
   const { getCliStatus, installCli, getVersion } = useTauri();
   const [status, setStatus] = useState<CliStatus | null>(null);
@@ -354,11 +697,16 @@ const CliSettings: React.FC = () => {
     setIsInstalling(true);
     setInstallError(null);
     try {
-      await installCli();
+      const outcome = await installCli();
       await fetchStatus();
-      toast('CLI Tools Installed', {
-        description: 'The lareview command is now available in your terminal.',
-      });
+      if (outcome.warning) {
+        setInstallError(outcome.warning);
+        toast('CLI Tools Installed', { description: outcome.warning });
+      } else {
+        toast('CLI Tools Installed', {
+          description: 'The lareview command is now available in your terminal.',
+        });
+      }
     } catch (error) {
       setInstallError(error as string);
       toast('Installation Failed', {
@@ -421,6 +769,7 @@ const CliSettings: React.FC = () => {
             <div className="text-text-tertiary flex items-center gap-1 text-[10px]">
               <Check size={10} className="text-status-done" />
               Active binary at: <span className="text-text-secondary font-mono">{status.path}</span>
+              {status.build && <span className="font-mono">({status.build})</span>}
             </div>
           )}
 
@@ -743,9 +1092,627 @@ const FeedbackFilterSettings: React.FC = () => {
   );
 };
 
+const VcsCliOverrides: React.FC<{ providerId: string; onSaved: () => void }> = ({
+  providerId,
+  onSaved,
+}) => {
+  const { config, setConfig, isUpdating } = useVcsCliConfig(providerId);
+  const [path, setPath] = useState('');
+  const [host, setHost] = useState('');
+  const cli = providerId === 'github' ? 'gh' : 'glab';
+
+  useEffect(() => {
+    setPath(config.path ?? '');
+    setHost(config.default_host ?? '');
+  }, [config.path, config.default_host]);
+
+  const isDirty = path !== (config.path ?? '') || host !== (config.default_host ?? '');
+
+  const handleSave = async () => {
+    try {
+      await setConfig({ path: path.trim() || null, default_host: host.trim() || null });
+      onSaved();
+    } catch (error) {
+      toast.error(`Failed to save ${cli} settings`, { description: String(error) });
+    }
+  };
+
+  const inputClass =
+    'bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand w-full rounded-md border px-3 py-2 font-mono text-xs transition-all focus:outline-none';
+
+  return (
+    <div className="border-border space-y-3 border-t pt-4">
+      <p className="text-text-tertiary text-xs">
+        Leave empty to use the <code>{cli}</code> found on PATH and its default host.
+      </p>
+      <div className="grid grid-cols-2 gap-3">
+        <label className="block">
+          <span className="text-text-disabled mb-1 block text-[10px] font-bold tracking-wider uppercase">
+            {cli} executable
+          </span>
+          <input
+            value={path}
+            onChange={e => setPath(e.target.value)}
+            placeholder={`/opt/homebrew/bin/${cli}`}
+            spellCheck={false}
+            className={inputClass}
+          />
+        </label>
+        <label className="block">
+          <span className="text-text-disabled mb-1 block text-[10px] font-bold tracking-wider uppercase">
+            Default host
+          </span>
+          <input
+            value={host}
+            onChange={e => setHost(e.target.value)}
+            placeholder={providerId === 'github' ? 'github.com' : 'gitlab.com'}
+            spellCheck={false}
+            className={inputClass}
+          />
+        </label>
+      </div>
+      <button
+        onClick={handleSave}
+        disabled={isUpdating || !isDirty}
+        className="bg-brand text-bg-primary hover:bg-brand/90 flex items-center gap-1.5 rounded-md px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+      >
+        {isUpdating ? (
+          <ArrowsClockwise size={14} className="animate-spin" />
+        ) : (
+          <Check size={14} weight="bold" />
+        )}
+        Save
+      </button>
+    </div>
+  );
+};
+
+const GiteaConnection: React.FC<{ onSaved: () => void }> = ({ onSaved }) => {
+  const { setGiteaToken } = useTauri();
+  const { config, setConfig, isUpdating } = useVcsCliConfig('gitea');
+  const [host, setHost] = useState('');
+  const [token, setToken] = useState('');
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    setHost(config.default_host ?? '');
+  }, [config.default_host]);
+
+  const isDirty = host !== (config.default_host ?? '') || token.length > 0;
+
+  const handleSave = async () => {
+    setIsSaving(true);
+    try {
+      await setConfig({ path: null, default_host: host.trim() || null });
+      if (token.trim()) {
+        await setGiteaToken(host, token.trim());
+        setToken('');
+      }
+      onSaved();
+    } catch (error) {
+      toast.error('Failed to save Gitea settings', { description: String(error) });
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const handleForget = async () => {
+    try {
+      await setGiteaToken(host, null);
+      onSaved();
+    } catch (error) {
+      toast.error('Failed to remove the token', { description: String(error) });
+    }
+  };
+
+  const inputClass =
+    'bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand w-full rounded-md border px-3 py-2 font-mono text-xs transition-all focus:outline-none';
+
+  return (
+    <div className="border-border space-y-3 border-t pt-4">
+      <p className="text-text-tertiary text-xs">
+        The token is kept in the system keychain. <code>GITEA_TOKEN</code> takes precedence for the
+        default Gitea host; other hosts only get the token saved for them.
+      </p>
+      <div className="grid grid-cols-2 gap-3">
+        <label className="block">
+          <span className="text-text-disabled mb-1 block text-[10px] font-bold tracking-wider uppercase">
+            Host
+          </span>
+          <input
+            value={host}
+            onChange={e => setHost(e.target.value)}
+            placeholder="codeberg.org"
+            spellCheck={false}
+            className={inputClass}
+          />
+        </label>
+        <label className="block">
+          <span className="text-text-disabled mb-1 block text-[10px] font-bold tracking-wider uppercase">
+            API token
+          </span>
+          <input
+            type="password"
+            value={token}
+            onChange={e => setToken(e.target.value)}
+            placeholder="Leave empty to keep the saved token"
+            spellCheck={false}
+            className={inputClass}
+          />
+        </label>
+      </div>
+      <div className="flex items-center gap-2">
+        <button
+          onClick={handleSave}
+          disabled={isUpdating || isSaving || !isDirty}
+          className="bg-brand text-bg-primary hover:bg-brand/90 flex items-center gap-1.5 rounded-md px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+        >
+          {isUpdating || isSaving ? (
+            <ArrowsClockwise size={14} className="animate-spin" />
+          ) : (
+            <Check size={14} weight="bold" />
+          )}
+          Save
+        </button>
+        <button
+          onClick={handleForget}
+          disabled={!host.trim()}
+          className="bg-bg-tertiary hover:bg-bg-secondary rounded-md px-4 py-2 text-xs font-medium transition-colors disabled:opacity-50"
+        >
+          Remove token
+        </button>
+      </div>
+    </div>
+  );
+};
+
+const DataStorageSettings: React.FC = () => {
+  const { selectDataFolder } = useTauri();
+  const { info, setDataDir, isUpdating } = useDataDir();
+
+  const move = async (path: string | null) => {
+    const previous = info?.path;
+    try {
+      const next = await setDataDir(path);
+      toast('Data directory changed', {
+        description:
+          previous && previous !== next.path
+            ? `Now using ${next.path}. The old copy is still in ${previous}.`
+            : `Now using ${next.path}.`,
+      });
+    } catch (error) {
+      toast.error('Failed to change the data directory', { description: String(error) });
+    }
+  };
+
+  const handleChoose = async () => {
+    const folder = await selectDataFolder();
+    if (folder) {
+      await move(folder);
+    }
+  };
+
+  const locked = Boolean(info?.envOverride);
+
+  return (
+    <div>
+      <SectionHeader
+        title="Data Storage"
+        description="Where LaReview keeps reviews, run logs and prompts. Point it at a synced or encrypted volume to keep your review data there."
+      />
+
+      <div className="bg-bg-secondary/40 border-border space-y-4 rounded-lg border p-6">
+        <div className="grid grid-cols-[120px_1fr] items-center gap-x-8 gap-y-4">
+          <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
+            Location
+          </span>
+          <span className="text-text-secondary truncate font-mono text-xs">
+            {info?.path ?? '…'}
+          </span>
+          <span className="text-text-disabled text-[10px] font-bold tracking-wider uppercase">
+            Default
+          </span>
+          <span className="text-text-tertiary truncate font-mono text-xs">
+            {info?.defaultPath ?? '…'}
+          </span>
+        </div>
+
+        {locked ? (
+          <p className="text-text-tertiary text-xs">
+            Set by <code>{info?.envOverride}</code>. Unset it to choose a folder here.
+          </p>
+        ) : (
+          <p className="text-text-tertiary text-xs">
+            The current data is copied to the new folder, unless it already has a LaReview
+            database, which is then used as-is. The old copy is left in place.
+          </p>
+        )}
+
+        <div className="flex items-center gap-2">
+          <button
+            onClick={handleChoose}
+            disabled={locked || isUpdating}
+            className="bg-brand text-bg-primary hover:bg-brand/90 flex items-center gap-1.5 rounded-md px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+          >
+            {isUpdating ? (
+              <ArrowsClockwise size={14} className="animate-spin" />
+            ) : (
+              <FolderOpen size={14} />
+            )}
+            Choose folder…
+          </button>
+          {info?.isCustom && (
+            <button
+              onClick={() => move(null)}
+              disabled={locked || isUpdating}
+              className="bg-bg-tertiary hover:bg-bg-secondary flex items-center gap-2 rounded-md px-4 py-2 text-xs font-medium transition-colors disabled:opacity-50"
+            >
+              Use default
+            </button>
+          )}
+        </div>
+      </div>
+
+      <EncryptionSettings />
+    </div>
+  );
+};
+
+const EncryptionSettings: React.FC = () => {
+  const { status, enable, isEnabling } = useEncryptionStatus();
+  const [passphrase, setPassphrase] = useState('');
+  const [confirm, setConfirm] = useState('');
+
+  if (!status) return null;
+
+  const tooShort = passphrase.length < 8;
+  const mismatch = confirm.length > 0 && confirm !== passphrase;
+
+  const handleEnable = async () => {
+    try {
+      await enable(passphrase);
+      setPassphrase('');
+      setConfirm('');
+      toast(status.enabled ? 'Passphrase changed' : 'Database encrypted', {
+        description: 'The passphrase is saved in your OS keychain.',
+      });
+    } catch (error) {
+      toast.error('Failed to encrypt the database', { description: String(error) });
+    }
+  };
+
+  const inputClass =
+    'bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand w-full rounded-md border px-3 py-2 font-mono text-xs transition-all focus:outline-none';
+
+  return (
+    <div className="bg-bg-secondary/40 border-border mt-6 space-y-4 rounded-lg border p-6">
+      <div>
+        <h3 className="text-text-primary text-sm font-medium">Encryption at rest</h3>
+        <p className="text-text-tertiary text-xs">
+          {!status.supported
+            ? 'This build of LaReview was compiled without database encryption.'
+            : status.enabled
+              ? 'The database is encrypted. Its passphrase is kept in your OS keychain.'
+              : 'Encrypt the database so diffs and feedback are unreadable without the passphrase. The passphrase is kept in your OS keychain.'}
+        </p>
+      </div>
+
+      {status.supported && (
+        <>
+          <div className="grid grid-cols-2 gap-3">
+            <input
+              type="password"
+              value={passphrase}
+              onChange={e => setPassphrase(e.target.value)}
+              placeholder={status.enabled ? 'New passphrase' : 'Passphrase'}
+              className={inputClass}
+            />
+            <input
+              type="password"
+              value={confirm}
+              onChange={e => setConfirm(e.target.value)}
+              placeholder="Confirm passphrase"
+              className={inputClass}
+            />
+          </div>
+          {mismatch && <p className="text-status-ignored text-xs">Passphrases don't match.</p>}
+          <button
+            onClick={handleEnable}
+            disabled={isEnabling || tooShort || confirm !== passphrase}
+            className="bg-brand text-bg-primary hover:bg-brand/90 flex items-center gap-1.5 rounded-md px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+          >
+            {isEnabling ? (
+              <ArrowsClockwise size={14} className="animate-spin" />
+            ) : (
+              <ShieldCheck size={14} />
+            )}
+            {status.enabled ? 'Change passphrase' : 'Encrypt database'}
+          </button>
+        </>
+      )}
+    </div>
+  );
+};
+
+const GitHubEnterpriseHosts: React.FC<{ onSaved: () => void }> = ({ onSaved }) => {
+  const { hosts, setHosts, isUpdating } = useGitHubEnterpriseHosts();
+  const [draft, setDraft] = useState('');
+
+  useEffect(() => {
+    setDraft(hosts.join(', '));
+  }, [hosts]);
+
+  const handleSave = async () => {
+    const next = draft
+      .split(/[\s,]+/)
+      .map(host => host.trim())
+      .filter(Boolean);
+    try {
+      await setHosts(next);
+      onSaved();
+    } catch (error) {
+      toast.error('Failed to save enterprise hosts', { description: String(error) });
+    }
+  };
+
+  return (
+    <div className="border-border border-t pt-4">
+      <label className="text-text-disabled mb-1 block text-[10px] font-bold tracking-wider uppercase">
+        GitHub Enterprise hosts
+      </label>
+      <p className="text-text-tertiary mb-3 text-xs">
+        Hosts <code>gh</code> is logged into are detected automatically. Add others here, separated
+        by commas.
+      </p>
+      <div className="flex items-center gap-2">
+        <input
+          value={draft}
+          onChange={e => setDraft(e.target.value)}
+          placeholder="ghe.company.com"
+          spellCheck={false}
+          className="bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand flex-1 rounded-md border px-3 py-2 font-mono text-xs transition-all focus:outline-none"
+        />
+        <button
+          onClick={handleSave}
+          disabled={isUpdating || draft === hosts.join(', ')}
+          className="bg-brand text-bg-primary hover:bg-brand/90 flex items-center gap-1.5 rounded-md px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+        >
+          {isUpdating ? (
+            <ArrowsClockwise size={14} className="animate-spin" />
+          ) : (
+            <Check size={14} weight="bold" />
+          )}
+          Save
+        </button>
+      </div>
+    </div>
+  );
+};
+
+const IDENTITY_SOURCE_LABELS = {
+  settings: 'Set here',
+  git: "From git's user.name",
+  os: 'From your OS user name',
+  fallback: 'No name found',
+} as const;
+
+const IdentitySettings: React.FC = () => {
+  const { identity, setIdentity, isUpdating } = useUserIdentity();
+  const [name, setName] = useState('');
+  const [email, setEmail] = useState('');
+
+  useEffect(() => {
+    if (!identity) return;
+    setName(identity.name);
+    setEmail(identity.email ?? '');
+  }, [identity]);
+
+  const save = async (nextName: string, nextEmail: string) => {
+    try {
+      const saved = await setIdentity({ name: nextName, email: nextEmail.trim() || null });
+      toast('Identity Updated', {
+        description: `New feedback and comments will be signed ${saved.name}.`,
+      });
+    } catch (error) {
+      toast.error('Failed to update identity', { description: String(error) });
+    }
+  };
+
+  return (
+    <div>
+      <SectionHeader
+        title="Identity"
+        description="The author shown on feedback and comments you write. Agent feedback keeps the agent's name."
+      />
+
+      <div className="bg-bg-secondary/40 border-border rounded-lg border p-6">
+        <div className="grid grid-cols-2 gap-4">
+          <div>
+            <label className="text-text-disabled mb-2 block text-[10px] font-bold tracking-wider uppercase">
+              Name
+            </label>
+            <input
+              type="text"
+              value={name}
+              onChange={e => setName(e.target.value)}
+              className="bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand w-full rounded-md border px-3 py-2 text-xs transition-all focus:outline-none"
+            />
+          </div>
+          <div>
+            <label className="text-text-disabled mb-2 block text-[10px] font-bold tracking-wider uppercase">
+              Email (optional)
+            </label>
+            <input
+              type="email"
+              value={email}
+              onChange={e => setEmail(e.target.value)}
+              placeholder="you@example.com"
+              className="bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand w-full rounded-md border px-3 py-2 text-xs transition-all focus:outline-none"
+            />
+          </div>
+        </div>
+        <div className="mt-3 flex items-center justify-between gap-2">
+          <span className="text-text-tertiary text-[11px]">
+            {identity ? IDENTITY_SOURCE_LABELS[identity.source] : ''}
+          </span>
+          <div className="flex items-center gap-2">
+            <button
+              onClick={() => save('', '')}
+              disabled={isUpdating || identity?.source !== 'settings'}
+              className="border-border text-text-secondary hover:bg-bg-tertiary rounded-md border px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+            >
+              Use default
+            </button>
+            <button
+              onClick={() => save(name, email)}
+              disabled={isUpdating || !name.trim()}
+              className="bg-brand text-bg-primary hover:bg-brand/90 flex items-center gap-1.5 rounded-md px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+            >
+              {isUpdating ? <ArrowsClockwise size={14} className="animate-spin" /> : <Check size={14} weight="bold" />}
+              Save
+            </button>
+          </div>
+        </div>
+      </div>
+    </div>
+  );
+};
+
+const GeneratedFilesSettings: React.FC = () => {
+  const { patterns, setPatterns, isUpdating } = useGeneratedFilePatterns();
+  const [draft, setDraft] = useState('');
+
+  useEffect(() => {
+    setDraft(patterns.join('\n'));
+  }, [patterns]);
+
+  const save = async (next: string[] | null) => {
+    try {
+      await setPatterns(next);
+      toast('Generated Files Updated', {
+        description:
+          next == null
+            ? 'Restored the default patterns.'
+            : 'Matching files will be collapsed and skipped in reviews.',
+      });
+    } catch (error) {
+      toast.error('Failed to update patterns', { description: String(error) });
+    }
+  };
+
+  const handleSave = () => {
+    const next = draft
+      .split('\n')
+      .map(line => line.trim())
+      .filter(Boolean);
+    save(next);
+  };
+
+  return (
+    <div>
+      <SectionHeader
+        title="Generated Files"
+        description="Lockfiles, build output and other generated files are collapsed in the diff and the agent is told not to review them."
+      />
+
+      <div className="bg-bg-secondary/40 border-border rounded-lg border p-6">
+        <label className="text-text-disabled mb-3 block text-[10px] font-bold tracking-wider uppercase">
+          Patterns
+        </label>
+        <p className="text-text-tertiary mb-4 text-xs">
+          One glob per line. Files marked <code>linguist-generated</code> in the repository&apos;s
+          <code> .gitattributes</code> are always included, and unmarked ones excluded.
+        </p>
+        <textarea
+          value={draft}
+          onChange={e => setDraft(e.target.value)}
+          rows={8}
+          spellCheck={false}
+          className="bg-bg-tertiary border-border text-text-primary placeholder-text-disabled focus:border-brand w-full rounded-md border px-3 py-2 font-mono text-xs transition-all focus:outline-none"
+        />
+        <div className="mt-3 flex items-center justify-end gap-2">
+          <button
+            onClick={() => save(null)}
+            disabled={isUpdating}
+            className="border-border text-text-secondary hover:bg-bg-tertiary rounded-md border px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+          >
+            Reset to defaults
+          </button>
+          <button
+            onClick={handleSave}
+            disabled={isUpdating}
+            className="bg-brand text-bg-primary hover:bg-brand/90 flex items-center gap-1.5 rounded-md px-4 py-2 text-xs font-medium transition-all disabled:opacity-50"
+          >
+            {isUpdating ? <ArrowsClockwise size={14} className="animate-spin" /> : <Check size={14} weight="bold" />}
+            Save
+          </button>
+        </div>
+      </div>
+    </div>
+  );
+};
+
 const AgentsSettings: React.FC = () => {
   const { getAgents, updateAgentConfig, addCustomAgent, deleteCustomAgent } = useTauri();
   const { config: timeoutConfig, updateTimeout, isUpdating: isUpdatingTimeout } = useTimeoutConfig();
+  const {
+    concurrency,
+    setLimit: setConcurrencyLimit,
+    isUpdating: isUpdatingConcurrency,
+  } = useGenerationConcurrency();
+  const { quickMaxLines, setQuickMaxLines } = useQuickReviewMaxLines();
+  const {
+    enabled: notifyOnCompletion,
+    setEnabled: setNotifyOnCompletion,
+    isUpdating: isUpdatingNotify,
+  } = useCompletionNotifications();
+  const {
+    enabled: includeRejectionExamples,
+    setEnabled: setIncludeRejectionExamples,
+    isUpdating: isUpdatingRejectionExamples,
+  } = useRejectionExamples();
+  const {
+    config: autoRefreshConfig,
+    updateAutoRefresh,
+    isUpdating: isUpdatingAutoRefresh,
+  } = useAutoRefreshConfig();
+  const [localRefreshInterval, setLocalRefreshInterval] = useState<string>('');
+
+  useEffect(() => {
+    setLocalRefreshInterval(
+      String(autoRefreshConfig.refreshInterval ?? DEFAULT_REFRESH_INTERVAL_SECS)
+    );
+  }, [autoRefreshConfig.refreshInterval]);
+
+  const handleAutoRefreshToggle = () => {
+    updateAutoRefresh(
+      { ...autoRefreshConfig, autoRefresh: !autoRefreshConfig.autoRefresh },
+      {
+        onError: (error: Error) => {
+          toast.error('Failed to update auto-refresh', { description: error.message });
+        },
+      }
+    );
+  };
+
+  const handleRefreshIntervalSave = () => {
+    const secs = parseInt(localRefreshInterval, 10);
+    if (isNaN(secs) || secs < 5 || secs > 3600) {
+      toast.error('Invalid interval', { description: 'Must be between 5 and 3600 seconds.' });
+      return;
+    }
+    updateAutoRefresh(
+      { ...autoRefreshConfig, refreshInterval: secs },
+      {
+        onSuccess: () => {
+          toast('Auto-refresh Updated', { description: `Reviews reload every ${secs}s.` });
+        },
+        onError: (error: Error) => {
+          toast.error('Failed to update auto-refresh', { description: error.message });
+        },
+      }
+    );
+  };
   const [agents, setAgents] = useState<Agent[]>([]);
   const [isLoading, setIsLoading] = useState(true);
   const [editingId, setEditingId] = useState<string | null>(null);
@@ -767,6 +1734,33 @@ const AgentsSettings: React.FC = () => {
     setLocalTimeout(String(timeoutConfig.timeoutSecs ?? DEFAULT_TIMEOUT_SECS));
   }, [timeoutConfig.timeoutSecs]);
 
+  const [localQuickMaxLines, setLocalQuickMaxLines] = useState<string>('');
+
+  useEffect(() => {
+    setLocalQuickMaxLines(String(quickMaxLines));
+  }, [quickMaxLines]);
+
+  const handleQuickMaxLinesSave = () => {
+    const lines = parseInt(localQuickMaxLines, 10);
+    if (isNaN(lines) || lines < 0 || lines > 10000) {
+      toast.error('Invalid size', { description: 'Must be between 0 and 10000 lines.' });
+      return;
+    }
+    setQuickMaxLines(lines, {
+      onSuccess: () => {
+        toast('Quick Reviews Updated', {
+          description:
+            lines === 0
+              ? 'New reviews default to thorough.'
+              : `Diffs up to ${lines} changed lines default to a quick review.`,
+        });
+      },
+      onError: (error: Error) => {
+        toast.error('Failed to update quick reviews', { description: error.message });
+      },
+    });
+  };
+
   const handleTimeoutSave = () => {
     const secs = parseInt(localTimeout, 10);
     if (isNaN(secs) || secs < 60 || secs > 7200) {
@@ -810,6 +1804,19 @@ const AgentsSettings: React.FC = () => {
     fetchAgents();
   }, [fetchAgents]);
 
+  // Open the path editor for the agent the generate view asked to configure.
+  const configuringAgentId = useAppStore(state => state.configuringAgentId);
+  const setConfiguringAgentId = useAppStore(state => state.setConfiguringAgentId);
+  useEffect(() => {
+    if (!configuringAgentId || isLoading) return;
+    const agent = agents.find(a => a.id === configuringAgentId);
+    if (agent) {
+      setEditingId(agent.id);
+      setEditState({ path: agent.path || '', args: (agent.args || []).join(' ') });
+    }
+    setConfiguringAgentId(null);
+  }, [configuringAgentId, isLoading, agents, setConfiguringAgentId]);
+
   useEffect(() => {
     if (savedId) {
       const timer = setTimeout(() => setSavedId(null), 2000);
@@ -963,6 +1970,191 @@ const AgentsSettings: React.FC = () => {
         </div>
       </div>
 
+      {/* Concurrent generations */}
+      {concurrency && (
+        <div className="mb-6 flex items-center gap-3">
+          <label
+            htmlFor="generationConcurrency"
+            className="text-text-tertiary flex items-center gap-1.5 text-xs whitespace-nowrap"
+          >
+            <Stack size={12} />
+            Concurrent reviews
+          </label>
+          <select
+            id="generationConcurrency"
+            value={String(concurrency.limit)}
+            disabled={isUpdatingConcurrency}
+            onChange={e => {
+              const limit = parseInt(e.target.value, 10);
+              setConcurrencyLimit(limit === concurrency.default_limit ? null : limit, {
+                onError: (error: Error) => {
+                  toast.error('Failed to update concurrency', { description: error.message });
+                },
+              });
+            }}
+            className="bg-bg-tertiary border-border text-text-primary focus:border-brand rounded-md border px-2 py-1.5 font-mono text-xs transition-all focus:outline-none"
+          >
+            {Array.from({ length: concurrency.max_limit }, (_, i) => i + 1).map(limit => (
+              <option key={limit} value={limit}>
+                {limit === concurrency.default_limit ? `${limit} (default)` : limit}
+              </option>
+            ))}
+          </select>
+          <span className="text-text-disabled text-[10px]">
+            {concurrency.queued > 0
+              ? `${concurrency.running} running, ${concurrency.queued} queued`
+              : 'More wait in a queue until one finishes'}
+          </span>
+        </div>
+      )}
+
+      {/* Quick review threshold */}
+      <div className="mb-6 flex items-center gap-3">
+        <label
+          htmlFor="quickReviewMaxLines"
+          className="text-text-tertiary flex items-center gap-1.5 text-xs whitespace-nowrap"
+        >
+          <Lightning size={12} />
+          Quick review up to
+        </label>
+        <input
+          id="quickReviewMaxLines"
+          type="number"
+          min="0"
+          max="10000"
+          value={localQuickMaxLines}
+          onChange={e => setLocalQuickMaxLines(e.target.value)}
+          className="bg-bg-tertiary border-border text-text-primary focus:border-brand w-20 rounded-md border px-2 py-1.5 font-mono text-xs transition-all focus:outline-none"
+        />
+        <span className="text-text-disabled text-[10px]">
+          changed lines; bigger diffs default to a thorough review
+        </span>
+        <button
+          onClick={handleQuickMaxLinesSave}
+          className="bg-brand text-bg-primary hover:bg-brand/90 ml-auto rounded-md px-3 py-1.5 text-xs font-medium transition-all"
+        >
+          Save
+        </button>
+      </div>
+
+      <div className="bg-bg-secondary/40 border-border mb-6 flex items-center justify-between gap-4 rounded-lg border p-4">
+        <div>
+          <h3 className="text-text-primary text-sm font-medium">Notify when a review finishes</h3>
+          <p className="text-text-tertiary text-xs">
+            Show a desktop notification when a generation completes or fails while LaReview is in
+            the background.
+          </p>
+        </div>
+        <button
+          role="switch"
+          aria-checked={notifyOnCompletion}
+          aria-label="Notify when a review finishes"
+          onClick={() =>
+            setNotifyOnCompletion(!notifyOnCompletion, {
+              onError: (error: Error) => {
+                toast.error('Failed to update notifications', { description: error.message });
+              },
+            })
+          }
+          disabled={isUpdatingNotify}
+          className={`relative h-5 w-9 flex-shrink-0 rounded-full transition-colors disabled:opacity-50 ${
+            notifyOnCompletion ? 'bg-brand' : 'bg-bg-tertiary'
+          }`}
+        >
+          <span
+            className={`absolute top-0.5 left-0.5 h-4 w-4 rounded-full bg-white transition-transform ${
+              notifyOnCompletion ? 'translate-x-4' : ''
+            }`}
+          />
+        </button>
+      </div>
+
+      <div className="bg-bg-secondary/40 border-border mb-6 flex items-center justify-between gap-4 rounded-lg border p-4">
+        <div>
+          <h3 className="text-text-primary text-sm font-medium">
+            Show recent rejections to the agent
+          </h3>
+          <p className="text-text-tertiary text-xs">
+            Include up to 10 recently ignored feedback titles for the same file types and repo in
+            the review prompt, so the agent avoids repeating them.
+          </p>
+        </div>
+        <button
+          role="switch"
+          aria-checked={includeRejectionExamples}
+          aria-label="Show recent rejections to the agent"
+          onClick={() =>
+            setIncludeRejectionExamples(!includeRejectionExamples, {
+              onError: (error: Error) => {
+                toast.error('Failed to update setting', { description: error.message });
+              },
+            })
+          }
+          disabled={isUpdatingRejectionExamples}
+          className={`relative h-5 w-9 flex-shrink-0 rounded-full transition-colors disabled:opacity-50 ${
+            includeRejectionExamples ? 'bg-brand' : 'bg-bg-tertiary'
+          }`}
+        >
+          <span
+            className={`absolute top-0.5 left-0.5 h-4 w-4 rounded-full bg-white transition-transform ${
+              includeRejectionExamples ? 'translate-x-4' : ''
+            }`}
+          />
+        </button>
+      </div>
+
+      <div className="bg-bg-secondary/40 border-border mb-6 rounded-lg border p-4">
+        <div className="flex items-center justify-between gap-4">
+          <div>
+            <h3 className="text-text-primary text-sm font-medium">Auto-refresh reviews</h3>
+            <p className="text-text-tertiary text-xs">
+              Reload the review list periodically so reviews from the CLI or other windows show
+              up. Paused while a review is generating.
+            </p>
+          </div>
+          <button
+            role="switch"
+            aria-checked={autoRefreshConfig.autoRefresh}
+            aria-label="Auto-refresh reviews"
+            onClick={handleAutoRefreshToggle}
+            disabled={isUpdatingAutoRefresh}
+            className={`relative h-5 w-9 flex-shrink-0 rounded-full transition-colors disabled:opacity-50 ${
+              autoRefreshConfig.autoRefresh ? 'bg-brand' : 'bg-bg-tertiary'
+            }`}
+          >
+            <span
+              className={`absolute top-0.5 left-0.5 h-4 w-4 rounded-full bg-white transition-transform ${
+                autoRefreshConfig.autoRefresh ? 'translate-x-4' : ''
+              }`}
+            />
+          </button>
+        </div>
+        {autoRefreshConfig.autoRefresh && (
+          <div className="mt-3 flex items-center gap-3">
+            <label className="text-text-tertiary flex items-center gap-1.5 text-xs whitespace-nowrap">
+              <ArrowsClockwise size={12} />
+              Every
+            </label>
+            <input
+              type="number"
+              min="5"
+              max="3600"
+              value={localRefreshInterval}
+              onChange={e => setLocalRefreshInterval(e.target.value)}
+              className="bg-bg-tertiary border-border text-text-primary focus:border-brand w-20 rounded-md border px-2 py-1.5 font-mono text-xs transition-all focus:outline-none"
+            />
+            <span className="text-text-disabled text-[10px]">sec</span>
+            <button
+              onClick={handleRefreshIntervalSave}
+              disabled={isUpdatingAutoRefresh}
+              className="bg-brand text-bg-primary hover:bg-brand/90 ml-auto rounded-md px-3 py-1.5 text-xs font-medium transition-all disabled:opacity-50"
+            >
+              Save
+            </button>
+          </div>
+        )}
+      </div>
+
       {showAddForm && (
         <div className="bg-bg-secondary/40 border-border mb-4 rounded-lg border p-5">
           <h3 className="text-text-primary mb-4 text-sm font-semibold">New Custom Agent</h3>
@@ -1244,3 +2436,106 @@ const AgentsSettings: React.FC = () => {
     </div>
   );
 };
+
+const AppearanceSettings: React.FC = () => {
+  const { t } = useI18n();
+  const { config, updateAppearance, isUpdating } = useAppearanceConfig();
+  const { language, setLanguage, isUpdating: isUpdatingLanguage } = useLanguage();
+
+  const update = (next: Partial<typeof config>) =>
+    updateAppearance(
+      { ...config, ...next },
+      {
+        onError: (error: Error) => {
+          toast.error('Failed to update appearance', { description: error.message });
+        },
+      }
+    );
+
+  return (
+    <div>
+      <SectionHeader
+        title={t('settings.tab.appearance')}
+        description={t('settings.appearance.description')}
+      />
+
+      <div className="bg-bg-secondary/40 border-border mb-6 flex items-center justify-between gap-4 rounded-lg border p-4">
+        <div>
+          <label htmlFor="language" className="text-text-primary text-sm font-medium">
+            {t('settings.language.title')}
+          </label>
+          <p className="text-text-tertiary text-xs">{t('settings.language.description')}</p>
+        </div>
+        <select
+          id="language"
+          value={language ?? ''}
+          disabled={isUpdatingLanguage}
+          onChange={e =>
+            setLanguage(e.target.value || null, {
+              onError: (error: Error) => {
+                toast.error('Failed to update language', { description: error.message });
+              },
+            })
+          }
+          className="bg-bg-tertiary border-border text-text-primary focus:border-brand rounded-md border px-2 py-1.5 text-xs transition-all focus:outline-none"
+        >
+          <option value="">{t('settings.language.system')}</option>
+          {LANGUAGES.map(({ code, name }) => (
+            <option key={code} value={code}>
+              {name}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      <div className="bg-bg-secondary/40 border-border mb-6 flex items-center justify-between gap-4 rounded-lg border p-4">
+        <div>
+          <label htmlFor="uiScale" className="text-text-primary text-sm font-medium">
+            UI scale
+          </label>
+          <p className="text-text-tertiary text-xs">
+            Zooms the whole interface, including the font size of diffs.
+          </p>
+        </div>
+        <select
+          id="uiScale"
+          value={String(config.uiScale)}
+          disabled={isUpdating}
+          onChange={e => update({ uiScale: parseFloat(e.target.value) })}
+          className="bg-bg-tertiary border-border text-text-primary focus:border-brand rounded-md border px-2 py-1.5 font-mono text-xs transition-all focus:outline-none"
+        >
+          {UI_SCALE_OPTIONS.map(scale => (
+            <option key={scale} value={scale}>
+              {scale === 1 ? '100% (default)' : `${Math.round(scale * 100)}%`}
+            </option>
+          ))}
+        </select>
+      </div>
+
+      <div className="bg-bg-secondary/40 border-border mb-6 flex items-center justify-between gap-4 rounded-lg border p-4">
+        <div>
+          <h3 className="text-text-primary text-sm font-medium">High contrast</h3>
+          <p className="text-text-tertiary text-xs">
+            Black backgrounds, white text, stronger borders and brighter status colors.
+          </p>
+        </div>
+        <button
+          role="switch"
+          aria-checked={config.highContrast}
+          aria-label="High contrast"
+          onClick={() => update({ highContrast: !config.highContrast })}
+          disabled={isUpdating}
+          className={`relative h-5 w-9 flex-shrink-0 rounded-full transition-colors disabled:opacity-50 ${
+            config.highContrast ? 'bg-brand' : 'bg-bg-tertiary'
+          }`}
+        >
+          <span
+            className={`absolute top-0.5 left-0.5 h-4 w-4 rounded-full bg-white transition-transform ${
+              config.highContrast ? 'translate-x-4' : ''
+            }`}
+          />
+        </button>
+      </div>
+    </div>
+  );
+};
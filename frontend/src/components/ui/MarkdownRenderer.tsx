@@ -1,21 +1,68 @@
+import type { ReactNode } from 'react';
 import ReactMarkdown from 'react-markdown';
 import type { Components } from 'react-markdown';
+import { useQuery } from '@tanstack/react-query';
 import { Mermaid } from '../Common/Mermaid';
+import { useTauri } from '../../hooks/useTauri';
+import { queryKeys } from '../../lib/query-keys';
 
 interface MarkdownRendererProps {
   children: string;
   className?: string;
 }
 
+interface HighlightedCodeProps {
+  code: string;
+  language: string;
+  className?: string;
+  fallback: ReactNode;
+}
+
+/** Code fence colored by the backend highlighter, with the diff viewer's theme. */
+function HighlightedCode({ code, language, className, fallback }: HighlightedCodeProps) {
+  const { highlightCode } = useTauri();
+  const { data: lines } = useQuery({
+    queryKey: queryKeys.highlightedCode(language, code),
+    queryFn: () => highlightCode(code, language),
+    staleTime: Infinity,
+  });
+
+  // Plain text while highlighting, or if it failed.
+  if (!lines) {
+    return <code className={className}>{fallback}</code>;
+  }
+
+  return (
+    <code className={className}>
+      {lines.map((spans, lineIdx) => (
+        <span key={lineIdx}>
+          {spans.map((span, spanIdx) => (
+            <span key={spanIdx} style={span.color ? { color: span.color } : undefined}>
+              {span.text}
+            </span>
+          ))}
+          {lineIdx < lines.length - 1 && '\n'}
+        </span>
+      ))}
+    </code>
+  );
+}
+
 export function MarkdownRenderer({ children, className }: MarkdownRendererProps) {
   const components: Components = {
     code({ className: codeClassName, children: codeChildren }) {
-      const match = /language-(\w+)/.exec(codeClassName || '');
+      const match = /language-([\w+#-]+)/.exec(codeClassName || '');
+      const code = String(codeChildren || '').replace(/\n$/, '');
       if (match && match[1] === 'mermaid') {
+        return <Mermaid chart={code} className="my-4 max-h-64" />;
+      }
+      if (match) {
         return (
-          <Mermaid
-            chart={String(codeChildren || '').replace(/\n$/, '')}
-            className="my-4 max-h-64"
+          <HighlightedCode
+            code={code}
+            language={match[1]}
+            className={codeClassName}
+            fallback={codeChildren}
           />
         );
       }
@@ -1,5 +1,6 @@
 import {
   Circle,
+  ClockCounterClockwise,
   CircleDashed,
   Spinner,
   CheckCircle,
@@ -12,6 +13,8 @@ import {
   Folder,
   Gear,
   FileText,
+  ImageSquare,
+  PushPin,
   ChartBar,
   Files,
   ChatCircle,
@@ -43,6 +46,7 @@ import {
   DotOutline,
   ArrowRight,
   CaretDown,
+  CaretRight,
   CaretUp,
   HandPalm,
   Lightbulb,
@@ -54,6 +58,11 @@ import {
   WarningCircle,
   MinusCircle,
   File,
+  PencilSimple,
+  UserCircle,
+  FilePdf,
+  GitMerge,
+  Wrench,
   ShieldCheck,
   SkipForward,
   Gauge,
@@ -108,17 +117,25 @@ export const ICONS = {
   ACTION_COLLAPSE: ArrowsInSimple,
   ACTION_BACK: ArrowSquareIn,
   ACTION_COPY: Copy,
+  ACTION_EDIT: PencilSimple,
+  ACTION_MERGE: GitMerge,
   ACTION_SAVE: FloppyDisk,
   ACTION_SEARCH: MagnifyingGlass,
   ACTION_LOADING: Spinner,
   ACTION_ZOOM_IN: MagnifyingGlassPlus,
   ACTION_ZOOM_OUT: MagnifyingGlassMinus,
+  ACTION_ATTACH_IMAGE: ImageSquare,
+  ACTION_PIN: PushPin,
 
   // --- Symbols ---
   ICON_PLAN: ListChecks,
+  ICON_TOOL: Wrench,
+  ICON_HISTORY: ClockCounterClockwise,
   ICON_FEEDBACK: ChatTeardrop,
   ICON_GITHUB: GithubLogo,
   ICON_GITLAB: GitlabLogo,
+  ICON_PDF: FilePdf,
+  ICON_USER_TASK: UserCircle,
   ICON_EMPTY: BoundingBox,
   ICON_CHECK: CheckCircle,
   ICON_WARNING: Warning,
@@ -131,6 +148,7 @@ export const ICONS = {
   ICON_ARROW_RIGHT: ArrowRight,
   CHEVRON_DOWN: CaretDown,
   CHEVRON_UP: CaretUp,
+  CHEVRON_RIGHT: CaretRight,
 
   // --- Impact ---
   IMPACT_BLOCKING: HandPalm,
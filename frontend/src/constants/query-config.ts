@@ -4,6 +4,8 @@ export const QUERY_CONFIG = {
   retry: 0,
   refetchOnWindowFocus: false,
   feedbackStaleTime: 30 * 1000,
+  // Parsed diffs of large PRs are heavy; keep only a few inactive ones around.
+  maxCachedParsedDiffs: 4,
 } as const;
 
 export const PERSIST_CONFIG = {
@@ -13,4 +15,5 @@ export const PERSIST_CONFIG = {
 
 export const STORAGE_KEYS = {
   agentId: 'agentId',
+  reviewListSort: 'reviewListSort',
 } as const;
@@ -132,4 +132,73 @@ describe('GenerationContext', () => {
 
     expect(useAppStore.getState().isGenerating).toBe(false);
   });
+
+  it('keeps agent stderr details on error messages', async () => {
+    const { result } = renderHook(() => useGeneration(), {
+      wrapper: createWrapper(),
+    });
+    let channelInstance: MockChannel | null = null;
+
+    vi.mocked(mockTauri.generateReview).mockImplementation(async (...args: unknown[]) => {
+      channelInstance = (args[6] as MockChannel | undefined) || null;
+      return new Promise(() => {});
+    });
+
+    act(() => {
+      result.current.startGeneration({
+        diffText: 'test diff',
+        agentId: 'test-agent',
+      });
+    });
+
+    await waitFor(() => expect(channelInstance).not.toBeNull());
+
+    act(() => {
+      channelInstance?.onmessage?.({
+        event: 'Error',
+        data: { message: 'Generation failed', details: 'Error: invalid API key' },
+      });
+    });
+
+    const errors = useAppStore.getState().progressMessages.filter(m => m.type === 'error');
+    expect(errors.at(-1)?.message).toBe('Generation failed');
+    expect(errors.at(-1)?.data).toEqual({ details: 'Error: invalid API key' });
+  });
+
+  it('opens a run with feedback but no tasks as a review', async () => {
+    const { result } = renderHook(() => useGeneration(), {
+      wrapper: createWrapper(),
+    });
+    vi.mocked(mockTauri.generateReview).mockResolvedValue({
+      task_count: 0,
+      feedback_count: 2,
+      review_id: 'review-1',
+    });
+
+    let ok: boolean | undefined;
+    await act(async () => {
+      ok = await result.current.startGeneration({ diffText: 'test diff', agentId: 'test-agent' });
+    });
+
+    expect(ok).toBe(true);
+    expect(useAppStore.getState().reviewId).toBe('review-1');
+  });
+
+  it('stays on generate when the run produced nothing', async () => {
+    const { result } = renderHook(() => useGeneration(), {
+      wrapper: createWrapper(),
+    });
+    vi.mocked(mockTauri.generateReview).mockResolvedValue({
+      task_count: 0,
+      feedback_count: 0,
+      review_id: 'review-1',
+    });
+
+    let ok: boolean | undefined;
+    await act(async () => {
+      ok = await result.current.startGeneration({ diffText: 'test diff', agentId: 'test-agent' });
+    });
+
+    expect(ok).toBe(false);
+  });
 });
@@ -5,6 +5,7 @@ import { useQueryClient } from '@tanstack/react-query';
 import type { ProgressEventPayload } from '../hooks/useTauri';
 import { useTauri } from '../hooks/useTauri';
 import { useAppStore } from '../store';
+import { completionMessage, hasReviewContent, type GenerationCounts } from '../lib/generation';
 
 import {
   GenerationContext,
@@ -44,7 +45,14 @@ export const GenerationProvider: React.FC<{ children: React.ReactNode }> = ({ ch
   const currentTaskTitleRef = useRef<string | null>(null);
 
   const startGeneration = useCallback(
-    async ({ diffText, agentId, repoId, source }: StartGenerationArgs): Promise<boolean> => {
+    async ({
+      diffText,
+      agentId,
+      repoId,
+      source,
+      excludedPaths,
+      mode,
+    }: StartGenerationArgs): Promise<boolean> => {
       if (isGeneratingRef.current) return false;
 
       isGeneratingRef.current = true;
@@ -145,30 +153,45 @@ export const GenerationProvider: React.FC<{ children: React.ReactNode }> = ({ ch
             case 'TaskCompleted':
               addProgressMessage('task_added', 'Task completed');
               break;
+            case 'Queued': {
+              const { limit } = payload.data as { limit: number };
+              addProgressMessage(
+                'log',
+                `Queued: ${limit} ${limit === 1 ? 'review is' : 'reviews are'} already ` +
+                  'generating. This one starts when a slot frees up.'
+              );
+              break;
+            }
             case 'Completed':
-              addProgressMessage('completed', 'Review generation complete!');
+              addProgressMessage('completed', completionMessage(payload.data as GenerationCounts));
               queryClient.invalidateQueries({ queryKey: ['reviews'] });
+              queryClient.invalidateQueries({ queryKey: ['runPlan'] });
+              queryClient.invalidateQueries({ queryKey: ['runToolCalls'] });
               isGeneratingRef.current = false;
               setIsGeneratingStore(false);
               break;
-            case 'Error':
-              addProgressMessage('error', (payload.data as { message: string }).message);
+            case 'Error': {
+              const { message, details } = payload.data as { message: string; details?: string };
+              addProgressMessage('error', message, details ? { details } : undefined);
               isGeneratingRef.current = false;
               setIsGeneratingStore(false);
               break;
+            }
             default:
               console.warn('[Progress] Unknown event type:', payload.event);
           }
         };
 
-        // Check if we should create a worktree for GitHub/GitLab PRs
+        // Check if we should create a worktree for GitHub/GitLab PRs and compares.
+        // Quick reviews only look at the diff.
         let useSnapshot = false;
 
         if (
+          mode !== 'quick' &&
           repoId &&
           source &&
           'head_sha' in source &&
-          (source.type === 'github_pr' || source.type === 'gitlab_mr')
+          source.type !== 'diff_paste'
         ) {
           // Check if we have a linked repo that matches
           try {
@@ -208,7 +231,9 @@ export const GenerationProvider: React.FC<{ children: React.ReactNode }> = ({ ch
           repoId,
           source || undefined,
           useSnapshot,
-          onProgress
+          onProgress,
+          excludedPaths,
+          mode
         );
 
         setReviewId(result.review_id);
@@ -217,8 +242,19 @@ export const GenerationProvider: React.FC<{ children: React.ReactNode }> = ({ ch
 
         isGeneratingRef.current = false;
         setIsGeneratingStore(false);
+        // Feedback on its own is still a review; only stay here when the run
+        // produced nothing at all.
+        if (!hasReviewContent(result)) {
+          toast('Nothing to review', {
+            description: 'The agent finished without tasks or feedback.',
+          });
+          return false;
+        }
         toast('Generation Complete', {
-          description: 'Your review plan is ready.',
+          description:
+            result.task_count > 0
+              ? 'Your review plan is ready.'
+              : 'The agent left feedback without tasks.',
         });
         return true;
       } catch (error: unknown) {
@@ -230,6 +266,7 @@ export const GenerationProvider: React.FC<{ children: React.ReactNode }> = ({ ch
 
         if (isCancelled) {
           addProgressMessage('error', 'Generation stopped by user');
+          queryClient.invalidateQueries({ queryKey: ['reviews'] });
           toast('Generation stopped', {
             description: 'The pending review has been deleted.',
           });
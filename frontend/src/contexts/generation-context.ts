@@ -1,11 +1,15 @@
 import React from 'react';
-import type { ReviewSource } from '../types';
+import type { ReviewMode, ReviewSource } from '../types';
 
 export interface StartGenerationArgs {
   diffText: string;
   agentId: string;
   repoId?: string;
   source?: ReviewSource | null;
+  /** Files in the diff to leave out of the review. */
+  excludedPaths?: string[];
+  /** Left unset, the backend picks quick for small diffs. */
+  mode?: ReviewMode;
 }
 
 export interface GenerationContextValue {
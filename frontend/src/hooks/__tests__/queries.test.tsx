@@ -2,14 +2,19 @@ import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import { renderHook, waitFor, act } from '@testing-library/react';
 import { QueryClient, QueryClientProvider } from '@tanstack/react-query';
 import { queryKeys } from '../../lib/query-keys';
-import { createQueryClient } from '../../lib/query-client';
-import { useReviews } from '../../hooks/useReviews';
+import { createQueryClient, evictParsedDiffs } from '../../lib/query-client';
+import { REVIEW_PAGE_SIZE, useReviews } from '../../hooks/useReviews';
 import { useTasks } from '../../hooks/useTasks';
 import { useReview } from '../../hooks/useReview';
 import { useParsedDiff } from '../../hooks/useParsedDiff';
 import { useRepos } from '../../hooks/useRepos';
 import { useAgents } from '../../hooks/useAgents';
-import { useFeedback, useFeedbackComments, useAddComment } from '../../hooks/useFeedback';
+import {
+  useFeedback,
+  useFeedbackComments,
+  useAddComment,
+  useFeedbackAttachments,
+} from '../../hooks/useFeedback';
 import {
   mockTauri,
   createMockReview,
@@ -17,6 +22,7 @@ import {
   createMockRun,
   createMockFeedback,
   createMockComment,
+  createMockAttachment,
 } from '../../test/mocks';
 
 vi.mock('../../hooks/useTauri', () => ({
@@ -70,10 +76,13 @@ describe('useReviews', () => {
   });
 
   it('fetches reviews from API', async () => {
-    mockTauri.getAllReviews.mockResolvedValue([
-      createMockReview({ id: '1', title: 'Review 1' }),
-      createMockReview({ id: '2', title: 'Review 2' }),
-    ]);
+    mockTauri.listReviews.mockResolvedValue({
+      reviews: [
+        createMockReview({ id: '1', title: 'Review 1' }),
+        createMockReview({ id: '2', title: 'Review 2' }),
+      ],
+      total: 2,
+    });
 
     const { result } = renderHook(() => useReviews(), {
       wrapper: createWrapper(),
@@ -88,6 +97,31 @@ describe('useReviews', () => {
     });
   });
 
+  it('fetches the first page and loads more on demand', async () => {
+    mockTauri.listReviews
+      .mockResolvedValueOnce({ reviews: [createMockReview({ id: '1' })], total: 2 })
+      .mockResolvedValueOnce({ reviews: [createMockReview({ id: '2' })], total: 2 });
+
+    const { result } = renderHook(() => useReviews(), {
+      wrapper: createWrapper(),
+    });
+
+    await waitFor(() => {
+      expect(result.current.data).toHaveLength(1);
+    });
+    expect(mockTauri.listReviews).toHaveBeenCalledWith(REVIEW_PAGE_SIZE, 0);
+    expect(result.current.total).toBe(2);
+    expect(result.current.hasMore).toBe(true);
+
+    await act(async () => {
+      await result.current.loadMore();
+    });
+
+    expect(mockTauri.listReviews).toHaveBeenLastCalledWith(REVIEW_PAGE_SIZE, 1);
+    expect(result.current.data?.map(review => review.id)).toEqual(['1', '2']);
+    expect(result.current.hasMore).toBe(false);
+  });
+
   it('invalidates reviews cache', () => {
     const { result } = renderHook(() => useReviews(), {
       wrapper: createWrapper(),
@@ -97,7 +131,7 @@ describe('useReviews', () => {
   });
 
   it('handles API errors gracefully', async () => {
-    mockTauri.getAllReviews.mockRejectedValue(new Error('Failed to fetch'));
+    mockTauri.listReviews.mockRejectedValue(new Error('Failed to fetch'));
 
     const { result } = renderHook(() => useReviews(), {
       wrapper: createWrapper(),
@@ -209,7 +243,7 @@ describe('useParsedDiff', () => {
   });
 
   it('returns undefined when runId is null', () => {
-    const { result } = renderHook(() => useParsedDiff(null, null), {
+    const { result } = renderHook(() => useParsedDiff(null), {
       wrapper: createWrapper(),
     });
 
@@ -225,7 +259,9 @@ describe('useParsedDiff', () => {
       total_deletions: 5,
     });
 
-    const { result } = renderHook(() => useParsedDiff('run-123', 'diff text'), {
+    mockTauri.getRunDiff.mockResolvedValue('diff text');
+
+    const { result } = renderHook(() => useParsedDiff('run-123'), {
       wrapper: createWrapper(),
     });
 
@@ -236,8 +272,24 @@ describe('useParsedDiff', () => {
       expect(result.current.data?.total_additions).toBe(10);
     });
 
+    expect(mockTauri.getRunDiff).toHaveBeenCalledWith('run-123');
     expect(mockTauri.parseDiff).toHaveBeenCalledWith('diff text');
   });
+
+  it('keeps the parsed diff cache bounded when browsing many large reviews', () => {
+    const client = new QueryClient();
+    const bigDiff = { files: [], total_additions: 50_000, total_deletions: 0 };
+
+    for (let i = 0; i < 50; i++) {
+      client.setQueryData(queryKeys.parsedDiff(`run-${i}`), { ...bigDiff, diff_text: `${i}` });
+      evictParsedDiffs(client, 4);
+    }
+
+    const cached = client.getQueryCache().findAll({ queryKey: queryKeys.parsedDiffs });
+    expect(cached).toHaveLength(4);
+    expect(client.getQueryData(queryKeys.parsedDiff('run-49'))).toBeDefined();
+    expect(client.getQueryData(queryKeys.parsedDiff('run-0'))).toBeUndefined();
+  });
 });
 
 describe('useRepos', () => {
@@ -400,6 +452,43 @@ describe('useFeedback', () => {
   });
 });
 
+describe('useFeedbackAttachments', () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it('fetches attachments for feedback', async () => {
+    mockTauri.getFeedbackAttachments.mockResolvedValue([createMockAttachment()]);
+
+    const { result } = renderHook(() => useFeedbackAttachments('feedback-1'), {
+      wrapper: createWrapper(),
+    });
+
+    await waitFor(() => {
+      expect(result.current.attachments).toHaveLength(1);
+    });
+    expect(mockTauri.getFeedbackAttachments).toHaveBeenCalledWith('feedback-1');
+  });
+
+  it('uploads pasted image bytes with their mime type', async () => {
+    const { result } = renderHook(() => useFeedbackAttachments('feedback-1'), {
+      wrapper: createWrapper(),
+    });
+
+    act(() => {
+      result.current.addAttachment(new Blob([new Uint8Array([1, 2, 3])], { type: 'image/png' }));
+    });
+
+    await waitFor(() => {
+      expect(mockTauri.addFeedbackAttachment).toHaveBeenCalledWith(
+        'feedback-1',
+        [1, 2, 3],
+        'image/png'
+      );
+    });
+  });
+});
+
 describe('useFeedbackComments', () => {
   beforeEach(() => {
     vi.clearAllMocks();
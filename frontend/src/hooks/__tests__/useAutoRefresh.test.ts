@@ -0,0 +1,24 @@
+import { describe, it, expect, vi } from 'vitest';
+import { mockTauri } from '../../test/mocks';
+import { autoRefreshIntervalMs, DEFAULT_REFRESH_INTERVAL_SECS } from '../useAutoRefresh';
+
+vi.mock('../../hooks/useTauri', () => ({
+  useTauri: () => mockTauri,
+}));
+
+describe('autoRefreshIntervalMs', () => {
+  it('is paused when auto-refresh is off', () => {
+    expect(autoRefreshIntervalMs({ autoRefresh: false, refreshInterval: 10 }, false)).toBeNull();
+  });
+
+  it('is paused while a generation is running', () => {
+    expect(autoRefreshIntervalMs({ autoRefresh: true, refreshInterval: 10 }, true)).toBeNull();
+  });
+
+  it('uses the configured interval, falling back to the default', () => {
+    expect(autoRefreshIntervalMs({ autoRefresh: true, refreshInterval: 10 }, false)).toBe(10_000);
+    expect(autoRefreshIntervalMs({ autoRefresh: true, refreshInterval: null }, false)).toBe(
+      DEFAULT_REFRESH_INTERVAL_SECS * 1000
+    );
+  });
+});
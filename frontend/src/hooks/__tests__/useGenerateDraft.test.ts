@@ -0,0 +1,14 @@
+import { describe, it, expect } from 'vitest';
+import { isDraftText } from '../useGenerateDraft';
+
+describe('isDraftText', () => {
+  it('keeps unfinished input', () => {
+    expect(isDraftText('diff --git a/x b/x', null)).toBe(true);
+    expect(isDraftText('diff --git a/x b/x', 'diff --git a/y b/y')).toBe(true);
+  });
+
+  it('drops blank input and the diff of a started generation', () => {
+    expect(isDraftText('  \n', null)).toBe(false);
+    expect(isDraftText('diff --git a/x b/x', 'diff --git a/x b/x')).toBe(false);
+  });
+});
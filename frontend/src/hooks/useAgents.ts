@@ -19,3 +19,19 @@ export function useAgents() {
     },
   });
 }
+
+/**
+ * Checks that the selected agent's binary exists and answers `--version`.
+ * Invalidated together with the agent list, so editing an agent's path in
+ * settings re-probes it.
+ */
+export function useAgentProbe(agentId: string) {
+  const { probeAgent } = useTauri();
+
+  return useQuery({
+    queryKey: queryKeys.agentProbe(agentId),
+    queryFn: () => probeAgent(agentId),
+    enabled: !!agentId,
+    staleTime: 60_000,
+  });
+}
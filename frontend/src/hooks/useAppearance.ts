@@ -0,0 +1,35 @@
+import { useEffect } from 'react';
+import { useQueryClient } from '@tanstack/react-query';
+import { listen } from '@tauri-apps/api/event';
+import { useAppearanceConfig } from './useSettings';
+import { useI18n } from './useI18n';
+import { queryKeys } from '../lib/query-keys';
+import { APPEARANCE_CHANGED_EVENT, applyTheme } from '../lib/appearance';
+
+/**
+ * Applies the configured theme and language to this window, and reloads them
+ * when another window changes them.
+ */
+export function useAppearance() {
+  const queryClient = useQueryClient();
+  const { config } = useAppearanceConfig();
+  const { language } = useI18n();
+
+  useEffect(() => {
+    applyTheme(config.highContrast);
+  }, [config.highContrast]);
+
+  useEffect(() => {
+    document.documentElement.lang = language;
+  }, [language]);
+
+  useEffect(() => {
+    const unlisten = listen(APPEARANCE_CHANGED_EVENT, () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.appearanceConfig });
+      queryClient.invalidateQueries({ queryKey: queryKeys.language });
+    });
+    return () => {
+      unlisten.then(fn => fn()).catch(console.error);
+    };
+  }, [queryClient]);
+}
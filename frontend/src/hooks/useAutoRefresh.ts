@@ -0,0 +1,42 @@
+import { useEffect } from 'react';
+import { useQueryClient } from '@tanstack/react-query';
+import { queryKeys } from '../lib/query-keys';
+import { useAppStore } from '../store';
+import { useAutoRefreshConfig, type AutoRefreshConfig } from './useSettings';
+
+export const DEFAULT_REFRESH_INTERVAL_SECS = 30;
+
+/**
+ * Polling interval in milliseconds, or null when polling should be paused:
+ * auto-refresh is off, or a generation is running and would only churn the
+ * list it is already updating.
+ */
+export function autoRefreshIntervalMs(
+  config: AutoRefreshConfig,
+  isGenerating: boolean
+): number | null {
+  if (!config.autoRefresh || isGenerating) return null;
+  return (config.refreshInterval ?? DEFAULT_REFRESH_INTERVAL_SECS) * 1000;
+}
+
+/**
+ * Reloads review data on an interval so reviews created by the CLI or
+ * another window show up without a manual refresh.
+ */
+export function useAutoRefresh() {
+  const queryClient = useQueryClient();
+  const { config } = useAutoRefreshConfig();
+  const isGenerating = useAppStore(state => state.isGenerating);
+  const intervalMs = autoRefreshIntervalMs(config, isGenerating);
+
+  useEffect(() => {
+    if (intervalMs === null) return;
+
+    const id = window.setInterval(() => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.reviews });
+      queryClient.invalidateQueries({ queryKey: ['reviewRuns'] });
+      queryClient.invalidateQueries({ queryKey: queryKeys.repos });
+    }, intervalMs);
+    return () => window.clearInterval(id);
+  }, [intervalMs, queryClient]);
+}
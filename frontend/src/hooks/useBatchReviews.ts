@@ -0,0 +1,50 @@
+import { useEffect, useState } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { useQueryClient } from '@tanstack/react-query';
+import { toast } from 'sonner';
+import { queryKeys } from '../lib/query-keys';
+import type { BatchReviewProgress } from '../types';
+
+const BATCH_PROGRESS_EVENT = 'lareview:batch-review-progress';
+
+/**
+ * Refreshes the review list as reviews of a PR batch are created and
+ * finish, so each shows as queued, generating or done, and reports the
+ * ones that fail.
+ */
+export function useBatchReviewEvents() {
+  const queryClient = useQueryClient();
+
+  useEffect(() => {
+    const unlisten = listen<BatchReviewProgress>(BATCH_PROGRESS_EVENT, event => {
+      const progress = event.payload;
+      if (progress.status === 'fetching') return;
+      queryClient.invalidateQueries({ queryKey: queryKeys.reviews });
+      if (progress.status === 'failed') {
+        toast.error(`Couldn't review #${progress.number}`, {
+          description: progress.error ?? progress.title,
+        });
+      }
+    });
+    return () => {
+      unlisten.then(fn => fn()).catch(console.error);
+    };
+  }, [queryClient]);
+}
+
+/** Latest progress of each PR of `repoId`'s batches, by PR number. */
+export function useBatchReviewProgress(repoId: string) {
+  const [progress, setProgress] = useState<Record<number, BatchReviewProgress>>({});
+
+  useEffect(() => {
+    const unlisten = listen<BatchReviewProgress>(BATCH_PROGRESS_EVENT, event => {
+      if (event.payload.repo_id !== repoId) return;
+      setProgress(prev => ({ ...prev, [event.payload.number]: event.payload }));
+    });
+    return () => {
+      unlisten.then(fn => fn()).catch(console.error);
+    };
+  }, [repoId]);
+
+  return progress;
+}
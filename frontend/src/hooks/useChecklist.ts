@@ -0,0 +1,47 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { toast } from 'sonner';
+import { useTauri } from './useTauri';
+import { queryKeys } from '../lib/query-keys';
+import type { ChecklistItem } from '../types';
+
+/** The review's follow-up checklist, with mutations to edit it. */
+export function useChecklist(reviewId: string | null) {
+  const { getChecklist, addChecklistItem, toggleChecklistItem, removeChecklistItem } = useTauri();
+  const queryClient = useQueryClient();
+  const queryKey = queryKeys.checklist(reviewId ?? '');
+
+  const query = useQuery({
+    queryKey,
+    queryFn: () => getChecklist(reviewId!),
+    enabled: !!reviewId,
+  });
+
+  const setItems = (update: (items: ChecklistItem[]) => ChecklistItem[]) =>
+    queryClient.setQueryData<ChecklistItem[]>(queryKey, items => update(items ?? []));
+
+  const onError = (error: unknown) => {
+    toast('Failed to update checklist', {
+      description: error instanceof Error ? error.message : String(error),
+    });
+  };
+
+  const addItem = useMutation({
+    mutationFn: (text: string) => addChecklistItem(reviewId!, text),
+    onSuccess: item => setItems(items => [...items, item]),
+    onError,
+  });
+
+  const toggleItem = useMutation({
+    mutationFn: (itemId: string) => toggleChecklistItem(itemId),
+    onSuccess: item => setItems(items => items.map(i => (i.id === item.id ? item : i))),
+    onError,
+  });
+
+  const removeItem = useMutation({
+    mutationFn: (itemId: string) => removeChecklistItem(itemId),
+    onSuccess: (_, itemId) => setItems(items => items.filter(i => i.id !== itemId)),
+    onError,
+  });
+
+  return { ...query, addItem, toggleItem, removeItem };
+}
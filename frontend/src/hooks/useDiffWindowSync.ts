@@ -0,0 +1,38 @@
+import { useEffect, useRef } from 'react';
+import { useQueryClient } from '@tanstack/react-query';
+import { emitTo, listen } from '@tauri-apps/api/event';
+import { queryKeys } from '../lib/query-keys';
+import {
+  DIFF_WINDOW_FEEDBACK_EVENT,
+  DIFF_WINDOW_LABEL,
+  DIFF_WINDOW_READY_EVENT,
+  DIFF_WINDOW_SYNC_EVENT,
+  type DiffWindowSelection,
+} from '../lib/diff-window';
+
+/**
+ * Keeps the detached diff window on the main window's review and task, and
+ * reloads feedback the diff window added.
+ */
+export function useDiffWindowSync(reviewId: string | null, taskId: string | null) {
+  const queryClient = useQueryClient();
+  const selection = useRef<DiffWindowSelection>({ reviewId, taskId });
+
+  useEffect(() => {
+    selection.current = { reviewId, taskId };
+    emitTo(DIFF_WINDOW_LABEL, DIFF_WINDOW_SYNC_EVENT, selection.current).catch(console.error);
+  }, [reviewId, taskId]);
+
+  useEffect(() => {
+    const unlistenReady = listen(DIFF_WINDOW_READY_EVENT, () => {
+      emitTo(DIFF_WINDOW_LABEL, DIFF_WINDOW_SYNC_EVENT, selection.current).catch(console.error);
+    });
+    const unlistenFeedback = listen(DIFF_WINDOW_FEEDBACK_EVENT, () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.feedback });
+    });
+    return () => {
+      unlistenReady.then(fn => fn()).catch(console.error);
+      unlistenFeedback.then(fn => fn()).catch(console.error);
+    };
+  }, [queryClient]);
+}
@@ -2,7 +2,7 @@ import { useQuery, useMutation, useQueryClient, keepPreviousData } from '@tansta
 import { toast } from 'sonner';
 import { useTauri } from '../hooks/useTauri';
 import { queryKeys } from '../lib/query-keys';
-import type { Feedback, Comment } from '../types';
+import type { Feedback, Comment, FeedbackAttachment } from '../types';
 import { QUERY_CONFIG } from '../constants/query-config';
 
 export interface UseFeedbackResult {
@@ -11,7 +11,7 @@ export interface UseFeedbackResult {
   isFetching: boolean;
   isPending: boolean;
   error: Error | null;
-  createFeedback: (vars: CreateFeedbackInput) => void;
+  createFeedback: (vars: CreateFeedbackInput, options?: { onSuccess?: () => void }) => void;
   isCreating: boolean;
   updateStatus: (vars: { feedbackId: string; status: Feedback['status'] }) => void;
   isUpdatingStatus: boolean;
@@ -40,6 +40,7 @@ export function useFeedback(reviewId: string | null): UseFeedbackResult {
     updateFeedbackImpact,
     deleteFeedback,
     getFeedbackByReview,
+    reanchorFeedback,
   } = useTauri();
   const queryClient = useQueryClient();
 
@@ -49,6 +50,10 @@ export function useFeedback(reviewId: string | null): UseFeedbackResult {
     queryKey,
     queryFn: async () => {
       if (!reviewId) return [];
+      // Anchors placed on an earlier run's diff are moved onto the active one first.
+      await reanchorFeedback(reviewId).catch(err =>
+        console.warn('Failed to re-anchor feedback:', err)
+      );
       const result = await getFeedbackByReview(reviewId);
       return result.map(f => ({
         ...f,
@@ -210,3 +215,115 @@ export function useAddComment() {
 
   return mutation;
 }
+
+/** Asks an agent why a finding was flagged; the answer is saved on the feedback. */
+export function useExplainFeedback(reviewId: string | null) {
+  const { explainFeedback } = useTauri();
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ feedbackId, agentId }: { feedbackId: string; agentId: string }) =>
+      explainFeedback(feedbackId, agentId),
+    onSuccess: () => {
+      if (reviewId) {
+        queryClient.invalidateQueries({
+          queryKey: queryKeys.feedbackByReview(reviewId),
+        });
+      }
+    },
+    onError: error => {
+      toast('Failed to explain feedback', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    },
+  });
+}
+
+export function useToggleFeedbackPin(reviewId: string | null) {
+  const { toggleFeedbackPin } = useTauri();
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (feedbackId: string) => toggleFeedbackPin(feedbackId),
+    onSuccess: () => {
+      if (reviewId) {
+        queryClient.invalidateQueries({
+          queryKey: queryKeys.feedbackByReview(reviewId),
+        });
+      }
+    },
+    onError: error => {
+      toast('Failed to pin feedback', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    },
+  });
+}
+
+export function useFeedbackAttachments(feedbackId: string | null) {
+  const {
+    getFeedbackAttachments,
+    addFeedbackAttachment,
+    pasteClipboardImageAttachment,
+    deleteFeedbackAttachment,
+  } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: feedbackId ? queryKeys.feedbackAttachments(feedbackId) : [],
+    queryFn: () =>
+      feedbackId
+        ? getFeedbackAttachments(feedbackId)
+        : Promise.resolve<FeedbackAttachment[]>([]),
+    enabled: !!feedbackId,
+  });
+
+  const invalidate = () => {
+    if (feedbackId) {
+      queryClient.invalidateQueries({ queryKey: queryKeys.feedbackAttachments(feedbackId) });
+    }
+  };
+  const onError = (error: unknown) => {
+    toast.error('Failed to attach image', {
+      description: error instanceof Error ? error.message : String(error),
+    });
+  };
+
+  const addMutation = useMutation({
+    mutationFn: async (file: Blob) => {
+      if (!feedbackId) throw new Error('No feedback selected');
+      const bytes = Array.from(new Uint8Array(await file.arrayBuffer()));
+      return addFeedbackAttachment(feedbackId, bytes, file.type);
+    },
+    onSuccess: invalidate,
+    onError,
+  });
+
+  const pasteMutation = useMutation({
+    mutationFn: () => {
+      if (!feedbackId) throw new Error('No feedback selected');
+      return pasteClipboardImageAttachment(feedbackId);
+    },
+    onSuccess: invalidate,
+    onError,
+  });
+
+  const deleteMutation = useMutation({
+    mutationFn: (attachmentId: string) => deleteFeedbackAttachment(attachmentId),
+    onSuccess: invalidate,
+    onError: error => {
+      toast.error('Failed to remove image', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    },
+  });
+
+  return {
+    attachments: data || [],
+    isLoading,
+    addAttachment: addMutation.mutate,
+    pasteFromClipboard: pasteMutation.mutate,
+    deleteAttachment: deleteMutation.mutate,
+    isAttaching: addMutation.isPending || pasteMutation.isPending,
+  };
+}
@@ -0,0 +1,13 @@
+import { useQuery } from '@tanstack/react-query';
+import { useTauri } from './useTauri';
+import { queryKeys } from '../lib/query-keys';
+
+export function useFileReviewStatus(reviewId: string | null | undefined) {
+  const { getFileReviewStatus } = useTauri();
+
+  return useQuery({
+    queryKey: queryKeys.fileReviewStatus(reviewId ?? ''),
+    queryFn: () => getFileReviewStatus(reviewId!),
+    enabled: !!reviewId,
+  });
+}
@@ -0,0 +1,90 @@
+import { useCallback, useEffect, useRef, useState } from 'react';
+import { useTauri } from './useTauri';
+import type { GenerateDraft } from '../types';
+
+/** Wait after the last edit before the draft is written. */
+export const DRAFT_SAVE_DELAY_MS = 1000;
+
+// Diff of the last generation started in this session; it's no longer a
+// draft. Kept outside the hook so it survives the view remounting.
+let startedDiffText: string | null = null;
+
+/** True when `diffText` is unfinished input worth keeping. */
+export function isDraftText(diffText: string, started: string | null): boolean {
+  return diffText.trim().length > 0 && diffText !== started;
+}
+
+interface GenerateDraftInput {
+  diffText: string;
+  agentId: string;
+  repoId: string;
+  prRef: string;
+}
+
+/**
+ * Keeps a scratch copy of the generate input on disk so a crash or
+ * accidental quit doesn't lose a large pasted diff. `onRestore` gets the saved
+ * draft once on mount; call `markStarted` when a generation starts, which
+ * drops the draft.
+ */
+export function useGenerateDraft(
+  input: GenerateDraftInput,
+  onRestore: (draft: GenerateDraft) => void
+) {
+  const { loadGenerateDraft, saveGenerateDraft, clearGenerateDraft } = useTauri();
+  const [isLoaded, setIsLoaded] = useState(false);
+  const onRestoreRef = useRef(onRestore);
+
+  useEffect(() => {
+    onRestoreRef.current = onRestore;
+  }, [onRestore]);
+
+  useEffect(() => {
+    let cancelled = false;
+    loadGenerateDraft()
+      .then(draft => {
+        if (!cancelled && draft && isDraftText(draft.diff_text, startedDiffText)) {
+          onRestoreRef.current(draft);
+        }
+      })
+      .catch(err => console.warn('Failed to load generate draft:', err))
+      .finally(() => {
+        if (!cancelled) setIsLoaded(true);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [loadGenerateDraft]);
+
+  const { diffText, agentId, repoId, prRef } = input;
+  useEffect(() => {
+    // Saving before the load finishes would overwrite the draft to restore.
+    if (!isLoaded) return;
+    const id = window.setTimeout(
+      () => {
+        // Checked again here: a generation may have started while waiting.
+        const write = isDraftText(diffText, startedDiffText)
+          ? saveGenerateDraft({
+              diff_text: diffText,
+              agent_id: agentId || null,
+              repo_id: repoId || null,
+              pr_ref: prRef.trim() || null,
+            })
+          : clearGenerateDraft();
+        write.catch(err => console.warn('Failed to save generate draft:', err));
+      },
+      isDraftText(diffText, startedDiffText) ? DRAFT_SAVE_DELAY_MS : 0
+    );
+    return () => window.clearTimeout(id);
+  }, [isLoaded, diffText, agentId, repoId, prRef, saveGenerateDraft, clearGenerateDraft]);
+
+  const markStarted = useCallback(
+    (text: string) => {
+      startedDiffText = text;
+      clearGenerateDraft().catch(err => console.warn('Failed to clear generate draft:', err));
+    },
+    [clearGenerateDraft]
+  );
+
+  return { markStarted };
+}
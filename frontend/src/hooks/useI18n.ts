@@ -0,0 +1,16 @@
+import { useCallback } from 'react';
+import { useLanguage } from './useSettings';
+import { resolveLanguage, translate, type MessageKey } from '../lib/i18n';
+
+/** `t()` bound to the configured UI language. */
+export function useI18n() {
+  const { language: saved } = useLanguage();
+  const language = resolveLanguage(saved);
+
+  const t = useCallback(
+    (key: MessageKey, vars?: Record<string, string | number>) => translate(language, key, vars),
+    [language]
+  );
+
+  return { t, language };
+}
@@ -1,6 +1,8 @@
-import { useQuery } from '@tanstack/react-query';
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { toast } from 'sonner';
 import { useTauri } from './useTauri';
 import { queryKeys } from '../lib/query-keys';
+import type { IssueCheckDefinitionInput } from '../types';
 
 export function useIssueChecks(runId: string | undefined) {
   const { getIssueChecksForRun } = useTauri();
@@ -11,3 +13,57 @@ export function useIssueChecks(runId: string | undefined) {
     enabled: !!runId,
   });
 }
+
+export function useIssueCheckDefinitions() {
+  const {
+    getIssueCheckDefinitions,
+    createIssueCheckDefinition,
+    updateIssueCheckDefinition,
+    deleteIssueCheckDefinition,
+  } = useTauri();
+  const queryClient = useQueryClient();
+
+  const query = useQuery({
+    queryKey: queryKeys.issueCheckDefinitions,
+    queryFn: getIssueCheckDefinitions,
+  });
+
+  const createCheck = useMutation({
+    mutationFn: (input: IssueCheckDefinitionInput) => createIssueCheckDefinition(input),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.issueCheckDefinitions });
+      toast('Check Saved', { description: 'The check runs on the next review.' });
+    },
+    onError: (error: Error) => {
+      toast('Failed to save check', { description: error.message });
+    },
+  });
+
+  const updateCheck = useMutation({
+    mutationFn: (payload: { id: string; input: IssueCheckDefinitionInput }) =>
+      updateIssueCheckDefinition(payload.id, payload.input),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.issueCheckDefinitions });
+    },
+    onError: (error: Error) => {
+      toast('Failed to update check', { description: error.message });
+    },
+  });
+
+  const removeCheck = useMutation({
+    mutationFn: (id: string) => deleteIssueCheckDefinition(id),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.issueCheckDefinitions });
+    },
+    onError: (error: Error) => {
+      toast('Failed to remove check', { description: error.message });
+    },
+  });
+
+  return {
+    ...query,
+    createCheck,
+    updateCheck,
+    removeCheck,
+  };
+}
@@ -1,19 +1,33 @@
-import { useQuery, keepPreviousData } from '@tanstack/react-query';
+import { useQuery, useQueryClient, keepPreviousData } from '@tanstack/react-query';
 import { useTauri } from '../hooks/useTauri';
+import { evictParsedDiffs } from '../lib/query-client';
+import { queryKeys } from '../lib/query-keys';
 import type { ParsedDiff } from '../types';
 
-export function useParsedDiff(runId: string | null, diffText: string | null) {
-  const { parseDiff } = useTauri();
+/**
+ * The run's diff, parsed. Run listings leave the diff text out, so it is
+ * loaded here, once per run.
+ */
+export function useParsedDiff(runId: string | null, repoRoot?: string | null) {
+  const { getRunDiff, parseDiff } = useTauri();
+  const queryClient = useQueryClient();
 
   return useQuery({
-    queryKey: ['parsedDiff', runId, diffText],
+    // A run's diff never changes, so the run id is enough to identify it
+    // without hashing the whole diff text into the key. The repo root adds
+    // its `.gitattributes` generated-file markers.
+    queryKey: queryKeys.parsedDiff(runId ?? '', repoRoot),
     queryFn: async (): Promise<ParsedDiff | null> => {
-      if (!runId || !diffText) return null;
-      return parseDiff(diffText);
+      if (!runId) return null;
+      const diffText = await getRunDiff(runId);
+      if (!diffText) return null;
+      const parsed = repoRoot ? await parseDiff(diffText, repoRoot) : await parseDiff(diffText);
+      evictParsedDiffs(queryClient);
+      return parsed;
     },
-    enabled: !!runId && !!diffText,
+    enabled: !!runId,
     staleTime: 1000 * 60 * 30,
-    gcTime: 1000 * 60 * 60,
+    gcTime: 1000 * 60 * 10,
     placeholderData: keepPreviousData,
   });
 }
@@ -0,0 +1,20 @@
+import { useEffect } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import { toast } from 'sonner';
+
+const RATE_LIMITED_EVENT = 'lareview:vcs-rate-limited';
+
+/**
+ * Tells the user when a GitHub/GitLab/Gitea call is waiting out a rate limit,
+ * so a slow fetch or push doesn't look stuck. Each wait replaces the last.
+ */
+export function useRateLimitNotices() {
+  useEffect(() => {
+    const unlisten = listen<string>(RATE_LIMITED_EVENT, event => {
+      toast(event.payload, { id: RATE_LIMITED_EVENT });
+    });
+    return () => {
+      unlisten.then(fn => fn()).catch(console.error);
+    };
+  }, []);
+}
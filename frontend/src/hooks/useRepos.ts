@@ -10,10 +10,22 @@ interface Repo {
   review_count: number;
   linked_at: string;
   remotes: string[];
+  default_agent_id?: string | null;
+  default_rule_categories?: string[];
+  watched?: boolean;
 }
 
 export function useRepos() {
-  const { getLinkedRepos, linkRepo, cloneAndLinkRepo, unlinkRepo, selectRepoFolder } = useTauri();
+  const {
+    getLinkedRepos,
+    linkRepo,
+    cloneAndLinkRepo,
+    unlinkRepo,
+    selectRepoFolder,
+    setRepoDefaults,
+    setRepoSnapshotAccess,
+    watchRepo,
+  } = useTauri();
   const queryClient = useQueryClient();
 
   const query = useQuery({
@@ -39,7 +51,7 @@ export function useRepos() {
 
   const cloneRepo = useMutation({
     mutationFn: (input: {
-      provider: 'github' | 'gitlab';
+      provider: 'github' | 'gitlab' | 'gitea';
       repo: string;
       host?: string;
       destDir: string;
@@ -89,11 +101,58 @@ export function useRepos() {
     },
   });
 
+  const setDefaults = useMutation({
+    mutationFn: (input: { repoId: string; agentId: string | null; ruleCategories: string[] }) =>
+      setRepoDefaults(input.repoId, input.agentId, input.ruleCategories),
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.repos });
+    },
+    onError: error => {
+      toast('Failed to save repository defaults', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    },
+  });
+
+  const setWatched = useMutation({
+    mutationFn: (input: { repoId: string; enabled: boolean }) =>
+      watchRepo(input.repoId, input.enabled),
+    onMutate: async ({ repoId, enabled }) => {
+      await queryClient.cancelQueries({ queryKey: queryKeys.repos });
+      queryClient.setQueryData<Repo[]>(queryKeys.repos, old =>
+        old?.map(r => (r.id === repoId ? { ...r, watched: enabled } : r))
+      );
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.repos });
+    },
+    onError: error => {
+      toast('Failed to update repository watch', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    },
+  });
+
+  const allowSnapshots = useMutation({
+    mutationFn: (repoId: string) => setRepoSnapshotAccess(repoId, true),
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.repos });
+    },
+    onError: error => {
+      toast('Failed to allow snapshots', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    },
+  });
+
   return {
     ...query,
     addRepo,
     cloneRepo,
     removeRepo,
+    setDefaults,
+    setWatched,
+    allowSnapshots,
     selectRepoFolder,
   };
 }
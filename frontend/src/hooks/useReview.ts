@@ -7,10 +7,11 @@ interface ReviewRunData {
   review_id: string;
   agent_id: string;
   input_ref: string;
-  diff_text: string;
+  diff_hash: string;
   created_at: string;
   task_count: number;
   status?: string;
+  agent_model?: string | null;
 }
 
 export interface UseReviewResult {
@@ -0,0 +1,13 @@
+import { useQuery } from '@tanstack/react-query';
+import { useTauri } from './useTauri';
+import { queryKeys } from '../lib/query-keys';
+
+export function useReviewStats(reviewId: string | null | undefined) {
+  const { getReviewStats } = useTauri();
+
+  return useQuery({
+    queryKey: queryKeys.reviewStats(reviewId ?? ''),
+    queryFn: () => getReviewStats(reviewId!),
+    enabled: !!reviewId,
+  });
+}
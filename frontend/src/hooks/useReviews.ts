@@ -1,17 +1,33 @@
-import { useQuery, useQueryClient } from '@tanstack/react-query';
+import {
+  useInfiniteQuery,
+  useMutation,
+  useQuery,
+  useQueryClient,
+  type InfiniteData,
+} from '@tanstack/react-query';
+import { toast } from 'sonner';
 import { useTauri } from '../hooks/useTauri';
 import { queryKeys } from '../lib/query-keys';
-import type { Review } from '../types';
+import type { Review, ReviewDecision, ReviewPage } from '../types';
 
+/** Reviews fetched per page of the review list. */
+export const REVIEW_PAGE_SIZE = 50;
+
+/**
+ * The review list, most recently updated first. Only the first page is
+ * fetched up front; `loadMore` appends the next one.
+ */
 export function useReviews() {
-  const { getAllReviews } = useTauri();
+  const { listReviews, addReviewTag, removeReviewTag, setReviewDecision } = useTauri();
   const queryClient = useQueryClient();
 
-  const query = useQuery({
+  const query = useInfiniteQuery({
     queryKey: queryKeys.reviews,
-    queryFn: async () => {
-      const result = await getAllReviews();
-      return result as Review[];
+    queryFn: ({ pageParam }) => listReviews(REVIEW_PAGE_SIZE, pageParam),
+    initialPageParam: 0,
+    getNextPageParam: (lastPage, pages) => {
+      const loaded = pages.reduce((count, page) => count + page.reviews.length, 0);
+      return loaded < lastPage.total ? loaded : undefined;
     },
   });
 
@@ -19,8 +35,82 @@ export function useReviews() {
     queryClient.invalidateQueries({ queryKey: queryKeys.reviews });
   };
 
+  const updateReview = (reviewId: string, update: (review: Review) => Review) => {
+    queryClient.setQueryData<InfiniteData<ReviewPage, number>>(queryKeys.reviews, old =>
+      old
+        ? {
+            ...old,
+            pages: old.pages.map(page => ({
+              ...page,
+              reviews: page.reviews.map(review =>
+                review.id === reviewId ? update(review) : review
+              ),
+            })),
+          }
+        : old
+    );
+  };
+
+  const setReviewTags = (reviewId: string, tags: string[]) => {
+    updateReview(reviewId, review => ({ ...review, tags }));
+  };
+
+  const onTagError = (error: unknown) => {
+    toast('Failed to update tags', {
+      description: error instanceof Error ? error.message : String(error),
+    });
+  };
+
+  const addTag = useMutation({
+    mutationFn: ({ reviewId, tag }: { reviewId: string; tag: string }) =>
+      addReviewTag(reviewId, tag),
+    onSuccess: (tags, { reviewId }) => setReviewTags(reviewId, tags),
+    onError: onTagError,
+  });
+
+  const removeTag = useMutation({
+    mutationFn: ({ reviewId, tag }: { reviewId: string; tag: string }) =>
+      removeReviewTag(reviewId, tag),
+    onSuccess: (tags, { reviewId }) => setReviewTags(reviewId, tags),
+    onError: onTagError,
+  });
+
+  const setDecision = useMutation({
+    mutationFn: ({ reviewId, decision }: { reviewId: string; decision: ReviewDecision | null }) =>
+      setReviewDecision(reviewId, decision),
+    onSuccess: (_, { reviewId, decision }) =>
+      updateReview(reviewId, review => ({ ...review, decision })),
+    onError: (error: unknown) => {
+      toast('Failed to set review decision', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    },
+  });
+
+  const data = query.data?.pages.flatMap(page => page.reviews);
+
   return {
     ...query,
+    data,
+    total: query.data?.pages.at(-1)?.total ?? 0,
+    hasMore: query.hasNextPage,
+    loadMore: () => query.fetchNextPage(),
     invalidate,
+    addTag,
+    removeTag,
+    setDecision,
   };
 }
+
+/** Reviews matching `query` by title/summary, ranked by relevance. */
+export function useReviewSearch(query: string) {
+  const { searchReviews } = useTauri();
+  const trimmed = query.trim();
+
+  return useQuery({
+    queryKey: queryKeys.reviewSearch(trimmed),
+    queryFn: () => searchReviews(trimmed),
+    enabled: trimmed.length > 0,
+    placeholderData: previous => previous,
+  });
+}
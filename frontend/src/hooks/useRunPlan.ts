@@ -0,0 +1,13 @@
+import { useQuery } from '@tanstack/react-query';
+import { useTauri } from './useTauri';
+import { queryKeys } from '../lib/query-keys';
+
+export function useRunPlan(runId: string | undefined) {
+  const { getRunPlan } = useTauri();
+
+  return useQuery({
+    queryKey: queryKeys.runPlan(runId ?? ''),
+    queryFn: () => getRunPlan(runId!),
+    enabled: !!runId,
+  });
+}
@@ -0,0 +1,13 @@
+import { useQuery } from '@tanstack/react-query';
+import { useTauri } from './useTauri';
+import { queryKeys } from '../lib/query-keys';
+
+export function useRunToolCalls(runId: string | undefined) {
+  const { getRunToolCalls } = useTauri();
+
+  return useQuery({
+    queryKey: queryKeys.runToolCalls(runId ?? ''),
+    queryFn: () => getRunToolCalls(runId!),
+    enabled: !!runId,
+  });
+}
@@ -1,6 +1,7 @@
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { useTauri } from './useTauri';
 import { queryKeys } from '../lib/query-keys';
+import type { DataDirInfo, VcsCliConfig } from '../types';
 
 export interface FeedbackFilterConfig {
   confidenceThreshold: number | null;
@@ -10,6 +11,11 @@ export interface TimeoutConfig {
   timeoutSecs: number | null;
 }
 
+export interface AutoRefreshConfig {
+  autoRefresh: boolean;
+  refreshInterval: number | null;
+}
+
 export function useTimeoutConfig() {
   const { getTimeoutConfig, updateTimeoutConfig } = useTauri();
   const queryClient = useQueryClient();
@@ -40,6 +46,307 @@ export function useTimeoutConfig() {
   };
 }
 
+export function useOfflineMode() {
+  const { getOfflineMode, updateOfflineMode } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.offlineMode,
+    queryFn: getOfflineMode,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (offline: boolean) => updateOfflineMode(offline),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.offlineMode });
+    },
+  });
+
+  return {
+    offline: data ?? false,
+    isLoading,
+    setOffline: updateMutation.mutate,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useCompletionNotifications() {
+  const { getCompletionNotifications, updateCompletionNotifications } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.completionNotifications,
+    queryFn: getCompletionNotifications,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (enabled: boolean) => updateCompletionNotifications(enabled),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.completionNotifications });
+    },
+  });
+
+  return {
+    enabled: data ?? false,
+    isLoading,
+    setEnabled: updateMutation.mutate,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useRejectionExamples() {
+  const { getRejectionExamples, updateRejectionExamples } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.rejectionExamples,
+    queryFn: getRejectionExamples,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (enabled: boolean) => updateRejectionExamples(enabled),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.rejectionExamples });
+    },
+  });
+
+  return {
+    enabled: data ?? false,
+    isLoading,
+    setEnabled: updateMutation.mutate,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useAutoRefreshConfig() {
+  const { getAutoRefreshConfig, updateAutoRefreshConfig } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.autoRefreshConfig,
+    queryFn: async (): Promise<AutoRefreshConfig> => {
+      const config = await getAutoRefreshConfig();
+      return {
+        autoRefresh: config.auto_refresh,
+        refreshInterval: config.refresh_interval,
+      };
+    },
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (config: AutoRefreshConfig) =>
+      updateAutoRefreshConfig(config.autoRefresh, config.refreshInterval),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.autoRefreshConfig });
+    },
+  });
+
+  return {
+    config: data ?? { autoRefresh: false, refreshInterval: null },
+    isLoading,
+    updateAutoRefresh: updateMutation.mutate,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useRepoWatchInterval() {
+  const { getRepoWatchInterval, updateRepoWatchInterval } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.repoWatchInterval,
+    queryFn: getRepoWatchInterval,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (intervalSecs: number | null) => updateRepoWatchInterval(intervalSecs),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.repoWatchInterval });
+    },
+  });
+
+  return {
+    intervalSecs: data ?? null,
+    isLoading,
+    setIntervalSecs: updateMutation.mutate,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useGenerationConcurrency() {
+  const { getGenerationConcurrency, updateGenerationConcurrency } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.generationConcurrency,
+    queryFn: getGenerationConcurrency,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (limit: number | null) => updateGenerationConcurrency(limit),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.generationConcurrency });
+    },
+  });
+
+  return {
+    concurrency: data ?? null,
+    isLoading,
+    setLimit: updateMutation.mutate,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useFeedbackContextLines() {
+  const { getFeedbackContextLines, updateFeedbackContextLines } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.feedbackContextLines,
+    queryFn: getFeedbackContextLines,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (lines: number) => updateFeedbackContextLines(lines),
+    onMutate: (lines: number) => {
+      queryClient.setQueryData(queryKeys.feedbackContextLines, lines);
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.feedbackContextLines });
+    },
+  });
+
+  return {
+    contextLines: data ?? 3,
+    isLoading,
+    setContextLines: updateMutation.mutate,
+  };
+}
+
+/** Changed lines up to which the Generate view defaults to a quick review. */
+export function useQuickReviewMaxLines() {
+  const { getQuickReviewMaxLines, updateQuickReviewMaxLines } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.quickReviewMaxLines,
+    queryFn: getQuickReviewMaxLines,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (lines: number) => updateQuickReviewMaxLines(lines),
+    onMutate: (lines: number) => {
+      queryClient.setQueryData(queryKeys.quickReviewMaxLines, lines);
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.quickReviewMaxLines });
+    },
+  });
+
+  return {
+    quickMaxLines: data ?? 50,
+    isLoading,
+    setQuickMaxLines: updateMutation.mutate,
+  };
+}
+
+export function usePrFetchTimeout() {
+  const { getPrFetchTimeout, updatePrFetchTimeout } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.prFetchTimeout,
+    queryFn: getPrFetchTimeout,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (secs: number) => updatePrFetchTimeout(secs),
+    onMutate: (secs: number) => {
+      queryClient.setQueryData(queryKeys.prFetchTimeout, secs);
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.prFetchTimeout });
+    },
+  });
+
+  return {
+    prFetchTimeout: data ?? 60,
+    isLoading,
+    setPrFetchTimeout: updateMutation.mutate,
+  };
+}
+
+export interface AppearanceConfig {
+  uiScale: number;
+  highContrast: boolean;
+}
+
+export function useAppearanceConfig() {
+  const { getAppearanceConfig, updateAppearanceConfig } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.appearanceConfig,
+    queryFn: async (): Promise<AppearanceConfig> => {
+      const config = await getAppearanceConfig();
+      return { uiScale: config.ui_scale, highContrast: config.high_contrast };
+    },
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (config: AppearanceConfig) =>
+      updateAppearanceConfig(config.uiScale, config.highContrast),
+    onMutate: (config: AppearanceConfig) => {
+      queryClient.setQueryData(queryKeys.appearanceConfig, config);
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.appearanceConfig });
+    },
+  });
+
+  return {
+    config: data ?? { uiScale: 1, highContrast: false },
+    isLoading,
+    updateAppearance: updateMutation.mutate,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useUserIdentity() {
+  const { getUserIdentity, updateUserIdentity } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.userIdentity,
+    queryFn: getUserIdentity,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: ({ name, email }: { name: string; email: string | null }) =>
+      updateUserIdentity(name, email),
+    onSuccess: identity => {
+      queryClient.setQueryData(queryKeys.userIdentity, identity);
+    },
+  });
+
+  return {
+    identity: data ?? null,
+    isLoading,
+    setIdentity: updateMutation.mutateAsync,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
 export function useFeedbackFilterConfig() {
   const { getFeedbackFilterConfig, updateFeedbackFilterConfig } = useTauri();
   const queryClient = useQueryClient();
@@ -69,3 +376,170 @@ export function useFeedbackFilterConfig() {
     isUpdating: updateMutation.isPending,
   };
 }
+
+export function useGitHubEnterpriseHosts() {
+  const { getGitHubEnterpriseHosts, updateGitHubEnterpriseHosts } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.githubEnterpriseHosts,
+    queryFn: getGitHubEnterpriseHosts,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (hosts: string[]) => updateGitHubEnterpriseHosts(hosts),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.githubEnterpriseHosts });
+    },
+  });
+
+  return {
+    hosts: data ?? [],
+    isLoading,
+    setHosts: updateMutation.mutateAsync,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useVcsCliConfig(providerId: string) {
+  const { getVcsCliConfig, updateVcsCliConfig } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.vcsCliConfig(providerId),
+    queryFn: () => getVcsCliConfig(providerId),
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (config: VcsCliConfig) => updateVcsCliConfig(providerId, config),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.vcsCliConfig(providerId) });
+    },
+  });
+
+  return {
+    config: data ?? { path: null, default_host: null },
+    isLoading,
+    setConfig: updateMutation.mutateAsync,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useDataDir() {
+  const { getDataDir, setDataDir } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.dataDir,
+    queryFn: getDataDir,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (path: string | null) => setDataDir(path),
+    onSuccess: (info: DataDirInfo) => {
+      queryClient.setQueryData(queryKeys.dataDir, info);
+      // Everything else now comes from the database in the new directory.
+      queryClient.invalidateQueries({
+        predicate: query => query.queryKey[0] !== queryKeys.dataDir[0],
+      });
+    },
+  });
+
+  return {
+    info: data,
+    isLoading,
+    setDataDir: updateMutation.mutateAsync,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useEncryptionStatus() {
+  const { getEncryptionStatus, enableEncryption, unlockDatabase } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.encryptionStatus,
+    queryFn: getEncryptionStatus,
+    staleTime: Infinity,
+  });
+
+  const enableMutation = useMutation({
+    mutationFn: (passphrase: string) => enableEncryption(passphrase),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.encryptionStatus });
+    },
+  });
+
+  const unlockMutation = useMutation({
+    mutationFn: (passphrase: string) => unlockDatabase(passphrase),
+    onSuccess: () => {
+      // Everything loaded so far came from the empty stand-in database.
+      queryClient.invalidateQueries();
+    },
+  });
+
+  return {
+    status: data,
+    isLoading,
+    enable: enableMutation.mutateAsync,
+    isEnabling: enableMutation.isPending,
+    unlock: unlockMutation.mutateAsync,
+    isUnlocking: unlockMutation.isPending,
+  };
+}
+
+export function useGeneratedFilePatterns() {
+  const { getGeneratedFilePatterns, updateGeneratedFilePatterns } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.generatedFilePatterns,
+    queryFn: getGeneratedFilePatterns,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (patterns: string[] | null) => updateGeneratedFilePatterns(patterns),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.generatedFilePatterns });
+    },
+  });
+
+  return {
+    patterns: data ?? [],
+    isLoading,
+    setPatterns: updateMutation.mutateAsync,
+    isUpdating: updateMutation.isPending,
+  };
+}
+
+export function useLanguage() {
+  const { getLanguage, updateLanguage } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data, isLoading } = useQuery({
+    queryKey: queryKeys.language,
+    queryFn: getLanguage,
+    staleTime: Infinity,
+  });
+
+  const updateMutation = useMutation({
+    mutationFn: (language: string | null) => updateLanguage(language),
+    onMutate: (language: string | null) => {
+      queryClient.setQueryData(queryKeys.language, language);
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.language });
+    },
+  });
+
+  return {
+    language: data ?? null,
+    isLoading,
+    setLanguage: updateMutation.mutate,
+    isUpdating: updateMutation.isPending,
+  };
+}
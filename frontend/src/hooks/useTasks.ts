@@ -74,3 +74,125 @@ export function useTasks(runId: string | null): UseTasksResult & {
     isUpdatingStatus: mutation.isPending,
   };
 }
+
+export interface UseSubFlowsResult {
+  order: string[];
+  renameSubFlow: (vars: { oldName: string | null; newName: string }) => void;
+  moveTask: (vars: { taskId: string; subFlow: string | null }) => void;
+  reorder: (order: string[]) => void;
+}
+
+/** Saved sub-flow order for a run, plus the edits that curate sub-flows. */
+export function useSubFlows(runId: string | null): UseSubFlowsResult {
+  const { getSubFlowOrder, setSubFlowOrder, renameSubFlow, setTaskSubFlow } = useTauri();
+  const queryClient = useQueryClient();
+
+  const { data } = useQuery({
+    queryKey: runId ? queryKeys.subFlowOrder(runId) : [],
+    queryFn: () => (runId ? getSubFlowOrder(runId) : Promise.resolve<string[]>([])),
+    enabled: !!runId,
+  });
+
+  const refresh = () => {
+    if (!runId) return;
+    queryClient.invalidateQueries({ queryKey: queryKeys.tasks(runId) });
+    queryClient.invalidateQueries({ queryKey: queryKeys.subFlowOrder(runId) });
+  };
+  const onError = (error: Error) => {
+    toast('Failed to update sub-flows', { description: error.message });
+  };
+
+  const rename = useMutation({
+    mutationFn: ({ oldName, newName }: { oldName: string | null; newName: string }) =>
+      runId ? renameSubFlow(runId, oldName, newName) : Promise.resolve(0),
+    onSuccess: refresh,
+    onError,
+  });
+
+  const move = useMutation({
+    mutationFn: ({ taskId, subFlow }: { taskId: string; subFlow: string | null }) =>
+      setTaskSubFlow(taskId, subFlow),
+    onSuccess: refresh,
+    onError,
+  });
+
+  const reorder = useMutation({
+    mutationFn: (order: string[]) => (runId ? setSubFlowOrder(runId, order) : Promise.resolve()),
+    onMutate: order => {
+      if (runId) queryClient.setQueryData(queryKeys.subFlowOrder(runId), order);
+    },
+    onSettled: refresh,
+    onError,
+  });
+
+  return {
+    order: data || [],
+    renameSubFlow: rename.mutate,
+    moveTask: move.mutate,
+    reorder: reorder.mutate,
+  };
+}
+
+export interface TaskFormFields {
+  title: string;
+  description: string;
+  files: string[];
+  subFlow: string | null;
+}
+
+/** Create, edit and delete reviewer-authored tasks in a run, and merge tasks. */
+export function useTaskEditor(runId: string | null) {
+  const { createTask, updateTask, deleteTask, mergeTasks } = useTauri();
+  const queryClient = useQueryClient();
+
+  const refresh = () => {
+    if (runId) queryClient.invalidateQueries({ queryKey: queryKeys.tasks(runId) });
+    // Task counts in the review list include user tasks.
+    queryClient.invalidateQueries({ queryKey: queryKeys.reviews });
+  };
+  const onError = (error: Error) => {
+    toast('Failed to save task', { description: error.message });
+  };
+
+  const create = useMutation({
+    mutationFn: (fields: TaskFormFields) =>
+      runId ? createTask(runId, fields) : Promise.reject(new Error('No active run')),
+    onSuccess: refresh,
+    onError,
+  });
+
+  const update = useMutation({
+    mutationFn: ({ taskId, fields }: { taskId: string; fields: TaskFormFields }) =>
+      updateTask(taskId, fields),
+    onSuccess: refresh,
+    onError,
+  });
+
+  const remove = useMutation({
+    mutationFn: (taskId: string) => deleteTask(taskId),
+    onSuccess: refresh,
+    onError,
+  });
+
+  const merge = useMutation({
+    mutationFn: (taskIds: string[]) =>
+      runId ? mergeTasks(runId, taskIds) : Promise.reject(new Error('No active run')),
+    onSuccess: () => {
+      refresh();
+      // Feedback of the merged-away tasks now points at the kept one.
+      queryClient.invalidateQueries({ queryKey: queryKeys.feedback });
+    },
+    onError: (error: Error) => {
+      toast('Failed to merge tasks', { description: error.message });
+    },
+  });
+
+  return {
+    createTask: create.mutateAsync,
+    updateTask: update.mutateAsync,
+    deleteTask: remove.mutate,
+    mergeTasks: merge.mutateAsync,
+    isMerging: merge.isPending,
+    isSaving: create.isPending || update.isPending,
+  };
+}
@@ -1,20 +1,38 @@
 import { invoke, Channel } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
-import { open } from '@tauri-apps/plugin-dialog';
+import { open, save } from '@tauri-apps/plugin-dialog';
 import type { EventCallback } from '@tauri-apps/api/event';
 import type {
   ParsedDiff,
+  Review,
   ReviewTask,
   Comment,
+  FeedbackAttachment,
   Agent,
+  AgentProbe,
   LinkedRepo,
+  OpenPullRequest,
   VcsStatus,
+  VcsCliConfig,
+  DataDirInfo,
+  GenerateDraft,
+  RecentPendingDiff,
+  PendingReviewState,
+  EncryptionStatus,
   EditorCandidate,
   EditorConfig,
   CliStatus,
+  CliInstallOutcome,
+  DiagnosticsReport,
+  ReviewMode,
   ReviewSource,
+  ReviewMergeOutcome,
+  ReviewPage,
+  RunToolLog,
   ReviewRule,
   IssueCheckWithFindings,
+  IssueCheckDefinition,
+  IssueCheckDefinitionInput,
   LibraryRule,
   DefaultIssueCategory,
   LibraryCategory,
@@ -23,6 +41,12 @@ import type {
   LearningStatus,
   LearningCompactionResult,
   MergeConfidence,
+  ReviewStats,
+  FileReviewStatus,
+  ReviewDecision,
+  ChecklistItem,
+  GhReviewExport,
+  RemoteReviewPush,
 } from '../types';
 import { useCallback } from 'react';
 
@@ -164,6 +188,7 @@ export interface ProgressEventPayload {
     | 'ToolCallComplete'
     | 'TaskStarted'
     | 'TaskCompleted'
+    | 'Queued'
     | 'Completed'
     | 'Error'
     | 'Plan';
@@ -180,11 +205,27 @@ export interface ProgressEventPayload {
       }
     | { task_id: string; title: string }
     | { task_id: string }
-    | { task_count: number }
+    | { limit: number }
+    | { task_count: number; feedback_count?: number }
     | { message: string }
     | Plan;
 }
 
+export interface UserIdentity {
+  name: string;
+  email: string | null;
+  /** Where the name came from: the setting, git's user.name, or the OS user. */
+  source: 'settings' | 'git' | 'os' | 'fallback';
+}
+
+export interface GenerationConcurrency {
+  limit: number;
+  default_limit: number;
+  max_limit: number;
+  running: number;
+  queued: number;
+}
+
 export const useTauri = () => {
   const getVersion = useCallback(async (): Promise<string> => {
     return invoke('get_app_version');
@@ -214,6 +255,7 @@ export const useTauri = () => {
     return invoke('get_pending_review_from_state');
   }, []);
 
+  /** @deprecated Loads every review at once; use `listReviews`. */
   const getAllReviews = useCallback(async (): Promise<
     Array<{
       id: string;
@@ -225,11 +267,20 @@ export const useTauri = () => {
       source: ReviewSource;
       status: string;
       active_run_status?: string | null;
+      tags?: string[];
+      suggested_tags?: string[];
     }>
   > => {
     return invoke('get_all_reviews');
   }, []);
 
+  const listReviews = useCallback(
+    async (limit?: number, offset?: number): Promise<ReviewPage> => {
+      return invoke('list_reviews', { limit, offset });
+    },
+    []
+  );
+
   const getReviewRuns = useCallback(
     async (
       reviewId: string
@@ -239,10 +290,11 @@ export const useTauri = () => {
         review_id: string;
         agent_id: string;
         input_ref: string;
-        diff_text: string;
+        diff_hash: string;
         created_at: string;
         task_count: number;
         status: string;
+        plan?: Plan | null;
       }>
     > => {
       return invoke('get_review_runs', { reviewId });
@@ -250,6 +302,10 @@ export const useTauri = () => {
     []
   );
 
+  const getRunDiff = useCallback(async (runId: string): Promise<string> => {
+    return invoke('get_run_diff', { runId });
+  }, []);
+
   const getLinkedRepos = useCallback(async (): Promise<
     Array<{
       id: string;
@@ -259,13 +315,23 @@ export const useTauri = () => {
       linked_at: string;
       remotes: string[];
       allow_snapshot_access: boolean;
+      default_agent_id?: string | null;
+      default_rule_categories?: string[];
+      watched?: boolean;
     }>
   > => {
     return invoke('get_linked_repos');
   }, []);
 
-  const parseDiff = useCallback(async (diffText: string): Promise<ParsedDiff> => {
-    return invoke('parse_diff', { diffText });
+  const parseDiff = useCallback(
+    async (diffText: string, repoRoot?: string): Promise<ParsedDiff> => {
+      return invoke('parse_diff', { diffText, repoRoot });
+    },
+    []
+  );
+
+  const loadPatchFiles = useCallback(async (paths: string[]): Promise<ParsedDiff> => {
+    return invoke('load_patch_files', { paths });
   }, []);
 
   const getFileContent = useCallback(
@@ -283,8 +349,15 @@ export const useTauri = () => {
       repoId?: string,
       source?: ReviewSource,
       useSnapshot?: boolean,
-      onProgress?: Channel<ProgressEventPayload>
-    ): Promise<{ task_count: number; review_id: string; run_id?: string }> => {
+      onProgress?: Channel<ProgressEventPayload>,
+      excludedPaths?: string[],
+      mode?: ReviewMode
+    ): Promise<{
+      task_count: number;
+      feedback_count?: number;
+      review_id: string;
+      run_id?: string;
+    }> => {
       return invoke('generate_review', {
         diffText,
         agentId,
@@ -293,11 +366,24 @@ export const useTauri = () => {
         source,
         useSnapshot: useSnapshot || false,
         onProgress,
+        excludedPaths,
+        mode,
       });
     },
     []
   );
 
+  const summarizeReview = useCallback(
+    async (
+      diffText: string,
+      agentId: string,
+      source?: ReviewSource
+    ): Promise<{ task_count: number; review_id: string; run_id?: string }> => {
+      return invoke('summarize_review', { diffText, agentId, source });
+    },
+    []
+  );
+
   const loadTasks = useCallback(async (runId?: string): Promise<ReviewTask[]> => {
     return invoke('load_tasks', { runId });
   }, []);
@@ -306,6 +392,56 @@ export const useTauri = () => {
     return invoke('update_task_status', { taskId, status });
   }, []);
 
+  const createTask = useCallback(
+    async (
+      runId: string,
+      task: { title: string; description: string; files: string[]; subFlow: string | null }
+    ): Promise<ReviewTask> => {
+      return invoke('create_task', { runId, ...task });
+    },
+    []
+  );
+
+  const updateTask = useCallback(
+    async (
+      taskId: string,
+      task: { title: string; description: string; files: string[]; subFlow: string | null }
+    ): Promise<ReviewTask> => {
+      return invoke('update_task', { taskId, ...task });
+    },
+    []
+  );
+
+  const deleteTask = useCallback(async (taskId: string): Promise<void> => {
+    return invoke('delete_task', { taskId });
+  }, []);
+
+  const mergeTasks = useCallback(async (runId: string, taskIds: string[]): Promise<ReviewTask> => {
+    return invoke('merge_tasks', { runId, taskIds });
+  }, []);
+
+  const renameSubFlow = useCallback(
+    async (runId: string, oldName: string | null, newName: string): Promise<number> => {
+      return invoke('rename_sub_flow', { runId, old: oldName, new: newName });
+    },
+    []
+  );
+
+  const setTaskSubFlow = useCallback(
+    async (taskId: string, subFlow: string | null): Promise<void> => {
+      return invoke('set_task_sub_flow', { taskId, subFlow });
+    },
+    []
+  );
+
+  const getSubFlowOrder = useCallback(async (runId: string): Promise<string[]> => {
+    return invoke('get_sub_flow_order', { runId });
+  }, []);
+
+  const setSubFlowOrder = useCallback(async (runId: string, order: string[]): Promise<void> => {
+    return invoke('set_sub_flow_order', { runId, order });
+  }, []);
+
   const saveFeedback = useCallback(
     async (feedback: {
       review_id: string;
@@ -331,6 +467,31 @@ export const useTauri = () => {
     return invoke('add_comment', { feedbackId, body });
   }, []);
 
+  const getFeedbackAttachments = useCallback(
+    async (feedbackId: string): Promise<FeedbackAttachment[]> => {
+      return invoke('get_feedback_attachments', { feedbackId });
+    },
+    []
+  );
+
+  const addFeedbackAttachment = useCallback(
+    async (feedbackId: string, bytes: number[], mime: string): Promise<FeedbackAttachment> => {
+      return invoke('add_feedback_attachment', { feedbackId, bytes, mime });
+    },
+    []
+  );
+
+  const pasteClipboardImageAttachment = useCallback(
+    async (feedbackId: string): Promise<FeedbackAttachment> => {
+      return invoke('paste_clipboard_image_attachment', { feedbackId });
+    },
+    []
+  );
+
+  const deleteFeedbackAttachment = useCallback(async (attachmentId: string): Promise<void> => {
+    return invoke('delete_feedback_attachment', { attachmentId });
+  }, []);
+
   const updateFeedbackStatus = useCallback(
     async (feedbackId: string, status: string): Promise<void> => {
       return invoke('update_feedback_status', { feedbackId, status });
@@ -345,6 +506,10 @@ export const useTauri = () => {
     []
   );
 
+  const toggleFeedbackPin = useCallback(async (feedbackId: string): Promise<boolean> => {
+    return invoke('toggle_feedback_pin', { feedbackId });
+  }, []);
+
   const deleteFeedback = useCallback(async (feedbackId: string): Promise<void> => {
     return invoke('delete_feedback', { feedbackId });
   }, []);
@@ -368,6 +533,7 @@ export const useTauri = () => {
           file_path: string | null;
           line_number: number | null;
           side: string | null;
+          outdated?: boolean;
         } | null;
         author: string;
         created_at: string;
@@ -379,35 +545,123 @@ export const useTauri = () => {
     []
   );
 
+  const reanchorFeedback = useCallback(
+    async (reviewId: string): Promise<{ moved: number; outdated: number }> => {
+      return invoke('reanchor_feedback', { reviewId });
+    },
+    []
+  );
+
   const getFeedbackDiffSnippet = useCallback(
     async (
       feedbackId: string,
       contextLines: number = 3
-    ): Promise<{
-      file_path: string;
-      hunk_header: string;
-      lines: Array<{
-        line_number: number;
-        content: string;
-        prefix: string;
-        is_addition: boolean;
-        is_deletion: boolean;
-      }>;
-      highlighted_line: number | null;
-    } | null> => {
+    ): Promise<
+      Array<{
+        file_path: string;
+        language: string;
+        hunk_header: string;
+        lines: Array<{
+          line_number: number;
+          content: string;
+          prefix: string;
+          is_addition: boolean;
+          is_deletion: boolean;
+          spans: Array<{ text: string; color: string | null }>;
+        }>;
+        highlighted_line: number | null;
+      }>
+    > => {
       return invoke('get_feedback_diff_snippet', { feedbackId, contextLines });
     },
     []
   );
 
-  const exportReview = useCallback(async (reviewId: string, format: string): Promise<string> => {
-    return invoke('export_review', { reviewId, format });
+  const highlightCode = useCallback(
+    async (
+      code: string,
+      language: string
+    ): Promise<Array<Array<{ text: string; color: string | null }>>> => {
+      return invoke('highlight_code', { code, language });
+    },
+    []
+  );
+
+  const getFeedbackRule = useCallback(async (feedbackId: string): Promise<ReviewRule | null> => {
+    return invoke('get_feedback_rule', { feedbackId });
   }, []);
 
+  const explainFeedback = useCallback(
+    async (feedbackId: string, agentId: string): Promise<string> => {
+      return invoke('explain_feedback', { feedbackId, agentId });
+    },
+    []
+  );
+
+  const exportReview = useCallback(
+    async (
+      reviewId: string,
+      format: string,
+      options: {
+        selectedTasks?: string[];
+        selectedFeedbacks?: string[];
+        outputPath?: string;
+      } = {}
+    ): Promise<string> => {
+      return invoke('export_review', { reviewId, format, ...options });
+    },
+    []
+  );
+
   const deleteReview = useCallback(async (reviewId: string): Promise<void> => {
     return invoke('delete_review', { reviewId });
   }, []);
 
+  const mergeReviews = useCallback(
+    async (primaryId: string, secondaryId: string): Promise<ReviewMergeOutcome> => {
+      return invoke('merge_reviews', { primaryId, secondaryId });
+    },
+    []
+  );
+
+  const searchReviews = useCallback(async (query: string): Promise<Review[]> => {
+    return invoke('search_reviews', { query });
+  }, []);
+
+  const addReviewTag = useCallback(async (reviewId: string, tag: string): Promise<string[]> => {
+    return invoke('add_review_tag', { reviewId, tag });
+  }, []);
+
+  const removeReviewTag = useCallback(async (reviewId: string, tag: string): Promise<string[]> => {
+    return invoke('remove_review_tag', { reviewId, tag });
+  }, []);
+
+  const setReviewDecision = useCallback(
+    async (reviewId: string, decision: ReviewDecision | null): Promise<void> => {
+      return invoke('set_review_decision', { reviewId, decision });
+    },
+    []
+  );
+
+  const getChecklist = useCallback(async (reviewId: string): Promise<ChecklistItem[]> => {
+    return invoke('get_checklist', { reviewId });
+  }, []);
+
+  const addChecklistItem = useCallback(
+    async (reviewId: string, text: string): Promise<ChecklistItem> => {
+      return invoke('add_checklist_item', { reviewId, text });
+    },
+    []
+  );
+
+  const toggleChecklistItem = useCallback(async (itemId: string): Promise<ChecklistItem> => {
+    return invoke('toggle_checklist_item', { itemId });
+  }, []);
+
+  const removeChecklistItem = useCallback(async (itemId: string): Promise<void> => {
+    return invoke('remove_checklist_item', { itemId });
+  }, []);
+
   const fetchRemotePr = useCallback(
     async (prRef: string, providerHint?: string | null): Promise<ParsedDiff> => {
       return invoke('fetch_remote_pr', { prRef, providerHint });
@@ -415,16 +669,39 @@ export const useTauri = () => {
     []
   );
 
+  const cancelPrFetch = useCallback(async (): Promise<void> => {
+    return invoke('cancel_pr_fetch');
+  }, []);
+
   const exportReviewMarkdown = useCallback(
     async (
       reviewId: string,
       selectedTasks: string[] = [],
-      selectedFeedbacks: string[] = []
+      selectedFeedbacks: string[] = [],
+      outputPath?: string
     ): Promise<string> => {
       return invoke('export_review_markdown', {
         reviewId,
         selectedTasks,
         selectedFeedbacks,
+        outputPath,
+      });
+    },
+    []
+  );
+
+  const exportReviewGhPayload = useCallback(
+    async (
+      reviewId: string,
+      selectedTasks: string[],
+      selectedFeedbacks: string[],
+      outputPath: string | null
+    ): Promise<GhReviewExport> => {
+      return invoke('export_review_gh_payload', {
+        reviewId,
+        selectedTasks,
+        selectedFeedbacks,
+        outputPath,
       });
     },
     []
@@ -435,7 +712,7 @@ export const useTauri = () => {
       reviewId: string,
       selectedTasks: string[],
       selectedFeedbacks: string[]
-    ): Promise<string> => {
+    ): Promise<RemoteReviewPush> => {
       return invoke('push_remote_review', {
         reviewId,
         selectedTasks,
@@ -453,14 +730,63 @@ export const useTauri = () => {
     return invoke('open_url', { url });
   }, []);
 
+  const openDiffWindow = useCallback(async (): Promise<void> => {
+    return invoke('open_diff_window');
+  }, []);
+
+  const openRunLog = useCallback(async (runId: string): Promise<string> => {
+    return invoke('open_run_log', { runId });
+  }, []);
+
   const copyToClipboard = useCallback(async (text: string): Promise<void> => {
     return invoke('copy_to_clipboard', { text });
   }, []);
 
+  const getTaskMarkdown = useCallback(async (taskId: string): Promise<string> => {
+    return invoke('get_task_markdown', { taskId });
+  }, []);
+
+  const getFeedbackMarkdown = useCallback(async (feedbackId: string): Promise<string> => {
+    return invoke('get_feedback_markdown', { feedbackId });
+  }, []);
+
+  const setRepoDefaults = useCallback(
+    async (repoId: string, agentId: string | null, ruleCategories: string[]): Promise<void> => {
+      return invoke('set_repo_defaults', { repoId, agentId, ruleCategories });
+    },
+    []
+  );
+
+  const watchRepo = useCallback(async (repoId: string, enabled: boolean): Promise<void> => {
+    return invoke('watch_repo', { repoId, enabled });
+  }, []);
+
+  const getRepoCommitDiff = useCallback(
+    async (repoId: string, from: string, to: string): Promise<string> => {
+      return invoke('get_repo_commit_diff', { repoId, from, to });
+    },
+    []
+  );
+
+  const listOpenPrs = useCallback(async (repoId: string): Promise<OpenPullRequest[]> => {
+    return invoke('list_open_prs', { repoId });
+  }, []);
+
+  const generateReviewsForPrs = useCallback(
+    async (repoId: string, prNumbers: number[], agentId: string): Promise<OpenPullRequest[]> => {
+      return invoke('generate_reviews_for_prs', { repoId, prNumbers, agentId });
+    },
+    []
+  );
+
   const getAgents = useCallback(async (): Promise<Agent[]> => {
     return invoke('get_agents');
   }, []);
 
+  const probeAgent = useCallback(async (agentId: string): Promise<AgentProbe> => {
+    return invoke('probe_agent', { agentId });
+  }, []);
+
   const getGitHubToken = useCallback(async (): Promise<string | null> => {
     return invoke('get_github_token');
   }, []);
@@ -502,7 +828,7 @@ export const useTauri = () => {
 
   const cloneAndLinkRepo = useCallback(
     async (request: {
-      provider: 'github' | 'gitlab';
+      provider: 'github' | 'gitlab' | 'gitea';
       repo: string;
       host?: string;
       destDir: string;
@@ -556,6 +882,25 @@ export const useTauri = () => {
     return result as string | null;
   }, []);
 
+  const selectDataFolder = useCallback(async (): Promise<string | null> => {
+    const result = await open({
+      directory: true,
+      multiple: false,
+      title: 'Select Data Folder',
+    });
+    return result as string | null;
+  }, []);
+
+  const selectSavePath = useCallback(
+    async (defaultPath: string, extension: string): Promise<string | null> => {
+      return save({
+        defaultPath,
+        filters: [{ name: extension.toUpperCase(), extensions: [extension] }],
+      });
+    },
+    []
+  );
+
   const onProgress = useCallback(
     (
       callback: EventCallback<{
@@ -587,32 +932,70 @@ export const useTauri = () => {
     getPendingReviews,
     getPendingReviewFromState,
     getAllReviews,
+    listReviews,
     getReviewRuns,
+    getRunDiff,
     getLinkedRepos,
     parseDiff,
+    loadPatchFiles,
     getFileContent,
     generateReview,
+    summarizeReview,
     loadTasks,
     updateTaskStatus,
+    createTask,
+    updateTask,
+    deleteTask,
+    mergeTasks,
+    renameSubFlow,
+    setTaskSubFlow,
+    getSubFlowOrder,
+    setSubFlowOrder,
     saveFeedback,
     getFeedbackComments,
     addComment,
+    getFeedbackAttachments,
+    addFeedbackAttachment,
+    pasteClipboardImageAttachment,
+    deleteFeedbackAttachment,
     updateFeedbackStatus,
     updateFeedbackImpact,
+    toggleFeedbackPin,
     deleteFeedback,
     deleteReview,
+    mergeReviews,
+    searchReviews,
+    addReviewTag,
+    setReviewDecision,
+    removeReviewTag,
+    getChecklist,
+    addChecklistItem,
+    toggleChecklistItem,
+    removeChecklistItem,
     getFeedbackByReview,
+    reanchorFeedback,
     getFeedbackDiffSnippet,
+    highlightCode,
+    getFeedbackRule,
+    explainFeedback,
     exportReview,
     fetchRemotePr,
+    cancelPrFetch,
     exportReviewMarkdown,
+    exportReviewGhPayload,
+    selectSavePath,
     pushRemoteReview,
     pushRemoteFeedback,
     openUrl,
+    openDiffWindow,
+    openRunLog,
     copyToClipboard,
+    getTaskMarkdown,
+    getFeedbackMarkdown,
     onProgress,
     onReviewComplete,
     getAgents,
+    probeAgent,
     updateAgentConfig,
     addCustomAgent,
     deleteCustomAgent,
@@ -627,8 +1010,14 @@ export const useTauri = () => {
     linkRepo,
     cloneAndLinkRepo,
     unlinkRepo,
+    setRepoDefaults,
+    watchRepo,
+    getRepoCommitDiff,
+    listOpenPrs,
+    generateReviewsForPrs,
 
     selectRepoFolder,
+    selectDataFolder,
     getAvailableEditors: useCallback(async (): Promise<EditorCandidate[]> => {
       return invoke('get_available_editors');
     }, []),
@@ -650,6 +1039,124 @@ export const useTauri = () => {
     updateTimeoutConfig: useCallback(async (timeoutSecs: number | null): Promise<void> => {
       return invoke('update_timeout_config', { timeoutSecs });
     }, []),
+    getOfflineMode: useCallback(async (): Promise<boolean> => {
+      return invoke('get_offline_mode');
+    }, []),
+    updateOfflineMode: useCallback(async (offline: boolean): Promise<void> => {
+      return invoke('update_offline_mode', { offline });
+    }, []),
+    getCompletionNotifications: useCallback(async (): Promise<boolean> => {
+      return invoke('get_completion_notifications');
+    }, []),
+    updateCompletionNotifications: useCallback(async (enabled: boolean): Promise<void> => {
+      return invoke('update_completion_notifications', { enabled });
+    }, []),
+    getGeneratedFilePatterns: useCallback(async (): Promise<string[]> => {
+      return invoke('get_generated_file_patterns');
+    }, []),
+    updateGeneratedFilePatterns: useCallback(
+      async (patterns: string[] | null): Promise<void> => {
+        return invoke('update_generated_file_patterns', { patterns });
+      },
+      []
+    ),
+    getGitHubEnterpriseHosts: useCallback(async (): Promise<string[]> => {
+      return invoke('get_github_enterprise_hosts');
+    }, []),
+    updateGitHubEnterpriseHosts: useCallback(async (hosts: string[]): Promise<void> => {
+      return invoke('update_github_enterprise_hosts', { hosts });
+    }, []),
+    getVcsCliConfig: useCallback(async (providerId: string): Promise<VcsCliConfig> => {
+      return invoke('get_vcs_cli_config', { providerId });
+    }, []),
+    updateVcsCliConfig: useCallback(
+      async (providerId: string, config: VcsCliConfig): Promise<void> => {
+        return invoke('update_vcs_cli_config', {
+          providerId,
+          path: config.path,
+          defaultHost: config.default_host,
+        });
+      },
+      []
+    ),
+    setGiteaToken: useCallback(async (host: string, token: string | null): Promise<void> => {
+      return invoke('set_gitea_token', { host, token });
+    }, []),
+    getRejectionExamples: useCallback(async (): Promise<boolean> => {
+      return invoke('get_rejection_examples');
+    }, []),
+    updateRejectionExamples: useCallback(async (enabled: boolean): Promise<void> => {
+      return invoke('update_rejection_examples', { enabled });
+    }, []),
+    getRepoWatchInterval: useCallback(async (): Promise<number | null> => {
+      return invoke('get_repo_watch_interval');
+    }, []),
+    updateRepoWatchInterval: useCallback(async (intervalSecs: number | null): Promise<void> => {
+      return invoke('update_repo_watch_interval', { intervalSecs });
+    }, []),
+    getGenerationConcurrency: useCallback(async (): Promise<GenerationConcurrency> => {
+      return invoke('get_generation_concurrency');
+    }, []),
+    updateGenerationConcurrency: useCallback(async (limit: number | null): Promise<void> => {
+      return invoke('update_generation_concurrency', { limit });
+    }, []),
+    getUserIdentity: useCallback(async (): Promise<UserIdentity> => {
+      return invoke('get_user_identity');
+    }, []),
+    updateUserIdentity: useCallback(
+      async (name: string, email: string | null): Promise<UserIdentity> => {
+        return invoke('update_user_identity', { name, email });
+      },
+      []
+    ),
+    getFeedbackContextLines: useCallback(async (): Promise<number> => {
+      return invoke('get_feedback_context_lines');
+    }, []),
+    updateFeedbackContextLines: useCallback(async (lines: number): Promise<void> => {
+      return invoke('update_feedback_context_lines', { lines });
+    }, []),
+    getQuickReviewMaxLines: useCallback(async (): Promise<number> => {
+      return invoke('get_quick_review_max_lines');
+    }, []),
+    updateQuickReviewMaxLines: useCallback(async (lines: number): Promise<void> => {
+      return invoke('update_quick_review_max_lines', { lines });
+    }, []),
+    getAppearanceConfig: useCallback(async (): Promise<{
+      ui_scale: number;
+      high_contrast: boolean;
+    }> => {
+      return invoke('get_appearance_config');
+    }, []),
+    updateAppearanceConfig: useCallback(
+      async (uiScale: number, highContrast: boolean): Promise<void> => {
+        return invoke('update_appearance_config', { uiScale, highContrast });
+      },
+      []
+    ),
+    getPrFetchTimeout: useCallback(async (): Promise<number> => {
+      return invoke('get_pr_fetch_timeout');
+    }, []),
+    updatePrFetchTimeout: useCallback(async (secs: number): Promise<void> => {
+      return invoke('update_pr_fetch_timeout', { secs });
+    }, []),
+    getLanguage: useCallback(async (): Promise<string | null> => {
+      return invoke('get_language');
+    }, []),
+    updateLanguage: useCallback(async (language: string | null): Promise<void> => {
+      return invoke('update_language', { language });
+    }, []),
+    getAutoRefreshConfig: useCallback(async (): Promise<{
+      auto_refresh: boolean;
+      refresh_interval: number | null;
+    }> => {
+      return invoke('get_auto_refresh_config');
+    }, []),
+    updateAutoRefreshConfig: useCallback(
+      async (autoRefresh: boolean, refreshInterval: number | null): Promise<void> => {
+        return invoke('update_auto_refresh_config', { autoRefresh, refreshInterval });
+      },
+      []
+    ),
     openInEditor: useCallback(
       async (filePath: string, lineNumber: number, repoRoot?: string): Promise<void> => {
         return invoke('open_in_editor', { filePath, lineNumber, repoRoot });
@@ -663,7 +1170,43 @@ export const useTauri = () => {
     getCliStatus: useCallback(async (): Promise<CliStatus> => {
       return invoke('get_cli_status');
     }, []),
-    installCli: useCallback(async (): Promise<void> => {
+    runDiagnostics: useCallback(async (): Promise<DiagnosticsReport> => {
+      return invoke('run_diagnostics');
+    }, []),
+    exportBugReport: useCallback(async (outputPath: string): Promise<string> => {
+      return invoke('export_bug_report', { outputPath });
+    }, []),
+    loadGenerateDraft: useCallback(async (): Promise<GenerateDraft | null> => {
+      return invoke('load_generate_draft');
+    }, []),
+    saveGenerateDraft: useCallback(async (draft: GenerateDraft): Promise<void> => {
+      return invoke('save_generate_draft', { draft });
+    }, []),
+    clearGenerateDraft: useCallback(async (): Promise<void> => {
+      return invoke('clear_generate_draft');
+    }, []),
+    getRecentPendingDiffs: useCallback(async (): Promise<RecentPendingDiff[]> => {
+      return invoke('get_recent_pending_diffs');
+    }, []),
+    restorePendingDiff: useCallback(async (id: string): Promise<PendingReviewState> => {
+      return invoke('restore_pending_diff', { id });
+    }, []),
+    getDataDir: useCallback(async (): Promise<DataDirInfo> => {
+      return invoke('get_data_dir');
+    }, []),
+    setDataDir: useCallback(async (path: string | null): Promise<DataDirInfo> => {
+      return invoke('set_data_dir', { path });
+    }, []),
+    getEncryptionStatus: useCallback(async (): Promise<EncryptionStatus> => {
+      return invoke('get_encryption_status');
+    }, []),
+    enableEncryption: useCallback(async (passphrase: string): Promise<void> => {
+      return invoke('enable_encryption', { passphrase });
+    }, []),
+    unlockDatabase: useCallback(async (passphrase: string): Promise<void> => {
+      return invoke('unlock_database', { passphrase });
+    }, []),
+    installCli: useCallback(async (): Promise<CliInstallOutcome> => {
       return invoke('install_cli');
     }, []),
     getDiffRequest: useCallback(async (): Promise<{
@@ -696,6 +1239,32 @@ export const useTauri = () => {
     getIssueChecksForRun: useCallback(async (runId: string): Promise<IssueCheckWithFindings[]> => {
       return invoke('get_issue_checks_for_run', { runId });
     }, []),
+    getIssueCheckDefinitions: useCallback(async (): Promise<IssueCheckDefinition[]> => {
+      return invoke('get_issue_check_definitions');
+    }, []),
+    createIssueCheckDefinition: useCallback(
+      async (input: IssueCheckDefinitionInput): Promise<IssueCheckDefinition> => {
+        return invoke('create_issue_check_definition', { input });
+      },
+      []
+    ),
+    updateIssueCheckDefinition: useCallback(
+      async (id: string, input: IssueCheckDefinitionInput): Promise<IssueCheckDefinition> => {
+        return invoke('update_issue_check_definition', { id, input });
+      },
+      []
+    ),
+    deleteIssueCheckDefinition: useCallback(async (id: string): Promise<void> => {
+      return invoke('delete_issue_check_definition', { id });
+    }, []),
+
+    getRunPlan: useCallback(async (runId: string): Promise<Plan | null> => {
+      return invoke('get_run_plan', { runId });
+    }, []),
+
+    getRunToolCalls: useCallback(async (runId: string): Promise<RunToolLog> => {
+      return invoke('get_run_tool_calls', { runId });
+    }, []),
 
     // Rule library
     getRuleLibrary: useCallback(async (): Promise<LibraryRule[]> => {
@@ -762,5 +1331,27 @@ export const useTauri = () => {
     getMergeConfidence: useCallback(async (runId: string): Promise<MergeConfidence | null> => {
       return invoke('get_merge_confidence', { runId });
     }, []),
+
+    // Review stats
+    getReviewStats: useCallback(async (reviewId: string): Promise<ReviewStats> => {
+      return invoke('get_review_stats', { reviewId });
+    }, []),
+    getReviewSummaryLine: useCallback(async (reviewId: string): Promise<string> => {
+      return invoke('get_review_summary_line', { reviewId });
+    }, []),
+    getFileReviewStatus: useCallback(async (reviewId: string): Promise<FileReviewStatus[]> => {
+      return invoke('get_file_review_status', { reviewId });
+    }, []),
+
+    // Viewed files
+    getViewedFiles: useCallback(async (runId: string): Promise<string[]> => {
+      return invoke('get_viewed_files', { runId });
+    }, []),
+    setFileViewed: useCallback(
+      async (runId: string, filePath: string, viewed: boolean): Promise<void> => {
+        return invoke('set_file_viewed', { runId, filePath, viewed });
+      },
+      []
+    ),
   };
 };
@@ -0,0 +1,40 @@
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { toast } from 'sonner';
+import { useTauri } from './useTauri';
+import { queryKeys } from '../lib/query-keys';
+
+/** Files the reviewer ticked as viewed in a run, and a setter for the tick. */
+export function useViewedFiles(runId: string | null | undefined) {
+  const { getViewedFiles, setFileViewed } = useTauri();
+  const queryClient = useQueryClient();
+  const queryKey = queryKeys.viewedFiles(runId ?? '');
+
+  const query = useQuery({
+    queryKey,
+    queryFn: () => getViewedFiles(runId!),
+    enabled: !!runId,
+  });
+
+  const setViewed = useMutation({
+    mutationFn: (payload: { filePath: string; viewed: boolean }) =>
+      setFileViewed(runId!, payload.filePath, payload.viewed),
+    onMutate: async ({ filePath, viewed }) => {
+      await queryClient.cancelQueries({ queryKey });
+      queryClient.setQueryData<string[]>(queryKey, (files = []) => {
+        const others = files.filter(file => file !== filePath);
+        return viewed ? [...others, filePath] : others;
+      });
+    },
+    onError: (error: Error) => {
+      toast('Failed to update viewed state', { description: error.message });
+    },
+    onSettled: () => {
+      queryClient.invalidateQueries({ queryKey });
+    },
+  });
+
+  return {
+    viewedFiles: query.data ?? [],
+    setViewed: (filePath: string, viewed: boolean) => setViewed.mutate({ filePath, viewed }),
+  };
+}
@@ -0,0 +1,23 @@
+import { describe, it, expect } from 'vitest';
+import { authorKind, authorName } from '../author';
+
+describe('authorName', () => {
+  it('drops the email from reviewer authors', () => {
+    expect(authorName('Ada Lovelace <ada@example.com>')).toBe('Ada Lovelace');
+    expect(authorName('ada')).toBe('ada');
+  });
+
+  it('shows the agent id for agent authors', () => {
+    expect(authorName('agent:claude')).toBe('claude');
+  });
+});
+
+describe('authorKind', () => {
+  it('tells agents, imports and people apart by prefix', () => {
+    expect(authorKind('agent:claude')).toBe('agent');
+    expect(authorKind('agent')).toBe('agent');
+    expect(authorKind('import:github')).toBe('imported');
+    expect(authorKind('user')).toBe('user');
+    expect(authorKind('agentsmith')).toBe('user');
+  });
+});
@@ -0,0 +1,16 @@
+import { describe, it, expect } from 'vitest';
+import { EMPTY_DIFF_MESSAGE, NOT_A_DIFF_MESSAGE, validateDiffInput } from '../diff-input';
+
+describe('validateDiffInput', () => {
+  it('tells empty input apart from text that is not a diff', () => {
+    expect(validateDiffInput('')).toBe(EMPTY_DIFF_MESSAGE);
+    expect(validateDiffInput('  \n\t')).toBe(EMPTY_DIFF_MESSAGE);
+    expect(validateDiffInput('fn main() {\n    println!("hi");\n}\n')).toBe(NOT_A_DIFF_MESSAGE);
+    expect(validateDiffInput('Some notes\n---\n+++ more\n')).toBe(NOT_A_DIFF_MESSAGE);
+  });
+
+  it('accepts git and plain unified diffs', () => {
+    expect(validateDiffInput('diff --git a/run.sh b/run.sh\r\nold mode 100644\r\n')).toBeNull();
+    expect(validateDiffInput('--- a.txt\n+++ b.txt\n@@ -1 +1 @@\n-a\n+b\n')).toBeNull();
+  });
+});
@@ -0,0 +1,32 @@
+import { describe, it, expect } from 'vitest';
+import { buildDiffSides, MAX_RENDERED_LINE_LENGTH } from '../diff-sides';
+import type { DiffHunk } from '../../types';
+
+const hunk = (content: string, old_start = 1, new_start = 1): DiffHunk => ({
+  old_start,
+  old_lines: 0,
+  new_start,
+  new_lines: 0,
+  content,
+});
+
+describe('buildDiffSides', () => {
+  it('splits hunks into old and new text with file line numbers', () => {
+    const sides = buildDiffSides([hunk('@@ -3,2 +3,2 @@\n keep\n-old\n+new', 3, 3)]);
+    expect(sides.original).toBe('keep\nold');
+    expect(sides.modified).toBe('keep\nnew');
+    expect(sides.originalLineMap).toEqual([3, 4]);
+    expect(sides.modifiedLineMap).toEqual([3, 4]);
+    expect(sides.truncatedLines).toBe(0);
+  });
+
+  it('cuts a 100k-character minified line', () => {
+    const minified = 'a'.repeat(100_000);
+    const sides = buildDiffSides([hunk(`@@ -0,0 +1,1 @@\n+${minified}`, 0, 1)]);
+
+    expect(sides.truncatedLines).toBe(1);
+    expect(sides.modified.length).toBeLessThan(MAX_RENDERED_LINE_LENGTH + 50);
+    expect(sides.modified).toContain('95000 more characters');
+    expect(sides.modifiedLineMap).toEqual([1]);
+  });
+});
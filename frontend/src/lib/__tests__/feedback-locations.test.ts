@@ -0,0 +1,35 @@
+import { describe, it, expect } from 'vitest';
+import { feedbackLocations, snippetForAnchor } from '../feedback-locations';
+import type { Feedback, FeedbackAnchor } from '../../types';
+
+const anchor = (file_path: string | null, line_number: number | null): FeedbackAnchor => ({
+  file_path,
+  line_number,
+  side: 'new',
+});
+
+describe('feedbackLocations', () => {
+  it('lists the primary anchor first, then the extra ones', () => {
+    const feedback = {
+      anchor: anchor('src/a.rs', 2),
+      extra_anchors: [anchor('src/b.rs', 11), anchor(null, null)],
+    } as Feedback;
+    expect(feedbackLocations(feedback).map(a => a.file_path)).toEqual(['src/a.rs', 'src/b.rs']);
+  });
+
+  it('handles single-location and general feedback', () => {
+    expect(feedbackLocations({ anchor: anchor('src/a.rs', 2) } as Feedback)).toHaveLength(1);
+    expect(feedbackLocations({ anchor: null } as Feedback)).toEqual([]);
+  });
+});
+
+describe('snippetForAnchor', () => {
+  it('matches snippets by file and line', () => {
+    const snippets = [
+      { file_path: 'src/a.rs', highlighted_line: 2 },
+      { file_path: 'src/b.rs', highlighted_line: 11 },
+    ];
+    expect(snippetForAnchor(snippets, anchor('src/b.rs', 11))).toBe(snippets[1]);
+    expect(snippetForAnchor(snippets, anchor('src/b.rs', 12))).toBeNull();
+  });
+});
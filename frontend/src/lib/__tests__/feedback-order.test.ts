@@ -0,0 +1,72 @@
+import { describe, it, expect } from 'vitest';
+import { compareFeedback, nextBlockingFeedback } from '../feedback-order';
+import type { Feedback } from '../../types';
+
+const feedback = (
+  id: string,
+  impact: Feedback['impact'],
+  status: Feedback['status'] = 'todo',
+  updated_at = '2024-01-01T00:00:00Z'
+) => ({ id, impact, status, updated_at }) as Feedback;
+
+describe('compareFeedback', () => {
+  it('sorts open feedback by severity, blocking first', () => {
+    const sorted = [
+      feedback('nit', 'nitpick'),
+      feedback('nice', 'nice_to_have'),
+      feedback('block', 'blocking'),
+    ].sort(compareFeedback);
+    expect(sorted.map(f => f.id)).toEqual(['block', 'nice', 'nit']);
+  });
+
+  it('keeps closed feedback after open feedback and newest first within a level', () => {
+    const sorted = [
+      feedback('done-block', 'blocking', 'done'),
+      feedback('old-nit', 'nitpick', 'todo', '2024-01-01T00:00:00Z'),
+      feedback('new-nit', 'nitpick', 'todo', '2024-02-01T00:00:00Z'),
+    ].sort(compareFeedback);
+    expect(sorted.map(f => f.id)).toEqual(['new-nit', 'old-nit', 'done-block']);
+  });
+});
+
+describe('nextBlockingFeedback', () => {
+  const at = (
+    id: string,
+    file: string,
+    line: number,
+    impact: Feedback['impact'] = 'blocking',
+    status: Feedback['status'] = 'todo'
+  ) =>
+    ({
+      id,
+      impact,
+      status,
+      created_at: '2024-01-01T00:00:00Z',
+      anchor: { file_path: file, line_number: line },
+    }) as Feedback;
+
+  const feedbacks = [
+    at('b-20', 'src/b.rs', 20),
+    at('a-10', 'src/a.rs', 10),
+    at('nit', 'src/a.rs', 5, 'nitpick'),
+    at('done', 'src/a.rs', 15, 'blocking', 'done'),
+    at('ignored', 'src/c.rs', 1, 'blocking', 'ignored'),
+    at('b-3', 'src/b.rs', 3, 'blocking', 'in_progress'),
+  ];
+
+  it('walks open blockers in diff order and wraps around', () => {
+    expect(nextBlockingFeedback(feedbacks, null)?.id).toBe('a-10');
+    expect(nextBlockingFeedback(feedbacks, 'a-10')?.id).toBe('b-3');
+    expect(nextBlockingFeedback(feedbacks, 'b-3')?.id).toBe('b-20');
+    expect(nextBlockingFeedback(feedbacks, 'b-20')?.id).toBe('a-10');
+  });
+
+  it('starts from the first blocker when another finding is selected', () => {
+    expect(nextBlockingFeedback(feedbacks, 'nit')?.id).toBe('a-10');
+  });
+
+  it('returns null when no blocker is open', () => {
+    expect(nextBlockingFeedback([at('done', 'src/a.rs', 1, 'blocking', 'done')], null)).toBeNull();
+    expect(nextBlockingFeedback([], 'a-10')).toBeNull();
+  });
+});
@@ -0,0 +1,47 @@
+import { describe, it, expect } from 'vitest';
+import { buildFileTree } from '../file-tree';
+import type { FileReviewStatus } from '../../types';
+
+const status = (path: string, overrides: Partial<FileReviewStatus> = {}): FileReviewStatus => ({
+  path,
+  open_feedback: 0,
+  closed_feedback: 0,
+  tasks: 0,
+  closed_tasks: 0,
+  in_diff: true,
+  ...overrides,
+});
+
+describe('buildFileTree', () => {
+  it('nests files under directories and sums their counts', () => {
+    const tree = buildFileTree([
+      status('README.md'),
+      status('src/a.rs', { open_feedback: 2, tasks: 1, closed_tasks: 1 }),
+      status('src/b.rs', { closed_feedback: 1, tasks: 2 }),
+    ]);
+
+    expect(tree.map(node => node.name)).toEqual(['src', 'README.md']);
+    expect(tree[0].children.map(node => node.name)).toEqual(['a.rs', 'b.rs']);
+    expect(tree[0].counts).toEqual({
+      openFeedback: 2,
+      closedFeedback: 1,
+      tasks: 3,
+      closedTasks: 1,
+    });
+  });
+
+  it('collapses chains of single directories', () => {
+    const tree = buildFileTree([
+      status('frontend/src/lib/a.ts'),
+      status('frontend/src/lib/b.ts'),
+    ]);
+
+    expect(tree).toHaveLength(1);
+    expect(tree[0].name).toBe('frontend/src/lib');
+    expect(tree[0].path).toBe('frontend/src/lib');
+    expect(tree[0].children.map(node => node.path)).toEqual([
+      'frontend/src/lib/a.ts',
+      'frontend/src/lib/b.ts',
+    ]);
+  });
+});
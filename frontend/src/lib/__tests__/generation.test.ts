@@ -0,0 +1,28 @@
+import { describe, it, expect } from 'vitest';
+import { completionMessage, hasReviewContent } from '../generation';
+
+describe('hasReviewContent', () => {
+  it('treats feedback without tasks as a review', () => {
+    expect(hasReviewContent({ task_count: 0, feedback_count: 2 })).toBe(true);
+    expect(hasReviewContent({ task_count: 3, feedback_count: 0 })).toBe(true);
+  });
+
+  it('is empty only when there are no tasks and no feedback', () => {
+    expect(hasReviewContent({ task_count: 0, feedback_count: 0 })).toBe(false);
+    expect(hasReviewContent({ task_count: 0 })).toBe(false);
+  });
+});
+
+describe('completionMessage', () => {
+  it('describes a feedback-only run', () => {
+    expect(completionMessage({ task_count: 0, feedback_count: 1 })).toBe(
+      'Review complete with 1 feedback item and no tasks.'
+    );
+  });
+
+  it('says when nothing was produced', () => {
+    expect(completionMessage({ task_count: 0, feedback_count: 0 })).toBe(
+      'The agent finished without tasks or feedback.'
+    );
+  });
+});
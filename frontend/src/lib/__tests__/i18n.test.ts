@@ -0,0 +1,27 @@
+import { describe, it, expect } from 'vitest';
+import en from '../../locales/en.json';
+import es from '../../locales/es.json';
+import { resolveLanguage, translate } from '../i18n';
+
+describe('resolveLanguage', () => {
+  it('prefers the saved language over the system one', () => {
+    expect(resolveLanguage('es', 'en-US')).toBe('es');
+    expect(resolveLanguage(null, 'es-MX')).toBe('es');
+  });
+
+  it('falls back to English for languages without a bundle', () => {
+    expect(resolveLanguage('fr', 'de-DE')).toBe('en');
+    expect(resolveLanguage('fr', 'es-ES')).toBe('es');
+  });
+});
+
+describe('translate', () => {
+  it('fills placeholders', () => {
+    expect(translate('en', 'nav.goTo', { page: 'Rules' })).toBe('Navigate to Rules');
+    expect(translate('es', 'nav.goTo', { page: 'Reglas' })).toBe('Ir a Reglas');
+  });
+
+  it('only has translations for keys that exist in English', () => {
+    expect(Object.keys(es).filter(key => !(key in en))).toEqual([]);
+  });
+});
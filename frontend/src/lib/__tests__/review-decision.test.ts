@@ -0,0 +1,45 @@
+import { describe, it, expect } from 'vitest';
+import { decisionMismatch, suggestedDecision } from '../review-decision';
+import type { ChecklistItem, Feedback } from '../../types';
+
+const feedback = (impact: Feedback['impact'], status: Feedback['status']) =>
+  ({ id: `${impact}-${status}`, impact, status }) as Feedback;
+
+const item = (done: boolean) =>
+  ({ id: `item-${done}`, text: 'Update changelog', done }) as ChecklistItem;
+
+describe('suggestedDecision', () => {
+  it('requests changes while blocking feedback is open', () => {
+    expect(suggestedDecision([feedback('blocking', 'todo')])).toBe('request_changes');
+    expect(suggestedDecision([feedback('blocking', 'in_progress')])).toBe('request_changes');
+  });
+
+  it('only comments once blocking feedback is resolved', () => {
+    expect(suggestedDecision([feedback('blocking', 'done'), feedback('nitpick', 'todo')])).toBe(
+      'comment'
+    );
+    expect(suggestedDecision([])).toBe('comment');
+  });
+
+  it('requests changes while checklist items are unchecked', () => {
+    expect(suggestedDecision([], [item(false)])).toBe('request_changes');
+    expect(suggestedDecision([], [item(true)])).toBe('comment');
+  });
+});
+
+describe('decisionMismatch', () => {
+  it('warns when approving over open blocking feedback', () => {
+    const feedbacks = [feedback('blocking', 'todo'), feedback('blocking', 'ignored')];
+    expect(decisionMismatch('approve', feedbacks)).toBe(
+      'Approving with 1 unresolved blocking feedback item'
+    );
+    expect(decisionMismatch('request_changes', feedbacks)).toBeNull();
+  });
+
+  it('warns when approving with unchecked checklist items', () => {
+    expect(decisionMismatch('approve', [feedback('blocking', 'todo')], [item(false)])).toBe(
+      'Approving with 1 unresolved blocking feedback item and 1 unchecked checklist item'
+    );
+    expect(decisionMismatch('approve', [], [item(true)])).toBeNull();
+  });
+});
@@ -0,0 +1,67 @@
+import { describe, it, expect } from 'vitest';
+import { filterReviewsByStatus, groupReviewsByRepo, sortReviews } from '../review-list';
+import type { Review } from '../../types';
+
+const review = (
+  id: string,
+  created_at: string,
+  status = 'todo',
+  runStatus?: string
+): Review => ({
+  id,
+  title: id,
+  summary: null,
+  source: { type: 'diff_paste', diff_hash: id },
+  active_run_id: null,
+  created_at,
+  updated_at: created_at,
+  task_count: 0,
+  status,
+  active_run_status: runStatus,
+});
+
+const reviews = [
+  review('mid', '2024-02-01T00:00:00Z', 'done'),
+  review('new', '2024-03-01T00:00:00Z'),
+  review('old', '2024-01-01T00:00:00Z', 'done', 'running'),
+];
+
+describe('sortReviews', () => {
+  it('keeps the backend order for recently updated', () => {
+    expect(sortReviews(reviews, 'updated')).toBe(reviews);
+  });
+
+  it('sorts by creation date', () => {
+    expect(sortReviews(reviews, 'newest').map(r => r.id)).toEqual(['new', 'mid', 'old']);
+    expect(sortReviews(reviews, 'oldest').map(r => r.id)).toEqual(['old', 'mid', 'new']);
+  });
+});
+
+describe('filterReviewsByStatus', () => {
+  it('treats generating reviews as in progress', () => {
+    expect(filterReviewsByStatus(reviews, 'in_progress').map(r => r.id)).toEqual(['new', 'old']);
+    expect(filterReviewsByStatus(reviews, 'done').map(r => r.id)).toEqual(['mid']);
+    expect(filterReviewsByStatus(reviews, 'all')).toBe(reviews);
+  });
+});
+
+describe('groupReviewsByRepo', () => {
+  it('groups by linked repo and puts unmatched reviews last', () => {
+    const inRepo = (id: string, repo: string): Review => ({
+      ...review(id, '2024-01-01T00:00:00Z'),
+      repo_id: repo,
+      repo_name: `${repo}-name`,
+    });
+    const groups = groupReviewsByRepo([
+      review('pasted', '2024-01-01T00:00:00Z'),
+      inRepo('a1', 'a'),
+      inRepo('b1', 'b'),
+      inRepo('a2', 'a'),
+    ]);
+    expect(groups.map(g => [g.repoName, g.reviews.map(r => r.id)])).toEqual([
+      ['a-name', ['a1', 'a2']],
+      ['b-name', ['b1']],
+      [null, ['pasted']],
+    ]);
+  });
+});
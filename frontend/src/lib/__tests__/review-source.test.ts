@@ -0,0 +1,69 @@
+import { describe, it, expect } from 'vitest';
+import { fileContextIssue, formatSourceRef, isGitLabSource } from '../review-source';
+
+describe('formatSourceRef', () => {
+  it('formats pull and merge requests', () => {
+    expect(
+      formatSourceRef({ type: 'github_pr', owner: 'puemos', repo: 'lareview', number: 7 })
+    ).toBe('puemos/lareview#7');
+    expect(
+      formatSourceRef({
+        type: 'gitlab_mr',
+        host: 'gitlab.com',
+        project_path: 'group/app',
+        number: 3,
+      })
+    ).toBe('group/app!3');
+  });
+
+  it('keeps the two-dot vs three-dot distinction for compares', () => {
+    expect(
+      formatSourceRef({
+        type: 'github_compare',
+        owner: 'puemos',
+        repo: 'lareview',
+        base: 'main',
+        head: 'feat/x',
+        mode: 'merge_base',
+      })
+    ).toBe('puemos/lareview@main...feat/x');
+    expect(
+      formatSourceRef({
+        type: 'gitlab_compare',
+        host: 'gitlab.com',
+        project_path: 'group/app',
+        base: 'v1.0',
+        head: 'v1.1',
+        mode: 'direct',
+      })
+    ).toBe('group/app@v1.0..v1.1');
+  });
+
+  it('returns null for pasted diffs', () => {
+    const source = { type: 'diff_paste' as const, diff_hash: 'h' };
+    expect(formatSourceRef(source)).toBeNull();
+    expect(isGitLabSource(source)).toBe(false);
+  });
+});
+
+describe('fileContextIssue', () => {
+  const pr = {
+    type: 'github_pr' as const,
+    owner: 'puemos',
+    repo: 'lareview',
+    number: 7,
+    head_sha: 'abc123',
+  };
+
+  it('flags a missing repo or disabled snapshots', () => {
+    expect(fileContextIssue(pr, null)).toBe('no_repo');
+    expect(fileContextIssue(pr, { allow_snapshot_access: false })).toBe('snapshot_disabled');
+    expect(fileContextIssue(pr, { allow_snapshot_access: true })).toBeNull();
+  });
+
+  it('ignores sources that cannot be snapshotted', () => {
+    expect(fileContextIssue({ type: 'diff_paste', diff_hash: 'h' }, null)).toBeNull();
+    expect(fileContextIssue({ ...pr, head_sha: undefined }, null)).toBeNull();
+    expect(fileContextIssue(null, null)).toBeNull();
+  });
+});
@@ -0,0 +1,36 @@
+import { describe, it, expect } from 'vitest';
+import { moveSubFlow, orderSubFlows, UNGROUPED_SUB_FLOW } from '../sub-flows';
+
+const alphabetical = (a: string, b: string) => a.localeCompare(b);
+
+describe('orderSubFlows', () => {
+  it('falls back when nothing was saved', () => {
+    expect(orderSubFlows(['Db', 'Auth'], [], alphabetical)).toEqual(['Auth', 'Db']);
+  });
+
+  it('puts saved names first, in saved order', () => {
+    const names = ['Auth', 'Db', 'Api', UNGROUPED_SUB_FLOW];
+    expect(orderSubFlows(names, ['Db', UNGROUPED_SUB_FLOW], alphabetical)).toEqual([
+      'Db',
+      UNGROUPED_SUB_FLOW,
+      'Api',
+      'Auth',
+    ]);
+  });
+
+  it('ignores saved names that no longer exist', () => {
+    expect(orderSubFlows(['Auth'], ['Gone', 'Auth'], alphabetical)).toEqual(['Auth']);
+  });
+});
+
+describe('moveSubFlow', () => {
+  it('swaps with the neighbour', () => {
+    expect(moveSubFlow(['A', 'B', 'C'], 'B', -1)).toEqual(['B', 'A', 'C']);
+    expect(moveSubFlow(['A', 'B', 'C'], 'B', 1)).toEqual(['A', 'C', 'B']);
+  });
+
+  it('does nothing at the edges', () => {
+    expect(moveSubFlow(['A', 'B'], 'A', -1)).toEqual(['A', 'B']);
+    expect(moveSubFlow(['A', 'B'], 'B', 1)).toEqual(['A', 'B']);
+  });
+});
@@ -0,0 +1,31 @@
+import { describe, it, expect } from 'vitest';
+import { fileOverlap, mergeCandidates } from '../task-merge';
+import type { ReviewTask } from '../../types';
+
+const task = (id: string, files: string[], run_id = 'run-1') =>
+  ({ id, run_id, files }) as ReviewTask;
+
+describe('fileOverlap', () => {
+  it('measures against the smaller task', () => {
+    expect(fileOverlap(task('a', ['x', 'y']), task('b', ['x', 'y', 'z', 'w']))).toBe(1);
+    expect(fileOverlap(task('a', ['x', 'y']), task('b', ['y', 'z']))).toBe(0.5);
+  });
+
+  it('is zero when either task has no files', () => {
+    expect(fileOverlap(task('a', []), task('b', ['x']))).toBe(0);
+  });
+});
+
+describe('mergeCandidates', () => {
+  it('suggests tasks sharing more than half their files, best first', () => {
+    const current = task('a', ['x', 'y', 'z']);
+    const tasks = [
+      current,
+      task('half', ['x', 'q']),
+      task('most', ['x', 'y', 'q']),
+      task('all', ['z']),
+      task('other-run', ['x', 'y', 'z'], 'run-2'),
+    ];
+    expect(mergeCandidates(current, tasks).map(t => t.id)).toEqual(['all', 'most']);
+  });
+});
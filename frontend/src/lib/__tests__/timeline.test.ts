@@ -0,0 +1,44 @@
+import { describe, it, expect } from 'vitest';
+import { filterTimeline, timelineText, type TimelineMessage } from '../timeline';
+
+const message = (type: string, text = type, timestamp = 0): TimelineMessage => ({
+  type,
+  message: text,
+  timestamp,
+});
+
+describe('filterTimeline', () => {
+  const messages = [
+    message('agent_thought'),
+    message('agent_message'),
+    message('tool_call'),
+    message('log'),
+    message('error'),
+    message('completed'),
+  ];
+
+  it('hides only the kinds that are switched off', () => {
+    const shown = filterTimeline(messages, new Set(['thoughts', 'logs']));
+    expect(shown.map(m => m.type)).toEqual(['agent_message', 'tool_call', 'error', 'completed']);
+  });
+
+  it('always keeps errors and completion', () => {
+    const shown = filterTimeline(
+      messages,
+      new Set(['messages', 'thoughts', 'tool_calls', 'logs'] as const)
+    );
+    expect(shown.map(m => m.type)).toEqual(['error', 'completed']);
+  });
+});
+
+describe('timelineText', () => {
+  it('renders one block per message with its time and type', () => {
+    const text = timelineText([
+      message('agent_message', 'Hello', 0),
+      message('log', 'Started', 1000),
+    ]);
+    expect(text).toBe(
+      '[1970-01-01T00:00:00.000Z] agent_message\nHello\n\n[1970-01-01T00:00:01.000Z] log\nStarted'
+    );
+  });
+});
@@ -0,0 +1,25 @@
+/**
+ * Font size of the Monaco diff editors, in CSS pixels. The UI scale zooms the
+ * whole webview, so this grows with it like the rest of the UI.
+ */
+export const DIFF_FONT_SIZE = 12;
+
+/** Emitted by the backend after the appearance settings were saved. */
+export const APPEARANCE_CHANGED_EVENT = 'lareview:appearance-changed';
+
+/** UI scales offered in settings; the backend clamps to the same range. */
+export const UI_SCALE_OPTIONS = [0.75, 0.9, 1, 1.1, 1.25, 1.5, 1.75, 2];
+
+/** Monaco theme matching the app theme. */
+export function monacoTheme(highContrast: boolean): string {
+  return highContrast ? 'hc-black' : 'lareview-dark';
+}
+
+/** Switches the CSS color variables between the default and high-contrast theme. */
+export function applyTheme(highContrast: boolean) {
+  if (highContrast) {
+    document.documentElement.dataset.theme = 'high-contrast';
+  } else {
+    delete document.documentElement.dataset.theme;
+  }
+}
@@ -0,0 +1,23 @@
+/**
+ * Display name for a feedback or comment author. Reviewer authors may be
+ * `Name <email>`; agent authors are `agent:<id>`.
+ */
+export function authorName(author: string): string {
+  if (author.startsWith('agent:')) return author.slice('agent:'.length);
+  return author.replace(/\s*<[^>]*>$/, '');
+}
+
+export type AuthorKind = 'user' | 'agent' | 'imported';
+
+export const AUTHOR_KIND_LABELS: Record<AuthorKind, string> = {
+  user: 'You',
+  agent: 'AI',
+  imported: 'Imported',
+};
+
+/** Mirrors `AuthorKind::of` on the backend. */
+export function authorKind(author: string): AuthorKind {
+  if (author === 'agent' || author.startsWith('agent:')) return 'agent';
+  if (author.startsWith('import:')) return 'imported';
+  return 'user';
+}
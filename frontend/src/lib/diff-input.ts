@@ -0,0 +1,24 @@
+/** Shown when there's nothing to review yet. */
+export const EMPTY_DIFF_MESSAGE = 'Please enter a diff to review';
+
+/** Shown for pasted text that isn't a diff, e.g. a whole file or prose. */
+export const NOT_A_DIFF_MESSAGE =
+  "This doesn't look like a unified diff. Paste the output of `git diff` (or `git show`) rather than a file or plain text.";
+
+// Lines only a diff has, matching the backend's check.
+const isDiffMarker = (line: string) =>
+  line.startsWith('diff --git ') ||
+  line.startsWith('@@ -') ||
+  (line.startsWith('Binary files ') && line.endsWith(' differ')) ||
+  line.startsWith('Submodule ');
+
+/**
+ * Quick check of the Generate input before it's sent to the backend, which
+ * has the final say when it parses the diff.
+ */
+export function validateDiffInput(text: string): string | null {
+  const trimmed = text.trim();
+  if (!trimmed) return EMPTY_DIFF_MESSAGE;
+  const lines = trimmed.split('\n').map(line => line.replace(/\r$/, ''));
+  return lines.some(isDiffMarker) ? null : NOT_A_DIFF_MESSAGE;
+}
@@ -0,0 +1,77 @@
+import type { DiffHunk } from '../types';
+
+/**
+ * Longest line the diff editor gets as-is. Minified files can have lines of
+ * hundreds of thousands of characters, which stall Monaco's layout and its
+ * inline (character-level) diff; longer lines are cut to this length.
+ */
+export const MAX_RENDERED_LINE_LENGTH = 5000;
+
+export interface DiffSides {
+  original: string;
+  modified: string;
+  /** `originalLineMap[i]` is the file line number shown for editor line `i + 1`. */
+  originalLineMap: number[];
+  modifiedLineMap: number[];
+  /** Lines cut to `maxLineLength`. */
+  truncatedLines: number;
+}
+
+/** Rebuilds the old and new text of a file's hunks for a side-by-side editor. */
+export function buildDiffSides(
+  hunks: DiffHunk[],
+  maxLineLength = MAX_RENDERED_LINE_LENGTH
+): DiffSides {
+  const originalLines: string[] = [];
+  const modifiedLines: string[] = [];
+  const originalLineMap: number[] = [];
+  const modifiedLineMap: number[] = [];
+  let truncatedLines = 0;
+
+  const shorten = (line: string) => {
+    if (line.length <= maxLineLength) return line;
+    truncatedLines++;
+    const hidden = line.length - maxLineLength;
+    return `${line.slice(0, maxLineLength)} … [${hidden} more characters]`;
+  };
+
+  hunks.forEach(hunk => {
+    let origLine = hunk.old_start;
+    let modLine = hunk.new_start;
+
+    for (const line of (hunk.content || '').split('\n')) {
+      if (line.startsWith('@@')) {
+        continue;
+      }
+
+      if (line.startsWith('-') && !line.startsWith('---')) {
+        // Deletion: add to original only
+        originalLines.push(shorten(line.slice(1)));
+        originalLineMap.push(origLine);
+        origLine++;
+      } else if (line.startsWith('+') && !line.startsWith('+++')) {
+        // Addition: add to modified only
+        modifiedLines.push(shorten(line.slice(1)));
+        modifiedLineMap.push(modLine);
+        modLine++;
+      } else {
+        // Context line: add to both (strip leading space if present)
+        const contextLine = shorten(line.startsWith(' ') ? line.slice(1) : line);
+        originalLines.push(contextLine);
+        modifiedLines.push(contextLine);
+        originalLineMap.push(origLine);
+        modifiedLineMap.push(modLine);
+        origLine++;
+        modLine++;
+      }
+    }
+  });
+
+  return {
+    original: originalLines.join('\n'),
+    modified: modifiedLines.join('\n'),
+    originalLineMap,
+    modifiedLineMap,
+    truncatedLines,
+  };
+}
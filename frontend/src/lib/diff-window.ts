@@ -0,0 +1,26 @@
+import { getCurrentWebviewWindow } from '@tauri-apps/api/webviewWindow';
+
+/** Label of the detached diff window; matches `DIFF_WINDOW_LABEL` on the backend. */
+export const DIFF_WINDOW_LABEL = 'diff';
+
+/** Sent by the main window whenever its review or task selection changes. */
+export const DIFF_WINDOW_SYNC_EVENT = 'lareview:diff-window-sync';
+/** Sent by the diff window once it listens, asking for the current selection. */
+export const DIFF_WINDOW_READY_EVENT = 'lareview:diff-window-ready';
+/** Sent by the diff window after it added feedback, so the main window reloads it. */
+export const DIFF_WINDOW_FEEDBACK_EVENT = 'lareview:diff-window-feedback';
+
+export interface DiffWindowSelection {
+  reviewId: string | null;
+  taskId: string | null;
+}
+
+/** Whether this webview is the detached diff window rather than the main app. */
+export function isDiffWindow(): boolean {
+  try {
+    return getCurrentWebviewWindow().label === DIFF_WINDOW_LABEL;
+  } catch {
+    // Not running inside Tauri (tests, plain browser).
+    return false;
+  }
+}
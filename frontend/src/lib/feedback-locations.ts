@@ -0,0 +1,21 @@
+import type { Feedback, FeedbackAnchor } from '../types';
+
+/** Every location of a finding with a file, primary anchor first. */
+export function feedbackLocations(feedback: Feedback): FeedbackAnchor[] {
+  return [feedback.anchor, ...(feedback.extra_anchors ?? [])].filter(
+    (anchor): anchor is FeedbackAnchor => !!anchor?.file_path
+  );
+}
+
+/** The snippet the backend returned for `anchor`, if it could locate one. */
+export function snippetForAnchor<T extends { file_path: string; highlighted_line: number | null }>(
+  snippets: T[],
+  anchor: FeedbackAnchor
+): T | null {
+  return (
+    snippets.find(
+      snippet =>
+        snippet.file_path === anchor.file_path && snippet.highlighted_line === anchor.line_number
+    ) ?? null
+  );
+}
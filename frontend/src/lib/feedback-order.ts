@@ -0,0 +1,58 @@
+import type { Feedback } from '../types';
+
+/** Severity rank per impact; higher is more severe. Mirrors `FeedbackImpact::rank`. */
+export const IMPACT_RANK: Record<Feedback['impact'], number> = {
+  nitpick: 0,
+  nice_to_have: 1,
+  blocking: 2,
+};
+
+const STATUS_RANK: Record<Feedback['status'], number> = {
+  todo: 0,
+  in_progress: 1,
+  done: 2,
+  ignored: 3,
+};
+
+/**
+ * Order for feedback lists: open before closed, then most severe first, then
+ * most recently updated.
+ */
+export function compareFeedback(a: Feedback, b: Feedback): number {
+  const status = (STATUS_RANK[a.status] ?? 0) - (STATUS_RANK[b.status] ?? 0);
+  if (status !== 0) return status;
+  const severity = (IMPACT_RANK[b.impact] ?? 0) - (IMPACT_RANK[a.impact] ?? 0);
+  if (severity !== 0) return severity;
+  return new Date(b.updated_at).getTime() - new Date(a.updated_at).getTime();
+}
+
+/** Blocking feedback that is neither done nor ignored. */
+export function isOpenBlocking(feedback: Feedback): boolean {
+  return (
+    feedback.impact === 'blocking' && feedback.status !== 'done' && feedback.status !== 'ignored'
+  );
+}
+
+/** Where a finding sits in the diff: by file, then line; unanchored ones first. */
+function compareLocation(a: Feedback, b: Feedback): number {
+  const file = (a.anchor?.file_path ?? '').localeCompare(b.anchor?.file_path ?? '');
+  if (file !== 0) return file;
+  const line = (a.anchor?.line_number ?? 0) - (b.anchor?.line_number ?? 0);
+  if (line !== 0) return line;
+  return a.created_at.localeCompare(b.created_at);
+}
+
+/**
+ * The open blocking finding after `currentId`, walking the diff top to bottom
+ * and wrapping around at the end. Starts from the first when `currentId` isn't
+ * one of them; null when no blocker is left open.
+ */
+export function nextBlockingFeedback(
+  feedbacks: Feedback[],
+  currentId: string | null
+): Feedback | null {
+  const blocking = feedbacks.filter(isOpenBlocking).sort(compareLocation);
+  if (blocking.length === 0) return null;
+  const current = blocking.findIndex(f => f.id === currentId);
+  return blocking[(current + 1) % blocking.length];
+}
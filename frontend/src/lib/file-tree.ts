@@ -0,0 +1,87 @@
+import type { FileReviewStatus } from '../types';
+
+export interface FileTreeCounts {
+  openFeedback: number;
+  closedFeedback: number;
+  tasks: number;
+  closedTasks: number;
+}
+
+export interface FileTreeNode {
+  /** Display name; directories with a single child directory are joined (`src/lib`). */
+  name: string;
+  /** Full path of the file or directory. */
+  path: string;
+  /** Set for files, absent for directories. */
+  file?: FileReviewStatus;
+  children: FileTreeNode[];
+  /** Totals over the node and everything below it. */
+  counts: FileTreeCounts;
+}
+
+const emptyCounts = (): FileTreeCounts => ({
+  openFeedback: 0,
+  closedFeedback: 0,
+  tasks: 0,
+  closedTasks: 0,
+});
+
+/**
+ * Builds a directory tree from per-file statuses. Directories sort before
+ * files, and chains of single-directory folders are collapsed into one node.
+ */
+export function buildFileTree(files: FileReviewStatus[]): FileTreeNode[] {
+  const root: FileTreeNode = { name: '', path: '', children: [], counts: emptyCounts() };
+
+  for (const file of files) {
+    const parts = file.path.split('/');
+    let parent = root;
+    parts.forEach((part, index) => {
+      const path = parts.slice(0, index + 1).join('/');
+      const isFile = index === parts.length - 1;
+      let node = parent.children.find(child => child.name === part && !child.file === !isFile);
+      if (!node) {
+        node = { name: part, path, children: [], counts: emptyCounts() };
+        if (isFile) node.file = file;
+        parent.children.push(node);
+      }
+      parent = node;
+    });
+  }
+
+  return root.children.map(finish).sort(compareNodes);
+}
+
+function finish(node: FileTreeNode): FileTreeNode {
+  if (node.file) {
+    node.counts = {
+      openFeedback: node.file.open_feedback,
+      closedFeedback: node.file.closed_feedback,
+      tasks: node.file.tasks,
+      closedTasks: node.file.closed_tasks,
+    };
+    return node;
+  }
+
+  let children = node.children.map(finish).sort(compareNodes);
+  let { name, path } = node;
+  while (children.length === 1 && !children[0].file) {
+    name = `${name}/${children[0].name}`;
+    path = children[0].path;
+    children = children[0].children;
+  }
+
+  const counts = emptyCounts();
+  for (const child of children) {
+    counts.openFeedback += child.counts.openFeedback;
+    counts.closedFeedback += child.counts.closedFeedback;
+    counts.tasks += child.counts.tasks;
+    counts.closedTasks += child.counts.closedTasks;
+  }
+  return { name, path, children, counts };
+}
+
+function compareNodes(a: FileTreeNode, b: FileTreeNode): number {
+  if (!a.file !== !b.file) return a.file ? 1 : -1;
+  return a.name.localeCompare(b.name);
+}
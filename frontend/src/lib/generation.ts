@@ -0,0 +1,20 @@
+/** What a finished generation produced, as reported by the backend. */
+export interface GenerationCounts {
+  task_count: number;
+  feedback_count?: number;
+}
+
+/** Whether a run left anything to open: tasks, or feedback on its own. */
+export function hasReviewContent({ task_count, feedback_count = 0 }: GenerationCounts): boolean {
+  return task_count > 0 || feedback_count > 0;
+}
+
+/** Progress line for a completed run. */
+export function completionMessage({ task_count, feedback_count = 0 }: GenerationCounts): string {
+  if (task_count > 0) return 'Review generation complete!';
+  if (feedback_count > 0) {
+    const items = feedback_count === 1 ? '1 feedback item' : `${feedback_count} feedback items`;
+    return `Review complete with ${items} and no tasks.`;
+  }
+  return 'The agent finished without tasks or feedback.';
+}
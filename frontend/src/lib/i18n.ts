@@ -0,0 +1,50 @@
+import en from '../locales/en.json';
+import es from '../locales/es.json';
+
+/** English is the source bundle: every key exists there. */
+export type MessageKey = keyof typeof en;
+type Bundle = Partial<Record<MessageKey, string>>;
+
+export const LANGUAGES = [
+  { code: 'en', name: 'English' },
+  { code: 'es', name: 'Español' },
+] as const;
+
+export type Language = (typeof LANGUAGES)[number]['code'];
+
+const BUNDLES: Record<Language, Bundle> = { en, es };
+
+function isLanguage(code: string): code is Language {
+  return code in BUNDLES;
+}
+
+/**
+ * The language to show: the saved one if we have a bundle for it, else the
+ * system language (`es-MX` matches `es`), else English.
+ */
+export function resolveLanguage(
+  saved: string | null,
+  system: string = navigator.language
+): Language {
+  for (const candidate of [saved, system]) {
+    const code = candidate?.toLowerCase().split('-')[0];
+    if (code && isLanguage(code)) return code;
+  }
+  return 'en';
+}
+
+/**
+ * The message for `key` in `language`, falling back to English for keys the
+ * bundle lacks. `{name}` placeholders are filled from `vars`.
+ */
+export function translate(
+  language: Language,
+  key: MessageKey,
+  vars?: Record<string, string | number>
+): string {
+  const message = BUNDLES[language][key] ?? en[key];
+  if (!vars) return message;
+  return message.replace(/\{(\w+)\}/g, (placeholder, name: string) =>
+    name in vars ? String(vars[name]) : placeholder
+  );
+}
@@ -1,7 +1,8 @@
-import { QueryClient } from '@tanstack/react-query';
+import { QueryClient, defaultShouldDehydrateQuery } from '@tanstack/react-query';
 import { persistQueryClient } from '@tanstack/react-query-persist-client';
 import { createSyncStoragePersister } from '@tanstack/query-sync-storage-persister';
 import { QUERY_CONFIG, PERSIST_CONFIG } from '../constants/query-config';
+import { queryKeys } from './query-keys';
 
 function createPersister() {
   if (typeof window === 'undefined') {
@@ -34,6 +35,12 @@ export const createQueryClient = () => {
       queryClient: client,
       persister,
       maxAge: PERSIST_CONFIG.maxAge,
+      dehydrateOptions: {
+        // Parsed diffs can be megabytes each; re-parsing is cheap compared to
+        // blowing through the localStorage quota.
+        shouldDehydrateQuery: query =>
+          query.queryKey[0] !== queryKeys.parsedDiffs[0] && defaultShouldDehydrateQuery(query),
+      },
       hydrateOptions: {
         defaultOptions: {
           queries: {
@@ -46,3 +53,24 @@ export const createQueryClient = () => {
 
   return client;
 };
+
+/**
+ * Drops the least recently loaded parsed diffs that no view is observing,
+ * keeping at most `keep` of them in the cache.
+ */
+export const evictParsedDiffs = (
+  client: QueryClient,
+  keep: number = QUERY_CONFIG.maxCachedParsedDiffs
+) => {
+  const inactive = client
+    .getQueryCache()
+    .findAll({ queryKey: queryKeys.parsedDiffs })
+    .filter(query => query.getObserversCount() === 0)
+    // The cache lists queries oldest-first; reverse so ties keep the newest.
+    .reverse()
+    .sort((a, b) => b.state.dataUpdatedAt - a.state.dataUpdatedAt);
+
+  inactive.slice(keep).forEach(query => {
+    client.removeQueries({ queryKey: query.queryKey, exact: true });
+  });
+};
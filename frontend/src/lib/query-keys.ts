@@ -1,18 +1,48 @@
-import type { ReviewTask, Review, Agent } from '../types';
+import type { ReviewTask, Review, Agent, Plan } from '../types';
 
 export const queryKeys = {
   reviews: ['reviews'] as const,
   review: (id: string) => ['reviews', id] as const,
+  reviewSearch: (query: string) => ['reviews', 'search', query] as const,
   reviewRuns: (reviewId: string) => ['reviewRuns', reviewId] as const,
+  checklist: (reviewId: string) => ['checklist', reviewId] as const,
   tasks: (runId: string) => ['tasks', runId] as const,
+  subFlowOrder: (runId: string) => ['subFlowOrder', runId] as const,
+  parsedDiffs: ['parsedDiff'] as const,
+  parsedDiff: (runId: string, repoRoot?: string | null) =>
+    ['parsedDiff', runId, repoRoot ?? null] as const,
+  highlightedCode: (language: string, code: string) =>
+    ['highlightedCode', language, code] as const,
   feedback: ['feedback'] as const,
   feedbackByReview: (reviewId: string) => ['feedback', 'byReview', reviewId] as const,
+  feedbackAttachments: (feedbackId: string) => ['feedbackAttachments', feedbackId] as const,
   feedbackFilterConfig: ['feedbackFilterConfig'] as const,
   timeoutConfig: ['timeoutConfig'] as const,
+  offlineMode: ['offlineMode'] as const,
+  completionNotifications: ['completionNotifications'] as const,
+  rejectionExamples: ['rejectionExamples'] as const,
+  generatedFilePatterns: ['generatedFilePatterns'] as const,
+  githubEnterpriseHosts: ['githubEnterpriseHosts'] as const,
+  vcsCliConfig: (providerId: string) => ['vcsCliConfig', providerId] as const,
+  dataDir: ['dataDir'] as const,
+  encryptionStatus: ['encryptionStatus'] as const,
+  autoRefreshConfig: ['autoRefreshConfig'] as const,
+  repoWatchInterval: ['repoWatchInterval'] as const,
+  feedbackContextLines: ['feedbackContextLines'] as const,
+  quickReviewMaxLines: ['quickReviewMaxLines'] as const,
+  appearanceConfig: ['appearanceConfig'] as const,
+  language: ['language'] as const,
+  prFetchTimeout: ['prFetchTimeout'] as const,
+  userIdentity: ['userIdentity'] as const,
+  generationConcurrency: ['generationConcurrency'] as const,
   repos: ['repos'] as const,
   agents: ['agents'] as const,
+  agentProbe: (agentId: string) => ['agents', 'probe', agentId] as const,
   rules: ['rules'] as const,
   issueChecks: (runId: string) => ['issueChecks', runId] as const,
+  issueCheckDefinitions: ['issueCheckDefinitions'] as const,
+  runPlan: (runId: string) => ['runPlan', runId] as const,
+  runToolCalls: (runId: string) => ['runToolCalls', runId] as const,
   ruleLibrary: ['ruleLibrary'] as const,
   defaultIssueCategories: ['defaultIssueCategories'] as const,
   ruleRejectionStats: ['ruleRejectionStats'] as const,
@@ -21,6 +51,10 @@ export const queryKeys = {
   learningStatus: ['learningStatus'] as const,
   // Merge confidence
   mergeConfidence: (runId: string) => ['mergeConfidence', runId] as const,
+  // Review stats
+  reviewStats: (reviewId: string) => ['reviewStats', reviewId] as const,
+  fileReviewStatus: (reviewId: string) => ['fileReviewStatus', reviewId] as const,
+  viewedFiles: (runId: string) => ['viewedFiles', runId] as const,
 };
 
 export type QueryKeyType = typeof queryKeys;
@@ -31,10 +65,11 @@ export type ReviewRunsQuery = Array<{
   review_id: string;
   agent_id: string;
   input_ref: string;
-  diff_text: string;
+  diff_hash: string;
   created_at: string;
   task_count: number;
   status: string;
+  plan?: Plan | null;
 }>;
 export type TasksQuery = ReviewTask[];
 export type ReposQuery = Array<{
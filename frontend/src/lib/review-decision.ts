@@ -0,0 +1,42 @@
+import type { ChecklistItem, Feedback, ReviewDecision } from '../types';
+
+export const DECISION_LABELS: Record<ReviewDecision, string> = {
+  approve: 'Approve',
+  request_changes: 'Request changes',
+  comment: 'Comment',
+};
+
+const isOpenBlocking = (feedback: Feedback) =>
+  feedback.impact === 'blocking' && feedback.status !== 'done' && feedback.status !== 'ignored';
+
+const plural = (count: number, noun: string) => `${count} ${noun}${count === 1 ? '' : 's'}`;
+
+/**
+ * The decision pushed when the user hasn't picked one: request changes while
+ * blocking feedback is unresolved or checklist items are left, otherwise only
+ * comment. Mirrors `ReviewDecision::suggested` on the backend.
+ */
+export function suggestedDecision(
+  feedbacks: Feedback[],
+  checklist: ChecklistItem[] = []
+): ReviewDecision {
+  return feedbacks.some(isOpenBlocking) || checklist.some(item => !item.done)
+    ? 'request_changes'
+    : 'comment';
+}
+
+/** Why `decision` contradicts the feedback or checklist, if it does. */
+export function decisionMismatch(
+  decision: ReviewDecision,
+  feedbacks: Feedback[],
+  checklist: ChecklistItem[] = []
+): string | null {
+  if (decision !== 'approve') return null;
+  const open = feedbacks.filter(isOpenBlocking).length;
+  const unchecked = checklist.filter(item => !item.done).length;
+  const reasons = [
+    open > 0 ? plural(open, 'unresolved blocking feedback item') : null,
+    unchecked > 0 ? plural(unchecked, 'unchecked checklist item') : null,
+  ].filter(Boolean);
+  return reasons.length > 0 ? `Approving with ${reasons.join(' and ')}` : null;
+}
@@ -0,0 +1,76 @@
+import type { Review } from '../types';
+import type { MessageKey } from './i18n';
+
+/** `updated` keeps the backend's most-recently-updated-first order. */
+export type ReviewSort = 'updated' | 'newest' | 'oldest';
+export type ReviewStatusFilter = 'all' | 'in_progress' | 'done';
+
+export const REVIEW_SORT_LABELS: Record<ReviewSort, MessageKey> = {
+  updated: 'reviews.sort.updated',
+  newest: 'reviews.sort.newest',
+  oldest: 'reviews.sort.oldest',
+};
+
+export const REVIEW_STATUS_FILTER_LABELS: Record<ReviewStatusFilter, MessageKey> = {
+  all: 'reviews.filter.all',
+  in_progress: 'reviews.filter.in_progress',
+  done: 'reviews.filter.done',
+};
+
+/** Reviews the user still has to finish, including ones still generating. */
+function isInProgress(review: Review): boolean {
+  return review.active_run_status === 'running' || review.status !== 'done';
+}
+
+export function filterReviewsByStatus(reviews: Review[], filter: ReviewStatusFilter): Review[] {
+  switch (filter) {
+    case 'all':
+      return reviews;
+    case 'in_progress':
+      return reviews.filter(isInProgress);
+    case 'done':
+      return reviews.filter(review => !isInProgress(review));
+  }
+}
+
+export function sortReviews(reviews: Review[], sort: ReviewSort): Review[] {
+  if (sort === 'updated') return reviews;
+  const direction = sort === 'newest' ? -1 : 1;
+  return [...reviews].sort(
+    (a, b) => direction * (Date.parse(a.created_at) - Date.parse(b.created_at))
+  );
+}
+
+export interface ReviewRepoGroup {
+  /** `null` for reviews that don't match a linked repo. */
+  repoId: string | null;
+  repoName: string | null;
+  reviews: Review[];
+}
+
+/** Groups in order of their first review, with unmatched reviews last. */
+export function groupReviewsByRepo(reviews: Review[]): ReviewRepoGroup[] {
+  const groups = new Map<string, ReviewRepoGroup>();
+  const unmatched: Review[] = [];
+  for (const review of reviews) {
+    if (!review.repo_id) {
+      unmatched.push(review);
+      continue;
+    }
+    const group = groups.get(review.repo_id);
+    if (group) {
+      group.reviews.push(review);
+    } else {
+      groups.set(review.repo_id, {
+        repoId: review.repo_id,
+        repoName: review.repo_name ?? review.repo_id,
+        reviews: [review],
+      });
+    }
+  }
+  const result = Array.from(groups.values());
+  if (unmatched.length > 0) {
+    result.push({ repoId: null, repoName: null, reviews: unmatched });
+  }
+  return result;
+}
@@ -0,0 +1,44 @@
+import type { LinkedRepo, ReviewSource } from '../types';
+
+/**
+ * Short label for a remote review source, e.g. `owner/repo#12`,
+ * `group/project!3` or `owner/repo@main...feature`. Null for pasted diffs.
+ */
+export function formatSourceRef(source: ReviewSource): string | null {
+  switch (source.type) {
+    case 'github_pr':
+    case 'gitea_pr':
+      return `${source.owner}/${source.repo}#${source.number}`;
+    case 'gitlab_mr':
+      return `${source.project_path}!${source.number}`;
+    case 'github_compare':
+      return `${source.owner}/${source.repo}@${compareRange(source)}`;
+    case 'gitlab_compare':
+      return `${source.project_path}@${compareRange(source)}`;
+    default:
+      return null;
+  }
+}
+
+export function isGitLabSource(source: ReviewSource): boolean {
+  return source.type === 'gitlab_mr' || source.type === 'gitlab_compare';
+}
+
+/**
+ * Why the agent would review `source` from the diff alone, without the repo's
+ * files: no linked repo is selected, or snapshots of it aren't allowed yet.
+ * Null when it will get a snapshot, or when one isn't possible anyway
+ * (pasted diffs, sources without a head commit).
+ */
+export function fileContextIssue(
+  source: ReviewSource | null,
+  repo: Pick<LinkedRepo, 'allow_snapshot_access'> | null
+): 'no_repo' | 'snapshot_disabled' | null {
+  if (!source || source.type === 'diff_paste' || !source.head_sha) return null;
+  if (!repo) return 'no_repo';
+  return repo.allow_snapshot_access ? null : 'snapshot_disabled';
+}
+
+function compareRange(source: Extract<ReviewSource, { base: string }>): string {
+  return `${source.base}${source.mode === 'direct' ? '..' : '...'}${source.head}`;
+}
@@ -0,0 +1,32 @@
+/** Key used in the saved order for tasks that have no sub-flow. */
+export const UNGROUPED_SUB_FLOW = '';
+
+/**
+ * Orders sub-flow names: names in `savedOrder` come first, in that order,
+ * followed by the rest sorted with `fallback`.
+ */
+export function orderSubFlows(
+  names: string[],
+  savedOrder: string[],
+  fallback: (a: string, b: string) => number
+): string[] {
+  const rank = new Map(savedOrder.map((name, index) => [name, index]));
+  return [...names].sort((a, b) => {
+    const rankA = rank.get(a);
+    const rankB = rank.get(b);
+    if (rankA !== undefined && rankB !== undefined) return rankA - rankB;
+    if (rankA !== undefined) return -1;
+    if (rankB !== undefined) return 1;
+    return fallback(a, b);
+  });
+}
+
+/** Moves `name` one step up (`-1`) or down (`1`) in the displayed order. */
+export function moveSubFlow(displayed: string[], name: string, delta: -1 | 1): string[] {
+  const index = displayed.indexOf(name);
+  const target = index + delta;
+  if (index < 0 || target < 0 || target >= displayed.length) return displayed;
+  const next = [...displayed];
+  [next[index], next[target]] = [next[target], next[index]];
+  return next;
+}
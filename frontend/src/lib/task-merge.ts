@@ -0,0 +1,25 @@
+import type { ReviewTask } from '../types';
+
+/** Share of the smaller task's files that the two tasks have in common. */
+export function fileOverlap(a: Pick<ReviewTask, 'files'>, b: Pick<ReviewTask, 'files'>): number {
+  const filesA = new Set(a.files);
+  const filesB = new Set(b.files);
+  const smaller = Math.min(filesA.size, filesB.size);
+  if (smaller === 0) return 0;
+  let shared = 0;
+  for (const file of filesA) if (filesB.has(file)) shared++;
+  return shared / smaller;
+}
+
+/**
+ * Other tasks of the same run sharing more than half of their files with
+ * `task`, best match first: candidates for merging into it.
+ */
+export function mergeCandidates(task: ReviewTask, tasks: ReviewTask[]): ReviewTask[] {
+  return tasks
+    .filter(other => other.id !== task.id && other.run_id === task.run_id)
+    .map(other => ({ other, overlap: fileOverlap(task, other) }))
+    .filter(({ overlap }) => overlap > 0.5)
+    .sort((a, b) => b.overlap - a.overlap)
+    .map(({ other }) => other);
+}
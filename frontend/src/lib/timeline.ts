@@ -0,0 +1,55 @@
+/** Progress message shown in the generation activity feed. */
+export interface TimelineMessage {
+  type: string;
+  message: string;
+  data?: Record<string, unknown>;
+  timestamp: number;
+}
+
+/** Kinds of activity the feed can hide; errors and completion always show. */
+export type TimelineFilter = 'messages' | 'thoughts' | 'tool_calls' | 'logs';
+
+export const TIMELINE_FILTERS: Array<{ filter: TimelineFilter; label: string }> = [
+  { filter: 'messages', label: 'Messages' },
+  { filter: 'thoughts', label: 'Thoughts' },
+  { filter: 'tool_calls', label: 'Tool calls' },
+  { filter: 'logs', label: 'Logs' },
+];
+
+/** The filter that hides messages of `type`, or null if they always show. */
+export function timelineFilterFor(type: string): TimelineFilter | null {
+  switch (type) {
+    case 'agent_message':
+      return 'messages';
+    case 'agent_thought':
+      return 'thoughts';
+    case 'tool_call':
+      return 'tool_calls';
+    case 'log':
+    case 'system':
+    case 'debug':
+      return 'logs';
+    default:
+      return null;
+  }
+}
+
+export function filterTimeline<T extends TimelineMessage>(
+  messages: T[],
+  hidden: ReadonlySet<TimelineFilter>
+): T[] {
+  return messages.filter(message => {
+    const filter = timelineFilterFor(message.type);
+    return filter === null || !hidden.has(filter);
+  });
+}
+
+/** Plain-text transcript of `messages`, one block per message. */
+export function timelineText(messages: TimelineMessage[]): string {
+  return messages
+    .map(message => {
+      const time = new Date(message.timestamp).toISOString();
+      return `[${time}] ${message.type}\n${message.message}`;
+    })
+    .join('\n\n');
+}
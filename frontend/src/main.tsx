@@ -1,10 +1,9 @@
 import React from 'react';
 import ReactDOM from 'react-dom/client';
-import App from './App';
+import App, { DiffWindowApp } from './App';
+import { isDiffWindow } from './lib/diff-window';
 import './styles/globals.css';
 
 ReactDOM.createRoot(document.getElementById('root')!).render(
-  <React.StrictMode>
-    <App />
-  </React.StrictMode>
+  <React.StrictMode>{isDiffWindow() ? <DiffWindowApp /> : <App />}</React.StrictMode>
 );
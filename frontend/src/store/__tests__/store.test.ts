@@ -1,6 +1,7 @@
 import { describe, it, expect, beforeEach } from 'vitest';
 import { useAppStore } from '../index';
 import type { Plan } from '../../types';
+import type { SessionUpdate } from '../../hooks/useTauri';
 
 describe('AppStore - Plan Merging', () => {
   beforeEach(() => {
@@ -76,3 +77,41 @@ describe('AppStore - Plan Merging', () => {
     expect(plan?.entries[0].status).toBe('in_progress');
   });
 });
+
+describe('AppStore - Streamed chunks', () => {
+  beforeEach(() => {
+    useAppStore.getState().reset();
+  });
+
+  const chunk = (sessionUpdate: string, id: string, text: string) =>
+    useAppStore.getState().handleServerUpdate({
+      sessionUpdate,
+      content: { type: 'text', text },
+      meta: { message_id: id },
+    } as SessionUpdate);
+
+  it('collapses deltas with the same id into one item', () => {
+    chunk('agent_message_chunk', 'm1', 'Hel');
+    chunk('agent_message_chunk', 'm1', 'lo ');
+    chunk('agent_message_chunk', 'm1', 'there');
+
+    const msgs = useAppStore.getState().progressMessages;
+    expect(msgs).toHaveLength(1);
+    expect(msgs[0].message).toBe('Hello there');
+  });
+
+  it('keeps interleaved streams as separate blocks in arrival order', () => {
+    chunk('agent_thought_chunk', 't1', 'Think');
+    chunk('agent_message_chunk', 'm1', 'Say');
+    chunk('agent_thought_chunk', 't1', 'ing');
+    chunk('agent_message_chunk', 'm2', 'Next');
+    chunk('agent_message_chunk', 'm1', 'ing');
+
+    const msgs = useAppStore.getState().progressMessages;
+    expect(msgs.map(m => [m.type, m.message])).toEqual([
+      ['agent_thought', 'Thinking'],
+      ['agent_message', 'Saying'],
+      ['agent_message', 'Next'],
+    ]);
+  });
+});
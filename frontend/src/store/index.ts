@@ -12,6 +12,7 @@ import {
 } from '../types';
 import { PERSIST_CONFIG, STORAGE_KEYS } from '../constants/query-config';
 import type { AvailableCommand, SessionUpdate } from '../hooks/useTauri';
+import type { ReviewSort } from '../lib/review-list';
 import {
   isAgentMessageChunk,
   isAgentThoughtChunk,
@@ -30,6 +31,41 @@ interface ProgressMessage {
   id?: string;
 }
 
+/**
+ * Adds a streamed text chunk to the block it belongs to. Chunks carrying a
+ * `meta.message_id` grow the block with that id wherever it sits, so
+ * interleaved messages and thoughts each stay one block in arrival order;
+ * chunks without an id extend the last block if it's the same kind.
+ */
+function appendChunk(
+  msgs: ProgressMessage[],
+  type: 'agent_message' | 'agent_thought',
+  update: SessionUpdate
+): ProgressMessage[] {
+  const text = update.content?.text || '';
+  const messageId = update.meta?.message_id;
+  const streamId = typeof messageId === 'string' ? messageId : undefined;
+
+  let idx = -1;
+  if (streamId) {
+    for (let i = msgs.length - 1; i >= 0; i--) {
+      if (msgs[i].type === type && msgs[i].id === streamId) {
+        idx = i;
+        break;
+      }
+    }
+  } else if (msgs.length > 0 && msgs[msgs.length - 1].type === type) {
+    idx = msgs.length - 1;
+  }
+
+  if (idx >= 0) {
+    const next = [...msgs];
+    next[idx] = { ...msgs[idx], message: msgs[idx].message + text };
+    return next;
+  }
+  return [...msgs, { type, message: text, data: update, timestamp: Date.now(), id: streamId }];
+}
+
 interface AppStore {
   diffText: string;
   parsedDiff: ParsedDiff | null;
@@ -52,6 +88,11 @@ interface AppStore {
   reviewViewMode: 'summary' | 'review';
   planItems: string[];
   isPlanExpanded: boolean;
+  /** Rule the rules view should scroll to and highlight once */
+  focusedRuleId: string | null;
+  /** Agent whose path editor the settings view should open once */
+  configuringAgentId: string | null;
+  reviewListSort: ReviewSort;
 
   setDiffText: (text: string) => void;
   setParsedDiff: (diff: ParsedDiff | null) => void;
@@ -78,6 +119,9 @@ interface AppStore {
   setViewMode: (mode: 'raw' | 'diff') => void;
   setReviewViewMode: (mode: 'summary' | 'review') => void;
   setIsPlanExpanded: (isExpanded: boolean) => void;
+  setFocusedRuleId: (ruleId: string | null) => void;
+  setConfiguringAgentId: (agentId: string | null) => void;
+  setReviewListSort: (sort: ReviewSort) => void;
   reset: () => void;
 }
 
@@ -106,6 +150,9 @@ export const useAppStore = create<AppStore>()(
         reviewViewMode: 'summary',
         planItems: [],
         isPlanExpanded: false,
+        focusedRuleId: null,
+        configuringAgentId: null,
+        reviewListSort: 'updated',
 
         setDiffText: text => set({ diffText: text }),
         setParsedDiff: diff => set({ parsedDiff: diff }),
@@ -202,47 +249,13 @@ export const useAppStore = create<AppStore>()(
             const sessionUpdate = update as SessionUpdate;
 
             if (isAgentMessageChunk(sessionUpdate)) {
-              const text = sessionUpdate.content?.text || '';
-              if (lastMsg && lastMsg.type === 'agent_message') {
-                msgs[msgs.length - 1] = {
-                  ...lastMsg,
-                  message: lastMsg.message + text,
-                };
-                return { progressMessages: msgs };
-              } else {
-                return {
-                  progressMessages: [
-                    ...msgs,
-                    {
-                      type: 'agent_message',
-                      message: text,
-                      data: sessionUpdate,
-                      timestamp: Date.now(),
-                    },
-                  ],
-                };
-              }
+              return {
+                progressMessages: appendChunk(msgs, 'agent_message', sessionUpdate),
+              };
             } else if (isAgentThoughtChunk(sessionUpdate)) {
-              const text = sessionUpdate.content?.text || '';
-              if (lastMsg && lastMsg.type === 'agent_thought') {
-                msgs[msgs.length - 1] = {
-                  ...lastMsg,
-                  message: lastMsg.message + text,
-                };
-                return { progressMessages: msgs };
-              } else {
-                return {
-                  progressMessages: [
-                    ...msgs,
-                    {
-                      type: 'agent_thought',
-                      message: text,
-                      data: sessionUpdate,
-                      timestamp: Date.now(),
-                    },
-                  ],
-                };
-              }
+              return {
+                progressMessages: appendChunk(msgs, 'agent_thought', sessionUpdate),
+              };
             } else if (isToolCall(sessionUpdate)) {
               return {
                 progressMessages: [
@@ -338,6 +351,9 @@ export const useAppStore = create<AppStore>()(
         setViewMode: mode => set({ viewMode: mode }),
         setReviewViewMode: mode => set({ reviewViewMode: mode }),
         setIsPlanExpanded: isExpanded => set({ isPlanExpanded: isExpanded }),
+        setFocusedRuleId: ruleId => set({ focusedRuleId: ruleId }),
+        setConfiguringAgentId: agentId => set({ configuringAgentId: agentId }),
+        setReviewListSort: sort => set({ reviewListSort: sort }),
 
         reset: () =>
           set({
@@ -367,6 +383,7 @@ export const useAppStore = create<AppStore>()(
         name: PERSIST_CONFIG.name,
         partialize: state => ({
           [STORAGE_KEYS.agentId]: state.agentId,
+          [STORAGE_KEYS.reviewListSort]: state.reviewListSort,
         }),
       }
     )
@@ -5,11 +5,16 @@ import type {
   Review,
   LinkedRepo,
   Agent,
+  AgentProbe,
   ParsedDiff,
   ReviewRun,
   Feedback,
   Comment,
+  FeedbackAttachment,
+  ReviewMode,
   ReviewSource,
+  ReviewMergeOutcome,
+  ReviewPage,
 } from '../types';
 import type { ProgressEventPayload } from '../hooks/useTauri';
 
@@ -46,7 +51,7 @@ const createMockRun = (overrides = {}): ReviewRun => ({
   review_id: 'review-1',
   agent_id: 'agent-1',
   input_ref: 'HEAD',
-  diff_text: 'diff --git a/test.ts b/test.ts',
+  diff_hash: 'hash-1',
   created_at: new Date().toISOString(),
   task_count: 5,
   status: 'completed',
@@ -87,15 +92,44 @@ const createMockComment = (overrides = {}): Comment => ({
   ...overrides,
 });
 
+const createMockAttachment = (overrides = {}): FeedbackAttachment => ({
+  id: 'attachment-1',
+  feedback_id: 'feedback-1',
+  mime: 'image/png',
+  byte_size: 2,
+  created_at: new Date().toISOString(),
+  data_url: 'data:image/png;base64,aGk=',
+  ...overrides,
+});
+
+interface TaskFields {
+  title: string;
+  description: string;
+  files: string[];
+  subFlow: string | null;
+}
+
 interface MockTauriReturn {
   getAllReviews: Mock<() => Promise<Review[]>>;
+  listReviews: Mock<(limit?: number, offset?: number) => Promise<ReviewPage>>;
   getPendingReviews: Mock<() => Promise<Review[]>>;
   getReviewRuns: Mock<(reviewId: string) => Promise<ReviewRun[]>>;
+  getRunDiff: Mock<(runId: string) => Promise<string>>;
+  mergeReviews: Mock<(primaryId: string, secondaryId: string) => Promise<ReviewMergeOutcome>>;
   getLinkedRepos: Mock<() => Promise<LinkedRepo[]>>;
   parseDiff: Mock<(diffText: string) => Promise<ParsedDiff>>;
   loadTasks: Mock<(runId: string) => Promise<ReviewTask[]>>;
   updateTaskStatus: Mock<(taskId: string, status: string) => Promise<void>>;
+  createTask: Mock<(runId: string, task: TaskFields) => Promise<ReviewTask>>;
+  updateTask: Mock<(taskId: string, task: TaskFields) => Promise<ReviewTask>>;
+  deleteTask: Mock<(taskId: string) => Promise<void>>;
+  mergeTasks: Mock<(runId: string, taskIds: string[]) => Promise<ReviewTask>>;
+  renameSubFlow: Mock<(runId: string, oldName: string | null, newName: string) => Promise<number>>;
+  setTaskSubFlow: Mock<(taskId: string, subFlow: string | null) => Promise<void>>;
+  getSubFlowOrder: Mock<(runId: string) => Promise<string[]>>;
+  setSubFlowOrder: Mock<(runId: string, order: string[]) => Promise<void>>;
   getAgents: Mock<() => Promise<Agent[]>>;
+  probeAgent: Mock<(agentId: string) => Promise<AgentProbe>>;
   addCustomAgent: Mock<
     (id: string, label: string, command: string, args?: string[], logo?: string) => Promise<void>
   >;
@@ -103,7 +137,7 @@ interface MockTauriReturn {
   linkRepo: Mock<(path: string) => Promise<LinkedRepo>>;
   cloneAndLinkRepo: Mock<
     (input: {
-      provider: 'github' | 'gitlab';
+      provider: 'github' | 'gitlab' | 'gitea';
       repo: string;
       host?: string;
       destDir: string;
@@ -116,7 +150,15 @@ interface MockTauriReturn {
   deleteFeedback: Mock<(feedbackId: string) => Promise<void>>;
   getFeedbackComments: Mock<(feedbackId: string) => Promise<Comment[]>>;
   addComment: Mock<(feedbackId: string, body: string) => Promise<string>>;
+  getFeedbackAttachments: Mock<(feedbackId: string) => Promise<FeedbackAttachment[]>>;
+  addFeedbackAttachment: Mock<
+    (feedbackId: string, bytes: number[], mime: string) => Promise<FeedbackAttachment>
+  >;
+  pasteClipboardImageAttachment: Mock<(feedbackId: string) => Promise<FeedbackAttachment>>;
+  deleteFeedbackAttachment: Mock<(attachmentId: string) => Promise<void>>;
   getFeedbackByReview: Mock<(reviewId: string) => Promise<Feedback[]>>;
+  reanchorFeedback: Mock<(reviewId: string) => Promise<{ moved: number; outdated: number }>>;
+  explainFeedback: Mock<(feedbackId: string, agentId: string) => Promise<string>>;
   generateReview: Mock<
     (
       diffText: string,
@@ -125,8 +167,15 @@ interface MockTauriReturn {
       repoId?: string,
       source?: ReviewSource,
       useSnapshot?: boolean,
-      onProgress?: Channel<ProgressEventPayload>
-    ) => Promise<{ task_count: number; review_id: string; run_id?: string }>
+      onProgress?: Channel<ProgressEventPayload>,
+      excludedPaths?: string[],
+      mode?: ReviewMode
+    ) => Promise<{
+      task_count: number;
+      feedback_count?: number;
+      review_id: string;
+      run_id?: string;
+    }>
   >;
   stop_generation: Mock<(runId: string) => Promise<void>>;
 }
@@ -134,15 +183,31 @@ interface MockTauriReturn {
 function createMockTauri(): MockTauriReturn {
   const mock: MockTauriReturn = {
     getAllReviews: vi.fn().mockResolvedValue([createMockReview()]),
+    listReviews: vi.fn().mockResolvedValue({ reviews: [createMockReview()], total: 1 }),
     getPendingReviews: vi.fn().mockResolvedValue([]),
     getReviewRuns: vi.fn().mockResolvedValue([createMockRun()]),
+    getRunDiff: vi.fn().mockResolvedValue('diff --git a/test.ts b/test.ts'),
+    mergeReviews: vi
+      .fn()
+      .mockResolvedValue({ runs_moved: 1, feedback_moved: 0, feedback_deduplicated: 0 }),
     getLinkedRepos: vi.fn().mockResolvedValue([]),
     parseDiff: vi.fn().mockResolvedValue(createMockParsedDiff()),
     loadTasks: vi.fn().mockResolvedValue([createMockTask()]),
     updateTaskStatus: vi.fn().mockResolvedValue(undefined),
+    createTask: vi.fn().mockResolvedValue(createMockTask({ ai_generated: false })),
+    updateTask: vi.fn().mockResolvedValue(createMockTask({ ai_generated: false })),
+    deleteTask: vi.fn().mockResolvedValue(undefined),
+    mergeTasks: vi.fn().mockResolvedValue(createMockTask()),
+    renameSubFlow: vi.fn().mockResolvedValue(0),
+    setTaskSubFlow: vi.fn().mockResolvedValue(undefined),
+    getSubFlowOrder: vi.fn().mockResolvedValue([]),
+    setSubFlowOrder: vi.fn().mockResolvedValue(undefined),
     getAgents: vi
       .fn()
       .mockResolvedValue([{ id: 'agent-1', name: 'Test Agent', description: 'Test' }]),
+    probeAgent: vi
+      .fn()
+      .mockResolvedValue({ available: true, version: '1.0.0', path: '/usr/bin/agent' }),
     addCustomAgent: vi.fn().mockResolvedValue(undefined),
     deleteCustomAgent: vi.fn().mockResolvedValue(undefined),
     linkRepo: vi.fn().mockImplementation((path: string) =>
@@ -169,12 +234,24 @@ function createMockTauri(): MockTauriReturn {
     deleteFeedback: vi.fn().mockResolvedValue(undefined),
     getFeedbackComments: vi.fn().mockResolvedValue([]),
     addComment: vi.fn().mockResolvedValue('comment-1'),
+    getFeedbackAttachments: vi.fn().mockResolvedValue([]),
+    addFeedbackAttachment: vi.fn().mockResolvedValue(createMockAttachment()),
+    pasteClipboardImageAttachment: vi.fn().mockResolvedValue(createMockAttachment()),
+    deleteFeedbackAttachment: vi.fn().mockResolvedValue(undefined),
     getFeedbackByReview: vi.fn().mockResolvedValue([createMockFeedback()]),
+    reanchorFeedback: vi.fn().mockResolvedValue({ moved: 0, outdated: 0 }),
+    toggleFeedbackPin: vi.fn().mockResolvedValue(true),
+    explainFeedback: vi.fn().mockResolvedValue('The value is never checked for null.'),
     generateReview: vi.fn().mockResolvedValue({
       task_count: 5,
       review_id: 'review-1',
       run_id: 'run-1',
     }),
+    summarizeReview: vi.fn().mockResolvedValue({
+      task_count: 0,
+      review_id: 'review-1',
+      run_id: 'run-1',
+    }),
     stop_generation: vi.fn().mockResolvedValue(undefined),
   };
   return mock;
@@ -189,4 +266,5 @@ export {
   createMockParsedDiff,
   createMockFeedback,
   createMockComment,
+  createMockAttachment,
 };
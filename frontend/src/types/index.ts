@@ -4,6 +4,20 @@ export interface DiffFile {
   new_path: string;
   hunks: DiffHunk[];
   status?: 'added' | 'modified' | 'deleted' | 'renamed';
+  /** Lockfile, build output or a `linguist-generated` file. */
+  generated?: boolean;
+  /** Set when the file is a submodule whose commit changed. */
+  submodule?: SubmoduleChange | null;
+}
+
+export interface SubmoduleChange {
+  path: string;
+  /** Null when the diff adds the submodule. */
+  old_commit: string | null;
+  /** Null when the diff removes the submodule. */
+  new_commit: string | null;
+  /** Compare page on the submodule's host, when `.gitmodules` has its URL. */
+  link?: string | null;
 }
 
 export interface DiffHunk {
@@ -24,6 +38,25 @@ export interface ParsedDiff {
   hunk_manifest?: string;
   source?: ReviewSource;
   title?: string | null;
+  /** Set when the diff was fetched from a pull request. */
+  pr_details?: PrDetails | null;
+  conflicts?: ConflictedFile[];
+}
+
+/** What a fetched pull request is about; `null` where the provider doesn't say. */
+export interface PrDetails {
+  author: string | null;
+  base_ref: string | null;
+  head_ref: string | null;
+  commit_count: number | null;
+  additions: number | null;
+  deletions: number | null;
+}
+
+export interface ConflictedFile {
+  file_path: string;
+  line_number: number;
+  conflicts: number;
 }
 
 export interface CommentThread {
@@ -77,15 +110,45 @@ export interface HunkRef {
   new_lines: number;
 }
 
+/** Quick reviews skip the snapshot, use a lighter prompt and cap the tasks. */
+export type ReviewMode = 'quick' | 'thorough';
+
 export interface ReviewRun {
   id: string;
   review_id: string;
   agent_id: string;
   input_ref: string;
-  diff_text: string;
+  diff_hash: string;
   created_at: string;
   task_count: number;
   status: string;
+  plan?: Plan | null;
+  /** Model the agent reported for the run, when it said. */
+  agent_model?: string | null;
+}
+
+export interface RunToolCall {
+  tool_call_id: string;
+  title: string;
+  kind: string;
+  status: string;
+  /** Only recorded when the run was generated with ACP debugging on. */
+  raw_input?: unknown;
+  raw_output?: unknown;
+}
+
+export interface ToolTiming {
+  tool: string;
+  calls: number;
+  total_ms: number;
+  max_ms: number;
+}
+
+export interface RunToolLog {
+  calls: RunToolCall[];
+  omitted: number;
+  /** Time spent in LaReview's MCP tools, slowest first. */
+  timings: ToolTiming[];
 }
 
 export interface Feedback {
@@ -102,16 +165,31 @@ export interface Feedback {
   /** Confidence score (0.0-1.0) indicating how certain the AI is this is a real issue */
   confidence: number;
   anchor: FeedbackAnchor | null;
+  /** Replacement for the anchored lines proposed by the agent */
+  suggested_replacement?: SuggestedReplacement | null;
+  /** Other places a cross-file finding applies to; `anchor` is the primary one */
+  extra_anchors?: FeedbackAnchor[];
+  /** Why the finding was flagged, from the agent or fetched on request */
+  rationale?: string | null;
+  /** Kept at the top of the feedback list while open */
+  pinned?: boolean;
   author: string;
   created_at: string;
   updated_at: string;
   comments?: Comment[];
 }
 
+export interface SuggestedReplacement {
+  old_lines: string[];
+  new_lines: string[];
+}
+
 export interface FeedbackAnchor {
   file_path: string | null;
   line_number: number | null;
   side: 'old' | 'new' | null;
+  /** The line is no longer part of the review's current diff. */
+  outdated?: boolean;
 }
 
 export interface Comment {
@@ -124,6 +202,35 @@ export interface Comment {
   updated_at: string;
 }
 
+/** Image attached to a feedback thread, with an inline `data:` URL for display. */
+export interface FeedbackAttachment {
+  id: string;
+  feedback_id: string;
+  mime: string;
+  byte_size: number;
+  created_at: string;
+  data_url: string;
+}
+
+/** A review exported as the request body for `gh api .../pulls/N/reviews`. */
+export interface GhReviewExport {
+  json: string;
+  endpoint: string | null;
+  skipped: Array<{ id: string; title: string; reason: string }>;
+  path: string | null;
+}
+
+/** A review pushed to its pull request, and how its comments were fitted onto the current diff. */
+export interface RemoteReviewPush {
+  url: string;
+  anchoring: {
+    moved: number;
+    /** Ids of feedback posted in the summary because its line is gone. */
+    unplaced: string[];
+    unplaced_locations: number;
+  };
+}
+
 export interface LinkedRepo {
   id: string;
   path: string;
@@ -132,6 +239,38 @@ export interface LinkedRepo {
   remotes: string[];
   review_count?: number;
   allow_snapshot_access: boolean;
+  default_agent_id?: string | null;
+  default_rule_categories?: string[];
+  watched?: boolean;
+}
+
+/** Emitted as `lareview:repo-head-changed` when a watched repo's HEAD moves. */
+export interface RepoHeadChange {
+  repo_id: string;
+  previous: string;
+  current: string;
+}
+
+/** An open pull/merge request of a linked repo. */
+export interface OpenPullRequest {
+  number: number;
+  title: string;
+  author: string | null;
+  url: string;
+  draft: boolean;
+  updated_at: string | null;
+}
+
+export type BatchReviewStatus = 'fetching' | 'generating' | 'done' | 'failed';
+
+/** Emitted as `lareview:batch-review-progress` for each PR of a batch review. */
+export interface BatchReviewProgress {
+  repo_id: string;
+  number: number;
+  title: string;
+  status: BatchReviewStatus;
+  review_id: string | null;
+  error: string | null;
 }
 
 export interface WorktreeSession {
@@ -188,6 +327,24 @@ export interface IssueCheckWithFindings extends IssueCheck {
   findings: IssueFinding[];
 }
 
+/** User-defined check the agent reports a result for on every run */
+export interface IssueCheckDefinition {
+  id: string;
+  name: string;
+  /** Category ID results are reported under */
+  category: string;
+  /** Instructions added to the generation prompt */
+  prompt: string;
+  enabled: boolean;
+  created_at: string;
+  updated_at: string;
+}
+
+export type IssueCheckDefinitionInput = Pick<
+  IssueCheckDefinition,
+  'name' | 'category' | 'prompt' | 'enabled'
+>;
+
 // Rule Library Types
 export type LibraryCategory =
   | 'security'
@@ -226,6 +383,10 @@ export interface Agent {
   args?: string[];
   logo?: string;
   available?: boolean;
+  /** Command looked up on PATH, for hints when the agent isn't found */
+  expected_command?: string;
+  /** Homebrew command that installs the agent, when there is one */
+  install_command?: string | null;
   is_custom?: boolean;
 }
 
@@ -235,14 +396,109 @@ export interface VcsStatus {
   cliPath: string;
   login?: string;
   error?: string;
+  offline?: boolean;
+  /** Host the status was checked against, e.g. github.com or an enterprise host. */
+  host?: string;
+  /** Where the CLI's credentials came from, e.g. keyring or GH_TOKEN. */
+  authMethod?: string;
+  /** Version reported by the CLI, e.g. 2.81.0. */
+  cliVersion?: string;
+}
+
+/** The gh/glab executable and default host picked for a VCS provider. */
+export interface VcsCliConfig {
+  path: string | null;
+  default_host: string | null;
+}
+
+export interface AgentProbe {
+  available: boolean;
+  version?: string | null;
+  path?: string | null;
+  error?: string | null;
 }
 
 export interface CliStatus {
   isInstalled: boolean;
   version?: string;
+  build?: string;
   path?: string;
 }
 
+export type DiagnosticLevel = 'ok' | 'warn' | 'error';
+
+export interface DiagnosticCheck {
+  section: string;
+  name: string;
+  level: DiagnosticLevel;
+  detail: string;
+  fix?: string;
+}
+
+export interface DiagnosticsReport {
+  appVersion: string;
+  platform: string;
+  generatedAt: string;
+  checks: DiagnosticCheck[];
+  /** Plain-text rendering for bug reports. */
+  text: string;
+}
+
+/** Generate input saved while composing, restored after a restart. */
+export interface GenerateDraft {
+  diff_text: string;
+  agent_id: string | null;
+  repo_id: string | null;
+  /** PR/MR link the diff was fetched from */
+  pr_ref: string | null;
+}
+
+/** A diff handed to the app earlier (piped in or fetched for a CLI request). */
+export interface RecentPendingDiff {
+  id: string;
+  /** How it was received, e.g. "stdin" */
+  source: string;
+  repo_root: string | null;
+  agent: string | null;
+  created_at: string;
+  review_source: ReviewSource | null;
+  files: number;
+}
+
+/** A pending diff made current again. */
+export interface PendingReviewState {
+  id: string;
+  diff: string;
+  repo_root: string | null;
+  agent: string | null;
+  source: string;
+  created_at: string;
+  review_source: ReviewSource | null;
+}
+
+/** Where LaReview keeps the review database, run logs and prompts. */
+export interface DataDirInfo {
+  path: string;
+  defaultPath: string;
+  /** Environment variable pinning the directory; the setting is locked while set. */
+  envOverride: string | null;
+  /** True when the directory was picked in Settings. */
+  isCustom: boolean;
+}
+
+export interface EncryptionStatus {
+  /** Whether this build can encrypt the database. */
+  supported: boolean;
+  enabled: boolean;
+  /** The database is encrypted and waiting for its passphrase. */
+  locked: boolean;
+}
+
+export interface CliInstallOutcome {
+  path: string;
+  warning?: string | null;
+}
+
 export interface EditorCandidate {
   id: string;
   label: string;
@@ -278,6 +534,38 @@ export interface Review {
   agent_id?: string;
   status: string;
   active_run_status?: string | null;
+  tags?: string[];
+  suggested_tags?: string[];
+  /** Overall verdict picked for the review; null until the user picks one. */
+  decision?: ReviewDecision | null;
+  /** Linked repo whose remote matches `source`, if any. */
+  repo_id?: string | null;
+  repo_name?: string | null;
+}
+
+/** One page of the review list, most recently updated first. */
+export interface ReviewPage {
+  reviews: Review[];
+  /** Number of reviews across all pages. */
+  total: number;
+}
+
+/** Submitted as the PR review event: APPROVE, REQUEST_CHANGES or COMMENT. */
+export type ReviewDecision = 'approve' | 'request_changes' | 'comment';
+
+/** A reviewer-written follow-up to finish before merging. */
+export interface ChecklistItem {
+  id: string;
+  review_id: string;
+  text: string;
+  done: boolean;
+  created_at: string;
+}
+
+export interface ReviewMergeOutcome {
+  runs_moved: number;
+  feedback_moved: number;
+  feedback_deduplicated: number;
 }
 
 export type ReviewSource =
@@ -300,8 +588,43 @@ export type ReviewSource =
       head_sha?: string;
       base_sha?: string;
       start_sha?: string;
+    }
+  | {
+      type: 'github_compare';
+      owner: string;
+      repo: string;
+      base: string;
+      head: string;
+      mode: CompareMode;
+      url?: string;
+      head_sha?: string;
+      base_sha?: string;
+    }
+  | {
+      type: 'gitea_pr';
+      host: string;
+      owner: string;
+      repo: string;
+      number: number;
+      url?: string;
+      head_sha?: string;
+      base_sha?: string;
+    }
+  | {
+      type: 'gitlab_compare';
+      host: string;
+      project_path: string;
+      base: string;
+      head: string;
+      mode: CompareMode;
+      url?: string;
+      head_sha?: string;
+      base_sha?: string;
     };
 
+/** `merge_base` is `base...head`, `direct` is `base..head`. */
+export type CompareMode = 'merge_base' | 'direct';
+
 export type ViewType = 'generate' | 'review' | 'repos' | 'rules' | 'settings' | 'learning';
 
 export interface Plan {
@@ -366,3 +689,29 @@ export interface MergeConfidence {
   /** When this assessment was computed (RFC3339) */
   computedAt: string;
 }
+
+// Review Stats Types
+export interface ReviewStats {
+  files: number;
+  additions: number;
+  deletions: number;
+  task_risk: { low: number; medium: number; high: number };
+  /** Risk of the review as a whole, under the configured risk policy */
+  overall_risk: 'low' | 'medium' | 'high';
+  /** Ignored feedback is not counted */
+  feedback_impact: { blocking: number; nice_to_have: number; nitpick: number };
+}
+
+/** Feedback and task coverage for one file, for the review nav's file tree */
+export interface FileReviewStatus {
+  path: string;
+  /** Todo or in-progress feedback anchored to the file */
+  open_feedback: number;
+  /** Done or ignored feedback anchored to the file */
+  closed_feedback: number;
+  /** Tasks whose diff refs include the file */
+  tasks: number;
+  closed_tasks: number;
+  /** False when only stale feedback points at the file */
+  in_diff: boolean;
+}
@@ -51,7 +51,23 @@ export const LANGUAGE_MAP: Record<string, string> = {
   '.gitattributes': 'shell',
 };
 
+// Files identified by their name rather than an extension.
+export const FILENAME_LANGUAGE_MAP: Record<string, string> = {
+  dockerfile: 'dockerfile',
+  makefile: 'makefile',
+  gnumakefile: 'makefile',
+  gemfile: 'ruby',
+  rakefile: 'ruby',
+  podfile: 'ruby',
+  'cargo.lock': 'toml',
+};
+
 export function getLanguageFromPath(path: string): string {
-  const ext = path.substring(path.lastIndexOf('.')) || '';
-  return LANGUAGE_MAP[ext.toLowerCase()] || 'plaintext';
+  const fileName = path.substring(path.lastIndexOf('/') + 1).toLowerCase();
+  const byName = FILENAME_LANGUAGE_MAP[fileName];
+  if (byName) return byName;
+
+  const dot = fileName.lastIndexOf('.');
+  if (dot === -1) return 'plaintext';
+  return LANGUAGE_MAP[fileName.substring(dot)] || 'plaintext';
 }
@@ -1 +1,2 @@
 pub mod review;
+pub mod snapshot;
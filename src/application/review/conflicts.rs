@@ -0,0 +1,121 @@
+//! Blocking feedback for merge conflict markers left in a reviewed diff.
+
+use crate::domain::{
+    Comment, Feedback, FeedbackAnchor, FeedbackImpact, FeedbackSide, ReviewStatus,
+};
+use crate::infra::diff::index::ConflictedFile;
+
+/// Author recorded on feedback LaReview raises itself.
+pub const SYSTEM_AUTHOR: &str = "agent:system";
+
+/// Title of the feedback raised for conflict markers in `file_path`.
+pub fn conflict_feedback_title(file_path: &str) -> String {
+    format!("Unresolved merge conflict in {}", file_path)
+}
+
+/// Builds a blocking feedback entry, with an explanatory comment, for each
+/// conflicted file that doesn't already have one in `existing`.
+pub fn conflict_feedback(
+    review_id: &str,
+    diff_hash: &str,
+    conflicted: &[ConflictedFile],
+    existing: &[Feedback],
+    now: &str,
+) -> Vec<(Feedback, Comment)> {
+    conflicted
+        .iter()
+        .filter(|file| {
+            let title = conflict_feedback_title(&file.file_path);
+            !existing.iter().any(|feedback| feedback.title == title)
+        })
+        .map(|file| {
+            let feedback_id = format!("feedback-{}", uuid::Uuid::new_v4());
+            let blocks = if file.conflicts == 1 {
+                "a conflict block".to_string()
+            } else {
+                format!("{} conflict blocks", file.conflicts)
+            };
+            let feedback = Feedback {
+                id: feedback_id.clone(),
+                review_id: review_id.to_string(),
+                task_id: None,
+                rule_id: None,
+                finding_id: None,
+                category: None,
+                title: conflict_feedback_title(&file.file_path),
+                status: ReviewStatus::Todo,
+                impact: FeedbackImpact::Blocking,
+                confidence: 1.0,
+                anchor: Some(FeedbackAnchor {
+                    file_path: Some(file.file_path.clone()),
+                    line_number: Some(file.line_number),
+                    side: Some(FeedbackSide::New),
+                    hunk_ref: None,
+                    head_sha: None,
+                    diff_hash: Some(diff_hash.to_string()),
+                    outdated: false,
+                }),
+                suggested_replacement: None,
+                extra_anchors: Vec::new(),
+                rationale: None,
+                pinned: false,
+                author: SYSTEM_AUTHOR.to_string(),
+                created_at: now.to_string(),
+                updated_at: now.to_string(),
+            };
+            let comment = Comment {
+                id: format!("comment-{}", uuid::Uuid::new_v4()),
+                feedback_id,
+                author: SYSTEM_AUTHOR.to_string(),
+                body: format!(
+                    "This file still contains {} marked with `<<<<<<<`, `=======` and \
+                     `>>>>>>>`. Resolve the merge conflict before approving.",
+                    blocks
+                ),
+                parent_id: None,
+                created_at: now.to_string(),
+                updated_at: now.to_string(),
+            };
+            (feedback, comment)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conflicted(path: &str) -> ConflictedFile {
+        ConflictedFile {
+            file_path: path.to_string(),
+            line_number: 7,
+            conflicts: 2,
+        }
+    }
+
+    #[test]
+    fn test_conflict_feedback_is_blocking_and_anchored() {
+        let raised = conflict_feedback("rev-1", "hash", &[conflicted("src/a.rs")], &[], "now");
+        assert_eq!(raised.len(), 1);
+        let (feedback, comment) = &raised[0];
+        assert_eq!(feedback.impact, FeedbackImpact::Blocking);
+        assert_eq!(feedback.title, "Unresolved merge conflict in src/a.rs");
+        let anchor = feedback.anchor.as_ref().unwrap();
+        assert_eq!(anchor.line_number, Some(7));
+        assert_eq!(anchor.diff_hash.as_deref(), Some("hash"));
+        assert_eq!(comment.feedback_id, feedback.id);
+        assert!(comment.body.contains("2 conflict blocks"));
+    }
+
+    #[test]
+    fn test_conflict_feedback_skips_files_already_flagged() {
+        let files = [conflicted("src/a.rs"), conflicted("src/b.rs")];
+        let (existing, _) = conflict_feedback("rev-1", "hash", &files[..1], &[], "now")
+            .pop()
+            .unwrap();
+
+        let raised = conflict_feedback("rev-1", "hash", &files, &[existing], "now");
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].0.title, "Unresolved merge conflict in src/b.rs");
+    }
+}
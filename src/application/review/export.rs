@@ -1,10 +1,14 @@
+use super::risk::RiskPolicy;
+use super::stats::compute_review_stats;
 use crate::domain::{
-    Comment, Feedback, FeedbackImpact, FeedbackSide, MergeConfidence, Review, ReviewRun,
-    ReviewTask, RiskLevel,
+    ChecklistItem, Comment, Feedback, FeedbackAttachment, FeedbackImpact, FeedbackSide,
+    MergeConfidence, Review, ReviewRule, ReviewRun, ReviewSource, ReviewTask, RiskLevel,
 };
+use crate::infra::diff::cached_diff_index;
 use crate::infra::diff::index::DiffIndex;
 use anyhow::Result;
 use std::collections::HashSet;
+use std::path::PathBuf;
 
 pub struct ExportData {
     pub review: Review,
@@ -12,7 +16,13 @@ pub struct ExportData {
     pub tasks: Vec<ReviewTask>,
     pub feedbacks: Vec<Feedback>,
     pub comments: Vec<Comment>,
+    /// Images attached to feedback; embedded as `data:` URIs.
+    pub attachments: Vec<FeedbackAttachment>,
+    /// Rules referenced by `feedbacks`; their text is quoted under each finding.
+    pub rules: Vec<ReviewRule>,
     pub merge_confidence: Option<MergeConfidence>,
+    /// The reviewer's follow-up checklist, in creation order.
+    pub checklist: Vec<ChecklistItem>,
 }
 
 #[derive(Debug, Clone)]
@@ -32,8 +42,28 @@ pub struct ExportOptions {
     pub include_toc: bool,
     pub selected_tasks: Option<HashSet<String>>,
     pub selected_feedbacks: Option<HashSet<String>>,
+    /// Handlebars template to render the Markdown export from instead of the
+    /// built-in layout. See [`ReviewExporter::render_template`] for its context.
+    pub template: Option<String>,
+    /// Also emit each task's diagram source as `diagrams/<task>.mmd` in
+    /// [`ExportResult::assets`], linked under the diagram.
+    pub diagram_files: bool,
 }
 
+/// Where a user's Markdown export template lives.
+pub fn user_template_path() -> PathBuf {
+    crate::infra::app_config::app_data_dir()
+        .join("templates")
+        .join(EXPORT_TEMPLATE_FILE)
+}
+
+/// The user's export template, if they wrote one.
+pub fn load_user_template() -> Option<String> {
+    std::fs::read_to_string(user_template_path()).ok()
+}
+
+const EXPORT_TEMPLATE_FILE: &str = "export.md.hbs";
+
 pub struct ReviewExporter;
 
 impl ReviewExporter {
@@ -41,9 +71,29 @@ impl ReviewExporter {
         data: &ExportData,
         options: &ExportOptions,
     ) -> Result<ExportResult> {
+        Self::render_markdown(data, options)
+    }
+
+    /// Renders the export synchronously; usable without an async runtime.
+    pub fn render_markdown(data: &ExportData, options: &ExportOptions) -> Result<ExportResult> {
+        if let Some(template) = &options.template {
+            match Self::render_template(template, data, options) {
+                Ok(markdown) => {
+                    return Ok(ExportResult {
+                        markdown,
+                        assets: Default::default(),
+                    });
+                }
+                Err(err) => log::warn!(
+                    "Ignoring export template: {}; using the built-in layout",
+                    err
+                ),
+            }
+        }
+
         let mut md = String::new();
-        let assets = std::collections::HashMap::new();
-        let diff_index = DiffIndex::new(&data.run.diff_text).ok();
+        let mut assets = std::collections::HashMap::new();
+        let diff_index = cached_diff_index(&data.run.diff_text).ok();
 
         // Title
         md.push_str(&format!("# {}\n\n", data.review.title));
@@ -52,6 +102,9 @@ impl ReviewExporter {
             md.push_str("## Table of Contents\n\n");
             md.push_str("- [Overview](#overview)\n");
             md.push_str("- [Metadata](#metadata)\n");
+            if !data.checklist.is_empty() {
+                md.push_str("- [Checklist](#checklist)\n");
+            }
 
             if options.include_tasks {
                 md.push_str("- [Tasks](#tasks)\n");
@@ -133,6 +186,12 @@ impl ReviewExporter {
             md.push_str("\n--- \n\n");
         }
 
+        if !data.checklist.is_empty() {
+            md.push_str("## Checklist\n\n");
+            md.push_str(&Self::render_checklist_markdown(&data.checklist));
+            md.push_str("\n--- \n\n");
+        }
+
         let mut rendered_feedback_ids = HashSet::new();
         if options.include_tasks {
             let mut rendered_tasks_header = false;
@@ -164,6 +223,11 @@ impl ReviewExporter {
                     md.push_str("**Diagram:**\n\n```mermaid\n");
                     md.push_str(diagram);
                     md.push_str("\n```\n\n");
+                    if options.diagram_files {
+                        let name = Self::diagram_asset_name(&task.title, &assets);
+                        md.push_str(&format!("[Diagram source]({})\n\n", name));
+                        assets.insert(name, diagram.as_bytes().to_vec());
+                    }
                 }
 
                 let task_feedbacks: Vec<_> = data
@@ -193,38 +257,28 @@ impl ReviewExporter {
                             .cloned()
                             .collect();
 
-                        let diff_snippet = if options.include_context_diff {
-                            if let (Some(index), Some(anchor)) = (&diff_index, &feedback.anchor) {
-                                if let (Some(path), Some(line)) =
-                                    (&anchor.file_path, anchor.line_number)
-                                {
-                                    index
-                                        .find_hunk_at_line(
-                                            path,
-                                            line,
-                                            anchor.side.unwrap_or(FeedbackSide::New),
-                                        )
-                                        .map(|indexed| {
-                                            DiffIndex::render_hunk_unified(
-                                                &indexed.hunk,
-                                                indexed.coords,
-                                            )
-                                        })
-                                } else {
-                                    None
-                                }
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        };
+                        let diff_snippet = diff_index
+                            .as_deref()
+                            .filter(|_| options.include_context_diff)
+                            .and_then(|index| Self::render_context_diff(index, feedback));
 
                         md.push_str(&Self::render_single_feedback_markdown(
                             feedback,
                             &comments,
                             diff_snippet.as_deref(),
                         ));
+                        md.push_str(&Self::render_extra_locations_markdown(
+                            feedback,
+                            &data.review.source,
+                        ));
+                        md.push_str(&Self::render_rule_markdown(
+                            feedback.rule_id.as_deref(),
+                            &data.rules,
+                        ));
+                        md.push_str(&Self::render_attachments_markdown(
+                            &feedback.id,
+                            &data.attachments,
+                        ));
                         rendered_feedback_ids.insert(feedback.id.clone());
                     }
                 }
@@ -256,33 +310,28 @@ impl ReviewExporter {
                     .cloned()
                     .collect();
 
-                let diff_snippet = if options.include_context_diff {
-                    if let (Some(index), Some(anchor)) = (&diff_index, &feedback.anchor) {
-                        if let (Some(path), Some(line)) = (&anchor.file_path, anchor.line_number) {
-                            index
-                                .find_hunk_at_line(
-                                    path,
-                                    line,
-                                    anchor.side.unwrap_or(FeedbackSide::New),
-                                )
-                                .map(|indexed| {
-                                    DiffIndex::render_hunk_unified(&indexed.hunk, indexed.coords)
-                                })
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
+                let diff_snippet = diff_index
+                    .as_deref()
+                    .filter(|_| options.include_context_diff)
+                    .and_then(|index| Self::render_context_diff(index, feedback));
 
                 md.push_str(&Self::render_single_feedback_markdown(
                     feedback,
                     &comments,
                     diff_snippet.as_deref(),
                 ));
+                md.push_str(&Self::render_extra_locations_markdown(
+                    feedback,
+                    &data.review.source,
+                ));
+                md.push_str(&Self::render_rule_markdown(
+                    feedback.rule_id.as_deref(),
+                    &data.rules,
+                ));
+                md.push_str(&Self::render_attachments_markdown(
+                    &feedback.id,
+                    &data.attachments,
+                ));
             }
         }
 
@@ -292,6 +341,115 @@ impl ReviewExporter {
         })
     }
 
+    /// Renders `template` with the selected part of `data`. Output is not
+    /// HTML-escaped. Context:
+    /// - `review`: `id`, `title`, `summary`, `source`, `status`, `tags[]`,
+    ///   `created_at`, `updated_at`
+    /// - `run`: `id`, `agent_id`, `input_ref`, `diff_hash`, `created_at`
+    /// - `tasks[]`: `id`, `title`, `description`, `files[]`, `stats`
+    ///   (`additions`, `deletions`, `risk`, `tags[]`), `status`, `sub_flow`,
+    ///   `insight`, `diagram`
+    /// - `feedbacks[]`: `id`, `task_id`, `title`, `status`, `impact`,
+    ///   `confidence`, `anchor` (`file_path`, `line_number`, `side`),
+    ///   `author`, `created_at`, `markdown` (the item as the built-in layout
+    ///   renders it)
+    /// - `comments[]`: `feedback_id`, `author`, `body`, `created_at`
+    /// - `stats`: `files`, `additions`, `deletions`, `task_risk` (`low`,
+    ///   `medium`, `high`), `overall_risk`, `feedback_impact` (`blocking`,
+    ///   `nice_to_have`, `nitpick`)
+    /// - `merge_confidence`: `score`, `reasons[]`, or null
+    /// - `checklist[]`: `id`, `text`, `done`, `created_at`
+    pub fn render_template(
+        template: &str,
+        data: &ExportData,
+        options: &ExportOptions,
+    ) -> Result<String> {
+        let tasks: Vec<&ReviewTask> = data
+            .tasks
+            .iter()
+            .filter(|task| {
+                options
+                    .selected_tasks
+                    .as_ref()
+                    .is_none_or(|selected| selected.contains(&task.id))
+            })
+            .collect();
+        let feedbacks: Vec<&Feedback> = data
+            .feedbacks
+            .iter()
+            .filter(|feedback| {
+                options
+                    .selected_feedbacks
+                    .as_ref()
+                    .is_none_or(|selected| selected.contains(&feedback.id))
+            })
+            .collect();
+        let comments: Vec<&Comment> = data
+            .comments
+            .iter()
+            .filter(|comment| feedbacks.iter().any(|f| f.id == comment.feedback_id))
+            .collect();
+
+        let feedback_context = feedbacks
+            .iter()
+            .map(|feedback| {
+                let thread: Vec<Comment> = comments
+                    .iter()
+                    .filter(|c| c.feedback_id == feedback.id)
+                    .map(|c| (*c).clone())
+                    .collect();
+                let mut value = serde_json::to_value(feedback)?;
+                value["markdown"] =
+                    Self::render_single_feedback_markdown(feedback, &thread, None).into();
+                Ok(value)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let owned_tasks: Vec<ReviewTask> = tasks.iter().map(|t| (*t).clone()).collect();
+        let owned_feedbacks: Vec<Feedback> = feedbacks.iter().map(|f| (*f).clone()).collect();
+        let stats = compute_review_stats(
+            &data.run,
+            &owned_tasks,
+            &owned_feedbacks,
+            &RiskPolicy::configured(),
+        )
+        .ok();
+
+        let context = serde_json::json!({
+            "review": data.review,
+            "run": {
+                "id": data.run.id,
+                "agent_id": data.run.agent_id,
+                "input_ref": data.run.input_ref,
+                "diff_hash": data.run.diff_hash,
+                "created_at": data.run.created_at,
+            },
+            "tasks": tasks,
+            "feedbacks": feedback_context,
+            "comments": comments,
+            "stats": stats,
+            "merge_confidence": data.merge_confidence,
+            "checklist": data.checklist,
+        });
+
+        let mut handlebars = handlebars::Handlebars::new();
+        handlebars.register_escape_fn(handlebars::no_escape);
+        Ok(handlebars.render_template(template, &context)?)
+    }
+
+    /// The checklist as GitHub task list items.
+    pub fn render_checklist_markdown(checklist: &[ChecklistItem]) -> String {
+        checklist
+            .iter()
+            .map(|item| {
+                format!(
+                    "- [{}] {}\n",
+                    if item.done { "x" } else { " " },
+                    item.text.replace('\n', " ")
+                )
+            })
+            .collect()
+    }
+
     pub fn render_task_markdown(task: &ReviewTask) -> String {
         let mut md = String::new();
         md.push_str(&format!("### {}\n\n", task.title));
@@ -331,9 +489,13 @@ impl ReviewExporter {
         };
 
         md.push_str(&format!(
-            "**Feedback:** {}<br>\n**Severity:** {} {}\n\n",
+            "**Feedback:** {}<br>\n**Severity:** {} {}",
             feedback.title, emoji, severity
         ));
+        if let Some(category) = &feedback.category {
+            md.push_str(&format!("<br>\n**Category:** `{}`", category));
+        }
+        md.push_str("\n\n");
 
         if let Some(snippet) = diff_snippet {
             md.push_str("**Context:**\n\n```diff\n");
@@ -357,6 +519,172 @@ impl ReviewExporter {
         md
     }
 
+    /// Quotes the rule that triggered a finding, when it's known.
+    pub fn render_rule_markdown(rule_id: Option<&str>, rules: &[ReviewRule]) -> String {
+        match rule_id.and_then(|id| rules.iter().find(|rule| rule.id == id)) {
+            Some(rule) => format!(
+                "> **Triggered by rule:** {}\n\n",
+                rule.text.replace('\n', "\n> ")
+            ),
+            None => String::new(),
+        }
+    }
+
+    /// A single task, for pasting outside LaReview: the export rendering plus
+    /// where its changes are, linked to the remote when possible.
+    pub fn render_task_item_markdown(task: &ReviewTask, source: &ReviewSource) -> String {
+        let mut md = Self::render_task_markdown(task);
+        let locations: Vec<String> = task
+            .diff_refs
+            .iter()
+            .map(|diff_ref| match diff_ref.hunks.first() {
+                Some(hunk) if hunk.new_lines > 0 => {
+                    Self::render_location(&diff_ref.file, hunk.new_start, FeedbackSide::New, source)
+                }
+                Some(hunk) => {
+                    Self::render_location(&diff_ref.file, hunk.old_start, FeedbackSide::Old, source)
+                }
+                None => format!("`{}`", diff_ref.file),
+            })
+            .collect();
+        if !locations.is_empty() {
+            md.push_str(&format!("**Files:** {}\n\n", locations.join(", ")));
+        }
+        md
+    }
+
+    /// A single finding with its location, suggested change and rule, for
+    /// pasting outside LaReview.
+    pub fn render_feedback_item_markdown(
+        feedback: &Feedback,
+        comments: &[Comment],
+        rules: &[ReviewRule],
+        source: &ReviewSource,
+    ) -> String {
+        let mut md = Self::render_single_feedback_markdown(feedback, comments, None);
+        if let Some(anchor) = &feedback.anchor
+            && let (Some(path), Some(line)) = (&anchor.file_path, anchor.line_number)
+        {
+            let side = anchor.side.unwrap_or(FeedbackSide::New);
+            md.push_str(&format!(
+                "**Location:** {}\n\n",
+                Self::render_location(path, line, side, source)
+            ));
+        }
+        md.push_str(&Self::render_extra_locations_markdown(feedback, source));
+        if let Some(suggestion) = &feedback.suggested_replacement {
+            md.push_str(&format!(
+                "**Suggested change:**\n\n{}\n\n",
+                suggestion.to_diff_block()
+            ));
+        }
+        md.push_str(&Self::render_rule_markdown(
+            feedback.rule_id.as_deref(),
+            rules,
+        ));
+        md
+    }
+
+    /// Hunks around each location of a finding. A finding spanning several
+    /// files gets a `---`/`+++` header per hunk so each one names its file.
+    fn render_context_diff(index: &DiffIndex, feedback: &Feedback) -> Option<String> {
+        let multi = !feedback.extra_anchors.is_empty();
+        let mut rendered = Vec::new();
+        for anchor in feedback.anchors() {
+            let (Some(path), Some(line)) = (&anchor.file_path, anchor.line_number) else {
+                continue;
+            };
+            let Some(indexed) =
+                index.find_hunk_at_line(path, line, anchor.side.unwrap_or(FeedbackSide::New))
+            else {
+                continue;
+            };
+            let hunk = DiffIndex::render_hunk_unified(&indexed.hunk, indexed.coords);
+            let hunk = if multi {
+                format!("--- a/{path}\n+++ b/{path}\n{hunk}")
+            } else {
+                hunk
+            };
+            if !rendered.contains(&hunk) {
+                rendered.push(hunk);
+            }
+        }
+        (!rendered.is_empty()).then(|| rendered.join("\n"))
+    }
+
+    /// The other places a cross-file finding applies to; empty for findings
+    /// with a single location.
+    fn render_extra_locations_markdown(feedback: &Feedback, source: &ReviewSource) -> String {
+        let locations: Vec<String> = feedback
+            .extra_anchors
+            .iter()
+            .filter_map(|anchor| {
+                let path = anchor.file_path.as_deref()?;
+                Some(match anchor.line_number {
+                    Some(line) => Self::render_location(
+                        path,
+                        line,
+                        anchor.side.unwrap_or(FeedbackSide::New),
+                        source,
+                    ),
+                    None => format!("`{}`", path),
+                })
+            })
+            .collect();
+        if locations.is_empty() {
+            String::new()
+        } else {
+            format!("**Also at:** {}\n\n", locations.join(", "))
+        }
+    }
+
+    /// `path:line`, linked to the remote file when the source has one.
+    fn render_location(path: &str, line: u32, side: FeedbackSide, source: &ReviewSource) -> String {
+        match source.file_permalink(path, line, side) {
+            Some(url) => format!("[`{}:{}`]({})", path, line, url),
+            None => format!("`{}:{}`", path, line),
+        }
+    }
+
+    /// Inline images for the attachments belonging to `feedback_id`.
+    pub fn render_attachments_markdown(
+        feedback_id: &str,
+        attachments: &[FeedbackAttachment],
+    ) -> String {
+        let mut md = String::new();
+        for (i, attachment) in attachments
+            .iter()
+            .filter(|a| a.feedback_id == feedback_id)
+            .enumerate()
+        {
+            md.push_str(&format!(
+                "![Attachment {}]({})\n\n",
+                i + 1,
+                attachment.data_url()
+            ));
+        }
+        md
+    }
+
+    /// `diagrams/<slug>.mmd` for a task titled `title`, numbered when another
+    /// task's diagram already took the name.
+    fn diagram_asset_name(
+        title: &str,
+        assets: &std::collections::HashMap<String, Vec<u8>>,
+    ) -> String {
+        let slug = match Self::slugify(title) {
+            slug if slug.is_empty() => "task".to_string(),
+            slug => slug,
+        };
+        let mut name = format!("diagrams/{slug}.mmd");
+        let mut n = 2;
+        while assets.contains_key(&name) {
+            name = format!("diagrams/{slug}-{n}.mmd");
+            n += 1;
+        }
+        name
+    }
+
     fn slugify(text: &str) -> String {
         text.to_lowercase()
             .chars()
@@ -368,3 +696,297 @@ impl ReviewExporter {
             .join("-")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        DiffRef, FeedbackAnchor, HunkRef, ReviewStatus, RuleScope, SuggestedReplacement,
+    };
+
+    fn github_source() -> ReviewSource {
+        ReviewSource::GitHubPr {
+            owner: "octo".into(),
+            repo: "app".into(),
+            number: 7,
+            url: None,
+            head_sha: Some("abc123".into()),
+            base_sha: None,
+        }
+    }
+
+    #[test]
+    fn test_feedback_item_includes_location_suggestion_and_rule() {
+        let feedback = Feedback {
+            id: "fb".into(),
+            review_id: "rev".into(),
+            task_id: None,
+            rule_id: Some("rule-1".into()),
+            finding_id: None,
+            category: None,
+            title: "Avoid unwrap".into(),
+            status: ReviewStatus::Todo,
+            impact: FeedbackImpact::Blocking,
+            confidence: 1.0,
+            anchor: Some(FeedbackAnchor {
+                file_path: Some("src/lib.rs".into()),
+                line_number: Some(3),
+                side: Some(FeedbackSide::New),
+                ..Default::default()
+            }),
+            suggested_replacement: Some(SuggestedReplacement {
+                old_lines: vec!["a.unwrap()".into()],
+                new_lines: vec!["a?".into()],
+            }),
+            extra_anchors: Vec::new(),
+            rationale: None,
+            pinned: false,
+            author: "agent".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        };
+        let rule = ReviewRule {
+            id: "rule-1".into(),
+            scope: RuleScope::Global,
+            repo_id: None,
+            glob: None,
+            category: None,
+            text: "No unwrap in library code".into(),
+            enabled: true,
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        };
+
+        let md = ReviewExporter::render_feedback_item_markdown(
+            &feedback,
+            &[],
+            &[rule],
+            &github_source(),
+        );
+
+        assert!(md.starts_with("**Feedback:** Avoid unwrap"));
+        assert!(md.contains(
+            "**Location:** [`src/lib.rs:3`](https://github.com/octo/app/blob/abc123/src/lib.rs#L3)"
+        ));
+        assert!(md.contains("```diff\n-a.unwrap()\n+a?\n```"));
+        assert!(md.contains("> **Triggered by rule:** No unwrap in library code"));
+    }
+
+    #[test]
+    fn test_feedback_spanning_files_lists_every_location() {
+        let anchor = |path: &str, line| FeedbackAnchor {
+            file_path: Some(path.into()),
+            line_number: Some(line),
+            side: Some(FeedbackSide::New),
+            ..Default::default()
+        };
+        let feedback = Feedback {
+            id: "fb".into(),
+            review_id: "rev".into(),
+            task_id: None,
+            rule_id: None,
+            finding_id: None,
+            category: None,
+            title: "Missing null check".into(),
+            status: ReviewStatus::Todo,
+            impact: FeedbackImpact::Blocking,
+            confidence: 1.0,
+            anchor: Some(anchor("src/a.rs", 2)),
+            suggested_replacement: None,
+            extra_anchors: vec![anchor("src/b.rs", 11)],
+            rationale: None,
+            pinned: false,
+            author: "agent".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        };
+
+        let md =
+            ReviewExporter::render_feedback_item_markdown(&feedback, &[], &[], &github_source());
+        assert!(md.contains("**Location:** [`src/a.rs:2`]"));
+        assert!(md.contains(
+            "**Also at:** [`src/b.rs:11`](https://github.com/octo/app/blob/abc123/src/b.rs#L11)"
+        ));
+
+        let diff = "diff --git a/src/a.rs b/src/a.rs\n--- a/src/a.rs\n+++ b/src/a.rs\n@@ -1,2 +1,3 @@\n fn a() {}\n+let x = load();\n fn b() {}\ndiff --git a/src/b.rs b/src/b.rs\n--- a/src/b.rs\n+++ b/src/b.rs\n@@ -10,2 +10,3 @@\n fn c() {}\n+let y = load();\n fn d() {}\n";
+        let index = DiffIndex::new(diff).unwrap();
+        let context = ReviewExporter::render_context_diff(&index, &feedback).unwrap();
+        assert!(context.contains("+++ b/src/a.rs"));
+        assert!(context.contains("+let x = load();"));
+        assert!(context.contains("+++ b/src/b.rs"));
+        assert!(context.contains("+let y = load();"));
+    }
+
+    #[test]
+    fn test_task_item_lists_files_without_links_for_pasted_diffs() {
+        let task = ReviewTask {
+            title: "Check parser".into(),
+            diff_refs: vec![DiffRef {
+                file: "src/parser.rs".into(),
+                hunks: vec![HunkRef {
+                    old_start: 10,
+                    old_lines: 2,
+                    new_start: 12,
+                    new_lines: 4,
+                }],
+            }],
+            ..Default::default()
+        };
+        let pasted = ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        };
+
+        let md = ReviewExporter::render_task_item_markdown(&task, &pasted);
+        assert!(md.starts_with("### Check parser"));
+        assert!(md.contains("**Files:** `src/parser.rs:12`"));
+
+        let linked = ReviewExporter::render_task_item_markdown(&task, &github_source());
+        assert!(linked.contains("(https://github.com/octo/app/blob/abc123/src/parser.rs#L12)"));
+    }
+
+    #[test]
+    fn test_user_template_renders_selected_items() {
+        use crate::domain::{ReviewRunStatus, ReviewStatus};
+
+        let feedback = |id: &str, title: &str| Feedback {
+            id: id.into(),
+            review_id: "rev".into(),
+            task_id: None,
+            rule_id: None,
+            finding_id: None,
+            category: None,
+            title: title.into(),
+            status: ReviewStatus::Todo,
+            impact: FeedbackImpact::Blocking,
+            confidence: 1.0,
+            anchor: None,
+            suggested_replacement: None,
+            extra_anchors: Vec::new(),
+            rationale: None,
+            pinned: false,
+            author: "agent".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        };
+        let data = ExportData {
+            review: Review {
+                id: "rev".into(),
+                title: "Parser & lexer".into(),
+                summary: None,
+                source: github_source(),
+                active_run_id: Some("run".into()),
+                status: ReviewStatus::Todo,
+                created_at: "now".into(),
+                updated_at: "now".into(),
+                tags: vec![],
+                decision: None,
+            },
+            run: ReviewRun {
+                id: "run".into(),
+                review_id: "rev".into(),
+                agent_id: "agent".into(),
+                input_ref: "input".into(),
+                diff_text: "".into(),
+                diff_hash: "template-test".into(),
+                status: ReviewRunStatus::Completed,
+                created_at: "now".into(),
+                agent_model: None,
+            },
+            tasks: vec![],
+            feedbacks: vec![feedback("keep", "Avoid unwrap"), feedback("drop", "Rename")],
+            comments: vec![],
+            attachments: vec![],
+            rules: vec![],
+            merge_confidence: None,
+            checklist: vec![],
+        };
+        let mut options = ExportOptions {
+            selected_feedbacks: Some(["keep".to_string()].into_iter().collect()),
+            template: Some(
+                "# {{review.title}}\n{{#each feedbacks}}- {{title}} ({{impact}})\n{{/each}}".into(),
+            ),
+            ..Default::default()
+        };
+
+        let md = ReviewExporter::render_markdown(&data, &options)
+            .unwrap()
+            .markdown;
+        assert_eq!(md, "# Parser & lexer\n- Avoid unwrap (blocking)\n");
+
+        // A broken template falls back to the built-in layout.
+        options.template = Some("{{#each feedbacks}}".into());
+        let md = ReviewExporter::render_markdown(&data, &options)
+            .unwrap()
+            .markdown;
+        assert!(md.starts_with("# Parser & lexer\n\n"));
+    }
+
+    #[test]
+    fn test_diagram_sources_are_emitted_as_assets() {
+        use std::sync::Arc;
+
+        let task = |id: &str| ReviewTask {
+            id: id.into(),
+            title: "Cache layer".into(),
+            diagram: Some(Arc::from(format!("flowchart LR\n  {id} --> db"))),
+            ..Default::default()
+        };
+        let data = ExportData {
+            review: Review {
+                id: "rev".into(),
+                title: "Caching".into(),
+                summary: None,
+                source: github_source(),
+                active_run_id: None,
+                status: ReviewStatus::Todo,
+                created_at: "now".into(),
+                updated_at: "now".into(),
+                tags: vec![],
+                decision: None,
+            },
+            run: ReviewRun {
+                id: "run".into(),
+                review_id: "rev".into(),
+                agent_id: "agent".into(),
+                input_ref: "input".into(),
+                diff_text: "".into(),
+                diff_hash: "diagram-assets-test".into(),
+                status: crate::domain::ReviewRunStatus::Completed,
+                created_at: "now".into(),
+                agent_model: None,
+            },
+            tasks: vec![task("a"), task("b")],
+            feedbacks: vec![],
+            comments: vec![],
+            attachments: vec![],
+            rules: vec![],
+            merge_confidence: None,
+            checklist: vec![],
+        };
+        let mut options = ExportOptions {
+            include_tasks: true,
+            diagram_files: true,
+            ..Default::default()
+        };
+
+        let result = ReviewExporter::render_markdown(&data, &options).unwrap();
+        assert_eq!(
+            result.assets["diagrams/cache-layer.mmd"],
+            b"flowchart LR\n  a --> db"
+        );
+        assert_eq!(
+            result.assets["diagrams/cache-layer-2.mmd"],
+            b"flowchart LR\n  b --> db"
+        );
+        assert!(
+            result
+                .markdown
+                .contains("[Diagram source](diagrams/cache-layer-2.mmd)")
+        );
+
+        options.diagram_files = false;
+        let result = ReviewExporter::render_markdown(&data, &options).unwrap();
+        assert!(result.assets.is_empty());
+        assert!(!result.markdown.contains("Diagram source"));
+    }
+}
@@ -0,0 +1,201 @@
+//! Per-file review status, for the file tree in the review nav.
+//!
+//! Reviewers often track coverage by file rather than by task: for every
+//! changed file this counts the feedback anchored to it and the tasks whose
+//! diff refs touch it, split by whether they're still open.
+
+use crate::domain::{Feedback, ReviewRun, ReviewTask};
+use crate::infra::diff::cached_diff_index;
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FileReviewStatus {
+    pub path: String,
+    /// Todo or in-progress feedback anchored to the file.
+    pub open_feedback: usize,
+    /// Done or ignored feedback anchored to the file.
+    pub closed_feedback: usize,
+    /// Tasks whose diff refs include the file.
+    pub tasks: usize,
+    /// Of those, the ones marked done or ignored.
+    pub closed_tasks: usize,
+    /// False when only feedback points here; the file isn't in the diff
+    /// (the anchor went stale after a force-push, for example).
+    pub in_diff: bool,
+}
+
+/// Status of every file in `run`'s diff, plus files that only feedback
+/// mentions, sorted by path.
+pub fn compute_file_review_status(
+    run: &ReviewRun,
+    tasks: &[ReviewTask],
+    feedbacks: &[Feedback],
+) -> Result<Vec<FileReviewStatus>> {
+    let mut files: BTreeMap<String, FileReviewStatus> = cached_diff_index(&run.diff_text)?
+        .get_all_file_paths()
+        .into_iter()
+        .map(|path| {
+            let status = FileReviewStatus {
+                path: path.clone(),
+                in_diff: true,
+                ..Default::default()
+            };
+            (path, status)
+        })
+        .collect();
+
+    for task in tasks {
+        // Tasks added by hand may list files without hunk refs.
+        let paths: BTreeSet<&str> = if task.diff_refs.is_empty() {
+            task.files.iter().map(String::as_str).collect()
+        } else {
+            task.diff_refs.iter().map(|r| r.file.as_str()).collect()
+        };
+        for path in paths {
+            let status = entry(&mut files, path);
+            status.tasks += 1;
+            if task.status.is_closed() {
+                status.closed_tasks += 1;
+            }
+        }
+    }
+
+    for feedback in feedbacks {
+        let Some(path) = feedback
+            .anchor
+            .as_ref()
+            .and_then(|anchor| anchor.file_path.as_deref())
+        else {
+            continue;
+        };
+        let status = entry(&mut files, path);
+        if feedback.status.is_closed() {
+            status.closed_feedback += 1;
+        } else {
+            status.open_feedback += 1;
+        }
+    }
+
+    Ok(files.into_values().collect())
+}
+
+fn entry<'a>(
+    files: &'a mut BTreeMap<String, FileReviewStatus>,
+    path: &str,
+) -> &'a mut FileReviewStatus {
+    files
+        .entry(path.to_string())
+        .or_insert_with(|| FileReviewStatus {
+            path: path.to_string(),
+            ..Default::default()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{DiffRef, FeedbackAnchor, FeedbackImpact, ReviewRunStatus, ReviewStatus};
+    use std::sync::Arc;
+
+    const DIFF: &str = "diff --git a/src/a.rs b/src/a.rs\n--- a/src/a.rs\n+++ b/src/a.rs\n@@ -1 +1 @@\n-a\n+b\ndiff --git a/src/b.rs b/src/b.rs\n--- a/src/b.rs\n+++ b/src/b.rs\n@@ -1 +1 @@\n-a\n+b\n";
+
+    fn run() -> ReviewRun {
+        ReviewRun {
+            id: "run".into(),
+            review_id: "rev".into(),
+            agent_id: "agent".into(),
+            input_ref: "input".into(),
+            diff_text: Arc::from(DIFF),
+            diff_hash: "h".into(),
+            status: ReviewRunStatus::Completed,
+            created_at: "now".into(),
+            agent_model: None,
+        }
+    }
+
+    fn task(files: &[&str], status: ReviewStatus) -> ReviewTask {
+        ReviewTask {
+            diff_refs: files
+                .iter()
+                .map(|file| DiffRef {
+                    file: file.to_string(),
+                    hunks: vec![],
+                })
+                .collect(),
+            status,
+            ..Default::default()
+        }
+    }
+
+    fn feedback(path: Option<&str>, status: ReviewStatus) -> Feedback {
+        Feedback {
+            id: "fb".into(),
+            review_id: "rev".into(),
+            task_id: None,
+            rule_id: None,
+            finding_id: None,
+            category: None,
+            title: "Title".into(),
+            status,
+            impact: FeedbackImpact::Nitpick,
+            confidence: 1.0,
+            anchor: path.map(|path| FeedbackAnchor {
+                file_path: Some(path.into()),
+                ..Default::default()
+            }),
+            suggested_replacement: None,
+            extra_anchors: Vec::new(),
+            rationale: None,
+            pinned: false,
+            author: "agent".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        }
+    }
+
+    #[test]
+    fn test_counts_feedback_and_task_coverage_per_file() {
+        let statuses = compute_file_review_status(
+            &run(),
+            &[
+                task(&["src/a.rs", "src/b.rs"], ReviewStatus::Done),
+                task(&["src/a.rs"], ReviewStatus::Todo),
+            ],
+            &[
+                feedback(Some("src/a.rs"), ReviewStatus::Todo),
+                feedback(Some("src/a.rs"), ReviewStatus::Ignored),
+                feedback(Some("src/gone.rs"), ReviewStatus::InProgress),
+                feedback(None, ReviewStatus::Todo),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            statuses,
+            vec![
+                FileReviewStatus {
+                    path: "src/a.rs".into(),
+                    open_feedback: 1,
+                    closed_feedback: 1,
+                    tasks: 2,
+                    closed_tasks: 1,
+                    in_diff: true,
+                },
+                FileReviewStatus {
+                    path: "src/b.rs".into(),
+                    tasks: 1,
+                    closed_tasks: 1,
+                    in_diff: true,
+                    ..Default::default()
+                },
+                FileReviewStatus {
+                    path: "src/gone.rs".into(),
+                    open_feedback: 1,
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+}
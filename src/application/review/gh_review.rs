@@ -0,0 +1,378 @@
+//! GitHub pull request review payloads.
+//!
+//! Builds the JSON body that `POST /repos/{owner}/{repo}/pulls/{number}/reviews`
+//! expects, so a review can be posted with
+//! `gh api --method POST repos/OWNER/REPO/pulls/N/reviews --input review.json` instead of
+//! through LaReview's own push. Inline comments whose line isn't part of the
+//! diff would make GitHub reject the whole review, so they are left out and
+//! reported instead.
+
+use super::export::{ExportData, ExportOptions, ReviewExporter};
+use crate::domain::{FeedbackSide, MergeConfidence, Review, ReviewSource, ReviewTask};
+use crate::infra::diff::cached_diff_index;
+use serde::Serialize;
+
+/// Request body for GitHub's "create a review" endpoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct GhReviewPayload {
+    pub body: String,
+    pub event: String,
+    pub comments: Vec<GhReviewComment>,
+}
+
+/// Inline comment anchored to a line of the PR diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GhReviewComment {
+    pub path: String,
+    pub line: u32,
+    /// `RIGHT` for the new side of the diff, `LEFT` for the old side.
+    pub side: String,
+    pub body: String,
+}
+
+/// A selected task or feedback that couldn't become an inline comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedGhComment {
+    pub id: String,
+    pub title: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GhReviewBundle {
+    pub payload: GhReviewPayload,
+    /// `gh api` endpoint for the review's PR, when the review came from one.
+    pub endpoint: Option<String>,
+    pub skipped: Vec<SkippedGhComment>,
+}
+
+/// Markdown body for the top-level review comment.
+pub fn render_review_body(review: &Review, merge_confidence: Option<&MergeConfidence>) -> String {
+    let mut body = String::new();
+
+    if let Some(confidence) = merge_confidence {
+        body.push_str(&format!(
+            "## Merge Confidence: {:.1}/5 - {}\n\n",
+            confidence.score,
+            confidence.label()
+        ));
+        body.push_str(&format!("*\"{}\"*\n\n", confidence.recommendation()));
+
+        if !confidence.reasons.is_empty() {
+            body.push_str("<details>\n<summary>Assessment</summary>\n\n");
+            for reason in &confidence.reasons {
+                body.push_str(&format!("- {}\n", reason));
+            }
+            body.push('\n');
+            body.push_str("</details>\n\n");
+        }
+        body.push_str("---\n\n");
+    }
+
+    body.push_str(&format!("# Review: {}\n\n", review.title));
+    if let Some(summary) = &review.summary {
+        body.push_str(summary);
+    }
+    body
+}
+
+/// Line a task's comment is anchored to: the start of its first hunk.
+pub fn task_anchor(task: &ReviewTask) -> Option<(String, u32, FeedbackSide)> {
+    let diff_ref = task.diff_refs.first()?;
+    let hunk = diff_ref.hunks.first()?;
+    if hunk.new_lines > 0 {
+        Some((diff_ref.file.clone(), hunk.new_start, FeedbackSide::New))
+    } else {
+        Some((diff_ref.file.clone(), hunk.old_start, FeedbackSide::Old))
+    }
+}
+
+pub fn gh_side(side: FeedbackSide) -> &'static str {
+    match side {
+        FeedbackSide::New => "RIGHT",
+        FeedbackSide::Old => "LEFT",
+    }
+}
+
+/// Builds the review payload for the tasks and feedback selected in `options`.
+pub fn render_gh_review(data: &ExportData, options: &ExportOptions) -> GhReviewBundle {
+    let diff_index = cached_diff_index(&data.run.diff_text).ok();
+    let in_diff = |path: &str, line: u32, side: FeedbackSide| {
+        diff_index
+            .as_ref()
+            .and_then(|index| index.find_position_in_diff(path, line, side))
+            .is_some()
+    };
+
+    let mut comments = Vec::new();
+    let mut skipped = Vec::new();
+    let mut place =
+        |id: &str, title: &str, anchor: Option<(String, u32, FeedbackSide)>, body: String| {
+            let reason = match anchor {
+                None => "No line anchor".to_string(),
+                Some((path, line, side)) if in_diff(&path, line, side) => {
+                    comments.push(GhReviewComment {
+                        path,
+                        line,
+                        side: gh_side(side).to_string(),
+                        body,
+                    });
+                    return;
+                }
+                Some((path, line, _)) => format!("{}:{} is not part of the PR diff", path, line),
+            };
+            skipped.push(SkippedGhComment {
+                id: id.to_string(),
+                title: title.to_string(),
+                reason,
+            });
+        };
+
+    for task in &data.tasks {
+        if options
+            .selected_tasks
+            .as_ref()
+            .is_some_and(|selected| !selected.contains(&task.id))
+        {
+            continue;
+        }
+        place(
+            &task.id,
+            &task.title,
+            task_anchor(task),
+            ReviewExporter::render_task_markdown(task),
+        );
+    }
+
+    for feedback in &data.feedbacks {
+        if options
+            .selected_feedbacks
+            .as_ref()
+            .is_some_and(|selected| !selected.contains(&feedback.id))
+        {
+            continue;
+        }
+        let feedback_comments: Vec<_> = data
+            .comments
+            .iter()
+            .filter(|comment| comment.feedback_id == feedback.id)
+            .cloned()
+            .collect();
+        let anchor = feedback.anchor.as_ref().and_then(|anchor| {
+            Some((
+                anchor.file_path.clone()?,
+                anchor.line_number?,
+                anchor.side.unwrap_or(FeedbackSide::New),
+            ))
+        });
+        place(
+            &feedback.id,
+            &feedback.title,
+            anchor,
+            ReviewExporter::render_single_feedback_markdown(feedback, &feedback_comments, None)
+                + &ReviewExporter::render_rule_markdown(feedback.rule_id.as_deref(), &data.rules),
+        );
+    }
+
+    let endpoint = match &data.review.source {
+        ReviewSource::GitHubPr {
+            owner,
+            repo,
+            number,
+            ..
+        } => Some(format!("repos/{}/{}/pulls/{}/reviews", owner, repo, number)),
+        _ => None,
+    };
+
+    GhReviewBundle {
+        payload: GhReviewPayload {
+            body: render_review_body(&data.review, data.merge_confidence.as_ref()),
+            event: data
+                .review
+                .effective_decision(&data.feedbacks, &data.checklist)
+                .github_event()
+                .to_string(),
+            comments,
+        },
+        endpoint,
+        skipped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        ChecklistItem, DiffRef, Feedback, FeedbackAnchor, FeedbackImpact, HunkRef, ReviewDecision,
+        ReviewRun, ReviewRunStatus, ReviewStatus,
+    };
+    use std::sync::Arc;
+
+    const DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,3 @@\n fn a() {}\n+fn b() {}\n fn c() {}\n";
+
+    fn feedback(id: &str, anchor: Option<FeedbackAnchor>) -> Feedback {
+        Feedback {
+            id: id.into(),
+            review_id: "rev".into(),
+            task_id: None,
+            rule_id: None,
+            finding_id: None,
+            category: None,
+            title: format!("Feedback {id}"),
+            status: ReviewStatus::Todo,
+            impact: FeedbackImpact::Blocking,
+            confidence: 1.0,
+            anchor,
+            suggested_replacement: None,
+            extra_anchors: Vec::new(),
+            rationale: None,
+            pinned: false,
+            author: "agent".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        }
+    }
+
+    fn anchor(line: u32) -> Option<FeedbackAnchor> {
+        Some(FeedbackAnchor {
+            file_path: Some("src/lib.rs".into()),
+            line_number: Some(line),
+            side: Some(FeedbackSide::New),
+            ..Default::default()
+        })
+    }
+
+    fn data() -> ExportData {
+        ExportData {
+            review: Review {
+                id: "rev".into(),
+                title: "Add b".into(),
+                summary: Some("Adds `b`.".into()),
+                source: ReviewSource::GitHubPr {
+                    owner: "octo".into(),
+                    repo: "app".into(),
+                    number: 7,
+                    url: None,
+                    head_sha: None,
+                    base_sha: None,
+                },
+                active_run_id: Some("run".into()),
+                status: ReviewStatus::Todo,
+                created_at: "now".into(),
+                updated_at: "now".into(),
+                tags: vec![],
+                decision: None,
+            },
+            run: ReviewRun {
+                id: "run".into(),
+                review_id: "rev".into(),
+                agent_id: "agent".into(),
+                input_ref: "input".into(),
+                diff_text: Arc::from(DIFF),
+                diff_hash: "h".into(),
+                status: ReviewRunStatus::Completed,
+                created_at: "now".into(),
+                agent_model: None,
+            },
+            tasks: vec![ReviewTask {
+                id: "task-1".into(),
+                title: "Check b".into(),
+                diff_refs: vec![DiffRef {
+                    file: "src/lib.rs".into(),
+                    hunks: vec![HunkRef {
+                        old_start: 1,
+                        old_lines: 2,
+                        new_start: 1,
+                        new_lines: 3,
+                    }],
+                }],
+                ..Default::default()
+            }],
+            feedbacks: vec![
+                feedback("fb-in", anchor(2)),
+                feedback("fb-out", anchor(50)),
+                feedback("fb-none", None),
+            ],
+            comments: vec![],
+            attachments: vec![],
+            rules: vec![],
+            merge_confidence: None,
+            checklist: vec![],
+        }
+    }
+
+    #[test]
+    fn test_comments_outside_the_diff_are_skipped_and_reported() {
+        let bundle = render_gh_review(&data(), &ExportOptions::default());
+
+        let lines: Vec<_> = bundle
+            .payload
+            .comments
+            .iter()
+            .map(|c| (c.path.as_str(), c.line, c.side.as_str()))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![("src/lib.rs", 1, "RIGHT"), ("src/lib.rs", 2, "RIGHT")]
+        );
+
+        let skipped: Vec<_> = bundle.skipped.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(skipped, vec!["fb-out", "fb-none"]);
+        assert!(bundle.skipped[0].reason.contains("src/lib.rs:50"));
+    }
+
+    #[test]
+    fn test_payload_matches_gh_api_shape() {
+        let bundle = render_gh_review(&data(), &ExportOptions::default());
+        assert_eq!(
+            bundle.endpoint.as_deref(),
+            Some("repos/octo/app/pulls/7/reviews")
+        );
+
+        let value = serde_json::to_value(&bundle.payload).unwrap();
+        // The fixture's feedback is open and blocking.
+        assert_eq!(value["event"], "REQUEST_CHANGES");
+        assert!(value["body"].as_str().unwrap().contains("# Review: Add b"));
+        let mut keys: Vec<_> = value["comments"][0]
+            .as_object()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        keys.sort();
+        assert_eq!(keys, vec!["body", "line", "path", "side"]);
+    }
+
+    #[test]
+    fn test_only_selected_items_are_included() {
+        let options = ExportOptions {
+            selected_tasks: Some(Default::default()),
+            selected_feedbacks: Some(["fb-in".to_string()].into_iter().collect()),
+            ..Default::default()
+        };
+        let bundle = render_gh_review(&data(), &options);
+        assert_eq!(bundle.payload.comments.len(), 1);
+        assert_eq!(bundle.payload.comments[0].line, 2);
+        assert!(bundle.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_event_follows_review_decision() {
+        let mut data = data();
+        for feedback in &mut data.feedbacks {
+            feedback.status = ReviewStatus::Done;
+        }
+        let event = |data: &ExportData| {
+            render_gh_review(data, &ExportOptions::default())
+                .payload
+                .event
+        };
+        assert_eq!(event(&data), "COMMENT");
+
+        data.checklist = vec![ChecklistItem::new("rev", "Get security sign-off").unwrap()];
+        assert_eq!(event(&data), "REQUEST_CHANGES");
+
+        data.review.decision = Some(ReviewDecision::Approve);
+        assert_eq!(event(&data), "APPROVE");
+    }
+}
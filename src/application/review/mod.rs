@@ -1,2 +1,14 @@
+pub mod conflicts;
 pub mod export;
+pub mod file_status;
+pub mod gh_review;
+pub mod pdf;
+pub mod remote_anchors;
+pub mod risk;
 pub mod rules;
+pub mod search;
+pub mod stats;
+pub mod summary_line;
+pub mod task_diagram;
+pub mod tool_calls;
+pub mod viewed;
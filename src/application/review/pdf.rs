@@ -0,0 +1,705 @@
+//! PDF export for sharing a review outside the app.
+//!
+//! The review is first laid out as styled lines grouped into blocks, then
+//! paginated, then drawn with the PDF builtin fonts. Keeping layout separate
+//! from drawing lets the pagination rules be tested without parsing a PDF.
+//!
+//! Builtin fonts only cover Latin-1, so text is reduced to printable ASCII
+//! before it is laid out. Diagrams are included as their source, since there
+//! is no renderer for them outside the webview.
+
+use super::export::{ExportData, ExportOptions};
+use crate::domain::{Comment, Feedback, FeedbackImpact, FeedbackSide, ReviewStatus, RiskLevel};
+use crate::infra::diff::cached_diff_index;
+use crate::infra::diff::index::DiffIndex;
+use anyhow::{Result, anyhow};
+use printpdf::{BuiltinFont, Color, IndirectFontRef, Mm, PdfDocument, Rgb};
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN_X: f32 = 18.0;
+const MARGIN_BOTTOM: f32 = 18.0;
+const HEADER_BASELINE: f32 = PAGE_HEIGHT - 12.0;
+const CONTENT_TOP: f32 = PAGE_HEIGHT - 24.0;
+/// Vertical space available for content on every page, in millimetres.
+const CONTENT_HEIGHT: f32 = CONTENT_TOP - MARGIN_BOTTOM;
+
+const CONTINUED_MARKER: &str = "(continued)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineStyle {
+    Title,
+    Heading,
+    Subheading,
+    Strong,
+    Body,
+    Meta,
+    Code,
+    Added,
+    Removed,
+    Spacer,
+}
+
+impl LineStyle {
+    fn font_size(self) -> f32 {
+        match self {
+            Self::Title => 16.0,
+            Self::Heading => 13.0,
+            Self::Subheading => 11.0,
+            Self::Strong | Self::Body => 10.0,
+            Self::Meta => 9.0,
+            Self::Code | Self::Added | Self::Removed | Self::Spacer => 8.0,
+        }
+    }
+
+    /// Height of one line in millimetres.
+    pub fn line_height(self) -> f32 {
+        match self {
+            Self::Title => 9.0,
+            Self::Heading => 8.0,
+            Self::Subheading => 6.5,
+            Self::Strong | Self::Body => 5.0,
+            Self::Meta => 4.5,
+            Self::Code | Self::Added | Self::Removed => 3.8,
+            Self::Spacer => 3.0,
+        }
+    }
+
+    /// Characters that fit on one line, estimated from the average glyph
+    /// width of the font.
+    fn wrap_width(self) -> usize {
+        match self {
+            Self::Title => 55,
+            Self::Heading => 68,
+            Self::Subheading => 80,
+            Self::Strong => 85,
+            Self::Body => 92,
+            Self::Meta => 105,
+            Self::Code | Self::Added | Self::Removed | Self::Spacer => 100,
+        }
+    }
+
+    fn is_code(self) -> bool {
+        matches!(self, Self::Code | Self::Added | Self::Removed)
+    }
+
+    fn color(self) -> (f32, f32, f32) {
+        match self {
+            Self::Added => (0.1, 0.45, 0.15),
+            Self::Removed => (0.7, 0.12, 0.12),
+            Self::Meta => (0.42, 0.42, 0.42),
+            _ => (0.0, 0.0, 0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfLine {
+    pub text: String,
+    pub style: LineStyle,
+}
+
+impl PdfLine {
+    fn new(text: impl Into<String>, style: LineStyle) -> Self {
+        Self {
+            text: text.into(),
+            style,
+        }
+    }
+}
+
+/// Lines that are paginated as a unit.
+#[derive(Debug, Clone, Default)]
+pub struct Block {
+    pub lines: Vec<PdfLine>,
+    /// Start the block on a fresh page rather than split it, when it fits on
+    /// one. Used for diff snippets and diagrams.
+    pub keep_together: bool,
+}
+
+impl Block {
+    fn height(&self) -> f32 {
+        self.lines.iter().map(|line| line.style.line_height()).sum()
+    }
+}
+
+/// Builds blocks of wrapped lines for the whole review.
+#[derive(Default)]
+struct Layout {
+    blocks: Vec<Block>,
+}
+
+impl Layout {
+    fn text(&mut self, text: &str, style: LineStyle) {
+        let mut block = Block::default();
+        for line in text.lines() {
+            if line.trim().is_empty() {
+                block.lines.push(PdfLine::new("", LineStyle::Spacer));
+                continue;
+            }
+            for wrapped in wrap(&sanitize(line), style.wrap_width(), style.is_code()) {
+                block.lines.push(PdfLine::new(wrapped, style));
+            }
+        }
+        self.blocks.push(block);
+    }
+
+    fn spacer(&mut self) {
+        self.blocks.push(Block {
+            lines: vec![PdfLine::new("", LineStyle::Spacer)],
+            keep_together: false,
+        });
+    }
+
+    fn code(&mut self, label: &str, code: &str, diff: bool) {
+        let mut block = Block {
+            lines: vec![PdfLine::new(label, LineStyle::Meta)],
+            keep_together: true,
+        };
+        for line in code.lines() {
+            let style = match line.chars().next() {
+                Some('+') if diff => LineStyle::Added,
+                Some('-') if diff => LineStyle::Removed,
+                _ => LineStyle::Code,
+            };
+            for wrapped in wrap(&sanitize(line), style.wrap_width(), true) {
+                block.lines.push(PdfLine::new(wrapped, style));
+            }
+        }
+        self.blocks.push(block);
+    }
+}
+
+/// Lays out the review as blocks, honouring the same selection and section
+/// options as the markdown export.
+pub fn layout_review(data: &ExportData, options: &ExportOptions) -> Vec<Block> {
+    let mut layout = Layout::default();
+    let diff_index = if options.include_context_diff {
+        cached_diff_index(&data.run.diff_text).ok()
+    } else {
+        None
+    };
+
+    layout.text(&data.review.title, LineStyle::Title);
+    if options.include_metadata {
+        layout.text(
+            &format!(
+                "Created {} by {}",
+                display_date(&data.review.created_at),
+                data.run.agent_id
+            ),
+            LineStyle::Meta,
+        );
+    }
+    layout.spacer();
+
+    if options.include_stats {
+        layout.text("Overview", LineStyle::Heading);
+        if let Some(confidence) = &data.merge_confidence {
+            layout.text(
+                &format!(
+                    "Merge confidence: {:.1}/5 - {}",
+                    confidence.score,
+                    confidence.label()
+                ),
+                LineStyle::Strong,
+            );
+            layout.text(
+                &format!("\"{}\"", confidence.recommendation()),
+                LineStyle::Body,
+            );
+            for reason in &confidence.reasons {
+                layout.text(&format!("- {}", reason), LineStyle::Body);
+            }
+        }
+        let count = |risk: RiskLevel| data.tasks.iter().filter(|t| t.stats.risk == risk).count();
+        layout.text(
+            &format!(
+                "{} tasks: {} high risk, {} medium risk, {} low risk",
+                data.tasks.len(),
+                count(RiskLevel::High),
+                count(RiskLevel::Medium),
+                count(RiskLevel::Low)
+            ),
+            LineStyle::Body,
+        );
+        layout.spacer();
+    }
+
+    if options.include_summary
+        && let Some(summary) = &data.review.summary
+    {
+        layout.text("Summary", LineStyle::Heading);
+        layout.text(summary, LineStyle::Body);
+        layout.spacer();
+    }
+
+    if !data.checklist.is_empty() {
+        layout.text("Checklist", LineStyle::Heading);
+        for item in &data.checklist {
+            layout.text(
+                &format!("[{}] {}", if item.done { "x" } else { " " }, item.text),
+                LineStyle::Body,
+            );
+        }
+        layout.spacer();
+    }
+
+    let feedback_selected = |feedback: &Feedback| {
+        options.include_feedbacks
+            && options
+                .selected_feedbacks
+                .as_ref()
+                .is_none_or(|selected| selected.contains(&feedback.id))
+    };
+    let mut rendered_feedback = std::collections::HashSet::new();
+
+    if options.include_tasks {
+        let tasks: Vec<_> = data
+            .tasks
+            .iter()
+            .filter(|task| {
+                options
+                    .selected_tasks
+                    .as_ref()
+                    .is_none_or(|selected| selected.contains(&task.id))
+            })
+            .collect();
+        if !tasks.is_empty() {
+            layout.text("Tasks", LineStyle::Heading);
+        }
+        for task in tasks {
+            layout.text(&task.title, LineStyle::Subheading);
+            layout.text(
+                &format!(
+                    "Risk: {} - Status: {}",
+                    task.stats.risk,
+                    status_word(task.status)
+                ),
+                LineStyle::Meta,
+            );
+            if !task.files.is_empty() {
+                layout.text(
+                    &format!("Files: {}", task.files.join(", ")),
+                    LineStyle::Meta,
+                );
+            }
+            layout.text(&task.description, LineStyle::Body);
+            if let Some(insight) = &task.insight {
+                layout.text(&format!("Insight: {}", insight), LineStyle::Body);
+            }
+            if let Some(diagram) = &task.diagram {
+                layout.code("Diagram source:", diagram, false);
+            }
+            for feedback in data
+                .feedbacks
+                .iter()
+                .filter(|f| f.task_id.as_ref() == Some(&task.id) && feedback_selected(f))
+            {
+                layout_feedback(&mut layout, data, feedback, diff_index.as_deref());
+                rendered_feedback.insert(feedback.id.clone());
+            }
+            layout.spacer();
+        }
+    }
+
+    let remaining: Vec<_> = data
+        .feedbacks
+        .iter()
+        .filter(|f| !rendered_feedback.contains(&f.id) && feedback_selected(f))
+        .collect();
+    if !remaining.is_empty() {
+        layout.text("Feedback", LineStyle::Heading);
+        for feedback in remaining {
+            layout_feedback(&mut layout, data, feedback, diff_index.as_deref());
+        }
+    }
+
+    layout.blocks
+}
+
+fn layout_feedback(
+    layout: &mut Layout,
+    data: &ExportData,
+    feedback: &Feedback,
+    diff_index: Option<&DiffIndex>,
+) {
+    layout.spacer();
+    let severity = match feedback.impact {
+        FeedbackImpact::Blocking => "Blocking",
+        FeedbackImpact::NiceToHave => "Nice to have",
+        FeedbackImpact::Nitpick => "Nitpick",
+    };
+    layout.text(
+        &format!("[{}] {}", severity, feedback.title),
+        LineStyle::Strong,
+    );
+
+    let location = feedback.anchor.as_ref().and_then(|anchor| {
+        Some((
+            anchor.file_path.clone()?,
+            anchor.line_number?,
+            anchor.side.unwrap_or(FeedbackSide::New),
+        ))
+    });
+    if let Some((path, line, side)) = &location {
+        layout.text(&format!("{}:{}", path, line), LineStyle::Meta);
+        if let Some(indexed) =
+            diff_index.and_then(|index| index.find_hunk_at_line(path, *line, *side))
+        {
+            let snippet = DiffIndex::render_hunk_unified(&indexed.hunk, indexed.coords);
+            layout.code("Context:", &snippet, true);
+        }
+    }
+
+    if let Some(rule) = feedback
+        .rule_id
+        .as_ref()
+        .and_then(|id| data.rules.iter().find(|rule| &rule.id == id))
+    {
+        layout.text(
+            &format!("Triggered by rule: {}", rule.text),
+            LineStyle::Meta,
+        );
+    }
+
+    let comments: Vec<&Comment> = data
+        .comments
+        .iter()
+        .filter(|comment| comment.feedback_id == feedback.id)
+        .collect();
+    for comment in comments {
+        let author = match comment.author.strip_prefix("agent:") {
+            Some(agent) => format!("Agent {}", agent),
+            None => comment.author.clone(),
+        };
+        layout.text(&format!("{}:", author), LineStyle::Strong);
+        layout.text(&comment.body, LineStyle::Body);
+    }
+}
+
+/// Splits blocks into pages of at most `page_height` millimetres.
+///
+/// A `keep_together` block that doesn't fit in the space left moves to the
+/// next page; one taller than a whole page is split, and each page it
+/// continues onto starts with a "(continued)" marker. Spacers are dropped at
+/// the top of a page.
+pub fn paginate(blocks: &[Block], page_height: f32) -> Vec<Vec<PdfLine>> {
+    let mut pages = vec![Vec::new()];
+    let mut used = 0.0;
+
+    for block in blocks {
+        let height = block.height();
+        if block.keep_together && used > 0.0 && used + height > page_height && height <= page_height
+        {
+            pages.push(Vec::new());
+            used = 0.0;
+        }
+
+        let mut block_started = false;
+        for line in &block.lines {
+            let line_height = line.style.line_height();
+            if used > 0.0 && used + line_height > page_height {
+                pages.push(Vec::new());
+                used = 0.0;
+                if block.keep_together && block_started {
+                    let marker = PdfLine::new(CONTINUED_MARKER, LineStyle::Meta);
+                    used += marker.style.line_height();
+                    pages.last_mut().unwrap().push(marker);
+                }
+            }
+            if used == 0.0 && line.style == LineStyle::Spacer {
+                continue;
+            }
+            used += line_height;
+            pages.last_mut().unwrap().push(line.clone());
+            block_started = true;
+        }
+    }
+
+    if pages.len() > 1 && pages.last().is_some_and(|page| page.is_empty()) {
+        pages.pop();
+    }
+    pages
+}
+
+/// Renders the review to PDF bytes.
+pub fn render_pdf(data: &ExportData, options: &ExportOptions) -> Result<Vec<u8>> {
+    let pages = paginate(&layout_review(data, options), CONTENT_HEIGHT);
+    let title = sanitize(&data.review.title);
+    let date = display_date(&data.review.created_at);
+
+    let (doc, first_page, first_layer) =
+        PdfDocument::new(&title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Content");
+    let font = |font: BuiltinFont| {
+        doc.add_builtin_font(font)
+            .map_err(|e| anyhow!("Failed to load PDF font: {:?}", e))
+    };
+    let fonts = Fonts {
+        regular: font(BuiltinFont::Helvetica)?,
+        bold: font(BuiltinFont::HelveticaBold)?,
+        italic: font(BuiltinFont::HelveticaOblique)?,
+        mono: font(BuiltinFont::Courier)?,
+    };
+    let set_color = |layer: &printpdf::PdfLayerReference, (r, g, b): (f32, f32, f32)| {
+        layer.set_fill_color(Color::Rgb(Rgb::new(r, g, b, None)));
+    };
+
+    let total = pages.len();
+    for (number, lines) in pages.iter().enumerate() {
+        let layer = if number == 0 {
+            doc.get_page(first_page).get_layer(first_layer)
+        } else {
+            let (page, layer) = doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Content");
+            doc.get_page(page).get_layer(layer)
+        };
+
+        set_color(&layer, LineStyle::Meta.color());
+        let header = truncate(&title, 70);
+        layer.use_text(
+            header,
+            8.0,
+            Mm(MARGIN_X),
+            Mm(HEADER_BASELINE),
+            &fonts.italic,
+        );
+        layer.use_text(
+            format!("{}  -  Page {} of {}", date, number + 1, total),
+            8.0,
+            Mm(PAGE_WIDTH - MARGIN_X - 52.0),
+            Mm(HEADER_BASELINE),
+            &fonts.italic,
+        );
+
+        let mut y = CONTENT_TOP;
+        for line in lines {
+            y -= line.style.line_height();
+            if line.style == LineStyle::Spacer || line.text.is_empty() {
+                continue;
+            }
+            set_color(&layer, line.style.color());
+            layer.use_text(
+                line.text.clone(),
+                line.style.font_size(),
+                Mm(MARGIN_X),
+                Mm(y),
+                fonts.for_style(line.style),
+            );
+        }
+    }
+
+    doc.save_to_bytes()
+        .map_err(|e| anyhow!("Failed to write PDF: {:?}", e))
+}
+
+struct Fonts {
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    italic: IndirectFontRef,
+    mono: IndirectFontRef,
+}
+
+impl Fonts {
+    fn for_style(&self, style: LineStyle) -> &IndirectFontRef {
+        match style {
+            LineStyle::Title | LineStyle::Heading | LineStyle::Subheading | LineStyle::Strong => {
+                &self.bold
+            }
+            LineStyle::Meta => &self.italic,
+            LineStyle::Code | LineStyle::Added | LineStyle::Removed => &self.mono,
+            LineStyle::Body | LineStyle::Spacer => &self.regular,
+        }
+    }
+}
+
+fn status_word(status: ReviewStatus) -> &'static str {
+    match status {
+        ReviewStatus::Todo => "Todo",
+        ReviewStatus::InProgress => "In progress",
+        ReviewStatus::Done => "Done",
+        ReviewStatus::Ignored => "Ignored",
+    }
+}
+
+/// `YYYY-MM-DD` for RFC 3339 timestamps, the raw value otherwise.
+fn display_date(timestamp: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(timestamp)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|_| timestamp.to_string())
+}
+
+/// Reduces `text` to printable ASCII, which every builtin font can encode.
+fn sanitize(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\t' => out.push_str("    "),
+            ' '..='~' => out.push(c),
+            '\u{2018}' | '\u{2019}' => out.push('\''),
+            '\u{201C}' | '\u{201D}' => out.push('"'),
+            '\u{2013}' | '\u{2014}' => out.push('-'),
+            '\u{2026}' => out.push_str("..."),
+            '\u{2022}' => out.push('*'),
+            '\u{2192}' => out.push_str("->"),
+            '\u{00A0}' => out.push(' '),
+            c if c.is_control() => {}
+            _ => out.push('?'),
+        }
+    }
+    out
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        let mut truncated: String = text.chars().take(max - 3).collect();
+        truncated.push_str("...");
+        truncated
+    }
+}
+
+/// Wraps `text` to `width` characters. Prose breaks at spaces; code keeps its
+/// indentation and is cut at the exact width.
+fn wrap(text: &str, width: usize, hard: bool) -> Vec<String> {
+    if text.chars().count() <= width {
+        return vec![text.to_string()];
+    }
+    if hard {
+        let chars: Vec<char> = text.chars().collect();
+        return chars
+            .chunks(width)
+            .map(|chunk| chunk.iter().collect())
+            .collect();
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let needed = if current.is_empty() {
+            word.len()
+        } else {
+            current.len() + 1 + word.len()
+        };
+        if needed > width && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        if word.len() > width {
+            let chars: Vec<char> = word.chars().collect();
+            let mut chunks = chars.chunks(width).map(|c| c.iter().collect::<String>());
+            let last = chunks.next_back().unwrap_or_default();
+            lines.extend(chunks);
+            current = last;
+            continue;
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(style: LineStyle, count: usize, keep_together: bool) -> Block {
+        Block {
+            lines: (0..count)
+                .map(|i| PdfLine::new(format!("line {i}"), style))
+                .collect(),
+            keep_together,
+        }
+    }
+
+    #[test]
+    fn test_wrap_breaks_prose_at_spaces_and_code_at_width() {
+        assert_eq!(
+            wrap("alpha beta gamma delta", 11, false),
+            vec!["alpha beta", "gamma delta"]
+        );
+        assert_eq!(
+            wrap("+    let x = 1;", 6, true),
+            vec!["+    l", "et x =", " 1;"]
+        );
+        assert_eq!(wrap("short", 10, false), vec!["short"]);
+    }
+
+    #[test]
+    fn test_sanitize_keeps_text_encodable() {
+        assert_eq!(
+            sanitize("Don\u{2019}t \u{2014} ok\u{2026}"),
+            "Don't - ok..."
+        );
+        assert_eq!(sanitize("\u{1F534} Blocking\tx"), "? Blocking    x");
+    }
+
+    #[test]
+    fn test_snippet_moves_to_next_page_when_it_fits_there() {
+        let body = LineStyle::Body.line_height();
+        let code = LineStyle::Code.line_height();
+        // Leaves room for three code lines on the first page.
+        let page = body * 4.0 + code * 3.5;
+        let blocks = vec![
+            block(LineStyle::Body, 4, false),
+            block(LineStyle::Code, 5, true),
+        ];
+
+        let pages = paginate(&blocks, page);
+
+        assert_eq!(pages.len(), 2);
+        assert!(pages[0].iter().all(|l| l.style == LineStyle::Body));
+        assert_eq!(pages[1].len(), 5);
+        assert!(pages[1].iter().all(|l| l.style == LineStyle::Code));
+    }
+
+    #[test]
+    fn test_long_snippet_is_split_with_continued_marker() {
+        let code = LineStyle::Code.line_height();
+        let page = code * 10.0 + 0.01;
+        let blocks = vec![block(LineStyle::Code, 25, true)];
+
+        let pages = paginate(&blocks, page);
+
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[0].len(), 10);
+        assert_eq!(pages[1][0].text, CONTINUED_MARKER);
+        assert_eq!(pages[1][1].text, "line 10");
+        let lines: usize = pages
+            .iter()
+            .flatten()
+            .filter(|l| l.style == LineStyle::Code)
+            .count();
+        assert_eq!(lines, 25);
+        for page_lines in &pages {
+            let height: f32 = page_lines.iter().map(|l| l.style.line_height()).sum();
+            assert!(height <= page);
+        }
+    }
+
+    #[test]
+    fn test_prose_splits_without_marker_and_drops_leading_spacers() {
+        let body = LineStyle::Body.line_height();
+        let blocks = vec![
+            block(LineStyle::Body, 3, false),
+            block(LineStyle::Spacer, 1, false),
+            block(LineStyle::Body, 2, false),
+        ];
+
+        let pages = paginate(&blocks, body * 3.0);
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[1].len(), 2);
+        assert!(pages[1].iter().all(|l| l.style == LineStyle::Body));
+    }
+
+    #[test]
+    fn test_display_date() {
+        assert_eq!(display_date("2024-05-01T10:00:00+00:00"), "2024-05-01");
+        assert_eq!(display_date("now"), "now");
+    }
+}
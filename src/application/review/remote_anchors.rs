@@ -0,0 +1,294 @@
+//! Fitting feedback anchors onto a pull request's current diff before a push.
+//!
+//! Feedback is anchored on the diff the review was generated from. If the pull
+//! request gained commits or was force-pushed since, the same line can sit at
+//! another position in the provider's diff, or be gone. Anchors are re-resolved
+//! against the fetched diff by content (see [`reanchor`]); feedback whose line
+//! can't be found is posted in the review summary instead of inline, and
+//! further locations whose line is gone keep only their file.
+
+use super::export::ReviewExporter;
+use crate::domain::{Comment, Feedback, FeedbackAnchor, FeedbackSide};
+use crate::infra::diff::index::DiffIndex;
+use crate::infra::diff::reanchor::{Reanchored, reanchor};
+use crate::infra::hash::hash_diff;
+use anyhow::Result;
+use serde::Serialize;
+
+/// What [`fit_to_remote`] changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RemoteAnchoring {
+    /// Feedback with a location that now sits elsewhere in the remote diff.
+    pub moved: usize,
+    /// Feedback whose line couldn't be found in the remote diff.
+    pub unplaced: Vec<String>,
+    /// Further locations (`extra_anchors`) whose line couldn't be found;
+    /// they keep their file but lose the line.
+    pub unplaced_locations: usize,
+}
+
+/// Re-anchors every location of the `selected` feedback, placed on
+/// `run_diff` (hashed as `run_hash`), onto `remote_diff`. Anchors are updated
+/// in place.
+pub fn fit_to_remote(
+    run_diff: &str,
+    run_hash: &str,
+    remote_diff: &str,
+    feedbacks: &mut [Feedback],
+    selected: &[String],
+) -> Result<RemoteAnchoring> {
+    let mut outcome = RemoteAnchoring::default();
+    let remote_hash = hash_diff(remote_diff);
+    if remote_hash == run_hash {
+        return Ok(outcome);
+    }
+    let local = DiffIndex::new(run_diff)?;
+    let remote = DiffIndex::new(remote_diff)?;
+    let fit = |anchor: &FeedbackAnchor| fit_anchor(anchor, run_hash, &remote_hash, &local, &remote);
+
+    for feedback in feedbacks
+        .iter_mut()
+        .filter(|feedback| selected.contains(&feedback.id))
+    {
+        let mut moved = false;
+        if let Some(anchor) = feedback.anchor.as_ref().filter(|anchor| on_a_line(anchor)) {
+            match fit(anchor) {
+                Some(fitted) => {
+                    moved |= fitted.line_number != anchor.line_number;
+                    feedback.anchor = Some(fitted);
+                }
+                None => outcome.unplaced.push(feedback.id.clone()),
+            }
+        }
+        for anchor in feedback
+            .extra_anchors
+            .iter_mut()
+            .filter(|anchor| on_a_line(anchor))
+        {
+            match fit(anchor) {
+                Some(fitted) => {
+                    moved |= fitted.line_number != anchor.line_number;
+                    *anchor = fitted;
+                }
+                None => {
+                    anchor.line_number = None;
+                    anchor.hunk_ref = None;
+                    outcome.unplaced_locations += 1;
+                }
+            }
+        }
+        if moved {
+            outcome.moved += 1;
+        }
+    }
+    Ok(outcome)
+}
+
+fn on_a_line(anchor: &FeedbackAnchor) -> bool {
+    anchor.file_path.is_some() && anchor.line_number.is_some()
+}
+
+/// `anchor` moved onto its line in `remote`, or None when that line isn't
+/// part of the remote diff any more.
+fn fit_anchor(
+    anchor: &FeedbackAnchor,
+    run_hash: &str,
+    remote_hash: &str,
+    local: &DiffIndex,
+    remote: &DiffIndex,
+) -> Option<FeedbackAnchor> {
+    // Pushed feedback belongs to the active run, whose diff this is.
+    let mut anchor = anchor.clone();
+    anchor.diff_hash = Some(run_hash.to_string());
+    if anchor.side.is_none() {
+        anchor.side = Some(FeedbackSide::New);
+    }
+
+    let fitted = match reanchor(&anchor, remote_hash, remote, &[(run_hash, local)]) {
+        Reanchored::Unchanged => anchor,
+        Reanchored::Moved(moved) => moved,
+        Reanchored::Outdated => return None,
+    };
+    let (Some(path), Some(line), Some(side)) =
+        (fitted.file_path.as_deref(), fitted.line_number, fitted.side)
+    else {
+        return None;
+    };
+    (!fitted.outdated && remote.find_position_in_diff(path, line, side).is_some()).then_some(fitted)
+}
+
+/// Summary section listing the `unplaced` feedback, with where it was
+/// anchored on the reviewed diff.
+pub fn unplaced_section(
+    feedbacks: &[Feedback],
+    comments: &[Comment],
+    unplaced: &[String],
+) -> String {
+    let mut section = String::from("\n\n## Comments on lines that changed since the review\n");
+    for feedback in feedbacks.iter().filter(|f| unplaced.contains(&f.id)) {
+        if let Some(anchor) = &feedback.anchor
+            && let (Some(path), Some(line)) = (&anchor.file_path, anchor.line_number)
+        {
+            section.push_str(&format!("\n`{path}:{line}`\n\n"));
+        }
+        let thread: Vec<_> = comments
+            .iter()
+            .filter(|c| c.feedback_id == feedback.id)
+            .cloned()
+            .collect();
+        section.push_str(&ReviewExporter::render_single_feedback_markdown(
+            feedback, &thread, None,
+        ));
+    }
+    section
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{FeedbackImpact, ReviewStatus};
+
+    const REVIEWED: &str = "diff --git a/src/app.rs b/src/app.rs\n--- a/src/app.rs\n+++ b/src/app.rs\n@@ -1,4 +1,5 @@\n fn setup() {\n     let config = load();\n+    validate(&config);\n     run(config);\n }\n";
+
+    /// The PR after a force-push that added a line above the reviewed one.
+    const FORCE_PUSHED: &str = "diff --git a/src/app.rs b/src/app.rs\n--- a/src/app.rs\n+++ b/src/app.rs\n@@ -1,4 +1,6 @@\n fn setup() {\n+    init_logging();\n     let config = load();\n+    validate(&config);\n     run(config);\n }\n";
+
+    /// The PR after a force-push that dropped the reviewed line.
+    const LINE_GONE: &str = "diff --git a/src/app.rs b/src/app.rs\n--- a/src/app.rs\n+++ b/src/app.rs\n@@ -1,4 +1,5 @@\n fn setup() {\n+    init_logging();\n     let config = load();\n     run(config);\n }\n";
+
+    fn feedback(id: &str, line: u32) -> Feedback {
+        Feedback {
+            id: id.into(),
+            review_id: "rev".into(),
+            task_id: None,
+            rule_id: None,
+            finding_id: None,
+            category: None,
+            title: format!("Finding {id}"),
+            status: ReviewStatus::Todo,
+            impact: FeedbackImpact::Nitpick,
+            confidence: 1.0,
+            anchor: Some(FeedbackAnchor {
+                file_path: Some("src/app.rs".into()),
+                line_number: Some(line),
+                side: Some(FeedbackSide::New),
+                ..Default::default()
+            }),
+            suggested_replacement: None,
+            extra_anchors: Vec::new(),
+            rationale: None,
+            pinned: false,
+            author: "me".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        }
+    }
+
+    fn line_of(feedback: &Feedback) -> Option<u32> {
+        feedback.anchor.as_ref().and_then(|a| a.line_number)
+    }
+
+    #[test]
+    fn test_follows_lines_that_moved() {
+        let mut feedbacks = vec![feedback("fb", 3)];
+        let selected = vec!["fb".to_string()];
+        let outcome = fit_to_remote(
+            REVIEWED,
+            &hash_diff(REVIEWED),
+            FORCE_PUSHED,
+            &mut feedbacks,
+            &selected,
+        )
+        .unwrap();
+        assert_eq!(outcome.moved, 1);
+        assert!(outcome.unplaced.is_empty());
+        assert_eq!(line_of(&feedbacks[0]), Some(4));
+    }
+
+    #[test]
+    fn test_reports_lines_that_are_gone() {
+        let mut feedbacks = vec![feedback("fb", 3), feedback("other", 3)];
+        let selected = vec!["fb".to_string()];
+        let outcome = fit_to_remote(
+            REVIEWED,
+            &hash_diff(REVIEWED),
+            LINE_GONE,
+            &mut feedbacks,
+            &selected,
+        )
+        .unwrap();
+        assert_eq!(outcome.unplaced, vec!["fb".to_string()]);
+        // Unselected feedback isn't touched.
+        assert_eq!(line_of(&feedbacks[1]), Some(3));
+
+        let section = unplaced_section(&feedbacks, &[], &outcome.unplaced);
+        assert!(section.contains("`src/app.rs:3`"));
+        assert!(section.contains("Finding fb"));
+        assert!(!section.contains("Finding other"));
+    }
+
+    /// Feedback on line 3 that also applies to `extra_lines`.
+    fn spanning(extra_lines: &[u32]) -> Feedback {
+        let mut fb = feedback("fb", 3);
+        fb.extra_anchors = extra_lines
+            .iter()
+            .map(|&line| FeedbackAnchor {
+                file_path: Some("src/app.rs".into()),
+                line_number: Some(line),
+                side: Some(FeedbackSide::New),
+                ..Default::default()
+            })
+            .collect();
+        fb
+    }
+
+    #[test]
+    fn test_extra_locations_follow_their_lines() {
+        let mut feedbacks = vec![spanning(&[4])];
+        let outcome = fit_to_remote(
+            REVIEWED,
+            &hash_diff(REVIEWED),
+            FORCE_PUSHED,
+            &mut feedbacks,
+            &["fb".to_string()],
+        )
+        .unwrap();
+        assert_eq!(outcome.moved, 1);
+        assert_eq!(outcome.unplaced_locations, 0);
+        let lines: Vec<_> = feedbacks[0].anchors().map(|a| a.line_number).collect();
+        assert_eq!(lines, [Some(4), Some(5)]);
+    }
+
+    #[test]
+    fn test_extra_locations_that_are_gone_keep_their_file() {
+        let mut feedbacks = vec![spanning(&[3, 4])];
+        let outcome = fit_to_remote(
+            REVIEWED,
+            &hash_diff(REVIEWED),
+            LINE_GONE,
+            &mut feedbacks,
+            &["fb".to_string()],
+        )
+        .unwrap();
+        assert_eq!(outcome.unplaced, vec!["fb".to_string()]);
+        assert_eq!(outcome.unplaced_locations, 1);
+        let extras = &feedbacks[0].extra_anchors;
+        assert_eq!(extras[0].file_path.as_deref(), Some("src/app.rs"));
+        assert_eq!(extras[0].line_number, None);
+        assert_eq!(extras[1].line_number, Some(4));
+    }
+
+    #[test]
+    fn test_same_diff_is_left_alone() {
+        let mut feedbacks = vec![feedback("fb", 3)];
+        let outcome = fit_to_remote(
+            REVIEWED,
+            &hash_diff(REVIEWED),
+            REVIEWED,
+            &mut feedbacks,
+            &["fb".to_string()],
+        )
+        .unwrap();
+        assert_eq!(outcome, RemoteAnchoring::default());
+    }
+}
@@ -0,0 +1,174 @@
+//! Overall risk of a review, for the badge in the review header.
+//!
+//! By default it's the highest task risk. Teams that weigh risk differently
+//! can set `[risk_policy]` in the config: tasks touching sensitive paths count
+//! one level higher, and enough Medium tasks add up to High.
+
+use crate::domain::{ReviewTask, RiskLevel};
+use crate::infra::app_config::{RiskPolicyConfig, load_config};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Default)]
+pub struct RiskPolicy {
+    sensitive_paths: Option<GlobSet>,
+    medium_tasks_for_high: Option<usize>,
+}
+
+impl RiskPolicy {
+    pub fn new(config: &RiskPolicyConfig) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        let mut has_globs = false;
+        for pattern in config
+            .sensitive_paths
+            .iter()
+            .map(|pattern| pattern.trim())
+            .filter(|pattern| !pattern.is_empty())
+        {
+            match GlobBuilder::new(pattern).literal_separator(true).build() {
+                Ok(glob) => {
+                    builder.add(glob);
+                    has_globs = true;
+                }
+                Err(err) => log::warn!("Skipping invalid sensitive path {}: {}", pattern, err),
+            }
+        }
+        let sensitive_paths = if has_globs {
+            builder
+                .build()
+                .map_err(|err| log::warn!("Ignoring sensitive paths: {}", err))
+                .ok()
+        } else {
+            None
+        };
+
+        Self {
+            sensitive_paths,
+            medium_tasks_for_high: config.medium_tasks_for_high.filter(|count| *count > 0),
+        }
+    }
+
+    /// The policy in the app config, or the max-based default.
+    pub fn configured() -> Self {
+        load_config()
+            .risk_policy
+            .map(|config| Self::new(&config))
+            .unwrap_or_default()
+    }
+
+    /// `task`'s risk, raised a level when it touches a sensitive path.
+    pub fn task_risk(&self, task: &ReviewTask) -> RiskLevel {
+        let risk = task.stats.risk;
+        let Some(sensitive) = &self.sensitive_paths else {
+            return risk;
+        };
+        // Tasks added by hand may list files without hunk refs.
+        let mut paths: BTreeSet<&str> = task.diff_refs.iter().map(|r| r.file.as_str()).collect();
+        if paths.is_empty() {
+            paths = task.files.iter().map(String::as_str).collect();
+        }
+        if paths.iter().any(|path| sensitive.is_match(path)) {
+            raise(risk)
+        } else {
+            risk
+        }
+    }
+}
+
+/// The risk shown for a review with `tasks`; Low when there are none.
+pub fn compute_overall_risk(tasks: &[ReviewTask], policy: &RiskPolicy) -> RiskLevel {
+    let risks: Vec<RiskLevel> = tasks.iter().map(|task| policy.task_risk(task)).collect();
+    let medium = risks
+        .iter()
+        .filter(|risk| **risk == RiskLevel::Medium)
+        .count();
+    if policy
+        .medium_tasks_for_high
+        .is_some_and(|threshold| medium >= threshold)
+    {
+        return RiskLevel::High;
+    }
+    risks.into_iter().max().unwrap_or_default()
+}
+
+fn raise(risk: RiskLevel) -> RiskLevel {
+    match risk {
+        RiskLevel::Low => RiskLevel::Medium,
+        RiskLevel::Medium | RiskLevel::High => RiskLevel::High,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{DiffRef, TaskStats};
+
+    fn task(risk: RiskLevel, file: &str) -> ReviewTask {
+        ReviewTask {
+            stats: TaskStats {
+                risk,
+                ..Default::default()
+            },
+            diff_refs: vec![DiffRef {
+                file: file.into(),
+                hunks: vec![],
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_default_policy_is_highest_task_risk() {
+        let policy = RiskPolicy::default();
+        assert_eq!(compute_overall_risk(&[], &policy), RiskLevel::Low);
+        assert_eq!(
+            compute_overall_risk(
+                &[
+                    task(RiskLevel::Low, "a.rs"),
+                    task(RiskLevel::Medium, "b.rs"),
+                    task(RiskLevel::Medium, "c.rs"),
+                ],
+                &policy
+            ),
+            RiskLevel::Medium
+        );
+    }
+
+    #[test]
+    fn test_sensitive_paths_raise_task_risk() {
+        let policy = RiskPolicy::new(&RiskPolicyConfig {
+            sensitive_paths: vec!["src/auth/**".into(), "[invalid".into()],
+            ..Default::default()
+        });
+        assert_eq!(
+            compute_overall_risk(&[task(RiskLevel::Medium, "src/auth/login.rs")], &policy),
+            RiskLevel::High
+        );
+        assert_eq!(
+            compute_overall_risk(&[task(RiskLevel::Low, "src/auth/login.rs")], &policy),
+            RiskLevel::Medium
+        );
+        assert_eq!(
+            compute_overall_risk(&[task(RiskLevel::Medium, "src/ui/auth.rs")], &policy),
+            RiskLevel::Medium
+        );
+    }
+
+    #[test]
+    fn test_enough_medium_tasks_escalate_to_high() {
+        let policy = RiskPolicy::new(&RiskPolicyConfig {
+            medium_tasks_for_high: Some(2),
+            ..Default::default()
+        });
+        let one = [
+            task(RiskLevel::Medium, "a.rs"),
+            task(RiskLevel::Low, "b.rs"),
+        ];
+        assert_eq!(compute_overall_risk(&one, &policy), RiskLevel::Medium);
+        let two = [
+            task(RiskLevel::Medium, "a.rs"),
+            task(RiskLevel::Medium, "b.rs"),
+        ];
+        assert_eq!(compute_overall_risk(&two, &policy), RiskLevel::High);
+    }
+}
@@ -70,6 +70,22 @@ pub fn resolve_rules(
     resolved
 }
 
+/// Applies a repo's default rule set: when `categories` is non-empty, global
+/// rules tagged with another category are dropped. Uncategorized and
+/// repo-scoped rules always apply.
+pub fn filter_by_repo_categories(rules: Vec<ReviewRule>, categories: &[String]) -> Vec<ReviewRule> {
+    if categories.is_empty() {
+        return rules;
+    }
+    rules
+        .into_iter()
+        .filter(|rule| match (&rule.scope, rule.category.as_deref()) {
+            (RuleScope::Global, Some(category)) => categories.iter().any(|c| c == category),
+            _ => true,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,4 +127,26 @@ mod tests {
         assert_eq!(resolved.len(), 1);
         assert_eq!(resolved[0].matched_files, vec!["src/main.rs".to_string()]);
     }
+
+    #[test]
+    fn repo_categories_limit_categorized_global_rules() {
+        let mut security = rule("sec", RuleScope::Global, None, None);
+        security.category = Some("security".into());
+        let mut perf = rule("perf", RuleScope::Global, None, None);
+        perf.category = Some("performance".into());
+        let mut repo_perf = rule("repo-perf", RuleScope::Repo, Some("repo-1"), None);
+        repo_perf.category = Some("performance".into());
+        let plain = rule("plain", RuleScope::Global, None, None);
+        let rules = vec![security, perf, repo_perf, plain];
+
+        let ids = |rules: Vec<ReviewRule>| rules.into_iter().map(|r| r.id).collect::<Vec<_>>();
+        assert_eq!(
+            ids(filter_by_repo_categories(
+                rules.clone(),
+                &["security".into()]
+            )),
+            vec!["sec", "repo-perf", "plain"]
+        );
+        assert_eq!(ids(filter_by_repo_categories(rules, &[])).len(), 4);
+    }
 }
@@ -0,0 +1,67 @@
+//! Keyword search over review titles and summaries.
+
+/// Weight of a term found in the title relative to one found in the summary.
+const TITLE_WEIGHT: u32 = 3;
+/// Bonus when the whole query appears verbatim in the title.
+const PHRASE_BONUS: u32 = 5;
+
+/// Splits a search query into lowercase terms.
+pub fn search_terms(query: &str) -> Vec<String> {
+    query.split_whitespace().map(str::to_lowercase).collect()
+}
+
+/// Relevance of a review for `terms`, or `None` if it doesn't match.
+///
+/// Every term must occur (case-insensitively, as a substring) in the title
+/// or the summary. Title hits count more than summary hits, and a title
+/// containing the full query phrase ranks above scattered matches.
+pub fn score_review(terms: &[String], title: &str, summary: Option<&str>) -> Option<u32> {
+    if terms.is_empty() {
+        return None;
+    }
+
+    let title = title.to_lowercase();
+    let summary = summary.unwrap_or_default().to_lowercase();
+
+    let mut score = 0;
+    for term in terms {
+        let in_title = title.matches(term.as_str()).count() as u32;
+        let in_summary = summary.matches(term.as_str()).count() as u32;
+        if in_title == 0 && in_summary == 0 {
+            return None;
+        }
+        score += in_title * TITLE_WEIGHT + in_summary;
+    }
+
+    if terms.len() > 1 && title.contains(&terms.join(" ")) {
+        score += PHRASE_BONUS;
+    }
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_every_term() {
+        let terms = search_terms("Auth Token");
+        assert!(score_review(&terms, "Fix auth token refresh", None).is_some());
+        assert!(score_review(&terms, "Fix auth", Some("Rotates the TOKEN")).is_some());
+        assert!(score_review(&terms, "Fix auth", Some("Nothing else")).is_none());
+        assert!(score_review(&[], "Anything", None).is_none());
+    }
+
+    #[test]
+    fn test_title_matches_rank_above_summary_matches() {
+        let terms = search_terms("cache");
+        let title_hit = score_review(&terms, "Diff cache eviction", None).unwrap();
+        let summary_hit = score_review(&terms, "Refactor", Some("Touches the cache")).unwrap();
+        assert!(title_hit > summary_hit);
+
+        let terms = search_terms("rate limit");
+        let phrase = score_review(&terms, "Add rate limit to API", None).unwrap();
+        let scattered = score_review(&terms, "Limit the API rate", None).unwrap();
+        assert!(phrase > scattered);
+    }
+}
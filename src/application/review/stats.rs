@@ -0,0 +1,217 @@
+//! At-a-glance size and risk summary of a review run.
+//!
+//! Diff totals only depend on the diff text, so they are memoized per
+//! `diff_hash`; task and feedback counts change as the review progresses and
+//! are recomputed on every call.
+
+use super::risk::{RiskPolicy, compute_overall_risk};
+use crate::domain::{Feedback, FeedbackImpact, ReviewRun, ReviewStatus, ReviewTask, RiskLevel};
+use crate::infra::diff::cached_diff_index;
+use anyhow::Result;
+use lru::LruCache;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::num::NonZeroUsize;
+
+/// Number of runs whose diff totals are kept.
+const MAX_CACHED_RUNS: usize = 64;
+
+lazy_static::lazy_static! {
+    static ref DIFF_TOTALS: Mutex<LruCache<String, DiffTotals>> = Mutex::new(LruCache::new(
+        NonZeroUsize::new(MAX_CACHED_RUNS).unwrap_or(NonZeroUsize::MIN)
+    ));
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct DiffTotals {
+    pub files: usize,
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct RiskDistribution {
+    pub low: usize,
+    pub medium: usize,
+    pub high: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ImpactDistribution {
+    pub blocking: usize,
+    pub nice_to_have: usize,
+    pub nitpick: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct ReviewStats {
+    #[serde(flatten)]
+    pub diff: DiffTotals,
+    pub task_risk: RiskDistribution,
+    /// Risk for the review as a whole, under the configured [`RiskPolicy`].
+    pub overall_risk: RiskLevel,
+    /// Ignored feedback is left out, as in exports.
+    pub feedback_impact: ImpactDistribution,
+}
+
+/// Files and changed lines in `run`'s diff, cached by its `diff_hash`.
+pub fn diff_totals(run: &ReviewRun) -> Result<DiffTotals> {
+    if let Some(totals) = DIFF_TOTALS.lock().get(&run.diff_hash) {
+        return Ok(*totals);
+    }
+
+    let (files, _, additions, deletions) = cached_diff_index(&run.diff_text)?.total_stats();
+    let totals = DiffTotals {
+        files,
+        additions,
+        deletions,
+    };
+    DIFF_TOTALS.lock().put(run.diff_hash.clone(), totals);
+    Ok(totals)
+}
+
+pub fn compute_review_stats(
+    run: &ReviewRun,
+    tasks: &[ReviewTask],
+    feedbacks: &[Feedback],
+    policy: &RiskPolicy,
+) -> Result<ReviewStats> {
+    let mut task_risk = RiskDistribution::default();
+    for task in tasks {
+        match task.stats.risk {
+            RiskLevel::Low => task_risk.low += 1,
+            RiskLevel::Medium => task_risk.medium += 1,
+            RiskLevel::High => task_risk.high += 1,
+        }
+    }
+
+    let mut feedback_impact = ImpactDistribution::default();
+    for feedback in feedbacks
+        .iter()
+        .filter(|feedback| feedback.status != ReviewStatus::Ignored)
+    {
+        match feedback.impact {
+            FeedbackImpact::Blocking => feedback_impact.blocking += 1,
+            FeedbackImpact::NiceToHave => feedback_impact.nice_to_have += 1,
+            FeedbackImpact::Nitpick => feedback_impact.nitpick += 1,
+        }
+    }
+
+    Ok(ReviewStats {
+        diff: diff_totals(run)?,
+        task_risk,
+        overall_risk: compute_overall_risk(tasks, policy),
+        feedback_impact,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ReviewRunStatus, TaskStats};
+    use std::sync::Arc;
+
+    const DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,3 @@\n fn a() {}\n-fn b() {}\n+fn b() -> u8 { 1 }\n+fn c() {}\n";
+
+    fn run(diff_hash: &str) -> ReviewRun {
+        ReviewRun {
+            id: "run".into(),
+            review_id: "rev".into(),
+            agent_id: "agent".into(),
+            input_ref: "input".into(),
+            diff_text: Arc::from(DIFF),
+            diff_hash: diff_hash.into(),
+            status: ReviewRunStatus::Completed,
+            created_at: "now".into(),
+            agent_model: None,
+        }
+    }
+
+    fn task(risk: RiskLevel) -> ReviewTask {
+        ReviewTask {
+            stats: TaskStats {
+                risk,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn feedback(impact: FeedbackImpact, status: ReviewStatus) -> Feedback {
+        Feedback {
+            id: "fb".into(),
+            review_id: "rev".into(),
+            task_id: None,
+            rule_id: None,
+            finding_id: None,
+            category: None,
+            title: "Title".into(),
+            status,
+            impact,
+            confidence: 1.0,
+            anchor: None,
+            suggested_replacement: None,
+            extra_anchors: Vec::new(),
+            rationale: None,
+            pinned: false,
+            author: "agent".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        }
+    }
+
+    #[test]
+    fn test_counts_diff_tasks_and_feedback() {
+        let stats = compute_review_stats(
+            &run("stats-counts"),
+            &[
+                task(RiskLevel::High),
+                task(RiskLevel::Low),
+                task(RiskLevel::High),
+            ],
+            &[
+                feedback(FeedbackImpact::Blocking, ReviewStatus::Todo),
+                feedback(FeedbackImpact::Nitpick, ReviewStatus::Done),
+                feedback(FeedbackImpact::Blocking, ReviewStatus::Ignored),
+            ],
+            &RiskPolicy::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            stats.diff,
+            DiffTotals {
+                files: 1,
+                additions: 2,
+                deletions: 1,
+            }
+        );
+        assert_eq!(
+            stats.task_risk,
+            RiskDistribution {
+                low: 1,
+                medium: 0,
+                high: 2,
+            }
+        );
+        assert_eq!(stats.overall_risk, RiskLevel::High);
+        assert_eq!(
+            stats.feedback_impact,
+            ImpactDistribution {
+                blocking: 1,
+                nice_to_have: 0,
+                nitpick: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_diff_totals_are_cached_by_diff_hash() {
+        let first = diff_totals(&run("stats-cached")).unwrap();
+        let changed = ReviewRun {
+            diff_text: Arc::from(""),
+            ..run("stats-cached")
+        };
+        assert_eq!(diff_totals(&changed).unwrap(), first);
+    }
+}
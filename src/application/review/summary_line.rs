@@ -0,0 +1,136 @@
+//! One-line review summary for standups and chat, e.g.
+//! `Review Fix login: 3 blocking, 5 nice-to-have, overall HIGH risk, 12/20 tasks done`.
+//!
+//! The line comes from a template with `{placeholder}`s so teams can change
+//! the wording; unknown placeholders are left as written.
+
+use super::stats::ReviewStats;
+use crate::domain::{Review, ReviewTask};
+
+pub const DEFAULT_SUMMARY_TEMPLATE: &str = "Review {title}: {blocking} blocking, {nice_to_have} nice-to-have, overall {risk} risk, {tasks_done}/{tasks} tasks done";
+
+/// Fills `template` in for `review`. Done and ignored tasks count as done,
+/// as in the file tree.
+pub fn render_summary_line(
+    template: &str,
+    review: &Review,
+    stats: &ReviewStats,
+    tasks: &[ReviewTask],
+) -> String {
+    let value = |name: &str| -> Option<String> {
+        Some(match name {
+            "title" => review.title.clone(),
+            "blocking" => stats.feedback_impact.blocking.to_string(),
+            "nice_to_have" => stats.feedback_impact.nice_to_have.to_string(),
+            "nitpick" => stats.feedback_impact.nitpick.to_string(),
+            "risk" => stats.overall_risk.to_string(),
+            "tasks" => tasks.len().to_string(),
+            "tasks_done" => tasks
+                .iter()
+                .filter(|task| task.status.is_closed())
+                .count()
+                .to_string(),
+            "files" => stats.diff.files.to_string(),
+            "additions" => stats.diff.additions.to_string(),
+            "deletions" => stats.diff.deletions.to_string(),
+            _ => return None,
+        })
+    };
+
+    let mut line = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        line.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after
+            .find('}')
+            .and_then(|end| value(&after[..end]).map(|value| (end, value)))
+        {
+            Some((end, value)) => {
+                line.push_str(&value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                line.push('{');
+                rest = after;
+            }
+        }
+    }
+    line.push_str(rest);
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::review::stats::ImpactDistribution;
+    use crate::domain::{ReviewSource, ReviewStatus, RiskLevel};
+
+    fn review() -> Review {
+        Review {
+            id: "rev".into(),
+            title: "Fix login".into(),
+            summary: None,
+            source: ReviewSource::DiffPaste {
+                diff_hash: "h".into(),
+            },
+            active_run_id: None,
+            status: ReviewStatus::Todo,
+            tags: vec![],
+            decision: None,
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        }
+    }
+
+    fn stats() -> ReviewStats {
+        ReviewStats {
+            overall_risk: RiskLevel::High,
+            feedback_impact: ImpactDistribution {
+                blocking: 3,
+                nice_to_have: 5,
+                nitpick: 1,
+            },
+            ..Default::default()
+        }
+    }
+
+    fn tasks(done: usize, total: usize) -> Vec<ReviewTask> {
+        (0..total)
+            .map(|i| ReviewTask {
+                status: if i < done {
+                    ReviewStatus::Done
+                } else {
+                    ReviewStatus::Todo
+                },
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_default_template() {
+        assert_eq!(
+            render_summary_line(
+                DEFAULT_SUMMARY_TEMPLATE,
+                &review(),
+                &stats(),
+                &tasks(12, 20)
+            ),
+            "Review Fix login: 3 blocking, 5 nice-to-have, overall HIGH risk, 12/20 tasks done"
+        );
+    }
+
+    #[test]
+    fn test_custom_template_keeps_unknown_placeholders() {
+        assert_eq!(
+            render_summary_line(
+                "{title} | {nitpick} nits | {owner} | {risk",
+                &review(),
+                &stats(),
+                &[],
+            ),
+            "Fix login | 1 nits | {owner} | {risk"
+        );
+    }
+}
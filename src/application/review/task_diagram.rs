@@ -0,0 +1,221 @@
+//! A fallback diagram for tasks the agent didn't draw one for.
+//!
+//! The diagram is derived from the task's files alone, so it's cheap enough to
+//! build whenever tasks are loaded: files become nodes grouped by directory and
+//! labelled with their hunk count, coloured by the task's risk. Edges link a
+//! test file to the file it tests and a module file (`mod.rs`, `index.ts`,
+//! `foo.rs` for `foo/`) to the files under it.
+
+use crate::domain::{ReviewTask, RiskLevel};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// Past this many files the graph stops being readable; no diagram is built.
+const MAX_FILES: usize = 30;
+
+/// Mermaid flowchart of `task`'s files, or `None` when the task already has a
+/// diagram, touches no files, or touches too many to draw.
+pub fn auto_diagram_for_task(task: &ReviewTask) -> Option<Arc<str>> {
+    if task
+        .diagram
+        .as_deref()
+        .is_some_and(|diagram| !diagram.trim().is_empty())
+    {
+        return None;
+    }
+    let mut files: Vec<&str> = task.files.iter().map(String::as_str).collect();
+    for diff_ref in &task.diff_refs {
+        if !files.contains(&diff_ref.file.as_str()) {
+            files.push(&diff_ref.file);
+        }
+    }
+    if files.is_empty() || files.len() > MAX_FILES {
+        return None;
+    }
+    files.sort_unstable();
+
+    let mut chart = String::from(
+        "%% Drawn from the task's files; the agent didn't supply a diagram.\nflowchart LR\n",
+    );
+
+    let mut by_dir: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    for (i, file) in files.iter().enumerate() {
+        by_dir.entry(parent(file)).or_default().push(i);
+    }
+    for (d, (dir, members)) in by_dir.iter().enumerate() {
+        let label = if dir.is_empty() { "/" } else { dir };
+        chart.push_str(&format!("  subgraph d{d}[\"{}\"]\n", escape(label)));
+        for &i in members {
+            chart.push_str(&format!(
+                "    f{i}[\"{}<br/>{}\"]\n",
+                escape(file_name(files[i])),
+                hunk_label(task, files[i])
+            ));
+        }
+        chart.push_str("  end\n");
+    }
+
+    for (from, to, label) in related_files(&files) {
+        chart.push_str(&format!("  f{from} -.->|{label}| f{to}\n"));
+    }
+
+    let (class, color) = match task.stats.risk {
+        RiskLevel::Low => ("low", "#4ade80"),
+        RiskLevel::Medium => ("medium", "#facc15"),
+        RiskLevel::High => ("high", "#f87171"),
+    };
+    let nodes: Vec<String> = (0..files.len()).map(|i| format!("f{i}")).collect();
+    chart.push_str(&format!(
+        "  classDef {class} stroke:{color},stroke-width:2px\n  class {} {class}\n",
+        nodes.join(",")
+    ));
+
+    Some(Arc::from(chart))
+}
+
+/// `(from, to, label)` edges between indices of `files`.
+fn related_files(files: &[&str]) -> Vec<(usize, usize, &'static str)> {
+    let mut edges = Vec::new();
+    for (i, file) in files.iter().enumerate() {
+        for (j, other) in files.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            if is_test(file) && !is_test(other) && subject(file) == subject(other) {
+                edges.push((i, j, "tests"));
+            } else if is_module_of(file, other) {
+                edges.push((i, j, "declares"));
+            }
+        }
+    }
+    edges
+}
+
+fn parent(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit_once('/').map_or(path, |(_, name)| name)
+}
+
+/// File name up to its first dot: `foo.test.ts` -> `foo`.
+fn stem(path: &str) -> &str {
+    let name = file_name(path);
+    name.split_once('.').map_or(name, |(stem, _)| stem)
+}
+
+fn is_test(path: &str) -> bool {
+    let name = file_name(path);
+    let stem = stem(path);
+    name.contains(".test.")
+        || name.contains(".spec.")
+        || stem.starts_with("test_")
+        || stem.ends_with("_test")
+        || stem.ends_with("_tests")
+        || path
+            .split('/')
+            .any(|part| part == "tests" || part == "__tests__")
+}
+
+/// The stem a test file is named after: `foo_test.rs` and `foo.test.ts` -> `foo`.
+fn subject(path: &str) -> &str {
+    let stem = stem(path);
+    stem.strip_prefix("test_")
+        .or_else(|| stem.strip_suffix("_tests"))
+        .or_else(|| stem.strip_suffix("_test"))
+        .unwrap_or(stem)
+}
+
+/// Whether `module` declares `file`: `dir/mod.rs` or `dir/index.ts` for files
+/// in `dir/`, and `dir.rs` for files in `dir/`.
+fn is_module_of(module: &str, file: &str) -> bool {
+    if is_test(module) {
+        return false;
+    }
+    let dir = parent(module);
+    let module_dir = match stem(module) {
+        "mod" | "index" | "lib" | "main" => dir.to_string(),
+        stem if dir.is_empty() => stem.to_string(),
+        stem => format!("{dir}/{stem}"),
+    };
+    parent(file) == module_dir && file_name(file) != file_name(module)
+}
+
+fn hunk_label(task: &ReviewTask, file: &str) -> String {
+    let hunks: usize = task
+        .diff_refs
+        .iter()
+        .filter(|diff_ref| diff_ref.file == file)
+        .map(|diff_ref| diff_ref.hunks.len())
+        .sum();
+    match hunks {
+        0 => "changed".to_string(),
+        1 => "1 hunk".to_string(),
+        n => format!("{n} hunks"),
+    }
+}
+
+/// Mermaid labels are quoted; quotes and angle brackets inside them are
+/// written as entities.
+fn escape(label: &str) -> String {
+    label
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{DiffRef, HunkRef, TaskStats};
+
+    fn task(files: &[&str]) -> ReviewTask {
+        ReviewTask {
+            id: "task".into(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+            stats: TaskStats {
+                risk: RiskLevel::High,
+                ..Default::default()
+            },
+            diff_refs: vec![DiffRef {
+                file: "src/db/mod.rs".into(),
+                hunks: vec![HunkRef::default(), HunkRef::default()],
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_draws_files_and_relations() {
+        let task = task(&["src/db/mod.rs", "src/db/task.rs", "src/db/task_test.rs"]);
+        let chart = auto_diagram_for_task(&task).expect("diagram");
+
+        assert!(chart.contains("flowchart LR"));
+        assert!(chart.contains("subgraph d0[\"src/db\"]"));
+        assert!(chart.contains("f0[\"mod.rs<br/>2 hunks\"]"));
+        assert!(chart.contains("f1[\"task.rs<br/>changed\"]"));
+        assert!(chart.contains("f0 -.->|declares| f1"));
+        assert!(chart.contains("f2 -.->|tests| f1"));
+        assert!(chart.contains("class f0,f1,f2 high"));
+    }
+
+    #[test]
+    fn test_keeps_agent_diagrams() {
+        let mut task = task(&["src/a.rs"]);
+        task.diagram = Some(Arc::from("flowchart TD\n  a --> b"));
+        assert!(auto_diagram_for_task(&task).is_none());
+
+        task.diagram = Some(Arc::from("  "));
+        assert!(auto_diagram_for_task(&task).is_some());
+    }
+
+    #[test]
+    fn test_skips_tasks_too_large_to_draw() {
+        let files: Vec<String> = (0..=MAX_FILES).map(|i| format!("src/f{i}.rs")).collect();
+        let files: Vec<&str> = files.iter().map(String::as_str).collect();
+        let mut task = task(&files);
+        task.diff_refs.clear();
+        assert!(auto_diagram_for_task(&task).is_none());
+    }
+}
@@ -0,0 +1,132 @@
+//! Compact record of the tools an agent called during a generation.
+//!
+//! Tool calls are only streamed live; this keeps a capped summary (tool,
+//! title, status) so a finished run can still show what the agent
+//! inspected. Raw inputs and outputs can be large and may contain file
+//! contents, so they are only kept for debug runs.
+
+use crate::domain::{RunToolCall, RunToolLog};
+use serde_json::Value;
+
+/// Most tool calls stored per run; later calls are only counted.
+pub const MAX_STORED_TOOL_CALLS: usize = 200;
+
+/// Builds a [`RunToolLog`] from tool call progress events.
+#[derive(Debug, Default)]
+pub struct ToolCallRecorder {
+    log: RunToolLog,
+    keep_raw: bool,
+}
+
+impl ToolCallRecorder {
+    pub fn new(keep_raw: bool) -> Self {
+        Self {
+            log: RunToolLog::default(),
+            keep_raw,
+        }
+    }
+
+    pub fn started(&mut self, tool_call_id: &str, title: &str, kind: &str) {
+        if self.find(tool_call_id).is_some() {
+            return;
+        }
+        if self.log.calls.len() >= MAX_STORED_TOOL_CALLS {
+            self.log.omitted += 1;
+            return;
+        }
+        self.log.calls.push(RunToolCall {
+            tool_call_id: tool_call_id.to_string(),
+            title: title.to_string(),
+            kind: kind.to_string(),
+            status: "in_progress".to_string(),
+            raw_input: None,
+            raw_output: None,
+        });
+    }
+
+    pub fn completed(
+        &mut self,
+        tool_call_id: &str,
+        status: &str,
+        title: &str,
+        raw_input: Option<&Value>,
+        raw_output: Option<&Value>,
+    ) {
+        let keep_raw = self.keep_raw;
+        let Some(call) = self.find(tool_call_id) else {
+            return;
+        };
+        call.status = status.to_string();
+        if !title.is_empty() {
+            call.title = title.to_string();
+        }
+        if keep_raw {
+            call.raw_input = raw_input.cloned();
+            call.raw_output = raw_output.cloned();
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.log.calls.is_empty() && self.log.omitted == 0
+    }
+
+    pub fn log(&self) -> &RunToolLog {
+        &self.log
+    }
+
+    fn find(&mut self, tool_call_id: &str) -> Option<&mut RunToolCall> {
+        self.log
+            .calls
+            .iter_mut()
+            .find(|call| call.tool_call_id == tool_call_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_completion_updates_the_started_call() {
+        let mut recorder = ToolCallRecorder::new(false);
+        recorder.started("1", "repo_search", "search");
+        recorder.completed(
+            "1",
+            "completed",
+            "repo_search: fn main",
+            Some(&json!({"query": "fn main"})),
+            Some(&json!("3 matches")),
+        );
+        // Completion for a call that never started is ignored.
+        recorder.completed("2", "failed", "read_file", None, None);
+
+        let calls = &recorder.log().calls;
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].status, "completed");
+        assert_eq!(calls[0].title, "repo_search: fn main");
+        assert_eq!(calls[0].raw_input, None);
+        assert_eq!(calls[0].raw_output, None);
+    }
+
+    #[test]
+    fn test_raw_payloads_are_kept_for_debug_runs() {
+        let mut recorder = ToolCallRecorder::new(true);
+        recorder.started("1", "read_file", "read");
+        recorder.completed("1", "completed", "", Some(&json!({"path": "a.rs"})), None);
+
+        let call = &recorder.log().calls[0];
+        assert_eq!(call.title, "read_file");
+        assert_eq!(call.raw_input, Some(json!({"path": "a.rs"})));
+    }
+
+    #[test]
+    fn test_calls_past_the_cap_are_counted_not_stored() {
+        let mut recorder = ToolCallRecorder::new(false);
+        for i in 0..MAX_STORED_TOOL_CALLS + 3 {
+            recorder.started(&i.to_string(), "read_file", "read");
+        }
+        assert_eq!(recorder.log().calls.len(), MAX_STORED_TOOL_CALLS);
+        assert_eq!(recorder.log().omitted, 3);
+    }
+}
@@ -0,0 +1,104 @@
+//! Files a reviewer has ticked as viewed, like GitHub's "Viewed" checkbox.
+//!
+//! A tick is stored with a hash of the file's hunks and carries over to
+//! later runs of the same review while that hash still matches, so a
+//! re-generated review keeps files checked off unless they changed again.
+
+use crate::domain::{FileViewState, ReviewRun};
+use crate::infra::diff::cached_diff_index;
+use anyhow::Result;
+use std::collections::HashSet;
+
+/// Paths in `run`'s diff that are viewed. `states` come from every run of
+/// the review, newest first; the newest state of a file wins, and a tick
+/// whose hash no longer matches the file's hunks doesn't count.
+pub fn viewed_files(run: &ReviewRun, states: &[FileViewState]) -> Result<Vec<String>> {
+    let index = cached_diff_index(&run.diff_text)?;
+    let mut seen = HashSet::new();
+    let mut viewed: Vec<String> = states
+        .iter()
+        .filter(|state| seen.insert(state.file_path.as_str()))
+        .filter(|state| state.viewed)
+        .filter(|state| {
+            index.file_content_hash(&state.file_path).as_deref() == Some(&state.content_hash)
+        })
+        .map(|state| state.file_path.clone())
+        .collect();
+    viewed.sort();
+    Ok(viewed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ReviewRunStatus;
+    use crate::infra::diff::index::DiffIndex;
+    use std::sync::Arc;
+
+    const DIFF: &str = "diff --git a/src/a.rs b/src/a.rs\n--- a/src/a.rs\n+++ b/src/a.rs\n@@ -1 +1 @@\n-a\n+b\ndiff --git a/src/b.rs b/src/b.rs\n--- a/src/b.rs\n+++ b/src/b.rs\n@@ -1 +1 @@\n-a\n+b\n";
+
+    fn run(diff: &str) -> ReviewRun {
+        ReviewRun {
+            id: "run-2".into(),
+            review_id: "rev".into(),
+            agent_id: "agent".into(),
+            input_ref: "input".into(),
+            diff_text: Arc::from(diff),
+            diff_hash: "h".into(),
+            status: ReviewRunStatus::Completed,
+            created_at: "now".into(),
+            agent_model: None,
+        }
+    }
+
+    fn state(path: &str, viewed: bool, diff: &str) -> FileViewState {
+        FileViewState {
+            run_id: "run-1".into(),
+            file_path: path.into(),
+            viewed,
+            content_hash: DiffIndex::new(diff)
+                .unwrap()
+                .file_content_hash(path)
+                .unwrap(),
+            updated_at: "then".into(),
+        }
+    }
+
+    #[test]
+    fn test_tick_carries_over_while_hunks_match() {
+        let viewed = viewed_files(&run(DIFF), &[state("src/a.rs", true, DIFF)]).unwrap();
+        assert_eq!(viewed, vec!["src/a.rs".to_string()]);
+    }
+
+    #[test]
+    fn test_tick_is_dropped_when_hunks_change() {
+        let earlier = DIFF.replace("+b\ndiff --git a/src/b.rs", "+c\ndiff --git a/src/b.rs");
+        let viewed = viewed_files(
+            &run(DIFF),
+            &[
+                state("src/a.rs", true, &earlier),
+                state("src/b.rs", true, &earlier),
+            ],
+        )
+        .unwrap();
+        assert_eq!(viewed, vec!["src/b.rs".to_string()]);
+    }
+
+    #[test]
+    fn test_newest_state_wins_and_moved_hunks_still_match() {
+        let moved = DIFF.replace(
+            "@@ -1 +1 @@\n-a\n+b\ndiff --git a/src/b.rs",
+            "@@ -9 +9 @@\n-a\n+b\ndiff --git a/src/b.rs",
+        );
+        let viewed = viewed_files(
+            &run(&moved),
+            &[
+                state("src/b.rs", false, DIFF),
+                state("src/b.rs", true, DIFF),
+                state("src/a.rs", true, DIFF),
+            ],
+        )
+        .unwrap();
+        assert_eq!(viewed, vec!["src/a.rs".to_string()]);
+    }
+}
@@ -0,0 +1,197 @@
+//! Read-only snapshot of the app's state for external dashboards.
+//!
+//! One call returns reviews with their stats, rule effectiveness, learning
+//! status and recent runs, built from the same queries the UI uses. The
+//! payload carries a `version`: fields may be added within a version, while
+//! renames and removals bump it.
+
+use crate::application::review::risk::RiskPolicy;
+use crate::application::review::stats::{ReviewStats, compute_review_stats};
+use crate::commands::ReviewState;
+use crate::domain::{LearningStatus, ReviewRun, ReviewTask};
+use crate::infra::db::Database;
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Shape version of [`StateSnapshot`].
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Runs listed in [`StateSnapshot::recent_runs`], newest first.
+pub const RECENT_RUNS: usize = 20;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StateSnapshot {
+    pub version: u32,
+    /// RFC3339 time the snapshot was taken.
+    pub generated_at: String,
+    pub reviews: Vec<ReviewSnapshot>,
+    pub rules: Vec<RuleEffectiveness>,
+    pub learning: LearningStatus,
+    pub recent_runs: Vec<RunSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewSnapshot {
+    #[serde(flatten)]
+    pub review: ReviewState,
+    /// Stats of the active run; None when the review has no run yet.
+    pub stats: Option<ReviewStats>,
+}
+
+/// A rule and how its feedback has been received.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleEffectiveness {
+    pub rule_id: String,
+    pub text: String,
+    pub category: Option<String>,
+    pub enabled: bool,
+    pub total_feedback: i64,
+    pub rejected_count: i64,
+    pub rejection_rate: f64,
+}
+
+/// A run without its diff text.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunSnapshot {
+    pub id: String,
+    pub review_id: String,
+    pub agent_id: String,
+    pub status: String,
+    pub created_at: String,
+    pub task_count: usize,
+}
+
+pub fn build_snapshot(db: &Database) -> Result<StateSnapshot> {
+    let runs = db.run_repo().list_all()?;
+    let mut tasks_by_run: HashMap<String, Vec<ReviewTask>> = HashMap::new();
+    for task in db.task_repo().list()? {
+        tasks_by_run
+            .entry(task.run_id.clone())
+            .or_default()
+            .push(task);
+    }
+    let runs_by_id: HashMap<&str, &ReviewRun> =
+        runs.iter().map(|run| (run.id.as_str(), run)).collect();
+
+    let feedback_repo = db.feedback_repo();
+    let risk_policy = RiskPolicy::configured();
+    let mut reviews = Vec::new();
+    for review in db.get_all_reviews()? {
+        let run = review
+            .active_run_id
+            .as_deref()
+            .and_then(|id| runs_by_id.get(id));
+        let stats = match run {
+            Some(run) => {
+                let tasks = tasks_by_run.get(&run.id).map(Vec::as_slice).unwrap_or(&[]);
+                let feedbacks = feedback_repo.find_by_review(&review.id)?;
+                Some(compute_review_stats(run, tasks, &feedbacks, &risk_policy)?)
+            }
+            None => None,
+        };
+        reviews.push(ReviewSnapshot { review, stats });
+    }
+
+    let mut rejection_stats: HashMap<String, _> = db
+        .rejection_repo()
+        .get_rule_stats()?
+        .into_iter()
+        .map(|stats| (stats.rule_id.clone(), stats))
+        .collect();
+    let rules = db
+        .rule_repo()
+        .list_all()?
+        .into_iter()
+        .map(|rule| {
+            let stats = rejection_stats.remove(&rule.id);
+            RuleEffectiveness {
+                total_feedback: stats.as_ref().map_or(0, |s| s.total_feedback),
+                rejected_count: stats.as_ref().map_or(0, |s| s.rejected_count),
+                rejection_rate: stats.as_ref().map_or(0.0, |s| s.rejection_rate),
+                rule_id: rule.id,
+                text: rule.text,
+                category: rule.category,
+                enabled: rule.enabled,
+            }
+        })
+        .collect();
+
+    let learning = db
+        .learning_state_repo()
+        .get_status(&db.learned_pattern_repo())?;
+
+    let recent_runs = runs
+        .iter()
+        .take(RECENT_RUNS)
+        .map(|run| RunSnapshot {
+            id: run.id.clone(),
+            review_id: run.review_id.clone(),
+            agent_id: run.agent_id.clone(),
+            status: run.status.to_string(),
+            created_at: run.created_at.clone(),
+            task_count: tasks_by_run.get(&run.id).map_or(0, Vec::len),
+        })
+        .collect();
+
+    Ok(StateSnapshot {
+        version: SNAPSHOT_VERSION,
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        reviews,
+        rules,
+        learning,
+        recent_runs,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Review, ReviewRunStatus, ReviewSource, ReviewStatus};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_snapshot_covers_reviews_and_runs() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.review_repo().save(&Review {
+            id: "rev-1".into(),
+            title: "Add parser".into(),
+            summary: None,
+            source: ReviewSource::DiffPaste {
+                diff_hash: "h".into(),
+            },
+            active_run_id: None,
+            status: ReviewStatus::Todo,
+            created_at: "2024-01-01T00:00:00Z".into(),
+            updated_at: "2024-01-01T00:00:00Z".into(),
+            tags: vec![],
+            decision: None,
+        })?;
+        db.run_repo().save(&ReviewRun {
+            id: "run-1".into(),
+            review_id: "rev-1".into(),
+            agent_id: "agent".into(),
+            input_ref: "paste".into(),
+            diff_text: Arc::from("diff --git a/a b/a\n--- a/a\n+++ b/a\n@@ -1 +1 @@\n-a\n+b\n"),
+            diff_hash: "h".into(),
+            status: ReviewRunStatus::Completed,
+            created_at: "2024-01-01T00:00:00Z".into(),
+            agent_model: None,
+        })?;
+        db.review_repo()
+            .set_active_run(&"rev-1".to_string(), &"run-1".to_string())?;
+
+        let snapshot = build_snapshot(&db)?;
+        assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+        assert_eq!(snapshot.reviews.len(), 1);
+        let stats = snapshot.reviews[0].stats.expect("active run has stats");
+        assert_eq!(stats.diff.files, 1);
+        assert_eq!(snapshot.recent_runs.len(), 1);
+        assert_eq!(snapshot.recent_runs[0].status, "completed");
+
+        let json = serde_json::to_value(&snapshot)?;
+        assert_eq!(json["reviews"][0]["title"], "Add parser");
+        assert!(json["reviews"][0]["stats"]["files"].is_number());
+        Ok(())
+    }
+}
@@ -1,21 +1,38 @@
+use crate::application::review::conflicts::conflict_feedback;
 use crate::application::review::export::{ExportData, ExportOptions, ReviewExporter};
-use crate::application::review::rules::resolve_rules;
+use crate::application::review::file_status::{FileReviewStatus, compute_file_review_status};
+use crate::application::review::risk::RiskPolicy;
+use crate::application::review::rules::{filter_by_repo_categories, resolve_rules};
+use crate::application::review::stats::{ReviewStats, compute_review_stats};
+use crate::application::review::summary_line::{DEFAULT_SUMMARY_TEMPLATE, render_summary_line};
+use crate::application::review::tool_calls::ToolCallRecorder;
+use crate::application::review::viewed::viewed_files;
 use crate::domain::{
-    Comment, Feedback, FeedbackAnchor, FeedbackImpact, FeedbackSide,
-    LinkedRepo as DomainLinkedRepo, ResolvedRule, Review, ReviewRule, ReviewRun, ReviewRunStatus,
-    ReviewSource, ReviewStatus, ReviewTask, RuleScope,
+    ChecklistItem, Comment, Feedback, FeedbackAnchor, FeedbackAttachment, FeedbackImpact,
+    FeedbackSide, IssueCheckDefinition, LinkedRepo as DomainLinkedRepo, ResolvedRule, Review,
+    ReviewDecision, ReviewMode, ReviewRule, ReviewRun, ReviewRunStatus, ReviewSource, ReviewStatus,
+    ReviewTask, RuleScope, RunPlan, RunPlanEntry, RunToolLog, sort_by_severity,
 };
 use crate::infra::acp::{
-    GenerateTasksInput, ProgressEvent, RunContext, generate_tasks_with_acp, invalidate_agent_cache,
-    list_agent_candidates,
+    AgentFailure, AgentProbe, GenerateTasksInput, ProgressEvent, RunContext, RunLog,
+    generate_tasks_with_acp, invalidate_agent_cache, list_agent_candidates,
+    select_rejection_examples,
 };
-use crate::infra::diff::index::DiffIndex;
+use crate::infra::automation::AutomationEvent;
+use crate::infra::db::repository::ReviewMergeOutcome;
+use crate::infra::diff::filter_diff_by_paths;
+use crate::infra::diff::generated::GeneratedFiles;
+use crate::infra::diff::index::ConflictedFile;
+pub use crate::infra::diff::parsed::{ParsedDiff, ParsedDiffFile, ParsedHunk};
+use crate::infra::diff::{cached_diff_index, cached_user_diff_index};
 use crate::infra::hash::hash_diff;
-use crate::infra::vcs::registry::VcsRegistry;
+use crate::infra::vcs::registry::{STATUS_TIMEOUT, VcsRegistry, status_with_timeout};
+use crate::infra::vcs::retry;
 use crate::infra::vcs::traits::{
-    FeedbackPushRequest, ReviewPushRequest, VcsCloneRequest, VcsStatus,
+    FeedbackPushRequest, ReviewPushRequest, VcsCloneRequest, VcsPrData, VcsPrSummary, VcsProvider,
+    VcsRemoteRepo, VcsStatus,
 };
-use crate::state::{AppState, PendingDiff};
+use crate::state::{ActiveRun, AppState, PendingDiff};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -51,7 +68,7 @@ pub enum ProgressEventPayload {
         raw_output: Option<serde_json::Value>,
     },
     // Other events
-    Plan(FrontendPlan),
+    Plan(RunPlan),
     TaskStarted {
         task_id: String,
         title: String,
@@ -59,26 +76,97 @@ pub enum ProgressEventPayload {
     TaskCompleted {
         task_id: String,
     },
+    /// Waiting for another generation to finish before starting.
+    Queued {
+        limit: usize,
+    },
     Completed {
         task_count: usize,
+        #[serde(default)]
+        feedback_count: usize,
     },
     Error {
         message: String,
+        /// Agent stderr tail, shown behind a "details" expander.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        details: Option<String>,
     },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct FrontendPlan {
-    pub entries: Vec<FrontendPlanEntry>,
+/// Stores something recorded during generation on the run, logging failures
+/// instead of interrupting the progress stream.
+fn save_run_activity(
+    db: &std::sync::Mutex<crate::infra::db::Database>,
+    run_id: &str,
+    what: &str,
+    save: impl FnOnce(&crate::infra::db::Database) -> anyhow::Result<()>,
+) {
+    let saved = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!(e.to_string()))
+        .and_then(|db| save(&db));
+    if let Err(err) = saved {
+        log::warn!("Failed to save {} for run {}: {}", what, run_id, err);
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct FrontendPlanEntry {
-    pub content: String,
-    pub priority: String,
-    pub status: String,
+/// Appends the time the task MCP server spent in each tool to the run log.
+fn log_tool_timings(
+    run_log: &RunLog,
+    db: &std::sync::Mutex<crate::infra::db::Database>,
+    run_id: &str,
+) {
+    let timings = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!(e.to_string()))
+        .and_then(|db| db.run_repo().get_tool_timings(&run_id.to_string()));
+    match timings {
+        Ok(timings) => {
+            for timing in timings {
+                run_log.write(&format!(
+                    "tool {}: {} calls, {} ms total, {} ms max",
+                    timing.tool, timing.calls, timing.total_ms, timing.max_ms
+                ));
+            }
+        }
+        Err(err) => log::warn!("Failed to read tool timings for run {}: {}", run_id, err),
+    }
+}
+
+/// Converts an ACP plan update into the shape the frontend renders and the
+/// run stores.
+fn run_plan_from_acp(plan: &agent_client_protocol::Plan) -> RunPlan {
+    let plan_value = serde_json::to_value(plan).unwrap_or_default();
+    let entries = if let Some(entries_val) = plan_value.get("entries").and_then(|v| v.as_array()) {
+        entries_val
+            .iter()
+            .map(|e| {
+                let content = e
+                    .get("content")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .to_string();
+                let priority = e
+                    .get("priority")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Medium")
+                    .to_string();
+                let status = e
+                    .get("status")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Pending")
+                    .to_string();
+                RunPlanEntry {
+                    content,
+                    priority,
+                    status,
+                }
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    RunPlan { entries }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,6 +174,8 @@ pub struct FrontendPlanEntry {
 pub struct CliStatus {
     pub is_installed: bool,
     pub version: Option<String>,
+    /// Build metadata reported alongside the version (commit, build date).
+    pub build: Option<String>,
     pub path: Option<String>,
 }
 
@@ -100,84 +190,154 @@ pub async fn get_cli_status() -> Result<CliStatus, String> {
     let is_installed = path.is_some();
     let path_str = path.as_ref().map(|p| p.to_string_lossy().to_string());
 
-    let version = if is_installed {
-        let output = std::process::Command::new("lareview")
+    let parsed = if is_installed {
+        std::process::Command::new("lareview")
             .arg("--version")
             .output()
-            .ok();
-
-        output.and_then(|o| {
-            if o.status.success() {
-                let s = String::from_utf8_lossy(&o.stdout).trim().to_string();
-                // "lareview 0.0.18" -> "0.0.18"
-                Some(s.replace("lareview ", ""))
-            } else {
-                None
-            }
-        })
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| {
+                crate::infra::cli::version::parse_cli_version(&String::from_utf8_lossy(&o.stdout))
+            })
     } else {
         None
     };
+    let (version, build) = match parsed {
+        Some(v) => (Some(v.version), v.build),
+        None => (None, None),
+    };
 
     Ok(CliStatus {
         is_installed,
         version,
+        build,
         path: path_str,
     })
 }
 
+/// Checks the runtime, database, VCS CLIs, agents and editor, for the
+/// settings Diagnostics panel and bug reports.
 #[tauri::command]
-pub async fn install_cli() -> Result<(), String> {
-    #[cfg(unix)]
-    {
-        let current_exe = std::env::current_exe().map_err(|e| e.to_string())?;
-
-        // Try /usr/local/bin first, fall back to ~/.local/bin on permission denied
-        let primary_target = std::path::PathBuf::from("/usr/local/bin/lareview");
-        let fallback_target = dirs::home_dir()
-            .map(|h| h.join(".local/bin/lareview"))
-            .ok_or_else(|| "Could not determine home directory".to_string())?;
-
-        let install_to_target = |target: &std::path::Path| -> Result<(), std::io::Error> {
-            // Ensure parent directory exists
-            if let Some(parent) = target.parent()
-                && !parent.exists()
-            {
-                std::fs::create_dir_all(parent)?;
-            }
+pub async fn run_diagnostics(
+    state: State<'_, AppState>,
+) -> Result<crate::infra::diagnostics::DiagnosticsReport, String> {
+    let runtime_ok = state.runtime.spawn(async {}).await.is_ok();
+    Ok(crate::infra::diagnostics::run_diagnostics(&state.db, runtime_ok).await)
+}
 
-            // Check if it already exists and points to the same executable
-            if target.exists() {
-                if let Ok(existing) = std::fs::read_link(target)
-                    && existing == current_exe
-                {
-                    return Ok(());
-                }
-                // Remove existing if it's different or not a symlink
-                let _ = std::fs::remove_file(target);
-            }
+/// Runs the diagnostics and zips them with the redacted config and recent run
+/// logs into `output_path`, for attaching to an issue. Returns the path.
+#[tauri::command]
+pub async fn export_bug_report(
+    state: State<'_, AppState>,
+    output_path: String,
+) -> Result<String, String> {
+    let runtime_ok = state.runtime.spawn(async {}).await.is_ok();
+    let report = crate::infra::diagnostics::run_diagnostics(&state.db, runtime_ok).await;
+    crate::infra::bug_report::write_bug_report(std::path::Path::new(&output_path), &report)
+        .map_err(|e| format!("{:#}", e))?;
+    Ok(output_path)
+}
 
-            std::os::unix::fs::symlink(&current_exe, target)
-        };
+/// The generate input saved before the app last closed, if any.
+#[tauri::command]
+pub fn load_generate_draft() -> Option<crate::infra::draft::GenerateDraft> {
+    crate::infra::draft::load_draft()
+}
 
-        // Try primary location first
-        match install_to_target(&primary_target) {
-            Ok(()) => Ok(()),
-            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
-                // Fall back to ~/.local/bin
-                install_to_target(&fallback_target).map_err(|e| {
-                    format!(
-                        "Failed to install CLI. Could not write to /usr/local/bin or ~/.local/bin: {}",
-                        e
-                    )
-                })
-            }
-            Err(e) => Err(format!("Failed to create symlink: {}", e)),
-        }
+#[tauri::command]
+pub fn save_generate_draft(draft: crate::infra::draft::GenerateDraft) -> Result<(), String> {
+    crate::infra::draft::save_draft(&draft).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn clear_generate_draft() -> Result<(), String> {
+    crate::infra::draft::clear_draft().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_data_dir() -> crate::infra::data_dir::DataDirInfo {
+    crate::infra::data_dir::data_dir_info()
+}
+
+/// Moves the review data to `path` (None for the platform default), copying
+/// the current database, logs and prompts, and switches to it.
+#[tauri::command]
+pub fn set_data_dir(
+    state: State<'_, AppState>,
+    path: Option<String>,
+) -> Result<crate::infra::data_dir::DataDirInfo, String> {
+    let active = state.active_runs.lock().map_err(|e| e.to_string())?;
+    if !active.is_empty() {
+        return Err("Wait for running reviews to finish before moving the data.".to_string());
+    }
+    let mut db = state.db.lock().map_err(|e| e.to_string())?;
+    let (new_db, info) = crate::infra::data_dir::change_data_dir(&db, path.as_deref())
+        .map_err(|e| format!("{:#}", e))?;
+    *db = new_db;
+    Ok(info)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionStatus {
+    /// Whether this build has the `encryption` feature.
+    pub supported: bool,
+    pub enabled: bool,
+    /// The database is encrypted and waiting for its passphrase.
+    pub locked: bool,
+}
+
+#[tauri::command]
+pub fn get_encryption_status(state: State<'_, AppState>) -> EncryptionStatus {
+    use crate::infra::db::encryption;
+    EncryptionStatus {
+        supported: encryption::is_supported(),
+        enabled: encryption::is_enabled(),
+        locked: state.db_locked.load(std::sync::atomic::Ordering::SeqCst),
+    }
+}
+
+/// Encrypts the database with `passphrase` (or changes the passphrase of an
+/// encrypted one) and keeps the passphrase in the OS keychain.
+#[tauri::command]
+pub fn enable_encryption(state: State<'_, AppState>, passphrase: String) -> Result<(), String> {
+    if state.db_locked.load(std::sync::atomic::Ordering::SeqCst) {
+        return Err("Unlock the database first.".to_string());
     }
+    let active = state.active_runs.lock().map_err(|e| e.to_string())?;
+    if !active.is_empty() {
+        return Err("Wait for running reviews to finish before encrypting the data.".to_string());
+    }
+    let mut db = state.db.lock().map_err(|e| e.to_string())?;
+    crate::infra::db::encryption::enable_encryption(&mut db, &passphrase)
+        .map_err(|e| format!("{:#}", e))
+}
+
+/// Opens the encrypted database with `passphrase` and saves it in the
+/// keychain for the next launch.
+#[tauri::command]
+pub fn unlock_database(state: State<'_, AppState>, passphrase: String) -> Result<(), String> {
+    use std::sync::atomic::Ordering;
+    if !state.db_locked.load(Ordering::SeqCst) {
+        return Ok(());
+    }
+    let unlocked =
+        crate::infra::db::encryption::unlock(&passphrase).map_err(|e| format!("{:#}", e))?;
+    if let Err(err) = unlocked.mark_stale_runs_failed() {
+        log::warn!("Failed to mark stale runs as failed: {}", err);
+    }
+    let watcher = crate::state::watcher_for_config(&unlocked);
+    *state.repo_watcher.lock().map_err(|e| e.to_string())? = watcher;
+    *state.db.lock().map_err(|e| e.to_string())? = unlocked;
+    state.db_locked.store(false, Ordering::SeqCst);
+    Ok(())
+}
 
-    #[cfg(not(unix))]
-    Err("CLI installation is only supported on macOS and Linux".to_string())
+#[tauri::command]
+pub async fn install_cli() -> Result<crate::infra::cli::install::CliInstallOutcome, String> {
+    let current_exe = std::env::current_exe().map_err(|e| e.to_string())?;
+    crate::infra::cli::install::install_cli(&current_exe).map_err(|e| format!("{:#}", e))
 }
 
 #[tauri::command]
@@ -237,6 +397,7 @@ async fn cleanup_snapshot(path: &std::path::Path) {
 #[tauri::command]
 #[allow(clippy::too_many_arguments)]
 pub async fn generate_review(
+    app: tauri::AppHandle,
     state: State<'_, AppState>,
     diff_text: String,
     agent_id: String,
@@ -245,8 +406,10 @@ pub async fn generate_review(
     source: Option<ReviewSource>,
     use_snapshot: bool,
     on_progress: Channel<ProgressEventPayload>,
+    excluded_paths: Option<Vec<String>>,
+    mode: Option<ReviewMode>,
 ) -> Result<ReviewGenerationResult, String> {
-    generate_review_inner(
+    let result = generate_review_inner(
         state.inner(),
         diff_text,
         agent_id,
@@ -255,10 +418,181 @@ pub async fn generate_review(
         source,
         use_snapshot,
         on_progress,
+        excluded_paths.unwrap_or_default(),
+        mode,
     )
+    .await;
+
+    let outcome = match &result {
+        Ok(generated) => Ok(crate::infra::notification::GenerationCounts {
+            tasks: generated.task_count,
+            feedback: generated.feedback_count,
+        }),
+        Err(err) => Err(err.as_str()),
+    };
+    if let Some(notice) = crate::infra::notification::generation_notice(outcome) {
+        notify_if_backgrounded(&app, notice);
+    }
+
+    result
+}
+
+/// Asks `agent_id` for a narrative summary of the diff (intent, key changes,
+/// risks) and saves it as a new review with no tasks. Much quicker than
+/// [`generate_review`]; tasks can still be generated for the diff later.
+#[tauri::command]
+pub async fn summarize_review(
+    state: State<'_, AppState>,
+    diff_text: String,
+    agent_id: String,
+    source: Option<ReviewSource>,
+) -> Result<ReviewGenerationResult, String> {
+    cached_user_diff_index(&diff_text).map_err(|e| e.to_string())?;
+    let input_hash = hash_diff(&diff_text);
+    let source = source.unwrap_or_else(|| ReviewSource::DiffPaste {
+        diff_hash: input_hash.clone(),
+    });
+    let title = initial_review_title(&source);
+
+    let agent = list_agent_candidates()
+        .into_iter()
+        .find(|c| c.id == agent_id)
+        .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+    let agent_command = agent.command.ok_or_else(|| {
+        format!(
+            "Agent '{}' is not available. Please configure it in settings.",
+            agent_id
+        )
+    })?;
+
+    let summary = crate::infra::acp::summarize_review(crate::infra::acp::SummarizeReviewInput {
+        title: title.clone(),
+        diff_text: diff_text.clone(),
+        agent_command,
+        agent_args: agent.args,
+        timeout_secs: None,
+    })
     .await
+    .map_err(|e| format!("{:#}", e))?;
+
+    let review_id = Uuid::new_v4().to_string();
+    let run_id = Uuid::new_v4().to_string();
+    let now = chrono::Utc::now().to_rfc3339();
+    let run = ReviewRun {
+        id: run_id.clone(),
+        review_id: review_id.clone(),
+        agent_id,
+        input_ref: review_input_ref(&input_hash, &[]),
+        diff_text: Arc::from(diff_text.as_str()),
+        diff_hash: input_hash,
+        status: ReviewRunStatus::Completed,
+        created_at: now.clone(),
+        agent_model: None,
+    };
+    let review = Review {
+        id: review_id.clone(),
+        title,
+        summary: Some(summary),
+        source,
+        active_run_id: Some(run_id.clone()),
+        status: ReviewStatus::Todo,
+        created_at: now.clone(),
+        updated_at: now,
+        tags: vec![],
+        decision: None,
+    };
+
+    let db = state.database()?;
+    db.save_review(&review).map_err(|e| e.to_string())?;
+    db.save_run(&run).map_err(|e| e.to_string())?;
+
+    Ok(ReviewGenerationResult {
+        task_count: 0,
+        feedback_count: 0,
+        review_id,
+        run_id: Some(run_id),
+    })
+}
+
+/// Shows `notice` as an OS notification when completion notifications are
+/// enabled and the main window doesn't have focus.
+fn notify_if_backgrounded(app: &tauri::AppHandle, notice: crate::infra::notification::Notice) {
+    use tauri::Manager;
+    use tauri_plugin_notification::NotificationExt;
+
+    if !crate::infra::app_config::load_config().notify_on_completion {
+        return;
+    }
+    let focused = app
+        .get_webview_window("main")
+        .and_then(|window| window.is_focused().ok())
+        .unwrap_or(false);
+    if focused {
+        return;
+    }
+    if let Err(err) = app
+        .notification()
+        .builder()
+        .title(notice.title)
+        .body(notice.body)
+        .show()
+    {
+        log::warn!("Failed to show completion notification: {}", err);
+    }
+}
+
+/// Provenance for a run's input: the submitted diff's hash, plus the files
+/// left out of the review, if any.
+fn review_input_ref(input_hash: &str, excluded_paths: &[String]) -> String {
+    let input_ref = format!("diff-{}", &input_hash[..8]);
+    if excluded_paths.is_empty() {
+        return input_ref;
+    }
+    let mut excluded = excluded_paths.to_vec();
+    excluded.sort();
+    excluded.dedup();
+    format!("{} (excluded: {})", input_ref, excluded.join(", "))
+}
+
+/// Title a new review starts with, until the agent names it.
+fn initial_review_title(source: &ReviewSource) -> String {
+    match source {
+        ReviewSource::GitHubPr { repo, number, .. }
+        | ReviewSource::GiteaPr { repo, number, .. } => {
+            format!("PR {}#{}", repo, number)
+        }
+        ReviewSource::GitLabMr {
+            project_path,
+            number,
+            ..
+        } => format!("MR {}!{}", project_path, number),
+        ReviewSource::GitHubCompare {
+            repo,
+            base,
+            head,
+            mode,
+            ..
+        } => format!("Compare {}@{}{}{}", repo, base, mode.separator(), head),
+        ReviewSource::GitLabCompare {
+            project_path,
+            base,
+            head,
+            mode,
+            ..
+        } => format!(
+            "Compare {}@{}{}{}",
+            project_path,
+            base,
+            mode.separator(),
+            head
+        ),
+        _ => "AI Review".to_string(),
+    }
 }
 
+/// Recent rejections scanned for ones relevant to a new review.
+const REJECTION_EXAMPLE_WINDOW: usize = 200;
+
 #[allow(clippy::too_many_arguments)]
 async fn generate_review_inner(
     state: &AppState,
@@ -269,17 +603,47 @@ async fn generate_review_inner(
     source: Option<ReviewSource>,
     use_snapshot: bool,
     on_progress: Channel<ProgressEventPayload>,
+    excluded_paths: Vec<String>,
+    mode: Option<ReviewMode>,
 ) -> Result<ReviewGenerationResult, String> {
+    // Fail before the agent starts when the input isn't a diff at all.
+    cached_user_diff_index(&diff_text).map_err(|e| e.to_string())?;
+    let input_hash = hash_diff(&diff_text);
+    let input_ref = review_input_ref(&input_hash, &excluded_paths);
+    let diff_text = if excluded_paths.is_empty() {
+        diff_text
+    } else {
+        let keep: std::collections::HashSet<String> = cached_diff_index(&diff_text)
+            .map_err(|e| e.to_string())?
+            .get_all_file_paths()
+            .into_iter()
+            .filter(|path| !excluded_paths.contains(path))
+            .collect();
+        let filtered = filter_diff_by_paths(&diff_text, &keep);
+        if filtered.trim().is_empty() {
+            return Err("Every file in the diff was excluded from the review".to_string());
+        }
+        filtered
+    };
+    let mode = match mode {
+        Some(mode) => mode,
+        None => {
+            let (_, _, additions, deletions) = cached_diff_index(&diff_text)
+                .map_err(|e| e.to_string())?
+                .total_stats();
+            ReviewMode::for_changed_lines(additions + deletions, quick_review_max_lines())
+        }
+    };
     let diff_hash = hash_diff(&diff_text);
     let review_id = Uuid::new_v4().to_string();
     let run_id = run_id.unwrap_or_else(|| Uuid::new_v4().to_string());
 
     let source = source.unwrap_or_else(|| ReviewSource::DiffPaste {
-        diff_hash: diff_hash.clone(),
+        diff_hash: input_hash.clone(),
     });
 
-    // Create snapshot if requested and applicable
-    let snapshot_path = if use_snapshot {
+    // Create snapshot if requested and applicable; quick reviews go diff-only
+    let snapshot_path = if use_snapshot && mode == ReviewMode::Thorough {
         let repo_id_ref = &repo_id;
         let head_sha = match &source {
             ReviewSource::GitHubPr {
@@ -289,13 +653,25 @@ async fn generate_review_inner(
             ReviewSource::GitLabMr {
                 head_sha: Some(head_sha),
                 ..
+            }
+            | ReviewSource::GitHubCompare {
+                head_sha: Some(head_sha),
+                ..
+            }
+            | ReviewSource::GitLabCompare {
+                head_sha: Some(head_sha),
+                ..
+            }
+            | ReviewSource::GiteaPr {
+                head_sha: Some(head_sha),
+                ..
             } => Some(head_sha.as_str()),
             _ => None,
         };
 
         if let (Some(rid), Some(head_sha)) = (repo_id_ref, head_sha) {
             let repos = {
-                let db = state.db.lock().map_err(|e| e.to_string())?;
+                let db = state.database()?;
                 db.get_linked_repos().map_err(|e| e.to_string())?
             };
             if let Some(repo) = repos.iter().find(|r| r.id == *rid) {
@@ -339,24 +715,15 @@ async fn generate_review_inner(
         id: run_id.clone(),
         review_id: review_id.clone(),
         agent_id: agent_id.clone(),
-        input_ref: format!("diff-{}", &diff_hash[..8]),
+        input_ref,
         diff_text: Arc::from(diff_text.as_str()),
         diff_hash: diff_hash.clone(),
         status: ReviewRunStatus::Running,
         created_at: now.clone(),
+        agent_model: None,
     };
 
-    let initial_title = match &source {
-        ReviewSource::GitHubPr { repo, number, .. } => {
-            format!("PR {}#{}", repo, number)
-        }
-        ReviewSource::GitLabMr {
-            project_path,
-            number,
-            ..
-        } => format!("MR {}!{}", project_path, number),
-        _ => "AI Review".to_string(),
-    };
+    let initial_title = initial_review_title(&source);
 
     let review = Review {
         id: review_id.clone(),
@@ -367,6 +734,8 @@ async fn generate_review_inner(
         status: ReviewStatus::Todo,
         created_at: now.clone(),
         updated_at: now.clone(),
+        tags: vec![],
+        decision: None,
     };
 
     let (candidate_label, command, candidate_args) = {
@@ -400,13 +769,53 @@ async fn generate_review_inner(
         source,
         initial_title: None,
         created_at: Some(now),
+        max_tasks: None,
+    };
+
+    let debug = std::env::var("RUST_LOG")
+        .map(|v| v.contains("acp"))
+        .unwrap_or(false);
+
+    // With ACP debugging on, keep a redacted on-disk record of the run so a
+    // stuck generation can be diagnosed after the fact.
+    let run_log = if debug {
+        match RunLog::create(&run_id) {
+            Ok(run_log) => {
+                log::info!("Writing run log to {}", run_log.path().display());
+                Some(Arc::new(run_log))
+            }
+            Err(err) => {
+                log::warn!("Failed to create run log for {}: {:#}", run_id, err);
+                None
+            }
+        }
+    } else {
+        None
     };
+    if let Some(run_log) = &run_log {
+        run_log.write(&format!(
+            "run {} (review {}) with agent {}: {} {}",
+            run_id,
+            review_id,
+            agent_id,
+            command,
+            candidate_args.join(" ")
+        ));
+    }
 
     let (mcp_tx, mut mcp_rx) = mpsc::unbounded_channel::<ProgressEvent>();
 
     let on_progress_clone = on_progress.clone();
+    let run_log_for_events = run_log.clone();
+    let db_for_events = state.db.clone();
+    let run_id_for_events = run_id.clone();
     tauri::async_runtime::spawn(async move {
+        let mut latest_plan: Option<RunPlan> = None;
+        let mut tool_calls = ToolCallRecorder::new(debug);
         while let Some(event) = mcp_rx.recv().await {
+            if let Some(run_log) = &run_log_for_events {
+                run_log.write(&format!("{:?}", event));
+            }
             let payload = match event {
                 ProgressEvent::LocalLog(msg) => ProgressEventPayload::Log(msg),
                 ProgressEvent::MessageDelta { id, delta } => {
@@ -419,58 +828,46 @@ async fn generate_review_inner(
                     tool_call_id,
                     title,
                     kind,
-                } => ProgressEventPayload::ToolCallStarted {
-                    tool_call_id,
-                    title,
-                    kind,
-                },
+                } => {
+                    tool_calls.started(&tool_call_id, &title, &kind);
+                    ProgressEventPayload::ToolCallStarted {
+                        tool_call_id,
+                        title,
+                        kind,
+                    }
+                }
                 ProgressEvent::ToolCallComplete {
                     tool_call_id,
                     status,
                     title,
                     raw_input,
                     raw_output,
-                } => ProgressEventPayload::ToolCallComplete {
-                    tool_call_id,
-                    status,
-                    title,
-                    raw_input,
-                    raw_output,
-                },
+                } => {
+                    tool_calls.completed(
+                        &tool_call_id,
+                        &status,
+                        &title,
+                        raw_input.as_ref(),
+                        raw_output.as_ref(),
+                    );
+                    ProgressEventPayload::ToolCallComplete {
+                        tool_call_id,
+                        status,
+                        title,
+                        raw_input,
+                        raw_output,
+                    }
+                }
                 ProgressEvent::Plan(plan) => {
-                    let plan_value = serde_json::to_value(&plan).unwrap_or_default();
-                    let entries = if let Some(entries_val) =
-                        plan_value.get("entries").and_then(|v| v.as_array())
-                    {
-                        entries_val
-                            .iter()
-                            .map(|e| {
-                                let content = e
-                                    .get("content")
-                                    .and_then(|v| v.as_str())
-                                    .unwrap_or("")
-                                    .to_string();
-                                let priority = e
-                                    .get("priority")
-                                    .and_then(|v| v.as_str())
-                                    .unwrap_or("Medium")
-                                    .to_string();
-                                let status = e
-                                    .get("status")
-                                    .and_then(|v| v.as_str())
-                                    .unwrap_or("Pending")
-                                    .to_string();
-                                FrontendPlanEntry {
-                                    content,
-                                    priority,
-                                    status,
-                                }
-                            })
-                            .collect()
-                    } else {
-                        Vec::new()
-                    };
-                    ProgressEventPayload::Plan(FrontendPlan { entries })
+                    let plan = run_plan_from_acp(&plan);
+                    latest_plan = Some(plan.clone());
+                    ProgressEventPayload::Plan(plan)
+                }
+                ProgressEvent::AgentModel(model) => {
+                    save_run_activity(&db_for_events, &run_id_for_events, "agent model", |db| {
+                        db.run_repo().set_agent_model(&run_id_for_events, &model)
+                    });
+                    ProgressEventPayload::Log(format!("Agent model: {}", model))
                 }
                 ProgressEvent::TaskStarted(id, title) => {
                     ProgressEventPayload::TaskStarted { task_id: id, title }
@@ -482,22 +879,54 @@ async fn generate_review_inner(
                 ProgressEvent::MetadataUpdated => {
                     ProgressEventPayload::Log("Metadata updated".to_string())
                 }
-                ProgressEvent::Finalized => ProgressEventPayload::Completed { task_count: 0 },
+                ProgressEvent::Finalized => {
+                    // Keep the plan the agent finished with, for the completed review.
+                    if let Some(plan) = &latest_plan {
+                        save_run_activity(&db_for_events, &run_id_for_events, "plan", |db| {
+                            db.run_repo().set_plan(&run_id_for_events, plan)
+                        });
+                    }
+                    save_run_activity(&db_for_events, &run_id_for_events, "tool calls", |db| {
+                        db.run_repo()
+                            .set_tool_log(&run_id_for_events, tool_calls.log())
+                    });
+                    ProgressEventPayload::Completed {
+                        task_count: 0,
+                        feedback_count: 0,
+                    }
+                }
             };
             if let Err(e) = on_progress_clone.send(payload) {
                 log::error!("Failed to send progress to channel: {:?}", e);
                 break;
             }
         }
+        // Failed and cancelled runs never finalize; keep what the agent got to.
+        if !tool_calls.is_empty() {
+            save_run_activity(&db_for_events, &run_id_for_events, "tool calls", |db| {
+                db.run_repo()
+                    .set_tool_log(&run_id_for_events, tool_calls.log())
+            });
+        }
+        if let Some(run_log) = &run_log_for_events {
+            log_tool_timings(run_log, &db_for_events, &run_id_for_events);
+        }
     });
 
-    let _ = on_progress.send(ProgressEventPayload::Log(format!(
-        "Starting review generation with {}...",
-        candidate_label
-    )));
+    // Take a generation slot now if one is free; otherwise the run is saved
+    // as queued and waits for one below.
+    let free_slot = state.generation_limiter.try_acquire();
+    let run = ReviewRun {
+        status: if free_slot.is_some() {
+            ReviewRunStatus::Running
+        } else {
+            ReviewRunStatus::Queued
+        },
+        ..run
+    };
 
     {
-        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let db = state.database()?;
         db.save_review(&review).map_err(|e| e.to_string())?;
         db.save_run(&run).map_err(|e| e.to_string())?;
     }
@@ -505,9 +934,49 @@ async fn generate_review_inner(
     let cancel_token = CancellationToken::new();
     {
         let mut active = state.active_runs.lock().unwrap();
-        active.insert(run_id.clone(), cancel_token.clone());
+        active.insert(
+            run_id.clone(),
+            ActiveRun {
+                cancel_token: cancel_token.clone(),
+                status: run.status,
+            },
+        );
     }
 
+    let generation_slot = match free_slot {
+        Some(slot) => slot,
+        None => {
+            let _ = on_progress.send(ProgressEventPayload::Queued {
+                limit: state.generation_limiter.limit(),
+            });
+            let Some(slot) = state.generation_limiter.acquire(&cancel_token).await else {
+                state.active_runs.lock().unwrap().remove(&run_id);
+                let db = state.database()?;
+                if let Err(err) = db
+                    .run_repo()
+                    .update_status(&run_id, ReviewRunStatus::Cancelled)
+                {
+                    log::error!("Failed to cancel queued run {}: {}", run_id, err);
+                }
+                let _ = db.review_repo().delete(&review_id);
+                return Err("cancelled by user".to_string());
+            };
+            if let Some(active) = state.active_runs.lock().unwrap().get_mut(&run_id) {
+                active.status = ReviewRunStatus::Running;
+            }
+            let db = state.database()?;
+            db.run_repo()
+                .update_status(&run_id, ReviewRunStatus::Running)
+                .map_err(|e| e.to_string())?;
+            slot
+        }
+    };
+
+    let _ = on_progress.send(ProgressEventPayload::Log(format!(
+        "Starting review generation with {}...",
+        candidate_label
+    )));
+
     let repo_id = repo_id.and_then(|id| {
         let trimmed = id.trim();
         if trimmed.is_empty() {
@@ -517,55 +986,146 @@ async fn generate_review_inner(
         }
     });
 
-    let diff_paths = DiffIndex::new(&diff_text)
-        .map(|index| index.get_all_file_paths())
+    let (diff_paths, conflicted_files) = cached_diff_index(&diff_text)
+        .map(|index| (index.get_all_file_paths(), index.conflicted_files()))
         .unwrap_or_default();
 
     let rules: Vec<ResolvedRule> = {
-        let db = state.db.lock().map_err(|e| e.to_string())?;
-        let all_rules = db.rule_repo().list_enabled().map_err(|e| e.to_string())?;
+        let db = state.database()?;
+        let mut all_rules = db.rule_repo().list_enabled().map_err(|e| e.to_string())?;
+        if let Some(repo_id) = repo_id.as_deref() {
+            let repo = db
+                .repo_repo()
+                .find_all()
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .find(|repo| repo.id == repo_id);
+            if let Some(repo) = repo {
+                all_rules = filter_by_repo_categories(all_rules, &repo.default_rule_categories);
+            }
+        }
         resolve_rules(&all_rules, repo_id.as_deref(), &diff_paths)
     };
+    let issue_checks = {
+        let db = state.database()?;
+        db.issue_check_definition_repo()
+            .list_enabled()
+            .map_err(|e| e.to_string())?
+    };
+    let rejection_examples = if crate::infra::app_config::load_config().include_rejection_examples {
+        let db = state.database()?;
+        let recent = db
+            .rejection_repo()
+            .list_all(REJECTION_EXAMPLE_WINDOW)
+            .map_err(|e| e.to_string())?;
+        // Keep to the linked repo's own reviews when the review has one.
+        let repo = match repo_id.as_deref() {
+            Some(repo_id) => db
+                .repo_repo()
+                .find_all()
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .find(|repo| repo.id == repo_id),
+            None => None,
+        };
+        let repo_review_ids: Option<std::collections::HashSet<String>> = match repo {
+            Some(repo) => Some(
+                db.review_repo()
+                    .list_all()
+                    .map_err(|e| e.to_string())?
+                    .into_iter()
+                    .filter(|review| {
+                        repo.remotes
+                            .iter()
+                            .any(|remote| review.source.matches_remote(remote))
+                    })
+                    .map(|review| review.id)
+                    .collect(),
+            ),
+            None => None,
+        };
+        select_rejection_examples(&recent, &diff_paths, repo_review_ids.as_ref())
+    } else {
+        Vec::new()
+    };
 
     // Use snapshot path as repo_root if provided for agent access
     let repo_root = snapshot_path.clone();
+    // The repo's own prompt comes from the linked checkout: the snapshot is
+    // the head under review, which the change's author controls.
+    let prompt_root = match repo_id.as_deref() {
+        Some(repo_id) => state
+            .database()?
+            .repo_repo()
+            .find_all()
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .find(|repo| repo.id == repo_id)
+            .map(|repo| repo.path),
+        None => None,
+    };
 
     let result = generate_tasks_with_acp(GenerateTasksInput {
         run_context,
         rules,
+        issue_checks,
+        rejection_examples,
+        mode,
         repo_root,
+        prompt_root,
         cleanup_path: snapshot_path.clone(),
         agent_command: command,
         agent_args: candidate_args,
         progress_tx: Some(mcp_tx),
         mcp_server_binary: None,
+        db_path: state.db_path(),
         timeout_secs: Some(
             crate::infra::app_config::load_config()
                 .review_timeout_secs
                 .unwrap_or(1000),
         ),
         cancel_token: Some(cancel_token),
-        debug: std::env::var("RUST_LOG")
-            .map(|v| v.contains("acp"))
-            .unwrap_or(false),
+        debug,
     })
     .await;
 
-    // Cleanup: remove token from active_runs
-    {
-        let mut active = state.active_runs.lock().unwrap();
-        active.remove(&run_id);
-    }
-
+    if let Some(run_log) = &run_log {
+        match &result {
+            Ok(output) => {
+                for line in &output.logs {
+                    run_log.write(&format!("log: {}", line));
+                }
+                for message in &output.messages {
+                    run_log.write(&format!("agent message: {}", message));
+                }
+                run_log.write("generation completed");
+            }
+            // The alternate format includes the captured stderr and phase logs.
+            Err(err) => run_log.write(&format!("generation failed: {:#}", err)),
+        }
+    }
+
+    // Cleanup: remove token from active_runs and let the next queued run start
+    {
+        let mut active = state.active_runs.lock().unwrap();
+        active.remove(&run_id);
+    }
+    drop(generation_slot);
+
     if let Some(snapshot_path) = snapshot_path.as_ref() {
         cleanup_snapshot(snapshot_path).await;
     }
 
-    match result {
+    let (task_count, feedback_count) = match result {
         Ok(_) => {
-            let db = state.db.lock().map_err(|e| e.to_string())?;
+            let db = state.database()?;
+            if !conflicted_files.is_empty() {
+                save_conflict_feedback(&db, &review_id, &run.diff_hash, &conflicted_files);
+            }
             let tasks_result = db.get_tasks_by_run(&run_id);
             let task_count = tasks_result.map(|t| t.len()).unwrap_or(0);
+            let feedbacks = db.get_feedback_by_review(&review_id).unwrap_or_default();
+            let feedback_count = feedbacks.len();
 
             if let Err(err) = db
                 .run_repo()
@@ -578,14 +1138,30 @@ async fn generate_review_inner(
                 );
             }
 
-            let _ = on_progress.send(ProgressEventPayload::Completed { task_count });
+            let _ = on_progress.send(ProgressEventPayload::Completed {
+                task_count,
+                feedback_count,
+            });
+
+            let mut events = vec![AutomationEvent::ReviewCompleted {
+                review_id: review_id.clone(),
+                run_id: run_id.clone(),
+                task_count,
+                feedback_count,
+            }];
+            events.extend(AutomationEvent::blocking_findings(&run, &feedbacks));
+            crate::infra::automation::emit(events);
+            (task_count, feedback_count)
         }
         Err(e) => {
             log::error!("Task generation failed: {:?}", e);
+            let failure = e.downcast_ref::<AgentFailure>();
+            let summary = failure.map_or_else(|| e.to_string(), |f| f.summary.clone());
             let _ = on_progress.send(ProgressEventPayload::Error {
-                message: format!("Generation failed: {}", e),
+                message: format!("Generation failed: {}", summary),
+                details: failure.map(|f| f.stderr_tail.join("\n")),
             });
-            let db = state.db.lock().map_err(|e| e.to_string())?;
+            let db = state.database()?;
             let is_cancelled = e.to_string().contains("cancelled by user");
             let status = if is_cancelled {
                 ReviewRunStatus::Cancelled
@@ -606,26 +1182,72 @@ async fn generate_review_inner(
                 return Err("cancelled by user".to_string());
             }
 
-            return Err(e.to_string());
+            return Err(summary);
         }
-    }
+    };
 
     Ok(ReviewGenerationResult {
-        task_count: 0,
+        task_count,
+        feedback_count,
         review_id,
         run_id: Some(run_id),
     })
 }
 
+/// Raises blocking feedback for files the diff leaves with conflict markers,
+/// so the review can't be approved without noticing them.
+fn save_conflict_feedback(
+    db: &crate::infra::db::Database,
+    review_id: &str,
+    diff_hash: &str,
+    conflicted_files: &[ConflictedFile],
+) {
+    let existing = db.get_feedback_by_review(review_id).unwrap_or_default();
+    let now = chrono::Utc::now().to_rfc3339();
+    for (feedback, comment) in
+        conflict_feedback(review_id, diff_hash, conflicted_files, &existing, &now)
+    {
+        if let Err(err) = db
+            .save_feedback(&feedback, &feedback.id)
+            .and_then(|_| db.save_comment(&comment))
+        {
+            log::error!(
+                "Failed to save conflict feedback for {}: {}",
+                review_id,
+                err
+            );
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn stop_generation(state: State<'_, AppState>, run_id: String) -> Result<(), String> {
     let token = {
         let active = state.active_runs.lock().unwrap();
-        active.get(&run_id).cloned()
+        active.get(&run_id).map(|run| run.cancel_token.clone())
     };
 
-    if let Some(token) = token {
-        token.cancel();
+    match token {
+        Some(token) => token.cancel(),
+        // Nothing is generating this run any more (e.g. the app quit mid-run);
+        // settle its status so it stops showing as running.
+        None => {
+            let db = state.database()?;
+            let run = db
+                .run_repo()
+                .find_by_id(&run_id)
+                .map_err(|e| e.to_string())?;
+            if run.is_some_and(|run| {
+                matches!(
+                    run.status,
+                    ReviewRunStatus::Queued | ReviewRunStatus::Running
+                )
+            }) {
+                db.run_repo()
+                    .update_status(&run_id, ReviewRunStatus::Cancelled)
+                    .map_err(|e| e.to_string())?;
+            }
+        }
     }
 
     Ok(())
@@ -633,70 +1255,65 @@ pub async fn stop_generation(state: State<'_, AppState>, run_id: String) -> Resu
 
 #[tauri::command]
 pub fn get_pending_reviews(state: State<'_, AppState>) -> Result<Vec<PendingReviewState>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     let reviews = db.get_pending_reviews().map_err(|e| e.to_string())?;
     Ok(reviews)
 }
 
+/// Parses a diff for the frontend. With `repo_root`, files its
+/// `.gitattributes` marks `linguist-generated` are flagged as generated too,
+/// and submodule changes link to their commits via `.gitmodules`.
+///
+/// Indexing a large PR can take long enough to stall the UI, so the work runs
+/// on the blocking pool instead of the IPC thread that serves sync commands.
 #[tauri::command]
-pub fn parse_diff(diff_text: String) -> Result<ParsedDiff, String> {
-    let index = DiffIndex::new(&diff_text).map_err(|e| e.to_string())?;
-    let manifest = index.generate_hunk_manifest_json();
-    let file_paths = index.get_all_file_paths();
+pub async fn parse_diff(
+    diff_text: String,
+    repo_root: Option<String>,
+) -> Result<ParsedDiff, String> {
+    tauri::async_runtime::spawn_blocking(move || build_parsed_diff(diff_text, repo_root))
+        .await
+        .map_err(|e| format!("Diff indexing task failed: {}", e))?
+}
 
-    let total_additions = diff_text
-        .lines()
-        .filter(|l| l.starts_with('+') && !l.starts_with("+++"))
-        .count();
+fn build_parsed_diff(diff_text: String, repo_root: Option<String>) -> Result<ParsedDiff, String> {
+    cached_user_diff_index(&diff_text).map_err(|e| e.to_string())?;
+    let mut parsed = crate::parse_unified_diff(&diff_text).map_err(|e| e.to_string())?;
+    let repo_root = repo_root.as_deref().map(std::path::Path::new);
+    parsed.mark_generated(&GeneratedFiles::from_config(repo_root));
+    parsed.link_submodules(repo_root);
+    Ok(parsed)
+}
 
-    let total_deletions = diff_text
-        .lines()
-        .filter(|l| l.starts_with('-') && !l.starts_with("---"))
-        .count();
+/// Reads dropped `.patch`/`.diff` files and parses them as a single diff.
+#[tauri::command]
+pub async fn load_patch_files(paths: Vec<String>) -> Result<ParsedDiff, String> {
+    if paths.is_empty() {
+        return Err("No patch files to load".to_string());
+    }
 
-    let files: Vec<ParsedDiffFile> = file_paths
-        .iter()
-        .map(|path| {
-            let hunk_ids = index.get_hunk_ids_for_file(path);
-            ParsedDiffFile {
-                name: path.clone(),
-                old_path: path.clone(),
-                new_path: path.clone(),
-                hunks: hunk_ids
-                    .iter()
-                    .filter_map(|hunk_id| {
-                        index.get_hunk_coords(hunk_id).map(|coords| {
-                            let content = index
-                                .get_hunk_content_by_coords(
-                                    path,
-                                    coords.old_start,
-                                    coords.new_start,
-                                )
-                                .unwrap_or_default();
-
-                            ParsedHunk {
-                                old_start: coords.old_start,
-                                old_lines: coords.old_lines,
-                                new_start: coords.new_start,
-                                new_lines: coords.new_lines,
-                                content,
-                            }
-                        })
-                    })
-                    .collect(),
-            }
-        })
-        .collect();
+    let mut patches = Vec::with_capacity(paths.len());
+    for path in &paths {
+        let bytes = tokio::fs::read(path)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        let text = String::from_utf8(bytes)
+            .ok()
+            .filter(|text| !text.contains('\0'))
+            .ok_or_else(|| format!("{} is not a text patch", path))?;
+        patches.push(text);
+    }
 
-    Ok(ParsedDiff {
-        diff_text,
-        total_additions,
-        total_deletions,
-        hunk_manifest: manifest,
-        files,
-        source: None,
-        title: None,
-    })
+    let title = match paths.as_slice() {
+        [path] => std::path::Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned()),
+        _ => Some(format!("{} patches", paths.len())),
+    };
+
+    let mut parsed = parse_diff(crate::combine_diffs(&patches), None).await?;
+    parsed.title = title;
+    Ok(parsed)
 }
 
 #[tauri::command]
@@ -715,251 +1332,1095 @@ pub fn get_file_content(
     String::from_utf8(output.stdout).map_err(|e| e.to_string())
 }
 
+/// Page size used when a list command is called without a `limit`.
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewPage {
+    pub reviews: Vec<ReviewState>,
+    /// Number of reviews across all pages.
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskPage {
+    pub tasks: Vec<ReviewTask>,
+    /// Number of matching tasks across all pages.
+    pub total: usize,
+}
+
+/// Loads the tasks of `run_id`, or every task when it's omitted.
+///
+/// Deprecated without a `run_id`: use [`list_tasks`] to page through all tasks.
 #[tauri::command]
 pub fn load_tasks(
     state: State<'_, AppState>,
     run_id: Option<String>,
 ) -> Result<Vec<ReviewTask>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     let tasks = if let Some(run_id) = run_id {
         db.get_tasks_by_run(&run_id).map_err(|e| e.to_string())?
     } else {
         db.get_all_tasks().map_err(|e| e.to_string())?
     };
-    Ok(tasks)
+    Ok(with_auto_diagrams(tasks))
 }
 
-#[tauri::command]
-pub fn get_all_reviews(state: State<'_, AppState>) -> Result<Vec<ReviewState>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let reviews = db.get_all_reviews().map_err(|e| e.to_string())?;
-    Ok(reviews)
+/// Gives tasks the agent didn't draw a diagram for one built from their files.
+/// The fallback isn't stored, so a later agent diagram still takes its place.
+fn with_auto_diagrams(mut tasks: Vec<ReviewTask>) -> Vec<ReviewTask> {
+    use crate::application::review::task_diagram::auto_diagram_for_task;
+
+    for task in &mut tasks {
+        if let Some(diagram) = auto_diagram_for_task(task) {
+            task.diagram = Some(diagram);
+        }
+    }
+    tasks
 }
 
+/// Pages through tasks, optionally limited to one run. Returns the first
+/// [`DEFAULT_PAGE_SIZE`] tasks when called without `limit`/`offset`.
 #[tauri::command]
-pub fn get_review_runs(
+pub fn list_tasks(
     state: State<'_, AppState>,
-    review_id: String,
-) -> Result<Vec<ReviewRunState>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let runs = db.get_review_runs(&review_id).map_err(|e| e.to_string())?;
-    Ok(runs)
+    run_id: Option<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+) -> Result<TaskPage, String> {
+    let db = state.database()?;
+    let (tasks, total) = db
+        .get_tasks_page(
+            run_id.as_deref(),
+            limit.unwrap_or(DEFAULT_PAGE_SIZE),
+            offset.unwrap_or(0),
+        )
+        .map_err(|e| e.to_string())?;
+    Ok(TaskPage {
+        tasks: with_auto_diagrams(tasks),
+        total,
+    })
 }
 
+/// Deprecated: loads every review at once; use [`list_reviews`].
 #[tauri::command]
-pub fn get_linked_repos(state: State<'_, AppState>) -> Result<Vec<LinkedRepoState>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let repos = db.get_linked_repos().map_err(|e| e.to_string())?;
-    Ok(repos)
+pub fn get_all_reviews(state: State<'_, AppState>) -> Result<Vec<ReviewState>, String> {
+    let db = state.database()?;
+    let reviews = db.get_all_reviews().map_err(|e| e.to_string())?;
+    Ok(reviews)
 }
 
+/// Pages through reviews, most recently updated first. Returns the first
+/// [`DEFAULT_PAGE_SIZE`] reviews when called without `limit`/`offset`.
 #[tauri::command]
-pub fn set_repo_snapshot_access(
+pub fn list_reviews(
     state: State<'_, AppState>,
-    repo_id: String,
-    allowed: bool,
-) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.repo_repo()
-        .update_snapshot_access(&repo_id, allowed)
+    limit: Option<usize>,
+    offset: Option<usize>,
+) -> Result<ReviewPage, String> {
+    let db = state.database()?;
+    let (reviews, total) = db
+        .get_reviews_page(limit.unwrap_or(DEFAULT_PAGE_SIZE), offset.unwrap_or(0))
         .map_err(|e| e.to_string())?;
-    Ok(())
+    Ok(ReviewPage { reviews, total })
 }
 
+/// Finds reviews whose title or summary contains every word of `query`,
+/// most relevant first. Ties keep the most-recently-updated-first order.
 #[tauri::command]
-pub fn update_task_status(
+pub fn search_reviews(
     state: State<'_, AppState>,
-    task_id: String,
-    status: String,
-) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let review_status = ReviewStatus::from_str(&status).unwrap_or(ReviewStatus::Todo);
-    db.update_task_status(&task_id, review_status)
-        .map_err(|e| e.to_string())?;
-    Ok(())
-}
+    query: String,
+) -> Result<Vec<ReviewState>, String> {
+    use crate::application::review::search::{score_review, search_terms};
 
-#[tauri::command]
-pub fn save_feedback(
-    state: State<'_, AppState>,
-    feedback: FeedbackInput,
-) -> Result<String, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let id = Uuid::new_v4().to_string();
+    let terms = search_terms(&query);
+    let db = state.database()?;
+    let reviews = db.get_all_reviews().map_err(|e| e.to_string())?;
+    if terms.is_empty() {
+        return Ok(reviews);
+    }
 
-    let anchor = if let (Some(file_path), Some(line_number), Some(side)) =
-        (feedback.file_path, feedback.line_number, feedback.side)
-    {
-        Some(FeedbackAnchor {
-            file_path: Some(file_path),
-            line_number: Some(line_number),
-            side: if side == "old" {
-                Some(FeedbackSide::Old)
-            } else {
-                Some(FeedbackSide::New)
-            },
-            hunk_ref: None,
-            head_sha: None,
+    let mut ranked: Vec<(u32, ReviewState)> = reviews
+        .into_iter()
+        .filter_map(|review| {
+            score_review(&terms, &review.title, review.summary.as_deref())
+                .map(|score| (score, review))
         })
-    } else {
-        None
-    };
-
-    let impact = FeedbackImpact::from_str(&feedback.impact).unwrap_or(FeedbackImpact::Nitpick);
-
-    let feedback_domain = Feedback {
-        id: id.clone(),
-        review_id: feedback.review_id,
-        task_id: feedback.task_id,
-        rule_id: feedback.rule_id,
-        finding_id: None,
-        category: None,
-        title: feedback.title,
-        status: ReviewStatus::Todo,
-        impact,
-        confidence: 1.0, // User-created feedback is high confidence
-        anchor,
-        author: "user".to_string(),
-        created_at: chrono::Utc::now().to_rfc3339(),
-        updated_at: chrono::Utc::now().to_rfc3339(),
-    };
-
-    db.save_feedback(&feedback_domain, &id)
-        .map_err(|e| e.to_string())?;
-    Ok(id)
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0));
+    Ok(ranked.into_iter().map(|(_, review)| review).collect())
 }
 
+/// Adds a tag to a review and returns the review's tags.
 #[tauri::command]
-pub fn get_feedback_comments(
+pub fn add_review_tag(
     state: State<'_, AppState>,
-    feedback_id: String,
-) -> Result<Vec<Comment>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let comments = db
-        .get_comments_for_feedback(&feedback_id)
-        .map_err(|e| e.to_string())?;
-    Ok(comments)
+    review_id: String,
+    tag: String,
+) -> Result<Vec<String>, String> {
+    let db = state.database()?;
+    db.review_repo()
+        .add_tag(&review_id, &tag)
+        .map_err(|e| e.to_string())
 }
 
+/// Removes a tag from a review and returns the review's remaining tags.
 #[tauri::command]
-pub fn add_comment(
+pub fn remove_review_tag(
     state: State<'_, AppState>,
-    feedback_id: String,
-    body: String,
-) -> Result<String, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let id = Uuid::new_v4().to_string();
-    let comment = Comment {
-        id: id.clone(),
-        feedback_id,
-        author: "user".to_string(),
-        body,
-        parent_id: None,
-        created_at: chrono::Utc::now().to_rfc3339(),
-        updated_at: chrono::Utc::now().to_rfc3339(),
-    };
-    db.save_comment(&comment).map_err(|e| e.to_string())?;
-    Ok(id)
+    review_id: String,
+    tag: String,
+) -> Result<Vec<String>, String> {
+    let db = state.database()?;
+    db.review_repo()
+        .remove_tag(&review_id, &tag)
+        .map_err(|e| e.to_string())
 }
 
+/// Sets the overall decision submitted with the review on the next push, or
+/// clears it to fall back to the suggested one.
 #[tauri::command]
-pub fn update_feedback_status(
+pub fn set_review_decision(
     state: State<'_, AppState>,
-    feedback_id: String,
-    status: String,
+    review_id: String,
+    decision: Option<ReviewDecision>,
 ) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let review_status = ReviewStatus::from_str(&status).unwrap_or(ReviewStatus::Todo);
-
-    // If status is being set to "ignored", record the rejection
-    if review_status == ReviewStatus::Ignored {
-        // Fetch feedback details for rejection tracking
-        if let Ok(Some(feedback)) = db.feedback_repo().find_by_id(&feedback_id) {
-            let rejection_repo = db.rejection_repo();
-
-            // Only record if not already recorded
-            if !rejection_repo
-                .rejection_exists(&feedback_id)
-                .unwrap_or(false)
-            {
-                // Extract agent_id from author (format: "agent:agent_id")
-                let agent_id = feedback
-                    .author
-                    .strip_prefix("agent:")
-                    .unwrap_or(&feedback.author)
-                    .to_string();
-
-                // Extract file extension from anchor if available
-                let file_extension = feedback
-                    .anchor
-                    .as_ref()
-                    .and_then(|a| a.file_path.as_ref())
-                    .and_then(|p| {
-                        std::path::Path::new(p)
-                            .extension()
-                            .and_then(|e| e.to_str())
-                            .map(|s| s.to_string())
-                    });
-
-                let _ = rejection_repo.record_rejection(
-                    &feedback_id,
-                    &feedback.review_id,
-                    feedback.rule_id.as_deref(),
-                    &agent_id,
-                    &feedback.impact.to_string(),
-                    feedback.confidence,
-                    file_extension.as_deref(),
-                    &feedback.title,
-                );
-            }
-        }
-    }
+    let db = state.database()?;
+    db.review_repo()
+        .set_decision(&review_id, decision)
+        .map_err(|e| e.to_string())
+}
 
-    db.update_feedback_status(&feedback_id, review_status)
-        .map_err(|e| e.to_string())?;
-    Ok(())
+/// The review's follow-up checklist, oldest item first.
+#[tauri::command]
+pub fn get_checklist(
+    state: State<'_, AppState>,
+    review_id: String,
+) -> Result<Vec<ChecklistItem>, String> {
+    let db = state.database()?;
+    db.checklist_repo()
+        .find_by_review(&review_id)
+        .map_err(|e| e.to_string())
 }
 
+/// Adds an unchecked item to a review's checklist and returns it.
 #[tauri::command]
-pub fn update_feedback_impact(
+pub fn add_checklist_item(
     state: State<'_, AppState>,
-    feedback_id: String,
-    impact: String,
-) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let feedback_impact = FeedbackImpact::from_str(&impact).unwrap_or(FeedbackImpact::Nitpick);
-    db.update_feedback_impact(&feedback_id, feedback_impact)
-        .map_err(|e| e.to_string())?;
-    Ok(())
+    review_id: String,
+    text: String,
+) -> Result<ChecklistItem, String> {
+    let item = ChecklistItem::new(&review_id, &text)?;
+    let db = state.database()?;
+    db.get_review(&review_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review not found".to_string())?;
+    db.checklist_repo().save(&item).map_err(|e| e.to_string())?;
+    Ok(item)
 }
 
+/// Checks or unchecks a checklist item and returns it.
 #[tauri::command]
-pub fn delete_feedback(state: State<'_, AppState>, feedback_id: String) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.delete_feedback(&feedback_id)
-        .map_err(|e| e.to_string())?;
-    Ok(())
+pub fn toggle_checklist_item(
+    state: State<'_, AppState>,
+    item_id: String,
+) -> Result<ChecklistItem, String> {
+    let db = state.database()?;
+    db.checklist_repo()
+        .toggle(&item_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Checklist item not found".to_string())
 }
 
 #[tauri::command]
-pub fn delete_review(state: State<'_, AppState>, review_id: String) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.review_repo()
-        .delete(&review_id)
+pub fn remove_checklist_item(state: State<'_, AppState>, item_id: String) -> Result<(), String> {
+    let db = state.database()?;
+    db.checklist_repo()
+        .delete(&item_id)
         .map_err(|e| e.to_string())?;
     Ok(())
 }
 
 #[tauri::command]
-pub fn get_feedback_by_review(
+pub fn get_review_runs(
     state: State<'_, AppState>,
     review_id: String,
-) -> Result<Vec<Feedback>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    let feedbacks = db
-        .get_feedback_by_review(&review_id)
-        .map_err(|e| e.to_string())?;
-    Ok(feedbacks)
+) -> Result<Vec<ReviewRunState>, String> {
+    let db = state.database()?;
+    let runs = db.get_review_runs(&review_id).map_err(|e| e.to_string())?;
+    Ok(runs)
+}
+
+/// The unified diff a run reviewed.
+#[tauri::command]
+pub fn get_run_diff(state: State<'_, AppState>, run_id: String) -> Result<String, String> {
+    let db = state.database()?;
+    db.run_repo()
+        .get_diff(&run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Review run not found: {}", run_id))
+}
+
+/// Diff size plus task-risk and feedback-impact counts for a review's active run.
+#[tauri::command]
+pub fn get_review_stats(
+    state: State<'_, AppState>,
+    review_id: String,
+) -> Result<ReviewStats, String> {
+    let db = state.database()?;
+    let (_, stats, _) = load_review_stats(&db, &review_id)?;
+    Ok(stats)
+}
+
+/// One-line summary of a review for standups, from the configured template.
+#[tauri::command]
+pub fn get_review_summary_line(
+    state: State<'_, AppState>,
+    review_id: String,
+) -> Result<String, String> {
+    let db = state.database()?;
+    let (review, stats, tasks) = load_review_stats(&db, &review_id)?;
+    let template = crate::infra::app_config::load_config()
+        .review_summary_template
+        .filter(|template| !template.trim().is_empty());
+    Ok(render_summary_line(
+        template.as_deref().unwrap_or(DEFAULT_SUMMARY_TEMPLATE),
+        &review,
+        &stats,
+        &tasks,
+    ))
+}
+
+/// A review with the stats and tasks of its active run.
+fn load_review_stats(
+    db: &crate::infra::db::Database,
+    review_id: &str,
+) -> Result<(Review, ReviewStats, Vec<ReviewTask>), String> {
+    let review = db
+        .get_review(review_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review not found".to_string())?;
+    let run_id = review
+        .active_run_id
+        .clone()
+        .ok_or_else(|| "Review has no active run".to_string())?;
+    let run = db
+        .get_review_run_by_id(&run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review run not found".to_string())?;
+    let tasks = db.get_tasks_by_run(&run_id).map_err(|e| e.to_string())?;
+    let feedbacks = db
+        .get_feedback_by_review(review_id)
+        .map_err(|e| e.to_string())?;
+    let stats = compute_review_stats(&run, &tasks, &feedbacks, &RiskPolicy::configured())
+        .map_err(|e| e.to_string())?;
+    Ok((review, stats, tasks))
+}
+
+/// Open/closed feedback and task coverage for each file in a review's
+/// active run, for the file tree in the review nav.
+#[tauri::command]
+pub fn get_file_review_status(
+    state: State<'_, AppState>,
+    review_id: String,
+) -> Result<Vec<FileReviewStatus>, String> {
+    let db = state.database()?;
+    let run_id = db
+        .get_review(&review_id)
+        .map_err(|e| e.to_string())?
+        .and_then(|review| review.active_run_id)
+        .ok_or_else(|| "Review has no active run".to_string())?;
+    let run = db
+        .get_review_run_by_id(&run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review run not found".to_string())?;
+    let tasks = db.get_tasks_by_run(&run_id).map_err(|e| e.to_string())?;
+    let feedbacks = db
+        .get_feedback_by_review(&review_id)
+        .map_err(|e| e.to_string())?;
+    compute_file_review_status(&run, &tasks, &feedbacks).map_err(|e| e.to_string())
+}
+
+/// Ticks or unticks a file as viewed in a run. The tick is tied to the
+/// file's current hunks and drops once they change.
+#[tauri::command]
+pub fn set_file_viewed(
+    state: State<'_, AppState>,
+    run_id: String,
+    file_path: String,
+    viewed: bool,
+) -> Result<(), String> {
+    let db = state.database()?;
+    let run = db
+        .get_review_run_by_id(&run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review run not found".to_string())?;
+    let content_hash = cached_diff_index(&run.diff_text)
+        .map_err(|e| e.to_string())?
+        .file_content_hash(&file_path)
+        .ok_or_else(|| format!("File not in diff: {}", file_path))?;
+    db.run_repo()
+        .set_file_viewed(&run_id, &file_path, &content_hash, viewed)
+        .map_err(|e| e.to_string())
+}
+
+/// Files marked viewed in a run, including ticks from earlier runs of the
+/// review whose hunks haven't changed since.
+#[tauri::command]
+pub fn get_viewed_files(state: State<'_, AppState>, run_id: String) -> Result<Vec<String>, String> {
+    let db = state.database()?;
+    let run = db
+        .get_review_run_by_id(&run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review run not found".to_string())?;
+    let states = db
+        .run_repo()
+        .get_file_view_states(&run.review_id)
+        .map_err(|e| e.to_string())?;
+    viewed_files(&run, &states).map_err(|e| e.to_string())
+}
+
+/// Tool calls the agent made during a run.
+#[tauri::command]
+pub fn get_run_tool_calls(
+    state: State<'_, AppState>,
+    run_id: String,
+) -> Result<RunToolLog, String> {
+    let db = state.database()?;
+    db.run_repo()
+        .get_tool_log(&run_id)
+        .map_err(|e| e.to_string())
+}
+
+/// The agent's final plan for a run.
+#[tauri::command]
+pub fn get_run_plan(state: State<'_, AppState>, run_id: String) -> Result<Option<RunPlan>, String> {
+    let db = state.database()?;
+    db.run_repo().get_plan(&run_id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_linked_repos(state: State<'_, AppState>) -> Result<Vec<LinkedRepoState>, String> {
+    let db = state.database()?;
+    let mut repos = db.get_linked_repos().map_err(|e| e.to_string())?;
+    let watched = crate::infra::app_config::load_config().watched_repo_ids;
+    for repo in &mut repos {
+        repo.watched = watched.contains(&repo.id);
+    }
+    Ok(repos)
+}
+
+#[tauri::command]
+pub fn set_repo_snapshot_access(
+    state: State<'_, AppState>,
+    repo_id: String,
+    allowed: bool,
+) -> Result<(), String> {
+    let db = state.database()?;
+    db.repo_repo()
+        .update_snapshot_access(&repo_id, allowed)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Sets the agent and rule categories pre-selected when `repo_id` is chosen
+/// in the Generate view. `None`/empty clears them.
+#[tauri::command]
+pub fn set_repo_defaults(
+    state: State<'_, AppState>,
+    repo_id: String,
+    agent_id: Option<String>,
+    rule_categories: Option<Vec<String>>,
+) -> Result<(), String> {
+    let agent_id = agent_id.filter(|id| !id.trim().is_empty());
+    let rule_categories: Vec<String> = rule_categories
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+        .collect();
+    let db = state.database()?;
+    db.repo_repo()
+        .update_defaults(&repo_id, agent_id.as_deref(), &rule_categories)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn update_task_status(
+    state: State<'_, AppState>,
+    task_id: String,
+    status: String,
+) -> Result<(), String> {
+    let db = state.database()?;
+    let review_status = ReviewStatus::from_str(&status).unwrap_or(ReviewStatus::Todo);
+    db.update_task_status(&task_id, review_status)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Adds a reviewer-authored task to a run, e.g. a follow-up the agent didn't
+/// think of. Files that are part of the run's diff link to all of their hunks.
+#[tauri::command]
+pub fn create_task(
+    state: State<'_, AppState>,
+    run_id: String,
+    title: String,
+    description: String,
+    files: Vec<String>,
+    sub_flow: Option<String>,
+) -> Result<ReviewTask, String> {
+    let db = state.database()?;
+    let run = db
+        .get_review_run_by_id(&run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review run not found".to_string())?;
+
+    let mut task = ReviewTask {
+        id: Uuid::new_v4().to_string(),
+        run_id,
+        ai_generated: false,
+        ..Default::default()
+    };
+    fill_user_task(
+        &mut task,
+        &run.diff_text,
+        title,
+        description,
+        files,
+        sub_flow,
+    )?;
+    db.task_repo().save(&task).map_err(|e| e.to_string())?;
+    Ok(task)
+}
+
+/// Edits a task the reviewer created. Agent-generated tasks are read-only.
+#[tauri::command]
+pub fn update_task(
+    state: State<'_, AppState>,
+    task_id: String,
+    title: String,
+    description: String,
+    files: Vec<String>,
+    sub_flow: Option<String>,
+) -> Result<ReviewTask, String> {
+    let db = state.database()?;
+    let mut task = find_user_task(&db, &task_id)?;
+    let run = db
+        .get_review_run_by_id(&task.run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review run not found".to_string())?;
+
+    fill_user_task(
+        &mut task,
+        &run.diff_text,
+        title,
+        description,
+        files,
+        sub_flow,
+    )?;
+    db.task_repo().save(&task).map_err(|e| e.to_string())?;
+    Ok(task)
+}
+
+/// Deletes a task the reviewer created, along with its feedback.
+#[tauri::command]
+pub fn delete_task(state: State<'_, AppState>, task_id: String) -> Result<(), String> {
+    let db = state.database()?;
+    let task = find_user_task(&db, &task_id)?;
+    db.task_repo()
+        .delete_by_ids(&[task.id])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Merges tasks of a run that cover the same ground into the first of
+/// `task_ids`, moving the others' feedback onto it.
+#[tauri::command]
+pub fn merge_tasks(
+    state: State<'_, AppState>,
+    run_id: String,
+    task_ids: Vec<String>,
+) -> Result<ReviewTask, String> {
+    let db = state.database()?;
+    db.task_repo()
+        .merge_tasks(&run_id, &task_ids)
+        .map_err(|e| e.to_string())
+}
+
+fn find_user_task(db: &crate::infra::db::Database, task_id: &str) -> Result<ReviewTask, String> {
+    let task = db
+        .task_repo()
+        .find_by_id(&task_id.to_string())
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Task not found".to_string())?;
+    if task.ai_generated {
+        return Err("Only tasks you created can be edited or deleted".to_string());
+    }
+    Ok(task)
+}
+
+/// Validates and applies the user-editable fields of a task.
+fn fill_user_task(
+    task: &mut ReviewTask,
+    diff_text: &str,
+    title: String,
+    description: String,
+    files: Vec<String>,
+    sub_flow: Option<String>,
+) -> Result<(), String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err("Task title cannot be empty".to_string());
+    }
+
+    let mut unique_files: Vec<String> = Vec::new();
+    for file in files.iter().map(|f| f.trim()).filter(|f| !f.is_empty()) {
+        if !unique_files.iter().any(|existing| existing == file) {
+            unique_files.push(file.to_string());
+        }
+    }
+
+    let index = cached_diff_index(diff_text).ok();
+    task.diff_refs = unique_files
+        .iter()
+        .filter_map(|file| index.as_ref()?.file_diff_ref(file))
+        .collect();
+    if let Some(index) = &index
+        && let Ok((additions, deletions)) = index.task_stats(&task.diff_refs)
+    {
+        task.stats.additions = additions;
+        task.stats.deletions = deletions;
+    }
+
+    task.title = title.to_string();
+    task.description = description.trim().to_string();
+    task.files = unique_files;
+    task.sub_flow = sub_flow
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+    Ok(())
+}
+
+/// Renames a sub-flow across every task of a run, keeping its place in the
+/// saved order. `old` is `None` for the tasks that have no sub-flow. Renaming
+/// onto an existing sub-flow merges the two.
+#[tauri::command]
+pub fn rename_sub_flow(
+    state: State<'_, AppState>,
+    run_id: String,
+    old: Option<String>,
+    new: String,
+) -> Result<usize, String> {
+    let new = new.trim();
+    if new.is_empty() {
+        return Err("Sub-flow name cannot be empty".to_string());
+    }
+
+    let db = state.database()?;
+    let moved = db
+        .task_repo()
+        .rename_sub_flow(&run_id, old.as_deref(), new)
+        .map_err(|e| e.to_string())?;
+
+    let run_repo = db.run_repo();
+    let mut order = run_repo
+        .get_sub_flow_order(&run_id)
+        .map_err(|e| e.to_string())?;
+    if rename_in_order(&mut order, old.as_deref().unwrap_or_default(), new) {
+        run_repo
+            .set_sub_flow_order(&run_id, &order)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(moved)
+}
+
+/// Replaces `old` with `new` in a saved sub-flow order, dropping `old` when
+/// `new` is already listed. Returns whether the order changed.
+fn rename_in_order(order: &mut Vec<String>, old: &str, new: &str) -> bool {
+    let Some(index) = order.iter().position(|name| name == old) else {
+        return false;
+    };
+    if order.iter().any(|name| name == new) {
+        order.remove(index);
+    } else {
+        order[index] = new.to_string();
+    }
+    true
+}
+
+/// Moves a task to another sub-flow. A blank name takes it out of any
+/// sub-flow.
+#[tauri::command]
+pub fn set_task_sub_flow(
+    state: State<'_, AppState>,
+    task_id: String,
+    sub_flow: Option<String>,
+) -> Result<(), String> {
+    let sub_flow = sub_flow
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty());
+    let db = state.database()?;
+    db.task_repo()
+        .update_sub_flow(&task_id, sub_flow)
+        .map_err(|e| e.to_string())
+}
+
+/// Sub-flow names in the reviewer's order; `""` stands for tasks without a
+/// sub-flow.
+#[tauri::command]
+pub fn get_sub_flow_order(
+    state: State<'_, AppState>,
+    run_id: String,
+) -> Result<Vec<String>, String> {
+    let db = state.database()?;
+    db.run_repo()
+        .get_sub_flow_order(&run_id)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn set_sub_flow_order(
+    state: State<'_, AppState>,
+    run_id: String,
+    order: Vec<String>,
+) -> Result<(), String> {
+    let db = state.database()?;
+    db.run_repo()
+        .set_sub_flow_order(&run_id, &order)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn save_feedback(
+    state: State<'_, AppState>,
+    feedback: FeedbackInput,
+) -> Result<String, String> {
+    let db = state.database()?;
+    let id = Uuid::new_v4().to_string();
+
+    let anchor = if let (Some(file_path), Some(line_number), Some(side)) =
+        (feedback.file_path, feedback.line_number, feedback.side)
+    {
+        // The line refers to the diff of the run the reviewer is looking at.
+        let diff_hash = db
+            .get_review(&feedback.review_id)
+            .map_err(|e| e.to_string())?
+            .and_then(|review| review.active_run_id)
+            .map(|run_id| db.run_repo().find_by_id(&run_id))
+            .transpose()
+            .map_err(|e| e.to_string())?
+            .flatten()
+            .map(|run| run.diff_hash);
+        Some(FeedbackAnchor {
+            file_path: Some(file_path),
+            line_number: Some(line_number),
+            side: if side == "old" {
+                Some(FeedbackSide::Old)
+            } else {
+                Some(FeedbackSide::New)
+            },
+            hunk_ref: None,
+            head_sha: None,
+            diff_hash,
+            outdated: false,
+        })
+    } else {
+        None
+    };
+
+    let impact = FeedbackImpact::from_str(&feedback.impact).unwrap_or(FeedbackImpact::Nitpick);
+
+    let feedback_domain = Feedback {
+        id: id.clone(),
+        review_id: feedback.review_id,
+        task_id: feedback.task_id,
+        rule_id: feedback.rule_id,
+        finding_id: None,
+        category: None,
+        title: feedback.title,
+        status: ReviewStatus::Todo,
+        impact,
+        confidence: 1.0, // User-created feedback is high confidence
+        anchor,
+        suggested_replacement: None,
+        extra_anchors: Vec::new(),
+        rationale: None,
+        pinned: false,
+        author: current_author(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        updated_at: chrono::Utc::now().to_rfc3339(),
+    };
+
+    db.save_feedback(&feedback_domain, &id)
+        .map_err(|e| e.to_string())?;
+    Ok(id)
+}
+
+#[tauri::command]
+pub fn get_feedback_comments(
+    state: State<'_, AppState>,
+    feedback_id: String,
+) -> Result<Vec<Comment>, String> {
+    let db = state.database()?;
+    let comments = db
+        .get_comments_for_feedback(&feedback_id)
+        .map_err(|e| e.to_string())?;
+    Ok(comments)
+}
+
+#[tauri::command]
+pub fn add_comment(
+    state: State<'_, AppState>,
+    feedback_id: String,
+    body: String,
+) -> Result<String, String> {
+    let db = state.database()?;
+    let id = Uuid::new_v4().to_string();
+    let comment = Comment {
+        id: id.clone(),
+        feedback_id,
+        author: current_author(),
+        body,
+        parent_id: None,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        updated_at: chrono::Utc::now().to_rfc3339(),
+    };
+    db.save_comment(&comment).map_err(|e| e.to_string())?;
+    Ok(id)
+}
+
+/// Attachment metadata plus an inline `data:` URL for rendering thumbnails.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedbackAttachmentView {
+    pub id: String,
+    pub feedback_id: String,
+    pub mime: String,
+    pub byte_size: usize,
+    pub created_at: String,
+    pub data_url: String,
+}
+
+impl From<FeedbackAttachment> for FeedbackAttachmentView {
+    fn from(attachment: FeedbackAttachment) -> Self {
+        let data_url = attachment.data_url();
+        Self {
+            id: attachment.id,
+            feedback_id: attachment.feedback_id,
+            mime: attachment.mime,
+            byte_size: attachment.byte_size,
+            created_at: attachment.created_at,
+            data_url,
+        }
+    }
+}
+
+fn save_feedback_attachment(
+    state: &AppState,
+    feedback_id: String,
+    data: Vec<u8>,
+    mime: String,
+) -> Result<FeedbackAttachmentView, String> {
+    FeedbackAttachment::validate(&data, &mime)?;
+    let attachment = FeedbackAttachment {
+        id: Uuid::new_v4().to_string(),
+        feedback_id,
+        mime,
+        byte_size: data.len(),
+        data,
+        created_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let db = state.database()?;
+    db.attachment_repo()
+        .save(&attachment)
+        .map_err(|e| e.to_string())?;
+    Ok(attachment.into())
+}
+
+#[tauri::command]
+pub fn get_feedback_attachments(
+    state: State<'_, AppState>,
+    feedback_id: String,
+) -> Result<Vec<FeedbackAttachmentView>, String> {
+    let db = state.database()?;
+    let attachments = db
+        .attachment_repo()
+        .list_for_feedback(&feedback_id)
+        .map_err(|e| e.to_string())?;
+    Ok(attachments.into_iter().map(Into::into).collect())
+}
+
+#[tauri::command]
+pub fn add_feedback_attachment(
+    state: State<'_, AppState>,
+    feedback_id: String,
+    bytes: Vec<u8>,
+    mime: String,
+) -> Result<FeedbackAttachmentView, String> {
+    save_feedback_attachment(&state, feedback_id, bytes, mime)
+}
+
+/// Attaches the image currently on the system clipboard, encoded as PNG.
+///
+/// Used when the webview's paste event carries no image data, which is the
+/// case for screenshots on some Linux desktops.
+#[tauri::command]
+pub fn paste_clipboard_image_attachment(
+    state: State<'_, AppState>,
+    feedback_id: String,
+) -> Result<FeedbackAttachmentView, String> {
+    let mut clipboard = arboard::Clipboard::new().map_err(|e| e.to_string())?;
+    let image = clipboard
+        .get_image()
+        .map_err(|_| "The clipboard does not contain an image".to_string())?;
+    let buffer = image::RgbaImage::from_raw(
+        image.width as u32,
+        image.height as u32,
+        image.bytes.into_owned(),
+    )
+    .ok_or_else(|| "Clipboard image has an unexpected size".to_string())?;
+    let mut png = std::io::Cursor::new(Vec::new());
+    buffer
+        .write_to(&mut png, image::ImageFormat::Png)
+        .map_err(|e| e.to_string())?;
+    save_feedback_attachment(
+        &state,
+        feedback_id,
+        png.into_inner(),
+        "image/png".to_string(),
+    )
+}
+
+#[tauri::command]
+pub fn delete_feedback_attachment(
+    state: State<'_, AppState>,
+    attachment_id: String,
+) -> Result<(), String> {
+    let db = state.database()?;
+    db.attachment_repo()
+        .delete(&attachment_id)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn update_feedback_status(
+    state: State<'_, AppState>,
+    feedback_id: String,
+    status: String,
+) -> Result<(), String> {
+    let db = state.database()?;
+    let review_status = ReviewStatus::from_str(&status).unwrap_or(ReviewStatus::Todo);
+
+    // If status is being set to "ignored", record the rejection
+    if review_status == ReviewStatus::Ignored {
+        // Fetch feedback details for rejection tracking
+        // Only agent feedback teaches the agent anything; the reviewer
+        // dropping their own note isn't a rejection.
+        if let Ok(Some(feedback)) = db.feedback_repo().find_by_id(&feedback_id)
+            && crate::domain::AuthorKind::of(&feedback.author) == crate::domain::AuthorKind::Agent
+        {
+            let agent_id = crate::domain::agent_id_of(&feedback.author).unwrap_or(&feedback.author);
+            let rejection_repo = db.rejection_repo();
+
+            // Only record if not already recorded
+            if !rejection_repo
+                .rejection_exists(&feedback_id)
+                .unwrap_or(false)
+            {
+                // Extract file extension from anchor if available
+                let file_extension = feedback
+                    .anchor
+                    .as_ref()
+                    .and_then(|a| a.file_path.as_ref())
+                    .and_then(|p| {
+                        std::path::Path::new(p)
+                            .extension()
+                            .and_then(|e| e.to_str())
+                            .map(|s| s.to_string())
+                    });
+
+                let _ = rejection_repo.record_rejection(
+                    &feedback_id,
+                    &feedback.review_id,
+                    feedback.rule_id.as_deref(),
+                    agent_id,
+                    &feedback.impact.to_string(),
+                    feedback.confidence,
+                    file_extension.as_deref(),
+                    &feedback.title,
+                );
+            }
+        }
+    }
+
+    db.update_feedback_status(&feedback_id, review_status)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn update_feedback_impact(
+    state: State<'_, AppState>,
+    feedback_id: String,
+    impact: String,
+) -> Result<(), String> {
+    let db = state.database()?;
+    let feedback_impact = FeedbackImpact::from_str(&impact).unwrap_or(FeedbackImpact::Nitpick);
+    db.update_feedback_impact(&feedback_id, feedback_impact)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Pins the feedback to the top of its review, or unpins it. Returns
+/// whether it is pinned now.
+#[tauri::command]
+pub fn toggle_feedback_pin(
+    state: State<'_, AppState>,
+    feedback_id: String,
+) -> Result<bool, String> {
+    let db = state.database()?;
+    let feedback = db
+        .feedback_repo()
+        .find_by_id(&feedback_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Feedback not found".to_string())?;
+    let pinned = !feedback.pinned;
+    db.feedback_repo()
+        .set_pinned(&feedback_id, pinned)
+        .map_err(|e| e.to_string())?;
+    Ok(pinned)
+}
+
+#[tauri::command]
+pub fn delete_feedback(state: State<'_, AppState>, feedback_id: String) -> Result<(), String> {
+    let db = state.database()?;
+    db.delete_feedback(&feedback_id)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_review(state: State<'_, AppState>, review_id: String) -> Result<(), String> {
+    let db = state.database()?;
+    db.review_repo()
+        .delete(&review_id)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Folds `secondary_id` into `primary_id`: runs, tasks and feedback move to
+/// the primary and the secondary review is deleted.
+#[tauri::command]
+pub fn merge_reviews(
+    state: State<'_, AppState>,
+    primary_id: String,
+    secondary_id: String,
+) -> Result<ReviewMergeOutcome, String> {
+    let db = state.database()?;
+    db.review_repo()
+        .merge_into(&primary_id, &secondary_id)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_feedback_by_review(
+    state: State<'_, AppState>,
+    review_id: String,
+) -> Result<Vec<Feedback>, String> {
+    let db = state.database()?;
+    let feedbacks = db
+        .get_feedback_by_review(&review_id)
+        .map_err(|e| e.to_string())?;
+    Ok(feedbacks)
+}
+
+/// Anchors changed by [`reanchor_feedback`].
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ReanchorSummary {
+    pub moved: usize,
+    pub outdated: usize,
+}
+
+/// Re-maps feedback anchored on an earlier run's diff onto the review's active
+/// run, marking anchors whose line is gone as outdated.
+#[tauri::command]
+pub fn reanchor_feedback(
+    state: State<'_, AppState>,
+    review_id: String,
+) -> Result<ReanchorSummary, String> {
+    use crate::infra::diff::reanchor::{Reanchored, reanchor};
+
+    let db = state.database()?;
+    let review = db
+        .get_review(&review_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Review not found: {}", review_id))?;
+    let mut runs = db
+        .run_repo()
+        .find_by_review_id(&review_id)
+        .map_err(|e| e.to_string())?;
+    runs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    let Some(active) = runs
+        .iter()
+        .find(|run| Some(&run.id) == review.active_run_id.as_ref())
+    else {
+        return Ok(ReanchorSummary::default());
+    };
+
+    let indexes = runs
+        .iter()
+        .map(|run| cached_diff_index(&run.diff_text).map(|index| (run.diff_hash.as_str(), index)))
+        .collect::<anyhow::Result<Vec<_>>>()
+        .map_err(|e| e.to_string())?;
+    let sources: Vec<_> = indexes
+        .iter()
+        .map(|(hash, index)| (*hash, index.as_ref()))
+        .collect();
+    let target = cached_diff_index(&active.diff_text).map_err(|e| e.to_string())?;
+
+    let feedback_repo = db.feedback_repo();
+    let feedbacks = feedback_repo
+        .find_by_review(&review_id)
+        .map_err(|e| e.to_string())?;
+    let mut summary = ReanchorSummary::default();
+    for feedback in feedbacks {
+        let Some(anchor) = &feedback.anchor else {
+            continue;
+        };
+        let updated = match reanchor(anchor, &active.diff_hash, &target, &sources) {
+            // Still in place: record the diff so it isn't checked again.
+            Reanchored::Unchanged
+                if !anchor.outdated
+                    && anchor.line_number.is_some()
+                    && anchor.diff_hash.as_ref() != Some(&active.diff_hash) =>
+            {
+                FeedbackAnchor {
+                    diff_hash: Some(active.diff_hash.clone()),
+                    ..anchor.clone()
+                }
+            }
+            Reanchored::Unchanged => continue,
+            Reanchored::Moved(moved) => {
+                summary.moved += 1;
+                moved
+            }
+            Reanchored::Outdated => {
+                summary.outdated += 1;
+                FeedbackAnchor {
+                    outdated: true,
+                    ..anchor.clone()
+                }
+            }
+        };
+        feedback_repo
+            .update_anchor(&feedback.id, &updated)
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(summary)
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -969,49 +2430,69 @@ pub struct DiffSnippetLine {
     pub prefix: String,
     pub is_addition: bool,
     pub is_deletion: bool,
+    pub spans: Vec<crate::infra::syntax::HighlightSpan>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct FeedbackDiffSnippet {
     pub file_path: String,
+    pub language: String,
     pub hunk_header: String,
     pub lines: Vec<DiffSnippetLine>,
     pub highlighted_line: Option<u32>,
 }
 
+/// Rule that produced a finding. `None` for general findings and for rules
+/// that only existed for one run (e.g. `--rules` files) or were deleted since.
 #[tauri::command]
-pub fn get_feedback_diff_snippet(
+pub fn get_feedback_rule(
     state: State<'_, AppState>,
     feedback_id: String,
-    context_lines: u32,
-) -> Result<Option<FeedbackDiffSnippet>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-
+) -> Result<Option<ReviewRule>, String> {
+    let db = state.database()?;
     let feedback = db
         .feedback_repo()
         .find_by_id(&feedback_id)
         .map_err(|e| e.to_string())?
         .ok_or_else(|| "Feedback not found".to_string())?;
+    match feedback.rule_id {
+        Some(rule_id) => db
+            .rule_repo()
+            .find_by_id(&rule_id)
+            .map_err(|e| e.to_string()),
+        None => Ok(None),
+    }
+}
 
-    let anchor = match &feedback.anchor {
-        Some(a) => a,
-        None => return Ok(None),
-    };
+/// Highlights a markdown code fence; unknown languages come back as plain text.
+#[tauri::command]
+pub fn highlight_code(
+    code: String,
+    language: String,
+) -> Vec<Vec<crate::infra::syntax::HighlightSpan>> {
+    crate::infra::syntax::highlight_code(&code, &language)
+}
 
-    let file_path = match &anchor.file_path {
-        Some(f) => f.clone(),
-        None => return Ok(None),
-    };
+/// One snippet per location of the feedback, primary anchor first. Anchors
+/// outside the active run's diff are skipped.
+#[tauri::command]
+pub fn get_feedback_diff_snippet(
+    state: State<'_, AppState>,
+    feedback_id: String,
+    context_lines: u32,
+) -> Result<Vec<FeedbackDiffSnippet>, String> {
+    let context_lines = context_lines.min(MAX_FEEDBACK_CONTEXT_LINES);
+    let db = state.database()?;
 
-    let line_number = match anchor.line_number {
-        Some(l) => l,
-        None => return Ok(None),
-    };
+    let feedback = db
+        .feedback_repo()
+        .find_by_id(&feedback_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Feedback not found".to_string())?;
 
-    let side = match anchor.side {
-        Some(crate::domain::FeedbackSide::Old) => crate::domain::FeedbackSide::Old,
-        _ => crate::domain::FeedbackSide::New,
-    };
+    if feedback.anchor.is_none() {
+        return Ok(Vec::new());
+    }
 
     let review_id = &feedback.review_id;
     let review = db
@@ -1021,7 +2502,7 @@ pub fn get_feedback_diff_snippet(
 
     let active_run_id = match &review.active_run_id {
         Some(id) => id.clone(),
-        None => return Ok(None),
+        None => return Ok(Vec::new()),
     };
 
     let review_run = db
@@ -1029,14 +2510,29 @@ pub fn get_feedback_diff_snippet(
         .map_err(|e| e.to_string())?
         .ok_or_else(|| "Review run not found".to_string())?;
 
-    let diff_index = crate::infra::diff::index::DiffIndex::new(&review_run.diff_text)
-        .map_err(|e| e.to_string())?;
+    let diff_index = cached_diff_index(&review_run.diff_text).map_err(|e| e.to_string())?;
+
+    Ok(feedback
+        .anchors()
+        .filter_map(|anchor| anchor_diff_snippet(&diff_index, anchor, context_lines))
+        .collect())
+}
+
+fn anchor_diff_snippet(
+    diff_index: &crate::infra::diff::index::DiffIndex,
+    anchor: &FeedbackAnchor,
+    context_lines: u32,
+) -> Option<FeedbackDiffSnippet> {
+    let file_path = anchor.file_path.clone()?;
+    let line_number = anchor.line_number?;
 
-    let file_index = match diff_index.files.get(&file_path) {
-        Some(f) => f,
-        None => return Ok(None),
+    let side = match anchor.side {
+        Some(crate::domain::FeedbackSide::Old) => crate::domain::FeedbackSide::Old,
+        _ => crate::domain::FeedbackSide::New,
     };
 
+    let file_index = diff_index.files.get(&file_path)?;
+
     let mut target_hunk: Option<&crate::infra::diff::index::IndexedHunk> = None;
     for indexed_hunk in &file_index.all_hunks {
         let coords = indexed_hunk.coords;
@@ -1059,10 +2555,7 @@ pub fn get_feedback_diff_snippet(
         }
     }
 
-    let indexed_hunk = match target_hunk {
-        Some(h) => h,
-        None => return Ok(None),
-    };
+    let indexed_hunk = target_hunk?;
 
     let coords = indexed_hunk.coords;
     let hunk = &indexed_hunk.hunk;
@@ -1100,6 +2593,11 @@ pub fn get_feedback_diff_snippet(
 
     let coords_clone = coords;
     let mut current_line_in_hunk = 0;
+    // One highlighter per side, as in the diff view: deleted and added lines
+    // belong to different versions of the file, so a string or comment opened
+    // on one side mustn't carry over into the other.
+    let mut old_side = crate::infra::syntax::FileHighlighter::for_path(&file_path);
+    let mut new_side = crate::infra::syntax::FileHighlighter::for_path(&file_path);
     crate::infra::diff::index::DiffIndex::walk_hunk_lines(
         hunk,
         coords_clone,
@@ -1119,9 +2617,20 @@ pub fn get_feedback_diff_snippet(
                     crate::domain::FeedbackSide::New => new_num,
                 };
 
+                let content = line.value.trim_end().to_string();
+                let spans = if is_add {
+                    new_side.highlight_line(&content)
+                } else if is_del {
+                    old_side.highlight_line(&content)
+                } else {
+                    // Context lines are in both versions.
+                    old_side.highlight_line(&content);
+                    new_side.highlight_line(&content)
+                };
                 snippet_lines.push(DiffSnippetLine {
                     line_number: display_line_number.unwrap_or(0),
-                    content: line.value.trim_end().to_string(),
+                    spans,
+                    content,
                     prefix: prefix.to_string(),
                     is_addition: is_add,
                     is_deletion: is_del,
@@ -1136,21 +2645,137 @@ pub fn get_feedback_diff_snippet(
         crate::domain::FeedbackSide::New => anchor.line_number,
     };
 
-    Ok(Some(FeedbackDiffSnippet {
+    Some(FeedbackDiffSnippet {
+        language: crate::infra::syntax::language_for_path(&file_path),
         file_path,
         hunk_header,
         lines: snippet_lines,
         highlighted_line,
-    }))
+    })
+}
+
+/// Why the feedback was flagged. Uses the rationale the agent gave with it,
+/// or asks `agent_id` about just this feedback and keeps the answer.
+#[tauri::command]
+pub async fn explain_feedback(
+    state: State<'_, AppState>,
+    feedback_id: String,
+    agent_id: String,
+) -> Result<String, String> {
+    let input = {
+        let db = state.database()?;
+        let feedback = db
+            .feedback_repo()
+            .find_by_id(&feedback_id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Feedback not found".to_string())?;
+        if let Some(rationale) = feedback.rationale.filter(|r| !r.trim().is_empty()) {
+            return Ok(rationale);
+        }
+
+        let agent = list_agent_candidates()
+            .into_iter()
+            .find(|c| c.id == agent_id)
+            .ok_or_else(|| format!("Agent '{}' not found", agent_id))?;
+        let agent_command = agent.command.ok_or_else(|| {
+            format!(
+                "Agent '{}' is not available. Please configure it in settings.",
+                agent_id
+            )
+        })?;
+
+        let body = db
+            .get_comments_for_feedback(&feedback_id)
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .next()
+            .map(|comment| comment.body);
+        let snippet = feedback.anchor.as_ref().and_then(|anchor| {
+            let review = db.get_review(&feedback.review_id).ok()??;
+            let run = db
+                .get_review_run_by_id(review.active_run_id.as_ref()?)
+                .ok()??;
+            let diff_index = cached_diff_index(&run.diff_text).ok()?;
+            let snippet = anchor_diff_snippet(&diff_index, anchor, MAX_FEEDBACK_CONTEXT_LINES)?;
+            Some(snippet_as_text(&snippet))
+        });
+
+        crate::infra::acp::ExplainFeedbackInput {
+            title: feedback.title,
+            impact: feedback.impact.to_string(),
+            body,
+            file_path: feedback.anchor.as_ref().and_then(|a| a.file_path.clone()),
+            line_number: feedback.anchor.as_ref().and_then(|a| a.line_number),
+            snippet,
+            agent_command,
+            agent_args: agent.args,
+            timeout_secs: Some(120),
+        }
+    };
+
+    let rationale = crate::infra::acp::explain_feedback(input)
+        .await
+        .map_err(|e| format!("{:#}", e))?;
+
+    state
+        .database()?
+        .feedback_repo()
+        .update_rationale(&feedback_id, &rationale)
+        .map_err(|e| e.to_string())?;
+    Ok(rationale)
+}
+
+/// Unified diff text of `snippet`, its highlighted line marked with `>`.
+fn snippet_as_text(snippet: &FeedbackDiffSnippet) -> String {
+    let mut text = snippet.hunk_header.clone();
+    for line in &snippet.lines {
+        let marker = if Some(line.line_number) == snippet.highlighted_line {
+            ">"
+        } else {
+            ""
+        };
+        text.push_str(&format!("\n{}{}{}", marker, line.prefix, line.content));
+    }
+    text
 }
 
+/// Exports a review in `format`.
+///
+/// `"markdown"` returns the markdown itself. `"pdf"` writes the selected
+/// tasks and feedback (everything when no selection is given) to
+/// `output_path` and returns that path.
 #[tauri::command]
 pub fn export_review(
     state: State<'_, AppState>,
     review_id: String,
     format: String,
+    selected_tasks: Option<Vec<String>>,
+    selected_feedbacks: Option<Vec<String>>,
+    output_path: Option<String>,
 ) -> Result<String, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    if format == "pdf" {
+        let output_path = output_path.ok_or_else(|| "No output path given".to_string())?;
+        let data = load_export_data(&state, &review_id)?;
+        let options = ExportOptions {
+            include_summary: true,
+            include_stats: true,
+            include_metadata: true,
+            include_tasks: true,
+            include_feedbacks: true,
+            include_context_diff: true,
+            include_toc: false,
+            selected_tasks: selected_tasks.map(|ids| ids.into_iter().collect()),
+            selected_feedbacks: selected_feedbacks.map(|ids| ids.into_iter().collect()),
+            template: None,
+            diagram_files: false,
+        };
+        let bytes = crate::application::review::pdf::render_pdf(&data, &options)
+            .map_err(|e| e.to_string())?;
+        std::fs::write(&output_path, bytes).map_err(|e| e.to_string())?;
+        return Ok(output_path);
+    }
+
+    let db = state.database()?;
     let review = db
         .get_review(&review_id)
         .map_err(|e| e.to_string())?
@@ -1185,22 +2810,27 @@ fn generate_markdown_export(
 
     md.push_str("## Tasks\n\n");
     for task in tasks {
-        let status_icon = match task.status {
-            ReviewStatus::Todo => "[]",
-            ReviewStatus::InProgress => "[ ]",
-            ReviewStatus::Done => "[x]",
-            ReviewStatus::Ignored => "[-]",
+        // GitHub and GitLab only render `[ ]` and `[x]` as checkboxes, so an
+        // in-progress task stays unchecked and the status word says why.
+        let (checkbox, status) = match task.status {
+            ReviewStatus::Todo => ("[ ]", "Todo"),
+            ReviewStatus::InProgress => ("[ ]", "In progress"),
+            ReviewStatus::Done => ("[x]", "Done"),
+            ReviewStatus::Ignored => ("[-]", "Ignored"),
         };
         let risk = task.stats.risk.to_string();
-        md.push_str(&format!("{} **{}** ({})\n", status_icon, task.title, risk));
+        md.push_str(&format!(
+            "- {} **{}** ({}) — {}\n",
+            checkbox, task.title, risk, status
+        ));
         if !task.description.is_empty() {
             md.push_str(&format!(
-                "> {}\n",
+                "  > {}\n",
                 task.description
                     .lines()
                     .take(3)
                     .collect::<Vec<_>>()
-                    .join("\n> ")
+                    .join("\n  > ")
             ));
         }
         md.push('\n');
@@ -1227,13 +2857,60 @@ fn generate_markdown_export(
 
     Ok(md)
 }
+/// How long a remote review may take to download when no timeout is saved.
+const DEFAULT_PR_FETCH_TIMEOUT_SECS: u64 = 60;
+/// Lower bound on the saved timeout; shorter ones fail on ordinary networks.
+const MIN_PR_FETCH_TIMEOUT_SECS: u64 = 5;
+
+fn pr_fetch_timeout_secs() -> u64 {
+    crate::infra::app_config::load_config()
+        .pr_fetch_timeout_secs
+        .unwrap_or(DEFAULT_PR_FETCH_TIMEOUT_SECS)
+}
+
+#[tauri::command]
+pub fn get_pr_fetch_timeout() -> u64 {
+    pr_fetch_timeout_secs()
+}
+
+#[tauri::command]
+pub fn update_pr_fetch_timeout(secs: u64) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.pr_fetch_timeout_secs = Some(secs.max(MIN_PR_FETCH_TIMEOUT_SECS));
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+/// Stops the [`fetch_remote_pr`] in flight; it then fails as cancelled.
+#[tauri::command]
+pub fn cancel_pr_fetch(state: State<'_, AppState>) -> Result<(), String> {
+    if let Some(token) = state.pr_fetch.lock().map_err(|e| e.to_string())?.take() {
+        token.cancel();
+    }
+    Ok(())
+}
+
+/// Forwards the provider's "rate limited, retrying in Ns" messages to the
+/// UI as `lareview:vcs-rate-limited`.
+fn rate_limit_notice(app: &tauri::AppHandle) -> retry::Notice {
+    let app = app.clone();
+    Arc::new(move |message: String| {
+        use tauri::Emitter;
+
+        if let Err(err) = app.emit("lareview:vcs-rate-limited", &message) {
+            log::warn!("Failed to emit rate limit notice: {}", err);
+        }
+    })
+}
+
 #[tauri::command]
 pub async fn fetch_remote_pr(
-    _state: State<'_, AppState>,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
     pr_ref: String,
     provider_hint: Option<String>,
 ) -> Result<ParsedDiff, String> {
-    let registry = VcsRegistry::default();
+    let registry = VcsRegistry::from_config(&crate::infra::app_config::load_config());
     let provider = if let Some(hint) = provider_hint
         .as_ref()
         .map(|s| s.trim())
@@ -1253,18 +2930,258 @@ pub async fn fetch_remote_pr(
         .parse_ref(&pr_ref)
         .ok_or_else(|| format!("Invalid VCS reference: {}", pr_ref))?;
 
-    let data = provider
-        .fetch_pr(reference.as_ref())
-        .await
-        .map_err(|e| e.to_string())?;
+    // A newer fetch replaces this one, so cancel whatever was still running.
+    let cancel = CancellationToken::new();
+    if let Some(previous) = state
+        .pr_fetch
+        .lock()
+        .map_err(|e| e.to_string())?
+        .replace(cancel.clone())
+    {
+        previous.cancel();
+    }
+
+    let timeout_secs = pr_fetch_timeout_secs();
+    let data = tokio::select! {
+        _ = cancel.cancelled() => return Err("Fetch cancelled".to_string()),
+        result = tokio::time::timeout(
+            std::time::Duration::from_secs(timeout_secs),
+            retry::with_notices(rate_limit_notice(&app), provider.fetch_pr(reference.as_ref())),
+        ) => match result {
+            Ok(data) => data.map_err(|e| e.to_string())?,
+            Err(_) => {
+                return Err(format!(
+                    "Fetching {} timed out after {}s. Check your connection, or raise the timeout in Settings > VCS Integration.",
+                    pr_ref, timeout_secs
+                ));
+            }
+        },
+    };
 
-    let mut parsed = parse_diff(data.diff_text)?;
+    let mut parsed = parse_diff(data.diff_text, None).await?;
     parsed.title = Some(data.title.clone());
     parsed.source = Some(data.source);
+    parsed.pr_details = Some(data.details);
 
     Ok(parsed)
 }
 
+/// The provider hosting `repo`, from the first of its remotes one recognizes.
+fn remote_provider<'a>(
+    registry: &'a VcsRegistry,
+    repo: &DomainLinkedRepo,
+) -> Result<(&'a dyn VcsProvider, VcsRemoteRepo), String> {
+    repo.remotes
+        .iter()
+        .find_map(|remote| registry.provider_for_remote(remote))
+        .ok_or_else(|| {
+            format!(
+                "None of {}'s remotes is on GitHub, GitLab or a Gitea/Forgejo host",
+                repo.name
+            )
+        })
+}
+
+#[tauri::command]
+pub async fn list_open_prs(
+    state: State<'_, AppState>,
+    repo_id: String,
+) -> Result<Vec<VcsPrSummary>, String> {
+    let repo = find_linked_repo(&state, &repo_id)?;
+    let registry = VcsRegistry::from_config(&crate::infra::app_config::load_config());
+    let (provider, remote) = remote_provider(&registry, &repo)?;
+    provider
+        .list_open_prs(&remote)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Where one pull request of a batch started by [`generate_reviews_for_prs`]
+/// is at; emitted as `lareview:batch-review-progress`.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchReviewProgress {
+    pub repo_id: String,
+    pub number: u32,
+    pub title: String,
+    pub status: BatchReviewStatus,
+    pub review_id: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchReviewStatus {
+    Fetching,
+    /// The review is saved, queued or running.
+    Generating,
+    Done,
+    Failed,
+}
+
+/// Starts a review of each of `pr_numbers`, which must be open pull requests
+/// of the linked repo, and returns them. Reviews beyond the concurrency limit
+/// wait in the queue like any other; progress arrives as events.
+#[tauri::command]
+pub async fn generate_reviews_for_prs(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    repo_id: String,
+    pr_numbers: Vec<u32>,
+    agent_id: String,
+) -> Result<Vec<VcsPrSummary>, String> {
+    let repo = find_linked_repo(&state, &repo_id)?;
+    let registry = VcsRegistry::from_config(&crate::infra::app_config::load_config());
+    let (provider, remote) = remote_provider(&registry, &repo)?;
+    let prs: Vec<VcsPrSummary> = provider
+        .list_open_prs(&remote)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter(|pr| pr_numbers.contains(&pr.number))
+        .collect();
+    if let Some(missing) = pr_numbers
+        .iter()
+        .find(|number| !prs.iter().any(|pr| pr.number == **number))
+    {
+        return Err(format!(
+            "#{} isn't an open pull request of {}",
+            missing, repo.name
+        ));
+    }
+
+    spawn_batch_reviews(app, repo_id, agent_id, prs.clone());
+    Ok(prs)
+}
+
+fn emit_batch_progress(
+    app: &tauri::AppHandle,
+    repo_id: &str,
+    pr: &VcsPrSummary,
+    status: BatchReviewStatus,
+    review_id: Option<String>,
+    error: Option<String>,
+) {
+    use tauri::Emitter;
+
+    let progress = BatchReviewProgress {
+        repo_id: repo_id.to_string(),
+        number: pr.number,
+        title: pr.title.clone(),
+        status,
+        review_id,
+        error,
+    };
+    if let Err(err) = app.emit("lareview:batch-review-progress", &progress) {
+        log::warn!("Failed to emit batch review progress: {}", err);
+    }
+}
+
+/// Fetches `prs` one at a time, so a large batch doesn't fire every request
+/// at once, and starts each review as soon as its diff is in.
+fn spawn_batch_reviews(
+    app: tauri::AppHandle,
+    repo_id: String,
+    agent_id: String,
+    prs: Vec<VcsPrSummary>,
+) {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use tauri::Manager;
+
+    tauri::async_runtime::spawn(async move {
+        let registry = VcsRegistry::from_config(&crate::infra::app_config::load_config());
+        for pr in prs {
+            emit_batch_progress(&app, &repo_id, &pr, BatchReviewStatus::Fetching, None, None);
+            let data = match fetch_pr_data(&registry, &pr.url).await {
+                Ok(data) => data,
+                Err(err) => {
+                    emit_batch_progress(
+                        &app,
+                        &repo_id,
+                        &pr,
+                        BatchReviewStatus::Failed,
+                        None,
+                        Some(err),
+                    );
+                    continue;
+                }
+            };
+
+            let app = app.clone();
+            let repo_id = repo_id.clone();
+            let agent_id = agent_id.clone();
+            tauri::async_runtime::spawn(async move {
+                // The first progress message comes once the review is saved,
+                // which is when it shows up in the review list.
+                let announced = AtomicBool::new(false);
+                let on_progress = {
+                    let app = app.clone();
+                    let repo_id = repo_id.clone();
+                    let pr = pr.clone();
+                    Channel::new(move |_| {
+                        if !announced.swap(true, Ordering::SeqCst) {
+                            emit_batch_progress(
+                                &app,
+                                &repo_id,
+                                &pr,
+                                BatchReviewStatus::Generating,
+                                None,
+                                None,
+                            );
+                        }
+                        Ok(())
+                    })
+                };
+
+                let state = app.state::<AppState>();
+                let result = generate_review_inner(
+                    state.inner(),
+                    data.diff_text,
+                    agent_id,
+                    None,
+                    Some(repo_id.clone()),
+                    Some(data.source),
+                    false,
+                    on_progress,
+                    Vec::new(),
+                    None,
+                )
+                .await;
+                match result {
+                    Ok(generated) => emit_batch_progress(
+                        &app,
+                        &repo_id,
+                        &pr,
+                        BatchReviewStatus::Done,
+                        Some(generated.review_id),
+                        None,
+                    ),
+                    Err(err) => emit_batch_progress(
+                        &app,
+                        &repo_id,
+                        &pr,
+                        BatchReviewStatus::Failed,
+                        None,
+                        Some(err),
+                    ),
+                }
+            });
+        }
+    });
+}
+
+async fn fetch_pr_data(registry: &VcsRegistry, pr_ref: &str) -> Result<VcsPrData, String> {
+    let provider = registry
+        .detect_provider(pr_ref)
+        .ok_or_else(|| format!("Unsupported VCS reference: {}", pr_ref))?;
+    let reference = provider
+        .parse_ref(pr_ref)
+        .ok_or_else(|| format!("Invalid VCS reference: {}", pr_ref))?;
+    provider
+        .fetch_pr(reference.as_ref())
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn get_agents(_state: State<'_, AppState>) -> Result<Vec<AgentInfo>, String> {
     let candidates = crate::infra::acp::list_agent_candidates();
@@ -1284,6 +3201,8 @@ pub async fn get_agents(_state: State<'_, AppState>) -> Result<Vec<AgentInfo>, S
                 args: candidate.args,
                 logo: candidate.logo,
                 available: candidate.available,
+                expected_command: Some(candidate.expected_command),
+                install_command: candidate.install_command,
                 is_custom,
             }
         })
@@ -1292,6 +3211,18 @@ pub async fn get_agents(_state: State<'_, AppState>) -> Result<Vec<AgentInfo>, S
     Ok(agents)
 }
 
+/// Checks that an agent's binary exists and runs, so the UI can refuse to
+/// start a generation that would fail during setup.
+#[tauri::command]
+pub async fn probe_agent(agent_id: String) -> Result<AgentProbe, String> {
+    let candidate = list_agent_candidates()
+        .into_iter()
+        .find(|candidate| candidate.id == agent_id)
+        .ok_or_else(|| format!("Unknown agent '{}'", agent_id))?;
+    let command = candidate.command.unwrap_or_default();
+    Ok(crate::infra::acp::probe_agent_command(&command).await)
+}
+
 #[tauri::command]
 pub fn update_agent_config(
     _state: State<'_, AppState>,
@@ -1414,24 +3345,18 @@ pub fn set_github_token(_token: String) -> Result<(), String> {
 
 #[tauri::command]
 pub async fn get_vcs_status() -> Result<Vec<VcsStatus>, String> {
-    let registry = VcsRegistry::default();
-    let mut statuses = Vec::new();
-    for provider in registry.providers() {
-        let status = provider.get_status().await.map_err(|e| e.to_string())?;
-        statuses.push(status);
-    }
-    Ok(statuses)
+    let registry = VcsRegistry::from_config(&crate::infra::app_config::load_config());
+    Ok(registry.statuses(STATUS_TIMEOUT).await)
 }
 
 #[tauri::command]
 pub async fn get_single_vcs_status(provider_id: String) -> Result<VcsStatus, String> {
-    let registry = VcsRegistry::default();
+    let registry = VcsRegistry::from_config(&crate::infra::app_config::load_config());
     let provider = registry
         .get_provider(&provider_id)
         .ok_or_else(|| format!("Provider {} not found", provider_id))?;
 
-    let status = provider.get_status().await.map_err(|e| e.to_string())?;
-    Ok(status)
+    Ok(status_with_timeout(provider, STATUS_TIMEOUT).await)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1454,9 +3379,11 @@ fn link_repo_impl(state: &AppState, path: String) -> Result<LinkedRepo, String>
         remotes: detect_remotes(&path),
         created_at: linked_at.clone(),
         allow_snapshot_access: false,
+        default_agent_id: None,
+        default_rule_categories: vec![],
     };
 
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     db.repo_repo()
         .save(&domain_repo)
         .map_err(|e| e.to_string())?;
@@ -1504,7 +3431,7 @@ pub async fn clone_and_link_repo(
         ));
     }
 
-    let registry = VcsRegistry::default();
+    let registry = VcsRegistry::from_config(&crate::infra::app_config::load_config());
     let provider = registry
         .get_provider(&provider)
         .ok_or_else(|| format!("Unsupported provider: {}", request.provider))?;
@@ -1524,15 +3451,124 @@ pub async fn clone_and_link_repo(
 }
 
 #[tauri::command]
-pub fn unlink_repo(state: State<'_, AppState>, repo_id: String) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
-    db.repo_repo().delete(&repo_id).map_err(|e| e.to_string())?;
-    Ok(())
+pub fn unlink_repo(state: State<'_, AppState>, repo_id: String) -> Result<(), String> {
+    let db = state.database()?;
+    db.repo_repo().delete(&repo_id).map_err(|e| e.to_string())?;
+    drop(db);
+    watch_repo(state, repo_id, false)
+}
+
+/// Starts or stops polling `repo_id` for new commits. Each time its HEAD
+/// moves, `lareview:repo-head-changed` is emitted with a [`HeadChange`].
+///
+/// [`HeadChange`]: crate::infra::vcs::watch::HeadChange
+#[tauri::command]
+pub fn watch_repo(
+    state: State<'_, AppState>,
+    repo_id: String,
+    enabled: bool,
+) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+
+    let repo = enabled
+        .then(|| find_linked_repo(&state, &repo_id))
+        .transpose()?;
+    {
+        let mut watcher = state.repo_watcher.lock().map_err(|e| e.to_string())?;
+        match repo {
+            Some(repo) => watcher.watch(&repo.id, repo.path),
+            None => watcher.unwatch(&repo_id),
+        }
+    }
+
+    let mut config = load_config();
+    config.watched_repo_ids.retain(|id| id != &repo_id);
+    if enabled {
+        config.watched_repo_ids.push(repo_id);
+    }
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+/// Diff of the commits between `from` and `to` in a linked repo, for
+/// reviewing the changes a watched repo picked up.
+#[tauri::command]
+pub async fn get_repo_commit_diff(
+    state: State<'_, AppState>,
+    repo_id: String,
+    from: String,
+    to: String,
+) -> Result<String, String> {
+    let repo = find_linked_repo(&state, &repo_id)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        crate::infra::vcs::watch::commit_range_diff(&repo.path, &from, &to)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())
+}
+
+fn find_linked_repo(state: &AppState, repo_id: &str) -> Result<DomainLinkedRepo, String> {
+    let db = state.database()?;
+    db.repo_repo()
+        .find_all()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|repo| repo.id == repo_id)
+        .ok_or_else(|| format!("Repository {} not found", repo_id))
+}
+
+/// Polls watched repos for new commits until the app exits.
+pub fn spawn_repo_watch_loop(app: tauri::AppHandle) {
+    use crate::infra::vcs::watch::{DEFAULT_WATCH_INTERVAL_SECS, read_head};
+    use tauri::{Emitter, Manager};
+
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let interval = crate::infra::app_config::load_config()
+                .repo_watch_interval_secs
+                .unwrap_or(DEFAULT_WATCH_INTERVAL_SECS);
+            tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+
+            let state = app.state::<AppState>();
+            let targets = match state.repo_watcher.lock() {
+                Ok(watcher) => watcher.targets(),
+                Err(_) => continue,
+            };
+            if targets.is_empty() {
+                continue;
+            }
+
+            let heads = tauri::async_runtime::spawn_blocking(move || {
+                targets
+                    .into_iter()
+                    .map(|(repo_id, path)| (repo_id, read_head(&path)))
+                    .collect::<Vec<_>>()
+            })
+            .await
+            .unwrap_or_default();
+
+            let changes: Vec<_> = match state.repo_watcher.lock() {
+                Ok(mut watcher) => heads
+                    .into_iter()
+                    .filter_map(|(repo_id, head)| watcher.record(&repo_id, head))
+                    .collect(),
+                Err(_) => continue,
+            };
+            for change in changes {
+                if let Err(err) = app.emit("lareview:repo-head-changed", &change) {
+                    log::warn!("Failed to emit repo head change: {}", err);
+                }
+            }
+        }
+    });
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReviewGenerationResult {
     pub task_count: usize,
+    /// Feedback the agent left; a run with feedback but no tasks is still a review.
+    #[serde(default)]
+    pub feedback_count: usize,
     pub review_id: String,
     pub run_id: Option<String>,
 }
@@ -1561,6 +3597,12 @@ pub struct AgentInfo {
     pub args: Vec<String>,
     pub logo: Option<String>,
     pub available: bool,
+    /// Command looked up on PATH, for hints when the agent isn't found.
+    #[serde(default)]
+    pub expected_command: Option<String>,
+    /// Homebrew command that installs the agent, when there is one.
+    #[serde(default)]
+    pub install_command: Option<String>,
     #[serde(default)]
     pub is_custom: bool,
 }
@@ -1606,19 +3648,42 @@ pub struct ReviewState {
     pub source: crate::domain::ReviewSource,
     pub status: String,
     #[serde(default)]
+    pub active_run_id: Option<String>,
+    #[serde(default)]
     pub active_run_status: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Overall decision picked for the review, if any.
+    #[serde(default)]
+    pub decision: Option<ReviewDecision>,
+    /// Tags derived from `source` (provider, owner, repo) offered in the UI.
+    #[serde(default)]
+    pub suggested_tags: Vec<String>,
+    /// Linked repo whose remote matches `source`, if any.
+    #[serde(default)]
+    pub repo_id: Option<String>,
+    #[serde(default)]
+    pub repo_name: Option<String>,
 }
 
+/// A run as listed in a review's history. The diff is left out to keep
+/// listings small; [`get_run_diff`] loads it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReviewRunState {
     pub id: String,
     pub review_id: String,
     pub agent_id: String,
     pub input_ref: String,
-    pub diff_text: String,
+    pub diff_hash: String,
     pub status: String,
     pub created_at: String,
     pub task_count: usize,
+    /// The agent's final plan, when it reported one.
+    #[serde(default)]
+    pub plan: Option<RunPlan>,
+    /// Model the agent reported, when it said.
+    #[serde(default)]
+    pub agent_model: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1630,6 +3695,11 @@ pub struct LinkedRepoState {
     pub linked_at: String,
     pub remotes: Vec<String>,
     pub allow_snapshot_access: bool,
+    pub default_agent_id: Option<String>,
+    pub default_rule_categories: Vec<String>,
+    /// Whether the repo's HEAD is polled for new commits.
+    #[serde(default)]
+    pub watched: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1645,99 +3715,483 @@ pub struct EditorConfig {
 }
 
 #[tauri::command]
-pub async fn get_available_editors() -> Vec<EditorCandidate> {
-    crate::infra::editor::list_available_editors()
-        .into_iter()
-        .map(|e| EditorCandidate {
-            id: e.id.to_string(),
-            label: e.label.to_string(),
-            path: e.path.to_string_lossy().to_string(),
-        })
-        .collect()
+pub async fn get_available_editors() -> Vec<EditorCandidate> {
+    crate::infra::editor::list_available_editors()
+        .into_iter()
+        .map(|e| EditorCandidate {
+            id: e.id.to_string(),
+            label: e.label.to_string(),
+            path: e.path.to_string_lossy().to_string(),
+        })
+        .collect()
+}
+
+fn detect_remotes(path: &str) -> Vec<String> {
+    use std::process::Command;
+    let output = Command::new("git")
+        .args(["remote", "-v"])
+        .current_dir(path)
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let mut remotes = std::collections::HashSet::new();
+            for line in stdout.lines() {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                if parts.len() >= 2 {
+                    remotes.insert(parts[1].to_string());
+                }
+            }
+            remotes.into_iter().collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[tauri::command]
+pub fn get_editor_config() -> EditorConfig {
+    use crate::infra::app_config::load_config;
+    let config = load_config();
+    EditorConfig {
+        preferred_editor_id: config.preferred_editor_id,
+    }
+}
+
+#[tauri::command]
+pub fn update_editor_config(editor_id: String) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.preferred_editor_id = Some(editor_id);
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedbackFilterConfig {
+    pub confidence_threshold: Option<f64>,
+}
+
+#[tauri::command]
+pub fn get_feedback_filter_config() -> FeedbackFilterConfig {
+    use crate::infra::app_config::load_config;
+    let config = load_config();
+    FeedbackFilterConfig {
+        confidence_threshold: config.feedback_confidence_threshold,
+    }
+}
+
+#[tauri::command]
+pub fn update_feedback_filter_config(threshold: Option<f64>) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    // Clamp to valid range
+    config.feedback_confidence_threshold = threshold.map(|t| t.clamp(0.0, 1.0));
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeoutConfig {
+    pub timeout_secs: Option<u64>,
+}
+
+#[tauri::command]
+pub fn get_timeout_config() -> TimeoutConfig {
+    use crate::infra::app_config::load_config;
+    let config = load_config();
+    TimeoutConfig {
+        timeout_secs: config.review_timeout_secs,
+    }
+}
+
+#[tauri::command]
+pub fn update_timeout_config(timeout_secs: Option<u64>) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.review_timeout_secs = timeout_secs.map(|t| t.clamp(60, 7200));
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_offline_mode() -> bool {
+    crate::infra::app_config::load_config().offline
+}
+
+#[tauri::command]
+pub fn update_offline_mode(offline: bool) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.offline = offline;
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_completion_notifications() -> bool {
+    crate::infra::app_config::load_config().notify_on_completion
+}
+
+#[tauri::command]
+pub fn update_completion_notifications(enabled: bool) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.notify_on_completion = enabled;
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_rejection_examples() -> bool {
+    crate::infra::app_config::load_config().include_rejection_examples
+}
+
+#[tauri::command]
+pub fn update_rejection_examples(enabled: bool) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.include_rejection_examples = enabled;
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_generated_file_patterns() -> Vec<String> {
+    crate::infra::diff::generated::configured_patterns()
+}
+
+/// Saves the generated-file patterns; `None` restores the built-in list.
+#[tauri::command]
+pub fn update_generated_file_patterns(patterns: Option<Vec<String>>) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.generated_file_patterns = patterns.map(|patterns| {
+        patterns
+            .into_iter()
+            .map(|pattern| pattern.trim().to_string())
+            .filter(|pattern| !pattern.is_empty())
+            .collect()
+    });
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_github_enterprise_hosts() -> Vec<String> {
+    crate::infra::app_config::load_config().github_enterprise_hosts
+}
+
+/// Saves the GitHub Enterprise hosts to recognize besides those `gh` knows.
+#[tauri::command]
+pub fn update_github_enterprise_hosts(hosts: Vec<String>) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.github_enterprise_hosts = hosts
+        .into_iter()
+        .map(|host| host.trim().to_string())
+        .filter(|host| !host.is_empty())
+        .collect();
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+/// The `gh`/`glab` executable and default host picked for a VCS provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VcsCliConfig {
+    pub path: Option<String>,
+    pub default_host: Option<String>,
+}
+
+#[tauri::command]
+pub fn get_vcs_cli_config(provider_id: String) -> VcsCliConfig {
+    let config = crate::infra::app_config::load_config();
+    VcsCliConfig {
+        path: config.vcs_path_overrides.get(&provider_id).cloned(),
+        default_host: config.vcs_default_hosts.get(&provider_id).cloned(),
+    }
+}
+
+/// Saves the CLI path and default host for a VCS provider; empty values go
+/// back to auto-detection.
+#[tauri::command]
+pub fn update_vcs_cli_config(
+    provider_id: String,
+    path: Option<String>,
+    default_host: Option<String>,
+) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    let set = |map: &mut std::collections::HashMap<String, String>, value: Option<String>| {
+        match value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+        {
+            Some(value) => map.insert(provider_id.clone(), value),
+            None => map.remove(&provider_id),
+        };
+    };
+    set(&mut config.vcs_path_overrides, path);
+    set(&mut config.vcs_default_hosts, default_host);
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+/// Saves the Gitea/Forgejo API token for `host` in the OS keychain; an empty
+/// token removes it.
+#[tauri::command]
+pub fn set_gitea_token(host: String, token: Option<String>) -> Result<(), String> {
+    let host = host
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/')
+        .to_string();
+    if host.is_empty() {
+        return Err("Enter the Gitea/Forgejo host first".to_string());
+    }
+    crate::infra::vcs::gitea::save_token(&host, token.as_deref()).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoRefreshConfig {
+    pub auto_refresh: bool,
+    pub refresh_interval: Option<u32>,
+}
+
+#[tauri::command]
+pub fn get_auto_refresh_config() -> AutoRefreshConfig {
+    use crate::infra::app_config::load_config;
+    let config = load_config();
+    AutoRefreshConfig {
+        auto_refresh: config.auto_refresh,
+        refresh_interval: config.refresh_interval,
+    }
+}
+
+#[tauri::command]
+pub fn update_auto_refresh_config(
+    auto_refresh: bool,
+    refresh_interval: Option<u32>,
+) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.auto_refresh = auto_refresh;
+    config.refresh_interval = refresh_interval.map(|secs| secs.clamp(5, 3600));
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_repo_watch_interval() -> Option<u64> {
+    crate::infra::app_config::load_config().repo_watch_interval_secs
+}
+
+#[tauri::command]
+pub fn update_repo_watch_interval(interval_secs: Option<u64>) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.repo_watch_interval_secs = interval_secs.map(|secs| secs.clamp(5, 3600));
+    save_config(&config).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GenerationConcurrency {
+    pub limit: usize,
+    pub default_limit: usize,
+    pub max_limit: usize,
+    pub running: usize,
+    pub queued: usize,
+}
+
+#[tauri::command]
+pub fn get_generation_concurrency(state: State<'_, AppState>) -> GenerationConcurrency {
+    let active = state.active_runs.lock().unwrap();
+    let queued = active
+        .values()
+        .filter(|run| run.status == ReviewRunStatus::Queued)
+        .count();
+    GenerationConcurrency {
+        limit: state.generation_limiter.limit(),
+        default_limit: crate::infra::acp::default_generation_limit(),
+        max_limit: crate::infra::acp::MAX_GENERATION_LIMIT,
+        running: active.len() - queued,
+        queued,
+    }
+}
+
+#[tauri::command]
+pub fn update_generation_concurrency(
+    state: State<'_, AppState>,
+    limit: Option<usize>,
+) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let limit = limit.map(|limit| limit.clamp(1, crate::infra::acp::MAX_GENERATION_LIMIT));
+    let mut config = load_config();
+    config.max_concurrent_generations = limit;
+    save_config(&config).map_err(|e| e.to_string())?;
+    state
+        .generation_limiter
+        .set_limit(limit.unwrap_or_else(crate::infra::acp::default_generation_limit));
+    Ok(())
+}
+
+/// Changed lines up to which a diff defaults to a quick review.
+fn quick_review_max_lines() -> u32 {
+    crate::infra::app_config::load_config()
+        .quick_review_max_lines
+        .unwrap_or(crate::domain::DEFAULT_QUICK_REVIEW_MAX_LINES)
+}
+
+#[tauri::command]
+pub fn get_quick_review_max_lines() -> u32 {
+    quick_review_max_lines()
+}
+
+#[tauri::command]
+pub fn update_quick_review_max_lines(lines: u32) -> Result<(), String> {
+    use crate::infra::app_config::{load_config, save_config};
+    let mut config = load_config();
+    config.quick_review_max_lines = Some(lines);
+    save_config(&config).map_err(|e| e.to_string())
 }
 
-fn detect_remotes(path: &str) -> Vec<String> {
-    use std::process::Command;
-    let output = Command::new("git")
-        .args(["remote", "-v"])
-        .current_dir(path)
-        .output();
+/// Bounds for the UI zoom; outside them the layout stops being usable.
+const MIN_UI_SCALE: f64 = 0.75;
+const MAX_UI_SCALE: f64 = 2.0;
 
-    match output {
-        Ok(output) if output.status.success() => {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let mut remotes = std::collections::HashSet::new();
-            for line in stdout.lines() {
-                let parts: Vec<&str> = line.split_whitespace().collect();
-                if parts.len() >= 2 {
-                    remotes.insert(parts[1].to_string());
-                }
-            }
-            remotes.into_iter().collect()
-        }
-        _ => Vec::new(),
-    }
+#[derive(Debug, Clone, Serialize)]
+pub struct AppearanceConfig {
+    pub ui_scale: f64,
+    pub high_contrast: bool,
 }
 
 #[tauri::command]
-pub fn get_editor_config() -> EditorConfig {
-    use crate::infra::app_config::load_config;
-    let config = load_config();
-    EditorConfig {
-        preferred_editor_id: config.preferred_editor_id,
+pub fn get_appearance_config() -> AppearanceConfig {
+    let config = crate::infra::app_config::load_config();
+    AppearanceConfig {
+        ui_scale: config.ui_scale.unwrap_or(1.0),
+        high_contrast: config.high_contrast,
     }
 }
 
+/// Saves the appearance settings and rescales every open window. The theme is
+/// applied by the frontend.
 #[tauri::command]
-pub fn update_editor_config(editor_id: String) -> Result<(), String> {
+pub fn update_appearance_config(
+    app: tauri::AppHandle,
+    ui_scale: f64,
+    high_contrast: bool,
+) -> Result<(), String> {
     use crate::infra::app_config::{load_config, save_config};
+    use tauri::{Emitter, Manager};
     let mut config = load_config();
-    config.preferred_editor_id = Some(editor_id);
-    save_config(&config).map_err(|e| e.to_string())
+    config.ui_scale = Some(ui_scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE));
+    config.high_contrast = high_contrast;
+    save_config(&config).map_err(|e| e.to_string())?;
+    for window in app.webview_windows().values() {
+        apply_ui_scale(window);
+    }
+    // Other windows reload the theme from this.
+    if let Err(err) = app.emit("lareview:appearance-changed", ()) {
+        log::warn!("Failed to emit appearance change: {}", err);
+    }
+    Ok(())
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FeedbackFilterConfig {
-    pub confidence_threshold: Option<f64>,
+/// Zooms `window` to the configured UI scale.
+pub fn apply_ui_scale(window: &tauri::WebviewWindow) {
+    let scale = crate::infra::app_config::load_config()
+        .ui_scale
+        .unwrap_or(1.0)
+        .clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+    if let Err(e) = window.set_zoom(scale) {
+        log::warn!("Failed to apply UI scale to {}: {}", window.label(), e);
+    }
 }
 
 #[tauri::command]
-pub fn get_feedback_filter_config() -> FeedbackFilterConfig {
-    use crate::infra::app_config::load_config;
-    let config = load_config();
-    FeedbackFilterConfig {
-        confidence_threshold: config.feedback_confidence_threshold,
-    }
+pub fn get_language() -> Option<String> {
+    crate::infra::app_config::load_config().language
 }
 
+/// Saves the UI language; `None` or a blank code follows the system language.
 #[tauri::command]
-pub fn update_feedback_filter_config(threshold: Option<f64>) -> Result<(), String> {
+pub fn update_language(app: tauri::AppHandle, language: Option<String>) -> Result<(), String> {
     use crate::infra::app_config::{load_config, save_config};
+    use tauri::Emitter;
     let mut config = load_config();
-    // Clamp to valid range
-    config.feedback_confidence_threshold = threshold.map(|t| t.clamp(0.0, 1.0));
-    save_config(&config).map_err(|e| e.to_string())
+    config.language = language
+        .map(|code| code.trim().to_string())
+        .filter(|code| !code.is_empty());
+    save_config(&config).map_err(|e| e.to_string())?;
+    // Other windows reload their strings from this.
+    if let Err(err) = app.emit("lareview:appearance-changed", ()) {
+        log::warn!("Failed to emit appearance change: {}", err);
+    }
+    Ok(())
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TimeoutConfig {
-    pub timeout_secs: Option<u64>,
+/// JSON Schemas of the MCP tools agents report a review through
+/// (`return_task`, `finalize_review`, `add_feedback`, `report_issue_check`),
+/// keyed by tool name. For authors of third-party agents.
+#[tauri::command]
+pub fn get_agent_tool_schemas() -> serde_json::Value {
+    crate::infra::acp::agent_tool_schemas()
+}
+
+/// Context lines shown around a feedback's anchor when no preference is saved.
+const DEFAULT_FEEDBACK_CONTEXT_LINES: u32 = 3;
+/// Upper bound on context lines around a feedback's anchor.
+const MAX_FEEDBACK_CONTEXT_LINES: u32 = 20;
+
+/// Author for feedback and comments the reviewer writes.
+fn current_author() -> String {
+    crate::infra::identity::resolve(
+        crate::infra::app_config::load_config()
+            .user_identity
+            .as_ref(),
+    )
+    .author()
 }
 
 #[tauri::command]
-pub fn get_timeout_config() -> TimeoutConfig {
-    use crate::infra::app_config::load_config;
-    let config = load_config();
-    TimeoutConfig {
-        timeout_secs: config.review_timeout_secs,
-    }
+pub fn get_user_identity() -> crate::infra::identity::UserIdentity {
+    crate::infra::identity::resolve(
+        crate::infra::app_config::load_config()
+            .user_identity
+            .as_ref(),
+    )
 }
 
+/// Sets the name (and optional email) attached to feedback and comments; an
+/// empty name goes back to git's `user.name` or the OS user name.
 #[tauri::command]
-pub fn update_timeout_config(timeout_secs: Option<u64>) -> Result<(), String> {
+pub fn update_user_identity(
+    name: String,
+    email: Option<String>,
+) -> Result<crate::infra::identity::UserIdentity, String> {
+    use crate::infra::app_config::{UserIdentityConfig, load_config, save_config};
+    let mut config = load_config();
+    config.user_identity = if name.trim().is_empty() {
+        None
+    } else {
+        Some(UserIdentityConfig {
+            name: crate::infra::identity::validate_name(&name)?,
+            email: email
+                .map(|email| email.trim().to_string())
+                .filter(|email| !email.is_empty()),
+        })
+    };
+    save_config(&config).map_err(|e| e.to_string())?;
+    Ok(crate::infra::identity::resolve(
+        config.user_identity.as_ref(),
+    ))
+}
+
+#[tauri::command]
+pub fn get_feedback_context_lines() -> u32 {
+    crate::infra::app_config::load_config()
+        .feedback_context_lines
+        .unwrap_or(DEFAULT_FEEDBACK_CONTEXT_LINES)
+        .min(MAX_FEEDBACK_CONTEXT_LINES)
+}
+
+#[tauri::command]
+pub fn update_feedback_context_lines(lines: u32) -> Result<(), String> {
     use crate::infra::app_config::{load_config, save_config};
     let mut config = load_config();
-    config.review_timeout_secs = timeout_secs.map(|t| t.clamp(60, 7200));
+    config.feedback_context_lines = Some(lines.min(MAX_FEEDBACK_CONTEXT_LINES));
     save_config(&config).map_err(|e| e.to_string())
 }
 
@@ -1753,7 +4207,7 @@ pub struct ReviewRuleInput {
 
 #[tauri::command]
 pub fn get_review_rules(state: State<'_, AppState>) -> Result<Vec<ReviewRule>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     db.rule_repo().list_all().map_err(|e| e.to_string())
 }
 
@@ -1765,7 +4219,7 @@ pub fn create_review_rule(
     let now = chrono::Utc::now().to_rfc3339();
     let rule_id = Uuid::new_v4().to_string();
     let rule = build_review_rule(rule_id, now.clone(), now, input)?;
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     db.rule_repo().save(&rule).map_err(|e| e.to_string())?;
     Ok(rule)
 }
@@ -1776,7 +4230,7 @@ pub fn update_review_rule(
     id: String,
     input: ReviewRuleInput,
 ) -> Result<ReviewRule, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     let existing = db
         .rule_repo()
         .find_by_id(&id)
@@ -1790,7 +4244,7 @@ pub fn update_review_rule(
 
 #[tauri::command]
 pub fn delete_review_rule(state: State<'_, AppState>, id: String) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     db.rule_repo().delete(&id).map_err(|e| e.to_string())?;
     Ok(())
 }
@@ -1857,6 +4311,105 @@ fn build_review_rule(
     })
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCheckDefinitionInput {
+    pub name: String,
+    pub category: String,
+    pub prompt: String,
+    pub enabled: bool,
+}
+
+#[tauri::command]
+pub fn get_issue_check_definitions(
+    state: State<'_, AppState>,
+) -> Result<Vec<IssueCheckDefinition>, String> {
+    let db = state.database()?;
+    db.issue_check_definition_repo()
+        .list_all()
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn create_issue_check_definition(
+    state: State<'_, AppState>,
+    input: IssueCheckDefinitionInput,
+) -> Result<IssueCheckDefinition, String> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let definition =
+        build_issue_check_definition(Uuid::new_v4().to_string(), now.clone(), now, input)?;
+    let db = state.database()?;
+    db.issue_check_definition_repo()
+        .save(&definition)
+        .map_err(|e| e.to_string())?;
+    Ok(definition)
+}
+
+#[tauri::command]
+pub fn update_issue_check_definition(
+    state: State<'_, AppState>,
+    id: String,
+    input: IssueCheckDefinitionInput,
+) -> Result<IssueCheckDefinition, String> {
+    let db = state.database()?;
+    let existing = db
+        .issue_check_definition_repo()
+        .find_by_id(&id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Issue check not found".to_string())?;
+    let now = chrono::Utc::now().to_rfc3339();
+    let definition = build_issue_check_definition(id, existing.created_at, now, input)?;
+    db.issue_check_definition_repo()
+        .save(&definition)
+        .map_err(|e| e.to_string())?;
+    Ok(definition)
+}
+
+#[tauri::command]
+pub fn delete_issue_check_definition(state: State<'_, AppState>, id: String) -> Result<(), String> {
+    let db = state.database()?;
+    db.issue_check_definition_repo()
+        .delete(&id)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+fn build_issue_check_definition(
+    id: String,
+    created_at: String,
+    updated_at: String,
+    input: IssueCheckDefinitionInput,
+) -> Result<IssueCheckDefinition, String> {
+    let name = input.name.trim().to_string();
+    if name.is_empty() {
+        return Err("Check name cannot be empty".to_string());
+    }
+    let prompt = input.prompt.trim().to_string();
+    if prompt.is_empty() {
+        return Err("Check prompt cannot be empty".to_string());
+    }
+    // Category IDs are what the agent reports back, so keep them slug-shaped.
+    let category = input
+        .category
+        .trim()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-");
+    if category.is_empty() {
+        return Err("Check category cannot be empty".to_string());
+    }
+
+    Ok(IssueCheckDefinition {
+        id,
+        name,
+        category,
+        prompt,
+        enabled: input.enabled,
+        created_at,
+        updated_at,
+    })
+}
+
 #[tauri::command]
 pub fn open_in_editor(
     file_path: String,
@@ -1900,15 +4453,13 @@ pub fn open_in_editor(
 }
 
 /// Gets the local repository path for a given review by matching the review's source
-/// (GitHub PR or GitLab MR) to a linked local repository via remote URLs.
+/// (GitHub PR/compare or GitLab MR/compare) to a linked local repository via remote URLs.
 #[tauri::command]
 pub fn get_repo_root_for_review(
     state: State<'_, AppState>,
     review_id: String,
 ) -> Result<Option<String>, String> {
-    use crate::domain::ReviewSource;
-
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
 
     // Get the review to access its source
     let review = db
@@ -1916,76 +4467,20 @@ pub fn get_repo_root_for_review(
         .map_err(|e| e.to_string())?
         .ok_or_else(|| format!("Review not found: {}", review_id))?;
 
-    // Build the expected remote URL pattern based on source type
-    let expected_patterns: Vec<String> = match &review.source {
-        ReviewSource::GitHubPr { owner, repo, .. } => {
-            vec![
-                format!("github.com/{}/{}", owner, repo),
-                format!("github.com:{}/{}", owner, repo),
-            ]
-        }
-        ReviewSource::GitLabMr {
-            host, project_path, ..
-        } => {
-            vec![
-                format!("{}/{}", host, project_path),
-                format!("{}:{}", host, project_path),
-            ]
-        }
-
-        ReviewSource::DiffPaste { .. } => {
-            // For pasted diffs, we can't auto-match to a repo
-            return Ok(None);
-        }
-    };
-
-    // Get all linked repos and search for a match
-    let repos = db.get_linked_repos().map_err(|e| e.to_string())?;
-    for repo in repos {
-        for remote in &repo.remotes {
-            // Normalize the remote URL for comparison
-            let remote_lower = remote.to_lowercase();
-            for pattern in &expected_patterns {
-                if remote_lower.contains(&pattern.to_lowercase()) {
-                    return Ok(Some(repo.path));
-                }
-            }
-        }
+    // For pasted diffs, we can't auto-match to a repo
+    if review.source.remote_patterns().is_empty() {
+        return Ok(None);
     }
 
-    Ok(None)
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ParsedDiff {
-    pub diff_text: String,
-    pub total_additions: usize,
-    pub total_deletions: usize,
-    pub hunk_manifest: String,
-    #[serde(default)]
-    pub files: Vec<ParsedDiffFile>,
-    #[serde(default)]
-    pub source: Option<ReviewSource>,
-    #[serde(default)]
-    pub title: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ParsedDiffFile {
-    pub name: String,
-    pub old_path: String,
-    pub new_path: String,
-    pub hunks: Vec<ParsedHunk>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ParsedHunk {
-    pub old_start: u32,
-    pub old_lines: u32,
-    pub new_start: u32,
-    pub new_lines: u32,
-    #[serde(default)]
-    pub content: String,
+    let repos = db.get_linked_repos().map_err(|e| e.to_string())?;
+    Ok(repos
+        .into_iter()
+        .find(|repo| {
+            repo.remotes
+                .iter()
+                .any(|remote| review.source.matches_remote(remote))
+        })
+        .map(|repo| repo.path))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1996,67 +4491,105 @@ pub struct LinkedRepo {
     pub linked_at: String,
 }
 
-#[tauri::command]
-pub async fn export_review_markdown(
-    state: State<'_, AppState>,
-    review_id: String,
-    selected_tasks: Vec<String>,
-    selected_feedbacks: Vec<String>,
-) -> Result<String, String> {
-    let data = {
-        let db = state.db.lock().map_err(|e| e.to_string())?;
+/// Loads a review's active run with its tasks, non-ignored feedback, comments
+/// and merge confidence, ready for export.
+fn load_export_data(state: &AppState, review_id: &str) -> Result<ExportData, String> {
+    let db = state.database()?;
 
-        let review = db
-            .get_review(&review_id)
-            .map_err(|e| e.to_string())?
-            .ok_or_else(|| "Review not found".to_string())?;
+    let review = db
+        .get_review(review_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review not found".to_string())?;
 
-        let active_run_id = review
-            .active_run_id
-            .clone()
-            .ok_or_else(|| "Review has no active run".to_string())?;
+    let active_run_id = review
+        .active_run_id
+        .clone()
+        .ok_or_else(|| "Review has no active run".to_string())?;
 
-        let run = db
-            .get_review_run_by_id(&active_run_id)
-            .map_err(|e| e.to_string())?
-            .ok_or_else(|| "Review run not found".to_string())?;
+    let run = db
+        .get_review_run_by_id(&active_run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review run not found".to_string())?;
 
-        let tasks = db
-            .get_tasks_by_run(&active_run_id)
+    let tasks = db
+        .get_tasks_by_run(&active_run_id)
+        .map_err(|e| e.to_string())?;
+
+    let mut feedbacks = db
+        .get_feedback_by_review(review_id)
+        .map_err(|e| e.to_string())?
+        // Filter out ignored feedbacks from export
+        .into_iter()
+        .filter(|f| f.status != ReviewStatus::Ignored)
+        .collect::<Vec<_>>();
+    sort_by_severity(&mut feedbacks);
+
+    let mut comments = Vec::new();
+    let mut attachments = Vec::new();
+    for f in &feedbacks {
+        let f_comments = db
+            .get_comments_for_feedback(&f.id)
             .map_err(|e| e.to_string())?;
+        comments.extend(f_comments);
+        attachments.extend(
+            db.attachment_repo()
+                .list_for_feedback(&f.id)
+                .map_err(|e| e.to_string())?,
+        );
+    }
 
-        let feedbacks = db
-            .get_feedback_by_review(&review_id)
+    let rule_ids: std::collections::HashSet<&str> = feedbacks
+        .iter()
+        .filter_map(|f| f.rule_id.as_deref())
+        .collect();
+    let mut rules = Vec::new();
+    for rule_id in rule_ids {
+        if let Some(rule) = db
+            .rule_repo()
+            .find_by_id(rule_id)
             .map_err(|e| e.to_string())?
-            // Filter out ignored feedbacks from export
-            .into_iter()
-            .filter(|f| f.status != ReviewStatus::Ignored)
-            .collect::<Vec<_>>();
-
-        let mut comments = Vec::new();
-        for f in &feedbacks {
-            let f_comments = db
-                .get_comments_for_feedback(&f.id)
-                .map_err(|e| e.to_string())?;
-            comments.extend(f_comments);
+        {
+            rules.push(rule);
         }
+    }
 
-        // Fetch merge confidence
-        let merge_confidence = db
-            .merge_confidence_repo()
-            .find_by_run_id(&active_run_id)
-            .ok()
-            .flatten();
+    // Fetch merge confidence
+    let merge_confidence = db
+        .merge_confidence_repo()
+        .find_by_run_id(&active_run_id)
+        .ok()
+        .flatten();
 
-        ExportData {
-            review,
-            run,
-            tasks,
-            feedbacks,
-            comments,
-            merge_confidence,
-        }
-    };
+    let checklist = db
+        .checklist_repo()
+        .find_by_review(review_id)
+        .map_err(|e| e.to_string())?;
+
+    Ok(ExportData {
+        review,
+        run,
+        tasks,
+        feedbacks,
+        comments,
+        attachments,
+        rules,
+        merge_confidence,
+        checklist,
+    })
+}
+
+/// Renders the selected tasks and feedback as Markdown and returns it. With an
+/// `output_path`, writes it there instead, with each task diagram's source
+/// saved next to it under `diagrams/`, and returns the path.
+#[tauri::command]
+pub async fn export_review_markdown(
+    state: State<'_, AppState>,
+    review_id: String,
+    selected_tasks: Vec<String>,
+    selected_feedbacks: Vec<String>,
+    output_path: Option<String>,
+) -> Result<String, String> {
+    let data = load_export_data(&state, &review_id)?;
 
     let options = ExportOptions {
         include_summary: true,
@@ -2068,24 +4601,93 @@ pub async fn export_review_markdown(
         include_toc: true,
         selected_tasks: Some(selected_tasks.into_iter().collect()),
         selected_feedbacks: Some(selected_feedbacks.into_iter().collect()),
+        template: crate::application::review::export::load_user_template(),
+        diagram_files: output_path.is_some(),
     };
 
     let result = ReviewExporter::export_to_markdown(&data, &options)
         .await
         .map_err(|e| e.to_string())?;
 
-    Ok(result.markdown)
+    let Some(output_path) = output_path else {
+        return Ok(result.markdown);
+    };
+    let dir = std::path::Path::new(&output_path)
+        .parent()
+        .unwrap_or(std::path::Path::new("."));
+    for (name, bytes) in &result.assets {
+        let asset_path = dir.join(name);
+        if let Some(parent) = asset_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(&asset_path, bytes)
+            .map_err(|e| format!("Failed to write {}: {}", asset_path.display(), e))?;
+    }
+    std::fs::write(&output_path, &result.markdown)
+        .map_err(|e| format!("Failed to write {}: {}", output_path, e))?;
+    Ok(output_path)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GhReviewExport {
+    /// Pretty-printed request body for `gh api --input`.
+    pub json: String,
+    pub endpoint: Option<String>,
+    pub skipped: Vec<crate::application::review::gh_review::SkippedGhComment>,
+    /// Where the JSON was written, when an output path was given.
+    pub path: Option<String>,
+}
+
+/// Exports the selected tasks and feedback as the JSON body GitHub's "create
+/// a review" endpoint expects, without calling the API. Comments whose line
+/// isn't in the PR diff are left out and listed in `skipped`.
+#[tauri::command]
+pub fn export_review_gh_payload(
+    state: State<'_, AppState>,
+    review_id: String,
+    selected_tasks: Vec<String>,
+    selected_feedbacks: Vec<String>,
+    output_path: Option<String>,
+) -> Result<GhReviewExport, String> {
+    let data = load_export_data(&state, &review_id)?;
+    let options = ExportOptions {
+        selected_tasks: Some(selected_tasks.into_iter().collect()),
+        selected_feedbacks: Some(selected_feedbacks.into_iter().collect()),
+        ..Default::default()
+    };
+    let bundle = crate::application::review::gh_review::render_gh_review(&data, &options);
+    let json = serde_json::to_string_pretty(&bundle.payload).map_err(|e| e.to_string())?;
+
+    if let Some(path) = &output_path {
+        std::fs::write(path, &json).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    }
+
+    Ok(GhReviewExport {
+        json,
+        endpoint: bundle.endpoint,
+        skipped: bundle.skipped,
+        path: output_path,
+    })
+}
+
+/// Where `push_remote_review` posted the review, and how its feedback was
+/// fitted onto the pull request's current diff.
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoteReviewPush {
+    pub url: String,
+    pub anchoring: crate::application::review::remote_anchors::RemoteAnchoring,
 }
 
 #[tauri::command]
 pub async fn push_remote_review(
+    app: tauri::AppHandle,
     state: State<'_, AppState>,
     review_id: String,
     selected_tasks: Vec<String>,
     selected_feedbacks: Vec<String>,
-) -> Result<String, String> {
+) -> Result<RemoteReviewPush, String> {
     let data = {
-        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let db = state.database()?;
 
         let review = db
             .get_review(&review_id)
@@ -2129,10 +4731,30 @@ pub async fn push_remote_review(
             .ok()
             .flatten();
 
-        (review, run, tasks, feedbacks, comments, merge_confidence)
+        let checklist = db
+            .checklist_repo()
+            .find_by_review(&review_id)
+            .map_err(|e| e.to_string())?;
+
+        (
+            review,
+            run,
+            tasks,
+            feedbacks,
+            comments,
+            merge_confidence,
+            checklist,
+        )
     };
 
-    let request = ReviewPushRequest {
+    let review_id = data.0.id.clone();
+    let pushed_feedbacks = selected_feedbacks.clone();
+    let decision = data.0.effective_decision(&data.3, &data.6);
+    // The UI warns before this; the push still goes out as chosen.
+    if let Some(mismatch) = decision.mismatch(&data.3, &data.6) {
+        log::warn!("Review {}: {}", review_id, mismatch);
+    }
+    let mut request = ReviewPushRequest {
         review: data.0,
         run: data.1,
         tasks: data.2,
@@ -2141,31 +4763,114 @@ pub async fn push_remote_review(
         selected_tasks,
         selected_feedbacks,
         merge_confidence: data.5,
+        decision,
     };
 
     let provider_id = request
         .review
         .source
         .provider_id()
-        .ok_or_else(|| "Review has no remote provider".to_string())?;
-    let registry = VcsRegistry::default();
+        .ok_or_else(|| "Review has no remote provider".to_string())?
+        .to_string();
+    let registry = VcsRegistry::from_config(&crate::infra::app_config::load_config());
+    let anchoring = fit_push_to_remote(&registry, &mut request).await;
     let provider = registry
-        .get_provider(provider_id)
+        .get_provider(&provider_id)
         .ok_or_else(|| format!("Unsupported VCS provider: {}", provider_id))?;
 
-    provider
-        .push_review(request)
+    let url = retry::with_notices(rate_limit_notice(&app), provider.push_review(request))
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    crate::infra::automation::emit(
+        pushed_feedbacks
+            .into_iter()
+            .map(|feedback_id| AutomationEvent::FeedbackPushed {
+                review_id: review_id.clone(),
+                feedback_id,
+                url: url.clone(),
+            })
+            .collect(),
+    );
+    Ok(RemoteReviewPush { url, anchoring })
+}
+
+/// Moves the pushed feedback onto the pull request's current diff, which
+/// differs from the run's once commits were added or force-pushed. Feedback
+/// whose line is gone goes into the review summary instead of inline. When the
+/// current diff can't be fetched the run's diff is used as before, and nothing
+/// is reported.
+async fn fit_push_to_remote(
+    registry: &VcsRegistry,
+    request: &mut ReviewPushRequest,
+) -> crate::application::review::remote_anchors::RemoteAnchoring {
+    use crate::application::review::remote_anchors::{
+        RemoteAnchoring, fit_to_remote, unplaced_section,
+    };
+
+    let Some(url) = request.review.source.url() else {
+        return RemoteAnchoring::default();
+    };
+    let remote = match fetch_pr_data(registry, &url).await {
+        Ok(remote) => remote,
+        Err(err) => {
+            log::warn!(
+                "Could not fetch the current diff of {url}; pushing against the reviewed diff: {err}"
+            );
+            return RemoteAnchoring::default();
+        }
+    };
+    let outcome = match fit_to_remote(
+        &request.run.diff_text,
+        &request.run.diff_hash,
+        &remote.diff_text,
+        &mut request.feedbacks,
+        &request.selected_feedbacks,
+    ) {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            log::warn!("Could not map feedback onto the current diff of {url}: {err}");
+            return RemoteAnchoring::default();
+        }
+    };
+
+    if outcome.moved > 0 {
+        log::info!(
+            "{} feedback item(s) moved to their new lines in {url}",
+            outcome.moved
+        );
+    }
+    if !outcome.unplaced.is_empty() {
+        log::warn!(
+            "{} feedback item(s) no longer match a line in {url}; posting them in the summary",
+            outcome.unplaced.len()
+        );
+        let section = unplaced_section(&request.feedbacks, &request.comments, &outcome.unplaced);
+        request
+            .review
+            .summary
+            .get_or_insert_with(String::new)
+            .push_str(&section);
+        request
+            .selected_feedbacks
+            .retain(|id| !outcome.unplaced.contains(id));
+    }
+    request.run.diff_hash = crate::infra::hash::hash_diff(&remote.diff_text);
+    request.run.diff_text = remote.diff_text;
+    if remote.source.provider_id() == request.review.source.provider_id() {
+        // Newer head and base commits, for providers that anchor on them.
+        request.review.source = remote.source;
+    }
+    outcome
 }
 
 #[tauri::command]
 pub async fn push_remote_feedback(
+    app: tauri::AppHandle,
     state: State<'_, AppState>,
     feedback_id: String,
 ) -> Result<String, String> {
     let (feedback, review, review_run, comments) = {
-        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let db = state.database()?;
 
         let feedback = db
             .feedback_repo()
@@ -2195,6 +4900,7 @@ pub async fn push_remote_feedback(
         (feedback, review, review_run, comments)
     };
 
+    let review_id = review.id.clone();
     let request = FeedbackPushRequest {
         review,
         run: review_run,
@@ -2207,15 +4913,20 @@ pub async fn push_remote_feedback(
         .source
         .provider_id()
         .ok_or_else(|| "Review has no remote provider".to_string())?;
-    let registry = VcsRegistry::default();
+    let registry = VcsRegistry::from_config(&crate::infra::app_config::load_config());
     let provider = registry
         .get_provider(provider_id)
         .ok_or_else(|| format!("Unsupported VCS provider: {}", provider_id))?;
 
-    provider
-        .push_feedback(request)
+    let url = retry::with_notices(rate_limit_notice(&app), provider.push_feedback(request))
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    crate::infra::automation::emit(vec![AutomationEvent::FeedbackPushed {
+        review_id,
+        feedback_id,
+        url: url.clone(),
+    }]);
+    Ok(url)
 }
 
 #[tauri::command]
@@ -2225,6 +4936,65 @@ pub fn copy_to_clipboard(text: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Markdown for a single task, for "Copy as markdown".
+#[tauri::command]
+pub fn get_task_markdown(state: State<'_, AppState>, task_id: String) -> Result<String, String> {
+    let db = state.database()?;
+    let task = db
+        .task_repo()
+        .find_by_id(&task_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Task not found".to_string())?;
+    let run = db
+        .get_review_run_by_id(&task.run_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review run not found".to_string())?;
+    let review = db
+        .get_review(&run.review_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review not found".to_string())?;
+    Ok(ReviewExporter::render_task_item_markdown(
+        &task,
+        &review.source,
+    ))
+}
+
+/// Markdown for a single finding, for "Copy as markdown".
+#[tauri::command]
+pub fn get_feedback_markdown(
+    state: State<'_, AppState>,
+    feedback_id: String,
+) -> Result<String, String> {
+    let db = state.database()?;
+    let feedback = db
+        .feedback_repo()
+        .find_by_id(&feedback_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Feedback not found".to_string())?;
+    let review = db
+        .get_review(&feedback.review_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Review not found".to_string())?;
+    let comments = db
+        .get_comments_for_feedback(&feedback_id)
+        .map_err(|e| e.to_string())?;
+    let rules = match &feedback.rule_id {
+        Some(rule_id) => db
+            .rule_repo()
+            .find_by_id(rule_id)
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .collect(),
+        None => vec![],
+    };
+    Ok(ReviewExporter::render_feedback_item_markdown(
+        &feedback,
+        &comments,
+        &rules,
+        &review.source,
+    ))
+}
+
 #[tauri::command]
 pub fn get_pending_review_from_state(
     state: State<'_, AppState>,
@@ -2244,6 +5014,137 @@ pub fn get_pending_review_from_state(
     }))
 }
 
+/// Entry in the Generate view's "Recent" menu; the diff itself is only sent
+/// by [`restore_pending_diff`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentPendingDiff {
+    pub id: String,
+    pub source: String,
+    pub repo_root: Option<String>,
+    pub agent: Option<String>,
+    pub created_at: String,
+    pub review_source: Option<ReviewSource>,
+    pub files: usize,
+}
+
+#[tauri::command]
+pub fn get_recent_pending_diffs(
+    state: State<'_, AppState>,
+) -> Result<Vec<RecentPendingDiff>, String> {
+    let db = state.database()?;
+    let records = db
+        .pending_diff_repo()
+        .list_recent()
+        .map_err(|e| e.to_string())?;
+    Ok(records
+        .into_iter()
+        .map(|record| RecentPendingDiff {
+            files: record
+                .diff
+                .lines()
+                .filter(|line| line.starts_with("diff --git "))
+                .count(),
+            id: record.id,
+            source: record.source,
+            repo_root: record.repo_root,
+            agent: record.agent,
+            created_at: record.created_at,
+            review_source: record.review_source,
+        })
+        .collect())
+}
+
+/// Makes the recent pending diff `id` the pending diff again and moves it to
+/// the top of the list.
+#[tauri::command]
+pub fn restore_pending_diff(
+    state: State<'_, AppState>,
+    id: String,
+) -> Result<PendingReviewState, String> {
+    let db = state.database()?;
+    let record = db
+        .pending_diff_repo()
+        .find_by_id(&id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Pending diff {} not found", id))?;
+
+    let pending = PendingDiff {
+        diff: record.diff,
+        repo_root: record.repo_root.as_ref().map(std::path::PathBuf::from),
+        agent: record.agent,
+        source: record.source,
+        created_at: chrono::Utc::now(),
+    };
+    let id = state
+        .set_pending_diff(pending.clone(), record.review_source.clone())
+        .unwrap_or(record.id);
+
+    Ok(PendingReviewState {
+        id,
+        diff: pending.diff,
+        repo_root: record.repo_root,
+        agent: pending.agent,
+        source: pending.source,
+        created_at: pending.created_at.to_rfc3339(),
+        review_source: record.review_source,
+    })
+}
+
+/// Opens the debug log written for `run_id` and returns its path.
+///
+/// Logs only exist for runs generated with ACP debugging enabled
+/// (`RUST_LOG` containing `acp`).
+#[tauri::command]
+pub fn open_run_log(run_id: String) -> Result<String, String> {
+    let path = crate::infra::acp::find_run_log(&run_id).ok_or_else(|| {
+        format!(
+            "No log found for run {}. Run logs are written when RUST_LOG includes \"acp\".",
+            run_id
+        )
+    })?;
+    let path = path.to_string_lossy().to_string();
+    open_url(path.clone())?;
+    Ok(path)
+}
+
+/// Label of the window the diff is detached into.
+pub const DIFF_WINDOW_LABEL: &str = "diff";
+
+/// Opens the diff in a window of its own, or focuses it if it's already open.
+/// The window follows the main window's review and task; closing it hands
+/// focus back to the main window.
+#[tauri::command]
+pub async fn open_diff_window(app: tauri::AppHandle) -> Result<(), String> {
+    use tauri::Manager;
+
+    if let Some(window) = app.get_webview_window(DIFF_WINDOW_LABEL) {
+        let _ = window.unminimize();
+        return window.set_focus().map_err(|e| e.to_string());
+    }
+
+    let window = tauri::WebviewWindowBuilder::new(
+        &app,
+        DIFF_WINDOW_LABEL,
+        tauri::WebviewUrl::App("index.html".into()),
+    )
+    .title("LaReview - Diff")
+    .inner_size(1200.0, 800.0)
+    .min_inner_size(600.0, 400.0)
+    .build()
+    .map_err(|e| e.to_string())?;
+    apply_ui_scale(&window);
+
+    let handle = app.clone();
+    window.on_window_event(move |event| {
+        if let tauri::WindowEvent::Destroyed = event
+            && let Some(main) = handle.get_webview_window("main")
+        {
+            let _ = main.set_focus();
+        }
+    });
+    Ok(())
+}
+
 #[tauri::command]
 pub fn open_url(url: String) -> Result<(), String> {
     use crate::infra::platform::{Platform, current_platform};
@@ -2316,6 +5217,107 @@ pub fn get_diff_request(state: State<'_, AppState>) -> Result<Option<DiffRequest
     }))
 }
 
+/// Fetches the diff behind a PR/MR or compare URL, with the review source to
+/// record for it. Offline mode refuses before any provider CLI runs, metadata
+/// lookups included.
+fn acquire_remote_diff(
+    runtime: &tokio::runtime::Handle,
+    remote_ref: crate::infra::cli::diff::RemoteRef,
+    from: &str,
+) -> Result<(String, ReviewSource), String> {
+    let config = crate::infra::app_config::load_config();
+    if config.offline {
+        return Err(crate::infra::vcs::offline::OFFLINE_MODE_ERROR.to_string());
+    }
+
+    match remote_ref {
+        crate::infra::cli::diff::RemoteRef::GitHub {
+            host,
+            owner,
+            repo,
+            number,
+        } => {
+            let pr_url = crate::infra::vcs::github::pr_url(&host, &owner, &repo, number);
+            let source = ReviewSource::GitHubPr {
+                owner: owner.clone(),
+                repo: repo.clone(),
+                number,
+                url: Some(pr_url),
+                head_sha: None,
+                base_sha: None,
+            };
+
+            let diff = crate::infra::cli::diff::acquire_diff_with(
+                runtime,
+                crate::infra::cli::diff::DiffSource::GitHubPr {
+                    host,
+                    owner,
+                    repo,
+                    number,
+                },
+            )
+            .map_err(|e| e.to_string())?;
+            Ok((diff, source))
+        }
+        crate::infra::cli::diff::RemoteRef::GitLab {
+            host,
+            project_path,
+            number,
+        } => {
+            let url = format!("https://{host}/{project_path}/-/merge_requests/{number}");
+            let mr_ref = crate::infra::vcs::gitlab::GitLabMrRef {
+                host: host.clone(),
+                project_path: project_path.clone(),
+                number,
+                url: url.clone(),
+            };
+            let metadata = crate::block_on_handle(
+                runtime,
+                crate::infra::vcs::gitlab::fetch_mr_metadata(&mr_ref),
+            )
+            .ok();
+
+            let source = ReviewSource::GitLabMr {
+                host: host.clone(),
+                project_path: project_path.clone(),
+                number,
+                url: Some(metadata.as_ref().map(|m| m.url.clone()).unwrap_or(url)),
+                head_sha: metadata.as_ref().and_then(|m| m.head_sha.clone()),
+                base_sha: metadata.as_ref().and_then(|m| m.base_sha.clone()),
+                start_sha: metadata.as_ref().and_then(|m| m.start_sha.clone()),
+            };
+
+            let diff = crate::infra::cli::diff::acquire_diff_with(
+                runtime,
+                crate::infra::cli::diff::DiffSource::GitLabMr {
+                    host,
+                    project_path,
+                    number,
+                },
+            )
+            .map_err(|e| e.to_string())?;
+            Ok((diff, source))
+        }
+        crate::infra::cli::diff::RemoteRef::GitHubCompare(_)
+        | crate::infra::cli::diff::RemoteRef::GitLabCompare(_) => {
+            // The provider resolves both refs to SHAs.
+            let registry = VcsRegistry::from_config(&config);
+            let provider = registry
+                .detect_provider(from)
+                .ok_or_else(|| format!("Unsupported compare URL: {}", from))?;
+            let reference = provider
+                .parse_ref(from)
+                .ok_or_else(|| format!("Invalid compare URL: {}", from))?;
+            let data = crate::block_on_handle(runtime, provider.fetch_pr(reference.as_ref()))
+                .map_err(|e| e.to_string())?;
+            if data.diff_text.trim().is_empty() {
+                return Err(format!("No changes in {}.", data.title));
+            }
+            Ok((data.diff_text, data.source))
+        }
+    }
+}
+
 #[tauri::command]
 pub fn acquire_diff_from_request(state: State<'_, AppState>) -> Result<PendingReviewState, String> {
     let diff_request = {
@@ -2329,86 +5331,37 @@ pub fn acquire_diff_from_request(state: State<'_, AppState>) -> Result<PendingRe
 
     let diff = if let Ok(remote_ref) = crate::infra::cli::diff::parse_remote_ref(&diff_request.from)
     {
-        match remote_ref {
-            crate::infra::cli::diff::RemoteRef::GitHub {
-                owner,
-                repo,
-                number,
-            } => {
-                let pr_url = format!("https://github.com/{}/{}/pull/{}", owner, repo, number);
-                review_source = Some(ReviewSource::GitHubPr {
-                    owner: owner.clone(),
-                    repo: repo.clone(),
-                    number,
-                    url: Some(pr_url),
-                    head_sha: None,
-                    base_sha: None,
-                });
-
-                crate::infra::cli::diff::acquire_diff(
-                    crate::infra::cli::diff::DiffSource::GitHubPr {
-                        owner,
-                        repo,
-                        number,
-                    },
-                )
-                .map_err(|e| e.to_string())?
-            }
-            crate::infra::cli::diff::RemoteRef::GitLab {
-                host,
-                project_path,
-                number,
-            } => {
-                let url = format!("https://{host}/{project_path}/-/merge_requests/{number}");
-                let mr_ref = crate::infra::vcs::gitlab::GitLabMrRef {
-                    host: host.clone(),
-                    project_path: project_path.clone(),
-                    number,
-                    url: url.clone(),
-                };
-                let metadata = tauri::async_runtime::block_on(
-                    crate::infra::vcs::gitlab::fetch_mr_metadata(&mr_ref),
-                )
-                .ok();
-
-                review_source = Some(ReviewSource::GitLabMr {
-                    host: host.clone(),
-                    project_path: project_path.clone(),
-                    number,
-                    url: Some(metadata.as_ref().map(|m| m.url.clone()).unwrap_or(url)),
-                    head_sha: metadata.as_ref().and_then(|m| m.head_sha.clone()),
-                    base_sha: metadata.as_ref().and_then(|m| m.base_sha.clone()),
-                    start_sha: metadata.as_ref().and_then(|m| m.start_sha.clone()),
-                });
-
-                crate::infra::cli::diff::acquire_diff(
-                    crate::infra::cli::diff::DiffSource::GitLabMr {
-                        host,
-                        project_path,
-                        number,
-                    },
-                )
-                .map_err(|e| e.to_string())?
-            }
-        }
+        let (diff, source) = acquire_remote_diff(&state.runtime, remote_ref, &diff_request.from)?;
+        review_source = Some(source);
+        diff
     } else if diff_request.source == "uncommitted changes" {
-        crate::infra::cli::diff::acquire_diff(crate::infra::cli::diff::DiffSource::GitStatus)
-            .map_err(|e| e.to_string())?
+        crate::infra::cli::diff::acquire_diff_with(
+            &state.runtime,
+            crate::infra::cli::diff::DiffSource::GitStatus,
+        )
+        .map_err(|e| e.to_string())?
     } else {
-        let from = if diff_request.from.is_empty() {
-            "HEAD".to_string()
-        } else {
-            diff_request.from.clone()
-        };
         let to = if diff_request.to.is_empty() {
             "HEAD".to_string()
         } else {
             diff_request.to.clone()
         };
-        crate::infra::cli::diff::acquire_diff(crate::infra::cli::diff::DiffSource::GitDiff {
-            from,
-            to,
-        })
+        // Without a base, review the branch against where it forked from the
+        // default branch.
+        let from = if diff_request.from.is_empty() {
+            crate::infra::cli::repo::detect_git_repo()
+                .and_then(|root| {
+                    let base = crate::infra::cli::repo::detect_default_branch(&root)?;
+                    Some(crate::infra::cli::repo::merge_base(&root, &base, &to).unwrap_or(base))
+                })
+                .unwrap_or_else(|| "HEAD".to_string())
+        } else {
+            diff_request.from.clone()
+        };
+        crate::infra::cli::diff::acquire_diff_with(
+            &state.runtime,
+            crate::infra::cli::diff::DiffSource::GitDiff { from, to },
+        )
         .map_err(|e| e.to_string())?
     };
 
@@ -2422,10 +5375,7 @@ pub fn acquire_diff_from_request(state: State<'_, AppState>) -> Result<PendingRe
         created_at: chrono::Utc::now(),
     };
 
-    {
-        let mut pending_diff = state.pending_diff.lock().map_err(|e| e.to_string())?;
-        *pending_diff = Some(pending.clone());
-    }
+    let history_id = state.set_pending_diff(pending.clone(), review_source.clone());
 
     {
         let mut diff_request = state.diff_request.lock().map_err(|e| e.to_string())?;
@@ -2433,7 +5383,7 @@ pub fn acquire_diff_from_request(state: State<'_, AppState>) -> Result<PendingRe
     }
 
     Ok(PendingReviewState {
-        id: uuid::Uuid::new_v4().to_string(),
+        id: history_id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
         diff: pending.diff,
         repo_root: pending.repo_root.map(|r| r.to_string_lossy().to_string()),
         agent: pending.agent,
@@ -2461,7 +5411,7 @@ pub fn get_issue_checks_for_run(
     state: State<'_, AppState>,
     run_id: String,
 ) -> Result<Vec<IssueCheckWithFindings>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     let checks_with_findings = db
         .issue_check_repo()
         .find_checks_with_findings(&run_id)
@@ -2525,7 +5475,7 @@ pub fn get_merge_confidence(
     state: State<'_, AppState>,
     run_id: String,
 ) -> Result<Option<MergeConfidenceState>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     let confidence = db
         .merge_confidence_repo()
         .find_by_run_id(&run_id)
@@ -2588,7 +5538,7 @@ pub fn add_rule_from_library(
     };
 
     let rule = build_review_rule(rule_id, now.clone(), now, input)?;
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     db.rule_repo().save(&rule).map_err(|e| e.to_string())?;
     Ok(rule)
 }
@@ -2621,7 +5571,7 @@ pub struct RuleRejectionStatsResponse {
 pub fn get_rule_rejection_stats(
     state: State<'_, AppState>,
 ) -> Result<Vec<RuleRejectionStatsResponse>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     let stats = db
         .rejection_repo()
         .get_rule_stats()
@@ -2649,7 +5599,7 @@ use crate::domain::{
 /// Get all learned patterns
 #[tauri::command]
 pub fn get_learned_patterns(state: State<'_, AppState>) -> Result<Vec<LearnedPattern>, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     db.learned_pattern_repo()
         .list_all()
         .map_err(|e| e.to_string())
@@ -2661,7 +5611,7 @@ pub fn create_learned_pattern(
     state: State<'_, AppState>,
     input: LearnedPatternInput,
 ) -> Result<LearnedPattern, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     db.learned_pattern_repo()
         .create(&input, 0) // source_count = 0 for manual creation
         .map_err(|e| e.to_string())
@@ -2674,7 +5624,7 @@ pub fn update_learned_pattern(
     id: String,
     input: LearnedPatternInput,
 ) -> Result<LearnedPattern, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     db.learned_pattern_repo()
         .update(&id, &input)
         .map_err(|e| e.to_string())?
@@ -2684,7 +5634,7 @@ pub fn update_learned_pattern(
 /// Delete a learned pattern
 #[tauri::command]
 pub fn delete_learned_pattern(state: State<'_, AppState>, id: String) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     let rows = db
         .learned_pattern_repo()
         .delete(&id)
@@ -2702,7 +5652,7 @@ pub fn toggle_learned_pattern(
     id: String,
     enabled: bool,
 ) -> Result<(), String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     let rows = db
         .learned_pattern_repo()
         .toggle_enabled(&id, enabled)
@@ -2716,7 +5666,7 @@ pub fn toggle_learned_pattern(
 /// Get the learning system status
 #[tauri::command]
 pub fn get_learning_status(state: State<'_, AppState>) -> Result<LearningStatus, String> {
-    let db = state.db.lock().map_err(|e| e.to_string())?;
+    let db = state.database()?;
     let pattern_repo = db.learned_pattern_repo();
     let state_repo = db.learning_state_repo();
     state_repo
@@ -2724,6 +5674,16 @@ pub fn get_learning_status(state: State<'_, AppState>) -> Result<LearningStatus,
         .map_err(|e| e.to_string())
 }
 
+/// Reviews with stats, rule effectiveness, learning status and recent runs
+/// in one versioned payload, for dashboards built on top of LaReview.
+#[tauri::command]
+pub fn get_state_snapshot(
+    state: State<'_, AppState>,
+) -> Result<crate::application::snapshot::StateSnapshot, String> {
+    let db = state.database()?;
+    crate::application::snapshot::build_snapshot(&db).map_err(|e| format!("{:#}", e))
+}
+
 /// Trigger learning compaction manually
 #[tauri::command]
 pub async fn trigger_learning_compaction(
@@ -2750,7 +5710,7 @@ pub async fn trigger_learning_compaction(
 
     // Get unprocessed rejections and existing patterns
     let (rejections, existing_patterns, db_clone) = {
-        let db = state.db.lock().map_err(|e| e.to_string())?;
+        let db = state.database()?;
         let rejection_repo = db.rejection_repo();
         let pattern_repo = db.learned_pattern_repo();
 
@@ -2792,3 +5752,203 @@ pub async fn trigger_learning_compaction(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{ReviewSource, RiskLevel, TaskStats};
+
+    fn task(title: &str, status: ReviewStatus, description: &str) -> ReviewTask {
+        ReviewTask {
+            id: title.to_string(),
+            title: title.to_string(),
+            description: description.to_string(),
+            status,
+            stats: TaskStats {
+                risk: RiskLevel::Low,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_markdown_export_renders_task_list_checkboxes() {
+        let review = Review {
+            id: "rev".into(),
+            title: "Checklist".into(),
+            summary: None,
+            source: ReviewSource::DiffPaste {
+                diff_hash: "h".into(),
+            },
+            active_run_id: None,
+            status: ReviewStatus::Todo,
+            created_at: "now".into(),
+            updated_at: "now".into(),
+            tags: vec![],
+            decision: None,
+        };
+        let tasks = vec![
+            task(
+                "Read the diff",
+                ReviewStatus::Todo,
+                "First line\nSecond line",
+            ),
+            task("Check errors", ReviewStatus::InProgress, ""),
+            task("Run tests", ReviewStatus::Done, ""),
+            task("Bikeshed names", ReviewStatus::Ignored, ""),
+        ];
+
+        let md = generate_markdown_export(&review, &tasks, &[]).unwrap();
+
+        assert_eq!(
+            md,
+            "# Checklist\n\n\
+             ## Tasks\n\n\
+             - [ ] **Read the diff** (LOW) — Todo\n  > First line\n  > Second line\n\n\
+             - [ ] **Check errors** (LOW) — In progress\n\n\
+             - [x] **Run tests** (LOW) — Done\n\n\
+             - [-] **Bikeshed names** (LOW) — Ignored\n\n"
+        );
+    }
+
+    #[test]
+    fn test_rename_in_order_keeps_position_and_merges() {
+        let mut order = vec!["Auth".to_string(), "".to_string(), "Db".to_string()];
+
+        assert!(rename_in_order(&mut order, "", "Misc"));
+        assert_eq!(order, vec!["Auth", "Misc", "Db"]);
+
+        assert!(rename_in_order(&mut order, "Auth", "Db"));
+        assert_eq!(order, vec!["Misc", "Db"]);
+
+        assert!(!rename_in_order(&mut order, "Missing", "Other"));
+        assert_eq!(order, vec!["Misc", "Db"]);
+    }
+
+    const DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,3 @@\n fn a() {}\n+fn b() {}\n fn c() {}\n";
+
+    #[test]
+    fn test_fill_user_task_links_files_in_the_diff() {
+        let mut task = ReviewTask::default();
+        fill_user_task(
+            &mut task,
+            DIFF,
+            "  Verify the rollback plan ".into(),
+            "Check the down migration.".into(),
+            vec![
+                "src/lib.rs".into(),
+                " src/lib.rs".into(),
+                "docs/rollback.md".into(),
+                " ".into(),
+            ],
+            Some("  ".into()),
+        )
+        .unwrap();
+
+        assert_eq!(task.title, "Verify the rollback plan");
+        assert_eq!(task.files, vec!["src/lib.rs", "docs/rollback.md"]);
+        assert_eq!(task.diff_refs.len(), 1);
+        assert_eq!(task.diff_refs[0].file, "src/lib.rs");
+        assert_eq!(task.stats.additions, 1);
+        assert_eq!(task.sub_flow, None);
+        assert!(!task.ai_generated);
+    }
+
+    #[test]
+    fn test_fill_user_task_requires_a_title() {
+        let mut task = ReviewTask::default();
+        assert!(fill_user_task(&mut task, DIFF, " ".into(), String::new(), vec![], None).is_err());
+    }
+
+    #[test]
+    fn test_run_plan_from_acp_keeps_entries_in_order() {
+        let plan: agent_client_protocol::Plan = serde_json::from_value(serde_json::json!({
+            "entries": [
+                { "content": "Read the diff", "priority": "high", "status": "completed" },
+                { "content": "Write tasks", "priority": "medium", "status": "in_progress" },
+            ]
+        }))
+        .unwrap();
+
+        let plan = run_plan_from_acp(&plan);
+        let contents: Vec<_> = plan.entries.iter().map(|e| e.content.as_str()).collect();
+        assert_eq!(contents, vec!["Read the diff", "Write tasks"]);
+        assert!(plan.entries.iter().all(|e| !e.status.is_empty()));
+    }
+
+    #[test]
+    fn test_review_input_ref_records_excluded_files() {
+        let hash = "0123456789abcdef";
+        assert_eq!(review_input_ref(hash, &[]), "diff-01234567");
+        let excluded = vec!["vendor/b.js".to_string(), "Cargo.lock".to_string()];
+        assert_eq!(
+            review_input_ref(hash, &excluded),
+            "diff-01234567 (excluded: Cargo.lock, vendor/b.js)"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_offline_remote_diff_never_runs_glab() {
+        use crate::infra::app_config::{AppConfig, ENV_MUTEX};
+        use std::os::unix::fs::PermissionsExt;
+
+        let _guard = ENV_MUTEX.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("glab-ran");
+        let glab = dir.path().join("glab");
+        std::fs::write(
+            &glab,
+            format!("#!/bin/sh\ntouch '{}'\necho '{{}}'\n", marker.display()),
+        )
+        .unwrap();
+        std::fs::set_permissions(&glab, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let config = AppConfig {
+            offline: true,
+            vcs_path_overrides: std::collections::HashMap::from([(
+                "gitlab".to_string(),
+                glab.to_string_lossy().into_owned(),
+            )]),
+            ..Default::default()
+        };
+        let config_path = dir.path().join("config.toml");
+        std::fs::write(&config_path, toml::to_string_pretty(&config).unwrap()).unwrap();
+
+        // Env changes are protected by ENV_MUTEX in test context.
+        unsafe {
+            std::env::set_var("LAREVIEW_CONFIG_PATH", &config_path);
+        }
+        let from = "https://gitlab.com/group/project/-/merge_requests/7";
+        let remote_ref = crate::infra::cli::diff::parse_remote_ref(from).unwrap();
+        let result = acquire_remote_diff(&crate::runtime_handle(), remote_ref, from);
+        unsafe {
+            std::env::remove_var("LAREVIEW_CONFIG_PATH");
+        }
+
+        assert_eq!(
+            result.unwrap_err(),
+            crate::infra::vcs::offline::OFFLINE_MODE_ERROR
+        );
+        assert!(!marker.exists());
+    }
+
+    #[test]
+    fn test_snippet_highlights_each_side_on_its_own() {
+        let diff = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,2 @@\n-/* retired\n+let x = 1;\n fn main() {}\n";
+        let index = crate::infra::diff::index::DiffIndex::new(diff).unwrap();
+        let anchor = FeedbackAnchor {
+            file_path: Some("src/lib.rs".into()),
+            line_number: Some(1),
+            side: Some(crate::domain::FeedbackSide::New),
+            ..Default::default()
+        };
+        let snippet = anchor_diff_snippet(&index, &anchor, 3).unwrap();
+        let added = snippet.lines.iter().find(|line| line.is_addition).unwrap();
+
+        // The comment opened on the deleted line doesn't swallow the added one.
+        let fresh = crate::infra::syntax::FileHighlighter::for_path("src/lib.rs")
+            .highlight_line("let x = 1;");
+        assert_eq!(added.spans, fresh);
+    }
+}
@@ -4,18 +4,28 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
-/// Impact/severity level for feedback
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+/// Impact/severity level for feedback, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum FeedbackImpact {
     /// Optional/nit-level feedback
     #[default]
     Nitpick,
-    /// Must address before merge
-    Blocking,
     /// Nice to have before/after merge
     #[serde(alias = "nice-to-have")]
     NiceToHave,
+    /// Must address before merge
+    Blocking,
+}
+
+impl FeedbackImpact {
+    pub fn rank(self) -> u8 {
+        match self {
+            Self::Nitpick => 0,
+            Self::NiceToHave => 1,
+            Self::Blocking => 2,
+        }
+    }
 }
 
 impl fmt::Display for FeedbackImpact {
@@ -77,6 +87,49 @@ pub struct FeedbackAnchor {
     /// Commit SHA (optional)
     #[serde(default)]
     pub head_sha: Option<String>,
+    /// Hash of the run diff `line_number` and `hunk_ref` refer to (optional)
+    #[serde(default)]
+    pub diff_hash: Option<String>,
+    /// The anchored line is no longer part of the review's current diff
+    #[serde(default)]
+    pub outdated: bool,
+}
+
+/// Code change proposed alongside a finding: the lines starting at the
+/// feedback's anchor, and what they should become.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuggestedReplacement {
+    /// Lines being replaced, as they appear on the new side of the diff
+    pub old_lines: Vec<String>,
+    /// Replacement lines (empty to delete the old lines)
+    #[serde(default)]
+    pub new_lines: Vec<String>,
+}
+
+impl SuggestedReplacement {
+    /// GitHub ```suggestion block applying the replacement.
+    pub fn to_github_suggestion(&self) -> String {
+        let mut block = String::from("```suggestion\n");
+        for line in &self.new_lines {
+            block.push_str(line);
+            block.push('\n');
+        }
+        block.push_str("```");
+        block
+    }
+
+    /// Plain ```diff block, readable outside GitHub.
+    pub fn to_diff_block(&self) -> String {
+        let mut block = String::from("```diff\n");
+        for line in &self.old_lines {
+            block.push_str(&format!("-{}\n", line));
+        }
+        for line in &self.new_lines {
+            block.push_str(&format!("+{}\n", line));
+        }
+        block.push_str("```");
+        block
+    }
 }
 
 /// Feedback entry spanning one or more comments
@@ -110,6 +163,21 @@ pub struct Feedback {
     /// Location of the feedback in the code
     #[serde(default)]
     pub anchor: Option<FeedbackAnchor>,
+    /// Code fix proposed for the anchored lines (optional)
+    #[serde(default)]
+    pub suggested_replacement: Option<SuggestedReplacement>,
+    /// Further locations of a finding that spans several places, e.g. call
+    /// sites needing the same fix. `anchor` stays the primary location.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_anchors: Vec<FeedbackAnchor>,
+    /// Why the finding was flagged, as opposed to what it says. Given by the
+    /// agent with the feedback, or fetched later on request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<String>,
+    /// Kept at the top of the review's feedback list, whatever its file or
+    /// status.
+    #[serde(default)]
+    pub pinned: bool,
     /// Author identifier (agent or user)
     pub author: String,
     /// Creation timestamp
@@ -118,6 +186,54 @@ pub struct Feedback {
     pub updated_at: String,
 }
 
+impl Feedback {
+    /// Every location of the finding, primary anchor first.
+    pub fn anchors(&self) -> impl Iterator<Item = &FeedbackAnchor> {
+        self.anchor.iter().chain(&self.extra_anchors)
+    }
+}
+
+/// Prefix of the `author` of feedback and comments written by an agent,
+/// e.g. `agent:claude`. Anything else was written by a person.
+pub const AGENT_AUTHOR_PREFIX: &str = "agent:";
+
+/// Prefix of the `author` of feedback brought in from another tool,
+/// e.g. `import:github`.
+pub const IMPORTED_AUTHOR_PREFIX: &str = "import:";
+
+/// The agent id in an `agent:<id>` author; None for people.
+pub fn agent_id_of(author: &str) -> Option<&str> {
+    author.strip_prefix(AGENT_AUTHOR_PREFIX)
+}
+
+/// Who wrote a piece of feedback, as told by its `author`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorKind {
+    User,
+    Agent,
+    Imported,
+}
+
+impl AuthorKind {
+    pub fn of(author: &str) -> Self {
+        // Issue-check findings use a bare "agent".
+        if author == "agent" || author.starts_with(AGENT_AUTHOR_PREFIX) {
+            Self::Agent
+        } else if author.starts_with(IMPORTED_AUTHOR_PREFIX) {
+            Self::Imported
+        } else {
+            Self::User
+        }
+    }
+}
+
+/// Sorts `feedbacks` most severe first, keeping the existing order within
+/// each impact level.
+pub fn sort_by_severity(feedbacks: &mut [Feedback]) {
+    feedbacks.sort_by_key(|feedback| std::cmp::Reverse(feedback.impact));
+}
+
 /// Comment within a feedback entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Comment {
@@ -138,6 +254,60 @@ pub struct Comment {
     pub updated_at: String,
 }
 
+/// Largest image that can be attached to a feedback entry (5 MiB).
+pub const MAX_ATTACHMENT_BYTES: usize = 5 * 1024 * 1024;
+
+/// Image types accepted as feedback attachments.
+pub const ATTACHMENT_MIME_TYPES: &[&str] = &["image/png", "image/jpeg", "image/gif", "image/webp"];
+
+/// Image (typically a pasted screenshot) attached to a feedback entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedbackAttachment {
+    /// Unique identifier for the attachment
+    pub id: String,
+    /// Parent feedback ID
+    pub feedback_id: String,
+    /// MIME type of the image (one of [`ATTACHMENT_MIME_TYPES`])
+    pub mime: String,
+    /// Raw image bytes
+    #[serde(skip)]
+    pub data: Vec<u8>,
+    /// Size of `data` in bytes
+    pub byte_size: usize,
+    /// Creation timestamp
+    pub created_at: String,
+}
+
+impl FeedbackAttachment {
+    /// Checks an image against the size limit and accepted types.
+    pub fn validate(data: &[u8], mime: &str) -> Result<(), String> {
+        if data.is_empty() {
+            return Err("Attachment is empty".to_string());
+        }
+        if data.len() > MAX_ATTACHMENT_BYTES {
+            return Err(format!(
+                "Attachment is {:.1} MB; the limit is {} MB",
+                data.len() as f64 / (1024.0 * 1024.0),
+                MAX_ATTACHMENT_BYTES / (1024 * 1024)
+            ));
+        }
+        if !ATTACHMENT_MIME_TYPES.contains(&mime) {
+            return Err(format!("Unsupported attachment type: {}", mime));
+        }
+        Ok(())
+    }
+
+    /// `data:` URI for embedding the image in markdown or an `<img>` tag.
+    pub fn data_url(&self) -> String {
+        use base64::Engine;
+        format!(
+            "data:{};base64,{}",
+            self.mime,
+            base64::engine::general_purpose::STANDARD.encode(&self.data)
+        )
+    }
+}
+
 /// Mapping to an external provider feedback (e.g., GitHub)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FeedbackLink {
@@ -141,6 +141,26 @@ pub struct IssueFinding {
     pub created_at: String,
 }
 
+/// A user-defined check the agent verifies on every run, alongside the
+/// built-in categories
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssueCheckDefinition {
+    /// Unique identifier for this definition
+    pub id: String,
+    /// Display name (e.g., "Accessibility")
+    pub name: String,
+    /// Category ID the agent reports results under (e.g., "accessibility")
+    pub category: String,
+    /// Instructions added to the generation prompt
+    pub prompt: String,
+    /// Disabled definitions are kept but not sent to the agent
+    pub enabled: bool,
+    /// Creation timestamp in RFC3339 format
+    pub created_at: String,
+    /// Last update timestamp in RFC3339 format
+    pub updated_at: String,
+}
+
 /// Default issue categories that are built-in
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DefaultIssueCategory {
@@ -55,4 +55,338 @@ mod tests {
             FeedbackImpact::Blocking
         );
     }
+
+    #[test]
+    fn test_sort_by_severity() {
+        let impacts = [
+            FeedbackImpact::Nitpick,
+            FeedbackImpact::NiceToHave,
+            FeedbackImpact::Blocking,
+        ];
+        for pair in impacts.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].rank() < pair[1].rank());
+        }
+
+        let feedback = |id: &str, impact| Feedback {
+            id: id.into(),
+            review_id: "rev".into(),
+            task_id: None,
+            rule_id: None,
+            finding_id: None,
+            category: None,
+            title: id.into(),
+            status: ReviewStatus::Todo,
+            impact,
+            confidence: 1.0,
+            anchor: None,
+            suggested_replacement: None,
+            extra_anchors: Vec::new(),
+            rationale: None,
+            pinned: false,
+            author: "agent".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        };
+        let mut feedbacks = vec![
+            feedback("nit", FeedbackImpact::Nitpick),
+            feedback("block-1", FeedbackImpact::Blocking),
+            feedback("nice", FeedbackImpact::NiceToHave),
+            feedback("block-2", FeedbackImpact::Blocking),
+        ];
+        sort_by_severity(&mut feedbacks);
+        let ids: Vec<_> = feedbacks.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(ids, ["block-1", "block-2", "nice", "nit"]);
+    }
+
+    #[test]
+    fn test_feedback_extra_anchors_are_optional() {
+        let json = r#"{
+            "id": "fb", "review_id": "rev", "title": "Null checks",
+            "status": "todo", "impact": "blocking", "author": "agent",
+            "created_at": "now", "updated_at": "now",
+            "anchor": { "file_path": "src/a.rs", "line_number": 3 }
+        }"#;
+        let mut feedback: Feedback = serde_json::from_str(json).unwrap();
+        assert!(feedback.extra_anchors.is_empty());
+        assert!(
+            !serde_json::to_string(&feedback)
+                .unwrap()
+                .contains("extra_anchors")
+        );
+
+        feedback.extra_anchors.push(FeedbackAnchor {
+            file_path: Some("src/b.rs".into()),
+            line_number: Some(9),
+            ..Default::default()
+        });
+        let paths: Vec<_> = feedback
+            .anchors()
+            .filter_map(|anchor| anchor.file_path.as_deref())
+            .collect();
+        assert_eq!(paths, ["src/a.rs", "src/b.rs"]);
+    }
+
+    #[test]
+    fn test_author_kind() {
+        assert_eq!(AuthorKind::of("agent:claude"), AuthorKind::Agent);
+        assert_eq!(AuthorKind::of("agent"), AuthorKind::Agent);
+        assert_eq!(AuthorKind::of("import:github"), AuthorKind::Imported);
+        assert_eq!(AuthorKind::of("Ada <ada@example.com>"), AuthorKind::User);
+        assert_eq!(AuthorKind::of("agentsmith"), AuthorKind::User);
+        assert_eq!(agent_id_of("agent:claude"), Some("claude"));
+    }
+
+    #[test]
+    fn test_review_tag_normalization() {
+        assert_eq!(
+            normalize_review_tag("  Needs  Follow Up "),
+            Some("needs-follow-up".to_string())
+        );
+        assert_eq!(normalize_review_tag("   "), None);
+        assert_eq!(
+            normalize_review_tag(&"x".repeat(100)).unwrap().len(),
+            MAX_REVIEW_TAG_LEN
+        );
+    }
+
+    #[test]
+    fn test_suggested_tags_from_source() {
+        let github = ReviewSource::GitHubPr {
+            owner: "Puemos".into(),
+            repo: "lareview".into(),
+            number: 1,
+            url: None,
+            head_sha: None,
+            base_sha: None,
+        };
+        assert_eq!(
+            github.suggested_tags(),
+            vec!["github", "puemos", "lareview"]
+        );
+
+        let gitlab = ReviewSource::GitLabMr {
+            host: "gitlab.com".into(),
+            project_path: "group/sub/project".into(),
+            number: 1,
+            url: None,
+            head_sha: None,
+            base_sha: None,
+            start_sha: None,
+        };
+        assert_eq!(gitlab.suggested_tags(), vec!["gitlab", "group", "project"]);
+
+        let compare = ReviewSource::GitHubCompare {
+            owner: "puemos".into(),
+            repo: "lareview".into(),
+            base: "main".into(),
+            head: "dev".into(),
+            mode: CompareMode::Direct,
+            url: None,
+            head_sha: None,
+            base_sha: None,
+        };
+        assert_eq!(
+            compare.suggested_tags(),
+            vec!["github", "puemos", "lareview"]
+        );
+        assert_eq!(compare.provider_id(), Some("github"));
+
+        let paste = ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        };
+        assert!(paste.suggested_tags().is_empty());
+    }
+
+    #[test]
+    fn test_feedback_attachment_validation() {
+        assert!(FeedbackAttachment::validate(b"png", "image/png").is_ok());
+        assert!(FeedbackAttachment::validate(b"", "image/png").is_err());
+        assert!(FeedbackAttachment::validate(b"<svg/>", "image/svg+xml").is_err());
+
+        let too_big = vec![0u8; MAX_ATTACHMENT_BYTES + 1];
+        let err = FeedbackAttachment::validate(&too_big, "image/png").unwrap_err();
+        assert!(err.contains("limit is 5 MB"));
+
+        let attachment = FeedbackAttachment {
+            id: "a".into(),
+            feedback_id: "fb".into(),
+            mime: "image/png".into(),
+            data: b"hi".to_vec(),
+            byte_size: 2,
+            created_at: "now".into(),
+        };
+        assert_eq!(attachment.data_url(), "data:image/png;base64,aGk=");
+    }
+
+    #[test]
+    fn test_suggested_replacement_renders_github_block() {
+        let suggestion = SuggestedReplacement {
+            old_lines: vec!["let x = a.unwrap();".into()],
+            new_lines: vec!["let x = a?;".into()],
+        };
+        assert_eq!(
+            suggestion.to_github_suggestion(),
+            "```suggestion\nlet x = a?;\n```"
+        );
+
+        let deletion = SuggestedReplacement {
+            old_lines: vec!["dbg!(x);".into()],
+            new_lines: vec![],
+        };
+        assert_eq!(deletion.to_github_suggestion(), "```suggestion\n```");
+        assert_eq!(deletion.to_diff_block(), "```diff\n-dbg!(x);\n```");
+    }
+
+    #[test]
+    fn test_file_permalink_uses_the_side_commit() {
+        let github = ReviewSource::GitHubPr {
+            owner: "octo".into(),
+            repo: "app".into(),
+            number: 7,
+            url: None,
+            head_sha: Some("head1".into()),
+            base_sha: None,
+        };
+        assert_eq!(
+            github.file_permalink("src/lib.rs", 12, FeedbackSide::New),
+            Some("https://github.com/octo/app/blob/head1/src/lib.rs#L12".into())
+        );
+        assert_eq!(
+            github.file_permalink("src/lib.rs", 12, FeedbackSide::Old),
+            None
+        );
+
+        let gitlab = ReviewSource::GitLabMr {
+            host: "gitlab.example.com".into(),
+            project_path: "group/app".into(),
+            number: 3,
+            url: None,
+            head_sha: None,
+            base_sha: Some("base1".into()),
+            start_sha: None,
+        };
+        assert_eq!(
+            gitlab.file_permalink("a.py", 4, FeedbackSide::Old),
+            Some("https://gitlab.example.com/group/app/-/blob/base1/a.py#L4".into())
+        );
+
+        let pasted = ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        };
+        assert_eq!(pasted.file_permalink("a.py", 4, FeedbackSide::New), None);
+    }
+
+    #[test]
+    fn test_github_enterprise_host_comes_from_the_url() {
+        let enterprise = ReviewSource::GitHubPr {
+            owner: "team".into(),
+            repo: "api".into(),
+            number: 9,
+            url: Some("https://ghe.company.com/team/api/pull/9".into()),
+            head_sha: Some("head1".into()),
+            base_sha: None,
+        };
+        assert_eq!(enterprise.host().as_deref(), Some("ghe.company.com"));
+        assert_eq!(
+            enterprise.file_permalink("a.rs", 1, FeedbackSide::New),
+            Some("https://ghe.company.com/team/api/blob/head1/a.rs#L1".into())
+        );
+        assert!(enterprise.matches_remote("git@ghe.company.com:team/api.git"));
+        assert!(!enterprise.matches_remote("git@github.com:team/api.git"));
+
+        let pasted = ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        };
+        assert_eq!(pasted.host(), None);
+    }
+
+    #[test]
+    fn test_matches_remote_in_https_and_ssh_form() {
+        let github = ReviewSource::GitHubCompare {
+            owner: "Octo".into(),
+            repo: "App".into(),
+            base: "main".into(),
+            head: "feature".into(),
+            mode: CompareMode::default(),
+            url: None,
+            head_sha: None,
+            base_sha: None,
+        };
+        assert!(github.matches_remote("https://github.com/octo/app.git"));
+        assert!(github.matches_remote("git@github.com:octo/app.git"));
+        assert!(!github.matches_remote("git@github.com:octo/other.git"));
+
+        let pasted = ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        };
+        assert!(pasted.remote_patterns().is_empty());
+        assert!(!pasted.matches_remote("git@github.com:octo/app.git"));
+    }
+
+    #[test]
+    fn test_gitea_pr_source() {
+        let gitea = ReviewSource::GiteaPr {
+            host: "codeberg.org".into(),
+            owner: "Forge".into(),
+            repo: "app".into(),
+            number: 12,
+            url: Some("https://codeberg.org/Forge/app/pulls/12".into()),
+            head_sha: Some("head1".into()),
+            base_sha: None,
+        };
+        assert_eq!(gitea.provider_id(), Some("gitea"));
+        assert_eq!(gitea.host().as_deref(), Some("codeberg.org"));
+        assert_eq!(gitea.suggested_tags(), vec!["gitea", "forge", "app"]);
+        assert_eq!(
+            gitea.file_permalink("src/lib.rs", 3, FeedbackSide::New),
+            Some("https://codeberg.org/Forge/app/src/commit/head1/src/lib.rs#L3".into())
+        );
+        assert!(gitea.matches_remote("ssh://git@codeberg.org/forge/app.git"));
+        assert!(gitea.matches_remote("git@codeberg.org:forge/app.git"));
+
+        let json = serde_json::to_value(&gitea).unwrap();
+        assert_eq!(json["type"], "gitea_pr");
+        let back: ReviewSource = serde_json::from_value(json).unwrap();
+        assert_eq!(back, gitea);
+    }
+
+    #[test]
+    fn test_review_mode_for_changed_lines() {
+        assert_eq!(ReviewMode::for_changed_lines(0, 50), ReviewMode::Quick);
+        assert_eq!(ReviewMode::for_changed_lines(50, 50), ReviewMode::Quick);
+        assert_eq!(ReviewMode::for_changed_lines(51, 50), ReviewMode::Thorough);
+        assert_eq!(ReviewMode::Quick.max_tasks(), Some(QUICK_REVIEW_MAX_TASKS));
+        assert_eq!(ReviewMode::Thorough.max_tasks(), None);
+        assert_eq!(serde_json::to_value(ReviewMode::Quick).unwrap(), "quick");
+    }
+
+    #[test]
+    fn test_unchecked_checklist_items_hold_back_approval() {
+        assert!(ChecklistItem::new("rev", "   ").is_err());
+        assert!(ChecklistItem::new("rev", &"x".repeat(MAX_CHECKLIST_ITEM_LEN + 1)).is_err());
+
+        let mut item = ChecklistItem::new("rev", "  Update the changelog ").unwrap();
+        assert_eq!(item.text, "Update the changelog");
+        assert!(!item.done);
+
+        let checklist = vec![item.clone()];
+        assert_eq!(
+            ReviewDecision::suggested(&[], &checklist),
+            ReviewDecision::RequestChanges
+        );
+        assert_eq!(
+            ReviewDecision::Approve.mismatch(&[], &checklist).as_deref(),
+            Some("Approving with 1 unchecked checklist item")
+        );
+        assert_eq!(ReviewDecision::Comment.mismatch(&[], &checklist), None);
+
+        item.done = true;
+        let checklist = vec![item];
+        assert_eq!(
+            ReviewDecision::suggested(&[], &checklist),
+            ReviewDecision::Comment
+        );
+        assert_eq!(ReviewDecision::Approve.mismatch(&[], &checklist), None);
+    }
 }
@@ -10,4 +10,11 @@ pub struct LinkedRepo {
     pub created_at: String,
     #[serde(default)]
     pub allow_snapshot_access: bool,
+    /// Agent pre-selected when this repo is chosen for a review.
+    #[serde(default)]
+    pub default_agent_id: Option<String>,
+    /// Rule categories applied by default for this repo. When non-empty,
+    /// categorized global rules outside this set are skipped.
+    #[serde(default)]
+    pub default_rule_categories: Vec<String>,
 }
@@ -1,3 +1,4 @@
+use super::{Feedback, FeedbackImpact, FeedbackSide};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -29,6 +30,166 @@ pub struct Review {
     pub created_at: String,
     /// Update timestamp in RFC3339 format.
     pub updated_at: String,
+    /// User-assigned labels used to organize and filter reviews.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Overall verdict submitted with the review; None until the user picks
+    /// one, in which case [`ReviewDecision::suggested`] applies.
+    #[serde(default)]
+    pub decision: Option<ReviewDecision>,
+}
+
+impl Review {
+    /// The decision to submit: the user's pick, else the suggested one.
+    pub fn effective_decision(
+        &self,
+        feedbacks: &[Feedback],
+        checklist: &[ChecklistItem],
+    ) -> ReviewDecision {
+        self.decision
+            .unwrap_or_else(|| ReviewDecision::suggested(feedbacks, checklist))
+    }
+}
+
+/// A reviewer-written follow-up that has to happen before merging, such as
+/// "update the changelog"; kept apart from the agent's tasks.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChecklistItem {
+    pub id: String,
+    pub review_id: ReviewId,
+    pub text: String,
+    pub done: bool,
+    /// Creation timestamp in RFC3339 format; items are listed in this order.
+    pub created_at: String,
+}
+
+/// Maximum length of a checklist item, in characters.
+pub const MAX_CHECKLIST_ITEM_LEN: usize = 200;
+
+impl ChecklistItem {
+    /// A new, unchecked item; `text` is trimmed and must not be blank or
+    /// longer than [`MAX_CHECKLIST_ITEM_LEN`].
+    pub fn new(review_id: &str, text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err("Checklist item is empty".to_string());
+        }
+        if text.chars().count() > MAX_CHECKLIST_ITEM_LEN {
+            return Err(format!(
+                "Checklist items are limited to {} characters",
+                MAX_CHECKLIST_ITEM_LEN
+            ));
+        }
+        Ok(Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            review_id: review_id.to_string(),
+            text: text.to_string(),
+            done: false,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+}
+
+/// Overall verdict on a review, submitted as the PR review event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewDecision {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+impl fmt::Display for ReviewDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Approve => write!(f, "approve"),
+            Self::RequestChanges => write!(f, "request_changes"),
+            Self::Comment => write!(f, "comment"),
+        }
+    }
+}
+
+impl FromStr for ReviewDecision {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "approve" => Ok(Self::Approve),
+            "request_changes" => Ok(Self::RequestChanges),
+            "comment" => Ok(Self::Comment),
+            _ => Err(format!("Unknown review decision: {}", s)),
+        }
+    }
+}
+
+impl ReviewDecision {
+    /// `event` for GitHub's "create a review" endpoint.
+    pub fn github_event(self) -> &'static str {
+        match self {
+            Self::Approve => "APPROVE",
+            Self::RequestChanges => "REQUEST_CHANGES",
+            Self::Comment => "COMMENT",
+        }
+    }
+
+    /// Request changes while blocking feedback is unresolved or checklist
+    /// items are left; otherwise only comment, since approving should be the
+    /// reviewer's explicit call.
+    pub fn suggested(feedbacks: &[Feedback], checklist: &[ChecklistItem]) -> Self {
+        if feedbacks.iter().any(is_open_blocking) || checklist.iter().any(|item| !item.done) {
+            Self::RequestChanges
+        } else {
+            Self::Comment
+        }
+    }
+
+    /// Why this decision contradicts `feedbacks` or `checklist`, if it does.
+    pub fn mismatch(self, feedbacks: &[Feedback], checklist: &[ChecklistItem]) -> Option<String> {
+        if self != Self::Approve {
+            return None;
+        }
+        let open = feedbacks
+            .iter()
+            .filter(|feedback| is_open_blocking(feedback))
+            .count();
+        let unchecked = checklist.iter().filter(|item| !item.done).count();
+        let mut reasons = Vec::new();
+        if open > 0 {
+            reasons.push(format!(
+                "{} unresolved blocking feedback item{}",
+                open,
+                if open == 1 { "" } else { "s" }
+            ));
+        }
+        if unchecked > 0 {
+            reasons.push(format!(
+                "{} unchecked checklist item{}",
+                unchecked,
+                if unchecked == 1 { "" } else { "s" }
+            ));
+        }
+        (!reasons.is_empty()).then(|| format!("Approving with {}", reasons.join(" and ")))
+    }
+}
+
+fn is_open_blocking(feedback: &Feedback) -> bool {
+    feedback.impact == FeedbackImpact::Blocking && !feedback.status.is_closed()
+}
+
+/// Maximum length of a review tag, in characters.
+pub const MAX_REVIEW_TAG_LEN: usize = 40;
+
+/// Canonical form of a review tag: trimmed, lowercase, with inner whitespace
+/// collapsed to `-`. Returns `None` for blank input.
+pub fn normalize_review_tag(tag: &str) -> Option<String> {
+    let tag = tag
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+    if tag.is_empty() {
+        return None;
+    }
+    Some(tag.chars().take(MAX_REVIEW_TAG_LEN).collect())
 }
 
 /// Source for a review.
@@ -81,6 +242,105 @@ pub enum ReviewSource {
         #[serde(default)]
         start_sha: Option<String>,
     },
+    /// Review is derived from a GitHub branch comparison (`compare/base...head`).
+    #[serde(rename = "github_compare")]
+    GitHubCompare {
+        /// GitHub owner (organization or user)
+        owner: String,
+        /// GitHub repository name
+        repo: String,
+        /// Base ref (branch, tag or SHA)
+        base: String,
+        /// Head ref (branch, tag or SHA)
+        head: String,
+        /// Whether the diff starts at the merge base or at `base` itself
+        #[serde(default)]
+        mode: CompareMode,
+        /// Optional canonical URL for the comparison
+        #[serde(default)]
+        url: Option<String>,
+        /// Commit SHA `head` resolved to
+        #[serde(default)]
+        head_sha: Option<String>,
+        /// Commit SHA the diff starts from
+        #[serde(default)]
+        base_sha: Option<String>,
+    },
+    /// Review is derived from a GitLab branch comparison (`-/compare/base...head`).
+    #[serde(rename = "gitlab_compare")]
+    GitLabCompare {
+        /// GitLab host (e.g. gitlab.com)
+        host: String,
+        /// GitLab project path (namespace/project)
+        project_path: String,
+        /// Base ref (branch, tag or SHA)
+        base: String,
+        /// Head ref (branch, tag or SHA)
+        head: String,
+        /// Whether the diff starts at the merge base or at `base` itself
+        #[serde(default)]
+        mode: CompareMode,
+        /// Optional canonical URL for the comparison
+        #[serde(default)]
+        url: Option<String>,
+        /// Commit SHA `head` resolved to
+        #[serde(default)]
+        head_sha: Option<String>,
+        /// Commit SHA the diff starts from
+        #[serde(default)]
+        base_sha: Option<String>,
+    },
+    /// Review is derived from a Gitea or Forgejo pull request, fetched through
+    /// the instance's API.
+    #[serde(rename = "gitea_pr")]
+    GiteaPr {
+        /// Gitea/Forgejo host (e.g. codeberg.org)
+        host: String,
+        /// Repository owner (organization or user)
+        owner: String,
+        /// Repository name
+        repo: String,
+        /// Pull request number
+        number: u32,
+        /// Optional canonical URL for the PR
+        #[serde(default)]
+        url: Option<String>,
+        /// Topmost commit SHA of the PR
+        #[serde(default)]
+        head_sha: Option<String>,
+        /// Merge base the PR's diff starts from
+        #[serde(default)]
+        base_sha: Option<String>,
+    },
+}
+
+/// How the two refs of a branch comparison are diffed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareMode {
+    /// `base...head`: changes on `head` since it diverged from `base`.
+    #[default]
+    MergeBase,
+    /// `base..head`: the straight diff between the two refs.
+    Direct,
+}
+
+impl CompareMode {
+    /// Parses the separator between the two refs of a compare URL.
+    pub fn from_separator(separator: &str) -> Option<Self> {
+        match separator {
+            "..." => Some(Self::MergeBase),
+            ".." => Some(Self::Direct),
+            _ => None,
+        }
+    }
+
+    pub fn separator(self) -> &'static str {
+        match self {
+            Self::MergeBase => "...",
+            Self::Direct => "..",
+        }
+    }
 }
 
 impl ReviewSource {
@@ -89,6 +349,9 @@ impl ReviewSource {
             ReviewSource::DiffPaste { .. } => None,
             ReviewSource::GitHubPr { url, .. } => url.clone(),
             ReviewSource::GitLabMr { url, .. } => url.clone(),
+            ReviewSource::GitHubCompare { url, .. } => url.clone(),
+            ReviewSource::GitLabCompare { url, .. } => url.clone(),
+            ReviewSource::GiteaPr { url, .. } => url.clone(),
         }
     }
 
@@ -97,6 +360,9 @@ impl ReviewSource {
             ReviewSource::DiffPaste { .. } => None,
             ReviewSource::GitHubPr { head_sha, .. } => head_sha.clone(),
             ReviewSource::GitLabMr { head_sha, .. } => head_sha.clone(),
+            ReviewSource::GitHubCompare { head_sha, .. } => head_sha.clone(),
+            ReviewSource::GitLabCompare { head_sha, .. } => head_sha.clone(),
+            ReviewSource::GiteaPr { head_sha, .. } => head_sha.clone(),
         }
     }
 
@@ -105,7 +371,151 @@ impl ReviewSource {
             ReviewSource::DiffPaste { .. } => None,
             ReviewSource::GitHubPr { .. } => Some("github"),
             ReviewSource::GitLabMr { .. } => Some("gitlab"),
+            ReviewSource::GitHubCompare { .. } => Some("github"),
+            ReviewSource::GitLabCompare { .. } => Some("gitlab"),
+            ReviewSource::GiteaPr { .. } => Some("gitea"),
+        }
+    }
+
+    /// Host the source lives on: the GitLab host, or for GitHub sources the
+    /// host in their URL, which is `github.com` unless it's GitHub Enterprise.
+    /// `None` for pasted diffs.
+    pub fn host(&self) -> Option<String> {
+        match self {
+            ReviewSource::DiffPaste { .. } => None,
+            ReviewSource::GitHubPr { url, .. } | ReviewSource::GitHubCompare { url, .. } => {
+                let host = url
+                    .as_deref()
+                    .map(|url| url.split_once("://").map_or(url, |(_, rest)| rest))
+                    .and_then(|rest| rest.split('/').next())
+                    .map(str::to_lowercase)
+                    .filter(|host| !host.is_empty() && host != "www.github.com");
+                Some(host.unwrap_or_else(|| "github.com".to_string()))
+            }
+            ReviewSource::GitLabMr { host, .. }
+            | ReviewSource::GitLabCompare { host, .. }
+            | ReviewSource::GiteaPr { host, .. } => Some(host.clone()),
+        }
+    }
+
+    /// Fragments a git remote URL of this source's repository contains, in
+    /// HTTPS (`host/path`) and SSH (`host:path`) form, lowercased.
+    ///
+    /// Empty for pasted diffs, which can't be matched to a repository.
+    pub fn remote_patterns(&self) -> Vec<String> {
+        let Some(host) = self.host() else {
+            return vec![];
+        };
+        let path = match self {
+            ReviewSource::DiffPaste { .. } => return vec![],
+            ReviewSource::GitHubPr { owner, repo, .. }
+            | ReviewSource::GitHubCompare { owner, repo, .. }
+            | ReviewSource::GiteaPr { owner, repo, .. } => format!("{}/{}", owner, repo),
+            ReviewSource::GitLabMr { project_path, .. }
+            | ReviewSource::GitLabCompare { project_path, .. } => project_path.clone(),
+        };
+        vec![
+            format!("{}/{}", host, path).to_lowercase(),
+            format!("{}:{}", host, path).to_lowercase(),
+        ]
+    }
+
+    /// Whether `remote_url` points at this source's repository.
+    pub fn matches_remote(&self, remote_url: &str) -> bool {
+        let remote = remote_url.to_lowercase();
+        self.remote_patterns()
+            .iter()
+            .any(|pattern| remote.contains(pattern))
+    }
+
+    /// Link to `line` of `path` at the commit the diff was taken from: the
+    /// head commit for the new side, the base commit for the old side.
+    ///
+    /// `None` for pasted diffs and when the commit SHA isn't known.
+    pub fn file_permalink(&self, path: &str, line: u32, side: FeedbackSide) -> Option<String> {
+        let (blob_url, head_sha, base_sha) = match self {
+            ReviewSource::DiffPaste { .. } => return None,
+            ReviewSource::GitHubPr {
+                owner,
+                repo,
+                head_sha,
+                base_sha,
+                ..
+            }
+            | ReviewSource::GitHubCompare {
+                owner,
+                repo,
+                head_sha,
+                base_sha,
+                ..
+            } => (
+                format!("https://{}/{}/{}/blob", self.host()?, owner, repo),
+                head_sha,
+                base_sha,
+            ),
+            ReviewSource::GitLabMr {
+                host,
+                project_path,
+                head_sha,
+                base_sha,
+                ..
+            }
+            | ReviewSource::GitLabCompare {
+                host,
+                project_path,
+                head_sha,
+                base_sha,
+                ..
+            } => (
+                format!("https://{}/{}/-/blob", host, project_path),
+                head_sha,
+                base_sha,
+            ),
+            ReviewSource::GiteaPr {
+                host,
+                owner,
+                repo,
+                head_sha,
+                base_sha,
+                ..
+            } => (
+                format!("https://{}/{}/{}/src/commit", host, owner, repo),
+                head_sha,
+                base_sha,
+            ),
+        };
+        let sha = match side {
+            FeedbackSide::New => head_sha,
+            FeedbackSide::Old => base_sha,
+        };
+        Some(format!("{}/{}/{}#L{}", blob_url, sha.as_ref()?, path, line))
+    }
+
+    /// Tags worth offering for a review from this source: the provider plus
+    /// the owner/namespace and repository name.
+    pub fn suggested_tags(&self) -> Vec<String> {
+        let parts: Vec<&str> = match self {
+            ReviewSource::DiffPaste { .. } => vec![],
+            ReviewSource::GitHubPr { owner, repo, .. }
+            | ReviewSource::GitHubCompare { owner, repo, .. } => vec!["github", owner, repo],
+            ReviewSource::GiteaPr { owner, repo, .. } => vec!["gitea", owner, repo],
+            ReviewSource::GitLabMr { project_path, .. }
+            | ReviewSource::GitLabCompare { project_path, .. } => {
+                let mut parts = vec!["gitlab"];
+                let mut segments = project_path.split('/').filter(|s| !s.is_empty());
+                parts.extend(segments.next());
+                parts.extend(segments.next_back());
+                parts
+            }
+        };
+
+        let mut tags: Vec<String> = Vec::new();
+        for tag in parts.into_iter().filter_map(normalize_review_tag) {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
         }
+        tags
     }
 }
 
@@ -148,6 +558,53 @@ impl FromStr for ReviewRunStatus {
     }
 }
 
+/// How much effort a generation run puts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewMode {
+    /// Lighter prompt, no snapshot and a few tasks at most; for small diffs.
+    Quick,
+    /// Full prompt with the repo snapshot when one is available.
+    #[default]
+    Thorough,
+}
+
+/// Most tasks a quick review may produce.
+pub const QUICK_REVIEW_MAX_TASKS: usize = 3;
+
+/// Changed lines up to which a review defaults to quick mode, unless
+/// configured otherwise.
+pub const DEFAULT_QUICK_REVIEW_MAX_LINES: u32 = 50;
+
+impl ReviewMode {
+    /// Mode picked when the user didn't choose one: quick for diffs with at
+    /// most `quick_max_lines` added plus deleted lines.
+    pub fn for_changed_lines(changed_lines: usize, quick_max_lines: u32) -> Self {
+        if changed_lines <= quick_max_lines as usize {
+            Self::Quick
+        } else {
+            Self::Thorough
+        }
+    }
+
+    /// Cap on the tasks the agent may save, if any.
+    pub fn max_tasks(self) -> Option<usize> {
+        match self {
+            Self::Quick => Some(QUICK_REVIEW_MAX_TASKS),
+            Self::Thorough => None,
+        }
+    }
+}
+
+impl fmt::Display for ReviewMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Quick => write!(f, "quick"),
+            Self::Thorough => write!(f, "thorough"),
+        }
+    }
+}
+
 /// A single generation run for a review (diff + agent output).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReviewRun {
@@ -168,4 +625,71 @@ pub struct ReviewRun {
     pub status: ReviewRunStatus,
     /// Creation timestamp in RFC3339 format.
     pub created_at: String,
+    /// Model the agent reported running the review with, e.g.
+    /// `claude-sonnet-4-5`. `None` when it didn't say.
+    #[serde(default)]
+    pub agent_model: Option<String>,
+}
+
+/// The approach an agent laid out for a run, as it last reported it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunPlan {
+    pub entries: Vec<RunPlanEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunPlanEntry {
+    pub content: String,
+    pub priority: String,
+    pub status: String,
+}
+
+/// A tool call the agent made during a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunToolCall {
+    pub tool_call_id: String,
+    pub title: String,
+    pub kind: String,
+    pub status: String,
+    /// Only recorded for runs generated with ACP debugging on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_input: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_output: Option<serde_json::Value>,
+}
+
+/// The tool calls recorded for a run, in the order they started.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunToolLog {
+    pub calls: Vec<RunToolCall>,
+    /// Calls left out once the log reached its cap.
+    #[serde(default)]
+    pub omitted: usize,
+    /// Time spent in LaReview's own MCP tools, slowest first. Recorded by
+    /// the task MCP server as the run goes, so never part of the stored log.
+    #[serde(default, skip_deserializing)]
+    pub timings: Vec<ToolTiming>,
+}
+
+/// A reviewer's "viewed" checkbox for one file of a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileViewState {
+    pub run_id: ReviewRunId,
+    pub file_path: String,
+    pub viewed: bool,
+    /// [`DiffIndex::file_content_hash`](crate::infra::diff::index::DiffIndex::file_content_hash)
+    /// when the box was ticked.
+    pub content_hash: String,
+    pub updated_at: String,
+}
+
+/// How long the task MCP server spent in one tool during a run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolTiming {
+    pub tool: String,
+    pub calls: u64,
+    pub total_ms: u64,
+    pub max_ms: u64,
 }
@@ -175,6 +175,53 @@ pub struct ReviewTask {
     pub sub_flow: Option<String>,
 }
 
+impl ReviewTask {
+    /// Folds `other` into this task: titles and descriptions are joined, files,
+    /// hunks and tags are unioned, and the status becomes the more open of the
+    /// two. Identity, run and sub-flow stay this task's.
+    pub fn absorb(&mut self, other: ReviewTask) {
+        self.title = format!("{} + {}", self.title, other.title);
+        if !other.description.trim().is_empty() {
+            self.description = if self.description.trim().is_empty() {
+                other.description
+            } else {
+                format!("{}\n\n{}", self.description, other.description)
+            };
+        }
+        for file in other.files {
+            if !self.files.contains(&file) {
+                self.files.push(file);
+            }
+        }
+        for diff_ref in other.diff_refs {
+            match self.diff_refs.iter_mut().find(|r| r.file == diff_ref.file) {
+                Some(existing) => {
+                    for hunk in diff_ref.hunks {
+                        if !existing.hunks.contains(&hunk) {
+                            existing.hunks.push(hunk);
+                        }
+                    }
+                }
+                None => self.diff_refs.push(diff_ref),
+            }
+        }
+        self.stats.additions += other.stats.additions;
+        self.stats.deletions += other.stats.deletions;
+        self.stats.risk = self.stats.risk.max(other.stats.risk);
+        for tag in other.stats.tags {
+            if !self.stats.tags.contains(&tag) {
+                self.stats.tags.push(tag);
+            }
+        }
+        self.insight = self.insight.take().or(other.insight);
+        self.diagram = self.diagram.take().or(other.diagram);
+        self.ai_generated &= other.ai_generated;
+        if other.status.rank() < self.status.rank() {
+            self.status = other.status;
+        }
+    }
+}
+
 /// Status of a plan entry
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
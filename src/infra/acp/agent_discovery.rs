@@ -2,7 +2,10 @@
 //! Detects and manages available ACP (Agent Client Protocol) agents such as Codex, Qwen, Gemini, and Mistral ACP-Vibe.
 
 use crate::infra::app_config::{AppConfig, load_config};
+use serde::Serialize;
+use std::process::Stdio;
 use std::sync::Mutex;
+use std::time::Duration;
 
 /// Information about a discoverable ACP agent
 #[derive(Debug, Clone, PartialEq)]
@@ -19,6 +22,10 @@ pub struct AgentCandidate {
     pub args: Vec<String>,
     /// Whether the agent is available and can be executed
     pub available: bool,
+    /// Command looked up on PATH, shown when the agent isn't found
+    pub expected_command: String,
+    /// Homebrew command that installs the agent, when there is one
+    pub install_command: Option<String>,
 }
 
 // Cache for agent candidates with timestamp to allow refresh
@@ -112,6 +119,8 @@ pub fn list_agent_candidates() -> Vec<AgentCandidate> {
             command,
             args: custom.args.clone(),
             available,
+            expected_command: custom.command.clone(),
+            install_command: None,
         });
     }
 
@@ -124,6 +133,107 @@ pub fn list_agent_candidates() -> Vec<AgentCandidate> {
     candidates
 }
 
+/// How long a `--version` probe may run before it is abandoned.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Result of checking that an agent's binary exists and actually runs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgentProbe {
+    pub available: bool,
+    pub version: Option<String>,
+    /// Resolved executable path.
+    pub path: Option<String>,
+    /// Why the agent can't be used, when `available` is false.
+    pub error: Option<String>,
+}
+
+impl AgentProbe {
+    fn unavailable(path: Option<String>, error: String) -> Self {
+        Self {
+            available: false,
+            version: None,
+            path,
+            error: Some(error),
+        }
+    }
+}
+
+/// Resolves `command` and runs `<command> --version` to confirm it works.
+///
+/// A binary that exists but fails its version check (broken install, bad
+/// wrapper script) is reported unavailable with its stderr. A probe that
+/// times out is treated as available: some ACP adapters ignore `--version`
+/// and wait on stdin instead.
+pub async fn probe_agent_command(command: &str) -> AgentProbe {
+    if command.trim().is_empty() {
+        return AgentProbe::unavailable(None, "No command configured for this agent".into());
+    }
+
+    let Some(path) = crate::infra::shell::find_bin(command) else {
+        return AgentProbe::unavailable(
+            None,
+            format!(
+                "'{}' was not found. Install it or set its path in settings.",
+                command
+            ),
+        );
+    };
+    let path_str = path.to_string_lossy().to_string();
+
+    let child = tokio::process::Command::new(&path)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn();
+    let child = match child {
+        Ok(child) => child,
+        Err(err) => {
+            return AgentProbe::unavailable(
+                Some(path_str),
+                format!("Failed to run '{}': {}", command, err),
+            );
+        }
+    };
+
+    match tokio::time::timeout(PROBE_TIMEOUT, child.wait_with_output()).await {
+        Ok(Ok(output)) if output.status.success() => AgentProbe {
+            available: true,
+            version: first_line(&output.stdout).or_else(|| first_line(&output.stderr)),
+            path: Some(path_str),
+            error: None,
+        },
+        Ok(Ok(output)) => {
+            let detail = first_line(&output.stderr)
+                .or_else(|| first_line(&output.stdout))
+                .unwrap_or_else(|| output.status.to_string());
+            AgentProbe::unavailable(
+                Some(path_str),
+                format!("'{} --version' failed: {}", command, detail),
+            )
+        }
+        Ok(Err(err)) => AgentProbe::unavailable(
+            Some(path_str),
+            format!("Failed to run '{}': {}", command, err),
+        ),
+        Err(_) => AgentProbe {
+            available: true,
+            version: None,
+            path: Some(path_str),
+            error: None,
+        },
+    }
+}
+
+fn first_line(bytes: &[u8]) -> Option<String> {
+    String::from_utf8_lossy(bytes)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_string)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,6 +252,8 @@ mod tests {
             command: Some("ls".into()),
             args: vec![],
             available: false,
+            expected_command: "ls".into(),
+            install_command: None,
         };
 
         // Should resolve to full path if available
@@ -322,4 +434,54 @@ mod tests {
             std::env::remove_var("LAREVIEW_TEST_CUSTOM_ENV");
         }
     }
+
+    #[cfg(unix)]
+    fn script(dir: &std::path::Path, name: &str, body: &str) -> String {
+        use std::os::unix::fs::PermissionsExt;
+        let path = dir.join(name);
+        std::fs::write(&path, format!("#!/bin/sh\n{body}\n")).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_probe_reports_version_of_working_agent() {
+        let dir = tempfile::tempdir().unwrap();
+        let command = script(dir.path(), "agent-ok", "echo 'agent 1.2.3'");
+
+        let probe = probe_agent_command(&command).await;
+
+        assert!(probe.available);
+        assert_eq!(probe.version.as_deref(), Some("agent 1.2.3"));
+        assert_eq!(probe.path.as_deref(), Some(command.as_str()));
+        assert!(probe.error.is_none());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_probe_flags_agent_that_fails_to_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let command = script(
+            dir.path(),
+            "agent-broken",
+            "echo 'not logged in' >&2; exit 3",
+        );
+
+        let probe = probe_agent_command(&command).await;
+
+        assert!(!probe.available);
+        assert!(probe.error.unwrap().contains("not logged in"));
+        assert_eq!(probe.path.as_deref(), Some(command.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_probe_flags_missing_agent() {
+        let probe = probe_agent_command("/nonexistent/lareview-agent").await;
+        assert!(!probe.available);
+        assert!(probe.path.is_none());
+        assert!(probe.error.unwrap().contains("not found"));
+
+        assert!(!probe_agent_command("  ").await.available);
+    }
 }
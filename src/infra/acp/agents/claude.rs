@@ -6,5 +6,6 @@ crate::define_standard_acp_agent!(
     "Claude",
     "assets/icons/claude.svg",
     "npx",
-    ["-y", "@zed-industries/claude-code-acp"]
+    ["-y", "@zed-industries/claude-code-acp"],
+    install = "brew install node"
 );
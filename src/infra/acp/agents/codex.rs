@@ -33,6 +33,8 @@ pub fn codex_candidate() -> AgentCandidate {
         command: command.map(|path| path.to_string_lossy().to_string()),
         args,
         available,
+        expected_command: bin_override.unwrap_or_else(|| "npx".to_string()),
+        install_command: Some("brew install node".to_string()),
     }
 }
 
@@ -6,5 +6,6 @@ crate::define_standard_acp_agent!(
     "Gemini",
     "assets/icons/gemini.svg",
     "gemini",
-    ["--experimental-acp"]
+    ["--experimental-acp"],
+    install = "brew install gemini-cli"
 );
@@ -12,7 +12,22 @@ pub use super::agent_trait::AgentRegistry;
 
 #[macro_export]
 macro_rules! define_standard_acp_agent {
-    ($struct_name:ident, $id:expr, $label:expr, $logo:expr, $command:expr, $args:expr) => {
+    (@install) => {
+        None
+    };
+    (@install $install:expr) => {
+        Some($install.to_string())
+    };
+    (
+        $struct_name:ident,
+        $id:expr,
+        $label:expr,
+        $logo:expr,
+        $command:expr,
+        $args:expr
+        $(, install = $install:expr)?
+        $(,)?
+    ) => {
         pub struct $struct_name;
 
         impl $crate::infra::acp::agent_trait::AcpAgent for $struct_name {
@@ -36,6 +51,8 @@ macro_rules! define_standard_acp_agent {
                     command: command_path,
                     args: $args.iter().map(|s| s.to_string()).collect(),
                     available,
+                    expected_command: $command.to_string(),
+                    install_command: $crate::define_standard_acp_agent!(@install $($install)?),
                 }
             }
 
@@ -51,6 +68,15 @@ mod tests {
     use crate::infra::acp::agent_trait::AcpAgent;
 
     define_standard_acp_agent!(MockAgent, "mock", "Mock Agent", "logo.png", "ls", &["-la"]);
+    define_standard_acp_agent!(
+        InstallableAgent,
+        "installable",
+        "Installable Agent",
+        "logo.png",
+        "installable-agent-bin",
+        &["--acp"],
+        install = "brew install installable-agent"
+    );
 
     #[test]
     fn test_mock_agent() {
@@ -61,5 +87,17 @@ mod tests {
         let _ = agent.is_available();
         let candidate = agent.candidate();
         assert_eq!(candidate.id, "mock");
+        assert_eq!(candidate.expected_command, "ls");
+        assert_eq!(candidate.install_command, None);
+    }
+
+    #[test]
+    fn test_agent_with_install_command() {
+        let candidate = InstallableAgent.candidate();
+        assert_eq!(candidate.expected_command, "installable-agent-bin");
+        assert_eq!(
+            candidate.install_command.as_deref(),
+            Some("brew install installable-agent")
+        );
     }
 }
@@ -6,5 +6,6 @@ crate::define_standard_acp_agent!(
     "OpenCode",
     "assets/icons/opencode.svg",
     "opencode",
-    ["acp"]
+    ["acp"],
+    install = "brew install opencode"
 );
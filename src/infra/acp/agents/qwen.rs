@@ -6,5 +6,6 @@ crate::define_standard_acp_agent!(
     "Qwen Code",
     "assets/icons/qwen.svg",
     "qwen",
-    ["--experimental-acp"]
+    ["--experimental-acp"],
+    install = "brew install qwen-code"
 );
@@ -0,0 +1,87 @@
+//! On-demand rationale for a single piece of feedback.
+//!
+//! When the agent didn't say why it flagged something, the reviewer can ask.
+//! A short ACP session is opened with the feedback and the hunk around its
+//! anchor; the agent gets no tools and no file access, and whatever text it
+//! replies with is the rationale.
+
+use super::text_session::{TextPrompt, ask_agent};
+use crate::prompts;
+use anyhow::{Context, Result, anyhow};
+use serde_json::json;
+
+/// What the agent is asked to explain.
+pub struct ExplainFeedbackInput {
+    pub title: String,
+    pub impact: String,
+    /// First comment of the feedback.
+    pub body: Option<String>,
+    pub file_path: Option<String>,
+    pub line_number: Option<u32>,
+    /// The anchored hunk as unified diff lines, the anchored line marked `>`.
+    pub snippet: Option<String>,
+    /// Agent command to run (e.g., "claude")
+    pub agent_command: String,
+    /// Agent arguments
+    pub agent_args: Vec<String>,
+    /// Timeout in seconds
+    pub timeout_secs: Option<u64>,
+}
+
+/// Asks the agent why the feedback in `input` was flagged and returns its
+/// answer, trimmed.
+pub async fn explain_feedback(input: ExplainFeedbackInput) -> Result<String> {
+    let rationale = ask_agent(TextPrompt {
+        client_name: "lareview-explain",
+        prompt: build_explain_prompt(&input)?,
+        agent_command: input.agent_command,
+        agent_args: input.agent_args,
+        timeout_secs: input.timeout_secs.unwrap_or(120),
+    })
+    .await?;
+    if rationale.is_empty() {
+        return Err(anyhow!("Agent finished without explaining the feedback"));
+    }
+    Ok(rationale)
+}
+
+fn build_explain_prompt(input: &ExplainFeedbackInput) -> Result<String> {
+    prompts::render(
+        "explain_feedback",
+        &json!({
+            "title": input.title,
+            "impact": input.impact,
+            "body": input.body,
+            "file_path": input.file_path,
+            "line_number": input.line_number,
+            "snippet": input.snippet,
+        }),
+    )
+    .context("failed to render explain_feedback prompt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_explain_prompt() {
+        let input = ExplainFeedbackInput {
+            title: "Lock held across await".into(),
+            impact: "blocking".into(),
+            body: Some("The guard lives until the end of the block.".into()),
+            file_path: Some("src/sync.rs".into()),
+            line_number: Some(42),
+            snippet: Some("@@ -40,3 +40,4 @@\n let guard = lock();\n>+fetch().await;".into()),
+            agent_command: "agent".into(),
+            agent_args: Vec::new(),
+            timeout_secs: None,
+        };
+
+        let prompt = build_explain_prompt(&input).unwrap();
+        assert!(prompt.contains("Lock held across await"));
+        assert!(prompt.contains("`src/sync.rs:42`"));
+        assert!(prompt.contains(">+fetch().await;"));
+        assert!(prompt.contains("The guard lives"));
+    }
+}
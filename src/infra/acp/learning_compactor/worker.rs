@@ -206,6 +206,7 @@ async fn run_learning_compaction_inner(
 
     // Build the prompt
     let prompt = build_learning_prompt(&rejections, &existing_patterns)?;
+    let db_path = db.lock().ok().and_then(|db| db.path());
 
     let log_fn = |msg: String| {
         push_log(&logs, &msg, debug);
@@ -220,6 +221,9 @@ async fn run_learning_compaction_inner(
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .kill_on_drop(true);
+    if let Some(db_path) = &db_path {
+        cmd.env("LAREVIEW_DB_PATH", db_path);
+    }
 
     #[cfg(unix)]
     {
@@ -310,9 +314,9 @@ async fn run_learning_compaction_inner(
 
         // Build MCP server args - learning mode doesn't need PR context or repo root
         let mut mcp_args = vec!["--task-mcp-server".to_string()];
-        if let Ok(db_path) = std::env::var("LAREVIEW_DB_PATH") {
+        if let Some(db_path) = &db_path {
             mcp_args.push("--db-path".to_string());
-            mcp_args.push(db_path);
+            mcp_args.push(db_path.to_string_lossy().to_string());
         }
 
         let mcp_servers = vec![McpServer::Stdio(
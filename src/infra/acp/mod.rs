@@ -3,16 +3,27 @@
 mod agent_discovery;
 mod agent_trait;
 mod agents;
+mod feedback_explainer;
 mod learning_compactor;
+mod pr_summarizer;
+mod run_log;
 mod task_generator;
 mod task_mcp_server;
+mod text_session;
 
-pub use agent_discovery::{AgentCandidate, invalidate_agent_cache, list_agent_candidates};
+pub use agent_discovery::{
+    AgentCandidate, AgentProbe, invalidate_agent_cache, list_agent_candidates, probe_agent_command,
+};
 pub use agents::AgentRegistry;
+pub use feedback_explainer::{ExplainFeedbackInput, explain_feedback};
 pub use learning_compactor::{LearningCompactionInput, run_learning_compaction};
+pub use pr_summarizer::{SummarizeReviewInput, summarize_review};
+pub use run_log::{REDACTED, RunLog, find_run_log, known_secrets, redact_secrets, run_logs_dir};
 pub use task_generator::{
-    GenerateTasksInput, ProgressEvent, build_prompt_with_patterns, generate_tasks_with_acp,
+    AgentFailure, GenerateTasksInput, GenerationLimiter, MAX_GENERATION_LIMIT, ProgressEvent,
+    RejectionExample, build_prompt_with_patterns, default_generation_limit,
+    generate_tasks_with_acp, select_rejection_examples,
 };
-pub use task_mcp_server::RunContext;
 #[allow(unused_imports)]
 pub use task_mcp_server::run_task_mcp_server;
+pub use task_mcp_server::{RunContext, agent_tool_schemas};
@@ -0,0 +1,91 @@
+//! Narrative summary of a whole PR, without the task breakdown.
+//!
+//! A lighter alternative to task generation: the agent reads the diff once
+//! and writes the intent, key changes and risks, which become the review's
+//! summary. It runs through the same tool-less session as
+//! [`explain_feedback`](super::explain_feedback); tasks can still be
+//! generated for the diff afterwards.
+
+use super::task_generator::is_large_diff;
+use super::text_session::{TextPrompt, ask_agent};
+use crate::infra::diff::cached_diff_index;
+use crate::prompts;
+use anyhow::{Context, Result, anyhow};
+use serde_json::json;
+
+/// The PR to summarize.
+pub struct SummarizeReviewInput {
+    pub title: String,
+    pub diff_text: String,
+    /// Agent command to run (e.g., "claude")
+    pub agent_command: String,
+    /// Agent arguments
+    pub agent_args: Vec<String>,
+    /// Timeout in seconds
+    pub timeout_secs: Option<u64>,
+}
+
+/// Asks the agent for a summary of the PR in `input` and returns it, as
+/// Markdown.
+pub async fn summarize_review(input: SummarizeReviewInput) -> Result<String> {
+    let summary = ask_agent(TextPrompt {
+        client_name: "lareview-summarize",
+        prompt: build_summary_prompt(&input)?,
+        agent_command: input.agent_command,
+        agent_args: input.agent_args,
+        timeout_secs: input.timeout_secs.unwrap_or(300),
+    })
+    .await?;
+    if summary.is_empty() {
+        return Err(anyhow!("Agent finished without writing a summary"));
+    }
+    Ok(summary)
+}
+
+fn build_summary_prompt(input: &SummarizeReviewInput) -> Result<String> {
+    // Large diffs get the compact manifest instead, as in task generation.
+    let large_diff = is_large_diff(&input.diff_text);
+    let manifest = cached_diff_index(&input.diff_text)
+        .map(|index| {
+            if large_diff {
+                index.generate_compact_manifest()
+            } else {
+                index.generate_unified_manifest()
+            }
+        })
+        .unwrap_or_default();
+
+    prompts::render(
+        "summarize_review",
+        &json!({
+            "title": input.title,
+            "diff": (!large_diff).then_some(&input.diff_text),
+            "is_large_diff": large_diff,
+            "manifest": manifest,
+        }),
+    )
+    .context("failed to render summarize_review prompt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_summary_prompt() {
+        let input = SummarizeReviewInput {
+            title: "Cache parsed configs".into(),
+            diff_text: "diff --git a/src/config.rs b/src/config.rs\n--- a/src/config.rs\n+++ b/src/config.rs\n@@ -1,2 +1,3 @@\n fn load() {\n+    cache();\n }\n".into(),
+            agent_command: "agent".into(),
+            agent_args: Vec::new(),
+            timeout_secs: None,
+        };
+
+        let prompt = build_summary_prompt(&input).unwrap();
+
+        assert!(prompt.contains("Cache parsed configs"));
+        assert!(prompt.contains("+    cache();"));
+        assert!(prompt.contains("## Intent"));
+        assert!(prompt.contains("## Risks"));
+    }
+}
@@ -26,6 +26,8 @@ const TOOL_GET_HUNK: &str = "get_hunk";
 const TOOL_GET_FILE_HUNKS: &str = "get_file_hunks";
 const TOOL_SEARCH_DIFF: &str = "search_diff";
 const TOOL_LIST_DIFF_FILES: &str = "list_diff_files";
+const TOOL_GET_FILE_DIFF: &str = "get_file_diff";
+const TOOL_LIST_CHANGED_FILES: &str = "list_changed_files";
 // Merge confidence evaluation
 const TOOL_SUBMIT_MERGE_CONFIDENCE: &str = "submit_merge_confidence";
 
@@ -41,6 +43,8 @@ const LAREVIEW_TOOLS: &[&str] = &[
     TOOL_GET_FILE_HUNKS,
     TOOL_SEARCH_DIFF,
     TOOL_LIST_DIFF_FILES,
+    TOOL_GET_FILE_DIFF,
+    TOOL_LIST_CHANGED_FILES,
     // Merge confidence evaluation
     TOOL_SUBMIT_MERGE_CONFIDENCE,
 ];
@@ -1114,6 +1118,8 @@ mod tests {
         assert!(client.looks_like_return_tool("search_diff"));
         assert!(client.looks_like_return_tool("list_diff_files"));
         assert!(client.looks_like_return_tool("mcp__lareview-tasks__list_diff_files"));
+        assert!(client.looks_like_return_tool("get_file_diff"));
+        assert!(client.looks_like_return_tool("list_changed_files"));
 
         // Test merge confidence tool
         assert!(client.looks_like_return_tool("submit_merge_confidence"));
@@ -0,0 +1,170 @@
+//! Cap on how many agent generations run at once.
+//!
+//! Every generation spawns its own agent process, so kicking off several
+//! reviews in a row could otherwise run them all side by side and thrash the
+//! machine. Runs past the limit wait for a free slot instead.
+
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio_util::sync::CancellationToken;
+
+/// Highest limit the settings accept.
+pub const MAX_GENERATION_LIMIT: usize = 16;
+
+/// Default limit: half the CPUs, between 1 and 4 runs.
+pub fn default_generation_limit() -> usize {
+    std::thread::available_parallelism()
+        .map(|cpus| cpus.get() / 2)
+        .unwrap_or(1)
+        .clamp(1, 4)
+}
+
+#[derive(Debug)]
+struct Sizing {
+    limit: usize,
+    /// Permits to drop instead of returning, after the limit was lowered
+    /// while runs held them.
+    to_retire: usize,
+}
+
+/// Semaphore-backed limiter whose size can change while runs hold slots.
+#[derive(Debug)]
+pub struct GenerationLimiter {
+    semaphore: Arc<Semaphore>,
+    sizing: Arc<Mutex<Sizing>>,
+}
+
+/// A claimed slot; dropping it lets the next queued run start.
+#[derive(Debug)]
+pub struct GenerationSlot {
+    permit: Option<OwnedSemaphorePermit>,
+    sizing: Arc<Mutex<Sizing>>,
+}
+
+impl GenerationLimiter {
+    pub fn new(limit: usize) -> Self {
+        let limit = limit.clamp(1, MAX_GENERATION_LIMIT);
+        Self {
+            semaphore: Arc::new(Semaphore::new(limit)),
+            sizing: Arc::new(Mutex::new(Sizing {
+                limit,
+                to_retire: 0,
+            })),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.sizing.lock().limit
+    }
+
+    /// Changes the limit. Lowering it below the number of running
+    /// generations lets them finish; new runs start once enough have ended.
+    pub fn set_limit(&self, limit: usize) {
+        let limit = limit.clamp(1, MAX_GENERATION_LIMIT);
+        let mut sizing = self.sizing.lock();
+        if limit > sizing.limit {
+            let grow = limit - sizing.limit;
+            let unretired = grow.min(sizing.to_retire);
+            sizing.to_retire -= unretired;
+            self.semaphore.add_permits(grow - unretired);
+        } else {
+            let shrink = sizing.limit - limit;
+            let forgotten = self.semaphore.forget_permits(shrink);
+            sizing.to_retire += shrink - forgotten;
+        }
+        sizing.limit = limit;
+    }
+
+    /// Claims a slot if one is free right now.
+    pub fn try_acquire(&self) -> Option<GenerationSlot> {
+        let permit = self.semaphore.clone().try_acquire_owned().ok()?;
+        Some(self.slot(permit))
+    }
+
+    /// Waits for a free slot. Returns `None` if `cancel` fires first.
+    pub async fn acquire(&self, cancel: &CancellationToken) -> Option<GenerationSlot> {
+        tokio::select! {
+            biased;
+            _ = cancel.cancelled() => None,
+            permit = self.semaphore.clone().acquire_owned() => {
+                permit.ok().map(|permit| self.slot(permit))
+            }
+        }
+    }
+
+    fn slot(&self, permit: OwnedSemaphorePermit) -> GenerationSlot {
+        GenerationSlot {
+            permit: Some(permit),
+            sizing: self.sizing.clone(),
+        }
+    }
+}
+
+impl Default for GenerationLimiter {
+    fn default() -> Self {
+        Self::new(default_generation_limit())
+    }
+}
+
+impl Drop for GenerationSlot {
+    fn drop(&mut self) {
+        let Some(permit) = self.permit.take() else {
+            return;
+        };
+        let mut sizing = self.sizing.lock();
+        if sizing.to_retire > 0 {
+            sizing.to_retire -= 1;
+            permit.forget();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[tokio::test]
+    async fn test_run_past_the_limit_waits_for_a_slot() {
+        let limiter = GenerationLimiter::new(2);
+        let cancel = CancellationToken::new();
+        let first = limiter.acquire(&cancel).await.unwrap();
+        let _second = limiter.acquire(&cancel).await.unwrap();
+
+        let mut third = Box::pin(limiter.acquire(&cancel));
+        assert!((&mut third).now_or_never().is_none());
+
+        drop(first);
+        assert!(third.now_or_never().flatten().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_cancelling_a_queued_run_gives_up_its_place() {
+        let limiter = GenerationLimiter::new(1);
+        let _running = limiter.try_acquire().unwrap();
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        assert!(limiter.acquire(&cancel).await.is_none());
+    }
+
+    #[test]
+    fn test_lowering_the_limit_waits_for_running_generations() {
+        let limiter = GenerationLimiter::new(2);
+        let first = limiter.try_acquire().unwrap();
+        let second = limiter.try_acquire().unwrap();
+
+        limiter.set_limit(1);
+        drop(first);
+        assert!(limiter.try_acquire().is_none());
+
+        drop(second);
+        let only = limiter.try_acquire().unwrap();
+        assert!(limiter.try_acquire().is_none());
+
+        drop(only);
+        limiter.set_limit(3);
+        let slots: Vec<_> = (0..3).filter_map(|_| limiter.try_acquire()).collect();
+        assert_eq!(slots.len(), 3);
+    }
+}
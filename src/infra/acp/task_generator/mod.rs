@@ -4,13 +4,17 @@
 //! review tasks from git diffs using AI agents like Codex, Qwen, and Gemini.
 
 mod client;
+mod limiter;
 mod prompt;
 mod types;
 mod validation;
 mod worker;
 
-pub use prompt::build_prompt_with_patterns;
-pub use types::{GenerateTasksInput, GenerateTasksResult, ProgressEvent};
+pub use limiter::{GenerationLimiter, MAX_GENERATION_LIMIT, default_generation_limit};
+pub use prompt::{
+    RejectionExample, build_prompt_with_patterns, is_large_diff, select_rejection_examples,
+};
+pub use types::{AgentFailure, GenerateTasksInput, GenerateTasksResult, ProgressEvent};
 pub use worker::generate_tasks_with_acp;
 
 #[cfg(test)]
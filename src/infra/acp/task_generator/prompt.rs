@@ -1,16 +1,27 @@
-use crate::domain::{DefaultIssueCategory, LearnedPattern, ResolvedRule};
+use crate::domain::{
+    DefaultIssueCategory, IssueCheckDefinition, LearnedPattern, ResolvedRule, ReviewMode,
+};
 use crate::infra::acp::task_mcp_server::RunContext;
-use crate::infra::diff::index::DiffIndex;
+use crate::infra::db::repository::FeedbackRejection;
+use crate::infra::diff::cached_diff_index;
+use crate::infra::diff::generated::GeneratedFiles;
 use crate::prompts;
 use agent_client_protocol::{ClientCapabilities, FileSystemCapability, Meta};
 use anyhow::Context;
 use serde_json::json;
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
 /// Threshold for considering a diff "large" (~25k tokens).
 /// Diffs larger than this will use compact manifest mode.
 const LARGE_DIFF_THRESHOLD_CHARS: usize = 100_000;
 
+/// Most recently rejected feedback titles shown to the agent.
+const MAX_REJECTION_EXAMPLES: usize = 10;
+
+/// Rejected titles longer than this are cut short in the prompt.
+const MAX_REJECTION_TITLE_CHARS: usize = 120;
+
 /// Check if a diff is considered "large" based on character count.
 pub fn is_large_diff(diff_text: &str) -> bool {
     diff_text.len() > LARGE_DIFF_THRESHOLD_CHARS
@@ -38,19 +49,86 @@ struct LearnedPatternItem {
     source_count: i32,
 }
 
+/// A recently rejected feedback title, shown as an example of what the team
+/// doesn't want.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct RejectionExample {
+    pub title: String,
+    pub file_extension: Option<String>,
+}
+
+/// Picks the most recent rejections relevant to a diff: ones on a file type
+/// the diff touches (or with no file), and from the same repository when
+/// `repo_review_ids` lists its reviews. Titles are de-duplicated and
+/// truncated; `recent` is expected newest first.
+pub fn select_rejection_examples(
+    recent: &[FeedbackRejection],
+    diff_paths: &[String],
+    repo_review_ids: Option<&HashSet<String>>,
+) -> Vec<RejectionExample> {
+    let extensions: HashSet<&str> = diff_paths
+        .iter()
+        .filter_map(|path| Path::new(path).extension().and_then(|ext| ext.to_str()))
+        .collect();
+
+    let mut seen = HashSet::new();
+    recent
+        .iter()
+        .filter(|rejection| {
+            repo_review_ids.is_none_or(|ids| ids.contains(&rejection.review_id))
+                && rejection
+                    .file_extension
+                    .as_deref()
+                    .is_none_or(|ext| extensions.contains(ext))
+        })
+        .filter(|rejection| seen.insert(rejection.title.trim().to_lowercase()))
+        .take(MAX_REJECTION_EXAMPLES)
+        .map(|rejection| RejectionExample {
+            title: truncate_title(rejection.title.trim()),
+            file_extension: rejection.file_extension.clone(),
+        })
+        .collect()
+}
+
+fn truncate_title(title: &str) -> String {
+    if title.chars().count() <= MAX_REJECTION_TITLE_CHARS {
+        return title.to_string();
+    }
+    let cut: String = title.chars().take(MAX_REJECTION_TITLE_CHARS - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+#[cfg(test)]
 pub(super) fn build_prompt(
     run: &RunContext,
     repo_root: Option<&PathBuf>,
     rules: &[ResolvedRule],
+    issue_checks: &[IssueCheckDefinition],
 ) -> anyhow::Result<String> {
-    build_prompt_with_patterns(run, repo_root, rules, &[])
+    build_prompt_with_patterns(
+        run,
+        ReviewMode::Thorough,
+        repo_root,
+        None,
+        rules,
+        issue_checks,
+        &[],
+        &[],
+    )
 }
 
+/// `prompt_root` is where a repository's own review prompt is looked up; see
+/// [`GenerateTasksInput::prompt_root`](super::GenerateTasksInput::prompt_root).
+#[allow(clippy::too_many_arguments)]
 pub fn build_prompt_with_patterns(
     run: &RunContext,
+    mode: ReviewMode,
     repo_root: Option<&PathBuf>,
+    prompt_root: Option<&Path>,
     rules: &[ResolvedRule],
+    issue_checks: &[IssueCheckDefinition],
     learned_patterns: &[LearnedPattern],
+    rejection_examples: &[RejectionExample],
 ) -> anyhow::Result<String> {
     let has_repo_access = repo_root.is_some();
     let source_json = serde_json::to_string(&run.source).unwrap_or_default();
@@ -62,20 +140,61 @@ pub fn build_prompt_with_patterns(
     // For large diffs, generate compact manifest instead of including full diff
     let (diff_content, unified_manifest, compact_manifest) = if large_diff {
         // Large diff mode: no full diff, use compact manifest
-        let compact = match DiffIndex::new(&run.diff_text) {
+        let compact = match cached_diff_index(&run.diff_text) {
             Ok(index) => index.generate_compact_manifest(),
             Err(_) => String::new(),
         };
         (None, String::new(), Some(compact))
     } else {
         // Normal mode: include full diff and unified manifest
-        let unified = match DiffIndex::new(&run.diff_text) {
+        let unified = match cached_diff_index(&run.diff_text) {
             Ok(index) => index.generate_unified_manifest(),
             Err(_) => String::new(),
         };
         (Some(run.diff_text.to_string()), unified, None)
     };
 
+    // Generated files (lockfiles, build output) the agent should only skim
+    let generated = GeneratedFiles::from_config(repo_root.map(PathBuf::as_path));
+    let mut generated_files: Vec<String> = cached_diff_index(&run.diff_text)
+        .map(|index| index.get_all_file_paths())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|path| generated.is_generated(path))
+        .collect();
+    generated_files.sort();
+
+    // Submodule pointer bumps, whose code isn't in the diff
+    let submodules: Vec<String> = cached_diff_index(&run.diff_text)
+        .map(|index| {
+            index
+                .submodules
+                .values()
+                .map(|change| {
+                    let short = |commit: &Option<String>| {
+                        commit
+                            .as_deref()
+                            .map_or("none", |sha| &sha[..sha.len().min(12)])
+                            .to_string()
+                    };
+                    format!(
+                        "{}: {} → {}",
+                        change.path,
+                        short(&change.old_commit),
+                        short(&change.new_commit)
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Functions and types around the changes in big files, for the agent to
+    // read whole and group tasks by
+    let changed_symbols = match (repo_root, cached_diff_index(&run.diff_text)) {
+        (Some(root), Ok(index)) => crate::infra::symbols::changed_symbols(&index, root),
+        _ => Vec::new(),
+    };
+
     // Convert all rules to rule items for the template
     let rule_items: Vec<RuleItem> = rules
         .iter()
@@ -109,8 +228,28 @@ pub fn build_prompt_with_patterns(
         })
         .collect();
 
-    prompts::render(
-        "generate_tasks",
+    // A repo's own prompt replaces the thorough one; quick reviews keep theirs.
+    // A change that edits that prompt doesn't get reviewed by its own version.
+    let prompt_root = prompt_root.filter(|_| {
+        let edits_prompt = cached_diff_index(&run.diff_text)
+            .map(|index| index.get_all_file_paths())
+            .unwrap_or_default()
+            .iter()
+            .any(|path| path == prompts::REPO_PROMPT_PATH);
+        if edits_prompt {
+            log::warn!(
+                "Ignoring {}: the diff under review changes it",
+                prompts::REPO_PROMPT_PATH
+            );
+        }
+        !edits_prompt
+    });
+    let template = match mode {
+        ReviewMode::Quick => "generate_tasks_quick",
+        ReviewMode::Thorough => "generate_tasks",
+    };
+    prompts::render_for_repo(
+        template,
         &json!({
             "review_id": run.review_id,
             "source_json": source_json,
@@ -123,18 +262,33 @@ pub fn build_prompt_with_patterns(
             "has_repo_access": has_repo_access,
             "repo_root": repo_root.map(|p| p.display().to_string()),
             "repo_access_note": if has_repo_access { "read-only" } else { "none" },
+            "has_generated_files": !generated_files.is_empty(),
+            "generated_files": generated_files,
+            "has_submodules": !submodules.is_empty(),
+            "submodules": submodules,
+            "has_changed_symbols": !changed_symbols.is_empty(),
+            "changed_symbols": changed_symbols,
             // All rules are treated equally - verified by AI
             "has_rules": !rule_items.is_empty(),
             "rules": rule_items,
             // Default categories (built-in)
             "has_default_categories": !default_categories.is_empty(),
             "default_categories": default_categories,
+            // User-defined checks
+            "has_custom_checks": !issue_checks.is_empty(),
+            "custom_checks": issue_checks,
             // Learned patterns from rejection analysis
             "has_learned_patterns": !learned_pattern_items.is_empty(),
             "learned_patterns": learned_pattern_items,
+            // Recently rejected feedback, when the user opted in
+            "has_rejection_examples": !rejection_examples.is_empty(),
+            "rejection_examples": rejection_examples,
+            // Quick reviews only
+            "max_tasks": mode.max_tasks(),
         }),
+        prompt_root,
     )
-    .context("failed to render generate_tasks prompt")
+    .with_context(|| format!("failed to render {} prompt", template))
 }
 
 /// Format a category ID into a display name
@@ -1,4 +1,5 @@
-use crate::domain::ResolvedRule;
+use super::prompt::RejectionExample;
+use crate::domain::{IssueCheckDefinition, ResolvedRule, ReviewMode};
 use crate::infra::acp::task_mcp_server::RunContext;
 use std::path::PathBuf;
 
@@ -8,11 +9,23 @@ pub struct GenerateTasksInput {
     pub run_context: RunContext,
     /// Review rules that apply to this run and should be injected into the prompt.
     pub rules: Vec<ResolvedRule>,
+    /// User-defined issue checks the agent must report a result for.
+    pub issue_checks: Vec<IssueCheckDefinition>,
+    /// Recently rejected feedback to steer the agent away from; empty unless
+    /// the user opted in.
+    pub rejection_examples: Vec<RejectionExample>,
+    /// Quick runs get the trimmed prompt and the MCP server holds them to the
+    /// mode's task cap.
+    pub mode: ReviewMode,
     /// Optional repository root for read-only context.
     ///
     /// When this is None, the agent must operate diff-only without filesystem or terminal access.
     /// When Some, the agent may read files under this root for context only.
     pub repo_root: Option<PathBuf>,
+    /// Checkout whose `.lareview/prompt.hbs` may replace the review prompt:
+    /// the linked repo, never a snapshot of the head under review, which the
+    /// change's author controls.
+    pub prompt_root: Option<PathBuf>,
     /// Optional snapshot path to cleanup after generation.
     pub cleanup_path: Option<PathBuf>,
     /// Command to execute the ACP agent.
@@ -23,6 +36,8 @@ pub struct GenerateTasksInput {
     pub progress_tx: Option<tokio::sync::mpsc::UnboundedSender<ProgressEvent>>,
     /// Override for MCP server binary path.
     pub mcp_server_binary: Option<PathBuf>,
+    /// Database the MCP server writes tasks to; None lets it pick the default.
+    pub db_path: Option<PathBuf>,
     /// Timeout in seconds for agent execution.
     pub timeout_secs: Option<u64>,
     /// Optional cancellation token to stop the agent.
@@ -62,6 +77,8 @@ pub enum ProgressEvent {
     },
     /// Plan update (sent as complete object).
     Plan(agent_client_protocol::Plan),
+    /// The model the agent reported when its session started.
+    AgentModel(String),
     /// Local log output from the ACP worker/process.
     LocalLog(String),
     /// Signal that the agent has finished its work (received finalize_review).
@@ -75,3 +92,30 @@ pub enum ProgressEvent {
     /// Review metadata (title/summary) has been updated by the MCP server.
     MetadataUpdated,
 }
+
+/// Context attached to a failed generation carrying the tail of the agent's
+/// stderr, which usually names the real cause (bad auth, unknown model, ...).
+///
+/// Retrieve it with `err.downcast_ref::<AgentFailure>()`.
+#[derive(Debug, Clone)]
+pub struct AgentFailure {
+    /// The error message without stderr.
+    pub summary: String,
+    /// Last stderr lines, ANSI-stripped and truncated.
+    pub stderr_tail: Vec<String>,
+}
+
+impl std::fmt::Display for AgentFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.summary)?;
+        if !self.stderr_tail.is_empty() {
+            write!(
+                f,
+                "\n\nAgent stderr (last {} lines):\n{}",
+                self.stderr_tail.len(),
+                self.stderr_tail.join("\n")
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -1,13 +1,18 @@
 use super::client::LaReviewClient;
-use super::prompt::{build_client_capabilities, build_prompt};
+use super::prompt::{build_client_capabilities, build_prompt_with_patterns};
+use super::types::AgentFailure;
 use super::validation::validate_tasks_payload;
+use crate::infra::acp::task_mcp_server::RunContext;
 use agent_client_protocol::{
     Agent, ClientSideConnection, ContentBlock, Implementation, InitializeRequest, McpServer,
-    McpServerStdio, NewSessionRequest, PromptRequest, ProtocolVersion, TextContent,
+    McpServerStdio, NewSessionRequest, NewSessionResponse, PromptRequest, ProtocolVersion,
+    TextContent,
 };
 use anyhow::{Context as _, Result};
 use futures::future::LocalBoxFuture;
 use log::debug;
+use regex::Regex;
+use std::collections::VecDeque;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::{Arc, Mutex};
@@ -18,6 +23,44 @@ use tokio::process::Command;
 use tokio::runtime::Builder;
 use tokio::task::LocalSet;
 
+/// Number of trailing stderr lines attached to a failed generation.
+const STDERR_TAIL_LINES: usize = 20;
+/// Longest stderr line kept in the tail; longer lines are cut.
+const STDERR_LINE_MAX_CHARS: usize = 500;
+
+lazy_static::lazy_static! {
+    /// CSI sequences (colors, cursor movement) and OSC sequences (titles, links).
+    static ref ANSI_ESCAPE: Regex =
+        Regex::new(r"\x1b\[[0-9;?]*[ -/]*[@-~]|\x1b\][^\x07\x1b]*(?:\x07|\x1b\\)").unwrap();
+}
+
+fn strip_ansi(line: &str) -> String {
+    ANSI_ESCAPE.replace_all(line, "").into_owned()
+}
+
+/// Cleans one stderr line for display in an error: no escape codes, bounded length.
+fn sanitize_stderr_line(line: &str) -> String {
+    let line = strip_ansi(line);
+    let line = line.trim_end();
+    match line.char_indices().nth(STDERR_LINE_MAX_CHARS) {
+        Some((cut, _)) => format!("{}…", &line[..cut]),
+        None => line.to_string(),
+    }
+}
+
+fn push_stderr_tail(tail: &Mutex<VecDeque<String>>, line: &str) {
+    let line = sanitize_stderr_line(line);
+    if line.trim().is_empty() {
+        return;
+    }
+    if let Ok(mut tail) = tail.lock() {
+        if tail.len() == STDERR_TAIL_LINES {
+            tail.pop_front();
+        }
+        tail.push_back(line);
+    }
+}
+
 async fn cleanup_snapshot_dir(path: &Path) {
     let mut retries = 5;
     let mut delay = Duration::from_millis(200);
@@ -184,12 +227,17 @@ async fn generate_tasks_with_acp_inner_impl(
     let GenerateTasksInput {
         run_context,
         rules,
+        issue_checks,
+        rejection_examples,
+        mode,
         repo_root,
+        prompt_root,
         cleanup_path: _,
         agent_command,
         agent_args,
         progress_tx,
         mcp_server_binary,
+        db_path,
         timeout_secs: _,
         cancel_token,
         debug,
@@ -198,6 +246,12 @@ async fn generate_tasks_with_acp_inner_impl(
     let logs = Arc::new(Mutex::new(Vec::new()));
     let progress_tx = progress_tx;
 
+    // The MCP server rejects tasks past the mode's cap.
+    let run_context = RunContext {
+        max_tasks: mode.max_tasks(),
+        ..run_context
+    };
+
     let has_repo_access = repo_root.is_some();
 
     // Spawn agent process
@@ -223,6 +277,9 @@ async fn generate_tasks_with_acp_inner_impl(
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .kill_on_drop(true);
+    if let Some(db_path) = &db_path {
+        cmd.env("LAREVIEW_DB_PATH", db_path);
+    }
 
     #[cfg(unix)]
     {
@@ -258,13 +315,16 @@ async fn generate_tasks_with_acp_inner_impl(
 
     let logs_clone = logs.clone();
     let progress_tx_for_stderr = progress_tx.clone();
+    let stderr_tail = Arc::new(Mutex::new(VecDeque::with_capacity(STDERR_TAIL_LINES)));
+    let stderr_tail_for_reader = stderr_tail.clone();
 
     // Spawn a background task to monitor the agent's stderr. Logs are forwarded
     // to the UI's progress stream to aid in debugging agent-side issues.
-    tokio::spawn(async move {
+    let stderr_reader = tokio::spawn(async move {
         let reader = BufReader::new(stderr);
         let mut lines = reader.lines();
         while let Ok(Some(line)) = lines.next_line().await {
+            push_stderr_tail(&stderr_tail_for_reader, &line);
             let msg = format!("stderr: {line}");
             push_log(&logs_clone, &msg, debug);
             if let Some(tx) = &progress_tx_for_stderr {
@@ -337,9 +397,9 @@ async fn generate_tasks_with_acp_inner_impl(
             "--pr-context".to_string(),
             pr_context_file.path().to_string_lossy().to_string(),
         ];
-        if let Ok(db_path) = std::env::var("LAREVIEW_DB_PATH") {
+        if let Some(db_path) = &db_path {
             mcp_args.push("--db-path".to_string());
-            mcp_args.push(db_path);
+            mcp_args.push(db_path.to_string_lossy().to_string());
         }
         if let Some(root) = &repo_root {
             mcp_args.push("--repo-root".to_string());
@@ -359,9 +419,24 @@ async fn generate_tasks_with_acp_inner_impl(
             .await
             .with_context(|| "ACP new_session failed")?;
         push_log(&logs, "new_session ok", debug);
+        if let Some(model) = session_model(&session) {
+            log_fn(format!("agent model: {model}"));
+            if let Some(tx) = &progress_tx {
+                let _ = tx.send(ProgressEvent::AgentModel(model));
+            }
+        }
 
         // Send prompt
-        let prompt_text = build_prompt(&run_context, repo_root.as_ref(), &rules)?;
+        let prompt_text = build_prompt_with_patterns(
+            &run_context,
+            mode,
+            repo_root.as_ref(),
+            prompt_root.as_deref(),
+            &rules,
+            &issue_checks,
+            &[],
+            &rejection_examples,
+        )?;
         push_log(&logs, "prompt", debug);
         let prompt_result = connection
             .prompt(PromptRequest::new(
@@ -528,15 +603,69 @@ async fn generate_tasks_with_acp_inner_impl(
         kill_process_group(child_pid, &logs, debug);
         let _ = child.wait().await;
         process_guard.disarm();
+        // Let the reader drain what the agent wrote before it died; that is
+        // usually the real cause of the failure.
+        let _ = tokio::time::timeout(Duration::from_millis(500), stderr_reader).await;
     }
 
-    result
+    result.map_err(|err| {
+        let stderr_tail: Vec<String> = stderr_tail
+            .lock()
+            .map(|tail| tail.iter().cloned().collect())
+            .unwrap_or_default();
+        if stderr_tail.is_empty() || err.to_string().contains("cancelled by user") {
+            return err;
+        }
+        let summary = err.to_string();
+        err.context(AgentFailure {
+            summary,
+            stderr_tail,
+        })
+    })
+}
+
+/// The model the agent says the session runs on: the current entry of its
+/// model list, or a `model` in the response's `_meta` for agents that only
+/// report it there.
+fn session_model(session: &NewSessionResponse) -> Option<String> {
+    let value = serde_json::to_value(session).ok()?;
+    ["/models/currentModelId", "/_meta/model"]
+        .iter()
+        .find_map(|pointer| value.pointer(pointer)?.as_str())
+        .map(str::trim)
+        .filter(|model| !model.is_empty())
+        .map(str::to_string)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn new_session(json: serde_json::Value) -> NewSessionResponse {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_session_model() {
+        let listed = new_session(serde_json::json!({
+            "sessionId": "s1",
+            "models": {
+                "currentModelId": "claude-sonnet-4-5",
+                "availableModels": [{ "modelId": "claude-sonnet-4-5", "name": "Sonnet" }],
+            },
+        }));
+        assert_eq!(session_model(&listed).as_deref(), Some("claude-sonnet-4-5"));
+
+        let meta = new_session(serde_json::json!({
+            "sessionId": "s1",
+            "_meta": { "model": "gpt-5-codex" },
+        }));
+        assert_eq!(session_model(&meta).as_deref(), Some("gpt-5-codex"));
+
+        let silent = new_session(serde_json::json!({ "sessionId": "s1" }));
+        assert_eq!(session_model(&silent), None);
+    }
+
     #[test]
     fn test_resolve_task_mcp_server_path() {
         let current = PathBuf::from("/bin/lareview");
@@ -551,6 +680,89 @@ mod tests {
         assert_eq!(resolve_task_mcp_server_path(None, &current), current);
     }
 
+    #[test]
+    fn test_sanitize_stderr_line_strips_ansi_and_truncates() {
+        assert_eq!(
+            sanitize_stderr_line("\x1b[31merror:\x1b[0m model not found  "),
+            "error: model not found"
+        );
+        assert_eq!(
+            sanitize_stderr_line("\x1b]0;title\x07plain"),
+            "plain".to_string()
+        );
+
+        let long = "é".repeat(STDERR_LINE_MAX_CHARS + 10);
+        let cut = sanitize_stderr_line(&long);
+        assert_eq!(cut.chars().count(), STDERR_LINE_MAX_CHARS + 1);
+        assert!(cut.ends_with('…'));
+    }
+
+    #[test]
+    fn test_stderr_tail_keeps_last_lines() {
+        let tail = Mutex::new(VecDeque::new());
+        for i in 0..(STDERR_TAIL_LINES + 5) {
+            push_stderr_tail(&tail, &format!("line {i}"));
+        }
+        push_stderr_tail(&tail, "   ");
+
+        let tail = tail.into_inner().unwrap();
+        assert_eq!(tail.len(), STDERR_TAIL_LINES);
+        assert_eq!(tail.front().unwrap(), "line 5");
+        assert_eq!(
+            tail.back().unwrap(),
+            &format!("line {}", STDERR_TAIL_LINES + 4)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_generation_carries_agent_stderr() {
+        use crate::infra::acp::RunContext;
+        let input = GenerateTasksInput {
+            run_context: RunContext {
+                review_id: "r".into(),
+                run_id: "run".into(),
+                agent_id: "a".into(),
+                input_ref: "ref".into(),
+                diff_text: "diff".into(),
+                diff_hash: "h".into(),
+                source: crate::domain::ReviewSource::DiffPaste {
+                    diff_hash: "h".into(),
+                },
+                initial_title: None,
+                created_at: None,
+                max_tasks: None,
+            },
+            rules: Vec::new(),
+            issue_checks: Vec::new(),
+            rejection_examples: Vec::new(),
+            mode: crate::domain::ReviewMode::Thorough,
+            repo_root: None,
+            prompt_root: None,
+            cleanup_path: None,
+            agent_command: "sh".into(),
+            agent_args: vec![
+                "-c".into(),
+                "printf '\\033[31mError: invalid API key\\033[0m\\n' >&2; exit 1".into(),
+            ],
+            progress_tx: None,
+            mcp_server_binary: None,
+            db_path: None,
+            timeout_secs: Some(10),
+            cancel_token: None,
+            debug: false,
+        };
+
+        let err = generate_tasks_with_acp(input).await.unwrap_err();
+        let failure = err
+            .downcast_ref::<AgentFailure>()
+            .expect("stderr should be attached to the error");
+        assert_eq!(
+            failure.stderr_tail,
+            vec!["Error: invalid API key".to_string()]
+        );
+        assert!(err.to_string().contains("Agent stderr"));
+    }
+
     #[test]
     fn test_push_log() {
         let logs = Arc::new(Mutex::new(Vec::new()));
@@ -575,15 +787,21 @@ mod tests {
                 },
                 initial_title: None,
                 created_at: None,
+                max_tasks: None,
             },
             rules: Vec::new(),
+            issue_checks: Vec::new(),
+            rejection_examples: Vec::new(),
+            mode: crate::domain::ReviewMode::Thorough,
             repo_root: None,
+            prompt_root: None,
             cleanup_path: None,
             agent_command: "sleep".into(),
 
             agent_args: vec!["10".into()],
             progress_tx: None,
             mcp_server_binary: None,
+            db_path: None,
             timeout_secs: Some(1),
             cancel_token: None,
             debug: false,
@@ -615,14 +833,20 @@ mod tests {
                 },
                 initial_title: None,
                 created_at: None,
+                max_tasks: None,
             },
             rules: Vec::new(),
+            issue_checks: Vec::new(),
+            rejection_examples: Vec::new(),
+            mode: crate::domain::ReviewMode::Thorough,
             repo_root: None,
+            prompt_root: None,
             cleanup_path: None,
             agent_command: "sleep".into(),
             agent_args: vec!["10".into()],
             progress_tx: None,
             mcp_server_binary: None,
+            db_path: None,
             timeout_secs: Some(10),
             cancel_token: Some(token_clone),
             debug: false,
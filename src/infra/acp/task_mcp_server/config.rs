@@ -1,4 +1,30 @@
-use std::path::PathBuf;
+use anyhow::{Context, Result, bail};
+use base64::Engine;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Caps on what `repo_search` and `repo_list_files` return per call, so a
+/// broad query can't flood the agent's context. Results past a cap are left
+/// for the agent to fetch with `offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputLimits {
+    /// Most matches or entries per call; a request's `limit` can only lower it.
+    pub max_results: usize,
+    /// Longest matched line returned, in bytes; longer ones are cut short.
+    pub max_result_bytes: usize,
+    /// Most bytes of results (as JSON) per call.
+    pub max_total_bytes: usize,
+}
+
+impl Default for OutputLimits {
+    fn default() -> Self {
+        Self {
+            max_results: 500,
+            max_result_bytes: 400,
+            max_total_bytes: 48 * 1024,
+        }
+    }
+}
 
 /// Configuration for the MCP server, parsed from CLI arguments.
 #[derive(Debug, Clone, Default)]
@@ -13,17 +39,24 @@ pub struct ServerConfig {
     pub repo_root: Option<PathBuf>,
     /// Optional database path override.
     pub db_path: Option<PathBuf>,
+    /// Diff to review, from `--diff-file` or `--diff-base64`; replaces the
+    /// run context's diff so other tools can drive a review directly.
+    pub diff_text: Option<Arc<str>>,
+    /// Output caps for the repo tools (`--max-results`, `--max-result-bytes`,
+    /// `--max-output-bytes`).
+    pub output_limits: OutputLimits,
 }
 
 impl ServerConfig {
     /// Parse server configuration from command-line arguments.
-    pub fn from_args() -> Self {
+    pub fn from_args() -> Result<Self> {
         let args: Vec<String> = std::env::args().collect();
         Self::from_iter(args)
     }
 
-    /// Parse server configuration from an iterator of strings.
-    pub fn from_iter<I>(iter: I) -> Self
+    /// Parse server configuration from an iterator of strings. Fails when a
+    /// `--diff-file` can't be read or a `--diff-base64` doesn't decode.
+    pub fn from_iter<I>(iter: I) -> Result<Self>
     where
         I: IntoIterator<Item = String>,
     {
@@ -89,12 +122,59 @@ impl ServerConfig {
                         i += 1;
                     }
                 }
+                "--diff-file" => {
+                    if i + 1 < args.len() {
+                        config.diff_text = Some(read_diff_file(Path::new(&args[i + 1]))?);
+                        i += 2;
+                    } else {
+                        bail!("--diff-file needs a path");
+                    }
+                }
+                "--diff-base64" => {
+                    if i + 1 < args.len() {
+                        config.diff_text = Some(decode_diff_base64(&args[i + 1])?);
+                        i += 2;
+                    } else {
+                        bail!("--diff-base64 needs a value");
+                    }
+                }
+                flag @ ("--max-results" | "--max-result-bytes" | "--max-output-bytes") => {
+                    let value = args
+                        .get(i + 1)
+                        .and_then(|value| value.parse::<usize>().ok())
+                        .filter(|value| *value > 0)
+                        .with_context(|| format!("{flag} needs a positive number"))?;
+                    let limits = &mut config.output_limits;
+                    match flag {
+                        "--max-results" => limits.max_results = value,
+                        "--max-result-bytes" => limits.max_result_bytes = value,
+                        _ => limits.max_total_bytes = value,
+                    }
+                    i += 2;
+                }
                 _ => i += 1,
             }
         }
 
-        config
+        Ok(config)
+    }
+}
+
+fn read_diff_file(path: &Path) -> Result<Arc<str>> {
+    if !path.is_file() {
+        bail!("--diff-file {} doesn't exist", path.display());
     }
+    let diff = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read --diff-file {}", path.display()))?;
+    Ok(diff.into())
+}
+
+fn decode_diff_base64(encoded: &str) -> Result<Arc<str>> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded.trim())
+        .context("--diff-base64 isn't valid base64")?;
+    let diff = String::from_utf8(bytes).context("--diff-base64 doesn't decode to UTF-8 text")?;
+    Ok(diff.into())
 }
 
 #[cfg(test)]
@@ -117,7 +197,7 @@ mod tests {
             "/tmp/test.db".to_string(),
         ];
 
-        let config = ServerConfig::from_iter(args);
+        let config = ServerConfig::from_iter(args).unwrap();
 
         assert_eq!(config.tasks_out, Some(PathBuf::from("/tmp/tasks.json")));
         assert_eq!(config.log_file, Some(PathBuf::from("/tmp/debug.log")));
@@ -129,7 +209,71 @@ mod tests {
     #[test]
     fn test_server_config_incomplete_args() {
         let args = vec!["program_name".to_string(), "--tasks-out".to_string()];
-        let config = ServerConfig::from_iter(args);
+        let config = ServerConfig::from_iter(args).unwrap();
         assert_eq!(config.tasks_out, None);
     }
+
+    #[test]
+    fn test_server_config_output_limits() {
+        let config = ServerConfig::from_iter(vec!["program_name".to_string()]).unwrap();
+        assert_eq!(config.output_limits, OutputLimits::default());
+
+        let args = [
+            "program_name",
+            "--max-results",
+            "50",
+            "--max-output-bytes",
+            "8192",
+        ];
+        let config = ServerConfig::from_iter(args.map(String::from)).unwrap();
+        assert_eq!(config.output_limits.max_results, 50);
+        assert_eq!(config.output_limits.max_total_bytes, 8192);
+        assert_eq!(
+            config.output_limits.max_result_bytes,
+            OutputLimits::default().max_result_bytes
+        );
+
+        let args = ["program_name", "--max-results", "0"];
+        assert!(ServerConfig::from_iter(args.map(String::from)).is_err());
+    }
+
+    #[test]
+    fn test_server_config_loads_diff_file() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "diff --git a/a b/a\n").unwrap();
+        let args = vec![
+            "program_name".to_string(),
+            "--diff-file".to_string(),
+            file.path().to_string_lossy().into_owned(),
+        ];
+        let config = ServerConfig::from_iter(args).unwrap();
+        assert_eq!(config.diff_text.as_deref(), Some("diff --git a/a b/a\n"));
+
+        let args = vec![
+            "program_name".to_string(),
+            "--diff-file".to_string(),
+            "/nonexistent/change.diff".to_string(),
+        ];
+        let err = ServerConfig::from_iter(args).unwrap_err();
+        assert!(err.to_string().contains("doesn't exist"));
+    }
+
+    #[test]
+    fn test_server_config_decodes_diff_base64() {
+        let encoded = base64::engine::general_purpose::STANDARD.encode("diff --git a/a b/a\n");
+        let args = vec![
+            "program_name".to_string(),
+            "--diff-base64".to_string(),
+            encoded,
+        ];
+        let config = ServerConfig::from_iter(args).unwrap();
+        assert_eq!(config.diff_text.as_deref(), Some("diff --git a/a b/a\n"));
+
+        let args = vec![
+            "program_name".to_string(),
+            "--diff-base64".to_string(),
+            "not base64!".to_string(),
+        ];
+        assert!(ServerConfig::from_iter(args).is_err());
+    }
 }
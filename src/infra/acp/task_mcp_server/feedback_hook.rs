@@ -0,0 +1,347 @@
+//! Opt-in `post_feedback` hook: an external command that sees each piece of
+//! feedback the agent adds and can annotate or suppress it, so teams can run
+//! deterministic checks (a secret scanner, a house linter) next to the agent.
+//!
+//! The command receives `{ "review_id", "run_id", "feedback", "body" }` as
+//! JSON on stdin. Exit code 0 keeps the feedback and adds anything printed to
+//! stdout as a comment on it; exit code 1 suppresses it, with stdout as the
+//! reason. Any other outcome (another exit code, a crash, a timeout) keeps the
+//! feedback unchanged.
+//!
+//! The command runs with only a few environment variables (so agent API keys
+//! and VCS tokens don't leak to it), in an empty temporary directory, in its
+//! own process group that is killed on timeout, and with its output capped.
+
+use super::config::ServerConfig;
+use super::logging::log_to_file;
+use super::run_context::RunContext;
+use crate::domain::{Comment, Feedback};
+use crate::infra::app_config::{FeedbackHookConfig, load_config};
+use anyhow::{Context, Result, bail};
+use std::io::{Read, Write};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Seconds the hook may run when the config doesn't say.
+pub const DEFAULT_HOOK_TIMEOUT_SECS: u64 = 10;
+
+/// Author recorded on comments the hook adds.
+pub const HOOK_AUTHOR: &str = "hook:post_feedback";
+
+/// Stdout beyond this is discarded.
+const MAX_HOOK_OUTPUT_BYTES: u64 = 64 * 1024;
+
+/// Environment variables the hook inherits; everything else is cleared.
+const PASSTHROUGH_ENV: &[&str] = &["PATH", "HOME", "LANG", "TMPDIR", "SYSTEMROOT"];
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// How long to wait for the rest of stdout after killing whatever the hook
+/// left holding it open.
+const DRAIN_GRACE: Duration = Duration::from_secs(1);
+
+/// Returned when the hook suppressed a piece of feedback.
+#[derive(Debug, thiserror::Error)]
+#[error("suppressed by the post_feedback hook: {reason}")]
+pub struct FeedbackSuppressed {
+    pub reason: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum HookVerdict {
+    Keep { annotation: Option<String> },
+    Suppress { reason: String },
+}
+
+#[derive(Debug)]
+pub struct FeedbackHook {
+    command: String,
+    args: Vec<String>,
+    timeout: Duration,
+}
+
+impl FeedbackHook {
+    pub fn new(config: FeedbackHookConfig) -> Self {
+        Self {
+            command: config.command,
+            args: config.args,
+            timeout: Duration::from_secs(config.timeout_secs.unwrap_or(DEFAULT_HOOK_TIMEOUT_SECS)),
+        }
+    }
+
+    /// The hook from the app config, if one is set up.
+    pub fn from_config() -> Option<Self> {
+        load_config()
+            .post_feedback_hook
+            .filter(|hook| !hook.command.trim().is_empty())
+            .map(Self::new)
+    }
+
+    /// Runs the command on `feedback` and interprets its exit code.
+    pub fn review(&self, ctx: &RunContext, feedback: &Feedback, body: &str) -> Result<HookVerdict> {
+        let payload = serde_json::json!({
+            "review_id": ctx.review_id,
+            "run_id": ctx.run_id,
+            "feedback": feedback,
+            "body": body,
+        });
+        let (code, stdout) = self.run(&payload.to_string())?;
+        let message = stdout.trim().to_string();
+        match code {
+            Some(0) => Ok(HookVerdict::Keep {
+                annotation: (!message.is_empty()).then_some(message),
+            }),
+            Some(1) if message.is_empty() => Ok(HookVerdict::Suppress {
+                reason: "no reason given".to_string(),
+            }),
+            Some(1) => Ok(HookVerdict::Suppress { reason: message }),
+            Some(code) => bail!("{} exited with status {}", self.command, code),
+            None => bail!("{} was terminated by a signal", self.command),
+        }
+    }
+
+    fn run(&self, input: &str) -> Result<(Option<i32>, String)> {
+        let workdir = tempfile::tempdir().context("create hook working directory")?;
+        let mut command = Command::new(&self.command);
+        command
+            .args(&self.args)
+            .current_dir(workdir.path())
+            .env_clear()
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null());
+        for key in PASSTHROUGH_ENV {
+            if let Some(value) = std::env::var_os(key) {
+                command.env(key, value);
+            }
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+
+        let mut child = command
+            .spawn()
+            .with_context(|| format!("start {}", self.command))?;
+
+        let mut stdin = child.stdin.take().context("hook stdin")?;
+        let input = input.to_owned();
+        std::thread::spawn(move || {
+            // A hook that doesn't read its input closes the pipe early.
+            let _ = stdin.write_all(input.as_bytes());
+        });
+        let mut stdout = child.stdout.take().context("hook stdout")?;
+        let (output_tx, output) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let mut output = Vec::new();
+            let _ = (&mut stdout)
+                .take(MAX_HOOK_OUTPUT_BYTES)
+                .read_to_end(&mut output);
+            let _ = std::io::copy(&mut stdout, &mut std::io::sink());
+            let _ = output_tx.send(String::from_utf8_lossy(&output).into_owned());
+        });
+
+        let deadline = Instant::now() + self.timeout;
+        let status = loop {
+            if let Some(status) = child.try_wait().context("wait for hook")? {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                kill_process_group(&mut child);
+                bail!(
+                    "{} timed out after {}s",
+                    self.command,
+                    self.timeout.as_secs()
+                );
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        };
+
+        // Stdout only closes once everything holding it exits, so a hook that
+        // leaves a background process behind could keep us waiting forever.
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let stdout = match output.recv_timeout(remaining) {
+            Ok(stdout) => stdout,
+            Err(_) => {
+                kill_group(child.id());
+                output.recv_timeout(DRAIN_GRACE).with_context(|| {
+                    format!("{} left a process holding its output open", self.command)
+                })?
+            }
+        };
+        Ok((status.code(), stdout))
+    }
+}
+
+fn kill_process_group(child: &mut Child) {
+    kill_group(child.id());
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Kills the process group the hook leads, which also reaches anything it
+/// spawned, even after the hook itself exited.
+fn kill_group(pid: u32) {
+    #[cfg(unix)]
+    unsafe {
+        libc::kill(-(pid as i32), libc::SIGKILL);
+    }
+    #[cfg(not(unix))]
+    let _ = pid;
+}
+
+/// Runs the configured hook, if any, on feedback about to be saved. Returns
+/// the comment to add when the hook annotated it, or [`FeedbackSuppressed`]
+/// when it should not be saved. Hook failures are logged and keep the
+/// feedback.
+pub(super) fn screen_feedback(
+    config: &ServerConfig,
+    ctx: &RunContext,
+    feedback: &Feedback,
+    body: &str,
+) -> Result<Option<Comment>, FeedbackSuppressed> {
+    let Some(hook) = FeedbackHook::from_config() else {
+        return Ok(None);
+    };
+    match hook.review(ctx, feedback, body) {
+        Ok(HookVerdict::Keep { annotation }) => Ok(annotation.map(|body| Comment {
+            id: format!("comment-{}", uuid::Uuid::new_v4()),
+            feedback_id: feedback.id.clone(),
+            author: HOOK_AUTHOR.to_string(),
+            body,
+            parent_id: None,
+            created_at: feedback.created_at.clone(),
+            updated_at: feedback.created_at.clone(),
+        })),
+        Ok(HookVerdict::Suppress { reason }) => {
+            log_to_file(
+                config,
+                &format!(
+                    "post_feedback hook suppressed '{}': {}",
+                    feedback.title, reason
+                ),
+            );
+            Err(FeedbackSuppressed { reason })
+        }
+        Err(err) => {
+            log_to_file(
+                config,
+                &format!("post_feedback hook failed, keeping feedback: {err:#}"),
+            );
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::domain::{FeedbackImpact, ReviewSource, ReviewStatus};
+
+    fn hook(script: &str, timeout_secs: u64) -> FeedbackHook {
+        FeedbackHook::new(FeedbackHookConfig {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+            timeout_secs: Some(timeout_secs),
+        })
+    }
+
+    fn ctx() -> RunContext {
+        RunContext {
+            review_id: "rev-1".into(),
+            run_id: "run-1".into(),
+            agent_id: "agent-1".into(),
+            input_ref: "diff".into(),
+            diff_text: "".into(),
+            diff_hash: "h".into(),
+            source: ReviewSource::DiffPaste {
+                diff_hash: "h".into(),
+            },
+            initial_title: None,
+            created_at: None,
+            max_tasks: None,
+        }
+    }
+
+    fn feedback() -> Feedback {
+        Feedback {
+            id: "fb-1".into(),
+            review_id: "rev-1".into(),
+            task_id: None,
+            rule_id: None,
+            finding_id: None,
+            category: None,
+            title: "Leaked key".into(),
+            status: ReviewStatus::Todo,
+            impact: FeedbackImpact::Blocking,
+            confidence: 0.9,
+            anchor: None,
+            suggested_replacement: None,
+            extra_anchors: Vec::new(),
+            rationale: None,
+            pinned: false,
+            author: "agent:agent-1".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        }
+    }
+
+    #[test]
+    fn test_hook_annotates_feedback_from_stdin() {
+        let verdict = hook(
+            r#"grep -q '"title":"Leaked key"' && echo "scanner: AWS key pattern""#,
+            5,
+        )
+        .review(&ctx(), &feedback(), "body")
+        .unwrap();
+        assert_eq!(
+            verdict,
+            HookVerdict::Keep {
+                annotation: Some("scanner: AWS key pattern".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_hook_exit_code_one_suppresses() {
+        let verdict = hook("cat >/dev/null; echo duplicate; exit 1", 5)
+            .review(&ctx(), &feedback(), "body")
+            .unwrap();
+        assert_eq!(
+            verdict,
+            HookVerdict::Suppress {
+                reason: "duplicate".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_hook_failures_are_errors() {
+        assert!(
+            hook("exit 3", 5)
+                .review(&ctx(), &feedback(), "body")
+                .is_err()
+        );
+
+        let started = Instant::now();
+        let timed_out = hook("sleep 30", 1).review(&ctx(), &feedback(), "body");
+        assert!(timed_out.unwrap_err().to_string().contains("timed out"));
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_hook_background_child_cannot_hold_the_review() {
+        let started = Instant::now();
+        let verdict = hook("sleep 30 & echo flagged; exit 1", 1)
+            .review(&ctx(), &feedback(), "body")
+            .unwrap();
+        assert_eq!(
+            verdict,
+            HookVerdict::Suppress {
+                reason: "flagged".to_string()
+            }
+        );
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+}
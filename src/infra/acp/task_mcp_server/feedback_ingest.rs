@@ -1,10 +1,14 @@
 use super::config::ServerConfig;
+use super::feedback_hook::screen_feedback;
 use super::logging::log_to_file;
 use super::task_ingest::{load_run_context, open_database};
 use crate::domain::{
     Comment, Feedback, FeedbackAnchor, FeedbackImpact, FeedbackSide, ReviewStatus,
+    SuggestedReplacement,
+};
+use crate::infra::db::{
+    CommentRepository, FeedbackRepository, ReviewRuleRepository, TaskRepository,
 };
-use crate::infra::db::{CommentRepository, FeedbackRepository, TaskRepository};
 use crate::infra::diff::index::DiffIndex;
 use anyhow::{Context, Result, anyhow};
 use chrono::Utc;
@@ -52,6 +56,27 @@ fn extract_rule_id(args: &Value) -> Option<String> {
     }
 }
 
+/// Category for new feedback: the one the agent passed, otherwise the category
+/// of the rule that triggered it.
+fn resolve_category(
+    args: &Value,
+    rule_id: Option<&str>,
+    rule_repo: &ReviewRuleRepository,
+) -> Option<String> {
+    let explicit = args
+        .get("category")
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty());
+    explicit.or_else(|| {
+        rule_repo
+            .find_by_id(rule_id?)
+            .ok()
+            .flatten()
+            .and_then(|rule| rule.category)
+    })
+}
+
 /// Save feedback using a simple line ID (e.g., "L3").
 /// This is the preferred method as it requires no string matching.
 fn save_by_line_id(
@@ -121,6 +146,11 @@ fn save_by_line_id(
     let ctx = load_run_context(config);
     let db = open_database(config)?;
     let conn = db.connection();
+    let category = resolve_category(
+        args,
+        rule_id.as_deref(),
+        &ReviewRuleRepository::new(conn.clone()),
+    );
     let feedback_repo = FeedbackRepository::new(conn.clone());
     let comment_repo = CommentRepository::new(conn.clone());
     let task_repo = TaskRepository::new(conn.clone());
@@ -163,6 +193,10 @@ fn save_by_line_id(
     let hunk_ref = diff_index
         .get_hunk_coords(hunk_id)
         .ok_or_else(|| anyhow!("Hunk {} not found in diff", hunk_id))?;
+    let suggested_replacement =
+        parse_suggested_replacement(args, &diff_index, hunk_id, line_number, side)?;
+    let extra_anchors = parse_extra_locations(args, &diff_index, &ctx.diff_hash)?;
+    let rationale = parse_rationale(args);
 
     let final_task_id = if let Some(id) = input_task_id {
         let tasks = task_repo.find_by_run(&ctx.run_id)?;
@@ -201,7 +235,7 @@ fn save_by_line_id(
         task_id: final_task_id,
         rule_id,
         finding_id: None,
-        category: None,
+        category,
         title,
         status: ReviewStatus::Todo,
         impact,
@@ -212,7 +246,13 @@ fn save_by_line_id(
             side: Some(side),
             hunk_ref: Some(hunk_ref),
             head_sha: None,
+            diff_hash: Some(ctx.diff_hash.clone()),
+            outdated: false,
         }),
+        suggested_replacement,
+        extra_anchors,
+        rationale,
+        pinned: false,
         author: format!("agent:{}", ctx.agent_id),
         created_at: now.clone(),
         updated_at: now.clone(),
@@ -228,8 +268,14 @@ fn save_by_line_id(
         updated_at: now,
     };
 
+    let annotation = screen_feedback(config, &ctx, &feedback, &comment.body)?;
     feedback_repo.save(&feedback).context("save feedback")?;
     comment_repo.save(&comment).context("save comment")?;
+    if let Some(annotation) = annotation {
+        comment_repo
+            .save(&annotation)
+            .context("save hook comment")?;
+    }
 
     Ok(feedback_id)
 }
@@ -301,6 +347,11 @@ fn save_by_content(
     let ctx = load_run_context(config);
     let db = open_database(config)?;
     let conn = db.connection();
+    let category = resolve_category(
+        args,
+        rule_id.as_deref(),
+        &ReviewRuleRepository::new(conn.clone()),
+    );
     let feedback_repo = FeedbackRepository::new(conn.clone());
     let comment_repo = CommentRepository::new(conn.clone());
     let task_repo = TaskRepository::new(conn.clone());
@@ -309,6 +360,10 @@ fn save_by_content(
 
     let (line_number, hunk_ref) =
         find_line_by_content_with_validation(&diff_index, hunk_id, line_content, side, file)?;
+    let suggested_replacement =
+        parse_suggested_replacement(args, &diff_index, hunk_id, line_number, side)?;
+    let extra_anchors = parse_extra_locations(args, &diff_index, &ctx.diff_hash)?;
+    let rationale = parse_rationale(args);
 
     let final_task_id = if let Some(id) = input_task_id {
         let tasks = task_repo.find_by_run(&ctx.run_id)?;
@@ -347,7 +402,7 @@ fn save_by_content(
         task_id: final_task_id,
         rule_id,
         finding_id: None,
-        category: None,
+        category,
         title,
         status: ReviewStatus::Todo,
         impact,
@@ -358,7 +413,13 @@ fn save_by_content(
             side: Some(side),
             hunk_ref: Some(hunk_ref),
             head_sha: None,
+            diff_hash: Some(ctx.diff_hash.clone()),
+            outdated: false,
         }),
+        suggested_replacement,
+        extra_anchors,
+        rationale,
+        pinned: false,
         author: format!("agent:{}", ctx.agent_id),
         created_at: now.clone(),
         updated_at: now.clone(),
@@ -374,12 +435,185 @@ fn save_by_content(
         updated_at: now,
     };
 
+    let annotation = screen_feedback(config, &ctx, &feedback, &comment.body)?;
     feedback_repo.save(&feedback).context("save feedback")?;
     comment_repo.save(&comment).context("save comment")?;
+    if let Some(annotation) = annotation {
+        comment_repo
+            .save(&annotation)
+            .context("save hook comment")?;
+    }
 
     Ok(feedback_id)
 }
 
+/// Reads the optional `suggested_replacement` argument of `add_feedback`.
+///
+/// The old lines must match the new side of the hunk, starting at the
+/// anchored line, so the suggestion still applies to the code under review.
+pub(super) fn parse_suggested_replacement(
+    args: &Value,
+    diff_index: &DiffIndex,
+    hunk_id: &str,
+    line_number: u32,
+    side: FeedbackSide,
+) -> Result<Option<SuggestedReplacement>> {
+    let Some(raw) = args.get("suggested_replacement").filter(|v| !v.is_null()) else {
+        return Ok(None);
+    };
+
+    let old_lines = lines_arg(raw.get("old_lines")).ok_or_else(|| {
+        anyhow!("suggested_replacement.old_lines must be a string or an array of strings")
+    })?;
+    let new_lines = match raw.get("new_lines").filter(|v| !v.is_null()) {
+        Some(value) => lines_arg(Some(value)).ok_or_else(|| {
+            anyhow!("suggested_replacement.new_lines must be a string or an array of strings")
+        })?,
+        None => Vec::new(),
+    };
+
+    if old_lines.is_empty() {
+        return Err(anyhow!(
+            "suggested_replacement.old_lines must contain at least one line"
+        ));
+    }
+    if side == FeedbackSide::Old {
+        return Err(anyhow!(
+            "Suggestions replace lines in the new file. Anchor the feedback with side: \"new\"."
+        ));
+    }
+
+    let hunk_lines = diff_index
+        .get_hunk_lines_with_numbers(hunk_id)
+        .ok_or_else(|| anyhow!("Hunk {} not found in diff", hunk_id))?;
+
+    for (offset, expected) in old_lines.iter().enumerate() {
+        let target = line_number + offset as u32;
+        let actual = hunk_lines
+            .iter()
+            .find(|line| !line.is_deletion && line.new_line_number == Some(target));
+        match actual {
+            Some(line) if line.content.trim_end() == expected.trim_end() => {}
+            Some(line) => {
+                return Err(anyhow!(
+                    "suggested_replacement.old_lines[{}] does not match new line {} of {}.\n\
+                     Expected: {:?}\nFound: {:?}",
+                    offset,
+                    target,
+                    hunk_id,
+                    expected,
+                    line.content
+                ));
+            }
+            None => {
+                return Err(anyhow!(
+                    "suggested_replacement covers new line {}, which is outside hunk {}. \
+                     Keep suggestions within the anchored hunk.",
+                    target,
+                    hunk_id
+                ));
+            }
+        }
+    }
+
+    Ok(Some(SuggestedReplacement {
+        old_lines,
+        new_lines,
+    }))
+}
+
+/// Reads the optional `locations` argument of `add_feedback`: further
+/// `{ hunk_id, line_id, side? }` places a cross-file finding applies to.
+pub(super) fn parse_extra_locations(
+    args: &Value,
+    diff_index: &DiffIndex,
+    diff_hash: &str,
+) -> Result<Vec<FeedbackAnchor>> {
+    let Some(raw) = args.get("locations").filter(|v| !v.is_null()) else {
+        return Ok(Vec::new());
+    };
+    let items = raw
+        .as_array()
+        .ok_or_else(|| anyhow!("locations must be an array of {{ hunk_id, line_id }} objects"))?;
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let field = |name: &str| {
+                item.get(name)
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow!("locations[{}] is missing {}", i, name))
+            };
+            let hunk_id = field("hunk_id")?;
+            let line_id = field("line_id")?;
+            let side = match item.get("side").and_then(|v| v.as_str()) {
+                Some(side) if side.eq_ignore_ascii_case("old") => FeedbackSide::Old,
+                _ => FeedbackSide::New,
+            };
+
+            let (file, _) = diff_index
+                .parse_hunk_id(hunk_id)
+                .ok_or_else(|| anyhow!("locations[{}]: invalid hunk_id '{}'", i, hunk_id))?;
+            let location = diff_index
+                .find_line_by_id(hunk_id, line_id)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "locations[{}]: line_id '{}' not found in hunk {}",
+                        i,
+                        line_id,
+                        hunk_id
+                    )
+                })?;
+            let line_number = match side {
+                FeedbackSide::Old => location.old_line_number,
+                FeedbackSide::New => location.new_line_number,
+            }
+            .ok_or_else(|| {
+                anyhow!(
+                    "locations[{}]: line {} of {} does not exist on the {} side",
+                    i,
+                    line_id,
+                    hunk_id,
+                    side
+                )
+            })?;
+
+            Ok(FeedbackAnchor {
+                file_path: Some(file),
+                line_number: Some(line_number),
+                side: Some(side),
+                hunk_ref: diff_index.get_hunk_coords(hunk_id),
+                head_sha: None,
+                diff_hash: Some(diff_hash.to_string()),
+                outdated: false,
+            })
+        })
+        .collect()
+}
+
+/// Reads the optional `rationale` argument of `add_feedback`: why the line
+/// was flagged, kept apart from the comment body.
+pub(super) fn parse_rationale(args: &Value) -> Option<String> {
+    args.get("rationale")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// Accepts either a newline-separated string or an array of strings.
+fn lines_arg(value: Option<&Value>) -> Option<Vec<String>> {
+    match value? {
+        Value::String(text) => Some(text.lines().map(str::to_string).collect()),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string))
+            .collect(),
+        _ => None,
+    }
+}
+
 fn find_line_by_content_with_validation(
     diff_index: &DiffIndex,
     hunk_id: &str,
@@ -590,6 +824,11 @@ fn save_by_file_and_line(config: &ServerConfig, args: &Value) -> Result<String>
     let ctx = load_run_context(config);
     let db = open_database(config)?;
     let conn = db.connection();
+    let category = resolve_category(
+        args,
+        rule_id.as_deref(),
+        &ReviewRuleRepository::new(conn.clone()),
+    );
     let feedback_repo = FeedbackRepository::new(conn.clone());
     let comment_repo = CommentRepository::new(conn.clone());
     let task_repo = TaskRepository::new(conn.clone());
@@ -608,6 +847,7 @@ fn save_by_file_and_line(config: &ServerConfig, args: &Value) -> Result<String>
             .created_at
             .clone()
             .unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
+        agent_model: None,
     };
 
     // Upsert the parent review to ensure data consistency
@@ -627,6 +867,8 @@ fn save_by_file_and_line(config: &ServerConfig, args: &Value) -> Result<String>
             .clone()
             .unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
         updated_at: chrono::Utc::now().to_rfc3339(),
+        tags: vec![],
+        decision: None,
     };
     review_repo
         .save(&review)
@@ -642,6 +884,8 @@ fn save_by_file_and_line(config: &ServerConfig, args: &Value) -> Result<String>
 
     let diff_index = DiffIndex::new(&ctx.diff_text)?;
     validate_line_in_diff(&diff_index, file, line, side)?;
+    let extra_anchors = parse_extra_locations(args, &diff_index, &ctx.diff_hash)?;
+    let rationale = parse_rationale(args);
 
     let final_task_id = if let Some(id) = input_task_id {
         let tasks = task_repo.find_by_run(&ctx.run_id)?;
@@ -681,7 +925,7 @@ fn save_by_file_and_line(config: &ServerConfig, args: &Value) -> Result<String>
         task_id: final_task_id,
         rule_id,
         finding_id: None,
-        category: None,
+        category,
         title,
         status: ReviewStatus::Todo,
         impact,
@@ -692,7 +936,13 @@ fn save_by_file_and_line(config: &ServerConfig, args: &Value) -> Result<String>
             side: Some(side),
             hunk_ref: None,
             head_sha: None,
+            diff_hash: Some(ctx.diff_hash.clone()),
+            outdated: false,
         }),
+        suggested_replacement: None,
+        extra_anchors,
+        rationale,
+        pinned: false,
         author: format!("agent:{}", ctx.agent_id),
         created_at: now.clone(),
         updated_at: now.clone(),
@@ -708,8 +958,14 @@ fn save_by_file_and_line(config: &ServerConfig, args: &Value) -> Result<String>
         updated_at: now,
     };
 
+    let annotation = screen_feedback(config, &ctx, &feedback, &comment.body)?;
     feedback_repo.save(&feedback).context("save feedback")?;
     comment_repo.save(&comment).context("save comment")?;
+    if let Some(annotation) = annotation {
+        comment_repo
+            .save(&annotation)
+            .context("save hook comment")?;
+    }
 
     Ok(feedback_id)
 }
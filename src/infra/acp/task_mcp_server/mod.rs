@@ -5,18 +5,21 @@
 //! The server runs over stdio so the ACP agent can launch it as an MCP server.
 
 mod config;
+mod feedback_hook;
 mod feedback_ingest;
 mod logging;
 mod parsing;
 mod persistence;
 mod run_context;
 mod task_ingest;
+mod timing;
 mod tool;
 mod transport;
 
 pub use config::ServerConfig;
 pub(crate) use parsing::parse_task;
 pub use run_context::RunContext;
+pub use tool::agent_tool_schemas;
 
 use std::sync::Arc;
 
@@ -24,7 +27,9 @@ use pmcp::{Server, ServerCapabilities};
 
 /// Run the MCP server over stdio. Blocks until the process is terminated.
 pub async fn run_task_mcp_server() -> pmcp::Result<()> {
-    let config = Arc::new(ServerConfig::from_args());
+    let config = Arc::new(
+        ServerConfig::from_args().map_err(|err| pmcp::Error::Validation(format!("{:#}", err)))?,
+    );
     logging::log_to_file(&config, "starting task MCP server");
 
     let server = Server::builder()
@@ -70,6 +75,14 @@ pub async fn run_task_mcp_server() -> pmcp::Result<()> {
             "list_diff_files",
             tool::create_list_diff_files_tool(config.clone()),
         )
+        .tool(
+            "get_file_diff",
+            tool::create_get_file_diff_tool(config.clone()),
+        )
+        .tool(
+            "list_changed_files",
+            tool::create_list_changed_files_tool(config.clone()),
+        )
         // Merge confidence evaluation
         .tool(
             "submit_merge_confidence",
@@ -1,6 +1,7 @@
 //! Persistence functions for MCP server tools
 
 use super::config::ServerConfig;
+use super::feedback_hook::screen_feedback;
 use super::task_ingest::open_database;
 use crate::domain::{
     CheckStatus, Comment, Confidence, Feedback, FeedbackAnchor, FeedbackImpact, FeedbackSide,
@@ -99,15 +100,24 @@ pub fn save_issue_check(config: &ServerConfig, args: Value) -> Result<String> {
                 Some(&check.category),
                 &now,
             );
+            // Create an initial comment with the finding's description and evidence
+            let comment = create_comment_from_finding(&finding, &feedback.id, &now);
+
+            // A suppressed finding stays on the check but gets no feedback.
+            let Ok(annotation) = screen_feedback(config, &ctx, &feedback, &comment.body) else {
+                continue;
+            };
             feedback_repo
                 .save(&feedback)
                 .with_context(|| format!("save feedback for finding {}", finding.id))?;
-
-            // Create an initial comment with the finding's description and evidence
-            let comment = create_comment_from_finding(&finding, &feedback.id, &now);
             comment_repo
                 .save(&comment)
                 .with_context(|| format!("save initial comment for finding {}", finding.id))?;
+            if let Some(annotation) = annotation {
+                comment_repo
+                    .save(&annotation)
+                    .with_context(|| format!("save hook comment for finding {}", finding.id))?;
+            }
         }
     }
 
@@ -149,6 +159,8 @@ fn create_feedback_from_finding(
             side: Some(FeedbackSide::New),
             hunk_ref: None,
             head_sha: None,
+            diff_hash: None,
+            outdated: false,
         })
     } else {
         None
@@ -166,6 +178,10 @@ fn create_feedback_from_finding(
         impact: finding.impact,
         confidence: 1.0, // Feedback from issue checks inherits check's confidence
         anchor,
+        suggested_replacement: None,
+        extra_anchors: Vec::new(),
+        rationale: None,
+        pinned: false,
         author: "agent".to_string(),
         created_at: now.to_string(),
         updated_at: now.to_string(),
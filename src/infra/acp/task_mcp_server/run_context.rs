@@ -15,4 +15,7 @@ pub struct RunContext {
     pub initial_title: Option<String>,
     #[serde(default)]
     pub created_at: Option<String>,
+    /// Most tasks the agent may save; None for no cap.
+    #[serde(default)]
+    pub max_tasks: Option<usize>,
 }
@@ -5,6 +5,7 @@ use super::run_context::RunContext;
 use crate::domain::{DiffRef, HunkRef, ReviewTask};
 use crate::infra::db::{Database, ReviewRepository, TaskRepository};
 use crate::infra::diff::index::DiffIndex;
+use crate::infra::hash::hash_diff;
 use anyhow::{Context, Result, anyhow};
 use chrono::Utc;
 use serde_json::Value;
@@ -203,6 +204,7 @@ pub(super) fn save_task(config: &ServerConfig, raw_task: Value) -> Result<Review
             .created_at
             .clone()
             .unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
+        agent_model: None,
     };
 
     // Upsert the parent review to ensure data consistency. Repository `save`
@@ -223,6 +225,8 @@ pub(super) fn save_task(config: &ServerConfig, raw_task: Value) -> Result<Review
             .clone()
             .unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
         updated_at: chrono::Utc::now().to_rfc3339(),
+        tags: vec![],
+        decision: None,
     };
     review_repo
         .save(&review)
@@ -271,6 +275,15 @@ pub(super) fn save_task(config: &ServerConfig, raw_task: Value) -> Result<Review
     let diff_index = DiffIndex::new(&ctx.diff_text)?;
     validate_task_references(&task, &diff_index)?;
     validate_task_diagram(&task)?;
+    if let Some(max_tasks) = ctx.max_tasks {
+        let saved = task_repo.find_by_run(&ctx.run_id)?;
+        if saved.len() >= max_tasks && !saved.iter().any(|existing| existing.id == task.id) {
+            anyhow::bail!(
+                "This quick review allows at most {} tasks. Fold the change into an existing task or call finalize_review.",
+                max_tasks
+            );
+        }
+    }
 
     // Always set files from the provided diff_refs
     let mut files = Vec::new();
@@ -328,6 +341,8 @@ pub(super) fn update_review_metadata(config: &ServerConfig, args: Value) -> Resu
             .clone()
             .unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
         updated_at: chrono::Utc::now().to_rfc3339(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review_placeholder)?;
 
@@ -361,6 +376,7 @@ pub(super) fn update_review_metadata(config: &ServerConfig, args: Value) -> Resu
             .created_at
             .clone()
             .unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
+        agent_model: None,
     };
     review_run_repo
         .save(&review_run)
@@ -381,27 +397,38 @@ pub(super) fn update_review_metadata(config: &ServerConfig, args: Value) -> Resu
     Ok(())
 }
 
+/// The run context file's contents, with the diff from `--diff-file` or
+/// `--diff-base64` when one was given.
 pub(super) fn load_run_context(config: &ServerConfig) -> RunContext {
-    if let Some(path) = &config.run_context
-        && let Ok(content) = std::fs::read_to_string(path)
-        && let Ok(ctx) = serde_json::from_str::<RunContext>(&content)
-    {
-        return ctx;
+    let mut ctx = read_run_context(config).unwrap_or_else(|| {
+        let diff_hash = config
+            .diff_text
+            .as_deref()
+            .map(hash_diff)
+            .unwrap_or_default();
+        RunContext {
+            review_id: "local-review".to_string(),
+            run_id: "local-run".to_string(),
+            agent_id: "unknown".to_string(),
+            input_ref: "unknown".to_string(),
+            diff_text: String::new().into(),
+            diff_hash: diff_hash.clone(),
+            source: crate::domain::ReviewSource::DiffPaste { diff_hash },
+            initial_title: Some("Review".to_string()),
+            created_at: Some(Utc::now().to_rfc3339()),
+            max_tasks: None,
+        }
+    });
+    if let Some(diff_text) = &config.diff_text {
+        ctx.diff_hash = hash_diff(diff_text);
+        ctx.diff_text = diff_text.clone();
     }
+    ctx
+}
 
-    RunContext {
-        review_id: "local-review".to_string(),
-        run_id: "local-run".to_string(),
-        agent_id: "unknown".to_string(),
-        input_ref: "unknown".to_string(),
-        diff_text: String::new().into(),
-        diff_hash: String::new(),
-        source: crate::domain::ReviewSource::DiffPaste {
-            diff_hash: String::new(),
-        },
-        initial_title: Some("Review".to_string()),
-        created_at: Some(Utc::now().to_rfc3339()),
-    }
+pub(super) fn read_run_context(config: &ServerConfig) -> Option<RunContext> {
+    let content = std::fs::read_to_string(config.run_context.as_ref()?).ok()?;
+    serde_json::from_str(&content).ok()
 }
 
 #[cfg(test)]
@@ -476,4 +503,15 @@ index 1234567..89abcdef 100644
         task.diff_refs[0].file = "b/b.rs".into();
         assert!(validate_task_references(&task, &diff_index).is_err());
     }
+
+    #[test]
+    fn test_diff_argument_replaces_run_context_diff() {
+        let config = ServerConfig {
+            diff_text: Some("diff --git a/a b/a\n".into()),
+            ..Default::default()
+        };
+        let ctx = load_run_context(&config);
+        assert_eq!(&*ctx.diff_text, "diff --git a/a b/a\n");
+        assert_eq!(ctx.diff_hash, hash_diff("diff --git a/a b/a\n"));
+    }
 }
@@ -46,6 +46,8 @@ async fn test_return_task_tool_writes_file() {
         run_context: Some(run_context_path.path().to_path_buf()),
         repo_root: None,
         db_path: Some(db_path.clone()),
+        diff_text: None,
+        output_limits: Default::default(),
     });
 
     let tool = tool::create_return_task_tool(config);
@@ -121,6 +123,8 @@ async fn test_return_task_tool_persists_to_db() {
         run_context: Some(run_context_path),
         repo_root: None,
         db_path: Some(db_path.clone()),
+        diff_text: None,
+        output_limits: Default::default(),
     });
 
     let tool = tool::create_return_task_tool(config);
@@ -153,6 +157,14 @@ async fn test_return_task_tool_persists_to_db() {
     assert_eq!(tasks[0].title, "DB Task");
     assert_eq!(tasks[0].status, crate::domain::ReviewStatus::Todo);
 
+    let timings = db
+        .run_repo()
+        .get_tool_timings(&"run-db".to_string())
+        .expect("tool timings");
+    assert_eq!(timings.len(), 1);
+    assert_eq!(timings[0].tool, "return_task");
+    assert_eq!(timings[0].calls, 1);
+
     // Restore original env var
     if let Some(original) = original_db_path {
         unsafe {
@@ -189,6 +201,8 @@ async fn test_finalize_review_tool_updates_metadata() {
         run_context: None,
         repo_root: None,
         db_path: Some(db_path.clone()),
+        diff_text: None,
+        output_limits: Default::default(),
     });
 
     let tool = tool::create_finalize_review_tool(config);
@@ -255,6 +269,8 @@ async fn test_multiple_tasks_and_finalize_persists_correctly() {
         run_context: Some(run_context_path.clone()),
         repo_root: None,
         db_path: Some(db_path.clone()),
+        diff_text: None,
+        output_limits: Default::default(),
     });
 
     let return_task_tool = tool::create_return_task_tool(config.clone());
@@ -370,6 +386,8 @@ async fn test_repo_list_files_tool() {
         run_context: None,
         repo_root: Some(repo_root.clone()),
         db_path: None,
+        diff_text: None,
+        output_limits: Default::default(),
     });
 
     let tool = tool::create_repo_list_files_tool(config);
@@ -414,6 +432,8 @@ async fn test_repo_search_tool() {
         run_context: None,
         repo_root: Some(repo_root.clone()),
         db_path: None,
+        diff_text: None,
+        output_limits: Default::default(),
     });
 
     let tool = tool::create_repo_search_tool(config);
@@ -475,6 +495,8 @@ async fn test_save_agent_comment() {
         run_context: Some(run_context_path),
         repo_root: None,
         db_path: Some(db_path.clone()),
+        diff_text: None,
+        output_limits: Default::default(),
     };
 
     // First, create a review and run and task so auto-linking works and FKs are happy
@@ -492,6 +514,8 @@ async fn test_save_agent_comment() {
         status: crate::domain::ReviewStatus::Todo,
         created_at: "2024-01-01T00:00:00Z".into(),
         updated_at: "2024-01-01T00:00:00Z".into(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review).unwrap();
 
@@ -505,6 +529,7 @@ async fn test_save_agent_comment() {
             diff_hash: "h".into(),
             status: crate::domain::ReviewRunStatus::Completed,
             created_at: "2024-01-01T00:00:00Z".into(),
+            agent_model: None,
         };
     run_repo.save(&run).unwrap();
 
@@ -533,6 +558,21 @@ async fn test_save_agent_comment() {
     };
     task_repo.save(&task).unwrap();
 
+    let rule_repo = crate::infra::db::ReviewRuleRepository::new(db.connection());
+    rule_repo
+        .save(&crate::domain::ReviewRule {
+            id: "rule-1".into(),
+            scope: crate::domain::RuleScope::Global,
+            repo_id: None,
+            glob: None,
+            category: Some("security".into()),
+            text: "Check auth".into(),
+            enabled: true,
+            created_at: "2024-01-01T00:00:00Z".into(),
+            updated_at: "2024-01-01T00:00:00Z".into(),
+        })
+        .unwrap();
+
     let args = serde_json::json!({
         "file": "file.rs",
         "line": 1,
@@ -552,6 +592,8 @@ async fn test_save_agent_comment() {
     assert_eq!(feedback.task_id, Some("task-1".to_string()));
     assert_eq!(feedback.impact, crate::domain::FeedbackImpact::Blocking);
     assert_eq!(feedback.rule_id, Some("rule-1".to_string()));
+    // Inherited from the triggering rule.
+    assert_eq!(feedback.category, Some("security".to_string()));
 
     let comment_repo = crate::infra::db::CommentRepository::new(db.connection());
     let comments = comment_repo.list_for_feedback(&feedback_id).unwrap();
@@ -594,13 +636,16 @@ async fn test_save_agent_comment_no_task() {
         run_context: Some(run_context_path),
         repo_root: None,
         db_path: Some(db_path.clone()),
+        diff_text: None,
+        output_limits: Default::default(),
     };
 
     let args = serde_json::json!({
         "file": "file.rs",
         "line": 1,
         "body": "Comment without task",
-        "side": "new"
+        "side": "new",
+        "category": " Performance "
     });
 
     // Should succeed - feedback outside tasks is now allowed (saved as unassigned)
@@ -614,8 +659,155 @@ async fn test_save_agent_comment_no_task() {
     let feedback = feedback_repo.find_by_id(&feedback_id).unwrap().unwrap();
     // task_id should be None since no task covers this line
     assert!(feedback.task_id.is_none());
+    assert_eq!(feedback.category, Some("performance".to_string()));
 
     unsafe {
         std::env::remove_var("LAREVIEW_DB_PATH");
     }
 }
+
+#[test]
+fn test_suggested_replacement_must_match_the_diff() {
+    use super::feedback_ingest::parse_suggested_replacement;
+    use crate::domain::FeedbackSide;
+    use crate::infra::diff::index::DiffIndex;
+    use serde_json::json;
+
+    let diff = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,3 +1,4 @@\n fn a() {}\n+let x = a.unwrap();\n+let y = b.unwrap();\n fn c() {}\n";
+    let index = DiffIndex::new(diff).unwrap();
+    let hunk = "src/lib.rs#H1";
+
+    let args = json!({
+        "suggested_replacement": {
+            "old_lines": ["let x = a.unwrap();", "let y = b.unwrap();"],
+            "new_lines": ["let x = a?;", "let y = b?;"]
+        }
+    });
+    let suggestion = parse_suggested_replacement(&args, &index, hunk, 2, FeedbackSide::New)
+        .unwrap()
+        .unwrap();
+    assert_eq!(suggestion.new_lines, vec!["let x = a?;", "let y = b?;"]);
+
+    // No suggestion is fine.
+    assert!(
+        parse_suggested_replacement(&json!({}), &index, hunk, 2, FeedbackSide::New)
+            .unwrap()
+            .is_none()
+    );
+
+    // Stale old lines are rejected.
+    let stale = json!({
+        "suggested_replacement": { "old_lines": ["let x = a.expect(\"x\");"], "new_lines": [] }
+    });
+    let err = parse_suggested_replacement(&stale, &index, hunk, 2, FeedbackSide::New)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("does not match new line 2"));
+
+    // Running past the hunk is rejected.
+    let too_long = json!({
+        "suggested_replacement": { "old_lines": ["fn c() {}", "fn d() {}"], "new_lines": [] }
+    });
+    assert!(parse_suggested_replacement(&too_long, &index, hunk, 4, FeedbackSide::New).is_err());
+
+    // Suggestions only apply to the new side.
+    assert!(parse_suggested_replacement(&args, &index, hunk, 2, FeedbackSide::Old).is_err());
+}
+
+#[test]
+fn test_extra_locations_resolve_to_anchors() {
+    use super::feedback_ingest::parse_extra_locations;
+    use crate::domain::FeedbackSide;
+    use crate::infra::diff::index::DiffIndex;
+    use serde_json::json;
+
+    let diff = "diff --git a/src/a.rs b/src/a.rs\n--- a/src/a.rs\n+++ b/src/a.rs\n@@ -1,2 +1,3 @@\n fn a() {}\n+let x = load().unwrap();\n fn b() {}\ndiff --git a/src/b.rs b/src/b.rs\n--- a/src/b.rs\n+++ b/src/b.rs\n@@ -10,2 +10,3 @@\n fn c() {}\n+let y = load().unwrap();\n fn d() {}\n";
+    let index = DiffIndex::new(diff).unwrap();
+
+    assert!(
+        parse_extra_locations(&json!({}), &index, "hash")
+            .unwrap()
+            .is_empty()
+    );
+
+    let args = json!({
+        "locations": [
+            { "hunk_id": "src/b.rs#H1", "line_id": "L2" },
+            { "hunk_id": "src/a.rs#H1", "line_id": "L1", "side": "old" }
+        ]
+    });
+    let anchors = parse_extra_locations(&args, &index, "hash").unwrap();
+    assert_eq!(anchors.len(), 2);
+    assert_eq!(anchors[0].file_path.as_deref(), Some("src/b.rs"));
+    assert_eq!(anchors[0].line_number, Some(11));
+    assert_eq!(anchors[0].side, Some(FeedbackSide::New));
+    assert_eq!(anchors[0].diff_hash.as_deref(), Some("hash"));
+    assert_eq!(anchors[1].line_number, Some(1));
+    assert_eq!(anchors[1].side, Some(FeedbackSide::Old));
+
+    // An added line has no old-side number.
+    let bad =
+        json!({ "locations": [{ "hunk_id": "src/b.rs#H1", "line_id": "L2", "side": "old" }] });
+    assert!(parse_extra_locations(&bad, &index, "hash").is_err());
+    let missing = json!({ "locations": [{ "hunk_id": "src/b.rs#H1", "line_id": "L9" }] });
+    assert!(parse_extra_locations(&missing, &index, "hash").is_err());
+}
+
+#[test]
+fn test_rationale_is_optional_and_trimmed() {
+    use super::feedback_ingest::parse_rationale;
+    use serde_json::json;
+
+    assert_eq!(parse_rationale(&json!({})), None);
+    assert_eq!(parse_rationale(&json!({ "rationale": "  " })), None);
+    assert_eq!(
+        parse_rationale(&json!({ "rationale": " Callers pass untrusted paths. " })),
+        Some("Callers pass untrusted paths.".to_string())
+    );
+}
+
+#[test]
+fn test_save_task_respects_the_task_cap() {
+    let tmp_dir = tempfile::tempdir().expect("tempdir");
+    let db_path = tmp_dir.path().join("db.sqlite");
+    let run_context_path = tmp_dir.path().join("run.json");
+    let run_context = serde_json::json!({
+        "review_id": "rev-quick",
+        "run_id": "run-quick",
+        "agent_id": "agent-1",
+        "input_ref": "diff",
+        "diff_text": "diff --git a/src/a.rs b/src/a.rs\n--- a/src/a.rs\n+++ b/src/a.rs\n@@ -1,1 +1,1 @@\n-old content\n+new content\n",
+        "diff_hash": "h",
+        "source": { "type": "diff_paste", "diff_hash": "h" },
+        "max_tasks": 1
+    });
+    std::fs::write(&run_context_path, run_context.to_string()).expect("write run context");
+
+    let config = ServerConfig {
+        tasks_out: None,
+        log_file: None,
+        run_context: Some(run_context_path),
+        repo_root: None,
+        db_path: Some(db_path),
+        diff_text: None,
+        output_limits: Default::default(),
+    };
+    let task = |id: &str| {
+        serde_json::json!({
+            "id": id,
+            "title": "Quick task",
+            "description": "d",
+            "stats": { "risk": "low", "tags": [] },
+            "diagram": "flowchart LR\n    A --> B",
+            "hunk_ids": ["src/a.rs#H1"]
+        })
+    };
+
+    task_ingest::save_task(&config, task("t1")).expect("first task fits");
+    // Resaving a task already counted is fine.
+    task_ingest::save_task(&config, task("t1")).expect("update fits");
+    let err = task_ingest::save_task(&config, task("t2"))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("at most 1 tasks"));
+}
@@ -0,0 +1,47 @@
+//! Per-tool latency, to tell slow repository IO apart from a slow model.
+//!
+//! Every call is logged with its duration. During a run, the time is also
+//! added to the run's totals in the database, which the run detail shows.
+
+use super::config::ServerConfig;
+use super::logging::log_to_file;
+use super::task_ingest::{open_database, read_run_context};
+use serde_json::Value;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Runs one call of `tool` and records how long it took.
+pub(super) async fn timed<F>(
+    config: Arc<ServerConfig>,
+    tool: &'static str,
+    call: F,
+) -> pmcp::Result<Value>
+where
+    F: Future<Output = pmcp::Result<Value>>,
+{
+    let started = Instant::now();
+    let result = call.await;
+    let elapsed = started.elapsed();
+    log_to_file(
+        &config,
+        &format!(
+            "{tool} took {} ms ({})",
+            elapsed.as_millis(),
+            if result.is_ok() { "ok" } else { "error" }
+        ),
+    );
+    let _ = tokio::task::spawn_blocking(move || record(&config, tool, elapsed)).await;
+    result
+}
+
+fn record(config: &ServerConfig, tool: &str, elapsed: Duration) {
+    let Some(ctx) = read_run_context(config) else {
+        return;
+    };
+    let recorded = open_database(config)
+        .and_then(|db| db.run_repo().record_tool_timing(&ctx.run_id, tool, elapsed));
+    if let Err(err) = recorded {
+        log_to_file(config, &format!("failed to record {tool} timing: {err:#}"));
+    }
+}
@@ -1,7 +1,10 @@
-use super::config::ServerConfig;
+use super::config::{OutputLimits, ServerConfig};
+use super::feedback_hook::FeedbackSuppressed;
 use super::feedback_ingest::save_agent_comment;
 use super::logging::log_to_file;
 use super::task_ingest::{load_run_context, save_task, update_review_metadata};
+use super::timing::timed;
+use crate::domain::{FeedbackImpact, RiskLevel};
 use crate::infra::diff::index::DiffIndex;
 use grep::{
     regex::RegexMatcherBuilder,
@@ -16,15 +19,13 @@ use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
 
 const DEFAULT_SEARCH_LIMIT: usize = 200;
-const MAX_SEARCH_LIMIT: usize = 1000;
-const DEFAULT_LIST_LIMIT: usize = 2000;
-const MAX_LIST_LIMIT: usize = 5000;
+const DEFAULT_LIST_LIMIT: usize = 500;
 
 /// Create the return_task tool for streaming task submission.
 pub(super) fn create_return_task_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("return_task", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "return_task", async move {
             log_to_file(&config, "return_task called");
             let raw_task = args.clone();
 
@@ -97,7 +98,7 @@ pub(super) fn create_return_task_tool(config: Arc<ServerConfig>) -> impl ToolHan
                     )))
                 }
             }
-        })
+        }))
     })
     .with_description(
         "Submit a single code review task for a pull request. Call this repeatedly to submit each task individually. \
@@ -112,7 +113,7 @@ pub(super) fn create_return_task_tool(config: Arc<ServerConfig>) -> impl ToolHan
 pub(super) fn create_finalize_review_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("finalize_review", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "finalize_review", async move {
             log_to_file(&config, "finalize_review called");
             let persist_args = args.clone();
             let persist_args_for_log = persist_args.clone();
@@ -190,7 +191,7 @@ pub(super) fn create_finalize_review_tool(config: Arc<ServerConfig>) -> impl Too
                     )))
                 }
             }
-        })
+        }))
     })
     .with_description(
         "Finalize the review by submitting the agent-generated review title/summary. \
@@ -203,7 +204,7 @@ pub(super) fn create_finalize_review_tool(config: Arc<ServerConfig>) -> impl Too
 pub(super) fn create_add_feedback_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("add_feedback", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "add_feedback", async move {
             log_to_file(&config, "add_feedback called");
             let persist_args = args.clone();
 
@@ -221,6 +222,9 @@ pub(super) fn create_add_feedback_tool(config: Arc<ServerConfig>) -> impl ToolHa
                     );
                     Ok(json!({ "status": "ok", "message": "Feedback added successfully", "feedback_id": feedback_id }))
                 },
+                Ok(Err(err)) if err.downcast_ref::<FeedbackSuppressed>().is_some() => {
+                    Ok(json!({ "status": "suppressed", "message": format!("Feedback not added: {err}") }))
+                }
                 Ok(Err(err)) => {
                     log_to_file(
                         &config,
@@ -238,7 +242,7 @@ pub(super) fn create_add_feedback_tool(config: Arc<ServerConfig>) -> impl ToolHa
                     )))
                 }
             }
-        })
+        }))
     })
     .with_description(
         "Add feedback on the diff—inline (specific line) or general (cross-cutting).\n\n\
@@ -261,7 +265,13 @@ pub(super) fn create_add_feedback_tool(config: Arc<ServerConfig>) -> impl ToolHa
          - 0.7-0.89: Medium confidence - likely real but could be intentional\n\
          - 0.5-0.69: Low confidence - speculative, might be wrong\n\n\
          **General feedback:** For cross-cutting concerns, anchor to the most representative hunk and prefix body with \"**General feedback:**\"\n\n\
-         **Optional fields:** title, impact (default: nitpick), confidence (default: 1.0), side (old|new, default: new), task_id",
+         **Suggested fix:** add `\"suggested_replacement\": { \"old_lines\": [...], \"new_lines\": [...] }` \
+         where `old_lines` are the exact new-side lines starting at `line_id`. It is posted as a one-click suggestion.\n\n\
+         **Same issue in several places:** add `\"locations\": [{ \"hunk_id\": ..., \"line_id\": ... }]` \
+         for the other places instead of repeating the feedback; the main `hunk_id`/`line_id` is the primary location.\n\n\
+         **Why it was flagged:** add `\"rationale\"` with the reasoning behind the finding (what in the code or its context made you raise it). \
+         Keep it out of the body, which is what gets posted.\n\n\
+         **Optional fields:** title, impact (default: nitpick), confidence (default: 1.0), side (old|new, default: new), task_id, suggested_replacement, locations, rationale",
     )
     .with_schema(add_feedback_schema())
 }
@@ -270,7 +280,7 @@ pub(super) fn create_add_feedback_tool(config: Arc<ServerConfig>) -> impl ToolHa
 pub(super) fn create_report_issue_check_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("report_issue_check", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "report_issue_check", async move {
             log_to_file(&config, "report_issue_check called");
             let persist_args = args.clone();
 
@@ -305,7 +315,7 @@ pub(super) fn create_report_issue_check_tool(config: Arc<ServerConfig>) -> impl
                     )))
                 }
             }
-        })
+        }))
     })
     .with_description(
         "Report the verification result for an issue checklist category.\n\n\
@@ -345,6 +355,7 @@ struct RepoSearchArgs {
     query: String,
     path: Option<String>,
     limit: Option<usize>,
+    offset: Option<usize>,
     case_sensitive: Option<bool>,
     regex: Option<bool>,
     extensions: Option<Vec<String>>,
@@ -362,6 +373,7 @@ struct RepoSearchMatch {
 struct RepoListArgs {
     path: Option<String>,
     limit: Option<usize>,
+    offset: Option<usize>,
     max_depth: Option<usize>,
     extensions: Option<Vec<String>>,
     include_dirs: Option<bool>,
@@ -374,10 +386,83 @@ struct RepoListEntry {
     kind: String,
 }
 
+/// One call's slice of the results: skips `offset` results, then keeps
+/// results until `limit` or the byte cap is reached.
+struct ResultPage<T> {
+    items: Vec<T>,
+    offset: usize,
+    limit: usize,
+    max_bytes: usize,
+    bytes: usize,
+    /// Results seen so far, skipped ones included.
+    seen: usize,
+    truncated: bool,
+}
+
+impl<T: Serialize> ResultPage<T> {
+    fn new(
+        offset: Option<usize>,
+        limit: Option<usize>,
+        default_limit: usize,
+        limits: &OutputLimits,
+    ) -> Self {
+        Self {
+            items: Vec::new(),
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(default_limit).clamp(1, limits.max_results),
+            max_bytes: limits.max_total_bytes,
+            bytes: 0,
+            seen: 0,
+            truncated: false,
+        }
+    }
+
+    /// Adds `item` unless it's before the offset. Returns false once the
+    /// page is full; `item` is then left for the next page.
+    fn push(&mut self, item: T) -> bool {
+        if self.truncated {
+            return false;
+        }
+        if self.seen < self.offset {
+            self.seen += 1;
+            return true;
+        }
+        let size = serde_json::to_string(&item).map_or(0, |json| json.len());
+        // A page always holds at least one result, however large.
+        if self.items.len() >= self.limit
+            || (!self.items.is_empty() && self.bytes + size > self.max_bytes)
+        {
+            self.truncated = true;
+            return false;
+        }
+        self.bytes += size;
+        self.seen += 1;
+        self.items.push(item);
+        true
+    }
+
+    /// Where the next page starts, when there is one.
+    fn next_offset(&self) -> Option<usize> {
+        self.truncated.then_some(self.seen)
+    }
+}
+
+/// `text` cut to at most `max_bytes` (on a char boundary), marked with `…`.
+fn truncate_to_bytes(text: &str, max_bytes: usize) -> String {
+    if text.len() <= max_bytes {
+        return text.to_string();
+    }
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}…", &text[..end])
+}
+
 pub(super) fn create_repo_search_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("repo_search", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "repo_search", async move {
             log_to_file(&config, "repo_search called");
             let input: RepoSearchArgs = serde_json::from_value(args)
                 .map_err(|err| pmcp::Error::Validation(err.to_string()))?;
@@ -400,36 +485,41 @@ pub(super) fn create_repo_search_tool(config: Arc<ServerConfig>) -> impl ToolHan
             let search_root =
                 resolve_repo_subpath(&root_canon, input.path.as_deref(), "repo_search")?;
 
-            let limit = input
-                .limit
-                .unwrap_or(DEFAULT_SEARCH_LIMIT)
-                .clamp(1, MAX_SEARCH_LIMIT);
+            let mut page = ResultPage::new(
+                input.offset,
+                input.limit,
+                DEFAULT_SEARCH_LIMIT,
+                &config.output_limits,
+            );
             let case_sensitive = input.case_sensitive.unwrap_or(false);
             let use_regex = input.regex.unwrap_or(false);
             let include_hidden = input.include_hidden.unwrap_or(false);
             let extensions = normalize_extensions(&input.extensions);
 
             let matcher = build_matcher(query, case_sensitive, use_regex)?;
-            let (matches, truncated) = search_repo(
+            search_repo(
                 &root_canon,
                 &search_root,
                 &matcher,
                 extensions.as_ref(),
                 include_hidden,
-                limit,
+                config.output_limits.max_result_bytes,
+                &mut page,
             )?;
 
             Ok(json!({
-                "matches": matches,
-                "truncated": truncated,
+                "matches": page.items,
+                "truncated": page.truncated,
+                "next_offset": page.next_offset(),
             }))
-        })
+        }))
     })
     .with_description(
         "Search the linked repository for a text query and return matching lines. \
          Accepts `query`, optional `path` (relative to repo root), optional `limit`, \
-         optional `case_sensitive`, optional `regex`, optional `extensions`, and \
-         optional `include_hidden`.",
+         optional `offset`, optional `case_sensitive`, optional `regex`, optional `extensions`, \
+         and optional `include_hidden`. Output is capped; when `truncated` is true, call again \
+         with `offset` set to `next_offset` for more.",
     )
     .with_schema(repo_search_schema())
 }
@@ -437,7 +527,7 @@ pub(super) fn create_repo_search_tool(config: Arc<ServerConfig>) -> impl ToolHan
 pub(super) fn create_repo_list_files_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("repo_list_files", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "repo_list_files", async move {
             log_to_file(&config, "repo_list_files called");
             let input: RepoListArgs = serde_json::from_value(args)
                 .map_err(|err| pmcp::Error::Validation(err.to_string()))?;
@@ -453,33 +543,38 @@ pub(super) fn create_repo_list_files_tool(config: Arc<ServerConfig>) -> impl Too
             let list_root =
                 resolve_repo_subpath(&root_canon, input.path.as_deref(), "repo_list_files")?;
 
-            let limit = input
-                .limit
-                .unwrap_or(DEFAULT_LIST_LIMIT)
-                .clamp(1, MAX_LIST_LIMIT);
+            let mut page = ResultPage::new(
+                input.offset,
+                input.limit,
+                DEFAULT_LIST_LIMIT,
+                &config.output_limits,
+            );
             let include_dirs = input.include_dirs.unwrap_or(false);
             let include_hidden = input.include_hidden.unwrap_or(false);
             let extensions = normalize_extensions(&input.extensions);
 
-            let (entries, truncated) = list_repo_files(
+            list_repo_files(
                 &root_canon,
                 &list_root,
                 extensions.as_ref(),
                 include_dirs,
                 include_hidden,
                 input.max_depth,
-                limit,
+                &mut page,
             )?;
 
             Ok(json!({
-                "entries": entries,
-                "truncated": truncated,
+                "entries": page.items,
+                "truncated": page.truncated,
+                "next_offset": page.next_offset(),
             }))
-        })
+        }))
     })
     .with_description(
         "List files under the linked repository. Accepts optional `path` (relative to repo root), \
-         `limit`, `max_depth`, `extensions`, `include_dirs`, and `include_hidden`.",
+         `limit`, `offset`, `max_depth`, `extensions`, `include_dirs`, and `include_hidden`. \
+         Output is capped; when `truncated` is true, call again with `offset` set to \
+         `next_offset` for more.",
     )
     .with_schema(repo_list_files_schema())
 }
@@ -515,11 +610,15 @@ fn resolve_repo_subpath(
         None => root.to_path_buf(),
     };
 
+    let escape = || {
+        pmcp::Error::Validation(format!(
+            "{tool} path must be within repo root: {}",
+            path.unwrap_or_default()
+        ))
+    };
     let normalized = normalize_path(&requested);
     if !normalized.starts_with(root) {
-        return Err(pmcp::Error::Validation(format!(
-            "{tool} path must be within repo root"
-        )));
+        return Err(escape());
     }
 
     if !normalized.exists() {
@@ -529,7 +628,15 @@ fn resolve_repo_subpath(
         )));
     }
 
-    Ok(normalized)
+    // `root` is canonical, so this catches symlinks that lead out of it.
+    let resolved = normalized
+        .canonicalize()
+        .map_err(|err| pmcp::Error::NotFound(err.to_string()))?;
+    if !resolved.starts_with(root) {
+        return Err(escape());
+    }
+
+    Ok(resolved)
 }
 
 fn normalize_extensions(extensions: &Option<Vec<String>>) -> Option<HashSet<String>> {
@@ -574,6 +681,8 @@ fn build_matcher(
 fn build_walker(root: &Path, include_hidden: bool, max_depth: Option<usize>) -> ignore::Walk {
     let mut builder = WalkBuilder::new(root);
     builder.standard_filters(true);
+    // Symlinks aren't followed, so links inside the repo can't reach outside it.
+    builder.follow_links(false);
     builder.hidden(!include_hidden);
     if let Some(depth) = max_depth {
         builder.max_depth(Some(depth));
@@ -587,10 +696,9 @@ fn search_repo(
     matcher: &grep::regex::RegexMatcher,
     extensions: Option<&HashSet<String>>,
     include_hidden: bool,
-    limit: usize,
-) -> Result<(Vec<RepoSearchMatch>, bool), pmcp::Error> {
-    let mut matches = Vec::new();
-    let mut truncated = false;
+    max_line_bytes: usize,
+    page: &mut ResultPage<RepoSearchMatch>,
+) -> Result<(), pmcp::Error> {
     let mut searcher = SearcherBuilder::new()
         .binary_detection(BinaryDetection::quit(b'\x00'))
         .line_number(true)
@@ -598,7 +706,7 @@ fn search_repo(
 
     let walk = build_walker(search_root, include_hidden, None);
     for result in walk {
-        if truncated {
+        if page.truncated {
             break;
         }
         let entry = result.map_err(|err| pmcp::Error::NotFound(err.to_string()))?;
@@ -623,17 +731,12 @@ fn search_repo(
             .to_string();
 
         let mut sink = Lossy(|line_number, line| {
-            let cleaned = line.trim_end_matches(&['\r', '\n'][..]).to_string();
-            matches.push(RepoSearchMatch {
+            let cleaned = line.trim_end_matches(&['\r', '\n'][..]);
+            Ok(page.push(RepoSearchMatch {
                 path: relative.clone(),
                 line: line_number as u32,
-                text: cleaned,
-            });
-            if matches.len() >= limit {
-                truncated = true;
-                return Ok(false);
-            }
-            Ok(true)
+                text: truncate_to_bytes(cleaned, max_line_bytes),
+            }))
         });
 
         searcher
@@ -641,7 +744,7 @@ fn search_repo(
             .map_err(|err| pmcp::Error::Internal(err.to_string()))?;
     }
 
-    Ok((matches, truncated))
+    Ok(())
 }
 
 fn list_repo_files(
@@ -651,14 +754,11 @@ fn list_repo_files(
     include_dirs: bool,
     include_hidden: bool,
     max_depth: Option<usize>,
-    limit: usize,
-) -> Result<(Vec<RepoListEntry>, bool), pmcp::Error> {
-    let mut entries = Vec::new();
-    let mut truncated = false;
-
+    page: &mut ResultPage<RepoListEntry>,
+) -> Result<(), pmcp::Error> {
     let walk = build_walker(list_root, include_hidden, max_depth);
     for result in walk {
-        if truncated {
+        if page.truncated {
             break;
         }
         let entry = result.map_err(|err| pmcp::Error::NotFound(err.to_string()))?;
@@ -674,13 +774,10 @@ fn list_repo_files(
                     .unwrap_or(path)
                     .to_string_lossy()
                     .to_string();
-                entries.push(RepoListEntry {
+                page.push(RepoListEntry {
                     path: relative,
                     kind: "dir".to_string(),
                 });
-                if entries.len() >= limit {
-                    truncated = true;
-                }
             }
             continue;
         }
@@ -700,17 +797,44 @@ fn list_repo_files(
             .unwrap_or(path)
             .to_string_lossy()
             .to_string();
-        entries.push(RepoListEntry {
+        page.push(RepoListEntry {
             path: relative,
             kind: "file".to_string(),
         });
-
-        if entries.len() >= limit {
-            truncated = true;
-        }
     }
 
-    Ok((entries, truncated))
+    Ok(())
+}
+
+/// Input schemas of the tools an agent reports its review through, keyed by
+/// tool name. These are the schemas the MCP server registers the tools with.
+pub fn agent_tool_schemas() -> Value {
+    json!({
+        "return_task": single_task_schema(),
+        "finalize_review": review_metadata_schema(),
+        "add_feedback": add_feedback_schema(),
+        "report_issue_check": report_issue_check_schema(),
+    })
+}
+
+/// Risk levels as agents write them; `parse_task` reads them back.
+fn risk_level_names() -> Vec<String> {
+    [RiskLevel::Low, RiskLevel::Medium, RiskLevel::High]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Feedback impacts in their serialized form.
+fn feedback_impact_names() -> Vec<Value> {
+    [
+        FeedbackImpact::Nitpick,
+        FeedbackImpact::Blocking,
+        FeedbackImpact::NiceToHave,
+    ]
+    .iter()
+    .map(|impact| json!(impact))
+    .collect()
 }
 
 fn single_task_schema() -> Value {
@@ -734,7 +858,7 @@ fn single_task_schema() -> Value {
                 "properties": {
                     "risk": {
                         "type": "string",
-                        "enum": ["LOW", "MEDIUM", "HIGH"],
+                        "enum": risk_level_names(),
                         "description": "Risk level: HIGH for dangerous changes (security, data loss, breaking changes), MEDIUM for complex logic or refactors, LOW for safe mechanical changes"
                     },
                     "tags": {
@@ -778,7 +902,11 @@ fn repo_search_schema() -> Value {
             },
             "limit": {
                 "type": "integer",
-                "description": "Maximum number of matches to return (default 200, max 1000)."
+                "description": "Maximum number of matches to return (default 200, capped by the server)."
+            },
+            "offset": {
+                "type": "integer",
+                "description": "Matches to skip; pass the previous call's `next_offset` to continue."
             },
             "case_sensitive": {
                 "type": "boolean",
@@ -812,7 +940,11 @@ fn repo_list_files_schema() -> Value {
             },
             "limit": {
                 "type": "integer",
-                "description": "Maximum number of entries to return (default 2000, max 5000)."
+                "description": "Maximum number of entries to return (default 500, capped by the server)."
+            },
+            "offset": {
+                "type": "integer",
+                "description": "Entries to skip; pass the previous call's `next_offset` to continue."
             },
             "max_depth": {
                 "type": "integer",
@@ -891,7 +1023,7 @@ fn add_feedback_schema() -> Value {
             },
             "impact": {
                 "type": "string",
-                "enum": ["nitpick", "blocking", "nice_to_have"],
+                "enum": feedback_impact_names(),
                 "description": "Severity of the issue if it's real (default: nitpick)."
             },
             "confidence": {
@@ -907,6 +1039,42 @@ fn add_feedback_schema() -> Value {
             "rule_id": {
                 "type": "string",
                 "description": "Optional: Rule ID that motivated this feedback (include when applying a rule)."
+            },
+            "category": {
+                "type": "string",
+                "description": "Optional: Issue category ID (e.g., 'security', 'performance', 'test-coverage'). Defaults to the category of `rule_id`."
+            },
+            "suggested_replacement": {
+                "type": "object",
+                "description": "Optional: A concrete fix. `old_lines` are the new-side lines starting at the anchored line (copied exactly from the hunk); `new_lines` replace them. Must stay within the anchored hunk and use side: new.",
+                "properties": {
+                    "old_lines": {
+                        "type": "array",
+                        "items": { "type": "string" }
+                    },
+                    "new_lines": {
+                        "type": "array",
+                        "items": { "type": "string" }
+                    }
+                },
+                "required": ["old_lines", "new_lines"]
+            },
+            "locations": {
+                "type": "array",
+                "description": "Optional: Other places the same finding applies to (e.g. every call site missing the same check). The main hunk_id/line_id stays the primary location.",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "hunk_id": { "type": "string" },
+                        "line_id": { "type": "string" },
+                        "side": { "type": "string", "enum": ["old", "new"] }
+                    },
+                    "required": ["hunk_id", "line_id"]
+                }
+            },
+            "rationale": {
+                "type": "string",
+                "description": "Optional: Why this was flagged - the evidence and reasoning behind the finding, as opposed to the comment itself. Shown to the reviewer, not posted."
             }
         },
         "required": ["body"]
@@ -970,7 +1138,7 @@ fn report_issue_check_schema() -> Value {
                         },
                         "impact": {
                             "type": "string",
-                            "enum": ["blocking", "nice_to_have", "nitpick"],
+                            "enum": feedback_impact_names(),
                             "description": "Severity of the finding."
                         }
                     },
@@ -987,7 +1155,7 @@ fn report_issue_check_schema() -> Value {
 pub(super) fn create_submit_learned_patterns_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("submit_learned_patterns", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "submit_learned_patterns", async move {
             log_to_file(&config, "submit_learned_patterns called");
             let persist_args = args.clone();
 
@@ -1035,7 +1203,7 @@ pub(super) fn create_submit_learned_patterns_tool(config: Arc<ServerConfig>) ->
                     )))
                 }
             }
-        })
+        }))
     })
     .with_description(
         "Submit learned patterns from rejection analysis. Used by the learning compaction agent \
@@ -1112,10 +1280,10 @@ fn submit_learned_patterns_schema() -> Value {
 pub(super) fn create_finalize_learning_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("finalize_learning", move |_args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "finalize_learning", async move {
             log_to_file(&config, "finalize_learning called");
             Ok(json!({"status": "ok", "message": "Learning analysis complete"}))
-        })
+        }))
     })
     .with_description(
         "Signal that learning analysis is complete. Call this after submitting all learned patterns \
@@ -1143,7 +1311,7 @@ struct GetHunkArgs {
 pub(super) fn create_get_hunk_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("get_hunk", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "get_hunk", async move {
             log_to_file(&config, "get_hunk called");
             let input: GetHunkArgs = serde_json::from_value(args)
                 .map_err(|err| pmcp::Error::Validation(err.to_string()))?;
@@ -1207,7 +1375,7 @@ pub(super) fn create_get_hunk_tool(config: Arc<ServerConfig>) -> impl ToolHandle
                 "file_path": file_path,
                 "content": content
             }))
-        })
+        }))
     })
     .with_description(
         "Fetch the content of a single hunk from the diff by its hunk ID.\n\n\
@@ -1236,7 +1404,7 @@ struct GetFileHunksArgs {
 pub(super) fn create_get_file_hunks_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("get_file_hunks", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "get_file_hunks", async move {
             log_to_file(&config, "get_file_hunks called");
             let input: GetFileHunksArgs = serde_json::from_value(args)
                 .map_err(|err| pmcp::Error::Validation(err.to_string()))?;
@@ -1292,7 +1460,7 @@ pub(super) fn create_get_file_hunks_tool(config: Arc<ServerConfig>) -> impl Tool
                 "hunk_ids": hunk_ids,
                 "content": content
             }))
-        })
+        }))
     })
     .with_description(
         "Fetch all hunks for a specific file from the diff.\n\n\
@@ -1324,7 +1492,7 @@ struct SearchDiffArgs {
 pub(super) fn create_search_diff_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("search_diff", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "search_diff", async move {
             log_to_file(&config, "search_diff called");
             let input: SearchDiffArgs = serde_json::from_value(args)
                 .map_err(|err| pmcp::Error::Validation(err.to_string()))?;
@@ -1372,7 +1540,7 @@ pub(super) fn create_search_diff_tool(config: Arc<ServerConfig>) -> impl ToolHan
                 "total_matches": total_matches,
                 "truncated": total_matches > limit
             }))
-        })
+        }))
     })
     .with_description(
         "Search for a pattern across all diff content.\n\n\
@@ -1401,7 +1569,7 @@ pub(super) fn create_search_diff_tool(config: Arc<ServerConfig>) -> impl ToolHan
 pub(super) fn create_list_diff_files_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("list_diff_files", move |_args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "list_diff_files", async move {
             log_to_file(&config, "list_diff_files called");
 
             let ctx = load_run_context(&config);
@@ -1459,7 +1627,7 @@ pub(super) fn create_list_diff_files_tool(config: Arc<ServerConfig>) -> impl Too
                 "total_deletions": total_deletions,
                 "files": files
             }))
-        })
+        }))
     })
     .with_description(
         "List all files changed in the diff with their statistics.\n\n\
@@ -1473,6 +1641,87 @@ pub(super) fn create_list_diff_files_tool(config: Arc<ServerConfig>) -> impl Too
     }))
 }
 
+/// Create the get_file_diff tool for fetching one file's plain unified diff.
+pub(super) fn create_get_file_diff_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
+    SimpleTool::new("get_file_diff", move |args: Value, _extra| {
+        let config = config.clone();
+        Box::pin(timed(config.clone(), "get_file_diff", async move {
+            log_to_file(&config, "get_file_diff called");
+            let input: GetFileHunksArgs = serde_json::from_value(args)
+                .map_err(|err| pmcp::Error::Validation(err.to_string()))?;
+
+            let ctx = load_run_context(&config);
+            let diff_index = DiffIndex::new(&ctx.diff_text)
+                .map_err(|err| pmcp::Error::Internal(format!("Failed to parse diff: {}", err)))?;
+
+            let diff = diff_index.file_diff_text(&input.file_path).ok_or_else(|| {
+                pmcp::Error::NotFound(format!("File not found in diff: {}", input.file_path))
+            })?;
+
+            Ok(json!({
+                "file_path": input.file_path,
+                "hunk_ids": diff_index.get_hunk_ids_for_file(&input.file_path),
+                "diff": diff
+            }))
+        }))
+    })
+    .with_description(
+        "Fetch the unified diff for a single file.\n\n\
+         Cheaper than `get_file_hunks` when you only need to read the change: no line IDs, \
+         just the hunks in standard diff format. Hunk IDs are returned in order (H1, H2, ...).\n\n\
+         **Parameters:**\n\
+         - `file_path`: The path to the file (e.g., 'src/auth.rs')",
+    )
+    .with_schema(json!({
+        "type": "object",
+        "properties": {
+            "file_path": {
+                "type": "string",
+                "description": "The file path to fetch the diff for (e.g., 'src/auth.rs')"
+            }
+        },
+        "required": ["file_path"]
+    }))
+}
+
+/// Create the list_changed_files tool: changed paths with add/del counts.
+pub(super) fn create_list_changed_files_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
+    SimpleTool::new("list_changed_files", move |_args: Value, _extra| {
+        let config = config.clone();
+        Box::pin(timed(config.clone(), "list_changed_files", async move {
+            log_to_file(&config, "list_changed_files called");
+
+            let ctx = load_run_context(&config);
+            let diff_index = DiffIndex::new(&ctx.diff_text)
+                .map_err(|err| pmcp::Error::Internal(format!("Failed to parse diff: {}", err)))?;
+
+            let files: Vec<Value> = diff_index
+                .file_change_stats()
+                .into_iter()
+                .map(|(path, additions, deletions)| {
+                    json!({
+                        "path": path,
+                        "additions": additions,
+                        "deletions": deletions
+                    })
+                })
+                .collect();
+
+            Ok(json!({ "files": files }))
+        }))
+    })
+    .with_description(
+        "List changed file paths with their addition/deletion counts, largest change first.\n\n\
+         A smaller response than `list_diff_files`; pair it with `get_file_diff` to read \
+         files on demand.",
+    )
+    .with_schema(json!({
+        "type": "object",
+        "properties": {},
+        "additionalProperties": false
+    }))
+}
+
 // ============================================================================
 // Merge Confidence Tool
 // ============================================================================
@@ -1481,7 +1730,7 @@ pub(super) fn create_list_diff_files_tool(config: Arc<ServerConfig>) -> impl Too
 pub(super) fn create_submit_merge_confidence_tool(config: Arc<ServerConfig>) -> impl ToolHandler {
     SimpleTool::new("submit_merge_confidence", move |args: Value, _extra| {
         let config = config.clone();
-        Box::pin(async move {
+        Box::pin(timed(config.clone(), "submit_merge_confidence", async move {
             log_to_file(&config, "submit_merge_confidence called");
             let persist_args = args.clone();
 
@@ -1522,7 +1771,7 @@ pub(super) fn create_submit_merge_confidence_tool(config: Arc<ServerConfig>) ->
                     )))
                 }
             }
-        })
+        }))
     })
     .with_description(
         "Submit your final merge confidence evaluation for this PR. Call this once after completing your review.\n\n\
@@ -1574,6 +1823,10 @@ mod tests {
     use super::*;
     use std::fs;
 
+    fn page<T: Serialize>(offset: usize, limit: usize) -> ResultPage<T> {
+        ResultPage::new(Some(offset), Some(limit), limit, &OutputLimits::default())
+    }
+
     #[test]
     fn test_normalize_extensions() {
         let exts = Some(vec![
@@ -1601,6 +1854,49 @@ mod tests {
         assert!(normalize_extensions(&Some(vec![" ".into()])).is_none());
     }
 
+    #[test]
+    fn test_return_task_schema_matches_parse_task() {
+        let schema = &agent_tool_schemas()["return_task"];
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(Value::as_str)
+            .collect();
+        // `parse_task` rejects payloads without these.
+        assert!(required.contains(&"id"));
+        assert!(required.contains(&"title"));
+
+        for risk in schema["properties"]["stats"]["properties"]["risk"]["enum"]
+            .as_array()
+            .unwrap()
+        {
+            let risk = risk.as_str().unwrap();
+            let task = super::super::parse_task(json!({
+                "id": "T1",
+                "title": "Title",
+                "description": "Desc",
+                "stats": { "risk": risk, "tags": [] },
+                "hunk_ids": ["src/lib.rs#H1"],
+                "diagram": "flowchart TD\n  A --> B",
+            }))
+            .unwrap();
+            assert_eq!(task.stats.risk.to_string(), risk);
+        }
+    }
+
+    #[test]
+    fn test_feedback_impact_schemas_match_the_domain() {
+        let schemas = agent_tool_schemas();
+        let feedback_impacts = &schemas["add_feedback"]["properties"]["impact"]["enum"];
+        let finding_impacts = &schemas["report_issue_check"]["properties"]["findings"]["items"]["properties"]
+            ["impact"]["enum"];
+        for impacts in [feedback_impacts, finding_impacts] {
+            let parsed: Vec<FeedbackImpact> = serde_json::from_value(impacts.clone()).unwrap();
+            assert_eq!(parsed.len(), 3);
+        }
+    }
+
     #[test]
     fn test_normalize_path_tool() {
         assert_eq!(normalize_path(Path::new("a/b/../c")), PathBuf::from("a/c"));
@@ -1643,6 +1939,58 @@ mod tests {
         assert!(resolve_repo_subpath(root, Some("missing"), "test").is_err());
     }
 
+    #[test]
+    fn test_resolve_repo_subpath_rejects_traversal() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().canonicalize().unwrap();
+        fs::create_dir(root.join("src")).unwrap();
+
+        for path in ["../../etc/passwd", "src/../../etc/passwd", "/etc/passwd"] {
+            let err = resolve_repo_subpath(&root, Some(path), "repo_search").unwrap_err();
+            assert!(
+                err.to_string().contains("must be within repo root"),
+                "{path}: {err}"
+            );
+        }
+        assert_eq!(
+            resolve_repo_subpath(&root, Some("src/../src"), "test").unwrap(),
+            root.join("src")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_resolve_repo_subpath_rejects_symlink_escape() {
+        let outside = tempfile::tempdir().unwrap();
+        fs::write(outside.path().join("secret.txt"), "token").unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().canonicalize().unwrap();
+        std::os::unix::fs::symlink(outside.path(), root.join("escape")).unwrap();
+        std::os::unix::fs::symlink(root.join("escape/secret.txt"), root.join("secret")).unwrap();
+
+        for path in ["escape", "escape/secret.txt", "secret"] {
+            let err = resolve_repo_subpath(&root, Some(path), "repo_list_files").unwrap_err();
+            assert!(
+                err.to_string().contains("must be within repo root"),
+                "{path}: {err}"
+            );
+        }
+
+        // Walking the root skips the links instead of following them.
+        let mut entries = page(0, 10);
+        list_repo_files(&root, &root, None, true, true, None, &mut entries).unwrap();
+        assert!(
+            entries
+                .items
+                .iter()
+                .all(|entry| !entry.path.starts_with("escape"))
+        );
+        let matcher = build_matcher("token", false, false).unwrap();
+        let mut matches = page(0, 10);
+        search_repo(&root, &root, &matcher, None, true, 400, &mut matches).unwrap();
+        assert!(matches.items.is_empty());
+    }
+
     #[test]
     fn test_search_repo_truncation() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1651,10 +1999,45 @@ mod tests {
         std::fs::write(&file, "line1\nline2\nline3").unwrap();
 
         let matcher = build_matcher("line", false, false).unwrap();
-        let (matches, truncated) = search_repo(root, root, &matcher, None, false, 2).unwrap();
+        let mut first = page(0, 2);
+        search_repo(root, root, &matcher, None, false, 400, &mut first).unwrap();
+        assert_eq!(first.items.len(), 2);
+        assert!(first.truncated);
+        assert_eq!(first.next_offset(), Some(2));
+
+        let mut second = page(2, 2);
+        search_repo(root, root, &matcher, None, false, 400, &mut second).unwrap();
+        assert_eq!(second.items.len(), 1);
+        assert_eq!(second.items[0].text, "line3");
+        assert_eq!(second.next_offset(), None);
+    }
 
-        assert_eq!(matches.len(), 2);
-        assert!(truncated);
+    #[test]
+    fn test_search_repo_caps_bytes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        let long_line = "needle ".repeat(100);
+        std::fs::write(root.join("a.txt"), format!("{long_line}\n{long_line}\n")).unwrap();
+
+        let matcher = build_matcher("needle", false, false).unwrap();
+        let limits = OutputLimits {
+            max_results: 10,
+            max_result_bytes: 20,
+            max_total_bytes: 100,
+        };
+        let mut matches = ResultPage::new(None, None, DEFAULT_SEARCH_LIMIT, &limits);
+        search_repo(root, root, &matcher, None, false, 20, &mut matches).unwrap();
+
+        assert!(matches.items[0].text.len() <= 20 + '…'.len_utf8());
+        assert!(matches.items[0].text.ends_with('…'));
+        assert_eq!(matches.items.len(), 1);
+        assert_eq!(matches.next_offset(), Some(1));
+    }
+
+    #[test]
+    fn test_truncate_to_bytes_respects_char_boundaries() {
+        assert_eq!(truncate_to_bytes("short", 10), "short");
+        assert_eq!(truncate_to_bytes("héllo", 2), "h…");
     }
 
     #[test]
@@ -1664,18 +2047,20 @@ mod tests {
         fs::create_dir(root.join("subdir")).unwrap();
         fs::write(root.join("subdir/file.rs"), "test").unwrap();
 
-        let (entries, truncated) =
-            list_repo_files(&root, &root, None, true, false, None, 10).unwrap();
+        let mut entries = page(0, 10);
+        list_repo_files(&root, &root, None, true, false, None, &mut entries).unwrap();
         assert!(
             entries
+                .items
                 .iter()
                 .any(|e| e.kind == "dir" && e.path == "subdir")
         );
         assert!(
             entries
+                .items
                 .iter()
                 .any(|e| e.kind == "file" && e.path == "subdir/file.rs")
         );
-        assert!(!truncated);
+        assert!(!entries.truncated);
     }
 }
@@ -0,0 +1,196 @@
+//! Single-prompt ACP sessions that only want the agent's written reply.
+//!
+//! Used where the agent answers a question instead of driving a review:
+//! explaining a finding, summarizing a PR. The agent gets no tools and no
+//! file access, and whatever text it streams back is the answer.
+
+use agent_client_protocol::{
+    Agent, ClientSideConnection, ContentBlock, Error, Implementation, InitializeRequest,
+    NewSessionRequest, PromptRequest, ProtocolVersion, ReadTextFileRequest, ReadTextFileResponse,
+    RequestPermissionOutcome, RequestPermissionRequest, RequestPermissionResponse,
+    SessionNotification, SessionUpdate, TextContent,
+};
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use futures::future::LocalBoxFuture;
+use log::debug;
+use serde_json::json;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::runtime::Builder;
+use tokio::task::LocalSet;
+
+/// A prompt for an agent and how to run it.
+pub struct TextPrompt {
+    /// Client name the session introduces itself with, e.g. `lareview-explain`.
+    pub client_name: &'static str,
+    pub prompt: String,
+    /// Agent command to run (e.g., "claude")
+    pub agent_command: String,
+    /// Agent arguments
+    pub agent_args: Vec<String>,
+    /// Timeout in seconds
+    pub timeout_secs: u64,
+}
+
+/// Collects the agent's reply; everything else is refused.
+struct TextReplyClient {
+    client_name: &'static str,
+    messages: Arc<Mutex<String>>,
+}
+
+#[async_trait(?Send)]
+impl agent_client_protocol::Client for TextReplyClient {
+    async fn request_permission(
+        &self,
+        args: RequestPermissionRequest,
+    ) -> agent_client_protocol::Result<RequestPermissionResponse> {
+        debug!(
+            target: "acp",
+            "{}: deny {:?}",
+            self.client_name,
+            args.tool_call.fields.title
+        );
+        Ok(RequestPermissionResponse::new(
+            RequestPermissionOutcome::Cancelled,
+        ))
+    }
+
+    async fn read_text_file(
+        &self,
+        _args: ReadTextFileRequest,
+    ) -> agent_client_protocol::Result<ReadTextFileResponse> {
+        Err(Error::invalid_params().data(json!({
+            "reason": "file access not available in this session"
+        })))
+    }
+
+    async fn session_notification(
+        &self,
+        notification: SessionNotification,
+    ) -> agent_client_protocol::Result<()> {
+        if let SessionUpdate::AgentMessageChunk(chunk) = &notification.update
+            && let ContentBlock::Text(text) = &chunk.content
+            && let Ok(mut guard) = self.messages.lock()
+        {
+            guard.push_str(&text.text);
+        }
+        Ok(())
+    }
+}
+
+/// Sends `request.prompt` to the agent and returns its reply, trimmed. The
+/// reply is empty when the agent finished without writing anything.
+pub async fn ask_agent(request: TextPrompt) -> Result<String> {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+    let timeout_secs = request.timeout_secs;
+
+    thread::spawn(move || {
+        let result = match Builder::new_current_thread().enable_all().build() {
+            Ok(rt) => LocalSet::new().block_on(&rt, async move {
+                tokio::time::timeout(Duration::from_secs(timeout_secs), ask_agent_inner(request))
+                    .await
+                    .map_err(|_| anyhow!("Agent timed out after {}s", timeout_secs))?
+            }),
+            Err(e) => Err(e.into()),
+        };
+        let _ = sender.send(result);
+    });
+
+    receiver
+        .await
+        .unwrap_or_else(|_| Err(anyhow!("Agent worker thread unexpectedly closed")))
+}
+
+async fn ask_agent_inner(request: TextPrompt) -> Result<String> {
+    let mut cmd = Command::new(&request.agent_command);
+    cmd.args(&request.agent_args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true);
+
+    #[cfg(unix)]
+    {
+        #[allow(unused_imports)]
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+
+    let mut child = cmd.spawn().with_context(|| {
+        format!(
+            "Failed to spawn agent: {} {}",
+            request.agent_command,
+            request.agent_args.join(" ")
+        )
+    })?;
+    let child_pid = child.id().unwrap_or(0);
+
+    let stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| anyhow!("Failed to get stdin"))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| anyhow!("Failed to get stdout"))?;
+
+    let messages = Arc::new(Mutex::new(String::new()));
+    let client = TextReplyClient {
+        client_name: request.client_name,
+        messages: messages.clone(),
+    };
+
+    use tokio_util::compat::{TokioAsyncReadCompatExt, TokioAsyncWriteCompatExt};
+    let spawn_fn = |fut: LocalBoxFuture<'static, ()>| {
+        tokio::task::spawn_local(fut);
+    };
+    let (connection, io_future) =
+        ClientSideConnection::new(client, stdin.compat_write(), stdout.compat(), spawn_fn);
+    tokio::task::spawn_local(async move {
+        let _ = io_future.await;
+    });
+
+    let result = async {
+        connection
+            .initialize(InitializeRequest::new(ProtocolVersion::V1).client_info(
+                Implementation::new(request.client_name, env!("CARGO_PKG_VERSION")),
+            ))
+            .await
+            .context("ACP initialize failed")?;
+
+        let temp_cwd = tempfile::tempdir().context("create temp working directory")?;
+        let session = connection
+            .new_session(NewSessionRequest::new(temp_cwd.path().to_path_buf()))
+            .await
+            .context("ACP new_session failed")?;
+
+        connection
+            .prompt(PromptRequest::new(
+                session.session_id,
+                vec![ContentBlock::Text(TextContent::new(request.prompt))],
+            ))
+            .await
+            .map_err(|e| anyhow!("ACP prompt failed: {:?}", e))?;
+        Ok::<_, anyhow::Error>(())
+    }
+    .await;
+
+    let _ = child.start_kill();
+    #[cfg(unix)]
+    if child_pid != 0 {
+        unsafe {
+            libc::killpg(child_pid as i32, libc::SIGKILL);
+        }
+    }
+    let _ = child.wait().await;
+    result?;
+
+    Ok(messages
+        .lock()
+        .map(|m| m.trim().to_string())
+        .unwrap_or_default())
+}
@@ -13,6 +13,59 @@ pub struct CustomAgentConfig {
     pub env_vars: HashMap<String, String>,
 }
 
+/// External command run on each piece of feedback the agent adds; see
+/// `task_mcp_server::feedback_hook` for the protocol.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct FeedbackHookConfig {
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Seconds before the command is killed.
+    /// None means use the built-in default of 10 seconds.
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+}
+
+/// Where automation events go; see `infra::automation` for the payloads.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct AutomationConfig {
+    /// Events are only sent while this is on.
+    #[serde(default)]
+    pub enabled: bool,
+    /// URL each event is POSTed to as JSON.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    /// Unix socket each event is written to as one line of JSON.
+    #[serde(default)]
+    pub socket_path: Option<String>,
+    /// Extra delivery attempts after a failure.
+    /// None means use the built-in default of 3.
+    #[serde(default)]
+    pub max_retries: Option<u32>,
+}
+
+/// How the overall risk of a review is derived from its tasks; see
+/// `application::review::risk`. The defaults give the highest task risk.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct RiskPolicyConfig {
+    /// Globs (e.g. `src/auth/**`) for files whose tasks count one risk
+    /// level higher.
+    #[serde(default)]
+    pub sensitive_paths: Vec<String>,
+    /// This many Medium tasks make the review High risk.
+    /// None means Medium tasks never escalate.
+    #[serde(default)]
+    pub medium_tasks_for_high: Option<usize>,
+}
+
+/// Who the reviewer is; see `infra::identity`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct UserIdentityConfig {
+    pub name: String,
+    #[serde(default)]
+    pub email: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AppConfig {
     pub has_seen_requirements: bool,
@@ -29,6 +82,104 @@ pub struct AppConfig {
     /// None means use the built-in default of 1000 seconds.
     #[serde(default)]
     pub review_timeout_secs: Option<u64>,
+    /// Offline (air-gapped) mode: disables every network call to VCS
+    /// providers. Pasted diffs and local reviews keep working.
+    #[serde(default)]
+    pub offline: bool,
+    /// Show an OS notification when a generation finishes while the window
+    /// is in the background.
+    #[serde(default)]
+    pub notify_on_completion: bool,
+    /// Periodically reload review data so changes made by other windows or
+    /// the CLI show up without a manual refresh.
+    #[serde(default)]
+    pub auto_refresh: bool,
+    /// Auto-refresh interval in seconds.
+    /// None means use the built-in default of 30 seconds.
+    #[serde(default)]
+    pub refresh_interval: Option<u32>,
+    /// Linked repos whose HEAD is polled for new commits.
+    #[serde(default)]
+    pub watched_repo_ids: Vec<String>,
+    /// Repo watch polling interval in seconds.
+    /// None means use the built-in default of 30 seconds.
+    #[serde(default)]
+    pub repo_watch_interval_secs: Option<u64>,
+    /// Lines of context shown around a feedback's anchored line.
+    /// None means use the built-in default of 3 lines.
+    #[serde(default)]
+    pub feedback_context_lines: Option<u32>,
+    /// Review generations allowed to run at once; later ones wait.
+    /// None means a default based on the CPU count.
+    #[serde(default)]
+    pub max_concurrent_generations: Option<usize>,
+    /// Diffs with at most this many changed lines default to a quick review.
+    /// None means use the built-in default of 50 lines.
+    #[serde(default)]
+    pub quick_review_max_lines: Option<u32>,
+    /// Zoom applied to the whole UI, text and diffs included.
+    /// None means use the built-in default of 100%.
+    #[serde(default)]
+    pub ui_scale: Option<f64>,
+    /// Use the high-contrast color theme.
+    #[serde(default)]
+    pub high_contrast: bool,
+    /// Seconds to wait for a remote review to download before giving up.
+    /// None means use the built-in default of 60 seconds.
+    #[serde(default)]
+    pub pr_fetch_timeout_secs: Option<u64>,
+    /// UI language code, e.g. `es`.
+    /// None means follow the system language, falling back to English.
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Show the agent recently rejected feedback titles as examples of what
+    /// not to comment on.
+    #[serde(default)]
+    pub include_rejection_examples: bool,
+    /// Globs for files treated as generated (collapsed in the diff and
+    /// skimmed by the agent), on top of `linguist-generated` attributes.
+    /// None means use the built-in list.
+    #[serde(default)]
+    pub generated_file_patterns: Option<Vec<String>>,
+    /// GitHub Enterprise hosts (e.g. `ghe.company.com`) to treat as GitHub,
+    /// besides those `gh` is already logged into.
+    #[serde(default)]
+    pub github_enterprise_hosts: Vec<String>,
+    /// Command that can annotate or suppress each piece of agent feedback.
+    /// None means no hook runs.
+    #[serde(default)]
+    pub post_feedback_hook: Option<FeedbackHookConfig>,
+    /// `gh`/`glab` executable to use, keyed by VCS provider id.
+    /// A missing entry means the one found on PATH.
+    #[serde(default)]
+    pub vcs_path_overrides: HashMap<String, String>,
+    /// Host to use when a command doesn't name one, keyed by VCS provider id.
+    /// A missing entry means github.com / gitlab.com.
+    #[serde(default)]
+    pub vcs_default_hosts: HashMap<String, String>,
+    /// Directory for the review database, run logs and prompts.
+    /// None means the platform default (see [`default_data_dir`]).
+    #[serde(default)]
+    pub data_dir: Option<String>,
+    /// The database is encrypted with SQLCipher and its passphrase is in the
+    /// OS keychain (builds with the `encryption` feature).
+    #[serde(default)]
+    pub encrypt_database: bool,
+    /// Outbound events for CI or chat integrations.
+    /// None means no events are sent.
+    #[serde(default)]
+    pub automation: Option<AutomationConfig>,
+    /// None means the overall risk is the highest task risk.
+    #[serde(default)]
+    pub risk_policy: Option<RiskPolicyConfig>,
+    /// Template for "Copy summary"; see `application::review::summary_line`.
+    /// None means the built-in one-liner.
+    #[serde(default)]
+    pub review_summary_template: Option<String>,
+    /// Author of feedback and comments written in the app.
+    /// None means git's `user.name`, else the OS user name.
+    #[serde(default)]
+    pub user_identity: Option<UserIdentityConfig>,
 }
 
 pub fn load_config() -> AppConfig {
@@ -53,12 +204,49 @@ fn config_path() -> PathBuf {
         return PathBuf::from(path);
     }
 
-    app_data_dir().join("config.toml")
+    // The config stays in the default directory even when the data moves,
+    // because it's where the chosen data directory is recorded.
+    default_data_dir().join("config.toml")
 }
 
-fn app_data_dir() -> PathBuf {
-    if let Ok(path) = std::env::var("LAREVIEW_DATA_HOME") {
-        return PathBuf::from(path);
+/// Environment variables that pin the data directory, overriding the setting.
+/// `LAREVIEW_DATA_HOME` is the older name.
+const DATA_DIR_ENV_VARS: [&str; 2] = ["LAREVIEW_DATA_DIR", "LAREVIEW_DATA_HOME"];
+
+/// The environment variable pinning the data directory, if one is set.
+pub fn data_dir_env_override() -> Option<(&'static str, PathBuf)> {
+    DATA_DIR_ENV_VARS.iter().find_map(|name| {
+        std::env::var_os(name)
+            .filter(|value| !value.is_empty())
+            .map(|value| (*name, PathBuf::from(value)))
+    })
+}
+
+/// Per-user directory for LaReview data (database, logs, prompts): the
+/// environment override, else the directory chosen in Settings, else the
+/// platform default.
+pub fn app_data_dir() -> PathBuf {
+    if let Some((_, path)) = data_dir_env_override() {
+        return path;
+    }
+    load_config()
+        .data_dir
+        .filter(|dir| !dir.trim().is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(default_data_dir)
+}
+
+/// Where LaReview keeps its data unless told otherwise:
+///
+/// - macOS: `~/Library/Application Support/LaReview`
+/// - Windows: `%APPDATA%\LaReview`
+/// - Linux: `$XDG_DATA_HOME/lareview`, or `~/.local/share/lareview`
+///
+/// An environment override from [`data_dir_env_override`] wins, so the
+/// config file moves along with everything else.
+pub fn default_data_dir() -> PathBuf {
+    if let Some((_, path)) = data_dir_env_override() {
+        return path;
     }
 
     #[cfg(target_os = "macos")]
@@ -93,14 +281,15 @@ fn app_data_dir() -> PathBuf {
         .join(".lareview")
 }
 
+/// Held by tests that point `LAREVIEW_CONFIG_PATH` somewhere else.
+#[cfg(test)]
+pub(crate) static ENV_MUTEX: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::Mutex;
     use tempfile::NamedTempFile;
 
-    static ENV_MUTEX: Mutex<()> = Mutex::new(());
-
     #[test]
     fn test_config_serialization() {
         let _guard = ENV_MUTEX.lock().unwrap();
@@ -138,6 +327,52 @@ mod tests {
             preferred_editor_id: Some("vscode".into()),
             feedback_confidence_threshold: None,
             review_timeout_secs: None,
+            offline: true,
+            notify_on_completion: true,
+            auto_refresh: true,
+            refresh_interval: Some(15),
+            watched_repo_ids: vec!["repo-1".to_string()],
+            repo_watch_interval_secs: Some(60),
+            feedback_context_lines: Some(5),
+            max_concurrent_generations: Some(2),
+            quick_review_max_lines: Some(20),
+            ui_scale: Some(1.25),
+            high_contrast: true,
+            pr_fetch_timeout_secs: Some(30),
+            language: Some("es".to_string()),
+            include_rejection_examples: true,
+            generated_file_patterns: Some(vec!["*.lock".to_string()]),
+            github_enterprise_hosts: vec!["ghe.company.com".to_string()],
+            post_feedback_hook: Some(FeedbackHookConfig {
+                command: "secret-scan".into(),
+                args: vec!["--json".into()],
+                timeout_secs: Some(5),
+            }),
+            vcs_path_overrides: HashMap::from([(
+                "github".to_string(),
+                "/opt/homebrew/bin/gh".to_string(),
+            )]),
+            vcs_default_hosts: HashMap::from([(
+                "github".to_string(),
+                "ghe.company.com".to_string(),
+            )]),
+            data_dir: Some("/Volumes/Encrypted/lareview".to_string()),
+            encrypt_database: true,
+            automation: Some(AutomationConfig {
+                enabled: true,
+                webhook_url: Some("https://hooks.example.com/lareview".to_string()),
+                socket_path: None,
+                max_retries: Some(5),
+            }),
+            risk_policy: Some(RiskPolicyConfig {
+                sensitive_paths: vec!["src/auth/**".to_string()],
+                medium_tasks_for_high: Some(3),
+            }),
+            review_summary_template: Some("{title}: {blocking} blocking".to_string()),
+            user_identity: Some(UserIdentityConfig {
+                name: "Ada Lovelace".to_string(),
+                email: Some("ada@example.com".to_string()),
+            }),
         };
 
         let tmp_file = NamedTempFile::new().unwrap();
@@ -153,6 +388,70 @@ mod tests {
         }
         let loaded = load_config();
         assert!(loaded.has_seen_requirements);
+        assert!(loaded.offline);
+        assert!(loaded.notify_on_completion);
+        assert!(loaded.auto_refresh);
+        assert_eq!(loaded.refresh_interval, Some(15));
+        assert_eq!(loaded.watched_repo_ids, vec!["repo-1".to_string()]);
+        assert_eq!(loaded.repo_watch_interval_secs, Some(60));
+        assert_eq!(loaded.feedback_context_lines, Some(5));
+        assert_eq!(loaded.max_concurrent_generations, Some(2));
+        assert_eq!(loaded.quick_review_max_lines, Some(20));
+        assert_eq!(loaded.ui_scale, Some(1.25));
+        assert!(loaded.high_contrast);
+        assert_eq!(loaded.pr_fetch_timeout_secs, Some(30));
+        assert_eq!(loaded.language.as_deref(), Some("es"));
+        assert!(loaded.include_rejection_examples);
+        assert_eq!(
+            loaded.generated_file_patterns,
+            Some(vec!["*.lock".to_string()])
+        );
+        assert_eq!(
+            loaded.github_enterprise_hosts,
+            vec!["ghe.company.com".to_string()]
+        );
+        assert_eq!(
+            loaded.post_feedback_hook,
+            Some(FeedbackHookConfig {
+                command: "secret-scan".into(),
+                args: vec!["--json".into()],
+                timeout_secs: Some(5),
+            })
+        );
+        assert_eq!(
+            loaded.vcs_path_overrides.get("github").unwrap(),
+            "/opt/homebrew/bin/gh"
+        );
+        assert_eq!(
+            loaded.vcs_default_hosts.get("github").unwrap(),
+            "ghe.company.com"
+        );
+        assert_eq!(
+            loaded.data_dir.as_deref(),
+            Some("/Volumes/Encrypted/lareview")
+        );
+        assert!(loaded.encrypt_database);
+        let automation = loaded.automation.as_ref().unwrap();
+        assert!(automation.enabled);
+        assert_eq!(
+            automation.webhook_url.as_deref(),
+            Some("https://hooks.example.com/lareview")
+        );
+        assert_eq!(automation.max_retries, Some(5));
+        let risk_policy = loaded.risk_policy.as_ref().unwrap();
+        assert_eq!(risk_policy.sensitive_paths, vec!["src/auth/**".to_string()]);
+        assert_eq!(risk_policy.medium_tasks_for_high, Some(3));
+        assert_eq!(
+            loaded.review_summary_template.as_deref(),
+            Some("{title}: {blocking} blocking")
+        );
+        assert_eq!(
+            loaded
+                .user_identity
+                .as_ref()
+                .map(|identity| identity.name.as_str()),
+            Some("Ada Lovelace")
+        );
         assert_eq!(loaded.custom_agents.len(), 1);
         assert_eq!(loaded.custom_agents[0].id, "my-agent");
         assert_eq!(
@@ -201,4 +500,36 @@ mod tests {
             std::env::remove_var("LAREVIEW_CONFIG_PATH");
         }
     }
+
+    #[test]
+    fn test_app_data_dir_prefers_env_then_setting() {
+        let _guard = ENV_MUTEX.lock().unwrap();
+        let tmp_file = NamedTempFile::new().unwrap();
+        let config = AppConfig {
+            data_dir: Some("/Volumes/Encrypted/lareview".to_string()),
+            ..Default::default()
+        };
+        std::fs::write(tmp_file.path(), toml::to_string_pretty(&config).unwrap()).unwrap();
+
+        // Env changes are protected by ENV_MUTEX in test context.
+        unsafe {
+            std::env::set_var("LAREVIEW_CONFIG_PATH", tmp_file.path());
+        }
+        assert_eq!(app_data_dir(), PathBuf::from("/Volumes/Encrypted/lareview"));
+
+        unsafe {
+            std::env::set_var("LAREVIEW_DATA_DIR", "/tmp/lareview-data");
+        }
+        assert_eq!(app_data_dir(), PathBuf::from("/tmp/lareview-data"));
+        assert_eq!(default_data_dir(), PathBuf::from("/tmp/lareview-data"));
+        assert_eq!(
+            data_dir_env_override().map(|(name, _)| name),
+            Some("LAREVIEW_DATA_DIR")
+        );
+
+        unsafe {
+            std::env::remove_var("LAREVIEW_DATA_DIR");
+            std::env::remove_var("LAREVIEW_CONFIG_PATH");
+        }
+    }
 }
@@ -0,0 +1,329 @@
+//! Outbound automation events, so teams can trigger CI jobs or chat
+//! notifications from what happens in LaReview.
+//!
+//! When `[automation]` is enabled in the config, each event is sent as JSON
+//! (`{ "version", "sent_at", "type", ... }`) to the webhook URL and/or as one
+//! line to the Unix socket. Delivery happens in the background and is retried
+//! with backoff; a destination that stays down is logged, never surfaced to
+//! the command that produced the event. Nothing is sent in offline mode.
+
+use crate::domain::{Feedback, FeedbackImpact, ReviewRun};
+use crate::infra::app_config::{AutomationConfig, load_config};
+use anyhow::{Result, bail};
+use serde::Serialize;
+use std::future::Future;
+use std::time::Duration;
+
+/// Shape version of the event payloads.
+pub const EVENT_VERSION: u32 = 1;
+
+/// Retries after a failed delivery when the config doesn't say.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Wait before the first retry; doubled for each one after it.
+const FIRST_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Per-attempt limit for connecting and sending.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AutomationEvent {
+    /// A generation finished successfully.
+    ReviewCompleted {
+        review_id: String,
+        run_id: String,
+        task_count: usize,
+        feedback_count: usize,
+    },
+    /// Feedback was posted to the PR/MR.
+    FeedbackPushed {
+        review_id: String,
+        feedback_id: String,
+        url: String,
+    },
+    /// A run raised blocking feedback.
+    BlockingFinding {
+        review_id: String,
+        run_id: String,
+        feedback_id: String,
+        title: String,
+        file_path: Option<String>,
+        line: Option<u32>,
+    },
+}
+
+impl AutomationEvent {
+    /// Events for the blocking feedback in `feedbacks` that `run` added.
+    pub fn blocking_findings(run: &ReviewRun, feedbacks: &[Feedback]) -> Vec<Self> {
+        feedbacks
+            .iter()
+            .filter(|feedback| feedback.impact == FeedbackImpact::Blocking)
+            .filter(|feedback| feedback.created_at >= run.created_at)
+            .map(|feedback| {
+                let anchor = feedback.anchor.as_ref();
+                Self::BlockingFinding {
+                    review_id: feedback.review_id.clone(),
+                    run_id: run.id.clone(),
+                    feedback_id: feedback.id.clone(),
+                    title: feedback.title.clone(),
+                    file_path: anchor.and_then(|anchor| anchor.file_path.clone()),
+                    line: anchor.and_then(|anchor| anchor.line_number),
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize)]
+struct Envelope<'a> {
+    version: u32,
+    sent_at: String,
+    #[serde(flatten)]
+    event: &'a AutomationEvent,
+}
+
+/// The JSON sent for `event`.
+pub fn event_json(event: &AutomationEvent) -> Result<String> {
+    Ok(serde_json::to_string(&Envelope {
+        version: EVENT_VERSION,
+        sent_at: chrono::Utc::now().to_rfc3339(),
+        event,
+    })?)
+}
+
+/// Sends `events` in the background when automation is enabled and
+/// LaReview isn't offline.
+pub fn emit(events: Vec<AutomationEvent>) {
+    let config = load_config();
+    if config.offline || events.is_empty() {
+        return;
+    }
+    let Some(config) = config.automation.filter(|config| config.enabled) else {
+        return;
+    };
+    crate::runtime_handle().spawn(async move {
+        for event in &events {
+            if let Err(err) = deliver(&config, event, FIRST_RETRY_DELAY).await {
+                log::warn!("Failed to send automation event: {:#}", err);
+            }
+        }
+    });
+}
+
+/// Sends `event` to every configured destination, retrying each on its own.
+pub async fn deliver(
+    config: &AutomationConfig,
+    event: &AutomationEvent,
+    retry_delay: Duration,
+) -> Result<()> {
+    let body = event_json(event)?;
+    let retries = config.max_retries.unwrap_or(DEFAULT_MAX_RETRIES);
+    let mut failures = Vec::new();
+
+    if let Some(url) = non_empty(&config.webhook_url) {
+        let sent = with_retries(retries, retry_delay, || post_webhook(url, &body)).await;
+        if let Err(err) = sent {
+            // The URL's path often carries the hook's secret; the host is enough.
+            failures.push(format!("webhook {}: {:#}", webhook_host(url), err));
+        }
+    }
+    if let Some(path) = non_empty(&config.socket_path) {
+        let sent = with_retries(retries, retry_delay, || write_socket(path, &body)).await;
+        if let Err(err) = sent {
+            failures.push(format!("socket {}: {:#}", path, err));
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        bail!("{}", failures.join("; "))
+    }
+}
+
+fn webhook_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "(invalid URL)".to_string())
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// Runs `attempt` until it succeeds or `retries` retries have failed,
+/// doubling `delay` between tries.
+async fn with_retries<F, Fut>(retries: u32, mut delay: Duration, mut attempt: F) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let mut remaining = retries;
+    loop {
+        match attempt().await {
+            Ok(()) => return Ok(()),
+            Err(err) if remaining == 0 => return Err(err),
+            Err(err) => {
+                log::debug!("Automation delivery failed, retrying: {:#}", err);
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                remaining -= 1;
+            }
+        }
+    }
+}
+
+async fn post_webhook(url: &str, body: &str) -> Result<()> {
+    let response = reqwest::Client::builder()
+        .timeout(DELIVERY_TIMEOUT)
+        .build()?
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(reqwest::header::USER_AGENT, "LaReview")
+        .body(body.to_string())
+        .send()
+        .await
+        .map_err(reqwest::Error::without_url)?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!("responded with {}", status);
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+async fn write_socket(path: &str, body: &str) -> Result<()> {
+    use anyhow::Context;
+    use tokio::io::AsyncWriteExt;
+
+    let mut stream = tokio::time::timeout(DELIVERY_TIMEOUT, tokio::net::UnixStream::connect(path))
+        .await
+        .context("timed out connecting")??;
+    stream.write_all(body.as_bytes()).await?;
+    stream.write_all(b"\n").await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn write_socket(_path: &str, _body: &str) -> Result<()> {
+    bail!("Unix sockets aren't supported on this platform")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn completed() -> AutomationEvent {
+        AutomationEvent::ReviewCompleted {
+            review_id: "rev-1".into(),
+            run_id: "run-1".into(),
+            task_count: 3,
+            feedback_count: 1,
+        }
+    }
+
+    #[test]
+    fn test_event_json_is_tagged_and_versioned() {
+        let json: serde_json::Value =
+            serde_json::from_str(&event_json(&completed()).unwrap()).unwrap();
+        assert_eq!(json["version"], EVENT_VERSION);
+        assert_eq!(json["type"], "review_completed");
+        assert_eq!(json["review_id"], "rev-1");
+        assert_eq!(json["task_count"], 3);
+        assert!(json["sent_at"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_success() {
+        let attempts = AtomicU32::new(0);
+        let result = with_retries(3, Duration::ZERO, || async {
+            if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                bail!("down")
+            }
+            Ok(())
+        })
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_retries() {
+        let attempts = AtomicU32::new(0);
+        let result = with_retries(2, Duration::ZERO, || async {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            bail!("down")
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_socket_receives_one_json_line() {
+        use tokio::io::AsyncBufReadExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.sock");
+        let listener = tokio::net::UnixListener::bind(&path).unwrap();
+        let reader = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut line = String::new();
+            tokio::io::BufReader::new(stream)
+                .read_line(&mut line)
+                .await
+                .unwrap();
+            line
+        });
+
+        let config = AutomationConfig {
+            enabled: true,
+            socket_path: Some(path.to_string_lossy().into_owned()),
+            ..Default::default()
+        };
+        deliver(&config, &completed(), Duration::ZERO)
+            .await
+            .unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&reader.await.unwrap()).unwrap();
+        assert_eq!(json["type"], "review_completed");
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_destination_is_reported() {
+        let config = AutomationConfig {
+            enabled: true,
+            socket_path: Some("/nonexistent/lareview.sock".into()),
+            max_retries: Some(0),
+            ..Default::default()
+        };
+        let err = deliver(&config, &completed(), Duration::ZERO)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/lareview.sock"));
+    }
+
+    #[tokio::test]
+    async fn test_webhook_failure_names_only_the_host() {
+        let config = AutomationConfig {
+            enabled: true,
+            webhook_url: Some("http://127.0.0.1:9/hooks/s3cret-token".into()),
+            max_retries: Some(0),
+            ..Default::default()
+        };
+        let err = deliver(&config, &completed(), Duration::ZERO)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("webhook 127.0.0.1"));
+        assert!(!err.contains("s3cret-token"));
+    }
+}
@@ -0,0 +1,278 @@
+//! Bug-report bundle: one zip with everything a maintainer usually has to ask
+//! for, so users can attach a file instead of guessing at their environment.
+//!
+//! The bundle holds the LaReview version, the diagnostics report, the app
+//! config, and the most recent run logs. Credentials are scrubbed from all of
+//! it, agent env values are replaced wholesale, and log lines are cut short so
+//! diffs and file contents an agent echoed don't end up in the report.
+
+use crate::infra::acp::{REDACTED, known_secrets, redact_secrets, run_logs_dir};
+use crate::infra::app_config::{AppConfig, load_config};
+use crate::infra::diagnostics::DiagnosticsReport;
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Run logs included, newest first.
+pub const RECENT_RUN_LOGS: usize = 5;
+
+/// Log lines are truncated to this many characters.
+const MAX_LOG_LINE_CHARS: usize = 240;
+
+const README: &str = "LaReview bug report\n\n\
+    version.txt      LaReview version and platform\n\
+    diagnostics.txt  Setup checks (Settings → Diagnostics)\n\
+    config.json      App config; agent env values, credentials, webhook URLs and your name are redacted\n\
+    logs/            Recent run logs (written when ACP debugging is on), redacted, agent replies omitted\n";
+
+/// Writes the bundle to `path`.
+pub fn write_bug_report(path: &Path, report: &DiagnosticsReport) -> Result<()> {
+    let config = load_config();
+    let secrets = known_secrets(&config);
+
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create {}", path.display()))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut add = |name: &str, contents: &str| -> Result<()> {
+        zip.start_file(name, options)
+            .with_context(|| format!("Failed to add {} to the bug report", name))?;
+        zip.write_all(contents.as_bytes())?;
+        Ok(())
+    };
+
+    add("README.txt", README)?;
+    add(
+        "version.txt",
+        &format!("LaReview {}\n{}\n", report.app_version, report.platform),
+    )?;
+    add("diagnostics.txt", &redact_secrets(&report.text, &secrets))?;
+    add("config.json", &redacted_config(&config, &secrets)?)?;
+    for path in recent_run_logs(&run_logs_dir(), RECENT_RUN_LOGS) {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        match std::fs::read_to_string(&path) {
+            Ok(text) => add(&format!("logs/{}", name), &scrub_log(&text, &secrets))?,
+            Err(err) => log::warn!("Skipping run log {}: {}", path.display(), err),
+        }
+    }
+
+    zip.finish().context("Failed to finish the bug report")?;
+    Ok(())
+}
+
+/// The config as pretty JSON, with every agent env value replaced and any
+/// remaining credential-looking text scrubbed.
+fn redacted_config(config: &AppConfig, secrets: &[String]) -> Result<String> {
+    let mut value = serde_json::to_value(config)?;
+    if let Some(envs) = value.get_mut("agent_envs").and_then(Value::as_object_mut) {
+        for env in envs.values_mut() {
+            redact_values(env);
+        }
+    }
+    if let Some(agents) = value.get_mut("custom_agents").and_then(Value::as_array_mut) {
+        for agent in agents {
+            if let Some(env) = agent.get_mut("env_vars") {
+                redact_values(env);
+            }
+        }
+    }
+    // Names and emails of the reviewer aren't needed to debug anything.
+    if let Some(identity) = value.get_mut("user_identity") {
+        redact_values(identity);
+    }
+    // Webhook URLs usually embed their token.
+    if let Some(url) = value
+        .get_mut("automation")
+        .and_then(|automation| automation.get_mut("webhook_url"))
+        .filter(|url| !url.is_null())
+    {
+        *url = Value::String(REDACTED.to_string());
+    }
+    Ok(redact_secrets(
+        &serde_json::to_string_pretty(&value)?,
+        secrets,
+    ))
+}
+
+fn redact_values(map: &mut Value) {
+    if let Some(map) = map.as_object_mut() {
+        for value in map.values_mut() {
+            *value = Value::String(REDACTED.to_string());
+        }
+    }
+}
+
+/// Prefix of the run log records that hold the agent's whole reply.
+const AGENT_MESSAGE_PREFIX: &str = "agent message: ";
+
+/// Redacts a run log and truncates each record. Run log records start with
+/// a `[timestamp]`; a record's other lines (an agent message quoting code)
+/// count towards its length, so quoted file contents can't slip through as
+/// many short lines. Agent messages keep only their length.
+fn scrub_log(text: &str, secrets: &[String]) -> String {
+    let mut scrubbed = String::new();
+    for record in log_records(&redact_secrets(text, secrets)) {
+        let record = match record.split_once("] ") {
+            Some((stamp, rest)) if rest.starts_with(AGENT_MESSAGE_PREFIX) => format!(
+                "{}] {}[{} chars omitted]",
+                stamp,
+                AGENT_MESSAGE_PREFIX,
+                rest[AGENT_MESSAGE_PREFIX.len()..].chars().count()
+            ),
+            _ => record,
+        };
+        let chars = record.chars().count();
+        if chars > MAX_LOG_LINE_CHARS {
+            scrubbed.extend(record.chars().take(MAX_LOG_LINE_CHARS));
+            scrubbed.push_str(&format!(
+                " … [{} chars truncated]",
+                chars - MAX_LOG_LINE_CHARS
+            ));
+        } else {
+            scrubbed.push_str(&record);
+        }
+        scrubbed.push('\n');
+    }
+    scrubbed
+}
+
+/// Splits a run log into records: a line starting with `[<digit>` opens
+/// one, and the lines after it up to the next belong to it. Lines before
+/// the first timestamp are records of their own.
+fn log_records(text: &str) -> Vec<String> {
+    let mut records: Vec<String> = Vec::new();
+    let mut in_record = false;
+    for line in text.lines() {
+        let starts_record = line
+            .strip_prefix('[')
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()));
+        match records.last_mut() {
+            Some(record) if in_record && !starts_record => {
+                record.push('\n');
+                record.push_str(line);
+            }
+            _ => {
+                in_record = starts_record;
+                records.push(line.to_string());
+            }
+        }
+    }
+    records
+}
+
+/// The `limit` newest `.log` files in `dir`; names start with a timestamp,
+/// so they sort chronologically.
+fn recent_run_logs(dir: &Path, limit: usize) -> Vec<PathBuf> {
+    let mut logs: Vec<PathBuf> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "log"))
+        .collect();
+    logs.sort();
+    logs.into_iter().rev().take(limit).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::app_config::CustomAgentConfig;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_config_env_values_are_redacted() {
+        let config = AppConfig {
+            agent_envs: HashMap::from([(
+                "claude".to_string(),
+                HashMap::from([("ANTHROPIC_BASE_URL".to_string(), "http://proxy".to_string())]),
+            )]),
+            custom_agents: vec![CustomAgentConfig {
+                id: "mine".into(),
+                label: "Mine".into(),
+                logo: None,
+                command: "mine".into(),
+                args: vec!["--token=abcdef123456".into()],
+                env_vars: HashMap::from([("MINE_KEY".to_string(), "k3y-value-1".to_string())]),
+            }],
+            user_identity: Some(crate::infra::app_config::UserIdentityConfig {
+                name: "Ada Lovelace".into(),
+                email: Some("ada@example.com".into()),
+            }),
+            automation: Some(crate::infra::app_config::AutomationConfig {
+                enabled: true,
+                webhook_url: Some("https://hooks.example.com/services/T0/B0/x9y8z7".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let json = redacted_config(&config, &known_secrets(&config)).unwrap();
+
+        assert!(json.contains("\"ANTHROPIC_BASE_URL\": \"[REDACTED]\""));
+        assert!(json.contains("\"MINE_KEY\": \"[REDACTED]\""));
+        assert!(!json.contains("http://proxy"));
+        assert!(!json.contains("k3y-value-1"));
+        assert!(!json.contains("abcdef123456"));
+        assert!(!json.contains("ada@example.com"));
+        assert!(!json.contains("hooks.example.com"));
+    }
+
+    #[test]
+    fn test_log_lines_are_redacted_and_truncated() {
+        let long = "x".repeat(MAX_LOG_LINE_CHARS + 10);
+        let log = format!("spawn: agent --key s3cr3t-value\n{}\n", long);
+        let scrubbed = scrub_log(&log, &["s3cr3t-value".to_string()]);
+
+        let lines: Vec<&str> = scrubbed.lines().collect();
+        assert_eq!(lines[0], "spawn: agent --key [REDACTED]");
+        assert!(lines[1].ends_with(" … [10 chars truncated]"));
+    }
+
+    #[test]
+    fn test_multi_line_agent_messages_keep_only_their_length() {
+        let quoted = (0..50)
+            .map(|i| format!("    let secret_line_{i} = {i};"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let log = format!(
+            "[2026-01-01T00:00:00Z] spawn: agent\n\
+             [2026-01-01T00:00:01Z] agent message: Here is the code:\n{quoted}\n\
+             [2026-01-01T00:00:02Z] tool: {}\n{}\n\
+             [2026-01-01T00:00:03Z] generation completed\n",
+            "y".repeat(MAX_LOG_LINE_CHARS),
+            "z".repeat(20),
+        );
+        let scrubbed = scrub_log(&log, &[]);
+
+        assert!(!scrubbed.contains("secret_line"));
+        assert!(!scrubbed.contains('z'));
+        let lines: Vec<&str> = scrubbed.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[1].starts_with("[2026-01-01T00:00:01Z] agent message: ["));
+        assert!(lines[1].ends_with(" chars omitted]"));
+        assert!(lines[2].ends_with(" chars truncated]"));
+        assert_eq!(lines[3], "[2026-01-01T00:00:03Z] generation completed");
+    }
+
+    #[test]
+    fn test_recent_run_logs_keeps_the_newest() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "20240101-a.log",
+            "20240103-c.log",
+            "20240102-b.log",
+            "notes.txt",
+        ] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+        let names: Vec<String> = recent_run_logs(dir.path(), 2)
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["20240103-c.log", "20240102-b.log"]);
+    }
+}
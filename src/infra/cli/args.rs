@@ -2,9 +2,12 @@
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 
 use crate::infra::cli::diff::{self, get_stash_diff};
 use crate::infra::cli::repo::detect_git_repo;
+use crate::infra::cli::review::{HeadlessReviewOptions, ReviewOutputFormat};
+use crate::infra::vcs::github;
 use crate::state::{DiffRequest, PendingDiff};
 
 #[derive(Parser, Debug, Clone)]
@@ -25,7 +28,7 @@ pub struct CliArgs {
     #[arg()]
     pub to: Option<String>,
 
-    /// PR reference (owner/repo#number or URL)
+    /// PR reference (owner/repo#number, PR URL, or compare URL)
     #[arg(short, long)]
     pub pr: Option<String>,
 
@@ -58,7 +61,7 @@ pub enum CliCommand {
 
     /// Review a GitHub PR
     Pr {
-        /// PR reference (owner/repo#number or URL)
+        /// PR reference (owner/repo#number, PR URL, or compare URL)
         #[arg(index = 1)]
         pr_ref: String,
     },
@@ -72,6 +75,86 @@ pub enum CliCommand {
         #[arg(default_value = "0")]
         index: usize,
     },
+
+    /// Generate a review without opening the window and print the result.
+    /// Exits with status 2 when any blocking feedback is produced.
+    Review {
+        /// Patch file to review ("-" or omitted reads piped stdin)
+        #[arg(long)]
+        diff: Option<PathBuf>,
+        /// Agent to use for review (claude, codex, qwen, etc.)
+        #[arg(short, long)]
+        agent: Option<String>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t)]
+        format: ReviewOutputFormat,
+        /// File with extra review rules for this run (repeatable)
+        #[arg(long = "rules")]
+        rules: Vec<PathBuf>,
+        /// Repository the agent may read for context
+        #[arg(long)]
+        repo: Option<PathBuf>,
+        /// Use the repository's `.lareview/prompt.hbs`. Only for checkouts
+        /// you trust: in CI it's the change's author who wrote it.
+        #[arg(long, requires = "repo")]
+        repo_prompt: bool,
+    },
+}
+
+/// A `lareview review` invocation, ready to run headlessly.
+#[derive(Debug, Clone)]
+pub struct HeadlessReviewRequest {
+    pub options: HeadlessReviewOptions,
+    pub format: ReviewOutputFormat,
+}
+
+/// Extracts the headless review request, if `args` asked for one.
+pub fn headless_review_request(
+    args: &CliArgs,
+    piped_stdin: Option<String>,
+) -> Result<Option<HeadlessReviewRequest>> {
+    let Some(CliCommand::Review {
+        diff,
+        agent,
+        format,
+        rules,
+        repo,
+        repo_prompt,
+    }) = &args.command
+    else {
+        return Ok(None);
+    };
+
+    let diff_text = match diff.as_deref() {
+        Some(path) if path.as_os_str() != "-" => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read diff file {}", path.display()))?,
+        _ => piped_stdin.context("Pass --diff <file> or pipe a diff on stdin")?,
+    };
+    let agent_id = agent
+        .clone()
+        .or_else(|| args.agent.clone())
+        .context("Pass --agent to choose which agent runs the review")?;
+
+    Ok(Some(HeadlessReviewRequest {
+        options: HeadlessReviewOptions {
+            diff_text,
+            agent_id,
+            rule_files: rules.clone(),
+            repo: repo.clone(),
+            repo_prompt: *repo_prompt,
+        },
+        format: *format,
+    }))
+}
+
+/// Diff request for a compare URL; the GUI fetches it through the provider.
+fn compare_request(url: String, pr_ref: &str, args: &CliArgs) -> DiffRequest {
+    DiffRequest {
+        from: url,
+        to: String::new(),
+        agent: args.agent.clone(),
+        source: format!("compare {}", pr_ref),
+    }
 }
 
 /// Translate parsed CLI args into initial app state.
@@ -88,7 +171,8 @@ pub fn process_cli_args(
 
     if let Some(cmd) = &args.command {
         match cmd {
-            CliCommand::Gui => {}
+            // Headless reviews never reach the GUI; see `headless_review_request`.
+            CliCommand::Gui | CliCommand::Review { .. } => {}
             CliCommand::Diff { from, to } => {
                 diff_req = Some(DiffRequest {
                     from: from.clone(),
@@ -101,12 +185,13 @@ pub fn process_cli_args(
                 let remote_ref = diff::parse_remote_ref(pr_ref)?;
                 match remote_ref {
                     diff::RemoteRef::GitHub {
+                        host,
                         owner,
                         repo,
                         number,
                     } => {
                         diff_req = Some(DiffRequest {
-                            from: format!("{}/{}/pull/{}", owner, repo, number),
+                            from: github::pr_url(&host, &owner, &repo, number),
                             to: String::new(),
                             agent: args.agent.clone(),
                             source: format!("PR {}", pr_ref),
@@ -126,6 +211,12 @@ pub fn process_cli_args(
                             source: format!("MR {}", pr_ref),
                         });
                     }
+                    diff::RemoteRef::GitHubCompare(compare) => {
+                        diff_req = Some(compare_request(compare.url, pr_ref, args));
+                    }
+                    diff::RemoteRef::GitLabCompare(compare) => {
+                        diff_req = Some(compare_request(compare.url, pr_ref, args));
+                    }
                 }
             }
             CliCommand::Status => {
@@ -151,12 +242,13 @@ pub fn process_cli_args(
         let remote_ref = diff::parse_remote_ref(pr_ref)?;
         match remote_ref {
             diff::RemoteRef::GitHub {
+                host,
                 owner,
                 repo,
                 number,
             } => {
                 diff_req = Some(DiffRequest {
-                    from: format!("{}/{}/pull/{}", owner, repo, number),
+                    from: github::pr_url(&host, &owner, &repo, number),
                     to: String::new(),
                     agent: args.agent.clone(),
                     source: format!("PR {}", pr_ref),
@@ -174,6 +266,12 @@ pub fn process_cli_args(
                     source: format!("MR {}", pr_ref),
                 });
             }
+            diff::RemoteRef::GitHubCompare(compare) => {
+                diff_req = Some(compare_request(compare.url, pr_ref, args));
+            }
+            diff::RemoteRef::GitLabCompare(compare) => {
+                diff_req = Some(compare_request(compare.url, pr_ref, args));
+            }
         }
     } else if args.status {
         diff_req = Some(DiffRequest {
@@ -297,6 +395,78 @@ mod tests {
         assert!(pending.is_none());
     }
 
+    #[test]
+    fn review_subcommand_builds_headless_request() {
+        let args = CliArgs::try_parse_from([
+            "lareview",
+            "review",
+            "--agent",
+            "codex",
+            "--format",
+            "json",
+            "--rules",
+            "rules.md",
+            "--repo",
+            "/tmp/repo",
+        ])
+        .unwrap();
+        let diff = "diff --git a/x b/x\n".to_string();
+
+        let request = headless_review_request(&args, Some(diff.clone()))
+            .unwrap()
+            .expect("expected headless request");
+
+        assert_eq!(request.format, ReviewOutputFormat::Json);
+        assert_eq!(request.options.diff_text, diff);
+        assert_eq!(request.options.agent_id, "codex");
+        assert_eq!(request.options.rule_files, vec![PathBuf::from("rules.md")]);
+        assert_eq!(request.options.repo, Some(PathBuf::from("/tmp/repo")));
+        assert!(!request.options.repo_prompt);
+
+        // The GUI path ignores the subcommand entirely.
+        let (req, pending) = process_cli_args(&args, None).unwrap();
+        assert!(req.is_none());
+        assert!(pending.is_none());
+    }
+
+    #[test]
+    fn review_subcommand_requires_agent_and_diff() {
+        let args = CliArgs::try_parse_from(["lareview", "review"]).unwrap();
+        assert!(headless_review_request(&args, Some("diff".into())).is_err());
+
+        let args = CliArgs::try_parse_from(["lareview", "review", "-a", "codex"]).unwrap();
+        assert!(headless_review_request(&args, None).is_err());
+
+        assert!(
+            headless_review_request(&base_args(), Some("diff".into()))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn repo_prompt_is_opt_in_and_needs_a_repo() {
+        let args = CliArgs::try_parse_from([
+            "lareview",
+            "review",
+            "-a",
+            "codex",
+            "--repo",
+            ".",
+            "--repo-prompt",
+        ])
+        .unwrap();
+        let request = headless_review_request(&args, Some("diff".into()))
+            .unwrap()
+            .unwrap();
+        assert!(request.options.repo_prompt);
+
+        assert!(
+            CliArgs::try_parse_from(["lareview", "review", "-a", "codex", "--repo-prompt"])
+                .is_err()
+        );
+    }
+
     #[test]
     fn explicit_refs_ignore_piped_stdin() {
         // Explicit CLI args win — stdin is ignored when user is clearly
@@ -314,4 +484,21 @@ mod tests {
         assert_eq!(r.from, "main");
         assert_eq!(r.to, "feature");
     }
+
+    #[test]
+    fn compare_url_becomes_diff_request() {
+        let args = CliArgs {
+            pr: Some("github.com/puemos/lareview/compare/main..dev".to_string()),
+            ..base_args()
+        };
+        let (req, pending) = process_cli_args(&args, None).unwrap();
+        assert!(pending.is_none());
+        let r = req.unwrap();
+        assert_eq!(
+            r.from,
+            "https://github.com/puemos/lareview/compare/main..dev"
+        );
+        assert!(r.to.is_empty());
+        assert!(r.source.starts_with("compare "));
+    }
 }
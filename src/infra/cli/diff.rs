@@ -1,25 +1,33 @@
 //! Diff acquisition from various sources.
 
-use crate::block_on;
+use crate::block_on_handle;
+use crate::infra::app_config::load_config;
 use crate::infra::shell;
+use crate::infra::vcs::offline::OFFLINE_MODE_ERROR;
 use crate::infra::vcs::{
+    cli::resolve_cli,
     github,
     gitlab::{self, GitLabMrRef},
 };
 use anyhow::{Context, Result};
 use std::io::{IsTerminal, Read};
+use std::path::Path;
 use std::process::Command;
+use tokio::runtime::Handle;
 
 /// Source of diff input
 pub enum DiffSource {
     /// Diff from stdin
     Stdin(String),
 
-    /// Diff between git refs
+    /// Diff between git refs: branches, tags, SHAs, stash entries
+    /// (`stash@{0}`) or reflog entries (`HEAD@{2}`, `main@{yesterday}`)
     GitDiff { from: String, to: String },
 
     /// GitHub PR (owner/repo#number or full URL)
     GitHubPr {
+        /// `github.com` or a GitHub Enterprise host
+        host: String,
         owner: String,
         repo: String,
         number: u32,
@@ -38,6 +46,7 @@ pub enum DiffSource {
 
 pub enum RemoteRef {
     GitHub {
+        host: String,
         owner: String,
         repo: String,
         number: u32,
@@ -47,12 +56,17 @@ pub enum RemoteRef {
         project_path: String,
         number: u32,
     },
+    /// Branch comparison; fetched through the VCS provider, which records
+    /// both refs on the review source.
+    GitHubCompare(github::GitHubCompareRef),
+    GitLabCompare(gitlab::GitLabCompareRef),
 }
 
 /// Parse a PR/MR reference into components
 pub fn parse_remote_ref(pr_ref: &str) -> Result<RemoteRef> {
     if let Some(res) = github::parse_pr_ref(pr_ref) {
         return Ok(RemoteRef::GitHub {
+            host: res.host,
             owner: res.owner,
             repo: res.repo,
             number: res.number,
@@ -67,8 +81,16 @@ pub fn parse_remote_ref(pr_ref: &str) -> Result<RemoteRef> {
         });
     }
 
+    if let Some(res) = github::parse_compare_ref(pr_ref) {
+        return Ok(RemoteRef::GitHubCompare(res));
+    }
+
+    if let Some(res) = gitlab::parse_compare_ref(pr_ref) {
+        return Ok(RemoteRef::GitLabCompare(res));
+    }
+
     Err(anyhow::anyhow!(
-        "Invalid PR reference. Expected owner/repo#number, group/project!number, or URL."
+        "Invalid PR reference. Expected owner/repo#number, group/project!number, a PR/MR URL, or a compare URL."
     ))
 }
 
@@ -98,56 +120,38 @@ pub fn read_stdin_diff() -> Result<String> {
 
 /// Acquire diff text from various sources
 pub fn acquire_diff(source: DiffSource) -> Result<String> {
-    match source {
-        DiffSource::Stdin(diff) => Ok(diff),
-
-        DiffSource::GitDiff { from, to } => {
-            let git_path = shell::find_bin("git").context("Could not find 'git' executable")?;
-            let output = Command::new(git_path)
-                .args(["diff", &from, &to])
-                .output()
-                .context("Failed to run git diff")?;
-
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-
-                if stderr.contains("unknown revision") {
-                    anyhow::bail!(
-                        "Could not find reference '{}' or '{}'. Run `git branch -a` to see available refs.",
-                        from,
-                        to
-                    );
-                }
-
-                anyhow::bail!("git diff failed: {}", stderr);
-            }
+    acquire_diff_with(&crate::runtime_handle(), source)
+}
 
-            let diff = String::from_utf8_lossy(&output.stdout).into_owned();
+/// Acquire diff text, driving any remote fetches on the given runtime.
+pub fn acquire_diff_with(runtime: &Handle, source: DiffSource) -> Result<String> {
+    if matches!(
+        source,
+        DiffSource::GitHubPr { .. } | DiffSource::GitLabMr { .. }
+    ) && load_config().offline
+    {
+        anyhow::bail!(OFFLINE_MODE_ERROR);
+    }
 
-            if diff.is_empty() {
-                anyhow::bail!(
-                    "No diff between '{}' and '{}'. The branches may be identical.",
-                    from,
-                    to
-                );
-            }
+    match source {
+        DiffSource::Stdin(diff) => Ok(diff),
 
-            Ok(diff)
-        }
+        DiffSource::GitDiff { from, to } => git_ref_diff(None, &from, &to),
 
         DiffSource::GitHubPr {
+            host,
             owner,
             repo,
             number,
         } => {
-            let gh_path = shell::find_bin("gh").context("Could not find 'gh' executable")?;
+            let gh_path = resolve_cli("github").context("Could not find 'gh' executable")?;
             let output = Command::new(gh_path)
                 .args([
                     "pr",
                     "diff",
                     &number.to_string(),
                     "--repo",
-                    &format!("{}/{}", owner, repo),
+                    &github::repo_arg(&host, &owner, &repo),
                 ])
                 .output()
                 .context("Failed to fetch PR via gh CLI")?;
@@ -190,7 +194,7 @@ pub fn acquire_diff(source: DiffSource) -> Result<String> {
                 url: format!("https://{host}/{project_path}/-/merge_requests/{number}"),
             };
 
-            let diff = block_on(gitlab::fetch_mr_diff(&mr)).map_err(|err| {
+            let diff = block_on_handle(runtime, gitlab::fetch_mr_diff(&mr)).map_err(|err| {
                 let msg = format!("{err:#}");
                 if msg.contains("Authentication") || msg.contains("not authenticated") {
                     anyhow::anyhow!("GitLab authentication required. Run `glab auth login` first.")
@@ -229,6 +233,57 @@ pub fn acquire_diff(source: DiffSource) -> Result<String> {
     }
 }
 
+/// Diffs two refs in `repo_dir` (the current directory when `None`).
+///
+/// Both refs are resolved with `git rev-parse` first, so a missing stash or
+/// reflog entry is reported by name instead of as git's raw error.
+pub fn git_ref_diff(repo_dir: Option<&Path>, from: &str, to: &str) -> Result<String> {
+    let git_path = shell::find_bin("git").context("Could not find 'git' executable")?;
+    let git = || {
+        let mut command = Command::new(&git_path);
+        if let Some(dir) = repo_dir {
+            command.current_dir(dir);
+        }
+        command
+    };
+
+    for reference in [from, to] {
+        let resolved = git()
+            .args(["rev-parse", "--verify", "--quiet", "--end-of-options"])
+            .arg(format!("{}^{{commit}}", reference))
+            .output()
+            .context("Failed to run git rev-parse")?;
+        if !resolved.status.success() {
+            anyhow::bail!(
+                "ref not found: {}. Run `git branch -a`, `git stash list` or `git reflog` to see available refs.",
+                reference
+            );
+        }
+    }
+
+    let output = git()
+        .args(["diff", from, to, "--"])
+        .output()
+        .context("Failed to run git diff")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git diff failed: {}", stderr);
+    }
+
+    let diff = String::from_utf8_lossy(&output.stdout).into_owned();
+
+    if diff.is_empty() {
+        anyhow::bail!(
+            "No diff between '{}' and '{}'. The refs may point at identical trees.",
+            from,
+            to
+        );
+    }
+
+    Ok(diff)
+}
+
 /// Acquire diff from git stash
 pub fn get_stash_diff(stash_index: usize) -> Result<String> {
     let git_path = shell::find_bin("git").context("Could not find 'git' executable")?;
@@ -250,3 +305,40 @@ pub fn get_stash_diff(stash_index: usize) -> Result<String> {
 
     Ok(diff)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    #[test]
+    fn test_diff_against_stash_entry() {
+        let dir = tempdir().unwrap();
+        let repo = dir.path();
+        if !git(repo, &["init"]) {
+            return; // git is not installed
+        }
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        std::fs::write(repo.join("file.txt"), "one\n").unwrap();
+        git(repo, &["add", "."]);
+        assert!(git(repo, &["commit", "-m", "initial"]));
+
+        std::fs::write(repo.join("file.txt"), "one\ntwo\n").unwrap();
+        assert!(git(repo, &["stash"]));
+
+        let diff = git_ref_diff(Some(repo), "HEAD", "stash@{0}").unwrap();
+        assert!(diff.contains("+two"));
+
+        let err = git_ref_diff(Some(repo), "HEAD", "stash@{1}").unwrap_err();
+        assert!(err.to_string().starts_with("ref not found: stash@{1}"));
+    }
+}
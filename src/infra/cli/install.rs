@@ -0,0 +1,221 @@
+//! Installs the `lareview` command into the user's shell.
+//!
+//! - macOS: symlink in `/usr/local/bin`, falling back to `~/.local/bin`.
+//! - Linux: symlink in the XDG bin dir (`$XDG_BIN_HOME` or `~/.local/bin`).
+//! - Windows: a `lareview.cmd` shim in `%LOCALAPPDATA%\LaReview\bin`.
+//!
+//! Installing again when the command already points at this executable is a
+//! no-op on every platform.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+/// Where the command was installed, and whether the shell will find it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliInstallOutcome {
+    pub path: PathBuf,
+    /// Set when the install dir isn't on `PATH`, with how to fix it.
+    pub warning: Option<String>,
+}
+
+/// Installs the command for `current_exe` using the platform's convention.
+pub fn install_cli(current_exe: &Path) -> Result<CliInstallOutcome> {
+    let path_var = std::env::var_os("PATH");
+    let target = install_for_platform(current_exe)?;
+    let warning = target
+        .parent()
+        .filter(|dir| !dir_on_path(dir, path_var.as_deref()))
+        .map(path_warning);
+    Ok(CliInstallOutcome {
+        path: target,
+        warning,
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn install_for_platform(current_exe: &Path) -> Result<PathBuf> {
+    let primary = PathBuf::from("/usr/local/bin/lareview");
+    match install_symlink(current_exe, &primary) {
+        Ok(()) => Ok(primary),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+            let fallback = dirs::home_dir()
+                .context("Could not determine home directory")?
+                .join(".local/bin/lareview");
+            install_symlink(current_exe, &fallback).with_context(
+                || "Failed to install CLI. Could not write to /usr/local/bin or ~/.local/bin",
+            )?;
+            Ok(fallback)
+        }
+        Err(e) => Err(e).context("Failed to create symlink"),
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn install_for_platform(current_exe: &Path) -> Result<PathBuf> {
+    let bin_dir = xdg_bin_dir(std::env::var_os("XDG_BIN_HOME"), dirs::home_dir())
+        .context("Could not determine home directory")?;
+    let target = bin_dir.join("lareview");
+    install_symlink(current_exe, &target)
+        .with_context(|| format!("Failed to create symlink at {}", target.display()))?;
+    Ok(target)
+}
+
+#[cfg(windows)]
+fn install_for_platform(current_exe: &Path) -> Result<PathBuf> {
+    let bin_dir = dirs::data_local_dir()
+        .context("Could not determine %LOCALAPPDATA%")?
+        .join("LaReview")
+        .join("bin");
+    let target = bin_dir.join("lareview.cmd");
+    install_cmd_shim(current_exe, &target)
+        .with_context(|| format!("Failed to write {}", target.display()))?;
+    Ok(target)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn install_for_platform(_current_exe: &Path) -> Result<PathBuf> {
+    anyhow::bail!("CLI installation is not supported on this platform")
+}
+
+/// `$XDG_BIN_HOME` when set to an absolute path, otherwise `~/.local/bin`.
+#[cfg_attr(not(unix), allow(dead_code))]
+fn xdg_bin_dir(xdg_bin_home: Option<std::ffi::OsString>, home: Option<PathBuf>) -> Option<PathBuf> {
+    xdg_bin_home
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| home.map(|home| home.join(".local").join("bin")))
+}
+
+/// Points `target` at `current_exe`, replacing whatever was there before.
+#[cfg(unix)]
+fn install_symlink(current_exe: &Path, target: &Path) -> std::io::Result<()> {
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    if target.symlink_metadata().is_ok() {
+        if std::fs::read_link(target).is_ok_and(|existing| existing == current_exe) {
+            return Ok(());
+        }
+        std::fs::remove_file(target)?;
+    }
+
+    std::os::unix::fs::symlink(current_exe, target)
+}
+
+/// Batch file that forwards every argument to `current_exe`.
+#[cfg_attr(not(windows), allow(dead_code))]
+fn cmd_shim_contents(current_exe: &Path) -> String {
+    format!("@echo off\r\n\"{}\" %*\r\n", current_exe.display())
+}
+
+/// Writes a `.cmd` shim; symlinks need admin rights or developer mode on
+/// Windows, a batch file doesn't.
+#[cfg_attr(not(windows), allow(dead_code))]
+fn install_cmd_shim(current_exe: &Path, target: &Path) -> std::io::Result<()> {
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let contents = cmd_shim_contents(current_exe);
+    if std::fs::read_to_string(target).is_ok_and(|existing| existing == contents) {
+        return Ok(());
+    }
+    std::fs::write(target, contents)
+}
+
+fn dir_on_path(dir: &Path, path_var: Option<&OsStr>) -> bool {
+    let Some(path_var) = path_var else {
+        return false;
+    };
+    let dir = normalize(dir);
+    std::env::split_paths(path_var).any(|entry| normalize(&entry) == dir)
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn path_warning(dir: &Path) -> String {
+    if cfg!(windows) {
+        format!(
+            "Installed, but {0} is not on your PATH. Add it with `setx PATH \"%PATH%;{0}\"` and open a new terminal.",
+            dir.display()
+        )
+    } else {
+        format!(
+            "Installed, but {0} is not on your PATH. Add `export PATH=\"{0}:$PATH\"` to your shell profile and open a new terminal.",
+            dir.display()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xdg_bin_dir_prefers_absolute_xdg_bin_home() {
+        let home = Some(PathBuf::from("/home/me"));
+        assert_eq!(
+            xdg_bin_dir(Some("/opt/bin".into()), home.clone()),
+            Some(PathBuf::from("/opt/bin"))
+        );
+        assert_eq!(
+            xdg_bin_dir(Some("relative/bin".into()), home.clone()),
+            Some(PathBuf::from("/home/me/.local/bin"))
+        );
+        assert_eq!(
+            xdg_bin_dir(None, home),
+            Some(PathBuf::from("/home/me/.local/bin"))
+        );
+        assert_eq!(xdg_bin_dir(None, None), None);
+    }
+
+    #[test]
+    fn test_dir_on_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let other = tempfile::tempdir().unwrap();
+        let path_var = std::env::join_paths([other.path(), dir.path()]).unwrap();
+
+        assert!(dir_on_path(dir.path(), Some(&path_var)));
+        assert!(!dir_on_path(Path::new("/nonexistent/bin"), Some(&path_var)));
+        assert!(!dir_on_path(dir.path(), None));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_install_is_idempotent_and_replaces_stale_links() {
+        let dir = tempfile::tempdir().unwrap();
+        let exe = dir.path().join("lareview-app");
+        std::fs::write(&exe, "").unwrap();
+        let target = dir.path().join("bin").join("lareview");
+
+        install_symlink(&exe, &target).unwrap();
+        assert_eq!(std::fs::read_link(&target).unwrap(), exe);
+        install_symlink(&exe, &target).unwrap();
+        assert_eq!(std::fs::read_link(&target).unwrap(), exe);
+
+        let newer = dir.path().join("lareview-new");
+        std::fs::write(&newer, "").unwrap();
+        install_symlink(&newer, &target).unwrap();
+        assert_eq!(std::fs::read_link(&target).unwrap(), newer);
+    }
+
+    #[test]
+    fn test_cmd_shim_forwards_arguments() {
+        let dir = tempfile::tempdir().unwrap();
+        let exe = dir.path().join("LaReview.exe");
+        let target = dir.path().join("bin").join("lareview.cmd");
+
+        install_cmd_shim(&exe, &target).unwrap();
+        install_cmd_shim(&exe, &target).unwrap();
+
+        let contents = std::fs::read_to_string(&target).unwrap();
+        assert!(contents.starts_with("@echo off\r\n"));
+        assert!(contents.contains(&format!("\"{}\" %*", exe.display())));
+    }
+}
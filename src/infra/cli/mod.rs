@@ -5,4 +5,7 @@
 
 pub mod args;
 pub mod diff;
+pub mod install;
 pub mod repo;
+pub mod review;
+pub mod version;
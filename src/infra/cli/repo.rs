@@ -100,3 +100,96 @@ pub fn get_current_branch(repo_root: &Path) -> Option<String> {
     let branch = String::from_utf8_lossy(&output.stdout);
     Some(branch.trim().to_string())
 }
+
+/// Detect the repository's default branch.
+///
+/// Uses the remote's `HEAD` (`origin/main`) when it is known, otherwise a
+/// local `main` or `master` branch.
+pub fn detect_default_branch(repo_root: &Path) -> Option<String> {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(repo_root)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    if let Some(remote_head) = git(&["symbolic-ref", "--quiet", "refs/remotes/origin/HEAD"])
+        && let Some(branch) = remote_head.strip_prefix("refs/remotes/")
+    {
+        return Some(branch.to_string());
+    }
+
+    ["main", "master"].into_iter().find_map(|branch| {
+        git(&[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{}", branch),
+        ])
+        .map(|_| branch.to_string())
+    })
+}
+
+/// Commit where `head` forked from `base`, so a branch diff leaves out what
+/// landed on `base` since.
+pub fn merge_base(repo_root: &Path, base: &str, head: &str) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["merge-base", base, head])
+        .current_dir(repo_root)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    #[test]
+    fn test_detect_default_branch() {
+        let dir = tempdir().unwrap();
+        let repo = dir.path();
+        if !git(repo, &["init", "--initial-branch=master"]) {
+            return; // git is not installed or too old
+        }
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "user.name", "Test User"]);
+        assert_eq!(detect_default_branch(repo), None);
+
+        std::fs::write(repo.join("file.txt"), "one\n").unwrap();
+        git(repo, &["add", "."]);
+        assert!(git(repo, &["commit", "-m", "initial"]));
+        assert_eq!(detect_default_branch(repo).as_deref(), Some("master"));
+
+        assert!(git(
+            repo,
+            &["update-ref", "refs/remotes/origin/trunk", "HEAD"]
+        ));
+        assert!(git(
+            repo,
+            &[
+                "symbolic-ref",
+                "refs/remotes/origin/HEAD",
+                "refs/remotes/origin/trunk"
+            ]
+        ));
+        assert_eq!(detect_default_branch(repo).as_deref(), Some("origin/trunk"));
+    }
+}
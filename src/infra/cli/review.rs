@@ -0,0 +1,390 @@
+//! Headless review generation (`lareview review`).
+//!
+//! Runs a full generation without opening the window and reports the result
+//! on stdout, so a review can gate CI.
+
+use crate::application::review::conflicts::conflict_feedback;
+use crate::application::review::export::{ExportData, ExportOptions, ReviewExporter};
+use crate::application::review::rules::{filter_by_repo_categories, resolve_rules};
+use crate::domain::{
+    Feedback, FeedbackImpact, LinkedRepo, ResolvedRule, Review, ReviewMode, ReviewRun,
+    ReviewRunStatus, ReviewSource, ReviewStatus, ReviewTask, RuleScope, sort_by_severity,
+};
+use crate::infra::acp::{
+    GenerateTasksInput, RunContext, generate_tasks_with_acp, list_agent_candidates,
+};
+use crate::infra::app_config::load_config;
+use crate::infra::db::Database;
+use crate::infra::diff::cached_diff_index;
+use crate::infra::hash::hash_diff;
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Default generation timeout, matching the GUI.
+const DEFAULT_TIMEOUT_SECS: u64 = 1000;
+
+/// Output format for `lareview review`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ReviewOutputFormat {
+    /// Machine-readable report with tasks and feedback
+    #[default]
+    Json,
+    /// The same markdown produced by "Export to Markdown"
+    Markdown,
+}
+
+/// Inputs for a headless review.
+#[derive(Debug, Clone)]
+pub struct HeadlessReviewOptions {
+    pub diff_text: String,
+    pub agent_id: String,
+    /// Files whose contents are added as extra global rules for this run.
+    pub rule_files: Vec<PathBuf>,
+    /// Repository root the agent may read for context.
+    pub repo: Option<PathBuf>,
+    /// Whether `repo`'s `.lareview/prompt.hbs` may replace the review
+    /// prompt. Off by default: in CI the checkout is the change under review.
+    pub repo_prompt: bool,
+}
+
+/// Result of a headless review.
+#[derive(Debug, Clone, Serialize)]
+pub struct HeadlessReviewReport {
+    pub review: Review,
+    pub run: ReviewRun,
+    pub tasks: Vec<ReviewTask>,
+    pub feedback: Vec<Feedback>,
+}
+
+impl HeadlessReviewReport {
+    /// True when the agent raised at least one blocking issue.
+    pub fn has_blocking_feedback(&self) -> bool {
+        self.feedback
+            .iter()
+            .any(|feedback| feedback.impact == FeedbackImpact::Blocking)
+    }
+
+    pub fn render(&self, format: ReviewOutputFormat) -> Result<String> {
+        match format {
+            ReviewOutputFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+            ReviewOutputFormat::Markdown => {
+                let mut feedbacks = self.feedback.clone();
+                sort_by_severity(&mut feedbacks);
+                let data = ExportData {
+                    review: self.review.clone(),
+                    run: self.run.clone(),
+                    tasks: self.tasks.clone(),
+                    feedbacks,
+                    comments: vec![],
+                    attachments: vec![],
+                    rules: vec![],
+                    merge_confidence: None,
+                    checklist: vec![],
+                };
+                let options = ExportOptions {
+                    include_summary: true,
+                    include_tasks: true,
+                    include_feedbacks: true,
+                    include_context_diff: true,
+                    ..Default::default()
+                };
+                Ok(ReviewExporter::render_markdown(&data, &options)?.markdown)
+            }
+        }
+    }
+}
+
+/// Generates a review for `options.diff_text` and waits for it to finish.
+///
+/// The review is persisted like any GUI review, so it shows up in the app
+/// afterwards.
+pub async fn run_headless_review(options: HeadlessReviewOptions) -> Result<HeadlessReviewReport> {
+    if options.diff_text.trim().is_empty() {
+        anyhow::bail!("The diff is empty; nothing to review.");
+    }
+
+    let candidate = list_agent_candidates()
+        .into_iter()
+        .find(|candidate| candidate.id == options.agent_id)
+        .with_context(|| format!("Unknown agent '{}'", options.agent_id))?;
+    let command = candidate.command.clone().with_context(|| {
+        format!(
+            "Agent '{}' is not available. Configure its path in settings or install it.",
+            options.agent_id
+        )
+    })?;
+
+    let repo_root = options
+        .repo
+        .as_deref()
+        .map(|path| {
+            path.canonicalize()
+                .with_context(|| format!("Repository path {} not found", path.display()))
+        })
+        .transpose()?;
+
+    let db = Database::open().context("Failed to open database")?;
+
+    let diff_paths = cached_diff_index(&options.diff_text)
+        .map(|index| index.get_all_file_paths())
+        .unwrap_or_default();
+    let linked_repo = match &repo_root {
+        Some(root) => find_linked_repo(&db, root)?,
+        None => None,
+    };
+    let mut enabled_rules = db.rule_repo().list_enabled()?;
+    if let Some(repo) = &linked_repo {
+        enabled_rules = filter_by_repo_categories(enabled_rules, &repo.default_rule_categories);
+    }
+    let mut rules = resolve_rules(
+        &enabled_rules,
+        linked_repo.as_ref().map(|repo| repo.id.as_str()),
+        &diff_paths,
+    );
+    rules.extend(load_rule_files(&options.rule_files)?);
+    let issue_checks = db.issue_check_definition_repo().list_enabled()?;
+
+    let diff_hash = hash_diff(&options.diff_text);
+    let review_id = Uuid::new_v4().to_string();
+    let run_id = Uuid::new_v4().to_string();
+    let now = chrono::Utc::now().to_rfc3339();
+    let source = ReviewSource::DiffPaste {
+        diff_hash: diff_hash.clone(),
+    };
+
+    let review = Review {
+        id: review_id.clone(),
+        title: "AI Review".to_string(),
+        summary: None,
+        source: source.clone(),
+        active_run_id: Some(run_id.clone()),
+        status: ReviewStatus::Todo,
+        created_at: now.clone(),
+        updated_at: now.clone(),
+        tags: vec![],
+        decision: None,
+    };
+    let run = ReviewRun {
+        id: run_id.clone(),
+        review_id: review_id.clone(),
+        agent_id: options.agent_id.clone(),
+        input_ref: format!("diff-{}", &diff_hash[..8]),
+        diff_text: Arc::from(options.diff_text.as_str()),
+        diff_hash: diff_hash.clone(),
+        status: ReviewRunStatus::Running,
+        created_at: now.clone(),
+        agent_model: None,
+    };
+    db.save_review(&review)?;
+    db.save_run(&run)?;
+
+    let result = generate_tasks_with_acp(GenerateTasksInput {
+        run_context: RunContext {
+            review_id: review_id.clone(),
+            run_id: run_id.clone(),
+            agent_id: options.agent_id.clone(),
+            input_ref: run.input_ref.clone(),
+            diff_text: run.diff_text.clone(),
+            diff_hash,
+            source,
+            initial_title: None,
+            created_at: Some(now),
+            max_tasks: None,
+        },
+        rules,
+        issue_checks,
+        rejection_examples: Vec::new(),
+        // CI gates want the full review whatever the diff's size.
+        mode: ReviewMode::Thorough,
+        prompt_root: repo_root.clone().filter(|_| options.repo_prompt),
+        repo_root,
+        cleanup_path: None,
+        agent_command: command,
+        agent_args: candidate.args,
+        progress_tx: None,
+        mcp_server_binary: None,
+        db_path: db.path(),
+        timeout_secs: Some(
+            load_config()
+                .review_timeout_secs
+                .unwrap_or(DEFAULT_TIMEOUT_SECS),
+        ),
+        cancel_token: None,
+        debug: std::env::var("RUST_LOG")
+            .map(|v| v.contains("acp"))
+            .unwrap_or(false),
+    })
+    .await;
+
+    let status = if result.is_ok() {
+        ReviewRunStatus::Completed
+    } else {
+        ReviewRunStatus::Failed
+    };
+    if let Err(err) = db.run_repo().update_status(&run_id, status) {
+        log::error!("Failed to update run status for {}: {}", run_id, err);
+    }
+    result.context("Review generation failed")?;
+
+    // Unresolved conflict markers block the review, like in the app.
+    if let Ok(index) = cached_diff_index(&options.diff_text) {
+        let existing = db.get_feedback_by_review(&review_id)?;
+        let now = chrono::Utc::now().to_rfc3339();
+        for (feedback, comment) in conflict_feedback(
+            &review_id,
+            &run.diff_hash,
+            &index.conflicted_files(),
+            &existing,
+            &now,
+        ) {
+            db.save_feedback(&feedback, &feedback.id)?;
+            db.save_comment(&comment)?;
+        }
+    }
+
+    Ok(HeadlessReviewReport {
+        // The agent fills in the title and summary as it goes.
+        review: db.get_review(&review_id)?.unwrap_or(review),
+        run: ReviewRun { status, ..run },
+        tasks: db.get_tasks_by_run(&run_id)?,
+        feedback: db.get_feedback_by_review(&review_id)?,
+    })
+}
+
+/// Finds the linked repo whose checkout is `root`, so repo-scoped rules and
+/// the repo's default rule set apply.
+fn find_linked_repo(db: &Database, root: &Path) -> Result<Option<LinkedRepo>> {
+    Ok(db
+        .repo_repo()
+        .find_all()?
+        .into_iter()
+        .find(|repo| repo.path.canonicalize().is_ok_and(|path| path == root)))
+}
+
+fn load_rule_files(paths: &[PathBuf]) -> Result<Vec<ResolvedRule>> {
+    let mut rules = Vec::new();
+    for path in paths {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read rules file {}", path.display()))?;
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        rules.push(ResolvedRule {
+            id: format!("cli:{}", path.display()),
+            scope: RuleScope::Global,
+            repo_id: None,
+            glob: None,
+            category: None,
+            text: text.to_string(),
+            matched_files: vec![],
+            has_matches: false,
+        });
+    }
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn feedback(impact: FeedbackImpact) -> Feedback {
+        Feedback {
+            id: "fb".into(),
+            review_id: "rev".into(),
+            task_id: None,
+            rule_id: None,
+            finding_id: None,
+            category: None,
+            title: "Title".into(),
+            status: ReviewStatus::Todo,
+            impact,
+            confidence: 1.0,
+            anchor: None,
+            suggested_replacement: None,
+            extra_anchors: Vec::new(),
+            rationale: None,
+            pinned: false,
+            author: "agent".into(),
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        }
+    }
+
+    fn report(feedback: Vec<Feedback>) -> HeadlessReviewReport {
+        HeadlessReviewReport {
+            review: Review {
+                id: "rev".into(),
+                title: "Review".into(),
+                summary: None,
+                source: ReviewSource::DiffPaste {
+                    diff_hash: "h".into(),
+                },
+                active_run_id: Some("run".into()),
+                status: ReviewStatus::Todo,
+                created_at: "now".into(),
+                updated_at: "now".into(),
+                tags: vec![],
+                decision: None,
+            },
+            run: ReviewRun {
+                id: "run".into(),
+                review_id: "rev".into(),
+                agent_id: "agent".into(),
+                input_ref: "input".into(),
+                diff_text: Arc::from(""),
+                diff_hash: "h".into(),
+                status: ReviewRunStatus::Completed,
+                created_at: "now".into(),
+                agent_model: None,
+            },
+            tasks: vec![],
+            feedback,
+        }
+    }
+
+    #[test]
+    fn test_blocking_feedback_is_detected() {
+        assert!(!report(vec![]).has_blocking_feedback());
+        assert!(!report(vec![feedback(FeedbackImpact::NiceToHave)]).has_blocking_feedback());
+        assert!(
+            report(vec![
+                feedback(FeedbackImpact::Nitpick),
+                feedback(FeedbackImpact::Blocking),
+            ])
+            .has_blocking_feedback()
+        );
+    }
+
+    #[test]
+    fn test_json_report_includes_feedback() {
+        let json = report(vec![feedback(FeedbackImpact::Blocking)])
+            .render(ReviewOutputFormat::Json)
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["review"]["id"], "rev");
+        assert_eq!(value["feedback"][0]["impact"], "blocking");
+        assert!(value["tasks"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rule_files_become_global_rules() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "  Flag any unwrap() in library code.  ").unwrap();
+        let empty = tempfile::NamedTempFile::new().unwrap();
+
+        let rules =
+            load_rule_files(&[file.path().to_path_buf(), empty.path().to_path_buf()]).unwrap();
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].scope, RuleScope::Global);
+        assert_eq!(rules[0].text, "Flag any unwrap() in library code.");
+
+        assert!(load_rule_files(&[PathBuf::from("/nonexistent/rules.md")]).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+//! Parsing of `lareview --version` output.
+
+use regex::Regex;
+
+lazy_static::lazy_static! {
+    /// Semver core plus optional pre-release, with optional `+build` metadata.
+    static ref SEMVER: Regex = Regex::new(
+        r"(\d+\.\d+\.\d+(?:-[0-9A-Za-z.-]+)?)(?:\+([0-9A-Za-z.-]+))?"
+    )
+    .unwrap();
+    static ref PARENTHESIZED: Regex = Regex::new(r"\(([^)]+)\)").unwrap();
+}
+
+/// Version reported by an installed `lareview` binary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliVersion {
+    /// Semantic version, including any pre-release tag (`0.1.0-rc.1`).
+    pub version: String,
+    /// Build metadata such as a commit hash or build date, if reported.
+    pub build: Option<String>,
+}
+
+/// Extracts the version from output like `lareview 0.0.37 (abc123)`.
+///
+/// Build metadata is taken from a semver `+build` suffix or, failing that,
+/// from a parenthesized note after the version.
+pub fn parse_cli_version(output: &str) -> Option<CliVersion> {
+    let line = output.lines().map(str::trim).find(|l| !l.is_empty())?;
+    let captures = SEMVER.captures(line)?;
+    let version = captures.get(1)?;
+    let build = captures
+        .get(2)
+        .map(|m| m.as_str().to_string())
+        .or_else(|| {
+            PARENTHESIZED
+                .captures(&line[version.end()..])
+                .map(|c| c[1].trim().to_string())
+        })
+        .filter(|build| !build.is_empty());
+
+    Some(CliVersion {
+        version: version.as_str().to_string(),
+        build,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(output: &str) -> (String, Option<String>) {
+        let v = parse_cli_version(output).unwrap();
+        (v.version, v.build)
+    }
+
+    #[test]
+    fn test_plain_version() {
+        assert_eq!(parsed("lareview 0.0.37\n"), ("0.0.37".into(), None));
+    }
+
+    #[test]
+    fn test_pre_release_version() {
+        assert_eq!(parsed("lareview 0.1.0-rc.1"), ("0.1.0-rc.1".into(), None));
+    }
+
+    #[test]
+    fn test_version_with_build_date() {
+        assert_eq!(
+            parsed("lareview 0.0.37 (2026-01-21)"),
+            ("0.0.37".into(), Some("2026-01-21".into()))
+        );
+    }
+
+    #[test]
+    fn test_version_with_commit_and_semver_metadata() {
+        assert_eq!(
+            parsed("lareview 0.0.37 (abc123)"),
+            ("0.0.37".into(), Some("abc123".into()))
+        );
+        assert_eq!(
+            parsed("lareview 0.2.0-beta.2+sha.5114f85"),
+            ("0.2.0-beta.2".into(), Some("sha.5114f85".into()))
+        );
+    }
+
+    #[test]
+    fn test_unparseable_output() {
+        assert!(parse_cli_version("").is_none());
+        assert!(parse_cli_version("lareview dev").is_none());
+    }
+}
@@ -0,0 +1,186 @@
+//! Moving LaReview's data directory.
+//!
+//! The database, run logs and prompts live in one directory (see
+//! [`app_data_dir`](crate::infra::app_config::app_data_dir)). Users can point
+//! it at a synced or encrypted volume; the existing data is copied there and
+//! the old copy is left in place, so a bad choice never loses reviews.
+
+use crate::infra::app_config::{
+    app_data_dir, data_dir_env_override, default_data_dir, load_config, save_config,
+};
+use crate::infra::db::Database;
+use crate::infra::db::database::DB_FILE_NAME;
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Subdirectories copied along with the database.
+const DATA_SUBDIRS: [&str; 2] = ["logs", "prompts"];
+
+/// Where the data directory is and what decided it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataDirInfo {
+    pub path: String,
+    pub default_path: String,
+    /// Name of the environment variable pinning the directory, if any. The
+    /// setting can't be changed while one is set.
+    pub env_override: Option<String>,
+    /// True when the directory was picked in Settings.
+    pub is_custom: bool,
+}
+
+pub fn data_dir_info() -> DataDirInfo {
+    let config = load_config();
+    let env_override = data_dir_env_override().map(|(name, _)| name.to_string());
+    DataDirInfo {
+        path: app_data_dir().to_string_lossy().into_owned(),
+        default_path: default_data_dir().to_string_lossy().into_owned(),
+        is_custom: env_override.is_none() && config.data_dir.is_some(),
+        env_override,
+    }
+}
+
+/// Creates `dir` if needed and checks a file can be written in it.
+pub fn ensure_writable(dir: &Path) -> Result<()> {
+    let fail = |err: std::io::Error| {
+        anyhow::anyhow!(
+            "LaReview can't write to the data directory {}: {}",
+            dir.display(),
+            err
+        )
+    };
+    std::fs::create_dir_all(dir).map_err(fail)?;
+    let probe = dir.join(".lareview-write-test");
+    std::fs::write(&probe, b"ok").map_err(fail)?;
+    std::fs::remove_file(&probe).map_err(fail)?;
+    Ok(())
+}
+
+/// Copies the database and the data subdirectories from `from` to `to`.
+///
+/// Returns false without copying anything when `to` already holds a
+/// database, e.g. a synced folder set up on another machine: that data is
+/// used as-is.
+pub fn migrate_data(db: &Database, from: &Path, to: &Path) -> Result<bool> {
+    let target_db = to.join(DB_FILE_NAME);
+    if target_db.exists() {
+        return Ok(false);
+    }
+    db.copy_to(&target_db)
+        .with_context(|| format!("Failed to copy the database to {}", target_db.display()))?;
+    for name in DATA_SUBDIRS {
+        let source = from.join(name);
+        let target = to.join(name);
+        if source.is_dir() && !target.exists() {
+            copy_dir(&source, &target)
+                .with_context(|| format!("Failed to copy {} to {}", name, to.display()))?;
+        }
+    }
+    Ok(true)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Moves the data directory to `path` (None for the platform default):
+/// validates it, copies the current data over, reopens the database there
+/// and saves the choice. Returns the database now in use.
+pub fn change_data_dir(db: &Database, path: Option<&str>) -> Result<(Database, DataDirInfo)> {
+    if let Some((name, _)) = data_dir_env_override() {
+        bail!(
+            "The data directory is set by {}; unset it to choose one here.",
+            name
+        );
+    }
+    let from = app_data_dir();
+    let to = match path.map(str::trim).filter(|path| !path.is_empty()) {
+        Some(path) => PathBuf::from(path),
+        None => default_data_dir(),
+    };
+    if !to.is_absolute() {
+        bail!("The data directory must be an absolute path");
+    }
+
+    ensure_writable(&to)?;
+    if to != from {
+        migrate_data(db, &from, &to)?;
+    }
+    let new_db = Database::open_at(to.join(DB_FILE_NAME))?;
+
+    let mut config = load_config();
+    config.data_dir = (to != default_data_dir()).then(|| to.to_string_lossy().into_owned());
+    save_config(&config).context("Failed to save the data directory setting")?;
+    Ok((new_db, data_dir_info()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrate_copies_database_and_logs() {
+        let from = tempfile::tempdir().unwrap();
+        let to = tempfile::tempdir().unwrap();
+        let db = Database::open_at(from.path().join(DB_FILE_NAME)).unwrap();
+        std::fs::create_dir_all(from.path().join("logs/runs")).unwrap();
+        std::fs::write(from.path().join("logs/runs/run.log"), "hello").unwrap();
+
+        assert!(migrate_data(&db, from.path(), to.path()).unwrap());
+        assert!(to.path().join(DB_FILE_NAME).is_file());
+        assert_eq!(
+            std::fs::read_to_string(to.path().join("logs/runs/run.log")).unwrap(),
+            "hello"
+        );
+        // The copy is a working database.
+        let copied = Database::open_at(to.path().join(DB_FILE_NAME)).unwrap();
+        assert_eq!(copied.health().unwrap().quick_check, "ok");
+    }
+
+    #[test]
+    fn test_migrate_keeps_existing_target_database() {
+        let from = tempfile::tempdir().unwrap();
+        let to = tempfile::tempdir().unwrap();
+        let db = Database::open_at(from.path().join(DB_FILE_NAME)).unwrap();
+        std::fs::write(to.path().join(DB_FILE_NAME), "existing").unwrap();
+
+        assert!(!migrate_data(&db, from.path(), to.path()).unwrap());
+        assert_eq!(
+            std::fs::read_to_string(to.path().join(DB_FILE_NAME)).unwrap(),
+            "existing"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_ensure_writable_reports_the_directory() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let locked = dir.path().join("locked");
+        std::fs::create_dir(&locked).unwrap();
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o555)).unwrap();
+
+        let result = ensure_writable(&locked);
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o755)).unwrap();
+        // Root ignores permission bits, so only check the message on failure.
+        if let Err(err) = result {
+            assert!(
+                err.to_string()
+                    .contains("can't write to the data directory")
+            );
+            assert!(err.to_string().contains("locked"));
+        }
+        assert!(ensure_writable(&dir.path().join("new/nested")).is_ok());
+    }
+}
@@ -1,27 +1,46 @@
 use crate::commands::{LinkedRepoState, PendingReviewState, ReviewRunState, ReviewState};
 use crate::domain::{Comment, Feedback, Review, ReviewRun, ReviewTask};
+use crate::infra::db::encryption;
+use crate::infra::db::pool::{DbConn, READ_CONNECTIONS};
 use anyhow::Result;
 use rusqlite::{Connection, params};
-use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
+/// File name of the database inside the data directory.
+pub const DB_FILE_NAME: &str = "db.sqlite";
+
+/// Handle on the database; clones share the same connections.
+#[derive(Clone)]
 pub struct Database {
-    conn: Arc<Mutex<Connection>>,
+    conn: DbConn,
+}
+
+/// Where the database lives and whether SQLite considers it intact.
+#[derive(Debug, Clone)]
+pub struct DatabaseHealth {
+    /// None for in-memory databases.
+    pub path: Option<String>,
+    pub sqlite_version: String,
+    /// `PRAGMA quick_check` output: "ok", or the problems found.
+    pub quick_check: String,
 }
 
 impl Database {
     pub fn open() -> Result<Self> {
         let path = Self::default_path();
+        if let Some(parent) = path.parent() {
+            crate::infra::data_dir::ensure_writable(parent)?;
+        }
         Self::open_at(path)
     }
 
     pub fn open_in_memory() -> Result<Self> {
         let conn = Connection::open_in_memory()?;
-        let db = Self {
-            conn: Arc::new(Mutex::new(conn)),
-        };
-        db.init()?;
-        Ok(db)
+        Self::init(&conn)?;
+        Ok(Self {
+            conn: DbConn::new(conn),
+        })
     }
 
     pub fn open_at(path: PathBuf) -> Result<Self> {
@@ -30,66 +49,71 @@ impl Database {
         }
 
         let conn = Connection::open(&path)?;
-        let db = Self {
-            conn: Arc::new(Mutex::new(conn)),
-        };
-        db.init()?;
-
-        if std::env::var("LAREVIEW_DB_PATH").is_err() {
-            unsafe {
-                std::env::set_var("LAREVIEW_DB_PATH", path.to_string_lossy().to_string());
-            }
+        if let Some(passphrase) = encryption::passphrase_for_open()? {
+            encryption::apply_key(&conn, &passphrase)?;
         }
-        Ok(db)
+        DbConn::configure_writer(&conn)?;
+        Self::init(&conn)?;
+        Ok(Self {
+            conn: DbConn::open_file(conn, &path, READ_CONNECTIONS)?,
+        })
     }
 
-    fn default_path() -> PathBuf {
+    /// `LAREVIEW_DB_PATH`, else `db.sqlite` in the data directory.
+    pub fn default_path() -> PathBuf {
         if let Ok(path) = std::env::var("LAREVIEW_DB_PATH") {
             return PathBuf::from(path);
         }
+        crate::infra::app_config::app_data_dir().join(DB_FILE_NAME)
+    }
 
-        #[cfg(target_os = "macos")]
-        {
-            if let Some(home) = home::home_dir() {
-                return home
-                    .join("Library")
-                    .join("Application Support")
-                    .join("LaReview")
-                    .join("db.sqlite");
-            }
+    /// Writes a consistent copy of the database to `path`, which must not
+    /// exist yet. Safe while the database is in use. An encrypted database
+    /// is copied encrypted with the same passphrase.
+    pub fn copy_to(&self, path: &Path) -> Result<()> {
+        if let Some(passphrase) = encryption::passphrase_for_open()? {
+            return self.export_encrypted(path, &passphrase);
         }
+        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        conn.execute("VACUUM INTO ?1", params![path.to_string_lossy()])?;
+        Ok(())
+    }
 
-        #[cfg(target_os = "windows")]
-        {
-            if let Some(appdata) = std::env::var_os("APPDATA") {
-                return PathBuf::from(appdata).join("LaReview").join("db.sqlite");
-            }
-        }
+    /// Writes an encrypted copy of the database to `path` (SQLCipher only).
+    pub fn export_encrypted(&self, path: &Path, passphrase: &str) -> Result<()> {
+        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        Self::export_encrypted_from(&conn, path, passphrase)
+    }
 
-        #[cfg(target_os = "linux")]
-        {
-            if let Some(xdg) = std::env::var_os("XDG_DATA_HOME") {
-                return PathBuf::from(xdg).join("lareview").join("db.sqlite");
-            }
-            if let Some(home) = home::home_dir() {
-                return home
-                    .join(".local")
-                    .join("share")
-                    .join("lareview")
-                    .join("db.sqlite");
-            }
-        }
+    /// `export_encrypted` for a connection taken out with `close`.
+    pub fn export_encrypted_from(conn: &Connection, path: &Path, passphrase: &str) -> Result<()> {
+        conn.execute(
+            "ATTACH DATABASE ?1 AS encrypted KEY ?2",
+            params![path.to_string_lossy(), passphrase],
+        )?;
+        let exported = conn.query_row("SELECT sqlcipher_export('encrypted')", [], |_| Ok(()));
+        conn.execute_batch("DETACH DATABASE encrypted")?;
+        exported?;
+        Ok(())
+    }
 
-        std::env::current_dir()
-            .unwrap_or_else(|_| PathBuf::from("."))
-            .join(".lareview")
-            .join("db.sqlite")
+    /// Closes the database for every clone of this handle and returns the
+    /// write connection; see [`DbConn::close`].
+    pub fn close(&self) -> Result<Connection> {
+        self.conn.close()
     }
 
-    fn init(&self) -> Result<()> {
+    /// The database file; None for in-memory databases.
+    pub fn path(&self) -> Option<PathBuf> {
         let conn = self.conn.lock().expect("Failed to acquire database lock");
+        conn.path()
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+    }
+
+    fn init(conn: &Connection) -> Result<()> {
         conn.execute_batch("PRAGMA foreign_keys = ON;")?;
-        Self::create_schema(&conn)?;
+        Self::create_schema(conn)?;
         Ok(())
     }
 
@@ -101,7 +125,9 @@ impl Database {
                 name TEXT NOT NULL,
                 path TEXT NOT NULL,
                 created_at TEXT NOT NULL,
-                allow_snapshot_access INTEGER DEFAULT 0
+                allow_snapshot_access INTEGER DEFAULT 0,
+                default_agent_id TEXT,
+                default_rule_categories TEXT
             );
 
             CREATE TABLE IF NOT EXISTS repo_remotes (
@@ -119,7 +145,8 @@ impl Database {
                 active_run_id TEXT,
                 status TEXT NOT NULL DEFAULT 'todo',
                 created_at TEXT NOT NULL,
-                updated_at TEXT NOT NULL
+                updated_at TEXT NOT NULL,
+                decision TEXT
             );
 
             CREATE TABLE IF NOT EXISTS review_runs (
@@ -131,6 +158,10 @@ impl Database {
                 diff_hash TEXT NOT NULL,
                 status TEXT NOT NULL DEFAULT 'completed',
                 created_at TEXT NOT NULL,
+                sub_flow_order TEXT,
+                plan TEXT,
+                tool_calls TEXT,
+                agent_model TEXT,
                 FOREIGN KEY(review_id) REFERENCES reviews(id) ON DELETE CASCADE
             );
 
@@ -287,6 +318,10 @@ impl Database {
             "CREATE INDEX IF NOT EXISTS idx_review_runs_status ON review_runs(status)",
             [],
         )?;
+        conn.execute_batch(
+            "CREATE INDEX IF NOT EXISTS idx_reviews_updated_at ON reviews(updated_at, created_at);
+             CREATE INDEX IF NOT EXISTS idx_reviews_created_at ON reviews(created_at);",
+        )?;
 
         // Migration: Add allow_snapshot_access to repos if it doesn't exist
         let has_snapshot_access = conn
@@ -302,6 +337,19 @@ impl Database {
             )?;
         }
 
+        // Migration: Add per-repo generation defaults if they don't exist
+        let has_repo_defaults = conn
+            .prepare("SELECT 1 FROM pragma_table_info('repos') WHERE name = 'default_agent_id'")?
+            .exists([])?;
+
+        if !has_repo_defaults {
+            conn.execute("ALTER TABLE repos ADD COLUMN default_agent_id TEXT", [])?;
+            conn.execute(
+                "ALTER TABLE repos ADD COLUMN default_rule_categories TEXT",
+                [],
+            )?;
+        }
+
         // Migration: Add rule_type to review_rules if it doesn't exist
         let has_rule_type = conn
             .prepare("SELECT 1 FROM pragma_table_info('review_rules') WHERE name = 'rule_type'")?
@@ -357,6 +405,154 @@ impl Database {
             conn.execute("ALTER TABLE feedback ADD COLUMN category TEXT", [])?;
         }
 
+        // Migration: Add suggested_replacement to feedback if it doesn't exist
+        let has_suggested_replacement = conn
+            .prepare(
+                "SELECT 1 FROM pragma_table_info('feedback') WHERE name = 'suggested_replacement'",
+            )?
+            .exists([])?;
+
+        if !has_suggested_replacement {
+            conn.execute(
+                "ALTER TABLE feedback ADD COLUMN suggested_replacement TEXT",
+                [],
+            )?;
+        }
+
+        // Migration: Add anchor_diff_hash to feedback if it doesn't exist
+        let has_anchor_diff_hash = conn
+            .prepare("SELECT 1 FROM pragma_table_info('feedback') WHERE name = 'anchor_diff_hash'")?
+            .exists([])?;
+
+        if !has_anchor_diff_hash {
+            conn.execute("ALTER TABLE feedback ADD COLUMN anchor_diff_hash TEXT", [])?;
+        }
+
+        // Migration: Add anchor_outdated to feedback if it doesn't exist
+        let has_anchor_outdated = conn
+            .prepare("SELECT 1 FROM pragma_table_info('feedback') WHERE name = 'anchor_outdated'")?
+            .exists([])?;
+
+        if !has_anchor_outdated {
+            conn.execute(
+                "ALTER TABLE feedback ADD COLUMN anchor_outdated INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+        }
+
+        // Migration: Add extra_anchors to feedback if it doesn't exist
+        let has_extra_anchors = conn
+            .prepare("SELECT 1 FROM pragma_table_info('feedback') WHERE name = 'extra_anchors'")?
+            .exists([])?;
+
+        if !has_extra_anchors {
+            conn.execute("ALTER TABLE feedback ADD COLUMN extra_anchors TEXT", [])?;
+        }
+
+        // Migration: Add rationale to feedback if it doesn't exist
+        let has_rationale = conn
+            .prepare("SELECT 1 FROM pragma_table_info('feedback') WHERE name = 'rationale'")?
+            .exists([])?;
+
+        if !has_rationale {
+            conn.execute("ALTER TABLE feedback ADD COLUMN rationale TEXT", [])?;
+        }
+
+        // Migration: Add pinned to feedback if it doesn't exist
+        let has_pinned = conn
+            .prepare("SELECT 1 FROM pragma_table_info('feedback') WHERE name = 'pinned'")?
+            .exists([])?;
+
+        if !has_pinned {
+            conn.execute(
+                "ALTER TABLE feedback ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0",
+                [],
+            )?;
+        }
+
+        // Migration: Add sub_flow_order to review_runs if it doesn't exist
+        let has_sub_flow_order = conn
+            .prepare(
+                "SELECT 1 FROM pragma_table_info('review_runs') WHERE name = 'sub_flow_order'",
+            )?
+            .exists([])?;
+
+        if !has_sub_flow_order {
+            conn.execute("ALTER TABLE review_runs ADD COLUMN sub_flow_order TEXT", [])?;
+        }
+
+        // Migration: Add decision to reviews if it doesn't exist
+        let has_review_decision = conn
+            .prepare("SELECT 1 FROM pragma_table_info('reviews') WHERE name = 'decision'")?
+            .exists([])?;
+
+        if !has_review_decision {
+            conn.execute("ALTER TABLE reviews ADD COLUMN decision TEXT", [])?;
+        }
+
+        // Migration: Add plan to review_runs if it doesn't exist
+        let has_run_plan = conn
+            .prepare("SELECT 1 FROM pragma_table_info('review_runs') WHERE name = 'plan'")?
+            .exists([])?;
+
+        if !has_run_plan {
+            conn.execute("ALTER TABLE review_runs ADD COLUMN plan TEXT", [])?;
+        }
+
+        // Migration: Add tool_calls to review_runs if it doesn't exist
+        let has_run_tool_calls = conn
+            .prepare("SELECT 1 FROM pragma_table_info('review_runs') WHERE name = 'tool_calls'")?
+            .exists([])?;
+
+        if !has_run_tool_calls {
+            conn.execute("ALTER TABLE review_runs ADD COLUMN tool_calls TEXT", [])?;
+        }
+
+        // Migration: Add agent_model to review_runs if it doesn't exist
+        let has_run_agent_model = conn
+            .prepare("SELECT 1 FROM pragma_table_info('review_runs') WHERE name = 'agent_model'")?
+            .exists([])?;
+
+        if !has_run_agent_model {
+            conn.execute("ALTER TABLE review_runs ADD COLUMN agent_model TEXT", [])?;
+        }
+
+        // Create review_tags table for user-assigned review labels
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS review_tags (
+                review_id TEXT NOT NULL,
+                tag TEXT NOT NULL,
+                PRIMARY KEY(review_id, tag),
+                FOREIGN KEY(review_id) REFERENCES reviews(id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_review_tags_tag ON review_tags(tag)",
+            [],
+        )?;
+
+        // Create review_checklist_items table for reviewer follow-ups before merge
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS review_checklist_items (
+                id TEXT PRIMARY KEY,
+                review_id TEXT NOT NULL,
+                text TEXT NOT NULL,
+                done INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL,
+                FOREIGN KEY(review_id) REFERENCES reviews(id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_review_checklist_items_review ON review_checklist_items(review_id)",
+            [],
+        )?;
+
         // Create feedback_rejections table for tracking rejected/ignored feedback patterns
         conn.execute(
             r#"
@@ -378,6 +574,42 @@ impl Database {
             [],
         )?;
 
+        // Create feedback_attachments table for images pasted into feedback threads
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS feedback_attachments (
+                id TEXT PRIMARY KEY,
+                feedback_id TEXT NOT NULL,
+                mime TEXT NOT NULL,
+                data BLOB NOT NULL,
+                byte_size INTEGER NOT NULL,
+                created_at TEXT NOT NULL,
+                FOREIGN KEY(feedback_id) REFERENCES feedback(id) ON DELETE CASCADE
+            )
+            "#,
+            [],
+        )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_feedback_attachments_feedback_id ON feedback_attachments(feedback_id)",
+            [],
+        )?;
+
+        // Create issue_check_definitions table for user-defined checklist items
+        conn.execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS issue_check_definitions (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                category TEXT NOT NULL,
+                prompt TEXT NOT NULL,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                created_at TEXT NOT NULL,
+                updated_at TEXT NOT NULL
+            )
+            "#,
+            [],
+        )?;
+
         // Add indexes for efficient querying of rejection patterns
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_feedback_rejections_rule_id ON feedback_rejections(rule_id)",
@@ -409,6 +641,37 @@ impl Database {
                 updated_at TEXT NOT NULL
             );
 
+            CREATE TABLE IF NOT EXISTS tool_timings (
+                run_id TEXT NOT NULL,
+                tool TEXT NOT NULL,
+                calls INTEGER NOT NULL DEFAULT 0,
+                total_ms INTEGER NOT NULL DEFAULT 0,
+                max_ms INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY(run_id, tool),
+                FOREIGN KEY(run_id) REFERENCES review_runs(id) ON DELETE CASCADE
+            );
+
+            CREATE TABLE IF NOT EXISTS file_view_state (
+                run_id TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                viewed INTEGER NOT NULL DEFAULT 0,
+                content_hash TEXT NOT NULL,
+                updated_at TEXT NOT NULL,
+                PRIMARY KEY(run_id, file_path),
+                FOREIGN KEY(run_id) REFERENCES review_runs(id) ON DELETE CASCADE
+            );
+
+            CREATE TABLE IF NOT EXISTS pending_diffs (
+                id TEXT PRIMARY KEY,
+                diff TEXT NOT NULL,
+                diff_hash TEXT NOT NULL UNIQUE,
+                repo_root TEXT,
+                agent TEXT,
+                source TEXT NOT NULL,
+                review_source TEXT,
+                created_at TEXT NOT NULL
+            );
+
             CREATE INDEX IF NOT EXISTS idx_learned_patterns_enabled ON learned_patterns(enabled);
             CREATE INDEX IF NOT EXISTS idx_learned_patterns_category ON learned_patterns(category);
             "#,
@@ -631,10 +894,28 @@ impl Database {
         Ok(targets)
     }
 
-    pub fn connection(&self) -> Arc<Mutex<Connection>> {
+    pub fn connection(&self) -> DbConn {
         self.conn.clone()
     }
 
+    pub fn health(&self) -> Result<DatabaseHealth> {
+        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let sqlite_version: String =
+            conn.query_row("SELECT sqlite_version()", [], |row| row.get(0))?;
+        let mut stmt = conn.prepare("PRAGMA quick_check")?;
+        let problems = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(DatabaseHealth {
+            path: conn
+                .path()
+                .filter(|path| !path.is_empty())
+                .map(str::to_string),
+            sqlite_version,
+            quick_check: problems.join("; "),
+        })
+    }
+
     pub fn task_repo(&self) -> crate::infra::db::repository::TaskRepository {
         crate::infra::db::repository::TaskRepository::new(self.connection())
     }
@@ -651,6 +932,14 @@ impl Database {
         crate::infra::db::repository::CommentRepository::new(self.connection())
     }
 
+    pub fn attachment_repo(&self) -> crate::infra::db::repository::AttachmentRepository {
+        crate::infra::db::repository::AttachmentRepository::new(self.connection())
+    }
+
+    pub fn checklist_repo(&self) -> crate::infra::db::repository::ChecklistRepository {
+        crate::infra::db::repository::ChecklistRepository::new(self.connection())
+    }
+
     pub fn rule_repo(&self) -> crate::infra::db::repository::ReviewRuleRepository {
         crate::infra::db::repository::ReviewRuleRepository::new(self.connection())
     }
@@ -671,10 +960,20 @@ impl Database {
         crate::infra::db::repository::IssueCheckRepository::new(self.connection())
     }
 
+    pub fn issue_check_definition_repo(
+        &self,
+    ) -> crate::infra::db::repository::IssueCheckDefinitionRepository {
+        crate::infra::db::repository::IssueCheckDefinitionRepository::new(self.connection())
+    }
+
     pub fn rejection_repo(&self) -> crate::infra::db::repository::FeedbackRejectionRepository {
         crate::infra::db::repository::FeedbackRejectionRepository::new(self.connection())
     }
 
+    pub fn pending_diff_repo(&self) -> crate::infra::db::repository::PendingDiffRepository {
+        crate::infra::db::repository::PendingDiffRepository::new(self.connection())
+    }
+
     pub fn merge_confidence_repo(&self) -> crate::infra::db::repository::MergeConfidenceRepository {
         crate::infra::db::repository::MergeConfidenceRepository::new(self.connection())
     }
@@ -688,7 +987,7 @@ impl Database {
     }
 
     pub fn get_pending_reviews(&self) -> Result<Vec<PendingReviewState>, rusqlite::Error> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
             "SELECT r.id, r.active_run_id, r.source_json, r.created_at, r.updated_at
              FROM reviews r
@@ -737,17 +1036,69 @@ impl Database {
         })
     }
 
+    /// Every review, most recently updated first.
+    ///
+    /// Deprecated: loads the whole table; use [`Self::get_reviews_page`].
     pub fn get_all_reviews(&self) -> Result<Vec<ReviewState>, rusqlite::Error> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        self.query_reviews(None, 0)
+    }
+
+    /// One page of reviews, most recently updated first, along with the total
+    /// number of reviews.
+    pub fn get_reviews_page(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<(Vec<ReviewState>, usize), rusqlite::Error> {
+        let total: i64 = {
+            let conn = self.conn.read().expect("Failed to acquire database lock");
+            conn.query_row("SELECT COUNT(*) FROM reviews", [], |row| row.get(0))?
+        };
+        let reviews = self.query_reviews(Some(limit), offset)?;
+        Ok((reviews, total as usize))
+    }
+
+    /// One page of tasks, optionally for a single run, along with how many
+    /// tasks match.
+    pub fn get_tasks_page(
+        &self,
+        run_id: Option<&str>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<(Vec<ReviewTask>, usize), rusqlite::Error> {
+        let run_id = run_id.map(str::to_string);
+        self.task_repo()
+            .find_page(run_id.as_ref(), limit, offset)
+            .map_err(|e| {
+                rusqlite::Error::SqliteFailure(rusqlite::ffi::Error::new(1), Some(e.to_string()))
+            })
+    }
+
+    /// Reviews ordered by last update; `limit: None` returns all of them.
+    fn query_reviews(
+        &self,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Result<Vec<ReviewState>, rusqlite::Error> {
+        // Linked repos are loaded once up front (before taking the lock) and
+        // each repository's match is reused by all of its reviews.
+        let linked_repos = self.get_linked_repos()?;
+        let mut repo_matches: HashMap<Vec<String>, Option<(String, String)>> = HashMap::new();
+
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
-            "SELECT r.id, r.title, r.summary, rr.agent_id, COUNT(t.id) as task_count, r.created_at, r.source_json, r.status, rr.status
+            "SELECT r.id, r.title, r.summary, rr.agent_id, COUNT(t.id) as task_count, r.created_at, r.source_json, r.status, rr.status, r.active_run_id, r.decision
              FROM reviews r
              LEFT JOIN review_runs rr ON r.active_run_id = rr.id
              LEFT JOIN tasks t ON t.run_id = rr.id
              GROUP BY r.id
-             ORDER BY r.updated_at DESC",
+             ORDER BY r.updated_at DESC, r.created_at DESC
+             LIMIT ?1 OFFSET ?2",
         )?;
-        let rows = stmt.query_map([], |row| {
+        let mut tags = crate::infra::db::repository::load_all_tags(&conn)?;
+        // SQLite treats a negative LIMIT as no limit.
+        let limit = limit.map_or(-1, |limit| limit as i64);
+        let rows = stmt.query_map(rusqlite::params![limit, offset as i64], |row| {
             let source_json: String = row.get(6)?;
             let source: crate::domain::ReviewSource = serde_json::from_str(&source_json)
                 .unwrap_or_else(|_| crate::domain::ReviewSource::DiffPaste {
@@ -764,22 +1115,46 @@ impl Database {
                 agent_id: row.get(3)?,
                 task_count: row.get::<_, i32>(4)? as usize,
                 created_at: row.get(5)?,
+                suggested_tags: source.suggested_tags(),
                 source,
                 status: status_str,
+                active_run_id: row.get(9)?,
                 active_run_status,
+                tags: vec![],
+                decision: row
+                    .get::<_, Option<String>>(10)?
+                    .and_then(|decision| decision.parse().ok()),
+                repo_id: None,
+                repo_name: None,
             })
         })?;
         let mut reviews = Vec::new();
         for row in rows {
-            reviews.push(row?);
+            let mut review = row?;
+            review.tags = tags.remove(&review.id).unwrap_or_default();
+            let patterns = review.source.remote_patterns();
+            if !patterns.is_empty() {
+                let source = &review.source;
+                let matched = repo_matches.entry(patterns).or_insert_with(|| {
+                    linked_repos
+                        .iter()
+                        .find(|repo| repo.remotes.iter().any(|r| source.matches_remote(r)))
+                        .map(|repo| (repo.id.clone(), repo.name.clone()))
+                });
+                if let Some((id, name)) = matched.clone() {
+                    review.repo_id = Some(id);
+                    review.repo_name = Some(name);
+                }
+            }
+            reviews.push(review);
         }
         Ok(reviews)
     }
 
     pub fn get_review_runs(&self, review_id: &str) -> Result<Vec<ReviewRunState>, rusqlite::Error> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
-            "SELECT rr.id, rr.review_id, rr.agent_id, rr.input_ref, rr.diff_text, rr.status, rr.created_at, COUNT(t.id) as task_count
+            "SELECT rr.id, rr.review_id, rr.agent_id, rr.input_ref, rr.diff_hash, rr.status, rr.created_at, COUNT(t.id) as task_count, rr.plan, rr.agent_model
              FROM review_runs rr
              LEFT JOIN tasks t ON t.run_id = rr.id
              WHERE rr.review_id = ?1
@@ -792,10 +1167,14 @@ impl Database {
                 review_id: row.get(1)?,
                 agent_id: row.get(2)?,
                 input_ref: row.get(3)?,
-                diff_text: row.get(4)?,
+                diff_hash: row.get(4)?,
                 status: row.get(5)?,
                 created_at: row.get(6)?,
                 task_count: row.get::<_, i32>(7)? as usize,
+                plan: row
+                    .get::<_, Option<String>>(8)?
+                    .and_then(|json| serde_json::from_str(&json).ok()),
+                agent_model: row.get(9)?,
             })
         })?;
         let mut runs = Vec::new();
@@ -814,9 +1193,9 @@ impl Database {
     }
 
     pub fn get_linked_repos(&self) -> Result<Vec<LinkedRepoState>, rusqlite::Error> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt =
-            conn.prepare("SELECT id, name, path, created_at, allow_snapshot_access FROM repos ORDER BY created_at DESC")?;
+            conn.prepare("SELECT id, name, path, created_at, allow_snapshot_access, default_agent_id, default_rule_categories FROM repos ORDER BY created_at DESC")?;
 
         let rows = stmt.query_map([], |row| {
             let id: String = row.get(0)?;
@@ -824,13 +1203,32 @@ impl Database {
             let path: String = row.get(2)?;
             let linked_at: String = row.get(3)?;
             let allow_snapshot_access: bool = row.get::<_, Option<bool>>(4)?.unwrap_or(false);
+            let default_agent_id: Option<String> = row.get(5)?;
+            let default_rule_categories =
+                crate::infra::db::repository::decode_categories(row.get::<_, Option<String>>(6)?);
 
-            Ok((id, name, path, linked_at, allow_snapshot_access))
+            Ok((
+                id,
+                name,
+                path,
+                linked_at,
+                allow_snapshot_access,
+                default_agent_id,
+                default_rule_categories,
+            ))
         })?;
 
         let mut repos = Vec::new();
         for row in rows {
-            let (id, name, path, linked_at, allow_snapshot_access) = row?;
+            let (
+                id,
+                name,
+                path,
+                linked_at,
+                allow_snapshot_access,
+                default_agent_id,
+                default_rule_categories,
+            ) = row?;
 
             // Fetch remotes
             let mut remote_stmt =
@@ -849,6 +1247,9 @@ impl Database {
                 linked_at,
                 remotes,
                 allow_snapshot_access,
+                default_agent_id,
+                default_rule_categories,
+                watched: false,
             });
         }
         Ok(repos)
@@ -860,8 +1261,8 @@ impl Database {
             rusqlite::Error::SqliteFailure(rusqlite::ffi::Error::new(1), Some(e.to_string()))
         })?;
         conn.execute(
-            "INSERT INTO reviews (id, title, summary, source_json, active_run_id, status, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "INSERT INTO reviews (id, title, summary, source_json, active_run_id, status, created_at, updated_at, decision)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
              ON CONFLICT(id) DO NOTHING",
             params![
                 &review.id,
@@ -872,6 +1273,7 @@ impl Database {
                 &review.status.to_string(),
                 &review.created_at,
                 &review.updated_at,
+                review.decision.map(|decision| decision.to_string()),
             ],
         )?;
         Ok(())
@@ -899,8 +1301,8 @@ impl Database {
     pub fn save_run(&self, run: &ReviewRun) -> Result<(), rusqlite::Error> {
         let conn = self.conn.lock().expect("Failed to acquire database lock");
         conn.execute(
-            "INSERT INTO review_runs (id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "INSERT INTO review_runs (id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at, agent_model)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
              ON CONFLICT(id) DO NOTHING",
             params![
                 &run.id,
@@ -911,6 +1313,7 @@ impl Database {
                 &run.diff_hash,
                 &run.status.to_string(),
                 &run.created_at,
+                &run.agent_model,
             ],
         )?;
         Ok(())
@@ -920,8 +1323,8 @@ impl Database {
         let conn = self.conn.lock().expect("Failed to acquire database lock");
         let anchor = feedback.anchor.as_ref();
         conn.execute(
-            "INSERT INTO feedback (id, review_id, task_id, rule_id, title, status, impact, anchor_file_path, anchor_line, anchor_side, anchor_hunk_ref, anchor_head_sha, author, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            "INSERT INTO feedback (id, review_id, task_id, rule_id, title, status, impact, anchor_file_path, anchor_line, anchor_side, anchor_hunk_ref, anchor_head_sha, author, created_at, updated_at, anchor_diff_hash)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
              ON CONFLICT(id) DO NOTHING",
             params![
                 &feedback.id,
@@ -939,6 +1342,7 @@ impl Database {
                 &feedback.author,
                 &feedback.created_at,
                 &feedback.updated_at,
+                anchor.and_then(|a| a.diff_hash.as_deref()),
             ],
         )?;
         Ok(())
@@ -1052,6 +1456,8 @@ mod tests {
             status: ReviewStatus::Todo,
             created_at: "now".to_string(),
             updated_at: "now".to_string(),
+            tags: vec![],
+            decision: None,
         };
         db.save_review(&review)?;
 
@@ -1064,11 +1470,13 @@ mod tests {
             diff_hash: "h".into(),
             status: ReviewRunStatus::Running,
             created_at: "now".into(),
+            agent_model: None,
         };
         db.save_run(&run)?;
 
         let reviews = db.get_all_reviews()?;
         assert_eq!(reviews.len(), 1);
+        assert_eq!(reviews[0].active_run_id.as_deref(), Some("run-1"));
         assert_eq!(reviews[0].active_run_status.as_deref(), Some("running"));
 
         Ok(())
@@ -1089,6 +1497,8 @@ mod tests {
             status: ReviewStatus::Todo,
             created_at: "now".to_string(),
             updated_at: "now".to_string(),
+            tags: vec![],
+            decision: None,
         };
         db.save_review(&running_review)?;
 
@@ -1103,6 +1513,8 @@ mod tests {
             status: ReviewStatus::Todo,
             created_at: "now".to_string(),
             updated_at: "now".to_string(),
+            tags: vec![],
+            decision: None,
         };
         db.save_review(&completed_review)?;
 
@@ -1115,6 +1527,7 @@ mod tests {
             diff_hash: "h".into(),
             status: ReviewRunStatus::Running,
             created_at: "now".into(),
+            agent_model: None,
         })?;
 
         db.save_run(&ReviewRun {
@@ -1126,6 +1539,7 @@ mod tests {
             diff_hash: "h2".into(),
             status: ReviewRunStatus::Completed,
             created_at: "now".into(),
+            agent_model: None,
         })?;
 
         let pending = db.get_pending_reviews()?;
@@ -1150,6 +1564,8 @@ mod tests {
             status: ReviewStatus::Todo,
             created_at: "now".to_string(),
             updated_at: "now".to_string(),
+            tags: vec![],
+            decision: None,
         };
         db.save_review(&review)?;
 
@@ -1162,6 +1578,7 @@ mod tests {
             diff_hash: "h".into(),
             status: ReviewRunStatus::Running,
             created_at: "now".into(),
+            agent_model: None,
         })?;
 
         let updated = db.mark_stale_runs_failed()?;
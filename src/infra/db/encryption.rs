@@ -0,0 +1,211 @@
+//! Optional encryption at rest (the `encryption` feature).
+//!
+//! With the feature on, SQLite is SQLCipher and the database can be keyed
+//! with a passphrase that lives in the OS keychain. SQLCipher derives the
+//! page key from the passphrase itself (PBKDF2-HMAC-SHA512). The config only
+//! records *that* the database is encrypted, so every process that opens it
+//! (the app, the CLI, the task MCP server) knows to fetch the passphrase.
+
+use crate::infra::app_config::{load_config, save_config};
+use crate::infra::db::Database;
+use anyhow::{Context, Result, bail};
+use rusqlite::Connection;
+
+/// Keychain entry holding the passphrase.
+#[cfg(feature = "encryption")]
+const KEYCHAIN_SERVICE: &str = "LaReview";
+#[cfg(feature = "encryption")]
+const KEYCHAIN_ACCOUNT: &str = "database";
+
+/// Shortest passphrase `enable_encryption` accepts.
+pub const MIN_PASSPHRASE_LEN: usize = 8;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    /// The database is encrypted but no passphrase is in the keychain; the
+    /// user has to unlock it.
+    #[error("The database is encrypted and locked")]
+    Locked,
+    #[error("Wrong passphrase for the encrypted database")]
+    WrongPassphrase,
+    #[error("This build of LaReview doesn't support database encryption")]
+    Unsupported,
+}
+
+/// Whether this build can encrypt the database.
+pub fn is_supported() -> bool {
+    cfg!(feature = "encryption")
+}
+
+/// Whether the config says the database is encrypted.
+pub fn is_enabled() -> bool {
+    load_config().encrypt_database
+}
+
+/// The passphrase to open the database with: None when it isn't encrypted.
+pub fn passphrase_for_open() -> Result<Option<String>> {
+    if !is_enabled() {
+        return Ok(None);
+    }
+    if !is_supported() {
+        return Err(EncryptionError::Unsupported.into());
+    }
+    match stored_passphrase()? {
+        Some(passphrase) => Ok(Some(passphrase)),
+        None => Err(EncryptionError::Locked.into()),
+    }
+}
+
+/// Keys `conn` and checks the passphrase by reading the schema.
+pub fn apply_key(conn: &Connection, passphrase: &str) -> Result<()> {
+    conn.pragma_update(None, "key", passphrase)?;
+    conn.query_row("SELECT count(*) FROM sqlite_master", [], |row| {
+        row.get::<_, i64>(0)
+    })
+    .map_err(|_| EncryptionError::WrongPassphrase)?;
+    Ok(())
+}
+
+/// Whether `err` means the user must enter the passphrase to continue.
+pub fn needs_unlock(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<EncryptionError>(),
+        Some(EncryptionError::Locked | EncryptionError::WrongPassphrase)
+    )
+}
+
+/// Encrypts `db` with `passphrase`, or changes the passphrase when it's
+/// already encrypted, and saves the passphrase in the keychain. `db` is replaced by
+/// the reopened, encrypted database; clones taken from it earlier stop working.
+pub fn enable_encryption(db: &mut Database, passphrase: &str) -> Result<()> {
+    if !is_supported() {
+        return Err(EncryptionError::Unsupported.into());
+    }
+    if passphrase.chars().count() < MIN_PASSPHRASE_LEN {
+        bail!(
+            "The passphrase must be at least {} characters",
+            MIN_PASSPHRASE_LEN
+        );
+    }
+    // A new passphrase is applied the same way: SQLCipher's rekey doesn't
+    // work in WAL mode, and the read-only connections would keep the old key.
+    let path = db
+        .path()
+        .context("An in-memory database can't be encrypted")?;
+    let previous = stored_passphrase()?;
+    let encrypted = path.with_extension("sqlite.encrypting");
+    let _ = std::fs::remove_file(&encrypted);
+    // Closing waits for writes in flight and cuts off every clone handed
+    // out earlier, so nothing lands in the plaintext file after the export
+    // or keeps it open through the rename.
+    let conn = db.close()?;
+    let exported = Database::export_encrypted_from(&conn, &encrypted, passphrase);
+    drop(conn);
+    if let Err(err) = exported {
+        *db = Database::open_at(path)?;
+        return Err(err).context("Failed to write the encrypted database");
+    }
+    // Saved before the plaintext file goes away, so a keychain failure
+    // can't leave an encrypted database nobody has the passphrase for.
+    if let Err(err) = store_passphrase(passphrase) {
+        *db = Database::open_at(path)?;
+        return Err(err).context("Failed to save the passphrase in the keychain");
+    }
+
+    if let Err(err) = std::fs::rename(&encrypted, &path) {
+        // The file still has the old passphrase.
+        if let Some(previous) = previous.filter(|_| is_enabled()) {
+            store_passphrase(&previous)?;
+        }
+        *db = Database::open_at(path)?;
+        return Err(err).context("Failed to replace the database with the encrypted copy");
+    }
+    for suffix in ["-wal", "-shm"] {
+        let mut journal = path.clone().into_os_string();
+        journal.push(suffix);
+        let _ = std::fs::remove_file(journal);
+    }
+
+    let mut config = load_config();
+    config.encrypt_database = true;
+    save_config(&config)?;
+    *db = Database::open_at(path)?;
+    Ok(())
+}
+
+/// Opens the locked database with `passphrase` and, when it's right, saves
+/// it in the keychain so later launches (and the CLI) open it directly.
+pub fn unlock(passphrase: &str) -> Result<Database> {
+    let conn = Connection::open(Database::default_path())?;
+    apply_key(&conn, passphrase)?;
+    drop(conn);
+    store_passphrase(passphrase).context("Failed to save the passphrase in the keychain")?;
+    Database::open()
+}
+
+#[cfg(feature = "encryption")]
+fn keychain_entry() -> Result<keyring::Entry> {
+    Ok(keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT)?)
+}
+
+/// The passphrase saved in the OS keychain, if any.
+#[cfg(feature = "encryption")]
+pub fn stored_passphrase() -> Result<Option<String>> {
+    match keychain_entry()?.get_password() {
+        Ok(passphrase) => Ok(Some(passphrase)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+pub fn stored_passphrase() -> Result<Option<String>> {
+    Ok(None)
+}
+
+/// Saves the passphrase in the OS keychain.
+#[cfg(feature = "encryption")]
+pub fn store_passphrase(passphrase: &str) -> Result<()> {
+    keychain_entry()?.set_password(passphrase)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "encryption"))]
+pub fn store_passphrase(_passphrase: &str) -> Result<()> {
+    Err(EncryptionError::Unsupported.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_unlock_only_for_passphrase_errors() {
+        assert!(needs_unlock(&EncryptionError::Locked.into()));
+        assert!(needs_unlock(&EncryptionError::WrongPassphrase.into()));
+        assert!(!needs_unlock(&EncryptionError::Unsupported.into()));
+        assert!(!needs_unlock(&anyhow::anyhow!("disk full")));
+    }
+
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn test_apply_key_rejects_wrong_passphrase() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("db.sqlite");
+        {
+            let conn = Connection::open(&path).unwrap();
+            apply_key(&conn, "correct horse").unwrap();
+            conn.execute_batch("CREATE TABLE t (x INTEGER);").unwrap();
+        }
+
+        let conn = Connection::open(&path).unwrap();
+        let err = apply_key(&conn, "battery staple").unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<EncryptionError>(),
+            Some(EncryptionError::WrongPassphrase)
+        ));
+
+        let conn = Connection::open(&path).unwrap();
+        apply_key(&conn, "correct horse").unwrap();
+    }
+}
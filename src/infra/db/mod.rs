@@ -1,9 +1,12 @@
 //! SQLite persistence (infrastructure).
 
 pub mod database;
+pub mod encryption;
+pub mod pool;
 pub mod repository;
 
 pub use database::Database;
+pub use pool::DbConn;
 pub use repository::{
     CommentRepository, FeedbackLinkRepository, FeedbackRepository, ReviewRepository,
     ReviewRuleRepository, ReviewRunRepository, TaskRepository,
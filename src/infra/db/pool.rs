@@ -0,0 +1,302 @@
+//! Connections to the database.
+//!
+//! SQLite allows one writer at a time, so writes share a single connection
+//! behind a mutex. File databases run in WAL mode and also get a few
+//! read-only connections: list and snippet queries then read a consistent
+//! snapshot instead of queueing behind a generation that's saving tasks.
+
+use crate::infra::db::encryption;
+use anyhow::Result;
+use rusqlite::{Connection, OpenFlags};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LockResult, Mutex, MutexGuard, PoisonError, TryLockError};
+use std::time::Duration;
+
+/// Read-only connections opened next to the writer.
+pub const READ_CONNECTIONS: usize = 4;
+
+/// How long a connection waits for another process's lock before failing
+/// with `SQLITE_BUSY` (the task MCP server writes to the same file).
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Shared handle on the database's connections; cheap to clone.
+#[derive(Clone)]
+pub struct DbConn {
+    writer: Arc<Mutex<Connection>>,
+    readers: Arc<[Mutex<Connection>]>,
+    next_reader: Arc<AtomicUsize>,
+}
+
+impl DbConn {
+    /// A handle with only `conn`; reads and writes share it. Used for
+    /// in-memory databases, which can't be opened twice.
+    pub fn new(conn: Connection) -> Self {
+        Self::with_readers(conn, Vec::new())
+    }
+
+    pub fn with_readers(writer: Connection, readers: Vec<Connection>) -> Self {
+        Self {
+            writer: Arc::new(Mutex::new(writer)),
+            readers: readers.into_iter().map(Mutex::new).collect(),
+            next_reader: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Opens `count` read-only connections to the file behind `writer`,
+    /// which must already be in WAL mode.
+    pub fn open_file(writer: Connection, path: &Path, count: usize) -> Result<Self> {
+        let passphrase = encryption::passphrase_for_open()?;
+        let mut readers = Vec::with_capacity(count);
+        for _ in 0..count {
+            let reader = Connection::open_with_flags(
+                path,
+                OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+            )?;
+            if let Some(passphrase) = &passphrase {
+                encryption::apply_key(&reader, passphrase)?;
+            }
+            reader.busy_timeout(BUSY_TIMEOUT)?;
+            readers.push(reader);
+        }
+        Ok(Self::with_readers(writer, readers))
+    }
+
+    /// Sets up a file connection for sharing: WAL so readers and the writer
+    /// don't block each other, and a busy timeout for other processes.
+    pub fn configure_writer(conn: &Connection) -> Result<()> {
+        conn.busy_timeout(BUSY_TIMEOUT)?;
+        conn.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get::<_, String>(0))?;
+        conn.pragma_update(None, "synchronous", "NORMAL")?;
+        Ok(())
+    }
+
+    /// The write connection. Everything that changes the database goes
+    /// through here, so writes are serialized.
+    pub fn lock(&self) -> LockResult<MutexGuard<'_, Connection>> {
+        self.writer.lock()
+    }
+
+    /// A connection for queries that only read: an idle reader if there is
+    /// one, else the next reader in turn. Falls back to the writer when the
+    /// database has no readers.
+    pub fn read(&self) -> LockResult<MutexGuard<'_, Connection>> {
+        if self.readers.is_empty() {
+            return self.writer.lock();
+        }
+        let start = self.next_reader.fetch_add(1, Ordering::Relaxed);
+        for offset in 0..self.readers.len() {
+            match self.readers[(start + offset) % self.readers.len()].try_lock() {
+                Ok(guard) => return Ok(guard),
+                Err(TryLockError::Poisoned(err)) => return Err(err),
+                Err(TryLockError::WouldBlock) => {}
+            }
+        }
+        self.readers[start % self.readers.len()].lock()
+    }
+
+    /// Number of read-only connections.
+    pub fn reader_count(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Takes the connections away from this handle and all its clones,
+    /// once the queries in flight finish, and returns the writer. Clones
+    /// are left with empty read-only stand-ins, so anything still holding
+    /// one gets an error instead of writing to a file that's being
+    /// replaced.
+    pub fn close(&self) -> Result<Connection> {
+        let mut writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        for reader in self.readers.iter() {
+            let mut reader = reader.lock().unwrap_or_else(PoisonError::into_inner);
+            drop(std::mem::replace(&mut *reader, closed_connection()?));
+        }
+        Ok(std::mem::replace(&mut *writer, closed_connection()?))
+    }
+}
+
+/// Stands in for a closed connection: no tables and no writes.
+fn closed_connection() -> Result<Connection> {
+    let conn = Connection::open_in_memory()?;
+    conn.pragma_update(None, "query_only", true)?;
+    Ok(conn)
+}
+
+impl From<Connection> for DbConn {
+    fn from(conn: Connection) -> Self {
+        Self::new(conn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Instant;
+
+    fn file_conn(dir: &Path) -> DbConn {
+        let path = dir.join("db.sqlite");
+        let writer = Connection::open(&path).unwrap();
+        DbConn::configure_writer(&writer).unwrap();
+        writer
+            .execute_batch("CREATE TABLE items (id INTEGER PRIMARY KEY, body TEXT NOT NULL);")
+            .unwrap();
+        DbConn::open_file(writer, &path, 2).unwrap()
+    }
+
+    #[test]
+    fn test_memory_reads_use_the_writer() {
+        let conn = DbConn::new(Connection::open_in_memory().unwrap());
+        conn.lock()
+            .unwrap()
+            .execute_batch("CREATE TABLE t (x INTEGER); INSERT INTO t VALUES (1);")
+            .unwrap();
+        let count: i64 = conn
+            .read()
+            .unwrap()
+            .query_row("SELECT count(*) FROM t", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(conn.reader_count(), 0);
+    }
+
+    #[test]
+    fn test_readers_are_read_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = file_conn(dir.path());
+        let result = conn
+            .read()
+            .unwrap()
+            .execute("INSERT INTO items (body) VALUES ('x')", []);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_reads_proceed_during_a_write_transaction() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = file_conn(dir.path());
+        conn.lock()
+            .unwrap()
+            .execute("INSERT INTO items (body) VALUES ('committed')", [])
+            .unwrap();
+
+        // A generation holding the writer mid-transaction.
+        let (started, wait_started) = mpsc::channel();
+        let (finish, wait_finish) = mpsc::channel::<()>();
+        let writer = conn.clone();
+        let handle = std::thread::spawn(move || {
+            let mut guard = writer.lock().unwrap();
+            let tx = guard.transaction().unwrap();
+            tx.execute("INSERT INTO items (body) VALUES ('pending')", [])
+                .unwrap();
+            started.send(()).unwrap();
+            wait_finish.recv().unwrap();
+            tx.commit().unwrap();
+        });
+        wait_started.recv().unwrap();
+
+        let begin = Instant::now();
+        let bodies: Vec<String> = {
+            let reader = conn.read().unwrap();
+            let mut stmt = reader.prepare("SELECT body FROM items").unwrap();
+            stmt.query_map([], |row| row.get(0))
+                .unwrap()
+                .collect::<rusqlite::Result<_>>()
+                .unwrap()
+        };
+        assert_eq!(bodies, ["committed"]);
+        assert!(begin.elapsed() < BUSY_TIMEOUT);
+
+        finish.send(()).unwrap();
+        handle.join().unwrap();
+        let count: i64 = conn
+            .read()
+            .unwrap()
+            .query_row("SELECT count(*) FROM items", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_close_cuts_off_every_clone() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = file_conn(dir.path());
+        let clone = conn.clone();
+        let writer = conn.close().unwrap();
+        writer
+            .execute("INSERT INTO items (body) VALUES ('last')", [])
+            .unwrap();
+        drop(writer);
+
+        let write = clone
+            .lock()
+            .unwrap()
+            .execute("INSERT INTO items (body) VALUES ('lost')", []);
+        assert!(write.is_err());
+        let read = clone
+            .read()
+            .unwrap()
+            .query_row("SELECT count(*) FROM items", [], |row| row.get::<_, i64>(0));
+        assert!(read.is_err());
+
+        // Nothing holds the file any more, so it can be replaced.
+        let path = dir.path().join("db.sqlite");
+        std::fs::rename(&path, dir.path().join("moved.sqlite")).unwrap();
+        let reopened = Connection::open(dir.path().join("moved.sqlite")).unwrap();
+        let count: i64 = reopened
+            .query_row("SELECT count(*) FROM items", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    /// Rough numbers for list queries while a writer keeps inserting; run
+    /// with `cargo test --release -- --ignored --nocapture bench_`.
+    #[test]
+    #[ignore]
+    fn bench_reads_under_concurrent_writes() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = file_conn(dir.path());
+        let done = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let writer = {
+            let conn = conn.clone();
+            let done = done.clone();
+            std::thread::spawn(move || {
+                while !done.load(Ordering::Relaxed) {
+                    let mut guard = conn.lock().unwrap();
+                    let tx = guard.transaction().unwrap();
+                    for _ in 0..50 {
+                        tx.execute("INSERT INTO items (body) VALUES (hex(randomblob(256)))", [])
+                            .unwrap();
+                    }
+                    tx.commit().unwrap();
+                }
+            })
+        };
+
+        let begin = Instant::now();
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let conn = conn.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..200 {
+                        let reader = conn.read().unwrap();
+                        let mut stmt = reader
+                            .prepare("SELECT id, substr(body, 1, 80) FROM items ORDER BY id DESC LIMIT 100")
+                            .unwrap();
+                        let rows = stmt
+                            .query_map([], |row| row.get::<_, i64>(0))
+                            .unwrap()
+                            .count();
+                        assert!(rows <= 100);
+                    }
+                })
+            })
+            .collect();
+        for reader in readers {
+            reader.join().unwrap();
+        }
+        done.store(true, Ordering::Relaxed);
+        writer.join().unwrap();
+        println!("800 list queries under load: {:?}", begin.elapsed());
+    }
+}
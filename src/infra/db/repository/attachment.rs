@@ -0,0 +1,77 @@
+use super::DbConn;
+use crate::domain::FeedbackAttachment;
+use anyhow::Result;
+use rusqlite::Row;
+
+pub struct AttachmentRepository {
+    conn: DbConn,
+}
+
+impl AttachmentRepository {
+    pub fn new(conn: DbConn) -> Self {
+        Self { conn }
+    }
+}
+
+impl AttachmentRepository {
+    pub fn save(&self, attachment: &FeedbackAttachment) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("AttachmentRepository: failed to acquire database lock");
+        conn.execute(
+            r#"
+            INSERT OR REPLACE INTO feedback_attachments (
+                id, feedback_id, mime, data, byte_size, created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+            rusqlite::params![
+                attachment.id,
+                attachment.feedback_id,
+                attachment.mime,
+                attachment.data,
+                attachment.data.len() as i64,
+                attachment.created_at
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn list_for_feedback(&self, feedback_id: &str) -> Result<Vec<FeedbackAttachment>> {
+        let conn = self
+            .conn
+            .read()
+            .expect("AttachmentRepository: failed to acquire database lock");
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, feedback_id, mime, data, byte_size, created_at
+            FROM feedback_attachments
+            WHERE feedback_id = ?1
+            ORDER BY created_at
+            "#,
+        )?;
+
+        let rows = stmt.query_map([feedback_id], Self::row_to_attachment)?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<usize> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("AttachmentRepository: failed to acquire database lock");
+        let count = conn.execute("DELETE FROM feedback_attachments WHERE id = ?1", [id])?;
+        Ok(count)
+    }
+
+    fn row_to_attachment(row: &Row) -> rusqlite::Result<FeedbackAttachment> {
+        Ok(FeedbackAttachment {
+            id: row.get(0)?,
+            feedback_id: row.get(1)?,
+            mime: row.get(2)?,
+            data: row.get(3)?,
+            byte_size: row.get::<_, i64>(4)? as usize,
+            created_at: row.get(5)?,
+        })
+    }
+}
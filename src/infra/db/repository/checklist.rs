@@ -0,0 +1,101 @@
+use super::{DbConn, Repository};
+use crate::domain::ChecklistItem;
+use anyhow::Result;
+use rusqlite::{OptionalExtension, Row};
+
+pub struct ChecklistRepository {
+    conn: DbConn,
+}
+
+impl Repository for ChecklistRepository {}
+
+impl ChecklistRepository {
+    pub fn new(conn: DbConn) -> Self {
+        Self { conn }
+    }
+}
+
+impl ChecklistRepository {
+    pub fn save(&self, item: &ChecklistItem) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ChecklistRepository: failed to acquire database lock");
+        conn.execute(
+            r#"
+            INSERT OR REPLACE INTO review_checklist_items (
+                id, review_id, text, done, created_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+            rusqlite::params![
+                item.id,
+                item.review_id,
+                item.text,
+                item.done,
+                item.created_at
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Flips an item between done and not done and returns it, or None if it
+    /// doesn't exist.
+    pub fn toggle(&self, id: &str) -> Result<Option<ChecklistItem>> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ChecklistRepository: failed to acquire database lock");
+        conn.execute(
+            "UPDATE review_checklist_items SET done = NOT done WHERE id = ?1",
+            [id],
+        )?;
+        conn.query_row(
+            r#"
+            SELECT id, review_id, text, done, created_at
+            FROM review_checklist_items
+            WHERE id = ?1
+            "#,
+            [id],
+            Self::row_to_item,
+        )
+        .optional()
+        .map_err(Into::into)
+    }
+
+    pub fn find_by_review(&self, review_id: &str) -> Result<Vec<ChecklistItem>> {
+        let conn = self
+            .conn
+            .read()
+            .expect("ChecklistRepository: failed to acquire database lock");
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, review_id, text, done, created_at
+            FROM review_checklist_items
+            WHERE review_id = ?1
+            ORDER BY created_at, rowid
+            "#,
+        )?;
+
+        let rows = stmt.query_map([review_id], Self::row_to_item)?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<usize> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ChecklistRepository: failed to acquire database lock");
+        let count = conn.execute("DELETE FROM review_checklist_items WHERE id = ?1", [id])?;
+        Ok(count)
+    }
+
+    fn row_to_item(row: &Row) -> rusqlite::Result<ChecklistItem> {
+        Ok(ChecklistItem {
+            id: row.get(0)?,
+            review_id: row.get(1)?,
+            text: row.get(2)?,
+            done: row.get(3)?,
+            created_at: row.get(4)?,
+        })
+    }
+}
@@ -42,7 +42,7 @@ impl CommentRepository {
     pub fn list_for_feedback(&self, feedback_id: &str) -> Result<Vec<Comment>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("CommentRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
@@ -28,14 +28,21 @@ impl FeedbackRepository {
         let hunk_ref = anchor
             .and_then(|a| a.hunk_ref.as_ref())
             .map(|h| serde_json::to_string(h).unwrap_or_default());
+        let suggested_replacement = feedback
+            .suggested_replacement
+            .as_ref()
+            .map(|r| serde_json::to_string(r).unwrap_or_default());
+        let extra_anchors = (!feedback.extra_anchors.is_empty())
+            .then(|| serde_json::to_string(&feedback.extra_anchors).unwrap_or_default());
 
         conn.execute(
             r#"
             INSERT OR REPLACE INTO feedback (
                 id, review_id, task_id, rule_id, finding_id, category, title, status, impact, confidence,
                 anchor_file_path, anchor_line, anchor_side, anchor_hunk_ref, anchor_head_sha,
-                author, created_at, updated_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)
+                author, created_at, updated_at, suggested_replacement, anchor_diff_hash, anchor_outdated,
+                extra_anchors, rationale, pinned
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24)
             "#,
             rusqlite::params![
                 feedback.id,
@@ -58,7 +65,13 @@ impl FeedbackRepository {
                 anchor.and_then(|a| a.head_sha.clone()),
                 feedback.author,
                 feedback.created_at,
-                feedback.updated_at
+                feedback.updated_at,
+                suggested_replacement,
+                anchor.and_then(|a| a.diff_hash.clone()),
+                anchor.is_some_and(|a| a.outdated),
+                extra_anchors,
+                feedback.rationale,
+                feedback.pinned
             ],
         )?;
         Ok(())
@@ -88,6 +101,29 @@ impl FeedbackRepository {
         Ok(updated)
     }
 
+    /// Replaces the anchor's position fields, leaving comments and status alone.
+    pub fn update_anchor(&self, id: &str, anchor: &FeedbackAnchor) -> Result<usize> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("FeedbackRepository: failed to acquire database lock");
+        let hunk_ref = anchor
+            .hunk_ref
+            .as_ref()
+            .map(|h| serde_json::to_string(h).unwrap_or_default());
+        let updated = conn.execute(
+            "UPDATE feedback SET anchor_line = ?2, anchor_hunk_ref = ?3, anchor_diff_hash = ?4, anchor_outdated = ?5 WHERE id = ?1",
+            rusqlite::params![
+                id,
+                anchor.line_number.map(|n| n as i32),
+                hunk_ref,
+                anchor.diff_hash,
+                anchor.outdated
+            ],
+        )?;
+        Ok(updated)
+    }
+
     pub fn update_title(&self, id: &str, title: &str) -> Result<usize> {
         let conn = self
             .conn
@@ -100,6 +136,32 @@ impl FeedbackRepository {
         Ok(updated)
     }
 
+    /// Stores why the feedback was flagged, leaving `updated_at` alone.
+    pub fn update_rationale(&self, id: &str, rationale: &str) -> Result<usize> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("FeedbackRepository: failed to acquire database lock");
+        let updated = conn.execute(
+            "UPDATE feedback SET rationale = ?2 WHERE id = ?1",
+            rusqlite::params![id, rationale],
+        )?;
+        Ok(updated)
+    }
+
+    /// Pins or unpins the feedback, leaving `updated_at` alone.
+    pub fn set_pinned(&self, id: &str, pinned: bool) -> Result<usize> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("FeedbackRepository: failed to acquire database lock");
+        let updated = conn.execute(
+            "UPDATE feedback SET pinned = ?2 WHERE id = ?1",
+            rusqlite::params![id, pinned],
+        )?;
+        Ok(updated)
+    }
+
     pub fn touch(&self, id: &str) -> Result<usize> {
         let conn = self
             .conn
@@ -115,13 +177,14 @@ impl FeedbackRepository {
     pub fn find_by_id(&self, id: &str) -> Result<Option<Feedback>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("FeedbackRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
             SELECT id, review_id, task_id, rule_id, finding_id, category, title, status, impact, confidence,
                    anchor_file_path, anchor_line, anchor_side, anchor_hunk_ref, anchor_head_sha,
-                   author, created_at, updated_at
+                   author, created_at, updated_at, suggested_replacement, anchor_diff_hash,
+                   anchor_outdated, extra_anchors, rationale, pinned
             FROM feedback
             WHERE id = ?1
             "#,
@@ -138,13 +201,14 @@ impl FeedbackRepository {
     pub fn find_by_review(&self, review_id: &str) -> Result<Vec<Feedback>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("FeedbackRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
             SELECT id, review_id, task_id, rule_id, finding_id, category, title, status, impact, confidence,
                    anchor_file_path, anchor_line, anchor_side, anchor_hunk_ref, anchor_head_sha,
-                   author, created_at, updated_at
+                   author, created_at, updated_at, suggested_replacement, anchor_diff_hash,
+                   anchor_outdated, extra_anchors, rationale, pinned
             FROM feedback
             WHERE review_id = ?1
             ORDER BY anchor_file_path, anchor_line, updated_at DESC
@@ -186,6 +250,10 @@ impl FeedbackRepository {
         let anchor_side: Option<String> = row.get(12)?;
         let anchor_hunk_ref: Option<String> = row.get(13)?;
         let anchor_head_sha: Option<String> = row.get(14)?;
+        let suggested_replacement: Option<String> = row.get(18)?;
+        let anchor_diff_hash: Option<String> = row.get(19)?;
+        let anchor_outdated: bool = row.get::<_, Option<bool>>(20)?.unwrap_or(false);
+        let extra_anchors: Option<String> = row.get(21)?;
 
         let anchor = if anchor_file_path.is_some()
             || anchor_line.is_some()
@@ -211,6 +279,8 @@ impl FeedbackRepository {
                 }),
                 hunk_ref,
                 head_sha: anchor_head_sha,
+                diff_hash: anchor_diff_hash,
+                outdated: anchor_outdated,
             })
         } else {
             None
@@ -228,6 +298,15 @@ impl FeedbackRepository {
             impact: FeedbackImpact::from_str(&impact).unwrap_or_default(),
             confidence,
             anchor,
+            suggested_replacement: suggested_replacement
+                .as_deref()
+                .and_then(|json| serde_json::from_str(json).ok()),
+            extra_anchors: extra_anchors
+                .as_deref()
+                .and_then(|json| serde_json::from_str(json).ok())
+                .unwrap_or_default(),
+            rationale: row.get(22)?,
+            pinned: row.get::<_, Option<bool>>(23)?.unwrap_or(false),
             author: row.get(15)?,
             created_at: row.get(16)?,
             updated_at: row.get(17)?,
@@ -40,7 +40,7 @@ impl FeedbackLinkRepository {
     pub fn find_by_feedback(&self, feedback_id: &str) -> Result<Option<FeedbackLink>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("FeedbackLinkRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
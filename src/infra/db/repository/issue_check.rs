@@ -70,7 +70,7 @@ impl IssueCheckRepository {
 
     /// Find all issue checks for a review run
     pub fn find_by_run(&self, run_id: &str) -> Result<Vec<IssueCheck>> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
             SELECT id, run_id, rule_id, category, display_name, status, confidence, summary, created_at
@@ -89,7 +89,7 @@ impl IssueCheckRepository {
 
     /// Find all findings for an issue check
     pub fn find_findings_by_check(&self, check_id: &str) -> Result<Vec<IssueFinding>> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
             SELECT id, check_id, title, description, evidence, file_path, line_number, impact, created_at
@@ -108,7 +108,7 @@ impl IssueCheckRepository {
 
     /// Find an issue check by ID
     pub fn find_by_id(&self, id: &str) -> Result<Option<IssueCheck>> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
             SELECT id, run_id, rule_id, category, display_name, status, confidence, summary, created_at
@@ -0,0 +1,106 @@
+use crate::domain::IssueCheckDefinition;
+use anyhow::{Context, Result};
+use rusqlite::{Row, params};
+
+use super::{DbConn, Repository};
+
+pub struct IssueCheckDefinitionRepository {
+    conn: DbConn,
+}
+
+impl Repository for IssueCheckDefinitionRepository {}
+
+impl IssueCheckDefinitionRepository {
+    pub fn new(conn: DbConn) -> Self {
+        Self { conn }
+    }
+
+    pub fn save(&self, definition: &IssueCheckDefinition) -> Result<()> {
+        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        conn.execute(
+            r#"
+            INSERT OR REPLACE INTO issue_check_definitions
+                (id, name, category, prompt, enabled, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+            params![
+                definition.id,
+                definition.name,
+                definition.category,
+                definition.prompt,
+                if definition.enabled { 1 } else { 0 },
+                definition.created_at,
+                definition.updated_at
+            ],
+        )
+        .context("save issue check definition")?;
+        Ok(())
+    }
+
+    pub fn list_all(&self) -> Result<Vec<IssueCheckDefinition>> {
+        self.query(
+            r#"
+            SELECT id, name, category, prompt, enabled, created_at, updated_at
+            FROM issue_check_definitions
+            ORDER BY created_at ASC
+            "#,
+        )
+    }
+
+    pub fn list_enabled(&self) -> Result<Vec<IssueCheckDefinition>> {
+        self.query(
+            r#"
+            SELECT id, name, category, prompt, enabled, created_at, updated_at
+            FROM issue_check_definitions
+            WHERE enabled = 1
+            ORDER BY created_at ASC
+            "#,
+        )
+    }
+
+    pub fn find_by_id(&self, id: &str) -> Result<Option<IssueCheckDefinition>> {
+        let conn = self.conn.read().expect("Failed to acquire database lock");
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, name, category, prompt, enabled, created_at, updated_at
+            FROM issue_check_definitions
+            WHERE id = ?1
+            "#,
+        )?;
+        let mut rows = stmt.query_map([id], Self::row_to_definition)?;
+        if let Some(row) = rows.next() {
+            return Ok(Some(row?));
+        }
+        Ok(None)
+    }
+
+    pub fn delete(&self, id: &str) -> Result<usize> {
+        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let affected = conn.execute("DELETE FROM issue_check_definitions WHERE id = ?1", [id])?;
+        Ok(affected)
+    }
+
+    fn query(&self, sql: &str) -> Result<Vec<IssueCheckDefinition>> {
+        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let mut stmt = conn.prepare(sql)?;
+        let rows = stmt.query_map([], Self::row_to_definition)?;
+        let mut definitions = Vec::new();
+        for row in rows {
+            definitions.push(row?);
+        }
+        Ok(definitions)
+    }
+
+    fn row_to_definition(row: &Row<'_>) -> rusqlite::Result<IssueCheckDefinition> {
+        let enabled: i64 = row.get(4)?;
+        Ok(IssueCheckDefinition {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            category: row.get(2)?,
+            prompt: row.get(3)?,
+            enabled: enabled != 0,
+            created_at: row.get(5)?,
+            updated_at: row.get(6)?,
+        })
+    }
+}
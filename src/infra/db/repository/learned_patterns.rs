@@ -19,7 +19,7 @@ impl LearnedPatternRepository {
     pub fn list_enabled(&self) -> Result<Vec<LearnedPattern>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("LearnedPatternRepository: failed to acquire database lock");
 
         let mut stmt = conn.prepare(
@@ -53,7 +53,7 @@ impl LearnedPatternRepository {
     pub fn list_all(&self) -> Result<Vec<LearnedPattern>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("LearnedPatternRepository: failed to acquire database lock");
 
         let mut stmt = conn.prepare(
@@ -86,7 +86,7 @@ impl LearnedPatternRepository {
     pub fn find_by_id(&self, id: &str) -> Result<Option<LearnedPattern>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("LearnedPatternRepository: failed to acquire database lock");
 
         let mut stmt = conn.prepare(
@@ -259,7 +259,7 @@ impl LearnedPatternRepository {
     pub fn count(&self) -> Result<i64> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("LearnedPatternRepository: failed to acquire database lock");
 
         let count: i64 = conn.query_row("SELECT COUNT(*) FROM learned_patterns", [], |row| {
@@ -273,7 +273,7 @@ impl LearnedPatternRepository {
     pub fn count_enabled(&self) -> Result<i64> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("LearnedPatternRepository: failed to acquire database lock");
 
         let count: i64 = conn.query_row(
@@ -300,7 +300,7 @@ impl LearningStateRepository {
     pub fn get(&self, key: &str) -> Result<Option<String>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("LearningStateRepository: failed to acquire database lock");
 
         let result = conn
@@ -339,7 +339,7 @@ impl LearningStateRepository {
     pub fn get_status(&self, pattern_repo: &LearnedPatternRepository) -> Result<LearningStatus> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("LearningStateRepository: failed to acquire database lock");
 
         // Get pending rejections count
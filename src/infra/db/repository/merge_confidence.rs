@@ -72,7 +72,7 @@ impl MergeConfidenceRepository {
 
     /// Find merge confidence by run ID
     pub fn find_by_run_id(&self, run_id: &str) -> Result<Option<MergeConfidence>> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
             SELECT score, reasons, computed_at
@@ -116,7 +116,6 @@ impl MergeConfidenceRepository {
 mod tests {
     use super::*;
     use rusqlite::Connection;
-    use std::sync::{Arc, Mutex};
 
     fn setup_test_db() -> DbConn {
         let conn = Connection::open_in_memory().unwrap();
@@ -147,7 +146,7 @@ mod tests {
             "#,
         )
         .unwrap();
-        Arc::new(Mutex::new(conn))
+        DbConn::new(conn)
     }
 
     #[test]
@@ -1,13 +1,17 @@
 //! Repository implementations for data access in LaReview.
 //!
-//! Provides database operations for reviews, runs, tasks, feedback, and comments.
+//! Provides database operations for reviews, runs, tasks, feedback, comments, and attachments.
 
+mod attachment;
+mod checklist;
 mod comment;
 mod feedback;
 mod feedback_link;
 mod issue_check;
+mod issue_check_definition;
 mod learned_patterns;
 mod merge_confidence;
+mod pending_diff;
 mod rejections;
 mod repo;
 mod review;
@@ -15,25 +19,28 @@ mod review_run;
 mod rule;
 mod task;
 
+pub use attachment::AttachmentRepository;
+pub use checklist::ChecklistRepository;
 pub use comment::CommentRepository;
 pub use feedback::FeedbackRepository;
 pub use feedback_link::FeedbackLinkRepository;
 pub use issue_check::IssueCheckRepository;
+pub use issue_check_definition::IssueCheckDefinitionRepository;
 pub use learned_patterns::{LearnedPatternRepository, LearningStateRepository};
 pub use merge_confidence::MergeConfidenceRepository;
+pub use pending_diff::{MAX_PENDING_DIFFS, PendingDiffRecord, PendingDiffRepository};
 pub use rejections::{
     AgentRejectionStats, FeedbackRejection, FeedbackRejectionRepository, RuleRejectionStats,
 };
 pub use repo::RepoRepository;
-pub use review::ReviewRepository;
+pub(crate) use repo::decode_categories;
+pub(crate) use review::load_all_tags;
+pub use review::{ReviewMergeOutcome, ReviewRepository};
 pub use review_run::ReviewRunRepository;
 pub use rule::ReviewRuleRepository;
 pub use task::TaskRepository;
 
-use rusqlite::Connection;
-use std::sync::{Arc, Mutex};
-
-pub(super) type DbConn = Arc<Mutex<Connection>>;
+pub(super) use super::pool::DbConn;
 
 /// Marker trait for repository types.
 ///
@@ -0,0 +1,132 @@
+//! Repository for recently received pending diffs.
+//!
+//! Every diff handed to the app (piped in, or acquired for a CLI request) is
+//! kept here so one pasted earlier can be reopened without fetching it again.
+//! Only the newest [`MAX_PENDING_DIFFS`] are kept; receiving the same diff
+//! again moves it to the top instead of adding a copy.
+
+use crate::domain::ReviewSource;
+use crate::infra::hash::hash_diff;
+use anyhow::{Context, Result};
+use rusqlite::{Row, params};
+
+use super::{DbConn, Repository};
+
+pub const MAX_PENDING_DIFFS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingDiffRecord {
+    pub id: String,
+    pub diff: String,
+    pub repo_root: Option<String>,
+    pub agent: Option<String>,
+    /// How the diff was received, e.g. "stdin" or "uncommitted changes".
+    pub source: String,
+    pub review_source: Option<ReviewSource>,
+    pub created_at: String,
+}
+
+pub struct PendingDiffRepository {
+    conn: DbConn,
+}
+
+impl Repository for PendingDiffRepository {}
+
+impl PendingDiffRepository {
+    pub fn new(conn: DbConn) -> Self {
+        Self { conn }
+    }
+
+    /// Saves `record`, or refreshes the entry for the same diff text, and
+    /// drops entries beyond the newest [`MAX_PENDING_DIFFS`]. Returns the id
+    /// the diff is stored under.
+    pub fn record(&self, record: &PendingDiffRecord) -> Result<String> {
+        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let review_source = record
+            .review_source
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .context("serialize review source")?;
+
+        let id: String = conn
+            .query_row(
+                r#"
+                INSERT INTO pending_diffs
+                    (id, diff, diff_hash, repo_root, agent, source, review_source, created_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                ON CONFLICT(diff_hash) DO UPDATE SET
+                    repo_root = excluded.repo_root,
+                    agent = excluded.agent,
+                    source = excluded.source,
+                    review_source = COALESCE(excluded.review_source, review_source),
+                    created_at = excluded.created_at
+                RETURNING id
+                "#,
+                params![
+                    record.id,
+                    record.diff,
+                    hash_diff(&record.diff),
+                    record.repo_root,
+                    record.agent,
+                    record.source,
+                    review_source,
+                    record.created_at,
+                ],
+                |row| row.get(0),
+            )
+            .context("save pending diff")?;
+
+        conn.execute(
+            r#"
+            DELETE FROM pending_diffs WHERE id NOT IN (
+                SELECT id FROM pending_diffs ORDER BY created_at DESC LIMIT ?1
+            )
+            "#,
+            [MAX_PENDING_DIFFS as i64],
+        )
+        .context("prune pending diffs")?;
+
+        Ok(id)
+    }
+
+    /// Newest first.
+    pub fn list_recent(&self) -> Result<Vec<PendingDiffRecord>> {
+        let conn = self.conn.read().expect("Failed to acquire database lock");
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, diff, repo_root, agent, source, review_source, created_at
+            FROM pending_diffs
+            ORDER BY created_at DESC
+            "#,
+        )?;
+        let rows = stmt.query_map([], Self::map_row)?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    pub fn find_by_id(&self, id: &str) -> Result<Option<PendingDiffRecord>> {
+        let conn = self.conn.read().expect("Failed to acquire database lock");
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, diff, repo_root, agent, source, review_source, created_at
+            FROM pending_diffs
+            WHERE id = ?1
+            "#,
+        )?;
+        let mut rows = stmt.query_map([id], Self::map_row)?;
+        Ok(rows.next().transpose()?)
+    }
+
+    fn map_row(row: &Row<'_>) -> rusqlite::Result<PendingDiffRecord> {
+        let review_source: Option<String> = row.get(5)?;
+        Ok(PendingDiffRecord {
+            id: row.get(0)?,
+            diff: row.get(1)?,
+            repo_root: row.get(2)?,
+            agent: row.get(3)?,
+            source: row.get(4)?,
+            review_source: review_source.and_then(|json| serde_json::from_str(&json).ok()),
+            created_at: row.get(6)?,
+        })
+    }
+}
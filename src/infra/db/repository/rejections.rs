@@ -108,7 +108,7 @@ impl FeedbackRejectionRepository {
     pub fn get_rule_stats(&self) -> Result<Vec<RuleRejectionStats>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("FeedbackRejectionRepository: failed to acquire database lock");
 
         let mut stmt = conn.prepare(
@@ -151,7 +151,7 @@ impl FeedbackRejectionRepository {
     pub fn get_agent_stats(&self) -> Result<Vec<AgentRejectionStats>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("FeedbackRejectionRepository: failed to acquire database lock");
 
         // Extract agent_id from author field (format: "agent:agent_id")
@@ -194,7 +194,7 @@ impl FeedbackRejectionRepository {
     pub fn list_all(&self, limit: usize) -> Result<Vec<FeedbackRejection>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("FeedbackRejectionRepository: failed to acquire database lock");
 
         let mut stmt = conn.prepare(
@@ -231,7 +231,7 @@ impl FeedbackRejectionRepository {
     pub fn get_unprocessed_count(&self) -> Result<i64> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("FeedbackRejectionRepository: failed to acquire database lock");
 
         let count: i64 = conn.query_row(
@@ -253,7 +253,7 @@ impl FeedbackRejectionRepository {
     pub fn get_unprocessed_rejections(&self, limit: usize) -> Result<Vec<FeedbackRejection>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("FeedbackRejectionRepository: failed to acquire database lock");
 
         let mut stmt = conn.prepare(
@@ -2,6 +2,45 @@ use super::DbConn;
 use crate::domain::LinkedRepo;
 use anyhow::Result;
 
+const REPO_COLUMNS: &str =
+    "id, name, path, created_at, allow_snapshot_access, default_agent_id, default_rule_categories";
+
+type RepoRow = (
+    String,
+    String,
+    String,
+    String,
+    bool,
+    Option<String>,
+    Option<String>,
+);
+
+fn read_repo_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<RepoRow> {
+    Ok((
+        row.get::<_, String>(0)?,
+        row.get::<_, String>(1)?,
+        row.get::<_, String>(2)?,
+        row.get::<_, String>(3)?,
+        row.get::<_, Option<bool>>(4)?.unwrap_or(false),
+        row.get::<_, Option<String>>(5)?,
+        row.get::<_, Option<String>>(6)?,
+    ))
+}
+
+/// Rule categories are stored as a JSON array; NULL or garbage means none.
+pub(crate) fn decode_categories(raw: Option<String>) -> Vec<String> {
+    raw.and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn encode_categories(categories: &[String]) -> Option<String> {
+    if categories.is_empty() {
+        None
+    } else {
+        serde_json::to_string(categories).ok()
+    }
+}
+
 pub struct RepoRepository {
     conn: DbConn,
 }
@@ -19,14 +58,16 @@ impl RepoRepository {
             .lock()
             .expect("RepoRepository: failed to acquire database lock");
         conn.execute(
-            "INSERT OR REPLACE INTO repos (id, name, path, created_at, allow_snapshot_access) VALUES (?1, ?2, ?3, ?4, ?5)",
-            (
+            "INSERT OR REPLACE INTO repos (id, name, path, created_at, allow_snapshot_access, default_agent_id, default_rule_categories) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            rusqlite::params![
                 &repo.id,
                 &repo.name,
                 repo.path.to_string_lossy().as_ref(),
                 &repo.created_at,
                 &repo.allow_snapshot_access,
-            ),
+                &repo.default_agent_id,
+                encode_categories(&repo.default_rule_categories),
+            ],
         )?;
 
         // Delete existing remotes for this repo
@@ -46,23 +87,22 @@ impl RepoRepository {
     pub fn find_all(&self) -> Result<Vec<LinkedRepo>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("RepoRepository: failed to acquire database lock");
-        let mut stmt =
-            conn.prepare("SELECT id, name, path, created_at, allow_snapshot_access FROM repos")?;
-        let rows = stmt.query_map([], |row| {
-            Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, String>(1)?,
-                row.get::<_, String>(2)?,
-                row.get::<_, String>(3)?,
-                row.get::<_, Option<bool>>(4)?.unwrap_or(false),
-            ))
-        })?;
+        let mut stmt = conn.prepare(&format!("SELECT {REPO_COLUMNS} FROM repos"))?;
+        let rows = stmt.query_map([], read_repo_row)?;
 
         let mut repos = Vec::new();
         for row in rows {
-            let (id, name, path_str, created_at, allow_snapshot_access) = row?;
+            let (
+                id,
+                name,
+                path_str,
+                created_at,
+                allow_snapshot_access,
+                default_agent_id,
+                default_rule_categories,
+            ) = row?;
 
             // Fetch remotes for this repo
             let mut remote_stmt =
@@ -80,6 +120,8 @@ impl RepoRepository {
                 remotes,
                 created_at,
                 allow_snapshot_access,
+                default_agent_id,
+                default_rule_categories: decode_categories(default_rule_categories),
             });
         }
         Ok(repos)
@@ -106,11 +148,29 @@ impl RepoRepository {
         Ok(())
     }
 
-    pub fn find_by_remote_url(&self, url_fragment: &str) -> Result<Option<LinkedRepo>> {
+    /// Stores the agent and rule categories pre-selected for `repo_id`.
+    pub fn update_defaults(
+        &self,
+        repo_id: &str,
+        agent_id: Option<&str>,
+        rule_categories: &[String],
+    ) -> Result<()> {
         let conn = self
             .conn
             .lock()
             .expect("RepoRepository: failed to acquire database lock");
+        conn.execute(
+            "UPDATE repos SET default_agent_id = ?1, default_rule_categories = ?2 WHERE id = ?3",
+            rusqlite::params![agent_id, encode_categories(rule_categories), repo_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn find_by_remote_url(&self, url_fragment: &str) -> Result<Option<LinkedRepo>> {
+        let conn = self
+            .conn
+            .read()
+            .expect("RepoRepository: failed to acquire database lock");
         let mut stmt =
             conn.prepare("SELECT repo_id FROM repo_remotes WHERE url LIKE ?1 LIMIT 1")?;
 
@@ -118,21 +178,20 @@ impl RepoRepository {
         if let Some(row) = rows.next()? {
             let repo_id: String = row.get(0)?;
             // Reuse find_all-like logic but for a single ID
-            let mut repo_stmt = conn.prepare(
-                "SELECT id, name, path, created_at, allow_snapshot_access FROM repos WHERE id = ?1",
-            )?;
-            let mut repo_rows = repo_stmt.query_map([&repo_id], |row| {
-                Ok((
-                    row.get::<_, String>(0)?,
-                    row.get::<_, String>(1)?,
-                    row.get::<_, String>(2)?,
-                    row.get::<_, String>(3)?,
-                    row.get::<_, Option<bool>>(4)?.unwrap_or(false),
-                ))
-            })?;
+            let mut repo_stmt =
+                conn.prepare(&format!("SELECT {REPO_COLUMNS} FROM repos WHERE id = ?1"))?;
+            let mut repo_rows = repo_stmt.query_map([&repo_id], read_repo_row)?;
 
             if let Some(repo_row) = repo_rows.next() {
-                let (id, name, path_str, created_at, allow_snapshot_access) = repo_row?;
+                let (
+                    id,
+                    name,
+                    path_str,
+                    created_at,
+                    allow_snapshot_access,
+                    default_agent_id,
+                    default_rule_categories,
+                ) = repo_row?;
                 let mut remote_stmt =
                     conn.prepare("SELECT url FROM repo_remotes WHERE repo_id = ?1")?;
                 let remote_rows = remote_stmt.query_map([&id], |r| r.get::<_, String>(0))?;
@@ -148,6 +207,8 @@ impl RepoRepository {
                     remotes,
                     created_at,
                     allow_snapshot_access,
+                    default_agent_id,
+                    default_rule_categories: decode_categories(default_rule_categories),
                 }));
             }
         }
@@ -1,6 +1,41 @@
 use super::DbConn;
-use crate::domain::{Review, ReviewId, ReviewRunId, ReviewSource};
+use crate::domain::{
+    Review, ReviewDecision, ReviewId, ReviewRunId, ReviewSource, normalize_review_tag,
+};
 use anyhow::Result;
+use rusqlite::Connection;
+use std::collections::HashMap;
+
+/// Tags of `review_id`, alphabetically.
+fn load_tags(conn: &Connection, review_id: &str) -> rusqlite::Result<Vec<String>> {
+    let mut stmt = conn.prepare("SELECT tag FROM review_tags WHERE review_id = ?1 ORDER BY tag")?;
+    let rows = stmt.query_map([review_id], |row| row.get::<_, String>(0))?;
+    rows.collect()
+}
+
+/// Tags of every review, keyed by review id.
+pub(crate) fn load_all_tags(conn: &Connection) -> rusqlite::Result<HashMap<String, Vec<String>>> {
+    let mut stmt = conn.prepare("SELECT review_id, tag FROM review_tags ORDER BY tag")?;
+    let rows = stmt.query_map([], |row| {
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+    })?;
+    let mut tags: HashMap<String, Vec<String>> = HashMap::new();
+    for row in rows {
+        let (review_id, tag) = row?;
+        tags.entry(review_id).or_default().push(tag);
+    }
+    Ok(tags)
+}
+
+/// Row counts from [`ReviewRepository::merge_into`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+pub struct ReviewMergeOutcome {
+    pub runs_moved: usize,
+    pub feedback_moved: usize,
+    /// Secondary feedback dropped because the primary already had feedback
+    /// on the same line.
+    pub feedback_deduplicated: usize,
+}
 
 /// Repository for review operations.
 pub struct ReviewRepository {
@@ -20,8 +55,8 @@ impl ReviewRepository {
         let source_json = serde_json::to_string(&review.source)?;
         conn.execute(
             r#"
-            INSERT INTO reviews (id, title, summary, source_json, active_run_id, status, created_at, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            INSERT INTO reviews (id, title, summary, source_json, active_run_id, status, created_at, updated_at, decision)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             ON CONFLICT(id) DO NOTHING
             "#,
             (
@@ -33,18 +68,29 @@ impl ReviewRepository {
                 &review.status.to_string(),
                 &review.created_at,
                 &review.updated_at,
+                review.decision.map(|decision| decision.to_string()),
             ),
         )?;
+        for tag in review
+            .tags
+            .iter()
+            .filter_map(|tag| normalize_review_tag(tag))
+        {
+            conn.execute(
+                "INSERT OR IGNORE INTO review_tags (review_id, tag) VALUES (?1, ?2)",
+                (&review.id, &tag),
+            )?;
+        }
         Ok(())
     }
 
     pub fn list_all(&self) -> Result<Vec<Review>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("ReviewRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
-            "SELECT id, title, summary, source_json, active_run_id, status, created_at, updated_at FROM reviews ORDER BY updated_at DESC",
+            "SELECT id, title, summary, source_json, active_run_id, status, created_at, updated_at, decision FROM reviews ORDER BY updated_at DESC",
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -65,18 +111,27 @@ impl ReviewRepository {
                 status,
                 created_at: row.get(6)?,
                 updated_at: row.get(7)?,
+                tags: vec![],
+                decision: row
+                    .get::<_, Option<String>>(8)?
+                    .and_then(|decision| decision.parse().ok()),
             })
         })?;
 
-        rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
+        let mut reviews = rows.collect::<Result<Vec<_>, _>>()?;
+        let mut tags = load_all_tags(&conn)?;
+        for review in &mut reviews {
+            review.tags = tags.remove(&review.id).unwrap_or_default();
+        }
+        Ok(reviews)
     }
 
     pub fn find_by_id(&self, id: &ReviewId) -> Result<Option<Review>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("ReviewRepository: failed to acquire database lock");
-        let mut stmt = conn.prepare("SELECT id, title, summary, source_json, active_run_id, status, created_at, updated_at FROM reviews WHERE id = ?1")?;
+        let mut stmt = conn.prepare("SELECT id, title, summary, source_json, active_run_id, status, created_at, updated_at, decision FROM reviews WHERE id = ?1")?;
         let mut rows = stmt.query_map([id], |row| {
             let source_json: String = row.get(3)?;
             let source: ReviewSource =
@@ -95,15 +150,52 @@ impl ReviewRepository {
                 status,
                 created_at: row.get(6)?,
                 updated_at: row.get(7)?,
+                tags: vec![],
+                decision: row
+                    .get::<_, Option<String>>(8)?
+                    .and_then(|decision| decision.parse().ok()),
             })
         })?;
 
         match rows.next() {
-            Some(row) => row.map(Some).map_err(Into::into),
+            Some(row) => {
+                let mut review = row?;
+                review.tags = load_tags(&conn, &review.id)?;
+                Ok(Some(review))
+            }
             None => Ok(None),
         }
     }
 
+    /// Adds a tag to a review and returns the review's tags. The tag is
+    /// normalized first; adding an existing tag is a no-op.
+    pub fn add_tag(&self, review_id: &ReviewId, tag: &str) -> Result<Vec<String>> {
+        let tag = normalize_review_tag(tag).ok_or_else(|| anyhow::anyhow!("Tag is empty"))?;
+        let conn = self
+            .conn
+            .lock()
+            .expect("ReviewRepository: failed to acquire database lock");
+        conn.execute(
+            "INSERT OR IGNORE INTO review_tags (review_id, tag) VALUES (?1, ?2)",
+            (review_id, &tag),
+        )?;
+        Ok(load_tags(&conn, review_id)?)
+    }
+
+    /// Removes a tag from a review and returns the review's remaining tags.
+    pub fn remove_tag(&self, review_id: &ReviewId, tag: &str) -> Result<Vec<String>> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ReviewRepository: failed to acquire database lock");
+        let tag = normalize_review_tag(tag).unwrap_or_default();
+        conn.execute(
+            "DELETE FROM review_tags WHERE review_id = ?1 AND tag = ?2",
+            (review_id, &tag),
+        )?;
+        Ok(load_tags(&conn, review_id)?)
+    }
+
     pub fn set_active_run(&self, review_id: &ReviewId, run_id: &ReviewRunId) -> Result<()> {
         let conn = self
             .conn
@@ -116,6 +208,23 @@ impl ReviewRepository {
         Ok(())
     }
 
+    /// Sets or clears the overall decision submitted with the review.
+    pub fn set_decision(
+        &self,
+        review_id: &ReviewId,
+        decision: Option<ReviewDecision>,
+    ) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ReviewRepository: failed to acquire database lock");
+        conn.execute(
+            "UPDATE reviews SET decision = ?1, updated_at = CURRENT_TIMESTAMP WHERE id = ?2",
+            (decision.map(|decision| decision.to_string()), review_id),
+        )?;
+        Ok(())
+    }
+
     pub fn update_title_and_summary(
         &self,
         review_id: &ReviewId,
@@ -141,4 +250,82 @@ impl ReviewRepository {
         let affected = conn.execute("DELETE FROM reviews WHERE id = ?1", [id])?;
         Ok(affected)
     }
+
+    /// Moves `secondary`'s runs (and with them their tasks), feedback and tags
+    /// under `primary`, then deletes `secondary`. Secondary feedback anchored
+    /// to the same file, line and side as primary feedback is dropped. The
+    /// primary keeps its title and active run.
+    pub fn merge_into(
+        &self,
+        primary: &ReviewId,
+        secondary: &ReviewId,
+    ) -> Result<ReviewMergeOutcome> {
+        if primary == secondary {
+            anyhow::bail!("Cannot merge a review into itself");
+        }
+        let mut conn = self
+            .conn
+            .lock()
+            .expect("ReviewRepository: failed to acquire database lock");
+        let tx = conn.transaction()?;
+        for id in [primary, secondary] {
+            let exists: bool = tx.query_row(
+                "SELECT EXISTS(SELECT 1 FROM reviews WHERE id = ?1)",
+                [id],
+                |row| row.get(0),
+            )?;
+            if !exists {
+                anyhow::bail!("Review not found: {}", id);
+            }
+        }
+
+        let feedback_deduplicated = tx.execute(
+            r#"
+            DELETE FROM feedback
+            WHERE review_id = ?2
+              AND anchor_file_path IS NOT NULL
+              AND anchor_line IS NOT NULL
+              AND EXISTS (
+                  SELECT 1 FROM feedback AS kept
+                  WHERE kept.review_id = ?1
+                    AND kept.anchor_file_path = feedback.anchor_file_path
+                    AND kept.anchor_line = feedback.anchor_line
+                    AND kept.anchor_side IS feedback.anchor_side
+              )
+            "#,
+            (primary, secondary),
+        )?;
+        let feedback_moved = tx.execute(
+            "UPDATE feedback SET review_id = ?1 WHERE review_id = ?2",
+            (primary, secondary),
+        )?;
+        tx.execute(
+            "UPDATE feedback_rejections SET review_id = ?1 WHERE review_id = ?2",
+            (primary, secondary),
+        )?;
+        let runs_moved = tx.execute(
+            "UPDATE review_runs SET review_id = ?1 WHERE review_id = ?2",
+            (primary, secondary),
+        )?;
+        tx.execute(
+            "INSERT OR IGNORE INTO review_tags (review_id, tag) SELECT ?1, tag FROM review_tags WHERE review_id = ?2",
+            (primary, secondary),
+        )?;
+        tx.execute(
+            "UPDATE review_checklist_items SET review_id = ?1 WHERE review_id = ?2",
+            (primary, secondary),
+        )?;
+        tx.execute("DELETE FROM reviews WHERE id = ?1", [secondary])?;
+        tx.execute(
+            "UPDATE reviews SET updated_at = CURRENT_TIMESTAMP WHERE id = ?1",
+            [primary],
+        )?;
+        tx.commit()?;
+
+        Ok(ReviewMergeOutcome {
+            runs_moved,
+            feedback_moved,
+            feedback_deduplicated,
+        })
+    }
 }
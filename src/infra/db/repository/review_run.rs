@@ -1,7 +1,12 @@
 use super::DbConn;
-use crate::domain::{ReviewId, ReviewRun, ReviewRunId, ReviewRunStatus};
+use crate::domain::{
+    FileViewState, ReviewId, ReviewRun, ReviewRunId, ReviewRunStatus, RunPlan, RunToolLog,
+    ToolTiming,
+};
 use anyhow::Result;
+use rusqlite::OptionalExtension;
 use std::str::FromStr;
+use std::time::Duration;
 
 /// Repository for review run operations.
 pub struct ReviewRunRepository {
@@ -22,8 +27,8 @@ impl ReviewRunRepository {
             .expect("ReviewRunRepository: failed to acquire database lock");
         conn.execute(
             r#"
-            INSERT INTO review_runs (id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            INSERT INTO review_runs (id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at, agent_model)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
             ON CONFLICT(id) DO NOTHING
             "#,
             (
@@ -35,6 +40,7 @@ impl ReviewRunRepository {
                 &run.diff_hash,
                 &run.status.to_string(),
                 &run.created_at,
+                &run.agent_model,
             ),
         )?;
         Ok(())
@@ -43,10 +49,10 @@ impl ReviewRunRepository {
     pub fn find_by_id(&self, id: &ReviewRunId) -> Result<Option<ReviewRun>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("ReviewRunRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
-            "SELECT id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at FROM review_runs WHERE id = ?1",
+            "SELECT id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at, agent_model FROM review_runs WHERE id = ?1",
         )?;
         let mut rows = stmt.query_map([id], |row| {
             let status_str: String = row.get(6)?;
@@ -60,6 +66,7 @@ impl ReviewRunRepository {
                 diff_hash: row.get(5)?,
                 status,
                 created_at: row.get(7)?,
+                agent_model: row.get(8)?,
             })
         })?;
 
@@ -72,10 +79,10 @@ impl ReviewRunRepository {
     pub fn find_by_review_id(&self, review_id: &ReviewId) -> Result<Vec<ReviewRun>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("ReviewRunRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
-            "SELECT id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at FROM review_runs WHERE review_id = ?1",
+            "SELECT id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at, agent_model FROM review_runs WHERE review_id = ?1",
         )?;
         let rows = stmt.query_map([review_id], |row| {
             let status_str: String = row.get(6)?;
@@ -89,6 +96,7 @@ impl ReviewRunRepository {
                 diff_hash: row.get(5)?,
                 status,
                 created_at: row.get(7)?,
+                agent_model: row.get(8)?,
             })
         })?;
         rows.collect::<Result<Vec<_>, _>>().map_err(Into::into)
@@ -106,6 +114,241 @@ impl ReviewRunRepository {
         Ok(())
     }
 
+    /// Sub-flow names in the order the reviewer arranged them. Empty until
+    /// the run's sub-flows have been reordered.
+    /// Just the run's diff, for callers that listed runs without it.
+    pub fn get_diff(&self, id: &ReviewRunId) -> Result<Option<String>> {
+        let conn = self
+            .conn
+            .read()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        Ok(conn
+            .query_row(
+                "SELECT diff_text FROM review_runs WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    pub fn get_sub_flow_order(&self, id: &ReviewRunId) -> Result<Vec<String>> {
+        let conn = self
+            .conn
+            .read()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        let order: Option<String> = conn
+            .query_row(
+                "SELECT sub_flow_order FROM review_runs WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        match order {
+            Some(json) => Ok(serde_json::from_str(&json)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    pub fn set_sub_flow_order(&self, id: &ReviewRunId, order: &[String]) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        conn.execute(
+            "UPDATE review_runs SET sub_flow_order = ?1 WHERE id = ?2",
+            (serde_json::to_string(order)?, id),
+        )?;
+        Ok(())
+    }
+
+    /// Records the model the agent reported once its session started.
+    pub fn set_agent_model(&self, id: &ReviewRunId, model: &str) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        conn.execute(
+            "UPDATE review_runs SET agent_model = ?1 WHERE id = ?2",
+            (model, id),
+        )?;
+        Ok(())
+    }
+
+    /// The plan the agent finished the run with, if it reported one.
+    pub fn get_plan(&self, id: &ReviewRunId) -> Result<Option<RunPlan>> {
+        let conn = self
+            .conn
+            .read()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        let plan: Option<String> = conn
+            .query_row("SELECT plan FROM review_runs WHERE id = ?1", [id], |row| {
+                row.get(0)
+            })
+            .optional()?
+            .flatten();
+        match plan {
+            Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn set_plan(&self, id: &ReviewRunId, plan: &RunPlan) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        conn.execute(
+            "UPDATE review_runs SET plan = ?1 WHERE id = ?2",
+            (serde_json::to_string(plan)?, id),
+        )?;
+        Ok(())
+    }
+
+    /// Tool calls recorded for the run; empty for runs that predate the log.
+    pub fn get_tool_log(&self, id: &ReviewRunId) -> Result<RunToolLog> {
+        let conn = self
+            .conn
+            .read()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        let log: Option<String> = conn
+            .query_row(
+                "SELECT tool_calls FROM review_runs WHERE id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        let mut log: RunToolLog = match log {
+            Some(json) => serde_json::from_str(&json)?,
+            None => RunToolLog::default(),
+        };
+        drop(conn);
+        log.timings = self.get_tool_timings(id)?;
+        Ok(log)
+    }
+
+    /// Adds one call of `tool` taking `elapsed` to the run's tool timings.
+    pub fn record_tool_timing(
+        &self,
+        id: &ReviewRunId,
+        tool: &str,
+        elapsed: Duration,
+    ) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        let ms = elapsed.as_millis() as i64;
+        conn.execute(
+            r#"
+            INSERT INTO tool_timings (run_id, tool, calls, total_ms, max_ms)
+            VALUES (?1, ?2, 1, ?3, ?3)
+            ON CONFLICT(run_id, tool) DO UPDATE SET
+                calls = calls + 1,
+                total_ms = total_ms + excluded.total_ms,
+                max_ms = max(max_ms, excluded.max_ms)
+            "#,
+            (id, tool, ms),
+        )?;
+        Ok(())
+    }
+
+    /// Time spent per tool during the run, slowest total first.
+    pub fn get_tool_timings(&self, id: &ReviewRunId) -> Result<Vec<ToolTiming>> {
+        let conn = self
+            .conn
+            .read()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        let mut stmt = conn.prepare(
+            "SELECT tool, calls, total_ms, max_ms FROM tool_timings WHERE run_id = ?1 ORDER BY total_ms DESC, tool",
+        )?;
+        let timings = stmt
+            .query_map([id], |row| {
+                Ok(ToolTiming {
+                    tool: row.get(0)?,
+                    calls: row.get::<_, i64>(1)? as u64,
+                    total_ms: row.get::<_, i64>(2)? as u64,
+                    max_ms: row.get::<_, i64>(3)? as u64,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(timings)
+    }
+
+    /// Ticks or unticks the "viewed" box for `file_path` in a run.
+    pub fn set_file_viewed(
+        &self,
+        id: &ReviewRunId,
+        file_path: &str,
+        content_hash: &str,
+        viewed: bool,
+    ) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        conn.execute(
+            r#"
+            INSERT INTO file_view_state (run_id, file_path, viewed, content_hash, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(run_id, file_path) DO UPDATE SET
+                viewed = excluded.viewed,
+                content_hash = excluded.content_hash,
+                updated_at = excluded.updated_at
+            "#,
+            (
+                id,
+                file_path,
+                viewed as i32,
+                content_hash,
+                chrono::Utc::now().to_rfc3339(),
+            ),
+        )?;
+        Ok(())
+    }
+
+    /// View states from every run of a review, newest first.
+    pub fn get_file_view_states(&self, review_id: &ReviewId) -> Result<Vec<FileViewState>> {
+        let conn = self
+            .conn
+            .read()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT f.run_id, f.file_path, f.viewed, f.content_hash, f.updated_at
+            FROM file_view_state f
+            JOIN review_runs r ON r.id = f.run_id
+            WHERE r.review_id = ?1
+            ORDER BY f.updated_at DESC, f.file_path
+            "#,
+        )?;
+        let states = stmt
+            .query_map([review_id], |row| {
+                Ok(FileViewState {
+                    run_id: row.get(0)?,
+                    file_path: row.get(1)?,
+                    viewed: row.get::<_, i32>(2)? != 0,
+                    content_hash: row.get(3)?,
+                    updated_at: row.get(4)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(states)
+    }
+
+    pub fn set_tool_log(&self, id: &ReviewRunId, log: &RunToolLog) -> Result<()> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("ReviewRunRepository: failed to acquire database lock");
+        conn.execute(
+            "UPDATE review_runs SET tool_calls = ?1 WHERE id = ?2",
+            (serde_json::to_string(log)?, id),
+        )?;
+        Ok(())
+    }
+
     pub fn delete_by_review_id(&self, review_id: &ReviewId) -> Result<usize> {
         let conn = self
             .conn
@@ -118,10 +361,10 @@ impl ReviewRunRepository {
     pub fn list_all(&self) -> Result<Vec<ReviewRun>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("ReviewRunRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
-            "SELECT id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at FROM review_runs ORDER BY created_at DESC",
+            "SELECT id, review_id, agent_id, input_ref, diff_text, diff_hash, status, created_at, agent_model FROM review_runs ORDER BY created_at DESC",
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -136,6 +379,7 @@ impl ReviewRunRepository {
                 diff_hash: row.get(5)?,
                 status,
                 created_at: row.get(7)?,
+                agent_model: row.get(8)?,
             })
         })?;
 
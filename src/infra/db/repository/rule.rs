@@ -41,7 +41,7 @@ impl ReviewRuleRepository {
     }
 
     pub fn list_all(&self) -> Result<Vec<ReviewRule>> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
             SELECT id, scope, repo_id, glob, category, text, enabled, created_at, updated_at
@@ -58,7 +58,7 @@ impl ReviewRuleRepository {
     }
 
     pub fn list_enabled(&self) -> Result<Vec<ReviewRule>> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
             SELECT id, scope, repo_id, glob, category, text, enabled, created_at, updated_at
@@ -76,7 +76,7 @@ impl ReviewRuleRepository {
     }
 
     pub fn find_by_id(&self, id: &str) -> Result<Option<ReviewRule>> {
-        let conn = self.conn.lock().expect("Failed to acquire database lock");
+        let conn = self.conn.read().expect("Failed to acquire database lock");
         let mut stmt = conn.prepare(
             r#"
             SELECT id, scope, repo_id, glob, category, text, enabled, created_at, updated_at
@@ -1,6 +1,7 @@
 use super::DbConn;
 use crate::domain::{ReviewRunId, ReviewStatus, ReviewTask, TaskId};
 use anyhow::Result;
+use rusqlite::OptionalExtension;
 use std::sync::Arc;
 
 use std::str::FromStr;
@@ -68,11 +69,135 @@ impl TaskRepository {
         Ok(())
     }
 
-    pub fn find_by_id(&self, task_id: &TaskId) -> Result<Option<ReviewTask>> {
+    /// Moves a task to `sub_flow`, or out of any sub-flow when `None`.
+    pub fn update_sub_flow(&self, task_id: &TaskId, sub_flow: Option<&str>) -> Result<()> {
         let conn = self
             .conn
             .lock()
             .expect("TaskRepository: failed to acquire database lock");
+        conn.execute(
+            "UPDATE tasks SET sub_flow = ?1 WHERE id = ?2",
+            (sub_flow, task_id),
+        )?;
+        Ok(())
+    }
+
+    /// Renames sub-flow `old` (`None` for tasks without one) to `new` across
+    /// a run. Returns the number of tasks that moved.
+    pub fn rename_sub_flow(
+        &self,
+        run_id: &ReviewRunId,
+        old: Option<&str>,
+        new: &str,
+    ) -> Result<usize> {
+        let conn = self
+            .conn
+            .lock()
+            .expect("TaskRepository: failed to acquire database lock");
+        let affected = conn.execute(
+            "UPDATE tasks SET sub_flow = ?1 WHERE run_id = ?2 AND sub_flow IS ?3",
+            (new, run_id, old),
+        )?;
+        Ok(affected)
+    }
+
+    /// Merges `task_ids` of `run_id` into the first of them, re-pointing the
+    /// others' feedback to it before deleting them. See
+    /// [`ReviewTask::absorb`] for how the fields combine.
+    pub fn merge_tasks(&self, run_id: &ReviewRunId, task_ids: &[TaskId]) -> Result<ReviewTask> {
+        let Some((primary_id, others)) = task_ids.split_first() else {
+            anyhow::bail!("No tasks to merge");
+        };
+        if others.is_empty() {
+            anyhow::bail!("Select at least two tasks to merge");
+        }
+        let unique: std::collections::HashSet<&TaskId> = task_ids.iter().collect();
+        if unique.len() != task_ids.len() {
+            anyhow::bail!("Cannot merge a task into itself");
+        }
+
+        let mut conn = self
+            .conn
+            .lock()
+            .expect("TaskRepository: failed to acquire database lock");
+        let tx = conn.transaction()?;
+        let mut merged: Option<ReviewTask> = None;
+        for id in task_ids {
+            let task = tx
+                .query_row(
+                    "SELECT id, run_id, title, description, files, stats, insight, diff_refs, diagram, ai_generated, status, sub_flow FROM tasks WHERE id = ?1 AND run_id = ?2",
+                    (id, run_id),
+                    Self::map_row,
+                )
+                .optional()?
+                .ok_or_else(|| anyhow::anyhow!("Task not found in run {}: {}", run_id, id))?;
+            match merged.as_mut() {
+                Some(merged) => merged.absorb(task),
+                None => merged = Some(task),
+            }
+        }
+        let merged = merged.expect("at least two tasks were loaded");
+
+        tx.execute(
+            "UPDATE tasks SET title = ?1, description = ?2, files = ?3, stats = ?4, insight = ?5, diff_refs = ?6, diagram = ?7, ai_generated = ?8, status = ?9 WHERE id = ?10",
+            (
+                &merged.title,
+                &merged.description,
+                serde_json::to_string(&merged.files)?,
+                serde_json::to_string(&merged.stats)?,
+                &merged.insight,
+                serde_json::to_string(&merged.diff_refs)?,
+                &merged.diagram,
+                merged.ai_generated as i32,
+                merged.status.to_string(),
+                primary_id,
+            ),
+        )?;
+        let placeholders = std::iter::repeat_n("?", others.len())
+            .collect::<Vec<_>>()
+            .join(",");
+        let params = std::iter::once(primary_id).chain(others.iter());
+        tx.execute(
+            &format!("UPDATE feedback SET task_id = ?1 WHERE task_id IN ({placeholders})"),
+            rusqlite::params_from_iter(params),
+        )?;
+        tx.execute(
+            &format!("DELETE FROM tasks WHERE id IN ({placeholders})"),
+            rusqlite::params_from_iter(others.iter()),
+        )?;
+        tx.commit()?;
+
+        Ok(merged)
+    }
+
+    fn map_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<ReviewTask> {
+        let files_json: String = row.get(4)?;
+        let stats_json: String = row.get(5)?;
+        let diff_refs_json: Option<String> = row.get(7)?;
+        let status_str: String = row.get(10)?;
+        Ok(ReviewTask {
+            id: row.get(0)?,
+            run_id: row.get(1)?,
+            title: row.get(2)?,
+            description: row.get(3)?,
+            files: serde_json::from_str(&files_json).unwrap_or_default(),
+            stats: serde_json::from_str(&stats_json).unwrap_or_default(),
+            insight: row.get::<_, Option<String>>(6)?.map(Arc::from),
+            diff_refs: diff_refs_json
+                .map(|s| serde_json::from_str(&s).unwrap_or_default())
+                .unwrap_or_default(),
+            diagram: row.get::<_, Option<String>>(8)?.map(Arc::from),
+            ai_generated: row.get::<_, i32>(9)? != 0,
+            status: ReviewStatus::from_str(&status_str).unwrap_or_default(),
+            sub_flow: row.get(11)?,
+        })
+    }
+
+    pub fn find_by_id(&self, task_id: &TaskId) -> Result<Option<ReviewTask>> {
+        let conn = self
+            .conn
+            .read()
+            .expect("TaskRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
             "SELECT id, run_id, title, description, files, stats, insight, diff_refs, diagram, ai_generated, status, sub_flow FROM tasks WHERE id = ?1",
         )?;
@@ -129,7 +254,7 @@ impl TaskRepository {
     pub fn find_all(&self) -> Result<Vec<ReviewTask>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("TaskRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
             "SELECT id, run_id, title, description, files, stats, insight, diff_refs, diagram, ai_generated, status, sub_flow FROM tasks",
@@ -204,7 +329,7 @@ impl TaskRepository {
         }
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("TaskRepository: failed to acquire database lock");
         let placeholders = std::iter::repeat_n("?", run_ids.len())
             .collect::<Vec<_>>()
@@ -278,11 +403,43 @@ impl TaskRepository {
         Ok(tasks)
     }
 
+    /// One page of tasks, optionally limited to a run, in insertion order,
+    /// along with how many tasks match in total.
+    pub fn find_page(
+        &self,
+        run_id: Option<&ReviewRunId>,
+        limit: usize,
+        offset: usize,
+    ) -> Result<(Vec<ReviewTask>, usize)> {
+        let conn = self
+            .conn
+            .read()
+            .expect("TaskRepository: failed to acquire database lock");
+        let total: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM tasks WHERE ?1 IS NULL OR run_id = ?1",
+            [run_id],
+            |row| row.get(0),
+        )?;
+        let mut stmt = conn.prepare(
+            "SELECT id, run_id, title, description, files, stats, insight, diff_refs, diagram, ai_generated, status, sub_flow FROM tasks
+             WHERE ?1 IS NULL OR run_id = ?1
+             ORDER BY rowid
+             LIMIT ?2 OFFSET ?3",
+        )?;
+        let tasks = stmt
+            .query_map(
+                rusqlite::params![run_id, limit as i64, offset as i64],
+                Self::map_row,
+            )?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok((tasks, total as usize))
+    }
+
     #[allow(dead_code)] // Used by ACP modules; invoked indirectly.
     pub fn find_by_run(&self, run_id_filter: &ReviewRunId) -> Result<Vec<ReviewTask>> {
         let conn = self
             .conn
-            .lock()
+            .read()
             .expect("TaskRepository: failed to acquire database lock");
         let mut stmt = conn.prepare(
             "SELECT id, run_id, title, description, files, stats, insight, diff_refs, diagram, ai_generated, status, sub_flow FROM tasks WHERE run_id = ?1",
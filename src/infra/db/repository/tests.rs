@@ -1,10 +1,13 @@
 use crate::domain::{
-    Comment, Feedback, FeedbackImpact, LinkedRepo, Review, ReviewRule, ReviewRun, ReviewRunStatus,
-    ReviewSource, ReviewStatus, RuleScope, TaskStats,
+    ChecklistItem, Comment, Feedback, FeedbackAttachment, FeedbackImpact, IssueCheckDefinition,
+    LinkedRepo, Review, ReviewDecision, ReviewRule, ReviewRun, ReviewRunStatus, ReviewSource,
+    ReviewStatus, RuleScope, RunPlan, RunPlanEntry, RunToolCall, RunToolLog, SuggestedReplacement,
+    TaskStats, ToolTiming,
 };
 use crate::infra::db::Database;
 use crate::infra::db::repository::*;
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[test]
 fn test_task_repository() -> anyhow::Result<()> {
@@ -25,6 +28,8 @@ fn test_task_repository() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".to_string(),
         updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review)?;
 
@@ -37,6 +42,7 @@ fn test_task_repository() -> anyhow::Result<()> {
         diff_hash: "h".into(),
         status: ReviewRunStatus::Completed,
         created_at: "now".into(),
+        agent_model: None,
     };
     run_repo.save(&run)?;
 
@@ -67,6 +73,193 @@ fn test_task_repository() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_sub_flow_curation() -> anyhow::Result<()> {
+    let db = Database::open_in_memory()?;
+    let conn = db.connection();
+    let repo = TaskRepository::new(conn.clone());
+    let run_repo = ReviewRunRepository::new(conn.clone());
+
+    ReviewRepository::new(conn.clone()).save(&Review {
+        id: "rev-1".to_string(),
+        title: "Test Review".to_string(),
+        summary: None,
+        source: ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        },
+        active_run_id: Some("run-1".into()),
+        status: ReviewStatus::Todo,
+        created_at: "now".to_string(),
+        updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
+    })?;
+    run_repo.save(&ReviewRun {
+        id: "run-1".into(),
+        review_id: "rev-1".into(),
+        agent_id: "agent".into(),
+        input_ref: "diff".into(),
+        diff_text: "diff".into(),
+        diff_hash: "h".into(),
+        status: ReviewRunStatus::Completed,
+        created_at: "now".into(),
+        agent_model: None,
+    })?;
+    for (id, sub_flow) in [("t1", Some("Auth")), ("t2", Some("Auth")), ("t3", None)] {
+        repo.save(&crate::domain::ReviewTask {
+            id: id.to_string(),
+            run_id: "run-1".into(),
+            sub_flow: sub_flow.map(str::to_string),
+            ..Default::default()
+        })?;
+    }
+    let sub_flow = |id: &str| -> anyhow::Result<Option<String>> {
+        Ok(repo.find_by_id(&id.to_string())?.expect("task").sub_flow)
+    };
+
+    let run_id = "run-1".to_string();
+    assert_eq!(repo.rename_sub_flow(&run_id, Some("Auth"), "Login")?, 2);
+    assert_eq!(sub_flow("t1")?.as_deref(), Some("Login"));
+    assert_eq!(repo.rename_sub_flow(&run_id, None, "Misc")?, 1);
+    assert_eq!(sub_flow("t3")?.as_deref(), Some("Misc"));
+
+    repo.update_sub_flow(&"t2".to_string(), Some("Misc"))?;
+    assert_eq!(sub_flow("t2")?.as_deref(), Some("Misc"));
+    repo.update_sub_flow(&"t2".to_string(), None)?;
+    assert_eq!(sub_flow("t2")?, None);
+
+    assert!(run_repo.get_sub_flow_order(&run_id)?.is_empty());
+    let order = vec!["Misc".to_string(), "Login".to_string()];
+    run_repo.set_sub_flow_order(&run_id, &order)?;
+    assert_eq!(run_repo.get_sub_flow_order(&run_id)?, order);
+
+    Ok(())
+}
+
+#[test]
+fn test_merge_tasks_repoints_feedback() -> anyhow::Result<()> {
+    let db = Database::open_in_memory()?;
+    let conn = db.connection();
+    let repo = TaskRepository::new(conn.clone());
+    let feedback_repo = FeedbackRepository::new(conn.clone());
+
+    ReviewRepository::new(conn.clone()).save(&Review {
+        id: "rev-1".to_string(),
+        title: "Test Review".to_string(),
+        summary: None,
+        source: ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        },
+        active_run_id: Some("run-1".into()),
+        status: ReviewStatus::Todo,
+        created_at: "now".to_string(),
+        updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
+    })?;
+    ReviewRunRepository::new(conn.clone()).save(&ReviewRun {
+        id: "run-1".into(),
+        review_id: "rev-1".into(),
+        agent_id: "agent".into(),
+        input_ref: "diff".into(),
+        diff_text: "diff".into(),
+        diff_hash: "h".into(),
+        status: ReviewRunStatus::Completed,
+        created_at: "now".into(),
+        agent_model: None,
+    })?;
+    let tasks = [
+        (
+            "t1",
+            "Parser",
+            vec!["src/a.rs", "src/b.rs"],
+            ReviewStatus::Done,
+        ),
+        (
+            "t2",
+            "Lexer",
+            vec!["src/b.rs", "src/c.rs"],
+            ReviewStatus::InProgress,
+        ),
+        ("t3", "Docs", vec!["README.md"], ReviewStatus::Todo),
+    ];
+    for (id, title, files, status) in tasks {
+        repo.save(&crate::domain::ReviewTask {
+            id: id.to_string(),
+            run_id: "run-1".into(),
+            title: title.to_string(),
+            description: format!("{title} changes"),
+            files: files.into_iter().map(str::to_string).collect(),
+            stats: TaskStats {
+                additions: 5,
+                ..Default::default()
+            },
+            status,
+            ..Default::default()
+        })?;
+    }
+    let feedback = |id: &str, task_id: &str| Feedback {
+        id: id.into(),
+        review_id: "rev-1".into(),
+        task_id: Some(task_id.into()),
+        rule_id: None,
+        finding_id: None,
+        category: None,
+        title: id.into(),
+        status: ReviewStatus::Todo,
+        impact: FeedbackImpact::Nitpick,
+        confidence: 1.0,
+        anchor: None,
+        suggested_replacement: None,
+        extra_anchors: Vec::new(),
+        rationale: None,
+        pinned: false,
+        author: "me".into(),
+        created_at: "now".into(),
+        updated_at: "now".into(),
+    };
+    feedback_repo.save(&feedback("fb-1", "t1"))?;
+    feedback_repo.save(&feedback("fb-2", "t2"))?;
+    feedback_repo.save(&feedback("fb-3", "t3"))?;
+
+    let run_id = "run-1".to_string();
+    let merged = repo.merge_tasks(&run_id, &["t1".to_string(), "t2".to_string()])?;
+    assert_eq!(merged.id, "t1");
+    assert_eq!(merged.title, "Parser + Lexer");
+    assert_eq!(merged.files, vec!["src/a.rs", "src/b.rs", "src/c.rs"]);
+    assert_eq!(merged.stats.additions, 10);
+    assert_eq!(merged.status, ReviewStatus::InProgress);
+
+    assert!(repo.find_by_id(&"t2".to_string())?.is_none());
+    let stored = repo.find_by_id(&"t1".to_string())?.expect("merged task");
+    assert_eq!(stored.description, "Parser changes\n\nLexer changes");
+    assert_eq!(stored.status, ReviewStatus::InProgress);
+
+    let task_of = |id: &str| -> anyhow::Result<Option<String>> {
+        Ok(feedback_repo
+            .find_by_id(&id.to_string())?
+            .expect("feedback")
+            .task_id)
+    };
+    assert_eq!(task_of("fb-1")?.as_deref(), Some("t1"));
+    assert_eq!(task_of("fb-2")?.as_deref(), Some("t1"));
+    assert_eq!(task_of("fb-3")?.as_deref(), Some("t3"));
+
+    // A failed merge leaves everything in place.
+    assert!(
+        repo.merge_tasks(&run_id, &["t1".to_string(), "missing".to_string()])
+            .is_err()
+    );
+    assert!(
+        repo.merge_tasks(&run_id, &["t1".to_string(), "t1".to_string()])
+            .is_err()
+    );
+    assert!(repo.merge_tasks(&run_id, &["t3".to_string()]).is_err());
+    assert_eq!(repo.find_by_run(&run_id)?.len(), 2);
+
+    Ok(())
+}
+
 #[test]
 fn test_repo_repository() -> anyhow::Result<()> {
     let db = Database::open_in_memory()?;
@@ -79,6 +272,8 @@ fn test_repo_repository() -> anyhow::Result<()> {
         remotes: vec!["https://github.com/test/repo".into()],
         created_at: "now".into(),
         allow_snapshot_access: false,
+        default_agent_id: None,
+        default_rule_categories: vec![],
     };
 
     repo.save(&linked)?;
@@ -89,6 +284,24 @@ fn test_repo_repository() -> anyhow::Result<()> {
 
     let found = repo.find_by_remote_url("test/repo")?.expect("found");
     assert_eq!(found.id, "repo-1");
+    assert!(found.default_agent_id.is_none());
+
+    repo.update_defaults("repo-1", Some("codex"), &["security".to_string()])?;
+    let found = repo.find_by_remote_url("test/repo")?.expect("found");
+    assert_eq!(found.default_agent_id.as_deref(), Some("codex"));
+    assert_eq!(found.default_rule_categories, vec!["security".to_string()]);
+
+    // Re-saving the repo keeps its defaults.
+    repo.save(&found)?;
+    assert_eq!(
+        repo.find_all()?[0].default_agent_id.as_deref(),
+        Some("codex")
+    );
+
+    repo.update_defaults("repo-1", None, &[])?;
+    let cleared = &repo.find_all()?[0];
+    assert!(cleared.default_agent_id.is_none());
+    assert!(cleared.default_rule_categories.is_empty());
 
     repo.delete("repo-1")?;
     assert_eq!(repo.find_all()?.len(), 0);
@@ -145,6 +358,8 @@ fn test_comment_repository() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".to_string(),
         updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review)?;
 
@@ -160,6 +375,10 @@ fn test_comment_repository() -> anyhow::Result<()> {
         impact: FeedbackImpact::Nitpick,
         confidence: 1.0,
         anchor: None,
+        suggested_replacement: None,
+        extra_anchors: Vec::new(),
+        rationale: None,
+        pinned: false,
         author: "me".into(),
         created_at: "now".into(),
         updated_at: "now".into(),
@@ -188,6 +407,157 @@ fn test_comment_repository() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_issue_check_definition_repository() -> anyhow::Result<()> {
+    let db = Database::open_in_memory()?;
+    let repo = IssueCheckDefinitionRepository::new(db.connection());
+
+    let mut definition = IssueCheckDefinition {
+        id: "check-1".into(),
+        name: "Accessibility".into(),
+        category: "accessibility".into(),
+        prompt: "Interactive elements need labels.".into(),
+        enabled: true,
+        created_at: "2024-01-01".into(),
+        updated_at: "2024-01-01".into(),
+    };
+    repo.save(&definition)?;
+    repo.save(&IssueCheckDefinition {
+        id: "check-2".into(),
+        name: "Logging".into(),
+        category: "logging".into(),
+        prompt: "No PII in logs.".into(),
+        enabled: false,
+        created_at: "2024-01-02".into(),
+        updated_at: "2024-01-02".into(),
+    })?;
+
+    assert_eq!(repo.list_all()?.len(), 2);
+    let enabled = repo.list_enabled()?;
+    assert_eq!(enabled, vec![definition.clone()]);
+
+    definition.prompt = "Interactive elements need accessible names.".into();
+    repo.save(&definition)?;
+    assert_eq!(repo.find_by_id("check-1")?, Some(definition));
+
+    assert_eq!(repo.delete("check-2")?, 1);
+    assert!(repo.find_by_id("check-2")?.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_attachment_repository() -> anyhow::Result<()> {
+    let db = Database::open_in_memory()?;
+    let repo = AttachmentRepository::new(db.connection());
+    let feedback_repo = FeedbackRepository::new(db.connection());
+    let review_repo = ReviewRepository::new(db.connection());
+
+    review_repo.save(&Review {
+        id: "rev-1".to_string(),
+        title: "Test Review".to_string(),
+        summary: None,
+        source: ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        },
+        active_run_id: None,
+        status: ReviewStatus::Todo,
+        created_at: "now".to_string(),
+        updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
+    })?;
+    feedback_repo.save(&Feedback {
+        id: "fb-1".into(),
+        review_id: "rev-1".into(),
+        task_id: None,
+        rule_id: None,
+        finding_id: None,
+        category: None,
+        title: "Feedback".into(),
+        status: ReviewStatus::Todo,
+        impact: FeedbackImpact::Nitpick,
+        confidence: 1.0,
+        anchor: None,
+        suggested_replacement: None,
+        extra_anchors: Vec::new(),
+        rationale: None,
+        pinned: false,
+        author: "me".into(),
+        created_at: "now".into(),
+        updated_at: "now".into(),
+    })?;
+
+    let png = vec![0x89, b'P', b'N', b'G', 0, 1, 2];
+    for (id, created_at) in [("a-1", "2024-01-01"), ("a-2", "2024-01-02")] {
+        repo.save(&FeedbackAttachment {
+            id: id.into(),
+            feedback_id: "fb-1".into(),
+            mime: "image/png".into(),
+            data: png.clone(),
+            byte_size: 0,
+            created_at: created_at.into(),
+        })?;
+    }
+
+    let list = repo.list_for_feedback("fb-1")?;
+    assert_eq!(list.len(), 2);
+    assert_eq!(list[0].id, "a-1");
+    assert_eq!(list[0].data, png);
+    assert_eq!(list[0].byte_size, png.len());
+
+    assert_eq!(repo.delete("a-1")?, 1);
+    assert_eq!(repo.list_for_feedback("fb-1")?.len(), 1);
+
+    feedback_repo.delete("fb-1")?;
+    assert!(repo.list_for_feedback("fb-1")?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_checklist_repository() -> anyhow::Result<()> {
+    let db = Database::open_in_memory()?;
+    let repo = ChecklistRepository::new(db.connection());
+    let review_repo = ReviewRepository::new(db.connection());
+
+    review_repo.save(&Review {
+        id: "rev-1".to_string(),
+        title: "Test Review".to_string(),
+        summary: None,
+        source: ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        },
+        active_run_id: None,
+        status: ReviewStatus::Todo,
+        created_at: "now".to_string(),
+        updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
+    })?;
+
+    let changelog = ChecklistItem::new("rev-1", "Update changelog").unwrap();
+    let sign_off = ChecklistItem::new("rev-1", "Get security sign-off").unwrap();
+    repo.save(&changelog)?;
+    repo.save(&sign_off)?;
+
+    let list = repo.find_by_review("rev-1")?;
+    assert_eq!(list, vec![changelog.clone(), sign_off.clone()]);
+
+    assert!(repo.toggle(&changelog.id)?.expect("item").done);
+    assert!(repo.find_by_review("rev-1")?[0].done);
+    assert!(!repo.toggle(&changelog.id)?.expect("item").done);
+    assert!(repo.toggle("missing")?.is_none());
+
+    assert_eq!(repo.delete(&sign_off.id)?, 1);
+    assert_eq!(repo.find_by_review("rev-1")?.len(), 1);
+
+    // Items go away with their review.
+    review_repo.delete(&"rev-1".to_string())?;
+    assert!(repo.find_by_review("rev-1")?.is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn test_feedback_repository() -> anyhow::Result<()> {
     let db = Database::open_in_memory()?;
@@ -205,6 +575,8 @@ fn test_feedback_repository() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".to_string(),
         updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review)?;
 
@@ -220,6 +592,13 @@ fn test_feedback_repository() -> anyhow::Result<()> {
         impact: FeedbackImpact::Nitpick,
         confidence: 1.0,
         anchor: None,
+        suggested_replacement: Some(SuggestedReplacement {
+            old_lines: vec!["a.unwrap()".into()],
+            new_lines: vec!["a?".into()],
+        }),
+        extra_anchors: Vec::new(),
+        rationale: None,
+        pinned: false,
         author: "me".into(),
         created_at: "now".into(),
         updated_at: "now".into(),
@@ -229,6 +608,10 @@ fn test_feedback_repository() -> anyhow::Result<()> {
     let list = repo.find_by_review("rev-1")?;
     assert_eq!(list.len(), 1);
     assert_eq!(list[0].title, "Feedback");
+    assert_eq!(
+        list[0].suggested_replacement,
+        feedback.suggested_replacement
+    );
 
     repo.update_status("t-1", ReviewStatus::Done)?;
     repo.update_impact("t-1", FeedbackImpact::Blocking)?;
@@ -240,6 +623,47 @@ fn test_feedback_repository() -> anyhow::Result<()> {
     assert_eq!(updated[0].impact, FeedbackImpact::Blocking);
     assert_eq!(updated[0].title, "New Title");
 
+    let anchor = crate::domain::FeedbackAnchor {
+        file_path: Some("src/lib.rs".into()),
+        line_number: Some(4),
+        side: Some(crate::domain::FeedbackSide::New),
+        diff_hash: Some("h2".into()),
+        outdated: true,
+        ..Default::default()
+    };
+    let extra = crate::domain::FeedbackAnchor {
+        file_path: Some("src/other.rs".into()),
+        line_number: Some(12),
+        ..Default::default()
+    };
+    repo.save(&Feedback {
+        anchor: Some(crate::domain::FeedbackAnchor {
+            line_number: Some(3),
+            outdated: false,
+            ..anchor.clone()
+        }),
+        extra_anchors: vec![extra.clone()],
+        ..feedback.clone()
+    })?;
+    repo.update_anchor("t-1", &anchor)?;
+    let reanchored = repo.find_by_id("t-1")?.expect("feedback");
+    assert_eq!(reanchored.anchor, Some(anchor));
+    assert_eq!(reanchored.extra_anchors, vec![extra]);
+
+    assert_eq!(reanchored.rationale, None);
+    repo.update_rationale("t-1", "The lock is held across the await.")?;
+    let explained = repo.find_by_id("t-1")?.expect("feedback");
+    assert_eq!(
+        explained.rationale.as_deref(),
+        Some("The lock is held across the await.")
+    );
+
+    assert!(!explained.pinned);
+    repo.set_pinned("t-1", true)?;
+    assert!(repo.find_by_id("t-1")?.expect("feedback").pinned);
+    repo.set_pinned("t-1", false)?;
+    assert!(!repo.find_by_id("t-1")?.expect("feedback").pinned);
+
     repo.delete_by_review("rev-1")?;
     assert_eq!(repo.find_by_review("rev-1")?.len(), 0);
 
@@ -263,6 +687,8 @@ fn test_review_run_repository() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".to_string(),
         updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review)?;
 
@@ -275,6 +701,7 @@ fn test_review_run_repository() -> anyhow::Result<()> {
         diff_hash: "h".into(),
         status: ReviewRunStatus::Running,
         created_at: "now".into(),
+        agent_model: None,
     };
 
     repo.save(&run)?;
@@ -283,6 +710,76 @@ fn test_review_run_repository() -> anyhow::Result<()> {
     assert_eq!(repo.find_by_review_id(&"rev-1".into())?.len(), 1);
     assert_eq!(repo.list_all()?.len(), 1);
 
+    assert_eq!(repo.get_plan(&"run-1".into())?, None);
+    let plan = RunPlan {
+        entries: vec![RunPlanEntry {
+            content: "Read the diff".into(),
+            priority: "high".into(),
+            status: "completed".into(),
+        }],
+    };
+    repo.set_plan(&"run-1".into(), &plan)?;
+    assert_eq!(repo.get_plan(&"run-1".into())?, Some(plan.clone()));
+    assert_eq!(db.get_review_runs("rev-1")?[0].plan, Some(plan));
+    assert_eq!(db.get_review_runs("rev-1")?[0].diff_hash, "h");
+    assert_eq!(repo.get_diff(&"run-1".into())?.as_deref(), Some("diff"));
+    assert_eq!(repo.get_diff(&"missing".into())?, None);
+
+    assert_eq!(fetched.agent_model, None);
+    repo.set_agent_model(&"run-1".into(), "gpt-5-codex")?;
+    let fetched = repo.find_by_id(&"run-1".into())?.expect("run exists");
+    assert_eq!(fetched.agent_model.as_deref(), Some("gpt-5-codex"));
+    assert_eq!(
+        db.get_review_runs("rev-1")?[0].agent_model.as_deref(),
+        Some("gpt-5-codex")
+    );
+
+    assert!(repo.get_tool_log(&"run-1".into())?.calls.is_empty());
+    let log = RunToolLog {
+        calls: vec![RunToolCall {
+            tool_call_id: "call-1".into(),
+            title: "read_file src/lib.rs".into(),
+            kind: "read".into(),
+            status: "completed".into(),
+            raw_input: None,
+            raw_output: None,
+        }],
+        omitted: 2,
+        timings: vec![],
+    };
+    repo.set_tool_log(&"run-1".into(), &log)?;
+    assert_eq!(repo.get_tool_log(&"run-1".into())?, log);
+
+    repo.record_tool_timing(&"run-1".into(), "repo_search", Duration::from_millis(120))?;
+    repo.record_tool_timing(&"run-1".into(), "repo_search", Duration::from_millis(30))?;
+    repo.record_tool_timing(&"run-1".into(), "get_hunk", Duration::from_millis(5))?;
+    let timings = repo.get_tool_log(&"run-1".into())?.timings;
+    assert_eq!(
+        timings,
+        vec![
+            ToolTiming {
+                tool: "repo_search".into(),
+                calls: 2,
+                total_ms: 150,
+                max_ms: 120,
+            },
+            ToolTiming {
+                tool: "get_hunk".into(),
+                calls: 1,
+                total_ms: 5,
+                max_ms: 5,
+            },
+        ]
+    );
+
+    repo.set_file_viewed(&"run-1".into(), "src/lib.rs", "abc", true)?;
+    repo.set_file_viewed(&"run-1".into(), "src/lib.rs", "def", false)?;
+    let states = repo.get_file_view_states(&"rev-1".into())?;
+    assert_eq!(states.len(), 1);
+    assert_eq!(states[0].file_path, "src/lib.rs");
+    assert_eq!(states[0].content_hash, "def");
+    assert!(!states[0].viewed);
+
     repo.delete_by_review_id(&"rev-1".into())?;
     assert_eq!(repo.list_all()?.len(), 0);
 
@@ -305,6 +802,8 @@ fn test_review_repository() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".to_string(),
         updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
     };
 
     repo.save(&review)?;
@@ -318,6 +817,15 @@ fn test_review_repository() -> anyhow::Result<()> {
     assert_eq!(updated.title, "New Title");
     assert_eq!(updated.summary, Some("New Summary".into()));
     assert_eq!(updated.active_run_id, Some("run-1".into()));
+    assert_eq!(updated.decision, None);
+
+    repo.set_decision(&"rev-1".into(), Some(ReviewDecision::RequestChanges))?;
+    let decided = repo.find_by_id(&"rev-1".into())?.unwrap();
+    assert_eq!(decided.decision, Some(ReviewDecision::RequestChanges));
+    assert_eq!(
+        repo.list_all()?[0].decision,
+        Some(ReviewDecision::RequestChanges)
+    );
 
     repo.delete(&"rev-1".into())?;
     assert_eq!(repo.list_all()?.len(), 0);
@@ -349,6 +857,8 @@ fn test_review_cascading_deletion() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".into(),
         updated_at: "now".into(),
+        tags: vec![],
+        decision: None,
     })?;
 
     run_repo.save(&ReviewRun {
@@ -360,6 +870,7 @@ fn test_review_cascading_deletion() -> anyhow::Result<()> {
         diff_hash: "h".into(),
         status: ReviewRunStatus::Completed,
         created_at: "now".into(),
+        agent_model: None,
     })?;
 
     task_repo.save(&crate::domain::ReviewTask {
@@ -389,6 +900,10 @@ fn test_review_cascading_deletion() -> anyhow::Result<()> {
         impact: FeedbackImpact::Nitpick,
         confidence: 1.0,
         anchor: None,
+        suggested_replacement: None,
+        extra_anchors: Vec::new(),
+        rationale: None,
+        pinned: false,
         author: "me".into(),
         created_at: "now".into(),
         updated_at: "now".into(),
@@ -412,6 +927,134 @@ fn test_review_cascading_deletion() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_merge_reviews() -> anyhow::Result<()> {
+    let db = Database::open_in_memory()?;
+    let conn = db.connection();
+    let review_repo = ReviewRepository::new(conn.clone());
+    let run_repo = ReviewRunRepository::new(conn.clone());
+    let task_repo = TaskRepository::new(conn.clone());
+    let feedback_repo = FeedbackRepository::new(conn.clone());
+
+    for (review_id, title) in [("rev-a", "Primary"), ("rev-b", "Secondary")] {
+        let run_id = format!("run-{review_id}");
+        review_repo.save(&Review {
+            id: review_id.into(),
+            title: title.into(),
+            summary: None,
+            source: ReviewSource::DiffPaste {
+                diff_hash: "h".into(),
+            },
+            active_run_id: Some(run_id.clone()),
+            status: ReviewStatus::Todo,
+            created_at: "now".into(),
+            updated_at: "now".into(),
+            tags: vec![format!("tag-{review_id}")],
+            decision: None,
+        })?;
+        run_repo.save(&ReviewRun {
+            id: run_id.clone(),
+            review_id: review_id.into(),
+            agent_id: "agent".into(),
+            input_ref: "input".into(),
+            diff_text: "diff".into(),
+            diff_hash: "h".into(),
+            status: ReviewRunStatus::Completed,
+            created_at: "now".into(),
+            agent_model: None,
+        })?;
+        task_repo.save(&crate::domain::ReviewTask {
+            id: format!("task-{review_id}"),
+            run_id,
+            title: "Task".into(),
+            description: "Desc".into(),
+            files: vec![],
+            stats: TaskStats::default(),
+            diff_refs: vec![],
+            insight: None,
+            diagram: None,
+            ai_generated: true,
+            status: ReviewStatus::Todo,
+            sub_flow: None,
+        })?;
+    }
+
+    let feedback = |id: &str, review_id: &str, line: Option<u32>| Feedback {
+        id: id.into(),
+        review_id: review_id.into(),
+        task_id: None,
+        rule_id: None,
+        finding_id: None,
+        category: None,
+        title: id.into(),
+        status: ReviewStatus::Todo,
+        impact: FeedbackImpact::Nitpick,
+        confidence: 1.0,
+        anchor: line.map(|line| crate::domain::FeedbackAnchor {
+            file_path: Some("src/lib.rs".into()),
+            line_number: Some(line),
+            side: Some(crate::domain::FeedbackSide::New),
+            ..Default::default()
+        }),
+        suggested_replacement: None,
+        extra_anchors: Vec::new(),
+        rationale: None,
+        pinned: false,
+        author: "me".into(),
+        created_at: "now".into(),
+        updated_at: "now".into(),
+    };
+    feedback_repo.save(&feedback("fb-a", "rev-a", Some(10)))?;
+    feedback_repo.save(&feedback("fb-b-dup", "rev-b", Some(10)))?;
+    feedback_repo.save(&feedback("fb-b-line", "rev-b", Some(20)))?;
+    feedback_repo.save(&feedback("fb-b-general", "rev-b", None))?;
+
+    let outcome = review_repo.merge_into(&"rev-a".to_string(), &"rev-b".to_string())?;
+    assert_eq!(
+        outcome,
+        ReviewMergeOutcome {
+            runs_moved: 1,
+            feedback_moved: 2,
+            feedback_deduplicated: 1,
+        }
+    );
+
+    let count = |sql: &str| -> rusqlite::Result<i64> {
+        conn.lock().unwrap().query_row(sql, [], |row| row.get(0))
+    };
+    assert_eq!(count("SELECT COUNT(*) FROM reviews")?, 1);
+    assert_eq!(
+        count("SELECT COUNT(*) FROM review_runs WHERE review_id = 'rev-a'")?,
+        2
+    );
+    assert_eq!(count("SELECT COUNT(*) FROM tasks")?, 2);
+    assert_eq!(
+        count("SELECT COUNT(*) FROM feedback WHERE review_id = 'rev-a'")?,
+        3
+    );
+    assert_eq!(count("SELECT COUNT(*) FROM feedback")?, 3);
+
+    let primary = review_repo
+        .find_by_id(&"rev-a".to_string())?
+        .expect("review");
+    assert_eq!(primary.title, "Primary");
+    assert_eq!(primary.active_run_id.as_deref(), Some("run-rev-a"));
+    assert_eq!(primary.tags, vec!["tag-rev-a", "tag-rev-b"]);
+
+    assert!(
+        review_repo
+            .merge_into(&"rev-a".to_string(), &"rev-b".to_string())
+            .is_err()
+    );
+    assert!(
+        review_repo
+            .merge_into(&"rev-a".to_string(), &"rev-a".to_string())
+            .is_err()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_review_run_repository_status_update() -> anyhow::Result<()> {
     let db = Database::open_in_memory()?;
@@ -429,6 +1072,8 @@ fn test_review_run_repository_status_update() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".to_string(),
         updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review)?;
 
@@ -441,6 +1086,7 @@ fn test_review_run_repository_status_update() -> anyhow::Result<()> {
         diff_hash: "h".into(),
         status: ReviewRunStatus::Running,
         created_at: "now".into(),
+        agent_model: None,
     };
 
     repo.save(&run)?;
@@ -451,3 +1097,172 @@ fn test_review_run_repository_status_update() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_review_tags() -> anyhow::Result<()> {
+    let db = Database::open_in_memory()?;
+    let review_repo = ReviewRepository::new(db.connection());
+
+    let review = Review {
+        id: "rev-1".to_string(),
+        title: "Tagged Review".to_string(),
+        summary: None,
+        source: ReviewSource::GitHubPr {
+            owner: "puemos".into(),
+            repo: "lareview".into(),
+            number: 7,
+            url: None,
+            head_sha: None,
+            base_sha: None,
+        },
+        active_run_id: None,
+        status: ReviewStatus::Todo,
+        created_at: "now".to_string(),
+        updated_at: "now".to_string(),
+        tags: vec!["Backend".to_string()],
+        decision: None,
+    };
+    review_repo.save(&review)?;
+
+    let id = "rev-1".to_string();
+    assert_eq!(
+        review_repo.add_tag(&id, "  Needs Follow Up ")?,
+        vec!["backend".to_string(), "needs-follow-up".to_string()]
+    );
+    // Duplicate tags are ignored.
+    assert_eq!(review_repo.add_tag(&id, "BACKEND")?.len(), 2);
+    assert!(review_repo.add_tag(&id, "   ").is_err());
+
+    let found = review_repo.find_by_id(&id)?.expect("review");
+    assert_eq!(found.tags, vec!["backend", "needs-follow-up"]);
+
+    assert_eq!(
+        review_repo.remove_tag(&id, "backend")?,
+        vec!["needs-follow-up"]
+    );
+
+    let listed = db.get_all_reviews()?;
+    assert_eq!(listed[0].tags, vec!["needs-follow-up"]);
+    assert_eq!(
+        listed[0].suggested_tags,
+        vec!["github", "puemos", "lareview"]
+    );
+
+    // Tags go away with their review.
+    review_repo.delete(&id)?;
+    assert!(
+        crate::infra::db::repository::load_all_tags(&db.connection().lock().unwrap())?.is_empty()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_review_and_task_pages() -> anyhow::Result<()> {
+    let db = Database::open_in_memory()?;
+    let review_repo = ReviewRepository::new(db.connection());
+    let run_repo = ReviewRunRepository::new(db.connection());
+    let task_repo = TaskRepository::new(db.connection());
+
+    for i in 1..=3 {
+        review_repo.save(&Review {
+            id: format!("rev-{i}"),
+            title: format!("Review {i}"),
+            summary: None,
+            source: ReviewSource::DiffPaste {
+                diff_hash: "h".into(),
+            },
+            active_run_id: None,
+            status: ReviewStatus::Todo,
+            created_at: format!("2024-01-0{i}"),
+            updated_at: format!("2024-01-0{i}"),
+            tags: vec![],
+            decision: None,
+        })?;
+    }
+
+    let (first, total) = db.get_reviews_page(2, 0)?;
+    assert_eq!(total, 3);
+    let ids: Vec<_> = first.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids, vec!["rev-3", "rev-2"]);
+    let (rest, _) = db.get_reviews_page(2, 2)?;
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].id, "rev-1");
+    assert_eq!(db.get_all_reviews()?.len(), 3);
+
+    run_repo.save(&ReviewRun {
+        id: "run-1".into(),
+        review_id: "rev-1".into(),
+        agent_id: "agent".into(),
+        input_ref: "diff".into(),
+        diff_text: String::new(),
+        diff_hash: "h".into(),
+        status: ReviewRunStatus::Completed,
+        created_at: "now".into(),
+        agent_model: None,
+    })?;
+    for i in 1..=3 {
+        task_repo.save(&crate::domain::ReviewTask {
+            id: format!("task-{i}"),
+            run_id: "run-1".into(),
+            title: format!("Task {i}"),
+            description: String::new(),
+            files: vec![],
+            stats: TaskStats::default(),
+            diff_refs: vec![],
+            insight: None,
+            diagram: None,
+            ai_generated: false,
+            status: ReviewStatus::Todo,
+            sub_flow: None,
+        })?;
+    }
+
+    let (tasks, total) = db.get_tasks_page(Some("run-1"), 2, 1)?;
+    assert_eq!(total, 3);
+    let ids: Vec<_> = tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec!["task-2", "task-3"]);
+    let (tasks, total) = db.get_tasks_page(Some("run-2"), 2, 0)?;
+    assert!(tasks.is_empty());
+    assert_eq!(total, 0);
+    assert_eq!(db.get_tasks_page(None, 10, 0)?.1, 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_pending_diff_history() -> anyhow::Result<()> {
+    let db = Database::open_in_memory()?;
+    let repo = db.pending_diff_repo();
+    let record = |id: &str, diff: &str, created_at: &str| PendingDiffRecord {
+        id: id.into(),
+        diff: diff.into(),
+        repo_root: None,
+        agent: None,
+        source: "stdin".into(),
+        review_source: None,
+        created_at: created_at.into(),
+    };
+
+    for i in 0..MAX_PENDING_DIFFS + 2 {
+        repo.record(&record(
+            &format!("pd-{i:02}"),
+            &format!("diff {i}"),
+            &format!("2024-01-01T00:00:{i:02}Z"),
+        ))?;
+    }
+    let recent = repo.list_recent()?;
+    assert_eq!(recent.len(), MAX_PENDING_DIFFS);
+    assert_eq!(recent[0].id, "pd-11");
+    assert!(repo.find_by_id("pd-00")?.is_none());
+
+    // The same diff again keeps its id and moves to the top.
+    let id = repo.record(&record("new-id", "diff 5", "2024-01-02T00:00:00Z"))?;
+    assert_eq!(id, "pd-05");
+    let recent = repo.list_recent()?;
+    assert_eq!(recent.len(), MAX_PENDING_DIFFS);
+    assert_eq!(recent[0].id, "pd-05");
+    assert_eq!(repo.find_by_id("pd-05")?.unwrap().diff, "diff 5");
+
+    Ok(())
+}
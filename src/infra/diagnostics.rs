@@ -0,0 +1,426 @@
+//! Setup health check: runs the checks behind most "disconnected" or "needs
+//! setup" reports (database, VCS CLIs, agents, editor) and collects them into
+//! one report the user can read in settings or paste into a bug report.
+
+use crate::infra::acp::{AgentCandidate, AgentProbe, list_agent_candidates, probe_agent_command};
+use crate::infra::app_config::{AppConfig, load_config};
+use crate::infra::db::Database;
+use crate::infra::db::database::DatabaseHealth;
+use crate::infra::vcs::cli::cli_command;
+use crate::infra::vcs::registry::{STATUS_TIMEOUT, VcsRegistry};
+use crate::infra::vcs::traits::VcsStatus;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticLevel {
+    Ok,
+    Warn,
+    Error,
+}
+
+impl DiagnosticLevel {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticCheck {
+    /// Group the check is shown under, e.g. "Agents".
+    pub section: String,
+    pub name: String,
+    pub level: DiagnosticLevel,
+    pub detail: String,
+    /// What the user can do about a warning or error.
+    pub fix: Option<String>,
+}
+
+impl DiagnosticCheck {
+    fn new(section: &str, name: &str, level: DiagnosticLevel, detail: impl Into<String>) -> Self {
+        Self {
+            section: section.to_string(),
+            name: name.to_string(),
+            level,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn with_fix(mut self, fix: impl Into<String>) -> Self {
+        self.fix = Some(fix.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsReport {
+    pub app_version: String,
+    pub platform: String,
+    pub generated_at: String,
+    pub checks: Vec<DiagnosticCheck>,
+    /// Plain-text rendering for bug reports.
+    pub text: String,
+}
+
+impl DiagnosticsReport {
+    pub fn new(checks: Vec<DiagnosticCheck>) -> Self {
+        let mut report = Self {
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            platform: format!(
+                "{:?} {}",
+                crate::infra::platform::current_platform(),
+                std::env::consts::ARCH
+            ),
+            generated_at: chrono::Utc::now().to_rfc3339(),
+            checks,
+            text: String::new(),
+        };
+        report.text = report.render_text();
+        report
+    }
+
+    fn render_text(&self) -> String {
+        let mut text = format!(
+            "LaReview {} ({}) at {}\n",
+            self.app_version, self.platform, self.generated_at
+        );
+        for check in &self.checks {
+            text.push_str(&format!(
+                "[{}] {} / {}: {}\n",
+                check.level.label(),
+                check.section,
+                check.name,
+                check.detail
+            ));
+            if let Some(fix) = &check.fix {
+                text.push_str(&format!("    fix: {}\n", fix));
+            }
+        }
+        text
+    }
+}
+
+/// Runs every check. `runtime_ok` is whether the app's async runtime
+/// answered; the caller checks it because only it holds the handle.
+pub async fn run_diagnostics(db: &Mutex<Database>, runtime_ok: bool) -> DiagnosticsReport {
+    let config = load_config();
+    let mut checks = vec![runtime_check(runtime_ok)];
+
+    let health = match db.lock() {
+        Ok(db) => db.health(),
+        Err(_) => Err(anyhow::anyhow!("the database lock is poisoned")),
+    };
+    checks.push(database_check(health));
+
+    let registry = VcsRegistry::from_config(&config);
+    for status in registry.statuses(STATUS_TIMEOUT).await {
+        checks.push(vcs_check(&status));
+    }
+
+    let agents: Vec<AgentCandidate> = list_agent_candidates()
+        .into_iter()
+        .filter(|candidate| is_configured(candidate, &config))
+        .collect();
+    let probes =
+        futures::future::join_all(agents.iter().map(|candidate| {
+            probe_agent_command(candidate.command.as_deref().unwrap_or_default())
+        }))
+        .await;
+    checks.extend(agent_checks(&agents, &probes));
+
+    checks.push(editor_check(&config));
+
+    DiagnosticsReport::new(checks)
+}
+
+fn runtime_check(runtime_ok: bool) -> DiagnosticCheck {
+    if runtime_ok {
+        DiagnosticCheck::new("App", "Runtime", DiagnosticLevel::Ok, "Initialized")
+    } else {
+        DiagnosticCheck::new(
+            "App",
+            "Runtime",
+            DiagnosticLevel::Error,
+            "The background runtime did not respond",
+        )
+        .with_fix("Restart LaReview.")
+    }
+}
+
+fn database_check(health: anyhow::Result<DatabaseHealth>) -> DiagnosticCheck {
+    match health {
+        Ok(health) if health.quick_check == "ok" => DiagnosticCheck::new(
+            "Database",
+            "SQLite",
+            DiagnosticLevel::Ok,
+            format!(
+                "{} (SQLite {})",
+                health.path.as_deref().unwrap_or("in memory"),
+                health.sqlite_version
+            ),
+        ),
+        Ok(health) => DiagnosticCheck::new(
+            "Database",
+            "SQLite",
+            DiagnosticLevel::Error,
+            format!("Integrity check failed: {}", health.quick_check),
+        )
+        .with_fix("Back up and delete the database file, then restart LaReview."),
+        Err(err) => DiagnosticCheck::new(
+            "Database",
+            "SQLite",
+            DiagnosticLevel::Error,
+            format!("Could not open the database: {:#}", err),
+        )
+        .with_fix("Check that the data directory is writable, or choose another in Settings → Data Storage."),
+    }
+}
+
+fn vcs_check(status: &VcsStatus) -> DiagnosticCheck {
+    let cli = cli_command(&status.id);
+    let check = |level, detail: String| DiagnosticCheck::new("VCS", &status.name, level, detail);
+
+    if status.offline {
+        return check(DiagnosticLevel::Warn, "Disabled in offline mode".into())
+            .with_fix("Turn off offline mode in Settings to fetch remote reviews.");
+    }
+    if let Some(login) = &status.login {
+        let host = status
+            .host
+            .as_deref()
+            .map(|host| format!(" on {}", host))
+            .unwrap_or_default();
+        let method = status
+            .auth_method
+            .as_deref()
+            .map(|method| format!(" via {}", method))
+            .unwrap_or_default();
+        let version = status
+            .cli_version
+            .as_deref()
+            .map(|version| format!(" {}", version))
+            .unwrap_or_default();
+        return check(
+            DiagnosticLevel::Ok,
+            format!(
+                "Signed in as {}{}{} ({}{} at {})",
+                login, host, method, cli, version, status.cli_path
+            ),
+        );
+    }
+    match &status.error {
+        // The check itself failed or timed out before `cli` reported anything.
+        Some(error) if status.cli_path.is_empty() => check(DiagnosticLevel::Error, error.clone())
+            .with_fix(format!(
+                "Run `{} auth status` in a terminal to see whether it hangs or fails.",
+                cli
+            )),
+        Some(error) if error.starts_with("Configured ") => {
+            check(DiagnosticLevel::Error, error.clone()).with_fix(format!(
+                "Fix the {} path in Settings → VCS, or clear it to use the one on PATH.",
+                cli
+            ))
+        }
+        Some(_) if status.cli_path.ends_with("not found") => {
+            check(DiagnosticLevel::Warn, format!("`{}` is not installed", cli)).with_fix(format!(
+                "Install `{}` to review {} links, then restart LaReview.",
+                cli, status.name
+            ))
+        }
+        Some(error) => check(
+            DiagnosticLevel::Error,
+            format!("`{}` is not signed in: {}", cli, first_line(error)),
+        )
+        .with_fix(format!("Run `{} auth login` in a terminal.", cli)),
+        None => check(
+            DiagnosticLevel::Warn,
+            format!("`{}` found, but the signed-in account is unknown", cli),
+        )
+        .with_fix(format!("Run `{} auth status` to check the login.", cli)),
+    }
+}
+
+/// Agents worth checking: found on PATH, or set up by the user.
+fn is_configured(candidate: &AgentCandidate, config: &AppConfig) -> bool {
+    candidate.available
+        || config.agent_path_overrides.contains_key(&candidate.id)
+        || config
+            .custom_agents
+            .iter()
+            .any(|agent| agent.id == candidate.id)
+}
+
+fn agent_checks(agents: &[AgentCandidate], probes: &[AgentProbe]) -> Vec<DiagnosticCheck> {
+    if agents.is_empty() {
+        return vec![
+            DiagnosticCheck::new(
+                "Agents",
+                "Agents",
+                DiagnosticLevel::Error,
+                "No agent was found",
+            )
+            .with_fix("Install a supported agent, or add a custom one in Settings → Agents."),
+        ];
+    }
+
+    agents
+        .iter()
+        .zip(probes)
+        .map(|(agent, probe)| {
+            if probe.available {
+                let version = probe.version.as_deref().unwrap_or("version unknown");
+                let path = probe.path.as_deref().unwrap_or_default();
+                DiagnosticCheck::new(
+                    "Agents",
+                    &agent.label,
+                    DiagnosticLevel::Ok,
+                    format!("{} ({})", version, path),
+                )
+            } else {
+                let fix = match &agent.install_command {
+                    Some(install) => format!(
+                        "Install it with `{}`, or set its path in Settings → Agents.",
+                        install
+                    ),
+                    None => "Set its path in Settings → Agents.".to_string(),
+                };
+                DiagnosticCheck::new(
+                    "Agents",
+                    &agent.label,
+                    DiagnosticLevel::Error,
+                    probe.error.clone().unwrap_or_else(|| "Unavailable".into()),
+                )
+                .with_fix(fix)
+            }
+        })
+        .collect()
+}
+
+fn editor_check(config: &AppConfig) -> DiagnosticCheck {
+    use crate::infra::editor::{editor_label, is_editor_available, list_available_editors};
+
+    match config.preferred_editor_id.as_deref() {
+        Some(id) => {
+            let label = editor_label(id).unwrap_or(id);
+            if is_editor_available(id) {
+                DiagnosticCheck::new("Editor", label, DiagnosticLevel::Ok, "Available")
+            } else {
+                DiagnosticCheck::new(
+                    "Editor",
+                    label,
+                    DiagnosticLevel::Warn,
+                    "The preferred editor was not found",
+                )
+                .with_fix("Install its command-line launcher or pick another editor in Settings → Editor.")
+            }
+        }
+        None if list_available_editors().is_empty() => DiagnosticCheck::new(
+            "Editor",
+            "Editor",
+            DiagnosticLevel::Warn,
+            "No supported editor was found",
+        )
+        .with_fix(
+            "Install an editor's command-line launcher (e.g. `code`) to open files from reviews.",
+        ),
+        None => DiagnosticCheck::new(
+            "Editor",
+            "Editor",
+            DiagnosticLevel::Warn,
+            "No preferred editor selected",
+        )
+        .with_fix("Pick one in Settings → Editor."),
+    }
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(login: Option<&str>, error: Option<&str>, cli_path: &str) -> VcsStatus {
+        VcsStatus {
+            id: "github".into(),
+            name: "GitHub".into(),
+            cli_path: cli_path.into(),
+            login: login.map(str::to_string),
+            error: error.map(str::to_string),
+            offline: false,
+            host: Some("github.com".into()),
+            auth_method: Some("keyring".into()),
+            cli_version: Some("2.81.0".into()),
+        }
+    }
+
+    #[test]
+    fn test_vcs_check_levels() {
+        let signed_in = vcs_check(&status(Some("octocat"), None, "/usr/bin/gh"));
+        assert_eq!(signed_in.level, DiagnosticLevel::Ok);
+        assert_eq!(
+            signed_in.detail,
+            "Signed in as octocat on github.com via keyring (gh 2.81.0 at /usr/bin/gh)"
+        );
+
+        let missing = vcs_check(&status(
+            None,
+            Some("gh executable not found"),
+            "gh not found",
+        ));
+        assert_eq!(missing.level, DiagnosticLevel::Warn);
+
+        let bad_override = vcs_check(&status(
+            None,
+            Some("Configured gh path not found: /opt/gh"),
+            "/opt/gh",
+        ));
+        assert_eq!(bad_override.level, DiagnosticLevel::Error);
+        assert!(bad_override.fix.unwrap().contains("Settings → VCS"));
+
+        let timed_out = vcs_check(&status(None, Some("Status check timed out after 15s"), ""));
+        assert_eq!(timed_out.level, DiagnosticLevel::Error);
+        assert_eq!(timed_out.detail, "Status check timed out after 15s");
+
+        let signed_out = vcs_check(&status(
+            None,
+            Some("\nYou are not logged into any GitHub hosts.\n"),
+            "/usr/bin/gh",
+        ));
+        assert_eq!(signed_out.level, DiagnosticLevel::Error);
+        assert_eq!(
+            signed_out.fix.as_deref(),
+            Some("Run `gh auth login` in a terminal.")
+        );
+        assert!(
+            signed_out
+                .detail
+                .ends_with("You are not logged into any GitHub hosts.")
+        );
+    }
+
+    #[test]
+    fn test_report_text_lists_checks_and_fixes() {
+        let report =
+            DiagnosticsReport::new(vec![runtime_check(true), agent_checks(&[], &[]).remove(0)]);
+        assert!(report.text.starts_with("LaReview "));
+        assert!(report.text.contains("[ok] App / Runtime: Initialized\n"));
+        assert!(
+            report
+                .text
+                .contains("[error] Agents / Agents: No agent was found\n    fix: ")
+        );
+    }
+}
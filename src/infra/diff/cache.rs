@@ -0,0 +1,207 @@
+//! Process-level cache of parsed diffs.
+//!
+//! Parsing a large unified diff into a [`DiffIndex`] is expensive, and the same
+//! review diff is indexed over and over (preview parsing, feedback snippets,
+//! exports). This module memoizes indexes keyed by [`hash_diff`] in a bounded
+//! LRU so repeated lookups for the same diff are O(1).
+
+use super::index::{DiffIndex, DiffIndexError};
+use crate::infra::hash::hash_diff;
+use anyhow::Result;
+use lru::LruCache;
+use parking_lot::Mutex;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+/// Maximum number of distinct diffs kept in the cache.
+const MAX_ENTRIES: usize = 32;
+
+/// Upper bound on the combined size of the cached diffs' source text.
+///
+/// The parsed index is roughly proportional to its input, so this keeps the
+/// cache from pinning hundreds of megabytes when several huge PRs are open.
+const MAX_TOTAL_BYTES: usize = 64 * 1024 * 1024;
+
+lazy_static::lazy_static! {
+    static ref CACHE: Mutex<DiffIndexCache> =
+        Mutex::new(DiffIndexCache::new(MAX_ENTRIES, MAX_TOTAL_BYTES));
+}
+
+struct CacheEntry {
+    index: Arc<DiffIndex>,
+    /// Length of the diff text, used as the entry weight and as a cheap guard
+    /// against hash collisions.
+    bytes: usize,
+}
+
+struct DiffIndexCache {
+    entries: LruCache<String, CacheEntry>,
+    total_bytes: usize,
+    max_bytes: usize,
+}
+
+impl DiffIndexCache {
+    fn new(max_entries: usize, max_bytes: usize) -> Self {
+        let capacity = NonZeroUsize::new(max_entries).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: LruCache::new(capacity),
+            total_bytes: 0,
+            max_bytes,
+        }
+    }
+
+    fn get(&mut self, key: &str, bytes: usize) -> Option<Arc<DiffIndex>> {
+        self.entries
+            .get(key)
+            .filter(|entry| entry.bytes == bytes)
+            .map(|entry| entry.index.clone())
+    }
+
+    fn insert(&mut self, key: String, index: Arc<DiffIndex>, bytes: usize) {
+        // A single diff larger than the whole budget is never worth pinning.
+        if bytes > self.max_bytes {
+            return;
+        }
+
+        self.total_bytes += bytes;
+        if let Some((_, evicted)) = self.entries.push(key, CacheEntry { index, bytes }) {
+            self.total_bytes -= evicted.bytes;
+        }
+
+        while self.total_bytes > self.max_bytes {
+            match self.entries.pop_lru() {
+                Some((_, evicted)) => self.total_bytes -= evicted.bytes,
+                None => break,
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.total_bytes = 0;
+    }
+}
+
+/// Returns the parsed [`DiffIndex`] for `diff_text`, reusing a cached index
+/// when the same diff has been parsed before.
+///
+/// Parse failures are not cached.
+pub fn cached_diff_index(diff_text: &str) -> Result<Arc<DiffIndex>> {
+    let key = hash_diff(diff_text);
+    let bytes = diff_text.len();
+
+    if let Some(index) = CACHE.lock().get(&key, bytes) {
+        return Ok(index);
+    }
+
+    // Parse outside the lock so concurrent callers with different diffs don't
+    // serialize on each other.
+    let index = Arc::new(DiffIndex::new(diff_text)?);
+    CACHE.lock().insert(key, index.clone(), bytes);
+    Ok(index)
+}
+
+/// [`cached_diff_index`] for a diff the user supplied, where empty input is
+/// an error too: there's nothing to review.
+pub fn cached_user_diff_index(diff_text: &str) -> Result<Arc<DiffIndex>> {
+    if diff_text.trim().is_empty() {
+        return Err(DiffIndexError::EmptyInput.into());
+    }
+    cached_diff_index(diff_text)
+}
+
+/// Drops every cached index, releasing the memory they hold.
+pub fn clear_diff_index_cache() {
+    CACHE.lock().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn large_diff(lines: usize) -> String {
+        let mut diff = String::from(
+            "diff --git a/src/big.rs b/src/big.rs\n--- a/src/big.rs\n+++ b/src/big.rs\n",
+        );
+        diff.push_str(&format!("@@ -0,0 +1,{} @@\n", lines));
+        for i in 0..lines {
+            diff.push_str(&format!("+let value_{i} = {i};\n"));
+        }
+        diff
+    }
+
+    #[test]
+    fn test_cached_index_is_reused_for_identical_diff() {
+        let diff = large_diff(10_000);
+        let key = hash_diff(&diff);
+
+        let first = cached_diff_index(&diff).unwrap();
+        // Other tests share the process-wide cache, so look up this diff's
+        // own entry rather than counting entries.
+        let cached = CACHE.lock().get(&key, diff.len());
+        assert!(cached.is_some_and(|cached| Arc::ptr_eq(&cached, &first)));
+
+        let second = cached_diff_index(&diff).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(first.get_all_file_paths(), vec!["src/big.rs".to_string()]);
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used_entries() {
+        let mut cache = DiffIndexCache::new(2, usize::MAX);
+        let index = Arc::new(DiffIndex::new("").unwrap());
+
+        cache.insert("a".into(), index.clone(), 1);
+        cache.insert("b".into(), index.clone(), 1);
+        assert!(cache.get("a", 1).is_some());
+        cache.insert("c".into(), index, 1);
+
+        assert!(cache.get("a", 1).is_some());
+        assert!(cache.get("b", 1).is_none());
+        assert!(cache.get("c", 1).is_some());
+        assert_eq!(cache.total_bytes, 2);
+    }
+
+    #[test]
+    fn test_cache_respects_byte_budget() {
+        let mut cache = DiffIndexCache::new(8, 10);
+        let index = Arc::new(DiffIndex::new("").unwrap());
+
+        cache.insert("a".into(), index.clone(), 6);
+        cache.insert("b".into(), index.clone(), 6);
+        assert!(cache.get("a", 6).is_none());
+        assert!(cache.get("b", 6).is_some());
+        assert_eq!(cache.total_bytes, 6);
+
+        cache.insert("huge".into(), index, 11);
+        assert!(cache.get("huge", 11).is_none());
+
+        cache.clear();
+        assert_eq!(cache.total_bytes, 0);
+    }
+
+    #[test]
+    fn test_cache_rejects_length_mismatch() {
+        let mut cache = DiffIndexCache::new(2, usize::MAX);
+        let index = Arc::new(DiffIndex::new("").unwrap());
+        cache.insert("a".into(), index, 4);
+        assert!(cache.get("a", 5).is_none());
+    }
+
+    #[test]
+    fn test_user_diff_distinguishes_empty_from_non_diff_input() {
+        let error = |text: &str| {
+            cached_user_diff_index(text)
+                .err()
+                .and_then(|err| err.downcast::<DiffIndexError>().ok())
+        };
+        assert!(matches!(error(""), Some(DiffIndexError::EmptyInput)));
+        assert!(matches!(error(" \n\t\n"), Some(DiffIndexError::EmptyInput)));
+        assert!(matches!(
+            error("# Notes\n\nJust some text.\n"),
+            Some(DiffIndexError::NotADiff)
+        ));
+        // Internal callers index empty text as a diff with no files.
+        assert!(cached_diff_index("").unwrap().files.is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+//! Recognises generated files in a diff: lockfiles, build output and anything
+//! a repository marks `linguist-generated` in its `.gitattributes`.
+//!
+//! Generated files are collapsed in the diff view and the agent is told not
+//! to review them in depth, so attention goes to hand-written code.
+
+use globset::{GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
+use std::path::Path;
+
+/// Patterns treated as generated when the user hasn't configured their own.
+pub const DEFAULT_GENERATED_PATTERNS: &[&str] = &[
+    "*.lock",
+    "package-lock.json",
+    "pnpm-lock.yaml",
+    "go.sum",
+    "*.min.js",
+    "*.min.css",
+    "*.map",
+    "*.snap",
+    "*.pb.go",
+    "*_pb2.py",
+    "dist/**",
+    "build/**",
+    "vendor/**",
+];
+
+/// Matches diff paths against generated-file patterns and `.gitattributes`.
+#[derive(Debug)]
+pub struct GeneratedFiles {
+    patterns: GlobSet,
+    /// `.gitattributes` entries in file order; the last matching one wins,
+    /// and it overrides `patterns` either way.
+    attributes: Vec<(GlobMatcher, bool)>,
+}
+
+impl GeneratedFiles {
+    /// Builds a matcher from `patterns`, plus the `.gitattributes` at
+    /// `repo_root` when there is one. Invalid patterns are skipped.
+    pub fn new(patterns: &[String], repo_root: Option<&Path>) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            match glob_for(pattern) {
+                Some(glob) => {
+                    builder.add(glob);
+                }
+                None => log::warn!("Ignoring invalid generated-file pattern: {}", pattern),
+            }
+        }
+        let patterns = builder.build().unwrap_or_else(|err| {
+            log::warn!("Ignoring generated-file patterns: {}", err);
+            GlobSet::empty()
+        });
+
+        let attributes = repo_root
+            .and_then(|root| std::fs::read_to_string(root.join(".gitattributes")).ok())
+            .map(|text| parse_gitattributes(&text))
+            .unwrap_or_default();
+
+        Self {
+            patterns,
+            attributes,
+        }
+    }
+
+    /// Matcher for the patterns in the app config (or the defaults).
+    pub fn from_config(repo_root: Option<&Path>) -> Self {
+        Self::new(&configured_patterns(), repo_root)
+    }
+
+    pub fn is_generated(&self, path: &str) -> bool {
+        self.attributes
+            .iter()
+            .rev()
+            .find(|(matcher, _)| matcher.is_match(path))
+            .map_or_else(|| self.patterns.is_match(path), |(_, generated)| *generated)
+    }
+}
+
+/// The user's generated-file patterns, or the defaults when none are saved.
+pub fn configured_patterns() -> Vec<String> {
+    crate::infra::app_config::load_config()
+        .generated_file_patterns
+        .unwrap_or_else(|| {
+            DEFAULT_GENERATED_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect()
+        })
+}
+
+/// Compiles a `.gitattributes`-style pattern: one without a slash matches at
+/// any depth, a leading slash anchors it to the root, and a trailing slash
+/// matches everything under that directory.
+fn glob_for(pattern: &str) -> Option<globset::Glob> {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return None;
+    }
+    let mut glob = match pattern.strip_prefix('/') {
+        Some(anchored) => anchored.to_string(),
+        None if !pattern.trim_end_matches('/').contains('/') => format!("**/{}", pattern),
+        None => pattern.to_string(),
+    };
+    if glob.ends_with('/') {
+        glob.push_str("**");
+    }
+    GlobBuilder::new(&glob).literal_separator(true).build().ok()
+}
+
+fn parse_gitattributes(text: &str) -> Vec<(GlobMatcher, bool)> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let pattern = fields.next()?;
+            let generated = fields.filter_map(linguist_generated).last()?;
+            Some((glob_for(pattern)?.compile_matcher(), generated))
+        })
+        .collect()
+}
+
+/// Reads a `linguist-generated` attribute: set, unset (`-`), or given a value.
+fn linguist_generated(attribute: &str) -> Option<bool> {
+    match attribute {
+        "linguist-generated" | "linguist-generated=true" => Some(true),
+        "-linguist-generated" | "!linguist-generated" | "linguist-generated=false" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defaults() -> Vec<String> {
+        DEFAULT_GENERATED_PATTERNS
+            .iter()
+            .map(|pattern| pattern.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_default_patterns_match_lockfiles_and_build_output() {
+        let generated = GeneratedFiles::new(&defaults(), None);
+        assert!(generated.is_generated("Cargo.lock"));
+        assert!(generated.is_generated("frontend/package-lock.json"));
+        assert!(generated.is_generated("dist/app.js"));
+        assert!(!generated.is_generated("src/dist/mod.rs"));
+        assert!(!generated.is_generated("src/main.rs"));
+    }
+
+    #[test]
+    fn test_gitattributes_marks_and_unmarks_files() {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::write(
+            repo.path().join(".gitattributes"),
+            "# generated code\n\
+             src/gen/** linguist-generated=true\n\
+             src/gen/keep.rs -linguist-generated\n\
+             Cargo.lock linguist-generated=false\n\
+             *.rs text eol=lf\n",
+        )
+        .unwrap();
+
+        let generated = GeneratedFiles::new(&defaults(), Some(repo.path()));
+        assert!(generated.is_generated("src/gen/api.rs"));
+        assert!(!generated.is_generated("src/gen/keep.rs"));
+        assert!(!generated.is_generated("Cargo.lock"));
+        assert!(!generated.is_generated("src/lib.rs"));
+    }
+}
@@ -4,9 +4,11 @@
 //! The index allows for efficient querying of diff statistics and reconstruction of
 //! partial diffs based on `DiffRef` pointers.
 
+use super::submodule::{SubmoduleChange, parse_summary_line, subproject_commit};
 use crate::domain::{DiffRef, FeedbackSide, HunkRef};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::{BTreeMap, HashMap};
 use unidiff::{Hunk, PatchSet};
 
@@ -32,6 +34,10 @@ pub enum DiffIndexError {
     InvalidHunkId { file: String, hunk_id: String },
     /// Parse error when processing the diff
     Parse { message: String },
+    /// Nothing to review: the input is empty or only whitespace
+    EmptyInput,
+    /// The input has text but no unified diff in it, e.g. a pasted file
+    NotADiff,
     /// Nearest hunk information when a hunk is not found
     NearestHunk {
         file: String,
@@ -64,6 +70,17 @@ impl fmt::Display for DiffIndexError {
             DiffIndexError::Parse { message } => {
                 write!(f, "Parse error: {}", message)
             }
+            DiffIndexError::EmptyInput => {
+                write!(
+                    f,
+                    "The diff is empty. Paste the output of `git diff` to review it."
+                )
+            }
+            DiffIndexError::NotADiff => write!(
+                f,
+                "This doesn't look like a unified diff. Paste the output of `git diff` \
+                 (or `git show`) rather than a file or plain text."
+            ),
             DiffIndexError::NearestHunk {
                 file,
                 old_start,
@@ -133,6 +150,16 @@ impl DiffIndexError {
     }
 }
 
+/// A file whose new version still contains merge conflict markers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConflictedFile {
+    pub file_path: String,
+    /// New-side line of the first `<<<<<<<` marker.
+    pub line_number: u32,
+    /// Number of conflict blocks (opening markers) in the diff.
+    pub conflicts: usize,
+}
+
 /// A hunk with its coordinates and potentially an ID
 #[derive(Debug, Clone)]
 pub struct IndexedHunk {
@@ -154,22 +181,34 @@ pub struct FileIndex {
 #[derive(Debug, Clone)]
 pub struct DiffIndex {
     pub files: HashMap<String, FileIndex>,
+    /// Submodule pointer changes by path. Each is also in `files`, with its
+    /// `Subproject commit` hunk or, for summary lines, no hunks.
+    pub submodules: BTreeMap<String, SubmoduleChange>,
 }
 
 impl DiffIndex {
     /// Creates a new `DiffIndex` from a unified diff string.
     pub fn new(diff_text: &str) -> Result<Self> {
-        let trimmed = diff_text.trim();
+        let normalized = normalize_diff_text(diff_text);
+        let trimmed = normalized.trim();
         if trimmed.is_empty() {
             return Ok(Self {
                 files: HashMap::new(),
+                submodules: BTreeMap::new(),
             });
         }
 
         let mut patch_set = PatchSet::new();
-        patch_set.parse(trimmed)?;
+        patch_set.parse(trimmed).map_err(|err| {
+            if has_diff_markers(trimmed) {
+                anyhow::Error::from(err)
+            } else {
+                DiffIndexError::NotADiff.into()
+            }
+        })?;
 
         let mut files = HashMap::new();
+        let mut submodules = BTreeMap::new();
 
         for file in patch_set.files() {
             let mut file_path = file
@@ -194,10 +233,35 @@ impl DiffIndex {
                 hunks.insert(coords, indexed_hunk.clone());
                 all_hunks.push(indexed_hunk);
             }
+            if let Some(change) = submodule_change(file_path, &all_hunks) {
+                submodules.insert(file_path.to_string(), change);
+            }
             files.insert(file_path.to_string(), FileIndex { hunks, all_hunks });
         }
 
-        Ok(DiffIndex { files })
+        // `git diff --submodule` prints these instead of a hunk; without
+        // this the submodule would be missing from the diff entirely.
+        for change in trimmed.lines().filter_map(parse_summary_line) {
+            files
+                .entry(change.path.clone())
+                .or_insert_with(|| FileIndex {
+                    hunks: HashMap::new(),
+                    all_hunks: Vec::new(),
+                });
+            submodules.entry(change.path.clone()).or_insert(change);
+        }
+
+        // A pasted file or prose parses as a patch set with no files.
+        if files.is_empty() && !has_diff_markers(trimmed) {
+            return Err(DiffIndexError::NotADiff.into());
+        }
+
+        Ok(DiffIndex { files, submodules })
+    }
+
+    /// The pointer change for `file_path`, when it's a submodule.
+    pub fn submodule(&self, file_path: &str) -> Option<&SubmoduleChange> {
+        self.submodules.get(file_path)
     }
 
     /// Finds the hunk containing a specific line number.
@@ -880,6 +944,137 @@ impl DiffIndex {
         }
     }
 
+    /// A `DiffRef` covering every hunk of `file_path`, if the file is in the
+    /// diff.
+    pub fn file_diff_ref(&self, file_path: &str) -> Option<DiffRef> {
+        let file_index = self.files.get(file_path)?;
+        Some(DiffRef {
+            file: file_path.to_string(),
+            hunks: file_index
+                .all_hunks
+                .iter()
+                .map(|ih| HunkRef {
+                    old_start: ih.coords.0,
+                    old_lines: ih.hunk.source_length as u32,
+                    new_start: ih.coords.1,
+                    new_lines: ih.hunk.target_length as u32,
+                })
+                .collect(),
+        })
+    }
+
+    /// The unified diff for a single file, rebuilt from its hunks so an agent
+    /// can fetch one file instead of the whole diff.
+    pub fn file_diff_text(&self, file_path: &str) -> Option<String> {
+        let hunk_ids = self.get_hunk_ids_for_file(file_path);
+        if hunk_ids.is_empty() {
+            return None;
+        }
+
+        let mut diff = format!("diff --git a/{0} b/{0}\n--- a/{0}\n+++ b/{0}\n", file_path);
+        for hunk_id in hunk_ids {
+            let coords = self.get_hunk_coords(&hunk_id)?;
+            let content =
+                self.get_hunk_content_by_coords(file_path, coords.old_start, coords.new_start)?;
+            diff.push_str(&format!(
+                "@@ -{},{} +{},{} @@\n",
+                coords.old_start, coords.old_lines, coords.new_start, coords.new_lines
+            ));
+            diff.push_str(&content);
+        }
+        Some(diff)
+    }
+
+    /// Hash of what `file_path`'s hunks change, ignoring where they sit:
+    /// the same edit rebased onto a moved base hashes the same. Submodules
+    /// hash their commit range. None if the file isn't in the diff.
+    pub fn file_content_hash(&self, file_path: &str) -> Option<String> {
+        let file_index = self.files.get(file_path)?;
+        let mut content = String::new();
+        if let Some(change) = self.submodule(file_path) {
+            content.push_str(&format!(
+                "{:?}..{:?}\n",
+                change.old_commit, change.new_commit
+            ));
+        }
+        for indexed in &file_index.all_hunks {
+            let (old_start, new_start) = indexed.coords;
+            content.push_str(
+                &self
+                    .get_hunk_content_by_coords(file_path, old_start, new_start)
+                    .unwrap_or_default(),
+            );
+            content.push('\n');
+        }
+        Some(crate::infra::hash::hash_diff(&content))
+    }
+
+    /// Changed files with their (additions, deletions), largest change first.
+    pub fn file_change_stats(&self) -> Vec<(String, usize, usize)> {
+        let mut stats: Vec<_> = self
+            .files
+            .iter()
+            .filter(|(_, file_index)| !file_index.all_hunks.is_empty())
+            .map(|(file_path, file_index)| {
+                let (mut adds, mut dels) = (0, 0);
+                for indexed_hunk in &file_index.all_hunks {
+                    for line in indexed_hunk.hunk.lines() {
+                        match line.line_type.as_str() {
+                            unidiff::LINE_TYPE_ADDED => adds += 1,
+                            unidiff::LINE_TYPE_REMOVED => dels += 1,
+                            _ => {}
+                        }
+                    }
+                }
+                (file_path.clone(), adds, dels)
+            })
+            .collect();
+        stats.sort_by(|a, b| (b.1 + b.2).cmp(&(a.1 + a.2)).then_with(|| a.0.cmp(&b.0)));
+        stats
+    }
+
+    /// Files whose new version has unresolved merge conflict markers, sorted
+    /// by path. A file counts only when the diff shows both an opening
+    /// `<<<<<<<` and a closing `>>>>>>>` marker, so a lone `=======` (such as
+    /// a Markdown heading underline) isn't mistaken for a conflict.
+    pub fn conflicted_files(&self) -> Vec<ConflictedFile> {
+        let mut conflicted: Vec<ConflictedFile> = self
+            .files
+            .iter()
+            .filter_map(|(file_path, file_index)| {
+                let mut first_line = None;
+                let mut conflicts = 0;
+                let mut closed = false;
+                for indexed_hunk in &file_index.all_hunks {
+                    Self::walk_hunk_lines(
+                        &indexed_hunk.hunk,
+                        indexed_hunk.coords,
+                        |_, line, _, new_line| {
+                            let Some(new_line) = new_line else {
+                                return;
+                            };
+                            if is_conflict_marker(&line.value, "<<<<<<<") {
+                                conflicts += 1;
+                                first_line.get_or_insert(new_line);
+                            } else if is_conflict_marker(&line.value, ">>>>>>>") {
+                                closed = true;
+                            }
+                        },
+                    );
+                }
+                first_line
+                    .filter(|_| closed)
+                    .map(|line_number| ConflictedFile {
+                        file_path: file_path.clone(),
+                        line_number,
+                        conflicts,
+                    })
+            })
+            .collect();
+        conflicted.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+        conflicted
+    }
+
     /// Get all file paths in the diff.
     pub fn get_all_file_paths(&self) -> Vec<String> {
         self.files.keys().cloned().collect()
@@ -984,7 +1179,11 @@ impl DiffIndex {
         result.push_str(
             "- `search_diff { pattern: \"keyword\" }` - Search across all diff content\n",
         );
+        result.push_str(
+            "- `get_file_diff { file_path: \"path/file.rs\" }` - Get the plain unified diff for a file\n",
+        );
         result.push_str("- `list_diff_files` - List all changed files\n");
+        result.push_str("- `list_changed_files` - List changed paths with add/del counts only\n");
 
         result
     }
@@ -1117,6 +1316,74 @@ impl DiffIndex {
     }
 }
 
+/// The submodule change a file's hunks record, when every line in them is a
+/// `Subproject commit` line.
+fn submodule_change(file_path: &str, hunks: &[IndexedHunk]) -> Option<SubmoduleChange> {
+    let mut old_commit = None;
+    let mut new_commit = None;
+    let mut lines = hunks
+        .iter()
+        .flat_map(|indexed| indexed.hunk.lines())
+        .peekable();
+    lines.peek()?;
+    for line in lines {
+        let commit = subproject_commit(&line.value)?.to_string();
+        match line.line_type.as_str() {
+            unidiff::LINE_TYPE_REMOVED => old_commit = Some(commit),
+            unidiff::LINE_TYPE_ADDED => new_commit = Some(commit),
+            _ => {
+                old_commit = Some(commit.clone());
+                new_commit = Some(commit);
+            }
+        }
+    }
+    Some(SubmoduleChange {
+        path: file_path.to_string(),
+        old_commit,
+        new_commit,
+        link: None,
+    })
+}
+
+/// Whether `content` is a conflict marker line such as `<<<<<<< HEAD`.
+fn is_conflict_marker(content: &str, marker: &str) -> bool {
+    content
+        .trim_end_matches(['\r', '\n'])
+        .strip_prefix(marker)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with(' '))
+}
+
+/// True when `text` has a line only a diff would: a file header or a hunk
+/// header. Header-only diffs (mode changes, binary files) parse to no hunks
+/// but are still diffs.
+fn has_diff_markers(text: &str) -> bool {
+    text.lines().any(|line| {
+        line.starts_with("diff --git ")
+            || line.starts_with("@@ -")
+            || (line.starts_with("Binary files ") && line.ends_with(" differ"))
+            || line.starts_with("Submodule ")
+    })
+}
+
+/// Prepares a diff for parsing: CRLF line endings become LF, and
+/// `\ No newline at end of file` markers, which aren't hunk lines and would
+/// otherwise shift line numbers, are dropped.
+pub fn normalize_diff_text(diff_text: &str) -> Cow<'_, str> {
+    if !diff_text.contains('\r') && !diff_text.contains("\n\\") {
+        return Cow::Borrowed(diff_text);
+    }
+
+    let mut normalized = String::with_capacity(diff_text.len());
+    for line in diff_text.lines() {
+        if line.starts_with('\\') {
+            continue;
+        }
+        normalized.push_str(line);
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
+
 /// Helper function to find the nearest hunk to the given coordinates
 fn find_nearest_hunk(all_hunks: &[IndexedHunk], target: (u32, u32)) -> Option<(u32, u32)> {
     if all_hunks.is_empty() {
@@ -1166,6 +1433,75 @@ index 0000000..abcdefg
 +}
 "#;
 
+    const CRLF_NO_NEWLINE_DIFF: &str = "diff --git a/src/win.rs b/src/win.rs\r\n--- a/src/win.rs\r\n+++ b/src/win.rs\r\n@@ -1,2 +1,2 @@\r\n fn a() {}\r\n-fn b() {}\r\n\\ No newline at end of file\r\n+fn b() -> u8 { 1 }\r\n\\ No newline at end of file\r\n";
+
+    const NO_NEWLINE_DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1,2 +1,3 @@\n fn a() {}\n-fn b() {}\n\\ No newline at end of file\n+fn b() {}\n+fn c() {}\n\\ No newline at end of file";
+
+    #[test]
+    fn test_windows_diff_with_no_newline_markers() {
+        let index = DiffIndex::new(CRLF_NO_NEWLINE_DIFF).unwrap();
+        assert_eq!(index.total_stats(), (1, 1, 1, 1));
+
+        let lines = index.get_hunk_lines_with_numbers("src/win.rs#H1").unwrap();
+        let contents: Vec<_> = lines.iter().map(|l| l.content.as_str()).collect();
+        assert_eq!(
+            contents,
+            vec!["fn a() {}", "fn b() {}", "fn b() -> u8 { 1 }"]
+        );
+        assert_eq!(lines[2].new_line_number, Some(2));
+        assert!(lines.iter().all(|l| !l.content.contains('\r')));
+        assert!(index.line_exists_in_file("src/win.rs", 2, FeedbackSide::New));
+    }
+
+    #[test]
+    fn test_missing_trailing_newline_keeps_line_numbers() {
+        let index = DiffIndex::new(NO_NEWLINE_DIFF).unwrap();
+        assert_eq!(index.total_stats(), (1, 1, 2, 1));
+
+        let lines = index.get_hunk_lines_with_numbers("src/lib.rs#H1").unwrap();
+        assert_eq!(lines.len(), 4);
+        let last = lines.last().unwrap();
+        assert_eq!(last.content, "fn c() {}");
+        assert_eq!(last.new_line_number, Some(3));
+        assert!(
+            index
+                .find_position_in_diff("src/lib.rs", 3, FeedbackSide::New)
+                .is_some()
+        );
+    }
+
+    fn input_error(diff_text: &str) -> Option<DiffIndexError> {
+        DiffIndex::new(diff_text)
+            .err()
+            .and_then(|err| err.downcast::<DiffIndexError>().ok())
+    }
+
+    #[test]
+    fn test_full_file_or_prose_is_not_a_diff() {
+        let file = "fn main() {\n    println!(\"hi\");\n}\n";
+        assert!(matches!(input_error(file), Some(DiffIndexError::NotADiff)));
+        let prose = "Please review my change.\n--- \nThanks!\n";
+        assert!(matches!(input_error(prose), Some(DiffIndexError::NotADiff)));
+        assert!(DiffIndexError::NotADiff.to_string().contains("git diff"));
+
+        assert!(input_error(TEST_DIFF).is_none());
+        // Header-only diffs have no hunks but are still diffs.
+        let mode_change = "diff --git a/run.sh b/run.sh\nold mode 100644\nnew mode 100755\n";
+        assert!(!matches!(
+            input_error(mode_change),
+            Some(DiffIndexError::NotADiff | DiffIndexError::EmptyInput)
+        ));
+    }
+
+    #[test]
+    fn test_normalize_diff_text_borrows_clean_diffs() {
+        assert!(matches!(normalize_diff_text(TEST_DIFF), Cow::Borrowed(_)));
+        assert_eq!(
+            normalize_diff_text("+a\r\n\\ No newline at end of file\r\n"),
+            "+a\n"
+        );
+    }
+
     #[test]
     fn test_find_line_by_content() {
         let index = DiffIndex::new(TEST_DIFF).unwrap();
@@ -1211,6 +1547,23 @@ index 0000000..abcdefg
         assert!(manifest.contains("\"new_start\": 1"));
     }
 
+    #[test]
+    fn test_file_diff_ref_covers_every_hunk() {
+        let index = DiffIndex::new(TEST_DIFF).unwrap();
+        let diff_ref = index.file_diff_ref("src/lib.rs").unwrap();
+        assert_eq!(diff_ref.file, "src/lib.rs");
+        assert_eq!(
+            diff_ref.hunks,
+            vec![HunkRef {
+                old_start: 0,
+                old_lines: 0,
+                new_start: 1,
+                new_lines: 3,
+            }]
+        );
+        assert!(index.file_diff_ref("missing.rs").is_none());
+    }
+
     #[test]
     fn test_task_stats() {
         let index = DiffIndex::new(TEST_DIFF).unwrap();
@@ -1659,6 +2012,34 @@ another context
         assert!(manifest.contains("get_file_hunks"));
         assert!(manifest.contains("search_diff"));
         assert!(manifest.contains("list_diff_files"));
+        assert!(manifest.contains("get_file_diff"));
+        assert!(manifest.contains("list_changed_files"));
+    }
+
+    #[test]
+    fn test_file_diff_text_and_change_stats() {
+        let index = DiffIndex::new(TEST_DIFF).unwrap();
+
+        let diff = index.file_diff_text("src/lib.rs").unwrap();
+        assert!(diff.starts_with("diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n"));
+        assert!(diff.contains("@@ -0,0 +1,3 @@\n+pub fn add(a: i32, b: i32) -> i32 {\n"));
+        assert!(!diff.contains("src/main.rs"));
+        // The rebuilt diff parses back to the same file.
+        let reparsed = DiffIndex::new(&diff).unwrap();
+        assert_eq!(
+            reparsed.get_all_file_paths(),
+            vec!["src/lib.rs".to_string()]
+        );
+        assert!(index.file_diff_text("missing.rs").is_none());
+
+        let stats = index.file_change_stats();
+        assert_eq!(
+            stats,
+            vec![
+                ("src/lib.rs".to_string(), 3, 0),
+                ("src/main.rs".to_string(), 1, 1),
+            ]
+        );
     }
 
     #[test]
@@ -1697,4 +2078,119 @@ diff --git a/large.rs b/large.rs
             "large.rs should appear before small.rs in the manifest"
         );
     }
+
+    #[test]
+    fn test_conflicted_files_flags_unresolved_markers() {
+        let diff = r#"diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,7 @@
+ fn answer() -> u32 {
++<<<<<<< HEAD
+     41
++=======
++    42
++>>>>>>> feature
+ }
+diff --git a/README.md b/README.md
+--- a/README.md
++++ b/README.md
+@@ -1,1 +1,3 @@
+ Intro
++Title
++=======
+"#;
+        let index = DiffIndex::new(diff).unwrap();
+        assert_eq!(
+            index.conflicted_files(),
+            vec![ConflictedFile {
+                file_path: "src/lib.rs".to_string(),
+                line_number: 2,
+                conflicts: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_submodule_pointer_bump_is_kept_and_detected() {
+        let diff = "diff --git a/src/main.rs b/src/main.rs
+index 3b18e51..a2a3b4c 100644
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1 +1 @@
+-fn main() {}
++fn main() { run() }
+diff --git a/vendor/lib b/vendor/lib
+index 1234567..89abcde 160000
+--- a/vendor/lib
++++ b/vendor/lib
+@@ -1 +1 @@
+-Subproject commit 1234567890abcdef1234567890abcdef12345678
++Subproject commit 89abcdef1234567890abcdef1234567890abcdef
+diff --git a/third_party/new b/third_party/new
+new file mode 160000
+index 0000000..fedcba9
+--- /dev/null
++++ b/third_party/new
+@@ -0,0 +1 @@
++Subproject commit fedcba9876543210fedcba9876543210fedcba98
+";
+        let index = DiffIndex::new(diff).unwrap();
+        let mut paths = index.get_all_file_paths();
+        paths.sort();
+        assert_eq!(paths, ["src/main.rs", "third_party/new", "vendor/lib"]);
+        assert_eq!(index.get_hunk_ids_for_file("vendor/lib").len(), 1);
+
+        let bump = index.submodule("vendor/lib").unwrap();
+        assert_eq!(
+            bump.old_commit.as_deref(),
+            Some("1234567890abcdef1234567890abcdef12345678")
+        );
+        assert_eq!(
+            bump.new_commit.as_deref(),
+            Some("89abcdef1234567890abcdef1234567890abcdef")
+        );
+        let added = index.submodule("third_party/new").unwrap();
+        assert_eq!(added.old_commit, None);
+        assert!(index.submodule("src/main.rs").is_none());
+    }
+
+    #[test]
+    fn test_submodule_summary_lines_become_files() {
+        let diff = "Submodule vendor/lib 1234567..89abcde:
+  > Fix overflow in parser
+diff --git a/src/main.rs b/src/main.rs
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1 +1 @@
+-a
++b
+";
+        let index = DiffIndex::new(diff).unwrap();
+        assert!(index.get_hunk_ids_for_file("vendor/lib").is_empty());
+        assert!(
+            index
+                .get_all_file_paths()
+                .contains(&"vendor/lib".to_string())
+        );
+        assert_eq!(
+            index.submodule("vendor/lib").unwrap().new_commit.as_deref(),
+            Some("89abcde")
+        );
+    }
+
+    #[test]
+    fn test_minified_line_is_indexed_whole() {
+        let minified = "x".repeat(100_000);
+        let diff = format!(
+            "diff --git a/dist/app.min.js b/dist/app.min.js\n--- a/dist/app.min.js\n+++ b/dist/app.min.js\n@@ -1 +1 @@\n-old\n+{minified}\n"
+        );
+        let index = DiffIndex::new(&diff).unwrap();
+        assert_eq!(index.total_stats(), (1, 1, 1, 1));
+
+        let content = index
+            .get_hunk_content_by_coords("dist/app.min.js", 1, 1)
+            .unwrap();
+        assert!(content.contains(&format!("+{minified}")));
+    }
 }
@@ -1,3 +1,9 @@
+pub mod cache;
+pub mod generated;
 pub mod index;
+pub mod parsed;
 pub mod parser;
+pub mod reanchor;
+pub mod submodule;
+pub use cache::{cached_diff_index, cached_user_diff_index, clear_diff_index_cache};
 pub use parser::*;
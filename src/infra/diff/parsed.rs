@@ -0,0 +1,141 @@
+//! Serializable, per-file view of a unified diff.
+//!
+//! This is the shape the frontend renders and the shape library consumers get
+//! back from [`crate::parse_unified_diff`].
+
+use super::cache::cached_diff_index;
+use super::generated::GeneratedFiles;
+use super::index::ConflictedFile;
+use super::submodule::{SubmoduleChange, link_submodules};
+use crate::domain::ReviewSource;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A parsed unified diff with per-file hunks and totals.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedDiff {
+    pub diff_text: String,
+    pub total_additions: usize,
+    pub total_deletions: usize,
+    /// JSON manifest of hunk ids, as produced by
+    /// [`DiffIndex::generate_hunk_manifest_json`](super::index::DiffIndex::generate_hunk_manifest_json).
+    pub hunk_manifest: String,
+    #[serde(default)]
+    pub files: Vec<ParsedDiffFile>,
+    #[serde(default)]
+    pub source: Option<ReviewSource>,
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Author, branches and size of the pull request this diff was fetched from.
+    #[serde(default)]
+    pub pr_details: Option<crate::infra::vcs::traits::VcsPrDetails>,
+    /// Files that still contain merge conflict markers.
+    #[serde(default)]
+    pub conflicts: Vec<ConflictedFile>,
+}
+
+/// A single file within a [`ParsedDiff`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedDiffFile {
+    pub name: String,
+    pub old_path: String,
+    pub new_path: String,
+    pub hunks: Vec<ParsedHunk>,
+    /// Lockfile, build output or other generated file; see
+    /// [`ParsedDiff::mark_generated`].
+    #[serde(default)]
+    pub generated: bool,
+    /// Set when the file is a submodule whose commit changed; its hunk, if
+    /// any, is just the `Subproject commit` lines.
+    #[serde(default)]
+    pub submodule: Option<SubmoduleChange>,
+}
+
+/// A single hunk within a [`ParsedDiffFile`], including its raw content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedHunk {
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    #[serde(default)]
+    pub content: String,
+}
+
+impl ParsedDiff {
+    /// Parses a unified diff into its per-file representation.
+    pub fn from_diff_text(diff_text: &str) -> Result<Self> {
+        let index = cached_diff_index(diff_text)?;
+        let manifest = index.generate_hunk_manifest_json();
+        let file_paths = index.get_all_file_paths();
+
+        let (_, _, total_additions, total_deletions) = index.total_stats();
+
+        let files: Vec<ParsedDiffFile> = file_paths
+            .iter()
+            .map(|path| {
+                let hunk_ids = index.get_hunk_ids_for_file(path);
+                ParsedDiffFile {
+                    name: path.clone(),
+                    old_path: path.clone(),
+                    new_path: path.clone(),
+                    hunks: hunk_ids
+                        .iter()
+                        .filter_map(|hunk_id| {
+                            index.get_hunk_coords(hunk_id).map(|coords| {
+                                let content = index
+                                    .get_hunk_content_by_coords(
+                                        path,
+                                        coords.old_start,
+                                        coords.new_start,
+                                    )
+                                    .unwrap_or_default();
+
+                                ParsedHunk {
+                                    old_start: coords.old_start,
+                                    old_lines: coords.old_lines,
+                                    new_start: coords.new_start,
+                                    new_lines: coords.new_lines,
+                                    content,
+                                }
+                            })
+                        })
+                        .collect(),
+                    generated: false,
+                    submodule: index.submodule(path).cloned(),
+                }
+            })
+            .collect();
+
+        Ok(ParsedDiff {
+            diff_text: diff_text.to_string(),
+            total_additions,
+            total_deletions,
+            hunk_manifest: manifest,
+            files,
+            source: None,
+            title: None,
+            pr_details: None,
+            conflicts: index.conflicted_files(),
+        })
+    }
+
+    /// Links submodule changes to their commits, using the URLs in the
+    /// `.gitmodules` at `repo_root`.
+    pub fn link_submodules(&mut self, repo_root: Option<&Path>) {
+        link_submodules(
+            self.files
+                .iter_mut()
+                .filter_map(|file| file.submodule.as_mut()),
+            repo_root,
+        );
+    }
+
+    /// Flags the files `generated` recognises.
+    pub fn mark_generated(&mut self, generated: &GeneratedFiles) {
+        for file in &mut self.files {
+            file.generated = generated.is_generated(&file.new_path);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::index::normalize_diff_text;
 use std::collections::HashSet;
 
 pub fn normalize_task_path(path: &str) -> String {
@@ -32,3 +33,215 @@ pub fn extract_changed_files(diff_text: &str) -> HashSet<String> {
     }
     files
 }
+
+/// Joins several patches into one diff that parses as a whole.
+///
+/// Each patch is normalized and newline-terminated, so a patch without a
+/// trailing newline can't glue its last hunk line onto the next patch's
+/// `diff --git` header.
+pub fn combine_diffs<S: AsRef<str>>(diffs: &[S]) -> String {
+    let mut combined = String::new();
+    for diff in diffs {
+        let normalized = normalize_diff_text(diff.as_ref());
+        let diff = strip_patch_signature(normalized.trim_matches('\n'));
+        if diff.trim().is_empty() {
+            continue;
+        }
+        combined.push_str(diff);
+        combined.push('\n');
+    }
+    combined
+}
+
+/// Drops the `-- ` / git version trailer that `git format-patch` appends.
+fn strip_patch_signature(diff: &str) -> &str {
+    match diff.rfind("\n-- \n") {
+        Some(pos) if !diff[pos + 5..].contains('\n') => &diff[..pos],
+        _ => diff,
+    }
+}
+
+/// Reduces `diff` to the files whose old or new path is in `keep`.
+///
+/// Each file section is copied verbatim, git extended headers included, so
+/// the result is still a valid unified diff. Text before the first file
+/// (mail headers, a diffstat) and a `format-patch` trailer are dropped.
+pub fn filter_diff_by_paths(diff: &str, keep: &HashSet<String>) -> String {
+    let diff = strip_patch_signature(diff.trim_end_matches('\n'));
+
+    let mut filtered = String::new();
+    for section in file_sections(diff) {
+        if section_paths(&section)
+            .iter()
+            .any(|path| keep.contains(path))
+        {
+            for line in section {
+                filtered.push_str(line);
+                filtered.push('\n');
+            }
+        }
+    }
+    filtered
+}
+
+/// Splits a diff into per-file sections. A section starts at a
+/// `diff --git` line, or at a `---`/`+++` pair outside a hunk for plain
+/// unified diffs. Hunk line counts are tracked so removed lines that start
+/// with `-- ` aren't mistaken for headers.
+fn file_sections(diff: &str) -> Vec<Vec<&str>> {
+    let lines: Vec<&str> = diff.lines().collect();
+    let mut sections: Vec<Vec<&str>> = Vec::new();
+    let mut current: Option<Vec<&str>> = None;
+    // Old and new lines left in the current hunk.
+    let mut remaining = (0u32, 0u32);
+    // Whether the current git section still expects its `---`/`+++` pair.
+    let mut awaiting_headers = false;
+
+    for (i, line) in lines.iter().copied().enumerate() {
+        let in_hunk = remaining != (0, 0);
+        let starts_section = if in_hunk {
+            false
+        } else if line.starts_with("diff --git ") {
+            awaiting_headers = true;
+            true
+        } else if line.starts_with("--- ")
+            && lines
+                .get(i + 1)
+                .is_some_and(|next| next.starts_with("+++ "))
+        {
+            let starts = !awaiting_headers || current.is_none();
+            awaiting_headers = false;
+            starts
+        } else {
+            false
+        };
+
+        if starts_section {
+            sections.extend(current.take());
+            current = Some(Vec::new());
+        }
+        let Some(section) = current.as_mut() else {
+            continue;
+        };
+        section.push(line);
+
+        if in_hunk {
+            match line.as_bytes().first() {
+                Some(b'-') => remaining.0 = remaining.0.saturating_sub(1),
+                Some(b'+') => remaining.1 = remaining.1.saturating_sub(1),
+                Some(b'\\') => {}
+                _ => {
+                    remaining.0 = remaining.0.saturating_sub(1);
+                    remaining.1 = remaining.1.saturating_sub(1);
+                }
+            }
+        } else if let Some(counts) = hunk_line_counts(line) {
+            remaining = counts;
+        }
+    }
+    sections.extend(current);
+    sections
+}
+
+/// Old and new line counts from a `@@ -a,b +c,d @@` hunk header.
+fn hunk_line_counts(line: &str) -> Option<(u32, u32)> {
+    let ranges = line.strip_prefix("@@ ")?.split(" @@").next()?;
+    let mut parts = ranges.split_whitespace();
+    let count = |range: &str| -> Option<u32> {
+        match range.split_once(',') {
+            Some((_, count)) => count.parse().ok(),
+            None => Some(1),
+        }
+    };
+    let old = count(parts.next()?.strip_prefix('-')?)?;
+    let new = count(parts.next()?.strip_prefix('+')?)?;
+    Some((old, new))
+}
+
+/// Paths a file section touches, from its `---`/`+++` lines or, for
+/// sections without them (binary files, pure renames), its git header.
+fn section_paths(section: &[&str]) -> Vec<String> {
+    let mut paths: Vec<String> = section
+        .iter()
+        .take_while(|line| !line.starts_with("@@"))
+        .filter_map(|line| {
+            line.strip_prefix("--- ")
+                .or_else(|| line.strip_prefix("+++ "))
+                .or_else(|| line.strip_prefix("rename from "))
+                .or_else(|| line.strip_prefix("rename to "))
+        })
+        .map(|path| normalize_task_path(path.split('\t').next().unwrap_or(path)))
+        .filter(|path| path != "dev/null" && path != "/dev/null")
+        .collect();
+
+    if let Some(rest) = section
+        .first()
+        .and_then(|line| line.strip_prefix("diff --git "))
+    {
+        paths.extend(rest.split_whitespace().map(normalize_task_path));
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs
+index 1111111..2222222 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,3 @@
+ pub mod app;
+--- a/removed
++++ b/added
+ pub mod domain;
+diff --git a/vendor/dep.js b/vendor/dep.js
+deleted file mode 100644
+index 3333333..0000000
+--- a/vendor/dep.js
++++ /dev/null
+@@ -1 +0,0 @@
+-module.exports = {};
+diff --git a/logo.png b/logo.png
+new file mode 100644
+Binary files /dev/null and b/logo.png differ
+";
+
+    fn keep(paths: &[&str]) -> HashSet<String> {
+        paths.iter().map(|path| path.to_string()).collect()
+    }
+
+    #[test]
+    fn test_filter_diff_keeps_whole_sections() {
+        let filtered = filter_diff_by_paths(DIFF, &keep(&["src/lib.rs", "logo.png"]));
+        assert!(filtered.starts_with("diff --git a/src/lib.rs b/src/lib.rs\n"));
+        // Hunk lines that look like file headers stay with their hunk.
+        assert!(filtered.contains("--- a/removed\n+++ b/added\n pub mod domain;\n"));
+        assert!(filtered.ends_with("Binary files /dev/null and b/logo.png differ\n"));
+        assert!(!filtered.contains("vendor/dep.js"));
+        assert_eq!(
+            extract_changed_files(&filtered),
+            keep(&["src/lib.rs", "logo.png"])
+        );
+    }
+
+    #[test]
+    fn test_filter_diff_matches_deleted_files_by_old_path() {
+        let filtered = filter_diff_by_paths(DIFF, &keep(&["vendor/dep.js"]));
+        assert_eq!(extract_changed_files(&filtered), keep(&["vendor/dep.js"]));
+        assert!(filtered.contains("-module.exports = {};\n"));
+    }
+
+    #[test]
+    fn test_filter_plain_unified_diff() {
+        let diff = "--- a/one.txt\n+++ b/one.txt\n@@ -1 +1 @@\n-a\n+b\n\
+                    --- a/two.txt\n+++ b/two.txt\n@@ -1 +1 @@\n-c\n+d\n";
+        let filtered = filter_diff_by_paths(diff, &keep(&["two.txt"]));
+        assert_eq!(
+            filtered,
+            "--- a/two.txt\n+++ b/two.txt\n@@ -1 +1 @@\n-c\n+d\n"
+        );
+        assert!(filter_diff_by_paths(diff, &keep(&[])).is_empty());
+    }
+}
@@ -0,0 +1,266 @@
+//! Re-resolving feedback anchors after a review's diff changes.
+//!
+//! An anchor stores a line number and the hunk it was placed in. When a review
+//! is re-generated against a slightly different diff those numbers can point
+//! at the wrong line, so the anchored line is looked up again by its content
+//! and the lines around it, taken from the diff the anchor was placed on.
+
+use super::index::{DiffIndex, IndexedHunk};
+use crate::domain::{FeedbackAnchor, FeedbackSide, HunkRef};
+
+/// Lines compared on each side of the anchored line when scoring a match.
+const CONTEXT_LINES: usize = 3;
+
+/// Outcome of re-resolving one anchor against a review's current diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reanchored {
+    /// The anchor still points at its line (or has nothing to re-resolve).
+    Unchanged,
+    /// The line moved; the anchor updated to its new position.
+    Moved(FeedbackAnchor),
+    /// The line can no longer be found in the diff.
+    Outdated,
+}
+
+/// A hunk's lines as seen from one side of the diff.
+struct SideLines {
+    hunk_ref: HunkRef,
+    /// `(line number, content)`, in hunk order.
+    lines: Vec<(u32, String)>,
+}
+
+/// Re-resolves `anchor` against the review's current diff, `target`, whose
+/// hash is `target_hash`. `runs` are the review's run diffs by hash, newest
+/// first, one of which the anchor was placed on.
+///
+/// Anchors without a recorded diff that still resolve in `target` are
+/// assumed to belong to it. Anchors already marked outdated are left alone.
+pub fn reanchor(
+    anchor: &FeedbackAnchor,
+    target_hash: &str,
+    target: &DiffIndex,
+    runs: &[(&str, &DiffIndex)],
+) -> Reanchored {
+    let (Some(file_path), Some(line_number), Some(side)) =
+        (anchor.file_path.as_deref(), anchor.line_number, anchor.side)
+    else {
+        return Reanchored::Unchanged;
+    };
+    if anchor.outdated || anchor.diff_hash.as_deref() == Some(target_hash) {
+        return Reanchored::Unchanged;
+    }
+    let in_target = || locate(target, file_path, line_number, side, anchor).is_some();
+
+    let origin = match anchor.diff_hash.as_deref() {
+        Some(hash) => runs
+            .iter()
+            .find(|(run_hash, _)| *run_hash == hash)
+            .and_then(|(_, index)| locate(index, file_path, line_number, side, anchor)),
+        None if in_target() => return Reanchored::Unchanged,
+        None => runs
+            .iter()
+            .find_map(|(_, index)| locate(index, file_path, line_number, side, anchor)),
+    };
+    let Some((original, position)) = origin else {
+        return if in_target() {
+            Reanchored::Unchanged
+        } else {
+            Reanchored::Outdated
+        };
+    };
+    let content = &original.lines[position].1;
+
+    let Some(file_index) = target.files.get(file_path) else {
+        return Reanchored::Outdated;
+    };
+    let has_context = original.lines.len() > 1;
+    let best = file_index
+        .all_hunks
+        .iter()
+        .map(|hunk| side_lines(hunk, side))
+        .flat_map(|candidate| {
+            candidate
+                .lines
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, text))| text == content)
+                .map(|(idx, (number, _))| {
+                    let score = context_score(&original, position, &candidate, idx);
+                    let distance = number.abs_diff(line_number);
+                    (score, distance, *number, candidate.hunk_ref.clone())
+                })
+                .collect::<Vec<_>>()
+        })
+        // Without matching neighbours a common line like `}` is ambiguous.
+        .filter(|(score, ..)| *score > 0 || !has_context)
+        .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    match best {
+        Some((_, _, number, hunk_ref))
+            if number == line_number && anchor.hunk_ref.as_ref() == Some(&hunk_ref) =>
+        {
+            Reanchored::Unchanged
+        }
+        Some((_, _, number, hunk_ref)) => Reanchored::Moved(FeedbackAnchor {
+            line_number: Some(number),
+            hunk_ref: Some(hunk_ref),
+            diff_hash: Some(target_hash.to_string()),
+            outdated: false,
+            ..anchor.clone()
+        }),
+        None => Reanchored::Outdated,
+    }
+}
+
+/// Finds the anchored line in `index`: in its recorded hunk when it has one
+/// (which must match exactly), otherwise in whichever hunk covers the line.
+fn locate(
+    index: &DiffIndex,
+    file_path: &str,
+    line_number: u32,
+    side: FeedbackSide,
+    anchor: &FeedbackAnchor,
+) -> Option<(SideLines, usize)> {
+    let hunk = match &anchor.hunk_ref {
+        Some(hunk_ref) => index
+            .files
+            .get(file_path)?
+            .hunks
+            .get(&(hunk_ref.old_start, hunk_ref.new_start))
+            .filter(|hunk| hunk_ref_of(hunk) == *hunk_ref)?,
+        None => index.find_hunk_at_line(file_path, line_number, side)?,
+    };
+    let lines = side_lines(hunk, side);
+    let position = lines
+        .lines
+        .iter()
+        .position(|(number, _)| *number == line_number)?;
+    Some((lines, position))
+}
+
+fn side_lines(hunk: &IndexedHunk, side: FeedbackSide) -> SideLines {
+    let mut lines = Vec::new();
+    DiffIndex::walk_hunk_lines(&hunk.hunk, hunk.coords, |_, line, old_num, new_num| {
+        let number = match side {
+            FeedbackSide::Old => old_num,
+            FeedbackSide::New => new_num,
+        };
+        if let Some(number) = number {
+            lines.push((number, line.value.trim_end().to_string()));
+        }
+    });
+    SideLines {
+        hunk_ref: hunk_ref_of(hunk),
+        lines,
+    }
+}
+
+fn hunk_ref_of(hunk: &IndexedHunk) -> HunkRef {
+    HunkRef {
+        old_start: hunk.coords.0,
+        old_lines: hunk.hunk.source_length as u32,
+        new_start: hunk.coords.1,
+        new_lines: hunk.hunk.target_length as u32,
+    }
+}
+
+/// Number of neighbouring lines, up to [`CONTEXT_LINES`] on each side, that
+/// are the same around both positions.
+fn context_score(original: &SideLines, at: usize, candidate: &SideLines, idx: usize) -> usize {
+    let content = |lines: &SideLines, i: Option<usize>| {
+        i.and_then(|i| lines.lines.get(i))
+            .map(|(_, text)| text.as_str())
+    };
+    (1..=CONTEXT_LINES)
+        .flat_map(|offset| {
+            [
+                (at.checked_sub(offset), idx.checked_sub(offset)),
+                (Some(at + offset), Some(idx + offset)),
+            ]
+        })
+        .filter(|(a, b)| {
+            let a = content(original, *a);
+            a.is_some() && a == content(candidate, *b)
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIGINAL: &str = "diff --git a/src/app.rs b/src/app.rs\n--- a/src/app.rs\n+++ b/src/app.rs\n@@ -1,4 +1,5 @@\n fn setup() {\n     let config = load();\n+    validate(&config);\n     run(config);\n }\n";
+
+    const LINE_INSERTED_ABOVE: &str = "diff --git a/src/app.rs b/src/app.rs\n--- a/src/app.rs\n+++ b/src/app.rs\n@@ -1,4 +1,6 @@\n fn setup() {\n+    init_logging();\n     let config = load();\n+    validate(&config);\n     run(config);\n }\n";
+
+    /// Same hunk header as `ORIGINAL`, but the anchored line is gone.
+    const LINE_DROPPED: &str = "diff --git a/src/app.rs b/src/app.rs\n--- a/src/app.rs\n+++ b/src/app.rs\n@@ -1,4 +1,5 @@\n fn setup() {\n+    init_logging();\n     let config = load();\n     run(config);\n }\n";
+
+    fn anchor() -> FeedbackAnchor {
+        FeedbackAnchor {
+            file_path: Some("src/app.rs".into()),
+            line_number: Some(3),
+            side: Some(FeedbackSide::New),
+            hunk_ref: Some(HunkRef {
+                old_start: 1,
+                old_lines: 4,
+                new_start: 1,
+                new_lines: 5,
+            }),
+            head_sha: None,
+            diff_hash: Some("original".into()),
+            outdated: false,
+        }
+    }
+
+    #[test]
+    fn test_follows_a_line_inserted_above_the_anchor() {
+        let original = DiffIndex::new(ORIGINAL).unwrap();
+        let target = DiffIndex::new(LINE_INSERTED_ABOVE).unwrap();
+
+        assert_eq!(
+            reanchor(&anchor(), "target", &target, &[("original", &original)]),
+            Reanchored::Moved(FeedbackAnchor {
+                line_number: Some(4),
+                hunk_ref: Some(HunkRef {
+                    old_start: 1,
+                    old_lines: 4,
+                    new_start: 1,
+                    new_lines: 6,
+                }),
+                diff_hash: Some("target".into()),
+                ..anchor()
+            })
+        );
+    }
+
+    #[test]
+    fn test_anchor_on_the_current_diff_is_unchanged() {
+        let original = DiffIndex::new(ORIGINAL).unwrap();
+        let runs = [("original", &original)];
+        assert_eq!(
+            reanchor(&anchor(), "original", &original, &runs),
+            Reanchored::Unchanged
+        );
+
+        // Legacy anchors without a diff hash that still resolve stay put.
+        let legacy = FeedbackAnchor {
+            diff_hash: None,
+            ..anchor()
+        };
+        assert_eq!(
+            reanchor(&legacy, "original", &original, &runs),
+            Reanchored::Unchanged
+        );
+    }
+
+    #[test]
+    fn test_removed_line_is_outdated_even_with_the_same_hunk_header() {
+        let original = DiffIndex::new(ORIGINAL).unwrap();
+        let target = DiffIndex::new(LINE_DROPPED).unwrap();
+        assert_eq!(
+            reanchor(&anchor(), "target", &target, &[("original", &original)]),
+            Reanchored::Outdated
+        );
+    }
+}
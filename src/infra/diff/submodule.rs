@@ -0,0 +1,229 @@
+//! Submodule pointer bumps in a diff.
+//!
+//! A submodule only appears in a diff as the commit its gitlink points to:
+//! a one-line hunk of `Subproject commit <sha>` lines, or, with
+//! `git diff --submodule`, a `Submodule <path> <old>..<new>` summary line
+//! with no hunk at all. Neither carries the submodule's own code, so these
+//! files are shown as a pointer change instead of a text diff.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+const SUBPROJECT_PREFIX: &str = "Subproject commit ";
+const SUMMARY_PREFIX: &str = "Submodule ";
+
+/// A submodule whose recorded commit changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmoduleChange {
+    pub path: String,
+    /// None when the diff adds the submodule.
+    pub old_commit: Option<String>,
+    /// None when the diff removes the submodule.
+    pub new_commit: Option<String>,
+    /// Page comparing the two commits; set by [`link_submodules`] when
+    /// `.gitmodules` has a URL for a host we know how to link to.
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
+/// The commit in a `Subproject commit <sha>` hunk line. Git appends
+/// `-dirty` for a modified work tree, which isn't part of the commit.
+pub(super) fn subproject_commit(line: &str) -> Option<&str> {
+    let rest = line.trim_end().strip_prefix(SUBPROJECT_PREFIX)?;
+    let sha = rest.strip_suffix("-dirty").unwrap_or(rest);
+    is_commit(sha).then_some(sha)
+}
+
+/// Parses a `Submodule <path> <old>..<new>:` summary line, as printed by
+/// `git diff --submodule=log`. Added and removed submodules use `...` and
+/// an all-zero commit on the missing side.
+pub(super) fn parse_summary_line(line: &str) -> Option<SubmoduleChange> {
+    let mut parts = line.strip_prefix(SUMMARY_PREFIX)?.split_whitespace();
+    let path = parts.next()?;
+    let range = parts.next()?.trim_end_matches(':');
+    let (old, new) = range.split_once("...").or_else(|| range.split_once(".."))?;
+    if !is_commit(old) || !is_commit(new) {
+        return None;
+    }
+    let side = |sha: &str| (!sha.bytes().all(|b| b == b'0')).then(|| sha.to_string());
+    Some(SubmoduleChange {
+        path: path.to_string(),
+        old_commit: side(old),
+        new_commit: side(new),
+        link: None,
+    })
+}
+
+/// Fills in [`SubmoduleChange::link`] from the `.gitmodules` at `repo_root`.
+pub fn link_submodules<'a>(
+    changes: impl IntoIterator<Item = &'a mut SubmoduleChange>,
+    repo_root: Option<&Path>,
+) {
+    let Some(text) =
+        repo_root.and_then(|root| std::fs::read_to_string(root.join(".gitmodules")).ok())
+    else {
+        return;
+    };
+    let urls = parse_gitmodules(&text);
+    for change in changes {
+        change.link = urls
+            .get(&change.path)
+            .and_then(|url| compare_link(url, change));
+    }
+}
+
+/// Submodule URLs by path.
+fn parse_gitmodules(text: &str) -> HashMap<String, String> {
+    let mut urls = HashMap::new();
+    let mut path = None;
+    let mut url = None;
+    let mut flush = |path: &mut Option<String>, url: &mut Option<String>| {
+        if let (Some(path), Some(url)) = (path.take(), url.take()) {
+            urls.insert(path, url);
+        }
+    };
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('[') {
+            flush(&mut path, &mut url);
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        match key.trim() {
+            "path" => path = Some(value.trim().to_string()),
+            "url" => url = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+    flush(&mut path, &mut url);
+    urls
+}
+
+/// The web page for `change` on the submodule's host: a compare view when
+/// both commits are known, else the commit that's there. None for remotes
+/// that aren't on a web host (relative URLs, local paths).
+fn compare_link(url: &str, change: &SubmoduleChange) -> Option<String> {
+    let web = web_url(url)?;
+    let gitlab = web.contains("gitlab");
+    match (&change.old_commit, &change.new_commit) {
+        (Some(old), Some(new)) if gitlab => Some(format!("{web}/-/compare/{old}...{new}")),
+        (Some(old), Some(new)) => Some(format!("{web}/compare/{old}...{new}")),
+        (_, Some(commit)) | (Some(commit), None) if gitlab => {
+            Some(format!("{web}/-/commit/{commit}"))
+        }
+        (_, Some(commit)) | (Some(commit), None) => Some(format!("{web}/commit/{commit}")),
+        (None, None) => None,
+    }
+}
+
+/// `https://host/owner/repo` for an https or scp-style SSH remote.
+fn web_url(url: &str) -> Option<String> {
+    let url = url.trim().trim_end_matches('/');
+    let url = url.strip_suffix(".git").unwrap_or(url);
+    if let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    {
+        return Some(format!("https://{rest}"));
+    }
+    if let Some(rest) = url.strip_prefix("ssh://") {
+        let rest = rest.split_once('@').map_or(rest, |(_, host)| host);
+        let (host, path) = rest.split_once('/')?;
+        let host = host.split(':').next()?;
+        return Some(format!("https://{host}/{path}"));
+    }
+    let (user_host, path) = url.split_once(':')?;
+    let host = user_host
+        .split_once('@')
+        .map_or(user_host, |(_, host)| host);
+    if host.is_empty() || host.contains('/') || path.starts_with('/') {
+        return None;
+    }
+    Some(format!("https://{host}/{path}"))
+}
+
+fn is_commit(sha: &str) -> bool {
+    (7..=64).contains(&sha.len()) && sha.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subproject_commit() {
+        let sha = "89abcdef1234567890abcdef1234567890abcdef";
+        assert_eq!(
+            subproject_commit(&format!("Subproject commit {sha}")),
+            Some(sha)
+        );
+        assert_eq!(
+            subproject_commit(&format!("Subproject commit {sha}-dirty")),
+            Some(sha)
+        );
+        assert_eq!(subproject_commit("Subproject commit later"), None);
+        assert_eq!(subproject_commit("fn main() {}"), None);
+    }
+
+    #[test]
+    fn test_parse_summary_line() {
+        let change = parse_summary_line("Submodule vendor/lib 1234567..89abcde:").unwrap();
+        assert_eq!(change.path, "vendor/lib");
+        assert_eq!(change.old_commit.as_deref(), Some("1234567"));
+        assert_eq!(change.new_commit.as_deref(), Some("89abcde"));
+
+        let added =
+            parse_summary_line("Submodule vendor/lib 0000000...89abcde (new submodule)").unwrap();
+        assert_eq!(added.old_commit, None);
+        assert_eq!(added.new_commit.as_deref(), Some("89abcde"));
+
+        assert!(parse_summary_line("Submodule vendor/lib contains modified content").is_none());
+    }
+
+    #[test]
+    fn test_link_submodules_reads_gitmodules() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".gitmodules"),
+            "[submodule \"lib\"]\n\tpath = vendor/lib\n\turl = git@github.com:acme/lib.git\n\
+             [submodule \"docs\"]\n\tpath = docs\n\turl = ../docs\n",
+        )
+        .unwrap();
+        let mut changes = vec![
+            SubmoduleChange {
+                path: "vendor/lib".into(),
+                old_commit: Some("1234567".into()),
+                new_commit: Some("89abcde".into()),
+                link: None,
+            },
+            SubmoduleChange {
+                path: "docs".into(),
+                old_commit: None,
+                new_commit: Some("89abcde".into()),
+                link: None,
+            },
+        ];
+        link_submodules(&mut changes, Some(dir.path()));
+        assert_eq!(
+            changes[0].link.as_deref(),
+            Some("https://github.com/acme/lib/compare/1234567...89abcde")
+        );
+        assert_eq!(changes[1].link, None);
+    }
+
+    #[test]
+    fn test_web_url() {
+        assert_eq!(
+            web_url("https://gitlab.com/acme/lib.git").as_deref(),
+            Some("https://gitlab.com/acme/lib")
+        );
+        assert_eq!(
+            web_url("ssh://git@github.com:22/acme/lib.git").as_deref(),
+            Some("https://github.com/acme/lib")
+        );
+        assert_eq!(web_url("../lib"), None);
+        assert_eq!(web_url("/srv/git/lib"), None);
+    }
+}
@@ -0,0 +1,109 @@
+//! Scratch copy of the generate view's input.
+//!
+//! A large pasted diff only lives in the webview until a generation starts,
+//! so a crash or an accidental quit would lose it. The view saves what's
+//! being composed here and restores it on launch; the file is removed once a
+//! generation starts.
+
+use crate::infra::app_config::app_data_dir;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const DRAFT_FILE_NAME: &str = "generate_draft.json";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenerateDraft {
+    pub diff_text: String,
+    #[serde(default)]
+    pub agent_id: Option<String>,
+    #[serde(default)]
+    pub repo_id: Option<String>,
+    /// PR/MR link the diff was fetched from.
+    #[serde(default)]
+    pub pr_ref: Option<String>,
+}
+
+fn draft_path() -> PathBuf {
+    app_data_dir().join(DRAFT_FILE_NAME)
+}
+
+pub fn save_draft(draft: &GenerateDraft) -> Result<()> {
+    save_draft_at(&draft_path(), draft)
+}
+
+pub fn load_draft() -> Option<GenerateDraft> {
+    load_draft_at(&draft_path())
+}
+
+pub fn clear_draft() -> Result<()> {
+    clear_draft_at(&draft_path())
+}
+
+/// Writes through a temporary file so a crash mid-write can't leave a
+/// truncated draft behind.
+fn save_draft_at(path: &Path, draft: &GenerateDraft) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_vec(draft)?)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// None when there's no draft, or it can't be read.
+fn load_draft_at(path: &Path) -> Option<GenerateDraft> {
+    let contents = std::fs::read(path).ok()?;
+    match serde_json::from_slice::<GenerateDraft>(&contents) {
+        Ok(draft) if !draft.diff_text.trim().is_empty() => Some(draft),
+        Ok(_) => None,
+        Err(err) => {
+            log::warn!("Ignoring unreadable generate draft: {}", err);
+            None
+        }
+    }
+}
+
+fn clear_draft_at(path: &Path) -> Result<()> {
+    match std::fs::remove_file(path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draft_round_trip_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(DRAFT_FILE_NAME);
+        assert_eq!(load_draft_at(&path), None);
+
+        let draft = GenerateDraft {
+            diff_text: "diff --git a/a b/a\n".into(),
+            agent_id: Some("codex".into()),
+            repo_id: Some("repo-1".into()),
+            pr_ref: None,
+        };
+        save_draft_at(&path, &draft).unwrap();
+        assert_eq!(load_draft_at(&path), Some(draft));
+
+        clear_draft_at(&path).unwrap();
+        assert_eq!(load_draft_at(&path), None);
+        clear_draft_at(&path).unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_or_empty_draft_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DRAFT_FILE_NAME);
+        std::fs::write(&path, "{not json").unwrap();
+        assert_eq!(load_draft_at(&path), None);
+
+        save_draft_at(&path, &GenerateDraft::default()).unwrap();
+        assert_eq!(load_draft_at(&path), None);
+    }
+}
@@ -0,0 +1,173 @@
+//! Who the reviewer is, for attributing the feedback and comments they write
+//! in the app.
+//!
+//! The name comes from the `user_identity` setting, else git's global
+//! `user.name`, else the OS user name. Agent-written and imported items use
+//! `agent:<id>` and `import:<tool>` authors, so a name can never take either
+//! form.
+
+use crate::domain::AuthorKind;
+use crate::infra::app_config::UserIdentityConfig;
+use crate::infra::shell;
+use serde::Serialize;
+use std::process::Command;
+
+/// Author used when no name can be found.
+pub const FALLBACK_AUTHOR: &str = "user";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentitySource {
+    Settings,
+    Git,
+    Os,
+    Fallback,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UserIdentity {
+    pub name: String,
+    pub email: Option<String>,
+    pub source: IdentitySource,
+}
+
+impl UserIdentity {
+    /// Author recorded on feedback and comments: `Name <email>`, or just the
+    /// name without an email.
+    pub fn author(&self) -> String {
+        match &self.email {
+            Some(email) => format!("{} <{}>", self.name, email),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// The reviewer's identity given the `user_identity` setting.
+pub fn resolve(config: Option<&UserIdentityConfig>) -> UserIdentity {
+    resolve_with(config, || git_config("user.name"), os_user_name)
+}
+
+fn resolve_with(
+    config: Option<&UserIdentityConfig>,
+    git_name: impl FnOnce() -> Option<String>,
+    os_name: impl FnOnce() -> Option<String>,
+) -> UserIdentity {
+    if let Some(config) = config
+        && let Ok(name) = validate_name(&config.name)
+    {
+        return UserIdentity {
+            name,
+            email: clean(config.email.as_deref()),
+            source: IdentitySource::Settings,
+        };
+    }
+
+    let found = git_name()
+        .and_then(|name| validate_name(&name).ok())
+        .map(|name| (name, IdentitySource::Git))
+        .or_else(|| {
+            os_name()
+                .and_then(|name| validate_name(&name).ok())
+                .map(|name| (name, IdentitySource::Os))
+        });
+    match found {
+        Some((name, source)) => UserIdentity {
+            name,
+            email: None,
+            source,
+        },
+        None => UserIdentity {
+            name: FALLBACK_AUTHOR.to_string(),
+            email: None,
+            source: IdentitySource::Fallback,
+        },
+    }
+}
+
+/// Trims `name` and rejects names that are empty or would read as an agent
+/// or an import.
+pub fn validate_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Name cannot be empty".to_string());
+    }
+    if AuthorKind::of(name) != AuthorKind::User {
+        return Err(format!(
+            "\"{name}\" is reserved for agent or imported feedback"
+        ));
+    }
+    Ok(name.to_string())
+}
+
+fn clean(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn git_config(key: &str) -> Option<String> {
+    let git = shell::find_bin("git")?;
+    let output = Command::new(git)
+        .args(["config", "--global", "--get", key])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    clean(Some(&String::from_utf8_lossy(&output.stdout)))
+}
+
+fn os_user_name() -> Option<String> {
+    ["USER", "USERNAME"]
+        .iter()
+        .find_map(|var| clean(std::env::var(var).ok().as_deref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(name: &str, email: Option<&str>) -> UserIdentityConfig {
+        UserIdentityConfig {
+            name: name.to_string(),
+            email: email.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_setting_wins_then_git_then_os() {
+        let configured = config(" Ada Lovelace ", Some("ada@example.com"));
+        let identity = resolve_with(
+            Some(&configured),
+            || Some("git-ada".into()),
+            || Some("ada".into()),
+        );
+        assert_eq!(identity.source, IdentitySource::Settings);
+        assert_eq!(identity.author(), "Ada Lovelace <ada@example.com>");
+
+        let identity = resolve_with(None, || Some("Ada L".into()), || Some("ada".into()));
+        assert_eq!(identity.source, IdentitySource::Git);
+        assert_eq!(identity.author(), "Ada L");
+
+        let identity = resolve_with(None, || None, || Some("ada".into()));
+        assert_eq!(identity.source, IdentitySource::Os);
+
+        let identity = resolve_with(None, || None, || None);
+        assert_eq!(identity.author(), FALLBACK_AUTHOR);
+    }
+
+    #[test]
+    fn test_names_never_look_like_agents() {
+        assert!(validate_name("agent:claude").is_err());
+        assert!(validate_name("agent").is_err());
+        assert!(validate_name("import:github").is_err());
+        assert!(validate_name("   ").is_err());
+
+        // A configured name in agent form is ignored rather than used.
+        let spoofed = config("agent:claude", None);
+        let identity = resolve_with(Some(&spoofed), || Some("Ada".into()), || None);
+        assert_eq!(identity.author(), "Ada");
+        assert!(crate::domain::agent_id_of(&identity.author()).is_none());
+    }
+}
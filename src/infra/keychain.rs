@@ -0,0 +1,34 @@
+//! Secrets kept in the OS keychain, such as forge API tokens.
+//!
+//! Every entry lives under the `LaReview` service; the account names the
+//! secret, e.g. `gitea:codeberg.org`.
+
+use anyhow::Result;
+
+const SERVICE: &str = "LaReview";
+
+fn entry(account: &str) -> Result<keyring::Entry> {
+    Ok(keyring::Entry::new(SERVICE, account)?)
+}
+
+/// The secret stored for `account`, or None when there isn't one.
+pub fn get_secret(account: &str) -> Result<Option<String>> {
+    match entry(account)?.get_password() {
+        Ok(secret) => Ok(Some(secret)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+pub fn set_secret(account: &str, secret: &str) -> Result<()> {
+    entry(account)?.set_password(secret)?;
+    Ok(())
+}
+
+/// Removes the secret for `account`; removing a missing one is fine.
+pub fn delete_secret(account: &str) -> Result<()> {
+    match entry(account)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
@@ -4,14 +4,24 @@
 
 pub mod acp;
 pub mod app_config;
+pub mod automation;
+pub mod bug_report;
 pub mod cli;
+pub mod data_dir;
 pub mod db;
+pub mod diagnostics;
 pub mod diagram;
 pub mod diff;
+pub mod draft;
 pub mod editor;
 pub mod hash;
+pub mod identity;
+pub mod keychain;
+pub mod notification;
 pub mod platform;
 pub mod shell;
+pub mod symbols;
+pub mod syntax;
 pub mod vcs;
 
 /// Normalizes escaped or literal newlines to standard \n.
@@ -0,0 +1,109 @@
+//! Desktop notifications for finished background work.
+
+/// Longest error text shown in a notification body.
+const MAX_BODY_CHARS: usize = 180;
+
+/// Title and body of an OS notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notice {
+    pub title: String,
+    pub body: String,
+}
+
+/// What a successful generation produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerationCounts {
+    pub tasks: usize,
+    pub feedback: usize,
+}
+
+/// Notice for a generation that finished with `outcome`: what it produced on
+/// success, the error summary on failure. Cancelled runs get no notice, since
+/// the user stopped them on purpose.
+pub fn generation_notice(outcome: Result<GenerationCounts, &str>) -> Option<Notice> {
+    match outcome {
+        Ok(GenerationCounts {
+            tasks: 0,
+            feedback: 0,
+        }) => Some(Notice {
+            title: "Nothing to review".to_string(),
+            body: "The agent finished without tasks or feedback.".to_string(),
+        }),
+        Ok(GenerationCounts { tasks: 0, feedback }) => Some(Notice {
+            title: "Review ready".to_string(),
+            body: match feedback {
+                1 => "1 feedback item, no tasks.".to_string(),
+                n => format!("{} feedback items, no tasks.", n),
+            },
+        }),
+        Ok(GenerationCounts { tasks, .. }) => Some(Notice {
+            title: "Review ready".to_string(),
+            body: match tasks {
+                1 => "1 task generated.".to_string(),
+                n => format!("{} tasks generated.", n),
+            },
+        }),
+        Err(error) if error.contains("cancelled by user") => None,
+        Err(error) => Some(Notice {
+            title: "Review generation failed".to_string(),
+            body: truncate(error.trim(), MAX_BODY_CHARS),
+        }),
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max_chars - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_success_notice_reports_task_count() {
+        let counts = |tasks| GenerationCounts { tasks, feedback: 2 };
+        assert_eq!(
+            generation_notice(Ok(counts(1))).unwrap().body,
+            "1 task generated."
+        );
+        let notice = generation_notice(Ok(counts(4))).unwrap();
+        assert_eq!(notice.title, "Review ready");
+        assert_eq!(notice.body, "4 tasks generated.");
+    }
+
+    #[test]
+    fn test_feedback_only_run_is_a_ready_review() {
+        let notice = generation_notice(Ok(GenerationCounts {
+            tasks: 0,
+            feedback: 3,
+        }))
+        .unwrap();
+        assert_eq!(notice.title, "Review ready");
+        assert_eq!(notice.body, "3 feedback items, no tasks.");
+
+        let empty = generation_notice(Ok(GenerationCounts {
+            tasks: 0,
+            feedback: 0,
+        }))
+        .unwrap();
+        assert_eq!(empty.title, "Nothing to review");
+    }
+
+    #[test]
+    fn test_failure_notice_is_truncated() {
+        let notice = generation_notice(Err(&"x".repeat(500))).unwrap();
+        assert_eq!(notice.title, "Review generation failed");
+        assert_eq!(notice.body.chars().count(), MAX_BODY_CHARS);
+        assert!(notice.body.ends_with('…'));
+    }
+
+    #[test]
+    fn test_cancelled_runs_are_silent() {
+        assert!(generation_notice(Err("cancelled by user")).is_none());
+    }
+}
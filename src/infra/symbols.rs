@@ -0,0 +1,278 @@
+//! Enclosing functions and types of diff hunks, found with tree-sitter.
+//!
+//! In a big file, a list of line hunks says little about what changed. Mapping
+//! each hunk to the function, method or type around it lets the agent group a
+//! file's changes by symbol and read whole bodies instead of hunk fragments.
+//! Rust, TypeScript (and TSX) and Python are parsed; other files have no
+//! symbols.
+
+use crate::domain::HunkRef;
+use crate::infra::diff::index::DiffIndex;
+use serde::Serialize;
+use std::path::Path;
+use tree_sitter::{Language, Node, Parser};
+
+/// Files shorter than this are read whole; symbols only help for big ones.
+pub const LARGE_FILE_LINES: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    /// A function, method or function-valued binding.
+    Function,
+    /// A struct, enum, trait, class or interface.
+    Type,
+    /// A Rust `impl` block.
+    Impl,
+}
+
+/// A symbol enclosing changed lines.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SymbolSpan {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// First line of the symbol in the new file, 1-based.
+    pub start_line: u32,
+    /// Last line of the symbol in the new file, 1-based and inclusive.
+    pub end_line: u32,
+}
+
+/// The changed symbols of one file.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileSymbols {
+    pub path: String,
+    pub symbols: Vec<SymbolSpan>,
+}
+
+/// The innermost symbols of `source` (the new version of `path`) that each
+/// of `hunks` touches, in file order. A hunk inside a method maps to the
+/// method, not its `impl` or class; a hunk spanning two functions maps to
+/// both. Empty when the language isn't supported or the file doesn't parse.
+pub fn symbols_for_hunks(path: &str, source: &str, hunks: &[HunkRef]) -> Vec<SymbolSpan> {
+    let Some(language) = language_for_path(path) else {
+        return Vec::new();
+    };
+    let mut parser = Parser::new();
+    if parser.set_language(&language).is_err() {
+        return Vec::new();
+    }
+    let Some(tree) = parser.parse(source, None) else {
+        return Vec::new();
+    };
+
+    let mut all = Vec::new();
+    collect_symbols(tree.root_node(), source.as_bytes(), &mut all);
+
+    let mut touched: Vec<SymbolSpan> = Vec::new();
+    for hunk in hunks {
+        let start = hunk.new_start.max(1);
+        let end = start + hunk.new_lines.saturating_sub(1);
+        let overlapping: Vec<&SymbolSpan> = all
+            .iter()
+            .filter(|symbol| symbol.start_line <= end && symbol.end_line >= start)
+            .collect();
+        for symbol in &overlapping {
+            let encloses_another = overlapping.iter().any(|other| {
+                !std::ptr::eq(*other, *symbol)
+                    && symbol.start_line <= other.start_line
+                    && other.end_line <= symbol.end_line
+            });
+            if !encloses_another && !touched.contains(*symbol) {
+                touched.push((*symbol).clone());
+            }
+        }
+    }
+    touched.sort_by_key(|symbol| (symbol.start_line, symbol.end_line));
+    touched
+}
+
+/// Changed symbols of the large files in `index`, read from `repo_root`.
+/// Files that are small, missing from the checkout or in an unsupported
+/// language are left out.
+pub fn changed_symbols(index: &DiffIndex, repo_root: &Path) -> Vec<FileSymbols> {
+    let mut paths: Vec<&String> = index
+        .files
+        .keys()
+        .filter(|path| language_for_path(path).is_some())
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let source = std::fs::read_to_string(repo_root.join(path)).ok()?;
+            if source.lines().count() < LARGE_FILE_LINES {
+                return None;
+            }
+            let hunks = index.file_diff_ref(path)?.hunks;
+            let symbols = symbols_for_hunks(path, &source, &hunks);
+            (!symbols.is_empty()).then(|| FileSymbols {
+                path: path.clone(),
+                symbols,
+            })
+        })
+        .collect()
+}
+
+fn language_for_path(path: &str) -> Option<Language> {
+    let ext = Path::new(path).extension()?.to_str()?;
+    let language = match ext {
+        "rs" => tree_sitter_rust::LANGUAGE,
+        "ts" | "mts" | "cts" => tree_sitter_typescript::LANGUAGE_TYPESCRIPT,
+        "tsx" => tree_sitter_typescript::LANGUAGE_TSX,
+        "py" | "pyi" => tree_sitter_python::LANGUAGE,
+        _ => return None,
+    };
+    Some(language.into())
+}
+
+fn collect_symbols(node: Node<'_>, source: &[u8], out: &mut Vec<SymbolSpan>) {
+    if let Some((name, kind)) = symbol_of(node, source) {
+        let start = node.start_position();
+        let end = node.end_position();
+        // A node ending at column 0 stops at the end of the previous line.
+        let end_row = if end.column == 0 && end.row > start.row {
+            end.row - 1
+        } else {
+            end.row
+        };
+        out.push(SymbolSpan {
+            name,
+            kind,
+            start_line: start.row as u32 + 1,
+            end_line: end_row as u32 + 1,
+        });
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_symbols(child, source, out);
+    }
+}
+
+fn symbol_of(node: Node<'_>, source: &[u8]) -> Option<(String, SymbolKind)> {
+    let text = |field: &str| {
+        node.child_by_field_name(field)
+            .and_then(|child| child.utf8_text(source).ok())
+            .map(str::to_string)
+    };
+    match node.kind() {
+        // Rust
+        "function_item" | "function_signature_item" => Some((text("name")?, SymbolKind::Function)),
+        "struct_item" | "enum_item" | "union_item" | "trait_item" => {
+            Some((text("name")?, SymbolKind::Type))
+        }
+        "impl_item" => {
+            let name = match (text("trait"), text("type")) {
+                (Some(trait_name), Some(type_name)) => format!("{trait_name} for {type_name}"),
+                (None, Some(type_name)) => type_name,
+                _ => return None,
+            };
+            Some((name, SymbolKind::Impl))
+        }
+        // TypeScript
+        "function_declaration" | "generator_function_declaration" | "method_definition" => {
+            Some((text("name")?, SymbolKind::Function))
+        }
+        "class_declaration" | "abstract_class_declaration" | "interface_declaration" => {
+            Some((text("name")?, SymbolKind::Type))
+        }
+        // `const handler = () => {}` and `const f = function () {}`
+        "variable_declarator" => {
+            let value = node.child_by_field_name("value")?;
+            if !matches!(value.kind(), "arrow_function" | "function_expression") {
+                return None;
+            }
+            Some((text("name")?, SymbolKind::Function))
+        }
+        // Python
+        "function_definition" => Some((text("name")?, SymbolKind::Function)),
+        "class_definition" => Some((text("name")?, SymbolKind::Type)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hunk(new_start: u32, new_lines: u32) -> HunkRef {
+        HunkRef {
+            new_start,
+            new_lines,
+            ..Default::default()
+        }
+    }
+
+    fn names(symbols: &[SymbolSpan]) -> Vec<&str> {
+        symbols.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    const RUST: &str = "\
+struct Parser {
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> char {
+        ' '
+    }
+
+    fn bump(&mut self) {
+        self.pos += 1;
+    }
+}
+";
+
+    #[test]
+    fn test_rust_hunk_maps_to_enclosing_method() {
+        let symbols = symbols_for_hunks("src/parser.rs", RUST, &[hunk(11, 1)]);
+        assert_eq!(
+            symbols,
+            vec![SymbolSpan {
+                name: "bump".into(),
+                kind: SymbolKind::Function,
+                start_line: 10,
+                end_line: 12,
+            }]
+        );
+
+        // A hunk across both methods touches both; the `impl` isn't listed.
+        let symbols = symbols_for_hunks("src/parser.rs", RUST, &[hunk(7, 5)]);
+        assert_eq!(names(&symbols), vec!["peek", "bump"]);
+
+        // The `impl` header itself maps to the `impl`.
+        let symbols = symbols_for_hunks("src/parser.rs", RUST, &[hunk(5, 1)]);
+        assert_eq!(names(&symbols), vec!["Parser"]);
+        assert_eq!(symbols[0].kind, SymbolKind::Impl);
+    }
+
+    #[test]
+    fn test_typescript_and_python_symbols() {
+        let ts = "\
+export class Store {
+  load(id: string) {
+    return id;
+  }
+}
+
+const handler = () => {
+  console.log('hi');
+};
+";
+        let symbols = symbols_for_hunks("src/store.ts", ts, &[hunk(3, 1), hunk(8, 1)]);
+        assert_eq!(names(&symbols), vec!["load", "handler"]);
+
+        let py = "\
+class Cache:
+    def get(self, key):
+        return self.data[key]
+";
+        let symbols = symbols_for_hunks("cache.py", py, &[hunk(3, 1)]);
+        assert_eq!(names(&symbols), vec!["get"]);
+    }
+
+    #[test]
+    fn test_unsupported_languages_have_no_symbols() {
+        assert!(symbols_for_hunks("README.md", "# Title\n", &[hunk(1, 1)]).is_empty());
+    }
+}
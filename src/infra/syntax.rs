@@ -0,0 +1,187 @@
+//! Syntax highlighting for diff snippets and markdown code fences.
+//!
+//! Each file in a diff is highlighted with the grammar matching its own path,
+//! so a mixed-language PR doesn't render everything as one language. Code
+//! fences use the grammar named by their info string. The syntect grammar set
+//! and theme are compiled once and shared process-wide.
+
+use serde::Serialize;
+use std::path::Path;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Color, Theme, ThemeSet};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+
+/// Theme used for snippet colors; matches the app's dark palette.
+const THEME_NAME: &str = "base16-ocean.dark";
+
+lazy_static::lazy_static! {
+    static ref SYNTAX_SET: SyntaxSet = SyntaxSet::load_defaults_newlines();
+    static ref THEME: Theme = ThemeSet::load_defaults()
+        .themes
+        .remove(THEME_NAME)
+        .unwrap_or_default();
+}
+
+/// A run of text sharing a single foreground color.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HighlightSpan {
+    pub text: String,
+    /// CSS hex color (`#rrggbb`), or `None` to use the default text color.
+    pub color: Option<String>,
+}
+
+/// Resolves the grammar for a file path, falling back to plain text.
+///
+/// The extension is tried first; bare file names such as `Makefile` are
+/// matched against the grammars' file-name lists next.
+pub fn syntax_for_path(path: &str) -> &'static SyntaxReference {
+    let path = Path::new(path);
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| SYNTAX_SET.find_syntax_by_extension(ext))
+        .or_else(|| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| SYNTAX_SET.find_syntax_by_extension(name))
+        })
+        .unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text())
+}
+
+/// Resolves the grammar for a code fence language such as `rust`, `ts` or
+/// `Python`, falling back to plain text for unknown languages.
+pub fn syntax_for_language(language: &str) -> &'static SyntaxReference {
+    let language = language.trim();
+    if language.is_empty() {
+        return SYNTAX_SET.find_syntax_plain_text();
+    }
+    SYNTAX_SET
+        .find_syntax_by_token(language)
+        .unwrap_or_else(|| SYNTAX_SET.find_syntax_plain_text())
+}
+
+/// Highlights a code block line by line with the grammar for `language`.
+pub fn highlight_code(code: &str, language: &str) -> Vec<Vec<HighlightSpan>> {
+    let mut highlighter = FileHighlighter::new(syntax_for_language(language));
+    code.lines()
+        .map(|line| highlighter.highlight_line(line))
+        .collect()
+}
+
+/// Human-readable language name for a file path (e.g. "Rust", "Plain Text").
+pub fn language_for_path(path: &str) -> String {
+    syntax_for_path(path).name.clone()
+}
+
+/// Stateful highlighter for consecutive lines of one file.
+pub struct FileHighlighter {
+    inner: HighlightLines<'static>,
+    plain: bool,
+}
+
+impl FileHighlighter {
+    pub fn for_path(path: &str) -> Self {
+        Self::new(syntax_for_path(path))
+    }
+
+    fn new(syntax: &'static SyntaxReference) -> Self {
+        Self {
+            inner: HighlightLines::new(syntax, &THEME),
+            plain: syntax.name == SYNTAX_SET.find_syntax_plain_text().name,
+        }
+    }
+
+    /// Highlights one line (without its trailing newline).
+    pub fn highlight_line(&mut self, line: &str) -> Vec<HighlightSpan> {
+        if self.plain {
+            return vec![plain_span(line)];
+        }
+
+        let with_newline = format!("{}\n", line);
+        match self.inner.highlight_line(&with_newline, &SYNTAX_SET) {
+            Ok(ranges) => ranges
+                .into_iter()
+                .filter_map(|(style, text)| {
+                    let text = text.trim_end_matches('\n');
+                    if text.is_empty() {
+                        return None;
+                    }
+                    Some(HighlightSpan {
+                        text: text.to_string(),
+                        color: Some(hex_color(style.foreground)),
+                    })
+                })
+                .collect(),
+            Err(err) => {
+                log::debug!("Syntax highlighting failed, using plain text: {}", err);
+                vec![plain_span(line)]
+            }
+        }
+    }
+}
+
+fn plain_span(line: &str) -> HighlightSpan {
+    HighlightSpan {
+        text: line.to_string(),
+        color: None,
+    }
+}
+
+fn hex_color(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_follows_each_file_extension() {
+        assert_eq!(language_for_path("src/main.rs"), "Rust");
+        assert_eq!(language_for_path("scripts/build.py"), "Python");
+        assert_eq!(language_for_path("Makefile"), "Makefile");
+    }
+
+    #[test]
+    fn test_unknown_extension_falls_back_to_plain_text() {
+        assert_eq!(language_for_path("data/blob.unknownext"), "Plain Text");
+        assert_eq!(language_for_path("LICENSE"), "Plain Text");
+
+        let mut highlighter = FileHighlighter::for_path("notes.unknownext");
+        assert_eq!(
+            highlighter.highlight_line("fn main() {}"),
+            vec![HighlightSpan {
+                text: "fn main() {}".to_string(),
+                color: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_highlight_preserves_line_text() {
+        let mut highlighter = FileHighlighter::for_path("src/lib.rs");
+        let spans = highlighter.highlight_line("pub fn add(a: i32) -> i32 { a }");
+        let joined: String = spans.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(joined, "pub fn add(a: i32) -> i32 { a }");
+        assert!(spans.len() > 1);
+        assert!(spans.iter().all(|s| s.color.is_some()));
+    }
+
+    #[test]
+    fn test_code_fence_language_resolves_by_name_or_extension() {
+        assert_eq!(syntax_for_language("rust").name, "Rust");
+        assert_eq!(syntax_for_language("rs").name, "Rust");
+        assert_eq!(syntax_for_language("Python").name, "Python");
+        assert_eq!(syntax_for_language("not-a-language").name, "Plain Text");
+        assert_eq!(syntax_for_language("").name, "Plain Text");
+    }
+
+    #[test]
+    fn test_highlight_code_keeps_one_entry_per_line() {
+        let lines = highlight_code("fn a() {}\n\nfn b() {}", "rust");
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].iter().all(|s| s.color.is_some()));
+
+        let plain = highlight_code("some text", "unknown");
+        assert_eq!(plain, vec![vec![plain_span("some text")]]);
+    }
+}
@@ -0,0 +1,323 @@
+//! The `gh`/`glab` executables the VCS providers shell out to.
+//!
+//! Each provider finds its CLI on `PATH` unless the user picked one in
+//! Settings (`vcs_path_overrides`), which matters when several installs
+//! exist and only one is logged into the right host. A default host per
+//! provider (`vcs_default_hosts`) is used wherever a command has no host of
+//! its own, e.g. status checks and clones.
+
+use crate::infra::app_config::{AppConfig, load_config};
+use crate::infra::shell;
+use crate::infra::vcs::retry::{RateLimited, cli_error, wait_from_response, with_retry};
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::{Duration, SystemTime};
+use tokio::io::AsyncWriteExt;
+
+/// The CLI a provider drives.
+pub fn cli_command(provider_id: &str) -> &str {
+    match provider_id {
+        "github" => "gh",
+        "gitlab" => "glab",
+        other => other,
+    }
+}
+
+/// Resolves the provider's CLI: the configured path when there is one,
+/// otherwise the command found on `PATH`.
+pub fn resolve_cli(provider_id: &str) -> Option<PathBuf> {
+    resolve_cli_in(&load_config(), provider_id)
+}
+
+fn resolve_cli_in(config: &AppConfig, provider_id: &str) -> Option<PathBuf> {
+    // A configured path that doesn't exist is an error, not a reason to pick
+    // up a different install behind the user's back.
+    match configured_path(config, provider_id) {
+        Some(path) => shell::find_bin(path),
+        None => shell::find_bin(cli_command(provider_id)),
+    }
+}
+
+/// The path set in Settings for the provider's CLI, if any.
+pub fn configured_path<'a>(config: &'a AppConfig, provider_id: &str) -> Option<&'a str> {
+    config
+        .vcs_path_overrides
+        .get(provider_id)
+        .map(|path| path.trim())
+        .filter(|path| !path.is_empty())
+}
+
+/// The host set in Settings for commands that don't name one.
+pub fn default_host(provider_id: &str) -> Option<String> {
+    default_host_in(&load_config(), provider_id)
+}
+
+pub fn default_host_in(config: &AppConfig, provider_id: &str) -> Option<String> {
+    config
+        .vcs_default_hosts
+        .get(provider_id)
+        .map(|host| host.trim().trim_end_matches('/').to_lowercase())
+        .map(|host| {
+            host.trim_start_matches("https://")
+                .trim_start_matches("http://")
+                .to_string()
+        })
+        .filter(|host| !host.is_empty())
+}
+
+/// The status a provider reports when its CLI can't be resolved.
+pub fn missing_cli_error(config: &AppConfig, provider_id: &str) -> (String, String) {
+    let command = cli_command(provider_id);
+    match configured_path(config, provider_id) {
+        Some(path) => (
+            path.to_string(),
+            format!("Configured {} path not found: {}", command, path),
+        ),
+        None => (
+            format!("{} not found", command),
+            format!("{} executable not found in PATH", command),
+        ),
+    }
+}
+
+/// Runs the CLI at `program` with `args`, writing `input` to its stdin when
+/// given, and returns its stdout. `what` names the run in errors, e.g.
+/// "`gh pr diff`". Runs the host rate limits are tried again, see
+/// [`with_retry`]; `probe` is the CLI's `api --include` call that asks the
+/// host how long the limit lasts, since a failed run doesn't show it.
+pub(crate) async fn run_cli(
+    what: &str,
+    program: &Path,
+    args: &[String],
+    input: Option<&str>,
+    probe: &[String],
+) -> Result<String> {
+    with_retry(what, || run_cli_once(what, program, args, input, probe)).await
+}
+
+async fn run_cli_once(
+    what: &str,
+    program: &Path,
+    args: &[String],
+    input: Option<&str>,
+    probe: &[String],
+) -> Result<String> {
+    let mut child = tokio::process::Command::new(program)
+        .args(args)
+        .stdin(if input.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("run {what}"))?;
+
+    if let Some(input) = input
+        && let Some(mut stdin) = child.stdin.take()
+    {
+        stdin
+            .write_all(input.as_bytes())
+            .await
+            .with_context(|| format!("write payload to {what} stdin"))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .await
+        .with_context(|| format!("run {what}"))?;
+    if !output.status.success() {
+        let mut err = cli_error(what, &String::from_utf8_lossy(&output.stderr));
+        if let Some(limited) = err.downcast_mut::<RateLimited>() {
+            limited.wait = rate_limit_wait(program, probe).await;
+        }
+        return Err(err);
+    }
+    String::from_utf8(output.stdout).with_context(|| format!("decode {what} stdout"))
+}
+
+/// Runs `probe` and reads how long the host's rate limit lasts from the
+/// response; None when it can't tell.
+async fn rate_limit_wait(program: &Path, probe: &[String]) -> Option<Duration> {
+    if probe.is_empty() {
+        return None;
+    }
+    // Limited hosts may answer slowly; the probe mustn't outlive the run.
+    let output = tokio::process::Command::new(program)
+        .args(probe)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .kill_on_drop(true)
+        .output()
+        .await
+        .ok()?;
+    wait_from_response(&String::from_utf8_lossy(&output.stdout), SystemTime::now())
+}
+
+/// Runs `<cli> --version` and returns the first version-looking word, e.g.
+/// `2.81.0` from `gh version 2.81.0 (2025-10-01)`. Killed if the status
+/// check it's part of times out.
+pub(crate) async fn cli_version(path: &Path) -> Option<String> {
+    let output = tokio::process::Command::new(path)
+        .arg("--version")
+        .kill_on_drop(true)
+        .output()
+        .await
+        .ok()?;
+    parse_cli_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+fn parse_cli_version(output: &str) -> Option<String> {
+    output.lines().next()?.split_whitespace().find_map(|word| {
+        let word = word.trim_start_matches('v');
+        word.starts_with(|c: char| c.is_ascii_digit())
+            .then(|| word.to_string())
+    })
+}
+
+/// The credential source `gh`/`glab auth status` prints in parentheses after
+/// the account, e.g. `keyring` in `Logged in to github.com account me (keyring)`.
+pub(crate) fn parse_auth_method(line: &str) -> Option<String> {
+    let (_, rest) = line.rsplit_once('(')?;
+    let method = rest.split(')').next()?.trim();
+    (!method.is_empty()).then(|| method.to_string())
+}
+
+/// The lines of `auth status` output that explain a failure (`X Failed to
+/// log in ...`, `- The token ... is invalid.`), or the whole output when
+/// there are none.
+pub(crate) fn auth_failure(output: &str) -> String {
+    let lines: Vec<&str> = output
+        .lines()
+        .map(str::trim)
+        .filter(|line| {
+            line.starts_with("X ")
+                || line.starts_with("x ")
+                || line.starts_with("- ")
+                || line.starts_with("ERROR")
+        })
+        .collect();
+    if lines.is_empty() {
+        output.trim().to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_configured_path_wins_over_path_lookup() {
+        let dir = tempfile::tempdir().unwrap();
+        let gh = dir.path().join("gh");
+        std::fs::write(&gh, "").unwrap();
+        let config = AppConfig {
+            vcs_path_overrides: HashMap::from([(
+                "github".to_string(),
+                gh.to_string_lossy().into_owned(),
+            )]),
+            ..Default::default()
+        };
+        assert_eq!(resolve_cli_in(&config, "github"), Some(gh));
+    }
+
+    #[test]
+    fn test_missing_configured_path_is_not_replaced() {
+        let config = AppConfig {
+            vcs_path_overrides: HashMap::from([(
+                "gitlab".to_string(),
+                "/nonexistent/glab".to_string(),
+            )]),
+            ..Default::default()
+        };
+        assert_eq!(resolve_cli_in(&config, "gitlab"), None);
+        let (cli_path, error) = missing_cli_error(&config, "gitlab");
+        assert_eq!(cli_path, "/nonexistent/glab");
+        assert!(error.contains("Configured glab path not found"));
+
+        let (cli_path, _) = missing_cli_error(&AppConfig::default(), "gitlab");
+        assert_eq!(cli_path, "glab not found");
+    }
+
+    #[test]
+    fn test_default_host_is_normalized() {
+        let config = AppConfig {
+            vcs_default_hosts: HashMap::from([
+                (
+                    "github".to_string(),
+                    " https://GHE.Company.com/ ".to_string(),
+                ),
+                ("gitlab".to_string(), "  ".to_string()),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            default_host_in(&config, "github").as_deref(),
+            Some("ghe.company.com")
+        );
+        assert_eq!(default_host_in(&config, "gitlab"), None);
+    }
+
+    #[test]
+    fn test_parse_cli_version() {
+        let gh =
+            "gh version 2.81.0 (2025-10-01)\nhttps://github.com/cli/cli/releases/tag/v2.81.0\n";
+        assert_eq!(parse_cli_version(gh).as_deref(), Some("2.81.0"));
+        assert_eq!(
+            parse_cli_version("glab 1.46.1 (abc123)\n").as_deref(),
+            Some("1.46.1")
+        );
+        assert_eq!(
+            parse_cli_version("glab version v1.36.0\n").as_deref(),
+            Some("1.36.0")
+        );
+        assert_eq!(parse_cli_version(""), None);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_rate_limited_run_waits_for_the_reset_the_host_reports() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let reset = SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 3600;
+        let dir = tempfile::tempdir().unwrap();
+        let gh = dir.path().join("gh");
+        std::fs::write(
+            &gh,
+            format!(
+                "#!/bin/sh\n\
+                 if [ \"$1\" = api ]; then\n\
+                 printf 'HTTP/2.0 200 OK\\r\\n\\r\\n{{\"resources\":{{\"core\":{{\"remaining\":0,\"reset\":{reset}}}}}}}'\n\
+                 exit 0\n\
+                 fi\n\
+                 echo 'HTTP 403: API rate limit exceeded for user ID 1.' >&2\n\
+                 exit 1\n"
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&gh, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let probe = ["api", "--include", "rate_limit"].map(String::from);
+        let args = ["pr", "diff", "1"].map(String::from);
+        let err = run_cli("`gh pr diff`", &gh, &args, None, &probe)
+            .await
+            .unwrap_err()
+            .to_string();
+
+        // An hour away is past what's worth waiting for: fail at once with it.
+        assert!(
+            err.contains("`gh pr diff` is rate limited for another"),
+            "{err}"
+        );
+        assert!(!err.contains("in a minute"));
+    }
+}
@@ -0,0 +1,733 @@
+//! Gitea and Forgejo pull requests, through the instance's REST API.
+//!
+//! There's no CLI every instance can rely on, so requests go straight to
+//! `https://<host>/api/v1` with a personal access token. The token comes from
+//! the OS keychain, where Settings saves one per host, or from `GITEA_TOKEN`
+//! for the host set in Settings. Public repositories can be fetched without
+//! one.
+
+use crate::application::review::export::ReviewExporter;
+use crate::application::review::gh_review::{render_review_body, task_anchor};
+use crate::domain::{FeedbackSide, ReviewDecision, ReviewSource};
+use crate::infra::diff::index::DiffIndex;
+use crate::infra::keychain;
+use crate::infra::shell;
+use crate::infra::vcs::cli::default_host;
+use crate::infra::vcs::retry::{self, RateLimitHeaders, with_retry};
+use crate::infra::vcs::traits::{
+    FeedbackPushRequest, ReviewPushRequest, VcsCloneRequest, VcsCloneResult, VcsPrData,
+    VcsPrDetails, VcsPrSummary, VcsProvider, VcsRef, VcsRemoteRepo, VcsStatus,
+};
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
+use tokio::process::Command;
+
+/// Environment variable that overrides the keychain token for the host set
+/// in Settings. Other hosts never see it.
+pub const TOKEN_ENV: &str = "GITEA_TOKEN";
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Page size for listing pull requests; Gitea caps it at 50 by default.
+const PR_PAGE_LIMIT: usize = 50;
+
+#[derive(Debug, Clone)]
+pub struct GiteaPrRef {
+    pub host: String,
+    pub owner: String,
+    pub repo: String,
+    pub number: u32,
+    pub url: String,
+}
+
+lazy_static! {
+    static ref GITEA_PR_URL_RE: Regex = Regex::new(
+        r"^(?:https?://)?([^/\s]+)/([^/\s]+)/([^/\s]+)/pulls/(\d+)(?:/(?:files|commits))?/?(?:[?#]\S*)?$"
+    )
+    .expect("gitea pr url regex");
+}
+
+/// Parses `https://<host>/<owner>/<repo>/pulls/<n>`. Any host qualifies:
+/// Gitea and Forgejo are self-hosted, and the `/pulls/` path is theirs.
+pub fn parse_pr_ref(input: &str) -> Option<GiteaPrRef> {
+    let caps = GITEA_PR_URL_RE.captures(input.trim())?;
+    let host = caps.get(1)?.as_str().to_lowercase();
+    let owner = caps.get(2)?.as_str().to_string();
+    let repo = caps.get(3)?.as_str().trim_end_matches(".git").to_string();
+    let number: u32 = caps.get(4)?.as_str().parse().ok()?;
+    let url = format!("https://{host}/{owner}/{repo}/pulls/{number}");
+    Some(GiteaPrRef {
+        host,
+        owner,
+        repo,
+        number,
+        url,
+    })
+}
+
+/// Keychain account holding the token for `host`.
+fn token_account(host: &str) -> String {
+    format!("gitea:{}", host.to_lowercase())
+}
+
+/// The API token for `host` and where it came from.
+fn token_for(host: &str) -> Result<Option<(String, &'static str)>> {
+    if let Some(token) = env_token_for(host, default_host("gitea").as_deref()) {
+        return Ok(Some((token, TOKEN_ENV)));
+    }
+    Ok(keychain::get_secret(&token_account(host))?.map(|token| (token, "keychain")))
+}
+
+/// `GITEA_TOKEN`, when `host` is the configured Gitea host. Any link with a
+/// `/pulls/N` path parses as a Gitea PR, so a crafted one must not be able
+/// to send the token to a host of its choosing.
+fn env_token_for(host: &str, configured_host: Option<&str>) -> Option<String> {
+    if configured_host != Some(host.to_lowercase().as_str()) {
+        return None;
+    }
+    std::env::var(TOKEN_ENV)
+        .ok()
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+/// Saves the API token for `host` in the keychain; `None` removes it.
+pub fn save_token(host: &str, token: Option<&str>) -> Result<()> {
+    let account = token_account(host);
+    match token.map(str::trim).filter(|token| !token.is_empty()) {
+        Some(token) => keychain::set_secret(&account, token),
+        None => keychain::delete_secret(&account),
+    }
+}
+
+struct GiteaApi {
+    host: String,
+    /// `https://<host>/api/v1`.
+    base_url: String,
+    token: Option<String>,
+    client: reqwest::Client,
+}
+
+impl GiteaApi {
+    fn new(host: &str) -> Result<Self> {
+        // Public repositories work without a token, so an unreadable
+        // keychain shouldn't stop the request.
+        let token = token_for(host).unwrap_or_else(|err| {
+            log::warn!("Failed to read the Gitea token for {}: {:#}", host, err);
+            None
+        });
+        Ok(Self {
+            host: host.to_string(),
+            base_url: format!("https://{}/api/v1", host),
+            token: token.map(|(token, _)| token),
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()?,
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let url = format!("{}/{}", self.base_url, path);
+        let request = self
+            .client
+            .request(method, url)
+            .header(reqwest::header::USER_AGENT, "LaReview")
+            .header(reqwest::header::ACCEPT, "application/json");
+        match &self.token {
+            Some(token) => request.header(reqwest::header::AUTHORIZATION, format!("token {token}")),
+            None => request,
+        }
+    }
+
+    /// Sends `request`, again while the instance rate limits it.
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String> {
+        let request = &request;
+        with_retry("Gitea API request", || async move {
+            let request = request
+                .try_clone()
+                .context("Gitea API request can't be resent")?;
+            self.send_once(request).await
+        })
+        .await
+    }
+
+    async fn send_once(&self, request: reqwest::RequestBuilder) -> Result<String> {
+        let response = request.send().await.context("Gitea API request failed")?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text().await.context("read Gitea API response")?;
+        if !status.is_success() {
+            let message = serde_json::from_str::<GiteaError>(&body)
+                .ok()
+                .and_then(|error| error.message)
+                .unwrap_or(body);
+            let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+            let rate_limit_headers = RateLimitHeaders {
+                retry_after: header("retry-after"),
+                remaining: header("x-ratelimit-remaining"),
+                reset: header("x-ratelimit-reset"),
+            };
+            if let Some(limited) = retry::from_http(
+                status.as_u16(),
+                rate_limit_headers,
+                SystemTime::now(),
+                &format!("Gitea API returned {}: {}", status, message.trim()),
+            ) {
+                return Err(limited.into());
+            }
+            if status == reqwest::StatusCode::UNAUTHORIZED && self.token.is_none() {
+                bail!(
+                    "Gitea API returned {}: add an API token for {} in Settings",
+                    status,
+                    self.host
+                );
+            }
+            bail!("Gitea API returned {}: {}", status, message.trim());
+        }
+        Ok(body)
+    }
+
+    async fn get(&self, path: &str) -> Result<String> {
+        self.send(self.request(reqwest::Method::GET, path)).await
+    }
+
+    async fn post<T: Serialize>(&self, path: &str, payload: &T) -> Result<String> {
+        let body = serde_json::to_string(payload)?;
+        self.send(
+            self.request(reqwest::Method::POST, path)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body),
+        )
+        .await
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaError {
+    message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaPrJson {
+    title: String,
+    html_url: Option<String>,
+    head: Option<GiteaBranchJson>,
+    base: Option<GiteaBranchJson>,
+    merge_base: Option<String>,
+    user: Option<GiteaUserJson>,
+    additions: Option<u32>,
+    deletions: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaPrListJson {
+    number: u32,
+    title: String,
+    html_url: String,
+    user: Option<GiteaUserJson>,
+    #[serde(default)]
+    draft: bool,
+    updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaBranchJson {
+    sha: Option<String>,
+    #[serde(rename = "ref")]
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaUserJson {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaReviewJson {
+    html_url: Option<String>,
+}
+
+/// Body for `POST /repos/{owner}/{repo}/pulls/{index}/reviews`.
+#[derive(Debug, Clone, Serialize)]
+struct GiteaReviewPayload {
+    body: String,
+    event: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+    comments: Vec<GiteaReviewComment>,
+}
+
+/// Inline comment; Gitea takes file line numbers, with 0 for the side the
+/// comment isn't on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct GiteaReviewComment {
+    path: String,
+    body: String,
+    new_position: u32,
+    old_position: u32,
+}
+
+impl GiteaReviewComment {
+    fn new(path: String, line: u32, side: FeedbackSide, body: String) -> Self {
+        let (new_position, old_position) = match side {
+            FeedbackSide::New => (line, 0),
+            FeedbackSide::Old => (0, line),
+        };
+        Self {
+            path,
+            body,
+            new_position,
+            old_position,
+        }
+    }
+}
+
+fn review_event(decision: ReviewDecision) -> &'static str {
+    match decision {
+        ReviewDecision::Approve => "APPROVED",
+        ReviewDecision::RequestChanges => "REQUEST_CHANGES",
+        ReviewDecision::Comment => "COMMENT",
+    }
+}
+
+fn parse_pr_list(json: &str) -> Result<Vec<VcsPrSummary>> {
+    let prs: Vec<GiteaPrListJson> =
+        serde_json::from_str(json).context("parse Gitea pull requests json")?;
+    Ok(prs
+        .into_iter()
+        .map(|pr| VcsPrSummary {
+            number: pr.number,
+            title: pr.title,
+            author: pr.user.map(|user| user.login),
+            url: pr.html_url,
+            draft: pr.draft,
+            updated_at: pr.updated_at,
+        })
+        .collect())
+}
+
+fn repo_path(pr: &GiteaPrRef) -> String {
+    format!("repos/{}/{}/pulls/{}", pr.owner, pr.repo, pr.number)
+}
+
+fn pr_ref_from_source(source: &ReviewSource) -> Result<(GiteaPrRef, Option<String>)> {
+    match source {
+        ReviewSource::GiteaPr {
+            host,
+            owner,
+            repo,
+            number,
+            url,
+            head_sha,
+            ..
+        } => Ok((
+            GiteaPrRef {
+                host: host.clone(),
+                owner: owner.clone(),
+                repo: repo.clone(),
+                number: *number,
+                url: url
+                    .clone()
+                    .unwrap_or_else(|| format!("https://{host}/{owner}/{repo}/pulls/{number}")),
+            },
+            head_sha.clone(),
+        )),
+        _ => Err(anyhow::anyhow!(
+            "Review must be from a Gitea or Forgejo PR to push"
+        )),
+    }
+}
+
+/// Inline comments for the selected tasks and feedback whose line is in the
+/// diff; the rest would make Gitea reject the review.
+fn review_comments(request: &ReviewPushRequest) -> Vec<GiteaReviewComment> {
+    let diff_index = DiffIndex::new(&request.run.diff_text).ok();
+    let in_diff = |path: &str, line: u32, side: FeedbackSide| {
+        diff_index
+            .as_ref()
+            .and_then(|index| index.find_position_in_diff(path, line, side))
+            .is_some()
+    };
+
+    let mut comments = Vec::new();
+    for task in request
+        .tasks
+        .iter()
+        .filter(|task| request.selected_tasks.contains(&task.id))
+    {
+        if let Some((path, line, side)) = task_anchor(task)
+            && in_diff(&path, line, side)
+        {
+            let body = ReviewExporter::render_task_markdown(task);
+            comments.push(GiteaReviewComment::new(path, line, side, body));
+        }
+    }
+
+    for feedback in request
+        .feedbacks
+        .iter()
+        .filter(|feedback| request.selected_feedbacks.contains(&feedback.id))
+    {
+        let Some(anchor) = &feedback.anchor else {
+            continue;
+        };
+        let (Some(path), Some(line)) = (&anchor.file_path, anchor.line_number) else {
+            continue;
+        };
+        let side = anchor.side.unwrap_or(FeedbackSide::New);
+        if in_diff(path, line, side) {
+            let feedback_comments: Vec<_> = request
+                .comments
+                .iter()
+                .filter(|comment| comment.feedback_id == feedback.id)
+                .cloned()
+                .collect();
+            let body =
+                ReviewExporter::render_single_feedback_markdown(feedback, &feedback_comments, None);
+            comments.push(GiteaReviewComment::new(path.clone(), line, side, body));
+        }
+    }
+    comments
+}
+
+pub struct GiteaProvider;
+
+impl GiteaProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for GiteaProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VcsRef for GiteaPrRef {
+    fn provider_id(&self) -> &str {
+        "gitea"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+#[async_trait]
+impl VcsProvider for GiteaProvider {
+    fn id(&self) -> &str {
+        "gitea"
+    }
+
+    fn name(&self) -> &str {
+        "Gitea / Forgejo"
+    }
+
+    fn matches_ref(&self, reference: &str) -> bool {
+        GITEA_PR_URL_RE.is_match(reference.trim())
+    }
+
+    fn parse_ref(&self, reference: &str) -> Option<Box<dyn VcsRef>> {
+        parse_pr_ref(reference).map(|pr| Box::new(pr) as Box<dyn VcsRef>)
+    }
+
+    async fn fetch_pr(&self, reference: &dyn VcsRef) -> Result<VcsPrData> {
+        let pr = reference
+            .as_any()
+            .downcast_ref::<GiteaPrRef>()
+            .ok_or_else(|| anyhow::anyhow!("Invalid Gitea PR reference"))?;
+        let api = GiteaApi::new(&pr.host)?;
+
+        let json = api.get(&repo_path(pr)).await?;
+        let metadata: GiteaPrJson = serde_json::from_str(&json).context("parse Gitea PR json")?;
+        let diff_text = api.get(&format!("{}.diff", repo_path(pr))).await?;
+
+        let (head_sha, head_ref) = metadata
+            .head
+            .map(|head| (head.sha, head.name))
+            .unwrap_or_default();
+        Ok(VcsPrData {
+            diff_text,
+            title: metadata.title,
+            source: ReviewSource::GiteaPr {
+                host: pr.host.clone(),
+                owner: pr.owner.clone(),
+                repo: pr.repo.clone(),
+                number: pr.number,
+                url: Some(metadata.html_url.unwrap_or_else(|| pr.url.clone())),
+                head_sha,
+                base_sha: metadata.merge_base,
+            },
+            // Gitea's PR payload has no commit count.
+            details: VcsPrDetails {
+                author: metadata.user.map(|user| user.login),
+                base_ref: metadata.base.and_then(|base| base.name),
+                head_ref,
+                commit_count: None,
+                additions: metadata.additions,
+                deletions: metadata.deletions,
+            },
+        })
+    }
+
+    async fn push_review(&self, request: ReviewPushRequest) -> Result<String> {
+        let (pr, head_sha) = pr_ref_from_source(&request.review.source)?;
+        let payload = GiteaReviewPayload {
+            body: render_review_body(&request.review, request.merge_confidence.as_ref()),
+            event: review_event(request.decision),
+            commit_id: head_sha,
+            comments: review_comments(&request),
+        };
+
+        let api = GiteaApi::new(&pr.host)?;
+        let json = api
+            .post(&format!("{}/reviews", repo_path(&pr)), &payload)
+            .await?;
+        let review: GiteaReviewJson =
+            serde_json::from_str(&json).context("parse Gitea review json")?;
+        Ok(review.html_url.unwrap_or(pr.url))
+    }
+
+    async fn push_feedback(&self, request: FeedbackPushRequest) -> Result<String> {
+        let (pr, head_sha) = pr_ref_from_source(&request.review.source)?;
+        let anchor = request
+            .feedback
+            .anchor
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Feedback missing anchor"))?;
+        let file_path = anchor
+            .file_path
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Feedback missing file path"))?;
+        let line_number = anchor
+            .line_number
+            .ok_or_else(|| anyhow::anyhow!("Feedback missing line number"))?;
+        let side = anchor.side.unwrap_or(FeedbackSide::New);
+
+        DiffIndex::new(&request.run.diff_text)
+            .context("Could not build diff index from review run diff")?
+            .find_position_in_diff(&file_path, line_number, side)
+            .ok_or_else(|| anyhow::anyhow!("Could not find line position in diff"))?;
+
+        let body = ReviewExporter::render_single_feedback_markdown(
+            &request.feedback,
+            &request.comments,
+            None,
+        );
+        let payload = GiteaReviewPayload {
+            body: String::new(),
+            event: review_event(ReviewDecision::Comment),
+            commit_id: head_sha,
+            comments: vec![GiteaReviewComment::new(file_path, line_number, side, body)],
+        };
+
+        let api = GiteaApi::new(&pr.host)?;
+        let json = api
+            .post(&format!("{}/reviews", repo_path(&pr)), &payload)
+            .await?;
+        let review: GiteaReviewJson =
+            serde_json::from_str(&json).context("parse Gitea review json")?;
+        Ok(review.html_url.unwrap_or(pr.url))
+    }
+
+    async fn clone_repo(&self, request: VcsCloneRequest) -> Result<VcsCloneResult> {
+        let host = request
+            .host
+            .clone()
+            .or_else(|| default_host("gitea"))
+            .context("Set the Gitea/Forgejo host in Settings to clone from it")?;
+        let git_path = shell::find_bin("git").context("resolve `git` path for cloning")?;
+        let url = format!("https://{host}/{}.git", request.repo);
+        let dest = request.dest_path.to_string_lossy().to_string();
+
+        let output = Command::new(&git_path)
+            .args(["clone".to_string(), url, dest])
+            .output()
+            .await
+            .context("run clone command")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(anyhow::anyhow!("Clone failed: {stderr}"));
+        }
+
+        Ok(VcsCloneResult {
+            path: request.dest_path,
+        })
+    }
+
+    /// The host set in Settings, and hosts named like Gitea or Forgejo.
+    fn repo_for_remote(&self, remote_url: &str) -> Option<VcsRemoteRepo> {
+        let repo = VcsRemoteRepo::parse(remote_url)?;
+        let known = ["gitea", "forgejo", "codeberg.org"]
+            .iter()
+            .any(|hint| repo.host.contains(hint))
+            || default_host("gitea").as_deref() == Some(repo.host.as_str());
+        (known && repo.path.matches('/').count() == 1).then_some(repo)
+    }
+
+    async fn list_open_prs(&self, repo: &VcsRemoteRepo) -> Result<Vec<VcsPrSummary>> {
+        let json = GiteaApi::new(&repo.host)?
+            .get(&format!(
+                "repos/{}/pulls?state=open&sort=recentupdate&limit={}",
+                repo.path, PR_PAGE_LIMIT
+            ))
+            .await?;
+        parse_pr_list(&json)
+    }
+
+    async fn get_status(&self) -> Result<VcsStatus> {
+        let mut status = VcsStatus {
+            id: self.id().to_string(),
+            name: self.name().to_string(),
+            cli_path: String::new(),
+            login: None,
+            error: None,
+            offline: false,
+            host: default_host("gitea"),
+            auth_method: None,
+            cli_version: None,
+        };
+
+        let Some(host) = status.host.clone() else {
+            status.error = Some("No Gitea/Forgejo host configured".to_string());
+            return Ok(status);
+        };
+        let Some((_, source)) = token_for(&host)? else {
+            status.error = Some(format!("No API token for {}", host));
+            return Ok(status);
+        };
+        status.auth_method = Some(source.to_string());
+
+        match GiteaApi::new(&host)?.get("user").await.and_then(|json| {
+            serde_json::from_str::<GiteaUserJson>(&json).context("parse Gitea user json")
+        }) {
+            Ok(user) => status.login = Some(user.login),
+            Err(err) => status.error = Some(format!("{:#}", err)),
+        }
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_token_stays_with_the_configured_host() {
+        assert_eq!(
+            env_token_for("evil.example", Some("gitea.example.com")),
+            None
+        );
+        assert_eq!(env_token_for("evil.example", None), None);
+    }
+
+    #[test]
+    fn test_parse_pr_ref() {
+        let pr = parse_pr_ref("https://Gitea.Example.com/owner/repo/pulls/12").unwrap();
+        assert_eq!(pr.host, "gitea.example.com");
+        assert_eq!(pr.owner, "owner");
+        assert_eq!(pr.repo, "repo");
+        assert_eq!(pr.number, 12);
+        assert_eq!(pr.url, "https://gitea.example.com/owner/repo/pulls/12");
+
+        let files = parse_pr_ref("codeberg.org/forgejo/forgejo/pulls/7/files#diff").unwrap();
+        assert_eq!(
+            (files.host.as_str(), files.repo.as_str(), files.number),
+            ("codeberg.org", "forgejo", 7)
+        );
+
+        assert!(parse_pr_ref("https://github.com/owner/repo/pull/12").is_none());
+        assert!(parse_pr_ref("https://gitlab.com/group/project/-/merge_requests/3").is_none());
+        assert!(parse_pr_ref("https://gitea.example.com/owner/repo/pulls").is_none());
+    }
+
+    #[test]
+    fn test_provider_only_claims_gitea_urls() {
+        let provider = GiteaProvider::new();
+        assert!(provider.matches_ref(" https://try.gitea.io/a/b/pulls/1 "));
+        assert!(!provider.matches_ref("owner/repo#12"));
+        assert!(!provider.matches_ref("https://github.com/a/b/pull/1"));
+    }
+
+    #[test]
+    fn test_lists_open_prs_of_gitea_remotes() {
+        let repo = GiteaProvider::new()
+            .repo_for_remote("git@codeberg.org:forgejo/forgejo.git")
+            .unwrap();
+        assert_eq!(
+            (repo.host.as_str(), repo.path.as_str()),
+            ("codeberg.org", "forgejo/forgejo")
+        );
+
+        let json = r#"[{"number": 3, "title": "Fix", "html_url": "https://codeberg.org/forgejo/forgejo/pulls/3",
+            "user": {"login": "ada"}, "updated_at": "2025-01-02T00:00:00Z"}]"#;
+        let prs = parse_pr_list(json).unwrap();
+        assert_eq!(prs[0].author.as_deref(), Some("ada"));
+        assert!(parse_pr_ref(&prs[0].url).is_some());
+    }
+
+    #[test]
+    fn test_review_payload_uses_file_line_numbers() {
+        let new = GiteaReviewComment::new("a.rs".into(), 4, FeedbackSide::New, "x".into());
+        assert_eq!((new.new_position, new.old_position), (4, 0));
+        let old = GiteaReviewComment::new("a.rs".into(), 9, FeedbackSide::Old, "y".into());
+        assert_eq!((old.new_position, old.old_position), (0, 9));
+
+        let payload = GiteaReviewPayload {
+            body: "Looks good".into(),
+            event: review_event(ReviewDecision::Approve),
+            commit_id: None,
+            comments: vec![new],
+        };
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["event"], "APPROVED");
+        assert!(json.get("commit_id").is_none());
+        assert_eq!(json["comments"][0]["new_position"], 4);
+    }
+
+    fn http_response(status: &str, headers: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_requests_are_retried() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let responses = [
+                http_response(
+                    "403 Forbidden",
+                    "X-RateLimit-Remaining: 0\r\nRetry-After: 0\r\n",
+                    r#"{"message":"rate limit exceeded"}"#,
+                ),
+                http_response("200 OK", "", r#"{"login":"ada"}"#),
+            ];
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = [0u8; 4096];
+                let _ = socket.read(&mut request).await.unwrap();
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        let api = GiteaApi {
+            host: "127.0.0.1".to_string(),
+            base_url: format!("http://{}/api/v1", addr),
+            token: None,
+            client: reqwest::Client::new(),
+        };
+        assert_eq!(api.get("user").await.unwrap(), r#"{"login":"ada"}"#);
+        server.await.unwrap();
+    }
+}
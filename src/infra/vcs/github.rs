@@ -1,33 +1,70 @@
 use crate::application::review::export::ReviewExporter;
-use crate::domain::{FeedbackSide, ReviewSource};
+use crate::application::review::gh_review::{gh_side, render_review_body, task_anchor};
+use crate::domain::{CompareMode, FeedbackSide, ReviewSource};
+use crate::infra::app_config::load_config;
 use crate::infra::diff::index::DiffIndex;
 use crate::infra::shell;
+use crate::infra::vcs::cli::{
+    auth_failure, cli_version, default_host_in, missing_cli_error, parse_auth_method, resolve_cli,
+    run_cli,
+};
 use crate::infra::vcs::traits::{
-    FeedbackPushRequest, ReviewPushRequest, VcsCloneRequest, VcsCloneResult, VcsPrData,
-    VcsProvider, VcsRef, VcsStatus,
+    FeedbackPushRequest, OPEN_PR_LIMIT, ReviewPushRequest, VcsCloneRequest, VcsCloneResult,
+    VcsPrData, VcsPrDetails, VcsPrSummary, VcsProvider, VcsRef, VcsRemoteRepo, VcsStatus,
 };
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use regex::Regex;
 use serde::Deserialize;
-use tokio::io::AsyncWriteExt;
+use std::path::PathBuf;
 use tokio::process::Command;
 
+/// Host of github.com itself; any other host is GitHub Enterprise.
+pub const GITHUB_HOST: &str = "github.com";
+
 #[derive(Debug, Clone)]
 pub struct GitHubPrRef {
+    /// `github.com` or a GitHub Enterprise host.
+    pub host: String,
     pub owner: String,
     pub repo: String,
     pub number: u32,
     pub url: String,
 }
 
+/// A branch comparison, e.g. `github.com/owner/repo/compare/main...feature`.
+#[derive(Debug, Clone)]
+pub struct GitHubCompareRef {
+    pub host: String,
+    pub owner: String,
+    pub repo: String,
+    pub base: String,
+    pub head: String,
+    pub mode: CompareMode,
+    pub url: String,
+}
+
+impl GitHubPrRef {
+    pub fn repo_arg(&self) -> String {
+        repo_arg(&self.host, &self.owner, &self.repo)
+    }
+}
+
+impl GitHubCompareRef {
+    /// `base...head` or `base..head`, as used in compare URLs and the API.
+    pub fn range(&self) -> String {
+        format!("{}{}{}", self.base, self.mode.separator(), self.head)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GitHubPrMetadata {
     pub title: String,
     pub url: String,
     pub head_sha: Option<String>,
     pub base_sha: Option<String>,
+    pub details: VcsPrDetails,
 }
 
 #[derive(Debug, Clone)]
@@ -59,24 +96,177 @@ lazy_static! {
         r"^(?:(?:https?://)?(?:www\.)?github\.com/)?([^/\s#]+)/([^/\s#]+)(?:/pull/|/|#)(\d+)/?$"
     )
     .expect("github pr regex");
+    static ref GH_HOST_PR_RE: Regex =
+        Regex::new(r"^(?:https?://)?([^/\s]+)/([^/\s#]+)/([^/\s#]+)/pull/(\d+)/?$")
+            .expect("github enterprise pr regex");
+    static ref GH_COMPARE_RE: Regex = Regex::new(
+        r"^(?:https?://)?(?:www\.)?([^/\s]+)/([^/\s#]+)/([^/\s#]+)/compare/([^\s?#]+?)(\.\.\.?)([^\s?#]+?)/?(?:[?#]\S*)?$"
+    )
+    .expect("github compare regex");
+}
+
+/// GitHub Enterprise hosts LaReview treats as GitHub: those in the app
+/// config, `GH_HOST`, and every host `gh` has credentials for.
+pub fn enterprise_hosts() -> Vec<String> {
+    let config = load_config();
+    let mut hosts: Vec<String> = default_host_in(&config, "github").into_iter().collect();
+    hosts.extend(config.github_enterprise_hosts);
+    hosts.extend(std::env::var("GH_HOST").ok());
+    if let Some(text) = gh_hosts_file().and_then(|path| std::fs::read_to_string(path).ok()) {
+        hosts.extend(parse_gh_hosts(&text));
+    }
+    normalize_hosts(hosts)
+}
+
+/// `gh`'s `hosts.yml`, following the same lookup order `gh` uses.
+fn gh_hosts_file() -> Option<PathBuf> {
+    let dir = if let Some(dir) = std::env::var_os("GH_CONFIG_DIR") {
+        PathBuf::from(dir)
+    } else if let Some(dir) = std::env::var_os("XDG_CONFIG_HOME") {
+        PathBuf::from(dir).join("gh")
+    } else if cfg!(windows) {
+        dirs::config_dir()?.join("GitHub CLI")
+    } else {
+        dirs::home_dir()?.join(".config").join("gh")
+    };
+    Some(dir.join("hosts.yml"))
+}
+
+/// Top-level keys of `hosts.yml`, which are the host names.
+fn parse_gh_hosts(text: &str) -> Vec<String> {
+    text.lines()
+        .filter(|line| !line.starts_with([' ', '\t', '#', '-']))
+        .filter_map(|line| line.trim_end().strip_suffix(':'))
+        .map(|host| host.trim_matches(['"', '\'']).to_string())
+        .collect()
+}
+
+/// Lowercases hosts and strips schemes and trailing slashes, dropping
+/// github.com and duplicates.
+fn normalize_hosts(hosts: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::new();
+    for host in hosts {
+        let host = host.trim().to_lowercase();
+        let host = host
+            .strip_prefix("https://")
+            .or_else(|| host.strip_prefix("http://"))
+            .unwrap_or(&host)
+            .trim_end_matches('/')
+            .to_string();
+        if !host.is_empty() && !is_github_com(&host) && !normalized.contains(&host) {
+            normalized.push(host);
+        }
+    }
+    normalized
+}
+
+fn is_github_com(host: &str) -> bool {
+    host.eq_ignore_ascii_case(GITHUB_HOST) || host.eq_ignore_ascii_case("www.github.com")
+}
+
+/// Canonical host for a URL host: github.com, or one of `enterprise_hosts`.
+fn known_host(host: &str, enterprise_hosts: &[String]) -> Option<String> {
+    if is_github_com(host) {
+        return Some(GITHUB_HOST.to_string());
+    }
+    let host = host.to_lowercase();
+    enterprise_hosts.contains(&host).then_some(host)
+}
+
+/// `owner/repo`, prefixed with the host for enterprise, as `gh --repo`
+/// expects it.
+pub fn repo_arg(host: &str, owner: &str, repo: &str) -> String {
+    if host == GITHUB_HOST {
+        format!("{owner}/{repo}")
+    } else {
+        format!("{host}/{owner}/{repo}")
+    }
+}
+
+/// Adds `--hostname` to `gh api` arguments for enterprise hosts.
+fn gh_api_args(host: &str, mut args: Vec<String>) -> Vec<String> {
+    if host != GITHUB_HOST {
+        args.push("--hostname".to_string());
+        args.push(host.to_string());
+    }
+    args
+}
+
+/// Asks `host` when its rate limits reset; see [`run_cli`].
+fn rate_limit_probe(host: &str) -> Vec<String> {
+    gh_api_args(
+        host,
+        ["api", "--include", "rate_limit"]
+            .map(String::from)
+            .to_vec(),
+    )
 }
 
 pub fn parse_pr_ref(input: &str) -> Option<GitHubPrRef> {
+    parse_pr_ref_for_hosts(input, &enterprise_hosts())
+}
+
+/// Like [`parse_pr_ref`], recognizing URLs on `enterprise_hosts` besides
+/// github.com. URLs on other hosts are left for other providers.
+pub fn parse_pr_ref_for_hosts(input: &str, enterprise_hosts: &[String]) -> Option<GitHubPrRef> {
     let trimmed = input.trim();
     if let Some(caps) = GH_PR_RE.captures(trimmed) {
-        let owner = caps.get(1)?.as_str().to_string();
-        let repo = caps.get(2)?.as_str().to_string();
-        let number: u32 = caps.get(3)?.as_str().parse().ok()?;
-        let url = format!("https://github.com/{owner}/{repo}/pull/{number}");
-        return Some(GitHubPrRef {
-            owner,
-            repo,
-            number,
-            url,
-        });
+        return pr_ref(GITHUB_HOST.to_string(), &caps, 1);
     }
+    let caps = GH_HOST_PR_RE.captures(trimmed)?;
+    let host = known_host(caps.get(1)?.as_str(), enterprise_hosts)?;
+    pr_ref(host, &caps, 2)
+}
+
+pub fn pr_url(host: &str, owner: &str, repo: &str, number: u32) -> String {
+    format!("https://{host}/{owner}/{repo}/pull/{number}")
+}
 
-    None
+fn pr_ref(host: String, caps: &regex::Captures, first: usize) -> Option<GitHubPrRef> {
+    let owner = caps.get(first)?.as_str().to_string();
+    let repo = caps.get(first + 1)?.as_str().to_string();
+    let number: u32 = caps.get(first + 2)?.as_str().parse().ok()?;
+    let url = pr_url(&host, &owner, &repo, number);
+    Some(GitHubPrRef {
+        host,
+        owner,
+        repo,
+        number,
+        url,
+    })
+}
+
+/// Parses a compare URL. `...` diffs from the merge base, `..` diffs the two
+/// refs directly.
+pub fn parse_compare_ref(input: &str) -> Option<GitHubCompareRef> {
+    parse_compare_ref_for_hosts(input, &enterprise_hosts())
+}
+
+/// Like [`parse_compare_ref`], also accepting URLs on `enterprise_hosts`.
+pub fn parse_compare_ref_for_hosts(
+    input: &str,
+    enterprise_hosts: &[String],
+) -> Option<GitHubCompareRef> {
+    let caps = GH_COMPARE_RE.captures(input.trim())?;
+    let host = known_host(caps.get(1)?.as_str(), enterprise_hosts)?;
+    let owner = caps.get(2)?.as_str().to_string();
+    let repo = caps.get(3)?.as_str().to_string();
+    let base = caps.get(4)?.as_str().to_string();
+    let mode = CompareMode::from_separator(caps.get(5)?.as_str())?;
+    let head = caps.get(6)?.as_str().to_string();
+    let url = format!(
+        "https://{host}/{owner}/{repo}/compare/{base}{}{head}",
+        mode.separator()
+    );
+    Some(GitHubCompareRef {
+        host,
+        owner,
+        repo,
+        base,
+        head,
+        mode,
+        url,
+    })
 }
 
 /// A single account entry returned by `gh auth status --json hosts` (gh >= 2.81.0).
@@ -85,6 +275,8 @@ struct GhAuthAccount {
     login: Option<String>,
     active: Option<bool>,
     state: Option<String>,
+    #[serde(rename = "tokenSource")]
+    token_source: Option<String>,
 }
 
 /// Top-level JSON from `gh auth status --json hosts`.
@@ -93,104 +285,245 @@ struct GhAuthStatusJson {
     hosts: std::collections::HashMap<String, Vec<GhAuthAccount>>,
 }
 
-/// Extract login from the structured `--json hosts` output.
-fn parse_gh_auth_json(output: &str) -> Option<String> {
+/// The signed-in account `gh auth status` reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GhAccount {
+    host: String,
+    login: String,
+    auth_method: Option<String>,
+}
+
+/// Extract the signed-in account from the structured `--json hosts` output.
+fn parse_gh_auth_json(output: &str) -> Option<GhAccount> {
     let parsed: GhAuthStatusJson = serde_json::from_str(output).ok()?;
-    parsed
-        .hosts
-        .values()
-        .flatten()
-        .find(|a| a.active.unwrap_or(false) && a.state.as_deref() == Some("success"))
-        .or_else(|| {
-            parsed
-                .hosts
-                .values()
-                .flatten()
-                .find(|a| a.state.as_deref() == Some("success"))
+    let accounts = || {
+        parsed
+            .hosts
+            .iter()
+            .flat_map(|(host, accounts)| accounts.iter().map(move |account| (host, account)))
+    };
+    accounts()
+        .find(|(_, a)| a.active.unwrap_or(false) && a.state.as_deref() == Some("success"))
+        .or_else(|| accounts().find(|(_, a)| a.state.as_deref() == Some("success")))
+        .and_then(|(host, a)| {
+            Some(GhAccount {
+                host: host.clone(),
+                login: a.login.clone()?,
+                auth_method: a.token_source.clone(),
+            })
         })
-        .and_then(|a| a.login.clone())
-}
-
-/// Fallback: extract login from the human-readable `gh auth status` output (gh < 2.81.0).
-fn parse_gh_auth_login(output: &str) -> Option<String> {
-    output
-        .lines()
-        .find(|line| line.contains("Logged in to"))
-        .and_then(|line| {
-            if line.contains(" as ") {
-                line.split(" as ")
-                    .nth(1)
-                    .map(|s| s.split_whitespace().next().unwrap_or("").to_string())
-            } else if line.contains(" account ") {
-                line.split(" account ")
-                    .nth(1)
-                    .map(|s| s.split_whitespace().next().unwrap_or("").to_string())
-            } else {
-                None
-            }
+}
+
+/// Fallback: extract the signed-in account from the human-readable
+/// `gh auth status` output (gh < 2.81.0).
+fn parse_gh_auth_login(output: &str) -> Option<GhAccount> {
+    output.lines().find_map(|line| {
+        let rest = line.split("Logged in to ").nth(1)?;
+        let (host, login) = rest
+            .split_once(" as ")
+            .or_else(|| rest.split_once(" account "))?;
+        let login = login.split_whitespace().next()?;
+        Some(GhAccount {
+            host: host.trim().to_string(),
+            login: login.to_string(),
+            auth_method: parse_auth_method(rest),
         })
+    })
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct GhPrViewJson {
     title: String,
     url: String,
-    #[serde(rename = "headRefOid")]
     head_ref_oid: Option<String>,
-    #[serde(rename = "baseRefOid")]
     base_ref_oid: Option<String>,
+    author: Option<GhAuthorJson>,
+    base_ref_name: Option<String>,
+    head_ref_name: Option<String>,
+    commits: Option<Vec<serde::de::IgnoredAny>>,
+    additions: Option<u32>,
+    deletions: Option<u32>,
 }
 
-pub async fn fetch_pr_metadata(pr: &GitHubPrRef) -> Result<GitHubPrMetadata> {
-    let gh_path = shell::find_bin("gh").context("resolve `gh` path")?;
-    let output = Command::new(&gh_path)
-        .args([
-            "pr",
-            "view",
-            pr.url.as_str(),
-            "--json",
-            "title,url,headRefOid,baseRefOid",
-        ])
-        .output()
-        .await
-        .context("run `gh pr view`")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!(format!("`gh pr view` failed: {stderr}")));
-    }
-
-    let json = String::from_utf8(output.stdout).context("decode `gh pr view` stdout")?;
-    let parsed: GhPrViewJson = serde_json::from_str(&json).context("parse `gh pr view` json")?;
+/// The `--json` fields [`parse_pr_view`] reads.
+const PR_VIEW_FIELDS: &str =
+    "title,url,headRefOid,baseRefOid,author,baseRefName,headRefName,commits,additions,deletions";
 
+fn parse_pr_view(json: &str) -> Result<GitHubPrMetadata> {
+    let parsed: GhPrViewJson = serde_json::from_str(json).context("parse `gh pr view` json")?;
     Ok(GitHubPrMetadata {
         title: parsed.title,
         url: parsed.url,
         head_sha: parsed.head_ref_oid,
         base_sha: parsed.base_ref_oid,
+        details: VcsPrDetails {
+            author: parsed.author.map(|author| author.login),
+            base_ref: parsed.base_ref_name,
+            head_ref: parsed.head_ref_name,
+            commit_count: parsed.commits.map(|commits| commits.len() as u32),
+            additions: parsed.additions,
+            deletions: parsed.deletions,
+        },
     })
 }
 
+pub async fn fetch_pr_metadata(pr: &GitHubPrRef) -> Result<GitHubPrMetadata> {
+    let gh_path = resolve_cli("github").context("resolve `gh` path")?;
+    let args = ["pr", "view", pr.url.as_str(), "--json", PR_VIEW_FIELDS].map(String::from);
+    let json = run_cli(
+        "`gh pr view`",
+        &gh_path,
+        &args,
+        None,
+        &rate_limit_probe(&pr.host),
+    )
+    .await?;
+    parse_pr_view(&json)
+}
+
 pub async fn fetch_pr_diff(pr: &GitHubPrRef) -> Result<String> {
-    let gh_path = shell::find_bin("gh").context("resolve `gh` path")?;
-    let output = Command::new(&gh_path)
-        .args([
-            "pr",
-            "diff",
-            &pr.number.to_string(),
-            "--repo",
-            &format!("{}/{}", pr.owner, pr.repo),
-        ])
-        .output()
-        .await
-        .context("run `gh pr diff`")?;
+    let gh_path = resolve_cli("github").context("resolve `gh` path")?;
+    let args = [
+        "pr".to_string(),
+        "diff".to_string(),
+        pr.number.to_string(),
+        "--repo".to_string(),
+        pr.repo_arg(),
+    ];
+    run_cli(
+        "`gh pr diff`",
+        &gh_path,
+        &args,
+        None,
+        &rate_limit_probe(&pr.host),
+    )
+    .await
+}
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!(format!("`gh pr diff` failed: {stderr}")));
-    }
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrListJson {
+    number: u32,
+    title: String,
+    url: String,
+    author: Option<GhAuthorJson>,
+    #[serde(default)]
+    is_draft: bool,
+    updated_at: Option<String>,
+}
 
-    String::from_utf8(output.stdout).context("decode `gh pr diff` stdout")
+#[derive(Debug, Deserialize)]
+struct GhAuthorJson {
+    login: String,
+}
+
+pub async fn list_open_prs(repo: &VcsRemoteRepo) -> Result<Vec<VcsPrSummary>> {
+    let gh_path = resolve_cli("github").context("resolve `gh` path")?;
+    let (owner, name) = repo.owner_and_name();
+    let args = [
+        "pr",
+        "list",
+        "--repo",
+        &repo_arg(&repo.host, owner, name),
+        "--state",
+        "open",
+        "--limit",
+        &OPEN_PR_LIMIT.to_string(),
+        "--json",
+        "number,title,url,author,isDraft,updatedAt",
+    ]
+    .map(String::from);
+    let json = run_cli(
+        "`gh pr list`",
+        &gh_path,
+        &args,
+        None,
+        &rate_limit_probe(&repo.host),
+    )
+    .await?;
+    parse_pr_list(&json)
+}
+
+fn parse_pr_list(json: &str) -> Result<Vec<VcsPrSummary>> {
+    let prs: Vec<GhPrListJson> = serde_json::from_str(json).context("parse `gh pr list` json")?;
+    Ok(prs
+        .into_iter()
+        .map(|pr| VcsPrSummary {
+            number: pr.number,
+            title: pr.title,
+            author: pr.author.map(|author| author.login),
+            url: pr.url,
+            draft: pr.is_draft,
+            updated_at: pr.updated_at,
+        })
+        .collect())
+}
+
+async fn gh_api_text(host: &str, endpoint: &str, accept: &str) -> Result<String> {
+    let gh_path = resolve_cli("github").context("resolve `gh` path")?;
+    let args = gh_api_args(
+        host,
+        vec![
+            "api".to_string(),
+            "-H".to_string(),
+            format!("Accept: {accept}"),
+            endpoint.to_string(),
+        ],
+    );
+    run_cli(
+        &format!("`gh api {endpoint}`"),
+        &gh_path,
+        &args,
+        None,
+        &rate_limit_probe(host),
+    )
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+struct GhCommitJson {
+    sha: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhCompareJson {
+    base_commit: GhCommitJson,
+    merge_base_commit: GhCommitJson,
+}
+
+pub async fn fetch_compare_diff(compare: &GitHubCompareRef) -> Result<String> {
+    let endpoint = format!(
+        "repos/{}/{}/compare/{}",
+        compare.owner,
+        compare.repo,
+        compare.range()
+    );
+    gh_api_text(&compare.host, &endpoint, "application/vnd.github.diff").await
+}
+
+/// Resolves the comparison to `(base_sha, head_sha)`, where the base is the
+/// commit the diff actually starts from.
+pub async fn fetch_compare_shas(compare: &GitHubCompareRef) -> Result<(String, String)> {
+    let repo_path = format!("repos/{}/{}", compare.owner, compare.repo);
+    let json = gh_api_text(
+        &compare.host,
+        &format!("{repo_path}/compare/{}?per_page=1", compare.range()),
+        "application/vnd.github+json",
+    )
+    .await?;
+    let parsed: GhCompareJson = serde_json::from_str(&json).context("parse compare json")?;
+    let base_sha = match compare.mode {
+        CompareMode::MergeBase => parsed.merge_base_commit.sha,
+        CompareMode::Direct => parsed.base_commit.sha,
+    };
+    let head_sha = gh_api_text(
+        &compare.host,
+        &format!("{repo_path}/commits/{}", compare.head),
+        "application/vnd.github.sha",
+    )
+    .await?;
+    Ok((base_sha, head_sha.trim().to_string()))
 }
 
 fn normalize_repo_path(path: &str) -> String {
@@ -200,61 +533,81 @@ fn normalize_repo_path(path: &str) -> String {
         .to_string()
 }
 
-/// Post a single review comment at a diff position. This creates a review thread automatically.
+/// Where a single review comment lands in the PR diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewCommentTarget {
+    /// Position within the file's diff, counted from its first hunk header.
+    Position(u32),
+    /// Lines `start_line..=line` on the new side; needed for suggestions that
+    /// replace more than one line.
+    NewLines { start_line: u32, line: u32 },
+}
+
+fn review_comment_payload(
+    body: &str,
+    commit_id: &str,
+    path: &str,
+    target: ReviewCommentTarget,
+) -> serde_json::Value {
+    let mut payload = serde_json::json!({
+        "body": body,
+        "commit_id": commit_id,
+        "path": normalize_repo_path(path),
+    });
+    match target {
+        ReviewCommentTarget::Position(position) => {
+            payload["position"] = position.into();
+        }
+        ReviewCommentTarget::NewLines { start_line, line } => {
+            payload["line"] = line.into();
+            payload["side"] = "RIGHT".into();
+            if start_line < line {
+                payload["start_line"] = start_line.into();
+                payload["start_side"] = "RIGHT".into();
+            }
+        }
+    }
+    payload
+}
+
+/// Post a single review comment on the PR diff. This creates a review thread automatically.
 pub async fn create_review_comment(
+    host: &str,
     owner: &str,
     repo: &str,
     number: u32,
     body: &str,
     commit_id: &str,
     path: &str,
-    position: u32,
+    target: ReviewCommentTarget,
 ) -> Result<GitHubReviewComment> {
-    let gh_path = shell::find_bin("gh").context("resolve `gh` path")?;
-    let normalized_path = normalize_repo_path(path);
+    let gh_path = resolve_cli("github").context("resolve `gh` path")?;
+    let payload = review_comment_payload(body, commit_id, path, target).to_string();
 
-    let payload = serde_json::json!({
-        "body": body,
-        "commit_id": commit_id,
-        "path": normalized_path,
-        "position": position,
-    });
-
-    let mut child = Command::new(&gh_path)
-        .args([
+    let endpoint = format!("repos/{owner}/{repo}/pulls/{number}/comments");
+    let args = gh_api_args(
+        host,
+        [
             "api",
-            &format!("repos/{owner}/{repo}/pulls/{number}/comments"),
+            endpoint.as_str(),
             "--method",
             "POST",
             "-H",
             "Accept: application/vnd.github+json",
             "--input",
             "-",
-        ])
-        .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .context("spawn `gh api` for pull review")?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        stdin
-            .write_all(payload.to_string().as_bytes())
-            .await
-            .context("write payload to gh stdin")?;
-    }
-
-    let output = child
-        .wait_with_output()
-        .await
-        .context("run `gh api` to create review comment")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!(format!("`gh api` failed: {stderr}")));
-    }
-
-    let json = String::from_utf8(output.stdout).context("decode `gh api` stdout")?;
+        ]
+        .map(String::from)
+        .to_vec(),
+    );
+    let json = run_cli(
+        &format!("`gh api {endpoint}`"),
+        &gh_path,
+        &args,
+        Some(&payload),
+        &rate_limit_probe(host),
+    )
+    .await?;
     let parsed: serde_json::Value =
         serde_json::from_str(&json).context("parse `gh api` response json")?;
 
@@ -272,17 +625,20 @@ pub async fn create_review_comment(
 }
 
 /// Create a PR review with an optional body and individual comments.
+/// `event` is `COMMENT`, `APPROVE` or `REQUEST_CHANGES`.
 pub async fn create_review(
+    host: &str,
     owner: &str,
     repo: &str,
     number: u32,
+    event: &str,
     body: Option<&str>,
     comments: Option<Vec<DraftReviewComment>>,
 ) -> Result<GitHubReview> {
-    let gh_path = shell::find_bin("gh").context("resolve `gh` path")?;
+    let gh_path = resolve_cli("github").context("resolve `gh` path")?;
 
     let mut payload = serde_json::json!({
-        "event": "COMMENT",
+        "event": event,
     });
 
     if let Some(body) = body {
@@ -293,41 +649,31 @@ pub async fn create_review(
         payload["comments"] = serde_json::to_value(comments)?;
     }
 
-    let mut child = Command::new(&gh_path)
-        .args([
+    let endpoint = format!("repos/{owner}/{repo}/pulls/{number}/reviews");
+    let args = gh_api_args(
+        host,
+        [
             "api",
-            &format!("repos/{owner}/{repo}/pulls/{number}/reviews"),
+            endpoint.as_str(),
             "--method",
             "POST",
             "-H",
             "Accept: application/vnd.github+json",
             "--input",
             "-",
-        ])
-        .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .context("spawn `gh api` for pull review creation")?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        stdin
-            .write_all(payload.to_string().as_bytes())
-            .await
-            .context("write payload to gh stdin")?;
-    }
-
-    let output = child
-        .wait_with_output()
-        .await
-        .context("run `gh api` to create review")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!(format!("`gh api` failed: {stderr}")));
-    }
-
-    let json = String::from_utf8(output.stdout).context("decode `gh api` stdout")?;
+        ]
+        .map(String::from)
+        .to_vec(),
+    );
+    let payload = payload.to_string();
+    let json = run_cli(
+        &format!("`gh api {endpoint}`"),
+        &gh_path,
+        &args,
+        Some(&payload),
+        &rate_limit_probe(host),
+    )
+    .await?;
     let parsed: serde_json::Value =
         serde_json::from_str(&json).context("parse `gh api` response json")?;
 
@@ -352,6 +698,7 @@ fn pr_ref_from_source(source: &ReviewSource) -> Result<GitHubPrRef> {
             number,
             ..
         } => Ok(GitHubPrRef {
+            host: source.host().unwrap_or_else(|| GITHUB_HOST.to_string()),
             owner: owner.clone(),
             repo: repo.clone(),
             number: *number,
@@ -361,11 +708,26 @@ fn pr_ref_from_source(source: &ReviewSource) -> Result<GitHubPrRef> {
     }
 }
 
-pub struct GitHubProvider;
+pub struct GitHubProvider {
+    enterprise_hosts: Vec<String>,
+    /// Host for commands that don't name one; github.com when unset.
+    default_host: Option<String>,
+}
 
 impl GitHubProvider {
+    /// Provider for github.com and the hosts from [`enterprise_hosts`].
     pub fn new() -> Self {
-        Self
+        Self {
+            default_host: default_host_in(&load_config(), "github"),
+            ..Self::with_enterprise_hosts(enterprise_hosts())
+        }
+    }
+
+    pub fn with_enterprise_hosts(hosts: Vec<String>) -> Self {
+        Self {
+            enterprise_hosts: normalize_hosts(hosts),
+            default_host: None,
+        }
     }
 }
 
@@ -385,6 +747,16 @@ impl VcsRef for GitHubPrRef {
     }
 }
 
+impl VcsRef for GitHubCompareRef {
+    fn provider_id(&self) -> &str {
+        "github"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
 #[async_trait]
 impl VcsProvider for GitHubProvider {
     fn id(&self) -> &str {
@@ -396,14 +768,44 @@ impl VcsProvider for GitHubProvider {
     }
 
     fn matches_ref(&self, reference: &str) -> bool {
-        parse_pr_ref(reference).is_some()
+        self.parse_ref(reference).is_some()
     }
 
     fn parse_ref(&self, reference: &str) -> Option<Box<dyn VcsRef>> {
-        parse_pr_ref(reference).map(|pr| Box::new(pr) as Box<dyn VcsRef>)
+        let hosts = &self.enterprise_hosts;
+        parse_pr_ref_for_hosts(reference, hosts)
+            .map(|pr| Box::new(pr) as Box<dyn VcsRef>)
+            .or_else(|| {
+                parse_compare_ref_for_hosts(reference, hosts)
+                    .map(|c| Box::new(c) as Box<dyn VcsRef>)
+            })
     }
 
     async fn fetch_pr(&self, reference: &dyn VcsRef) -> Result<VcsPrData> {
+        if let Some(compare) = reference.as_any().downcast_ref::<GitHubCompareRef>() {
+            let diff_text = fetch_compare_diff(compare).await?;
+            let (base_sha, head_sha) = fetch_compare_shas(compare).await?;
+            return Ok(VcsPrData {
+                diff_text,
+                title: compare.range(),
+                source: ReviewSource::GitHubCompare {
+                    owner: compare.owner.clone(),
+                    repo: compare.repo.clone(),
+                    base: compare.base.clone(),
+                    head: compare.head.clone(),
+                    mode: compare.mode,
+                    url: Some(compare.url.clone()),
+                    head_sha: Some(head_sha),
+                    base_sha: Some(base_sha),
+                },
+                details: VcsPrDetails {
+                    base_ref: Some(compare.base.clone()),
+                    head_ref: Some(compare.head.clone()),
+                    ..Default::default()
+                },
+            });
+        }
+
         let pr = reference
             .as_any()
             .downcast_ref::<GitHubPrRef>()
@@ -421,6 +823,7 @@ impl VcsProvider for GitHubProvider {
                 head_sha: metadata.head_sha,
                 base_sha: metadata.base_sha,
             },
+            details: metadata.details,
         })
     }
 
@@ -431,39 +834,17 @@ impl VcsProvider for GitHubProvider {
         for task_id in &request.selected_tasks {
             if let Some(task) = request.tasks.iter().find(|t| t.id == *task_id) {
                 let body = ReviewExporter::render_task_markdown(task);
-                let anchor = task.diff_refs.first().and_then(|dr| {
-                    let hunk = dr.hunks.first()?;
-                    let line_num = if hunk.new_lines > 0 {
-                        hunk.new_start
-                    } else {
-                        hunk.old_start
-                    };
-                    let side = if hunk.new_lines > 0 {
-                        FeedbackSide::New
-                    } else {
-                        FeedbackSide::Old
-                    };
-
-                    diff_index
-                        .as_ref()?
-                        .find_position_in_diff(&dr.file, line_num, side)?;
-                    Some(dr.file.clone())
-                });
-
-                if let Some(path) = anchor {
-                    let hunk = task.diff_refs.first().unwrap().hunks.first().unwrap();
-                    let line_num = if hunk.new_lines > 0 {
-                        hunk.new_start
-                    } else {
-                        hunk.old_start
-                    };
-                    let side = if hunk.new_lines > 0 { "RIGHT" } else { "LEFT" };
-
+                if let Some((path, line_num, side)) = task_anchor(task)
+                    && diff_index
+                        .as_ref()
+                        .and_then(|idx| idx.find_position_in_diff(&path, line_num, side))
+                        .is_some()
+                {
                     gh_comments.push(DraftReviewComment {
                         path,
                         position: None,
                         line: Some(line_num),
-                        side: Some(side.to_string()),
+                        side: Some(gh_side(side).to_string()),
                         body,
                     });
                 }
@@ -489,10 +870,6 @@ impl VcsProvider for GitHubProvider {
                     && let (Some(path), Some(line_num)) = (&anchor.file_path, anchor.line_number)
                 {
                     let side_enum = anchor.side.unwrap_or(FeedbackSide::New);
-                    let side_str = match side_enum {
-                        FeedbackSide::New => "RIGHT",
-                        FeedbackSide::Old => "LEFT",
-                    };
 
                     if diff_index
                         .as_ref()
@@ -503,7 +880,7 @@ impl VcsProvider for GitHubProvider {
                             path: path.clone(),
                             position: None,
                             line: Some(line_num),
-                            side: Some(side_str.to_string()),
+                            side: Some(gh_side(side_enum).to_string()),
                             body,
                         });
                         continue;
@@ -514,40 +891,14 @@ impl VcsProvider for GitHubProvider {
 
         let pr_ref = pr_ref_from_source(&request.review.source)?;
 
-        // Build summary body with optional merge confidence
-        let mut summary_body = String::new();
-
-        // Add merge confidence at the top if available
-        if let Some(confidence) = &request.merge_confidence {
-            summary_body.push_str(&format!(
-                "## Merge Confidence: {:.1}/5 - {}\n\n",
-                confidence.score,
-                confidence.label()
-            ));
-            summary_body.push_str(&format!("*\"{}\"*\n\n", confidence.recommendation()));
-
-            if !confidence.reasons.is_empty() {
-                summary_body.push_str("<details>\n<summary>Assessment</summary>\n\n");
-
-                for reason in &confidence.reasons {
-                    summary_body.push_str(&format!("- {}\n", reason));
-                }
-                summary_body.push('\n');
-
-                summary_body.push_str("</details>\n\n");
-            }
-            summary_body.push_str("---\n\n");
-        }
-
-        summary_body.push_str(&format!("# Review: {}\n\n", request.review.title));
-        if let Some(summary) = &request.review.summary {
-            summary_body.push_str(summary);
-        }
+        let summary_body = render_review_body(&request.review, request.merge_confidence.as_ref());
 
         let gh_review = create_review(
+            &pr_ref.host,
             &pr_ref.owner,
             &pr_ref.repo,
             pr_ref.number,
+            request.decision.github_event(),
             Some(&summary_body),
             Some(gh_comments),
         )
@@ -576,11 +927,15 @@ impl VcsProvider for GitHubProvider {
             .line_number
             .ok_or_else(|| anyhow::anyhow!("Feedback missing line number"))?;
 
-        let markdown = ReviewExporter::render_single_feedback_markdown(
+        let mut markdown = ReviewExporter::render_single_feedback_markdown(
             &request.feedback,
             &request.comments,
             None,
         );
+        if let Some(suggestion) = &request.feedback.suggested_replacement {
+            markdown.push_str(&suggestion.to_github_suggestion());
+            markdown.push('\n');
+        }
 
         let commit_id = pr_ref
             .clone()
@@ -598,14 +953,25 @@ impl VcsProvider for GitHubProvider {
             )
             .ok_or_else(|| anyhow::anyhow!("Could not find line position in diff"))?;
 
+        // A multi-line suggestion only applies if the comment spans every
+        // line it replaces.
+        let target = match &request.feedback.suggested_replacement {
+            Some(suggestion) if suggestion.old_lines.len() > 1 => ReviewCommentTarget::NewLines {
+                start_line: line_number,
+                line: line_number + suggestion.old_lines.len() as u32 - 1,
+            },
+            _ => ReviewCommentTarget::Position(position as u32),
+        };
+
         let comment = create_review_comment(
+            &pr_ref.host,
             &pr_ref.owner,
             &pr_ref.repo,
             pr_ref.number,
             &markdown,
             &commit_id,
             &file_path,
-            position as u32,
+            target,
         )
         .await?;
 
@@ -614,12 +980,23 @@ impl VcsProvider for GitHubProvider {
 
     async fn clone_repo(&self, request: VcsCloneRequest) -> Result<VcsCloneResult> {
         let dest = request.dest_path.to_string_lossy().to_string();
-        let (command_path, args) = if let Some(gh_path) = shell::find_bin("gh") {
-            let args = vec!["repo".to_string(), "clone".to_string(), request.repo, dest];
+        let host = request
+            .host
+            .as_deref()
+            .or(self.default_host.as_deref())
+            .filter(|host| !is_github_com(host))
+            .unwrap_or(GITHUB_HOST);
+        let (command_path, args) = if let Some(gh_path) = resolve_cli("github") {
+            let repo = if host == GITHUB_HOST {
+                request.repo
+            } else {
+                format!("{}/{}", host, request.repo)
+            };
+            let args = vec!["repo".to_string(), "clone".to_string(), repo, dest];
             (gh_path, args)
         } else {
             let git_path = shell::find_bin("git").context("resolve `git` path for cloning")?;
-            let url = format!("https://github.com/{}.git", request.repo);
+            let url = format!("https://{}/{}.git", host, request.repo);
             let args = vec!["clone".to_string(), url, dest];
             (git_path, args)
         };
@@ -640,71 +1017,90 @@ impl VcsProvider for GitHubProvider {
         })
     }
 
+    fn repo_for_remote(&self, remote_url: &str) -> Option<VcsRemoteRepo> {
+        let repo = VcsRemoteRepo::parse(remote_url)?;
+        let host = known_host(&repo.host, &self.enterprise_hosts)?;
+        (repo.path.matches('/').count() == 1).then_some(VcsRemoteRepo { host, ..repo })
+    }
+
+    async fn list_open_prs(&self, repo: &VcsRemoteRepo) -> Result<Vec<VcsPrSummary>> {
+        list_open_prs(repo).await
+    }
+
     async fn get_status(&self) -> Result<VcsStatus> {
-        let gh_path = shell::find_bin("gh");
-        match gh_path {
-            Some(path) => {
-                let path_str = path.to_string_lossy().to_string();
-
-                // Try structured JSON output first (gh >= 2.81.0).
-                let json_output = Command::new(&path)
-                    .args(["auth", "status", "--json", "hosts"])
-                    .output()
-                    .await
-                    .context("run `gh auth status --json hosts`")?;
-
-                let stdout = String::from_utf8_lossy(&json_output.stdout);
-                if let Some(login) = parse_gh_auth_json(&stdout) {
-                    return Ok(VcsStatus {
-                        id: self.id().to_string(),
-                        name: self.name().to_string(),
-                        cli_path: path_str,
-                        login: Some(login),
-                        error: None,
-                    });
+        // Reported when no account is signed in: the host the user set up.
+        let expected_host = self
+            .default_host
+            .clone()
+            .or_else(|| self.enterprise_hosts.first().cloned())
+            .unwrap_or_else(|| GITHUB_HOST.to_string());
+        // With a default host, only that host's login counts.
+        let hostname_args = match &self.default_host {
+            Some(host) => vec!["--hostname", host.as_str()],
+            None => Vec::new(),
+        };
+        let status =
+            |cli_path: String, cli_version: Option<String>, account: Option<GhAccount>, error| {
+                let (host, login, auth_method) = match account {
+                    Some(account) => (account.host, Some(account.login), account.auth_method),
+                    None => (expected_host.clone(), None, None),
+                };
+                VcsStatus {
+                    id: self.id().to_string(),
+                    name: self.name().to_string(),
+                    cli_path,
+                    login,
+                    error,
+                    offline: false,
+                    host: Some(host),
+                    auth_method,
+                    cli_version,
                 }
+            };
 
-                // Fallback: plain-text output for older gh versions.
-                let output = Command::new(&path)
-                    .args(["auth", "status"])
-                    .output()
-                    .await
-                    .context("run `gh auth status`")?;
-
-                let combined_output = format!(
-                    "{}\n{}",
-                    String::from_utf8_lossy(&output.stdout),
-                    String::from_utf8_lossy(&output.stderr)
-                );
+        let Some(path) = resolve_cli("github") else {
+            let (cli_path, error) = missing_cli_error(&load_config(), "github");
+            return Ok(status(cli_path, None, None, Some(error)));
+        };
+        let path_str = path.to_string_lossy().to_string();
+        let version = cli_version(&path).await;
+
+        // Try structured JSON output first (gh >= 2.81.0). Both runs are
+        // killed if the status check times out and drops them.
+        let json_output = Command::new(&path)
+            .args(["auth", "status", "--json", "hosts"])
+            .args(&hostname_args)
+            .kill_on_drop(true)
+            .output()
+            .await
+            .context("run `gh auth status --json hosts`")?;
 
-                if output.status.success() {
-                    let login = parse_gh_auth_login(&combined_output);
-
-                    Ok(VcsStatus {
-                        id: self.id().to_string(),
-                        name: self.name().to_string(),
-                        cli_path: path_str,
-                        login,
-                        error: None,
-                    })
-                } else {
-                    Ok(VcsStatus {
-                        id: self.id().to_string(),
-                        name: self.name().to_string(),
-                        cli_path: path_str,
-                        login: None,
-                        error: Some(combined_output),
-                    })
-                }
-            }
-            None => Ok(VcsStatus {
-                id: self.id().to_string(),
-                name: self.name().to_string(),
-                cli_path: "gh not found".to_string(),
-                login: None,
-                error: Some("gh executable not found in PATH".to_string()),
-            }),
+        let stdout = String::from_utf8_lossy(&json_output.stdout);
+        if let Some(account) = parse_gh_auth_json(&stdout) {
+            return Ok(status(path_str, version, Some(account), None));
         }
+
+        // Fallback: plain-text output for older gh versions.
+        let output = Command::new(&path)
+            .args(["auth", "status"])
+            .args(&hostname_args)
+            .kill_on_drop(true)
+            .output()
+            .await
+            .context("run `gh auth status`")?;
+
+        let combined_output = format!(
+            "{}\n{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        // `gh auth status` fails when any host has a bad token, even if
+        // another one (e.g. an enterprise host) is signed in.
+        let account = parse_gh_auth_login(&combined_output);
+        let error =
+            (account.is_none() && !output.status.success()).then(|| auth_failure(&combined_output));
+        Ok(status(path_str, version, account, error))
     }
 }
 
@@ -712,6 +1108,35 @@ impl VcsProvider for GitHubProvider {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_pr_view_reads_the_preview_details() {
+        let json = r#"{
+            "title": "Add retries",
+            "url": "https://github.com/puemos/lareview/pull/7",
+            "headRefOid": "abc",
+            "baseRefOid": "def",
+            "author": { "login": "octocat" },
+            "baseRefName": "main",
+            "headRefName": "retries",
+            "commits": [{ "oid": "1" }, { "oid": "2" }],
+            "additions": 40,
+            "deletions": 3
+        }"#;
+        let metadata = parse_pr_view(json).unwrap();
+        assert_eq!(metadata.head_sha.as_deref(), Some("abc"));
+        assert_eq!(
+            metadata.details,
+            VcsPrDetails {
+                author: Some("octocat".to_string()),
+                base_ref: Some("main".to_string()),
+                head_ref: Some("retries".to_string()),
+                commit_count: Some(2),
+                additions: Some(40),
+                deletions: Some(3),
+            }
+        );
+    }
+
     #[test]
     fn test_parse_pr_ref_valid_url() {
         let inputs = [
@@ -748,22 +1173,161 @@ mod tests {
         assert!(parse_pr_ref("owner/repo").is_none());
     }
 
+    #[test]
+    fn test_parse_compare_ref() {
+        let res = parse_compare_ref("https://github.com/puemos/lareview/compare/main...feat/x")
+            .expect("should parse three-dot compare");
+        assert_eq!(res.owner, "puemos");
+        assert_eq!(res.repo, "lareview");
+        assert_eq!(res.base, "main");
+        assert_eq!(res.head, "feat/x");
+        assert_eq!(res.mode, CompareMode::MergeBase);
+
+        let res = parse_compare_ref("github.com/puemos/lareview/compare/v1.0..v1.1?expand=1")
+            .expect("should parse two-dot compare");
+        assert_eq!(res.base, "v1.0");
+        assert_eq!(res.head, "v1.1");
+        assert_eq!(res.mode, CompareMode::Direct);
+        assert_eq!(res.range(), "v1.0..v1.1");
+        assert_eq!(
+            res.url,
+            "https://github.com/puemos/lareview/compare/v1.0..v1.1"
+        );
+    }
+
+    #[test]
+    fn test_parse_compare_ref_invalid() {
+        assert!(parse_compare_ref("https://github.com/puemos/lareview/compare/main").is_none());
+        assert!(parse_compare_ref("https://github.com/puemos/lareview/pull/1").is_none());
+        assert!(parse_pr_ref("https://github.com/puemos/lareview/compare/main...dev").is_none());
+    }
+
+    #[test]
+    fn test_parse_enterprise_pr_url() {
+        let hosts = vec!["ghe.company.com".to_string()];
+        let pr = parse_pr_ref_for_hosts("https://GHE.company.com/team/api/pull/42/", &hosts)
+            .expect("should parse enterprise PR");
+        assert_eq!(pr.host, "ghe.company.com");
+        assert_eq!(pr.owner, "team");
+        assert_eq!(pr.repo, "api");
+        assert_eq!(pr.number, 42);
+        assert_eq!(pr.url, "https://ghe.company.com/team/api/pull/42");
+        assert_eq!(pr.repo_arg(), "ghe.company.com/team/api");
+
+        let github = parse_pr_ref_for_hosts("puemos/lareview#1", &hosts).unwrap();
+        assert_eq!(github.host, GITHUB_HOST);
+        assert_eq!(github.repo_arg(), "puemos/lareview");
+
+        // Unknown hosts belong to some other forge.
+        assert!(
+            parse_pr_ref_for_hosts("https://git.example.org/team/api/pull/42", &hosts).is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_enterprise_compare_url() {
+        let hosts = vec!["ghe.company.com".to_string()];
+        let compare = parse_compare_ref_for_hosts(
+            "https://ghe.company.com/team/api/compare/main...dev",
+            &hosts,
+        )
+        .expect("should parse enterprise compare");
+        assert_eq!(compare.host, "ghe.company.com");
+        assert_eq!(compare.range(), "main...dev");
+        assert!(
+            parse_compare_ref_for_hosts("https://ghe.company.com/team/api/compare/main...dev", &[])
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_repo_for_remote() {
+        let provider = GitHubProvider::with_enterprise_hosts(vec!["ghe.company.com".into()]);
+        for remote in [
+            "https://github.com/puemos/lareview.git",
+            "git@github.com:puemos/lareview.git",
+            "ssh://git@github.com/puemos/lareview",
+        ] {
+            let repo = provider.repo_for_remote(remote).expect(remote);
+            assert_eq!(repo.host, GITHUB_HOST);
+            assert_eq!(repo.path, "puemos/lareview");
+        }
+        let enterprise = provider
+            .repo_for_remote("git@GHE.company.com:team/api.git")
+            .unwrap();
+        assert_eq!(enterprise.host, "ghe.company.com");
+
+        assert!(
+            provider
+                .repo_for_remote("git@gitlab.com:group/project.git")
+                .is_none()
+        );
+        assert!(provider.repo_for_remote("/home/me/lareview").is_none());
+    }
+
+    #[test]
+    fn test_parse_pr_list() {
+        let json = r#"[
+            {"number": 12, "title": "Fix login", "url": "https://github.com/o/r/pull/12",
+             "author": {"login": "ada"}, "isDraft": true, "updatedAt": "2025-01-02T00:00:00Z"},
+            {"number": 9, "title": "Docs", "url": "https://github.com/o/r/pull/9", "author": null}
+        ]"#;
+        let prs = parse_pr_list(json).unwrap();
+        assert_eq!(prs.len(), 2);
+        assert_eq!(prs[0].author.as_deref(), Some("ada"));
+        assert!(prs[0].draft);
+        assert_eq!(prs[1].author, None);
+        assert!(!prs[1].draft);
+    }
+
+    #[test]
+    fn test_parse_gh_hosts_file() {
+        let yaml = "github.com:\n    user: octocat\n    git_protocol: https\nghe.company.com:\n    users:\n        john_doe:\n    user: john_doe\n";
+        assert_eq!(parse_gh_hosts(yaml), ["github.com", "ghe.company.com"]);
+        assert_eq!(
+            normalize_hosts(vec![
+                "https://GHE.company.com/".into(),
+                "github.com".into(),
+                "ghe.company.com".into(),
+                " ".into(),
+            ]),
+            ["ghe.company.com"]
+        );
+    }
+
+    fn account(host: &str, login: &str, auth_method: Option<&str>) -> GhAccount {
+        GhAccount {
+            host: host.to_string(),
+            login: login.to_string(),
+            auth_method: auth_method.map(str::to_string),
+        }
+    }
+
     #[test]
     fn test_parse_gh_auth_json_github_com() {
         let json = r#"{"hosts":{"github.com":[{"state":"success","active":true,"login":"octocat","tokenSource":"keyring","scopes":"repo","gitProtocol":"https","host":"github.com"}]}}"#;
-        assert_eq!(parse_gh_auth_json(json), Some("octocat".to_string()));
+        assert_eq!(
+            parse_gh_auth_json(json),
+            Some(account("github.com", "octocat", Some("keyring")))
+        );
     }
 
     #[test]
     fn test_parse_gh_auth_json_enterprise() {
         let json = r#"{"hosts":{"ghe.company.com":[{"state":"success","active":true,"login":"john_doe","tokenSource":"oauth_token","scopes":"repo","gitProtocol":"https","host":"ghe.company.com"}]}}"#;
-        assert_eq!(parse_gh_auth_json(json), Some("john_doe".to_string()));
+        assert_eq!(
+            parse_gh_auth_json(json),
+            Some(account("ghe.company.com", "john_doe", Some("oauth_token")))
+        );
     }
 
     #[test]
     fn test_parse_gh_auth_json_multiple_hosts_picks_active() {
         let json = r#"{"hosts":{"github.com":[{"state":"success","active":false,"login":"personal"}],"ghe.corp.com":[{"state":"success","active":true,"login":"work"}]}}"#;
-        assert_eq!(parse_gh_auth_json(json), Some("work".to_string()));
+        assert_eq!(
+            parse_gh_auth_json(json),
+            Some(account("ghe.corp.com", "work", None))
+        );
     }
 
     #[test]
@@ -781,19 +1345,38 @@ mod tests {
     #[test]
     fn test_parse_gh_auth_login_github_com() {
         let output = "github.com\n  ✓ Logged in to github.com as octocat (oauth_token)\n  ✓ Git operations for github.com configured to use https protocol.\n";
-        assert_eq!(parse_gh_auth_login(output), Some("octocat".to_string()));
+        assert_eq!(
+            parse_gh_auth_login(output),
+            Some(account("github.com", "octocat", Some("oauth_token")))
+        );
     }
 
     #[test]
     fn test_parse_gh_auth_login_enterprise() {
         let output = "ghe.company.com\n  ✓ Logged in to ghe.company.com as john_doe (oauth_token)\n  ✓ Git operations for ghe.company.com configured to use https protocol.\n";
-        assert_eq!(parse_gh_auth_login(output), Some("john_doe".to_string()));
+        assert_eq!(
+            parse_gh_auth_login(output),
+            Some(account("ghe.company.com", "john_doe", Some("oauth_token")))
+        );
     }
 
     #[test]
     fn test_parse_gh_auth_login_account_format() {
         let output = "  ✓ Logged in to github.com account myuser (keyring)\n";
-        assert_eq!(parse_gh_auth_login(output), Some("myuser".to_string()));
+        assert_eq!(
+            parse_gh_auth_login(output),
+            Some(account("github.com", "myuser", Some("keyring")))
+        );
+    }
+
+    #[test]
+    fn test_parse_gh_auth_login_skips_failed_hosts() {
+        let output = "github.com\n  X Failed to log in to github.com account old (keyring)\n\
+                      ghe.company.com\n  ✓ Logged in to ghe.company.com account work (keyring)\n";
+        assert_eq!(
+            parse_gh_auth_login(output),
+            Some(account("ghe.company.com", "work", Some("keyring")))
+        );
     }
 
     #[test]
@@ -801,4 +1384,44 @@ mod tests {
         let output = "You are not logged in to any GitHub hosts.\n";
         assert_eq!(parse_gh_auth_login(output), None);
     }
+
+    #[test]
+    fn test_auth_failure_keeps_the_explanation() {
+        let output = "github.com\n  X Failed to log in to github.com account old (keyring)\n  \
+                      - Active account: true\n  \
+                      - The token in keyring is invalid.\n  \
+                      - To re-authenticate, run: gh auth login -h github.com\n";
+        let failure = auth_failure(output);
+        assert!(failure.starts_with("X Failed to log in to github.com account old (keyring)"));
+        assert!(failure.contains("- The token in keyring is invalid."));
+        assert!(!failure.starts_with("github.com"));
+
+        assert_eq!(
+            auth_failure("You are not logged in to any GitHub hosts.\n"),
+            "You are not logged in to any GitHub hosts."
+        );
+    }
+
+    #[test]
+    fn test_review_comment_payload_targets() {
+        let single =
+            review_comment_payload("b", "sha", "b/src/lib.rs", ReviewCommentTarget::Position(4));
+        assert_eq!(single["path"], "src/lib.rs");
+        assert_eq!(single["position"], 4);
+        assert!(single.get("line").is_none());
+
+        let range = review_comment_payload(
+            "b",
+            "sha",
+            "src/lib.rs",
+            ReviewCommentTarget::NewLines {
+                start_line: 10,
+                line: 12,
+            },
+        );
+        assert_eq!(range["start_line"], 10);
+        assert_eq!(range["line"], 12);
+        assert_eq!(range["side"], "RIGHT");
+        assert!(range.get("position").is_none());
+    }
 }
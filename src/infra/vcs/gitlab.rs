@@ -1,17 +1,21 @@
 use crate::application::review::export::ReviewExporter;
-use crate::domain::{FeedbackSide, ReviewSource};
+use crate::domain::{CompareMode, FeedbackSide, ReviewDecision, ReviewSource};
+use crate::infra::app_config::load_config;
 use crate::infra::diff::index::{DiffIndex, LineLocation};
 use crate::infra::shell;
+use crate::infra::vcs::cli::{
+    auth_failure, cli_version, default_host, missing_cli_error, parse_auth_method, resolve_cli,
+    run_cli,
+};
 use crate::infra::vcs::traits::{
-    FeedbackPushRequest, ReviewPushRequest, VcsCloneRequest, VcsCloneResult, VcsPrData,
-    VcsProvider, VcsRef, VcsStatus,
+    FeedbackPushRequest, OPEN_PR_LIMIT, ReviewPushRequest, VcsCloneRequest, VcsCloneResult,
+    VcsPrData, VcsPrDetails, VcsPrSummary, VcsProvider, VcsRef, VcsRemoteRepo, VcsStatus,
 };
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use regex::Regex;
 use serde::Deserialize;
-use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 
 #[derive(Debug, Clone)]
@@ -22,6 +26,24 @@ pub struct GitLabMrRef {
     pub url: String,
 }
 
+/// A branch comparison, e.g. `gitlab.com/group/project/-/compare/main...feature`.
+#[derive(Debug, Clone)]
+pub struct GitLabCompareRef {
+    pub host: String,
+    pub project_path: String,
+    pub base: String,
+    pub head: String,
+    pub mode: CompareMode,
+    pub url: String,
+}
+
+impl GitLabCompareRef {
+    /// `base...head` or `base..head`, as used in compare URLs.
+    pub fn range(&self) -> String {
+        format!("{}{}{}", self.base, self.mode.separator(), self.head)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GitLabMrMetadata {
     pub title: String,
@@ -29,6 +51,8 @@ pub struct GitLabMrMetadata {
     pub head_sha: Option<String>,
     pub base_sha: Option<String>,
     pub start_sha: Option<String>,
+    /// GitLab doesn't report line counts for an MR; those stay `None`.
+    pub details: VcsPrDetails,
 }
 
 lazy_static! {
@@ -38,6 +62,10 @@ lazy_static! {
     .expect("gitlab mr url regex");
     static ref GL_MR_SHORT_RE: Regex =
         Regex::new(r"^([^\s!#]+(?:/[^\s!#]+)*)[!#](\d+)$").expect("gitlab mr shorthand");
+    static ref GL_COMPARE_RE: Regex = Regex::new(
+        r"^(?:(?:https?://)?([^/\s]+))/([^/\s]+(?:/[^/\s]+)*)/-/compare/([^\s?#]+?)(\.\.\.?)([^\s?#]+?)/?(?:[?#]\S*)?$"
+    )
+    .expect("gitlab compare url regex");
 }
 
 pub fn parse_mr_ref(input: &str) -> Option<GitLabMrRef> {
@@ -71,6 +99,29 @@ pub fn parse_mr_ref(input: &str) -> Option<GitLabMrRef> {
     None
 }
 
+/// Parses a compare URL. `...` diffs from the merge base, `..` diffs the two
+/// refs directly.
+pub fn parse_compare_ref(input: &str) -> Option<GitLabCompareRef> {
+    let caps = GL_COMPARE_RE.captures(input.trim())?;
+    let host = caps.get(1)?.as_str().to_string();
+    let project_path = caps.get(2)?.as_str().to_string();
+    let base = caps.get(3)?.as_str().to_string();
+    let mode = CompareMode::from_separator(caps.get(4)?.as_str())?;
+    let head = caps.get(5)?.as_str().to_string();
+    let url = format!(
+        "https://{host}/{project_path}/-/compare/{base}{}{head}",
+        mode.separator()
+    );
+    Some(GitLabCompareRef {
+        host,
+        project_path,
+        base,
+        head,
+        mode,
+        url,
+    })
+}
+
 fn encode_project_path(path: &str) -> String {
     path.replace('/', "%2F")
 }
@@ -83,11 +134,23 @@ fn glab_args_with_host(host: &str, mut args: Vec<String>) -> Vec<String> {
     args
 }
 
+/// Asks `host` how long its rate limit lasts: a limited host answers even
+/// this cheap call with `Retry-After`/`RateLimit-Reset`. See [`run_cli`].
+fn rate_limit_probe(host: &str) -> Vec<String> {
+    glab_args_with_host(
+        host,
+        ["api", "--include", "version"].map(String::from).to_vec(),
+    )
+}
+
 #[derive(Debug, Deserialize)]
 struct GlabMrJson {
     title: String,
     web_url: String,
     diff_refs: Option<GlabDiffRefs>,
+    author: Option<GlabAuthorJson>,
+    source_branch: Option<String>,
+    target_branch: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -116,27 +179,20 @@ struct GlabMrChange {
 }
 
 pub async fn fetch_mr_metadata(mr: &GitLabMrRef) -> Result<GitLabMrMetadata> {
-    let glab_path = shell::find_bin("glab").context("resolve `glab` path")?;
     let endpoint = format!(
         "projects/{}/merge_requests/{}",
         encode_project_path(&mr.project_path),
         mr.number
     );
-    let args = glab_args_with_host(&mr.host, vec!["api".to_string(), endpoint]);
-
-    let output = Command::new(&glab_path)
-        .args(args)
-        .output()
-        .await
-        .context("run `glab api` for MR")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!(format!("`glab api` failed: {stderr}")));
-    }
+    let json = glab_api(&mr.host, endpoint).await?;
+    let mut metadata = parse_mr_metadata(&json)?;
+    // Only for the preview, so an MR whose commits can't be listed still loads.
+    metadata.details.commit_count = fetch_mr_commit_count(mr).await.ok();
+    Ok(metadata)
+}
 
-    let json = String::from_utf8(output.stdout).context("decode `glab api` stdout")?;
-    let parsed: GlabMrJson = serde_json::from_str(&json).context("parse `glab api` json")?;
+fn parse_mr_metadata(json: &str) -> Result<GitLabMrMetadata> {
+    let parsed: GlabMrJson = serde_json::from_str(json).context("parse `glab api` json")?;
 
     Ok(GitLabMrMetadata {
         title: parsed.title,
@@ -153,15 +209,38 @@ pub async fn fetch_mr_metadata(mr: &GitLabMrRef) -> Result<GitLabMrMetadata> {
             .diff_refs
             .as_ref()
             .and_then(|refs| refs.start_sha.clone()),
+        details: VcsPrDetails {
+            author: parsed.author.map(|author| author.username),
+            base_ref: parsed.target_branch,
+            head_ref: parsed.source_branch,
+            ..Default::default()
+        },
     })
 }
 
+/// GitLab's page size cap; counting further would take one call per page.
+const MR_COMMITS_PER_PAGE: u32 = 100;
+
+/// Commits on the MR, counting at most [`MR_COMMITS_PER_PAGE`] of them.
+async fn fetch_mr_commit_count(mr: &GitLabMrRef) -> Result<u32> {
+    let endpoint = format!(
+        "projects/{}/merge_requests/{}/commits?per_page={}",
+        encode_project_path(&mr.project_path),
+        mr.number,
+        MR_COMMITS_PER_PAGE
+    );
+    let json = glab_api(&mr.host, endpoint).await?;
+    let commits: Vec<serde::de::IgnoredAny> =
+        serde_json::from_str(&json).context("parse `glab api` commits json")?;
+    Ok(commits.len() as u32)
+}
+
 // GitLab 17.8.x 500s /diffs when per_page > 30
 // (NoMethodError on PaginatedMergeRequestDiff). Do not raise.
 const MR_DIFFS_PER_PAGE: u32 = 20;
 
 pub async fn fetch_mr_diff(mr: &GitLabMrRef) -> Result<String> {
-    let glab_path = shell::find_bin("glab").context("resolve `glab` path")?;
+    let glab_path = resolve_cli("gitlab").context("resolve `glab` path")?;
     let endpoint = format!(
         "projects/{}/merge_requests/{}/diffs?per_page={}",
         encode_project_path(&mr.project_path),
@@ -179,25 +258,128 @@ pub async fn fetch_mr_diff(mr: &GitLabMrRef) -> Result<String> {
         ],
     );
 
-    let output = Command::new(&glab_path)
-        .args(args)
-        .output()
-        .await
-        .context("run `glab api` for MR diffs")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!(format!(
-            "`glab api` diffs failed: {stderr}"
-        )));
-    }
-
-    let json = String::from_utf8(output.stdout).context("decode `glab api` diffs stdout")?;
+    let json = run_cli(
+        "`glab api` diffs",
+        &glab_path,
+        &args,
+        None,
+        &rate_limit_probe(&mr.host),
+    )
+    .await?;
     let changes = parse_ndjson_changes(&json).context("parse `glab api` diffs ndjson")?;
 
     Ok(synthesize_unified_diff(&changes))
 }
 
+async fn glab_api(host: &str, endpoint: String) -> Result<String> {
+    let glab_path = resolve_cli("gitlab").context("resolve `glab` path")?;
+    let args = glab_args_with_host(host, vec!["api".to_string(), endpoint]);
+
+    run_cli(
+        "`glab api`",
+        &glab_path,
+        &args,
+        None,
+        &rate_limit_probe(host),
+    )
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+struct GlabMrListJson {
+    iid: u32,
+    title: String,
+    web_url: String,
+    author: Option<GlabAuthorJson>,
+    #[serde(default)]
+    draft: bool,
+    updated_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlabAuthorJson {
+    username: String,
+}
+
+pub async fn list_open_mrs(repo: &VcsRemoteRepo) -> Result<Vec<VcsPrSummary>> {
+    let endpoint = format!(
+        "projects/{}/merge_requests?state=opened&order_by=updated_at&per_page={}",
+        encode_project_path(&repo.path),
+        OPEN_PR_LIMIT
+    );
+    let json = glab_api(&repo.host, endpoint).await?;
+    parse_mr_list(&json)
+}
+
+fn parse_mr_list(json: &str) -> Result<Vec<VcsPrSummary>> {
+    let mrs: Vec<GlabMrListJson> =
+        serde_json::from_str(json).context("parse `glab api` merge requests json")?;
+    Ok(mrs
+        .into_iter()
+        .map(|mr| VcsPrSummary {
+            number: mr.iid,
+            title: mr.title,
+            author: mr.author.map(|author| author.username),
+            url: mr.web_url,
+            draft: mr.draft,
+            updated_at: mr.updated_at,
+        })
+        .collect())
+}
+
+#[derive(Debug, Deserialize)]
+struct GlabCommitJson {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlabCompareJson {
+    /// Head commit; null when both refs point at the same commit.
+    commit: Option<GlabCommitJson>,
+    #[serde(default)]
+    diffs: Vec<GlabMrChange>,
+}
+
+/// Diff and commit SHAs of a comparison.
+pub struct GitLabCompareData {
+    pub diff_text: String,
+    pub head_sha: Option<String>,
+    pub base_sha: Option<String>,
+}
+
+pub async fn fetch_compare(compare: &GitLabCompareRef) -> Result<GitLabCompareData> {
+    let project = encode_project_path(&compare.project_path);
+    // Branch names escape like project paths: only `/` needs encoding.
+    let from = encode_project_path(&compare.base);
+    let to = encode_project_path(&compare.head);
+    // `straight=false` diffs from the merge base, like `...` on the web.
+    let straight = compare.mode == CompareMode::Direct;
+    let json = glab_api(
+        &compare.host,
+        format!("projects/{project}/repository/compare?from={from}&to={to}&straight={straight}"),
+    )
+    .await?;
+    let parsed: GlabCompareJson = serde_json::from_str(&json).context("parse compare json")?;
+
+    let base_endpoint = match compare.mode {
+        CompareMode::MergeBase => {
+            format!("projects/{project}/repository/merge_base?refs[]={from}&refs[]={to}")
+        }
+        CompareMode::Direct => format!("projects/{project}/repository/commits/{from}"),
+    };
+    let base_sha = glab_api(&compare.host, base_endpoint)
+        .await
+        .ok()
+        .and_then(|json| serde_json::from_str::<GlabCommitJson>(&json).ok())
+        .map(|commit| commit.id);
+
+    Ok(GitLabCompareData {
+        diff_text: synthesize_unified_diff(&parsed.diffs),
+        head_sha: parsed.commit.map(|commit| commit.id),
+        base_sha,
+    })
+}
+
 fn parse_ndjson_changes(ndjson: &str) -> Result<Vec<GlabMrChange>> {
     serde_json::Deserializer::from_str(ndjson)
         .into_iter::<GlabMrChange>()
@@ -246,7 +428,7 @@ async fn post_glab_api(
     endpoint: &str,
     payload: serde_json::Value,
 ) -> Result<serde_json::Value> {
-    let glab_path = shell::find_bin("glab").context("resolve `glab` path")?;
+    let glab_path = resolve_cli("gitlab").context("resolve `glab` path")?;
     let args = glab_args_with_host(
         &mr.host,
         vec![
@@ -261,29 +443,14 @@ async fn post_glab_api(
         ],
     );
 
-    let mut child = Command::new(&glab_path)
-        .args(args)
-        .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .context("spawn `glab api`")?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        stdin
-            .write_all(payload.to_string().as_bytes())
-            .await
-            .context("write payload to glab stdin")?;
-    }
-
-    let output = child.wait_with_output().await.context("run `glab api`")?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(anyhow::anyhow!(format!("`glab api` failed: {stderr}")));
-    }
-
-    let json = String::from_utf8(output.stdout).context("decode `glab api` stdout")?;
+    let json = run_cli(
+        "`glab api`",
+        &glab_path,
+        &args,
+        Some(&payload.to_string()),
+        &rate_limit_probe(&mr.host),
+    )
+    .await?;
     let parsed: serde_json::Value = serde_json::from_str(&json).context("parse glab json")?;
     Ok(parsed)
 }
@@ -396,6 +563,16 @@ impl VcsRef for GitLabMrRef {
     }
 }
 
+impl VcsRef for GitLabCompareRef {
+    fn provider_id(&self) -> &str {
+        "gitlab"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
 #[async_trait]
 impl VcsProvider for GitLabProvider {
     fn id(&self) -> &str {
@@ -409,15 +586,41 @@ impl VcsProvider for GitLabProvider {
     fn matches_ref(&self, reference: &str) -> bool {
         let trimmed = reference.trim();
         GL_MR_URL_RE.is_match(trimmed)
+            || GL_COMPARE_RE.is_match(trimmed)
             || (trimmed.contains('!') && GL_MR_SHORT_RE.is_match(trimmed))
             || (trimmed.contains("gitlab") && GL_MR_SHORT_RE.is_match(trimmed))
     }
 
     fn parse_ref(&self, reference: &str) -> Option<Box<dyn VcsRef>> {
-        parse_mr_ref(reference).map(|mr| Box::new(mr) as Box<dyn VcsRef>)
+        parse_mr_ref(reference)
+            .map(|mr| Box::new(mr) as Box<dyn VcsRef>)
+            .or_else(|| parse_compare_ref(reference).map(|c| Box::new(c) as Box<dyn VcsRef>))
     }
 
     async fn fetch_pr(&self, reference: &dyn VcsRef) -> Result<VcsPrData> {
+        if let Some(compare) = reference.as_any().downcast_ref::<GitLabCompareRef>() {
+            let data = fetch_compare(compare).await?;
+            return Ok(VcsPrData {
+                diff_text: data.diff_text,
+                title: compare.range(),
+                source: ReviewSource::GitLabCompare {
+                    host: compare.host.clone(),
+                    project_path: compare.project_path.clone(),
+                    base: compare.base.clone(),
+                    head: compare.head.clone(),
+                    mode: compare.mode,
+                    url: Some(compare.url.clone()),
+                    head_sha: data.head_sha,
+                    base_sha: data.base_sha,
+                },
+                details: VcsPrDetails {
+                    base_ref: Some(compare.base.clone()),
+                    head_ref: Some(compare.head.clone()),
+                    ..Default::default()
+                },
+            });
+        }
+
         let mr = reference
             .as_any()
             .downcast_ref::<GitLabMrRef>()
@@ -438,6 +641,7 @@ impl VcsProvider for GitLabProvider {
                 base_sha: metadata.base_sha,
                 start_sha: metadata.start_sha,
             },
+            details: metadata.details,
         })
     }
 
@@ -529,6 +733,10 @@ impl VcsProvider for GitLabProvider {
             summary_body.push_str("---\n\n");
         }
 
+        // GitLab's REST API has no "request changes" state, so say it in the note.
+        if request.decision == ReviewDecision::RequestChanges {
+            summary_body.push_str("**Changes requested**\n\n");
+        }
         summary_body.push_str(&format!("# Review: {}\n\n", request.review.title));
         if let Some(summary) = &request.review.summary {
             summary_body.push_str(summary);
@@ -574,6 +782,15 @@ impl VcsProvider for GitLabProvider {
             }
         }
 
+        if request.decision == ReviewDecision::Approve {
+            let endpoint = format!(
+                "projects/{}/merge_requests/{}/approve",
+                encode_project_path(&mr_ref.project_path),
+                mr_ref.number
+            );
+            post_glab_api(&mr_ref, &endpoint, serde_json::json!({})).await?;
+        }
+
         Ok(result_url.unwrap_or_else(|| "Success".to_string()))
     }
 
@@ -639,9 +856,10 @@ impl VcsProvider for GitLabProvider {
         let host = request
             .host
             .clone()
+            .or_else(|| default_host("gitlab"))
             .unwrap_or_else(|| "gitlab.com".to_string());
         let dest = request.dest_path.to_string_lossy().to_string();
-        let (command_path, args) = if let Some(glab_path) = shell::find_bin("glab") {
+        let (command_path, args) = if let Some(glab_path) = resolve_cli("gitlab") {
             let mut args = vec!["repo".to_string(), "clone".to_string(), request.repo, dest];
             if host != "gitlab.com" {
                 args.push("--hostname".to_string());
@@ -671,16 +889,32 @@ impl VcsProvider for GitLabProvider {
         })
     }
 
+    /// gitlab.com, the host set in Settings, and hosts named like GitLab.
+    fn repo_for_remote(&self, remote_url: &str) -> Option<VcsRemoteRepo> {
+        let repo = VcsRemoteRepo::parse(remote_url)?;
+        let known = repo.host.contains("gitlab")
+            || default_host("gitlab").as_deref() == Some(repo.host.as_str());
+        known.then_some(repo)
+    }
+
+    async fn list_open_prs(&self, repo: &VcsRemoteRepo) -> Result<Vec<VcsPrSummary>> {
+        list_open_mrs(repo).await
+    }
+
     async fn get_status(&self) -> Result<VcsStatus> {
-        let glab_path = shell::find_bin("glab");
+        let glab_path = resolve_cli("gitlab");
+        let default_host = default_host("gitlab");
         match glab_path {
             Some(path) => {
                 let path_str = path.to_string_lossy().to_string();
-                let output = Command::new(&path)
-                    .args(["auth", "status"])
-                    .output()
-                    .await
-                    .context("run `glab auth status`")?;
+                let version = cli_version(&path).await;
+                let mut command = Command::new(&path);
+                // Killed if the status check times out and drops it.
+                command.args(["auth", "status"]).kill_on_drop(true);
+                if let Some(host) = &default_host {
+                    command.args(["--hostname", host]);
+                }
+                let output = command.output().await.context("run `glab auth status`")?;
 
                 let combined_output = format!(
                     "{}\n{}",
@@ -690,15 +924,22 @@ impl VcsProvider for GitLabProvider {
 
                 // Always try to extract login, even when glab reports partial failure
                 // (e.g. multiple instances configured but only one authenticated)
-                let login = combined_output
+                let logged_in = combined_output
                     .lines()
-                    .find(|line| line.contains("Logged in to") && line.contains(" as "))
+                    .find(|line| line.contains("Logged in to") && line.contains(" as "));
+                let login = logged_in
                     .and_then(|line| line.split(" as ").nth(1))
                     .map(|value| value.split_whitespace().next().unwrap_or("").to_string());
+                let host = logged_in
+                    .and_then(|line| line.split("Logged in to ").nth(1))
+                    .and_then(|rest| rest.split(" as ").next())
+                    .map(|host| host.trim().to_string())
+                    .or(default_host);
+                let auth_method = logged_in.and_then(parse_auth_method);
 
                 // Only report error if no login was found
                 let error = if login.is_none() && !output.status.success() {
-                    Some(combined_output)
+                    Some(auth_failure(&combined_output))
                 } else {
                     None
                 };
@@ -709,15 +950,26 @@ impl VcsProvider for GitLabProvider {
                     cli_path: path_str,
                     login,
                     error,
+                    offline: false,
+                    host,
+                    auth_method,
+                    cli_version: version,
+                })
+            }
+            None => {
+                let (cli_path, error) = missing_cli_error(&load_config(), "gitlab");
+                Ok(VcsStatus {
+                    id: self.id().to_string(),
+                    name: self.name().to_string(),
+                    cli_path,
+                    login: None,
+                    error: Some(error),
+                    offline: false,
+                    host: default_host,
+                    auth_method: None,
+                    cli_version: None,
                 })
             }
-            None => Ok(VcsStatus {
-                id: self.id().to_string(),
-                name: self.name().to_string(),
-                cli_path: "glab not found".to_string(),
-                login: None,
-                error: Some("glab executable not found in PATH".to_string()),
-            }),
         }
     }
 }
@@ -730,10 +982,28 @@ impl GitLabMrRef {
 
 #[cfg(test)]
 mod tests {
-    use super::{build_gitlab_position, parse_mr_ref};
-    use crate::domain::FeedbackSide;
+    use super::{build_gitlab_position, parse_compare_ref, parse_mr_metadata, parse_mr_ref};
+    use crate::domain::{CompareMode, FeedbackSide};
     use crate::infra::diff::index::DiffIndex;
 
+    #[test]
+    fn test_parse_mr_metadata_reads_the_preview_details() {
+        let json = r#"{
+            "title": "Add retries",
+            "web_url": "https://gitlab.com/group/app/-/merge_requests/7",
+            "diff_refs": { "head_sha": "abc", "base_sha": "def", "start_sha": "ghi" },
+            "author": { "username": "tanuki" },
+            "source_branch": "retries",
+            "target_branch": "main"
+        }"#;
+        let metadata = parse_mr_metadata(json).unwrap();
+        assert_eq!(metadata.start_sha.as_deref(), Some("ghi"));
+        assert_eq!(metadata.details.author.as_deref(), Some("tanuki"));
+        assert_eq!(metadata.details.base_ref.as_deref(), Some("main"));
+        assert_eq!(metadata.details.head_ref.as_deref(), Some("retries"));
+        assert_eq!(metadata.details.commit_count, None);
+    }
+
     #[test]
     fn test_gitlab_position_for_added_line() {
         let diff = r#"--- lib/testo/exams/exam_question.ex
@@ -768,6 +1038,46 @@ mod tests {
         assert!(object.get("old_line").is_none());
     }
 
+    #[test]
+    fn test_parse_mr_list() {
+        let json = r#"[{"iid": 7, "title": "Add cache", "web_url": "https://gitlab.com/g/sub/p/-/merge_requests/7",
+            "author": {"username": "ada"}, "draft": false, "updated_at": "2025-01-02T00:00:00Z"}]"#;
+        let mrs = parse_mr_list(json).unwrap();
+        assert_eq!(mrs[0].number, 7);
+        assert_eq!(mrs[0].author.as_deref(), Some("ada"));
+        assert!(parse_mr_ref(&mrs[0].url).is_some());
+
+        let repo = GitLabProvider::new()
+            .repo_for_remote("git@gitlab.com:g/sub/p.git")
+            .unwrap();
+        assert_eq!(repo.path, "g/sub/p");
+    }
+
+    #[test]
+    fn test_parse_compare_ref() {
+        let result =
+            parse_compare_ref("https://git.company.com/group/sub/repo/-/compare/main...feat/x")
+                .unwrap();
+        assert_eq!(result.host, "git.company.com");
+        assert_eq!(result.project_path, "group/sub/repo");
+        assert_eq!(result.base, "main");
+        assert_eq!(result.head, "feat/x");
+        assert_eq!(result.mode, CompareMode::MergeBase);
+
+        let result =
+            parse_compare_ref("gitlab.com/owner/repo/-/compare/v1.0..v1.1?from_project_id=1")
+                .unwrap();
+        assert_eq!(result.mode, CompareMode::Direct);
+        assert_eq!(result.range(), "v1.0..v1.1");
+        assert_eq!(
+            result.url,
+            "https://gitlab.com/owner/repo/-/compare/v1.0..v1.1"
+        );
+
+        assert!(parse_compare_ref("https://gitlab.com/owner/repo/-/compare/main").is_none());
+        assert!(parse_mr_ref("https://gitlab.com/owner/repo/-/compare/main...dev").is_none());
+    }
+
     #[test]
     fn test_parse_mr_ref_gitlab_com_url() {
         let result = parse_mr_ref("https://gitlab.com/owner/repo/-/merge_requests/123").unwrap();
@@ -1,6 +1,11 @@
+pub mod cli;
 pub mod git;
+pub mod gitea;
 pub mod github;
 pub mod gitlab;
+pub mod offline;
 pub mod registry;
+pub mod retry;
 pub mod snapshot;
 pub mod traits;
+pub mod watch;
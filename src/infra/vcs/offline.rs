@@ -0,0 +1,203 @@
+//! Offline (air-gapped) wrapper around a VCS provider.
+//!
+//! Reference detection still works so the UI can tell the user *why* a PR
+//! link was rejected, but every operation that would reach the network fails
+//! immediately without touching the wrapped provider.
+
+use crate::infra::vcs::traits::{
+    FeedbackPushRequest, ReviewPushRequest, VcsCloneRequest, VcsCloneResult, VcsPrData,
+    VcsPrSummary, VcsProvider, VcsRef, VcsRemoteRepo, VcsStatus,
+};
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+
+/// Error returned by every network operation while offline mode is enabled.
+pub const OFFLINE_MODE_ERROR: &str = "Offline mode is enabled; remote VCS operations are disabled. Paste a diff or review a local repository instead.";
+
+pub struct OfflineProvider {
+    inner: Box<dyn VcsProvider>,
+}
+
+impl OfflineProvider {
+    pub fn new(inner: Box<dyn VcsProvider>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl VcsProvider for OfflineProvider {
+    fn id(&self) -> &str {
+        self.inner.id()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn matches_ref(&self, reference: &str) -> bool {
+        self.inner.matches_ref(reference)
+    }
+
+    fn parse_ref(&self, reference: &str) -> Option<Box<dyn VcsRef>> {
+        self.inner.parse_ref(reference)
+    }
+
+    async fn fetch_pr(&self, _reference: &dyn VcsRef) -> Result<VcsPrData> {
+        bail!(OFFLINE_MODE_ERROR)
+    }
+
+    async fn push_review(&self, _request: ReviewPushRequest) -> Result<String> {
+        bail!(OFFLINE_MODE_ERROR)
+    }
+
+    async fn push_feedback(&self, _request: FeedbackPushRequest) -> Result<String> {
+        bail!(OFFLINE_MODE_ERROR)
+    }
+
+    async fn clone_repo(&self, _request: VcsCloneRequest) -> Result<VcsCloneResult> {
+        bail!(OFFLINE_MODE_ERROR)
+    }
+
+    fn repo_for_remote(&self, remote_url: &str) -> Option<VcsRemoteRepo> {
+        self.inner.repo_for_remote(remote_url)
+    }
+
+    async fn list_open_prs(&self, _repo: &VcsRemoteRepo) -> Result<Vec<VcsPrSummary>> {
+        bail!(OFFLINE_MODE_ERROR)
+    }
+
+    async fn get_status(&self) -> Result<VcsStatus> {
+        // The status check shells out to `gh`/`glab auth status`, which talks to
+        // the remote host, so report the provider as offline without calling it.
+        Ok(VcsStatus {
+            id: self.id().to_string(),
+            name: self.name().to_string(),
+            cli_path: String::new(),
+            login: None,
+            error: Some("Offline mode is enabled".to_string()),
+            offline: true,
+            host: None,
+            auth_method: None,
+            cli_version: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::any::Any;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct TestRef;
+
+    impl VcsRef for TestRef {
+        fn provider_id(&self) -> &str {
+            "counting"
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    /// Provider that counts every network-bound call it receives.
+    struct CountingProvider {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl CountingProvider {
+        fn hit(&self) -> Result<()> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl VcsProvider for CountingProvider {
+        fn id(&self) -> &str {
+            "counting"
+        }
+
+        fn name(&self) -> &str {
+            "Counting"
+        }
+
+        fn matches_ref(&self, reference: &str) -> bool {
+            reference.starts_with("counting:")
+        }
+
+        fn parse_ref(&self, _reference: &str) -> Option<Box<dyn VcsRef>> {
+            Some(Box::new(TestRef))
+        }
+
+        async fn fetch_pr(&self, _reference: &dyn VcsRef) -> Result<VcsPrData> {
+            self.hit()?;
+            bail!("network")
+        }
+
+        async fn push_review(&self, _request: ReviewPushRequest) -> Result<String> {
+            self.hit()?;
+            Ok(String::new())
+        }
+
+        async fn push_feedback(&self, _request: FeedbackPushRequest) -> Result<String> {
+            self.hit()?;
+            Ok(String::new())
+        }
+
+        async fn clone_repo(&self, request: VcsCloneRequest) -> Result<VcsCloneResult> {
+            self.hit()?;
+            Ok(VcsCloneResult {
+                path: request.dest_path,
+            })
+        }
+
+        async fn get_status(&self) -> Result<VcsStatus> {
+            self.hit()?;
+            bail!("network")
+        }
+
+        async fn list_open_prs(&self, _repo: &VcsRemoteRepo) -> Result<Vec<VcsPrSummary>> {
+            self.hit()?;
+            Ok(vec![])
+        }
+    }
+
+    #[tokio::test]
+    async fn test_offline_provider_never_reaches_inner_provider() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let provider = OfflineProvider::new(Box::new(CountingProvider {
+            calls: calls.clone(),
+        }));
+
+        assert!(provider.matches_ref("counting:1"));
+        let reference = provider.parse_ref("counting:1").unwrap();
+
+        let err = provider.fetch_pr(reference.as_ref()).await.unwrap_err();
+        assert_eq!(err.to_string(), OFFLINE_MODE_ERROR);
+
+        let err = provider
+            .clone_repo(VcsCloneRequest {
+                repo: "example/repo".into(),
+                dest_path: PathBuf::from("/tmp/never"),
+                host: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.to_string(), OFFLINE_MODE_ERROR);
+
+        let repo = VcsRemoteRepo::parse("git@example.com:example/repo.git").unwrap();
+        let err = provider.list_open_prs(&repo).await.unwrap_err();
+        assert_eq!(err.to_string(), OFFLINE_MODE_ERROR);
+
+        let status = provider.get_status().await.unwrap();
+        assert!(status.offline);
+        assert_eq!(status.id, "counting");
+        assert!(status.login.is_none());
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+}
@@ -1,5 +1,12 @@
-use crate::infra::vcs::traits::VcsProvider;
-use crate::infra::vcs::{github::GitHubProvider, gitlab::GitLabProvider};
+use crate::infra::app_config::AppConfig;
+use crate::infra::vcs::offline::OfflineProvider;
+use crate::infra::vcs::traits::{VcsProvider, VcsRemoteRepo, VcsStatus};
+use crate::infra::vcs::{gitea::GiteaProvider, github::GitHubProvider, gitlab::GitLabProvider};
+use std::time::Duration;
+
+/// How long one provider's status check may take before it is reported as
+/// timed out, so a hanging `gh`/`glab` call doesn't hold up the others.
+pub const STATUS_TIMEOUT: Duration = Duration::from_secs(15);
 
 pub struct VcsRegistry {
     providers: Vec<Box<dyn VcsProvider>>,
@@ -11,12 +18,33 @@ impl Default for VcsRegistry {
             providers: vec![
                 Box::new(GitHubProvider::new()),
                 Box::new(GitLabProvider::new()),
+                Box::new(GiteaProvider::new()),
             ],
         }
     }
 }
 
 impl VcsRegistry {
+    /// Registry whose providers refuse every network operation.
+    pub fn offline() -> Self {
+        Self {
+            providers: Self::default()
+                .providers
+                .into_iter()
+                .map(|provider| Box::new(OfflineProvider::new(provider)) as Box<dyn VcsProvider>)
+                .collect(),
+        }
+    }
+
+    /// Builds the registry matching the user's configuration.
+    pub fn from_config(config: &AppConfig) -> Self {
+        if config.offline {
+            Self::offline()
+        } else {
+            Self::default()
+        }
+    }
+
     pub fn detect_provider(&self, reference: &str) -> Option<&dyn VcsProvider> {
         self.providers
             .iter()
@@ -31,10 +59,206 @@ impl VcsRegistry {
             .find(|provider| provider.id() == id)
     }
 
+    /// The provider hosting the repository `remote_url` points at, and that
+    /// repository.
+    pub fn provider_for_remote(
+        &self,
+        remote_url: &str,
+    ) -> Option<(&dyn VcsProvider, VcsRemoteRepo)> {
+        self.providers.iter().find_map(|provider| {
+            provider
+                .repo_for_remote(remote_url)
+                .map(|repo| (provider.as_ref(), repo))
+        })
+    }
+
     pub fn providers(&self) -> Vec<&dyn VcsProvider> {
         self.providers
             .iter()
             .map(|provider| provider.as_ref())
             .collect()
     }
+
+    /// Every provider's status, checked concurrently, in registry order.
+    /// A provider whose check fails or outlasts `timeout` gets a status
+    /// carrying the error instead of failing the whole list.
+    pub async fn statuses(&self, timeout: Duration) -> Vec<VcsStatus> {
+        futures::future::join_all(
+            self.providers
+                .iter()
+                .map(|provider| status_with_timeout(provider.as_ref(), timeout)),
+        )
+        .await
+    }
+}
+
+/// `provider`'s status, or a status describing why it couldn't be checked.
+pub async fn status_with_timeout(provider: &dyn VcsProvider, timeout: Duration) -> VcsStatus {
+    let error = match tokio::time::timeout(timeout, provider.get_status()).await {
+        Ok(Ok(status)) => return status,
+        Ok(Err(err)) => format!("Status check failed: {:#}", err),
+        Err(_) => format!("Status check timed out after {}s", timeout.as_secs_f32()),
+    };
+    VcsStatus {
+        id: provider.id().to_string(),
+        name: provider.name().to_string(),
+        cli_path: String::new(),
+        login: None,
+        error: Some(error),
+        offline: false,
+        host: None,
+        auth_method: None,
+        cli_version: None,
+    }
+}
+
+#[cfg(test)]
+impl VcsRegistry {
+    fn with_providers(providers: Vec<Box<dyn VcsProvider>>) -> Self {
+        Self { providers }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::vcs::traits::{
+        FeedbackPushRequest, ReviewPushRequest, VcsCloneRequest, VcsCloneResult, VcsPrData, VcsRef,
+    };
+    use anyhow::{Result, bail};
+    use async_trait::async_trait;
+    use std::time::Instant;
+
+    /// Provider whose status check takes `delay` and then succeeds or fails.
+    struct MockProvider {
+        id: &'static str,
+        delay: Duration,
+        fails: bool,
+    }
+
+    #[async_trait]
+    impl VcsProvider for MockProvider {
+        fn id(&self) -> &str {
+            self.id
+        }
+
+        fn name(&self) -> &str {
+            self.id
+        }
+
+        fn matches_ref(&self, _reference: &str) -> bool {
+            false
+        }
+
+        fn parse_ref(&self, _reference: &str) -> Option<Box<dyn VcsRef>> {
+            None
+        }
+
+        async fn fetch_pr(&self, _reference: &dyn VcsRef) -> Result<VcsPrData> {
+            bail!("unused")
+        }
+
+        async fn push_review(&self, _request: ReviewPushRequest) -> Result<String> {
+            bail!("unused")
+        }
+
+        async fn push_feedback(&self, _request: FeedbackPushRequest) -> Result<String> {
+            bail!("unused")
+        }
+
+        async fn clone_repo(&self, _request: VcsCloneRequest) -> Result<VcsCloneResult> {
+            bail!("unused")
+        }
+
+        async fn get_status(&self) -> Result<VcsStatus> {
+            tokio::time::sleep(self.delay).await;
+            if self.fails {
+                bail!("gh crashed");
+            }
+            Ok(VcsStatus {
+                id: self.id.to_string(),
+                name: self.id.to_string(),
+                cli_path: format!("/usr/bin/{}", self.id),
+                login: Some("octocat".to_string()),
+                error: None,
+                offline: false,
+                host: None,
+                auth_method: None,
+                cli_version: None,
+            })
+        }
+    }
+
+    fn mock(id: &'static str, delay_ms: u64, fails: bool) -> Box<dyn VcsProvider> {
+        Box::new(MockProvider {
+            id,
+            delay: Duration::from_millis(delay_ms),
+            fails,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_slow_provider_times_out_without_blocking_others() {
+        let registry = VcsRegistry::with_providers(vec![
+            mock("slow", 10_000, false),
+            mock("fast", 10, false),
+            mock("broken", 10, true),
+        ]);
+
+        let started = Instant::now();
+        let statuses = registry.statuses(Duration::from_millis(200)).await;
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        let ids: Vec<&str> = statuses.iter().map(|status| status.id.as_str()).collect();
+        assert_eq!(ids, ["slow", "fast", "broken"]);
+        assert!(statuses[0].error.as_deref().unwrap().contains("timed out"));
+        assert_eq!(statuses[1].login.as_deref(), Some("octocat"));
+        assert!(statuses[1].error.is_none());
+        assert_eq!(
+            statuses[2].error.as_deref(),
+            Some("Status check failed: gh crashed")
+        );
+    }
+
+    #[test]
+    fn test_provider_for_remote() {
+        let registry = VcsRegistry::with_providers(vec![
+            Box::new(GitHubProvider::with_enterprise_hosts(vec![])),
+            Box::new(GitLabProvider::new()),
+            Box::new(GiteaProvider::new()),
+        ]);
+        let provider_of = |remote: &str| {
+            registry
+                .provider_for_remote(remote)
+                .map(|(provider, repo)| (provider.id().to_string(), repo.path))
+        };
+
+        assert_eq!(
+            provider_of("git@github.com:puemos/lareview.git"),
+            Some(("github".into(), "puemos/lareview".into()))
+        );
+        assert_eq!(
+            provider_of("https://gitlab.example.com/group/sub/project.git"),
+            Some(("gitlab".into(), "group/sub/project".into()))
+        );
+        assert_eq!(
+            provider_of("https://codeberg.org/forgejo/forgejo"),
+            Some(("gitea".into(), "forgejo/forgejo".into()))
+        );
+        assert_eq!(provider_of("/home/me/project"), None);
+    }
+
+    #[tokio::test]
+    async fn test_checks_run_concurrently() {
+        let registry = VcsRegistry::with_providers(vec![
+            mock("a", 300, false),
+            mock("b", 300, false),
+            mock("c", 300, false),
+        ]);
+
+        let started = Instant::now();
+        let statuses = registry.statuses(Duration::from_secs(5)).await;
+        assert!(started.elapsed() < Duration::from_millis(800));
+        assert!(statuses.iter().all(|status| status.error.is_none()));
+    }
 }
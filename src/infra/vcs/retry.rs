@@ -0,0 +1,390 @@
+//! Retrying provider API calls that hit a rate limit.
+//!
+//! GitHub and GitLab answer a burst of requests with 403/429 until the
+//! window resets. A call that fails that way is tried again after the wait
+//! the host asked for (`Retry-After`, or the `X-RateLimit-Reset` time), or
+//! an exponential backoff when it didn't say, up to [`MAX_ATTEMPTS`] times.
+//! `gh` and `glab` don't show the headers of a failed run, so the host is
+//! asked again with `api --include` (see [`wait_from_response`]).
+//! Every wait is announced through the sink the caller installed with
+//! [`with_notices`], so a fetch or push says "rate limited, retrying in 8s"
+//! rather than hanging without a word.
+
+use anyhow::Result;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How many times a rate-limited call is tried before giving up.
+pub const MAX_ATTEMPTS: u32 = 4;
+
+/// First wait when the host doesn't say how long; doubles on every retry.
+const BASE_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Longest wait worth sitting through. A window resetting later than this
+/// fails straight away with the time to come back.
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+lazy_static! {
+    /// `gh` prints the API message (`API rate limit exceeded`, `secondary
+    /// rate limit`) and `glab` the status line (`429 Too Many Requests`).
+    static ref CLI_RATE_LIMIT_RE: Regex =
+        Regex::new(r"(?i)rate limit|\bHTTP 429\b|\b429 Too Many Requests\b").unwrap();
+}
+
+/// A call the host refused because too many requests were made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimited {
+    pub message: String,
+    /// How long the host asked to wait, when it said.
+    pub wait: Option<Duration>,
+}
+
+impl std::fmt::Display for RateLimited {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for RateLimited {}
+
+/// The rate-limit headers of an HTTP response, as sent.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RateLimitHeaders<'a> {
+    pub retry_after: Option<&'a str>,
+    pub remaining: Option<&'a str>,
+    pub reset: Option<&'a str>,
+}
+
+/// Reads an HTTP response as a rate limit: any 429, or a 403 that says the
+/// window is used up or when to retry. Other 403s are permission errors.
+pub fn from_http(
+    status: u16,
+    headers: RateLimitHeaders<'_>,
+    now: SystemTime,
+    message: &str,
+) -> Option<RateLimited> {
+    let exhausted = headers.remaining.map(str::trim) == Some("0");
+    let limited = status == 429 || (status == 403 && (exhausted || headers.retry_after.is_some()));
+    if !limited {
+        return None;
+    }
+
+    let wait = headers
+        .retry_after
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .or_else(|| headers.reset.and_then(|reset| wait_until(reset, now)));
+    Some(RateLimited {
+        message: message.to_string(),
+        wait,
+    })
+}
+
+/// Time left until `reset`, a Unix timestamp in seconds.
+fn wait_until(reset: &str, now: SystemTime) -> Option<Duration> {
+    let reset = reset.trim().parse::<u64>().ok()?;
+    let now = now.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(Duration::from_secs(reset.saturating_sub(now)))
+}
+
+/// The wait a `gh`/`glab api --include` response asks for: its rate-limit
+/// headers (GitHub's `X-RateLimit-*`, GitLab's `RateLimit-*`), or for
+/// GitHub's `rate_limit` endpoint, the latest reset of the resources its
+/// body lists as used up. None when it says neither.
+pub fn wait_from_response(response: &str, now: SystemTime) -> Option<Duration> {
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .or_else(|| response.split_once("\n\n"))
+        .unwrap_or((response, ""));
+    let mut lines = head.lines();
+    let status = lines
+        .next()?
+        .split_whitespace()
+        .nth(1)?
+        .parse::<u16>()
+        .ok()?;
+    let header = |name: &str| {
+        lines.clone().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim().eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    };
+    let headers = RateLimitHeaders {
+        retry_after: header("retry-after"),
+        remaining: header("x-ratelimit-remaining").or_else(|| header("ratelimit-remaining")),
+        reset: header("x-ratelimit-reset").or_else(|| header("ratelimit-reset")),
+    };
+    if let Some(wait) = from_http(status, headers, now, "").and_then(|limited| limited.wait) {
+        return Some(wait);
+    }
+
+    let body: serde_json::Value = serde_json::from_str(body.trim()).ok()?;
+    let reset = body
+        .get("resources")?
+        .as_object()?
+        .values()
+        .filter(|resource| resource.get("remaining").and_then(|v| v.as_u64()) == Some(0))
+        .filter_map(|resource| resource.get("reset").and_then(|v| v.as_u64()))
+        .max()?;
+    wait_until(&reset.to_string(), now)
+}
+
+/// The error for a failed `gh`/`glab` run: [`RateLimited`] when its output
+/// says so, so [`with_retry`] tries again, otherwise a plain failure.
+pub fn cli_error(what: &str, stderr: &str) -> anyhow::Error {
+    let message = format!("{what} failed: {stderr}");
+    if CLI_RATE_LIMIT_RE.is_match(stderr) {
+        RateLimited {
+            message,
+            wait: None,
+        }
+        .into()
+    } else {
+        anyhow::anyhow!(message)
+    }
+}
+
+/// Receives "rate limited, retrying" messages while a call waits.
+pub type Notice = Arc<dyn Fn(String) + Send + Sync>;
+
+tokio::task_local! {
+    static NOTICE: Notice;
+}
+
+/// Runs `future` with `notice` receiving the retry messages of every
+/// provider call made inside it.
+pub async fn with_notices<F: Future>(notice: Notice, future: F) -> F::Output {
+    NOTICE.scope(notice, future).await
+}
+
+fn notify(message: String) {
+    log::info!("{}", message);
+    // Outside `with_notices` there's nobody to tell beyond the log.
+    let _ = NOTICE.try_with(|notice| notice(message));
+}
+
+fn backoff(attempt: u32) -> Duration {
+    BASE_BACKOFF * 2u32.pow(attempt.saturating_sub(1))
+}
+
+/// Runs `call` until it succeeds, fails with anything but [`RateLimited`],
+/// or is still rate limited after [`MAX_ATTEMPTS`] tries. `what` names the
+/// call in the notices and the final error, e.g. "`gh pr diff`".
+pub async fn with_retry<T, F, Fut>(what: &str, mut call: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut attempt = 1;
+    loop {
+        let err = match call().await {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+        let Some(limited) = err.downcast_ref::<RateLimited>() else {
+            return Err(err);
+        };
+
+        let wait = limited.wait.unwrap_or_else(|| backoff(attempt));
+        if wait > MAX_WAIT {
+            anyhow::bail!(
+                "{} is rate limited for another {}s; try again then. {}",
+                what,
+                wait.as_secs(),
+                limited.message.trim()
+            );
+        }
+        if attempt >= MAX_ATTEMPTS {
+            anyhow::bail!(gave_up_message(what, attempt, limited));
+        }
+
+        notify(format!(
+            "{} rate limited, retrying in {}s",
+            what,
+            wait.as_secs().max(1)
+        ));
+        tokio::time::sleep(wait).await;
+        attempt += 1;
+    }
+}
+
+/// The final error once [`MAX_ATTEMPTS`] tries were rate limited. Only the
+/// host knows when its window resets, so without a wait it doesn't guess.
+fn gave_up_message(what: &str, attempts: u32, limited: &RateLimited) -> String {
+    let when = match limited.wait {
+        Some(wait) => format!("in {}s", wait.as_secs().max(1)),
+        None => "later".to_string(),
+    };
+    format!(
+        "{} is still rate limited after {} attempts; try again {}. {}",
+        what,
+        attempts,
+        when,
+        limited.message.trim()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_from_http_reads_the_wait() {
+        let retry_after = RateLimitHeaders {
+            retry_after: Some("30"),
+            ..Default::default()
+        };
+        let limited = from_http(429, retry_after, at(0), "slow down").unwrap();
+        assert_eq!(limited.wait, Some(Duration::from_secs(30)));
+        assert_eq!(limited.message, "slow down");
+
+        let exhausted = RateLimitHeaders {
+            retry_after: None,
+            remaining: Some("0"),
+            reset: Some("1000"),
+        };
+        let limited = from_http(403, exhausted, at(988), "API rate limit exceeded").unwrap();
+        assert_eq!(limited.wait, Some(Duration::from_secs(12)));
+
+        let unknown = from_http(429, RateLimitHeaders::default(), at(0), "").unwrap();
+        assert_eq!(unknown.wait, None);
+    }
+
+    #[test]
+    fn test_from_http_leaves_permission_errors_alone() {
+        let forbidden = RateLimitHeaders {
+            remaining: Some("4999"),
+            ..Default::default()
+        };
+        assert!(from_http(403, forbidden, at(0), "forbidden").is_none());
+        assert!(from_http(404, RateLimitHeaders::default(), at(0), "").is_none());
+    }
+
+    #[test]
+    fn test_cli_error_spots_rate_limits() {
+        let gh = cli_error(
+            "`gh pr diff`",
+            "HTTP 403: API rate limit exceeded for user ID 1. (https://api.github.com/graphql)",
+        );
+        assert!(gh.downcast_ref::<RateLimited>().is_some());
+        let glab = cli_error(
+            "`glab api`",
+            "GET https://gitlab.com/api/v4/x: 429 Too Many Requests",
+        );
+        assert!(glab.downcast_ref::<RateLimited>().is_some());
+
+        let missing = cli_error("`gh pr diff`", "HTTP 404: Not Found");
+        assert!(missing.downcast_ref::<RateLimited>().is_none());
+        assert_eq!(
+            missing.to_string(),
+            "`gh pr diff` failed: HTTP 404: Not Found"
+        );
+    }
+
+    #[test]
+    fn test_wait_from_response_reads_headers_and_rate_limit_body() {
+        let glab = "HTTP/2.0 429 Too Many Requests\r\nRetry-After: 45\r\nRateLimit-Reset: 2000\r\n\r\nRetry later\n";
+        assert_eq!(
+            wait_from_response(glab, at(1000)),
+            Some(Duration::from_secs(45))
+        );
+
+        let gitlab_reset =
+            "HTTP/1.1 429 Too Many Requests\nRateLimit-Remaining: 0\nRateLimit-Reset: 1030\n\n";
+        assert_eq!(
+            wait_from_response(gitlab_reset, at(1000)),
+            Some(Duration::from_secs(30))
+        );
+
+        let gh = "HTTP/2.0 200 OK\r\nX-Ratelimit-Remaining: 4999\r\nX-Ratelimit-Reset: 1100\r\n\r\n\
+                  {\"resources\":{\"core\":{\"remaining\":4999,\"reset\":1100},\
+                  \"graphql\":{\"remaining\":0,\"reset\":4600}}}";
+        assert_eq!(
+            wait_from_response(gh, at(1000)),
+            Some(Duration::from_secs(3600))
+        );
+
+        let fine = "HTTP/2.0 200 OK\r\n\r\n{\"version\":\"17.0\"}";
+        assert_eq!(wait_from_response(fine, at(1000)), None);
+        assert_eq!(wait_from_response("", at(1000)), None);
+    }
+
+    fn limited_now() -> anyhow::Error {
+        RateLimited {
+            message: "HTTP 429".to_string(),
+            wait: Some(Duration::ZERO),
+        }
+        .into()
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_waits_out_the_limit() {
+        let calls = &AtomicU32::new(0);
+        let notices = Arc::new(Mutex::new(Vec::new()));
+        let sink = notices.clone();
+        let notice: Notice = Arc::new(move |message| sink.lock().unwrap().push(message));
+
+        let result = with_notices(
+            notice,
+            with_retry("`gh pr diff`", || async move {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(limited_now()),
+                    _ => Ok("diff"),
+                }
+            }),
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), "diff");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            *notices.lock().unwrap(),
+            vec!["`gh pr diff` rate limited, retrying in 1s".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_retry_gives_up() {
+        let calls = &AtomicU32::new(0);
+        let err = with_retry("`glab api`", || async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(limited_now())
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(calls.load(Ordering::SeqCst), MAX_ATTEMPTS);
+        assert!(
+            err.to_string()
+                .contains("still rate limited after 4 attempts; try again in 1s")
+        );
+
+        let unknown = RateLimited {
+            message: "HTTP 403: secondary rate limit".to_string(),
+            wait: None,
+        };
+        assert_eq!(
+            gave_up_message("`gh pr diff`", 4, &unknown),
+            "`gh pr diff` is still rate limited after 4 attempts; try again later. \
+             HTTP 403: secondary rate limit"
+        );
+
+        let calls = &AtomicU32::new(0);
+        let err = with_retry("`glab api`", || async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(anyhow::anyhow!("`glab api` failed: 404 Not Found"))
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(err.to_string(), "`glab api` failed: 404 Not Found");
+    }
+}
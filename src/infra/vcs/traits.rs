@@ -1,5 +1,5 @@
 use crate::domain::{
-    Comment, Feedback, MergeConfidence, Review, ReviewRun, ReviewSource, ReviewTask,
+    Comment, Feedback, MergeConfidence, Review, ReviewDecision, ReviewRun, ReviewSource, ReviewTask,
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -15,6 +15,20 @@ pub struct VcsStatus {
     pub cli_path: String,
     pub login: Option<String>,
     pub error: Option<String>,
+    /// True when the provider is disabled because offline mode is on.
+    #[serde(default)]
+    pub offline: bool,
+    /// Host the status was checked against, e.g. `github.com` or a GitHub
+    /// Enterprise host.
+    #[serde(default)]
+    pub host: Option<String>,
+    /// Where the CLI's credentials came from, as it reports them, e.g.
+    /// `keyring`, `oauth_token` or `GH_TOKEN`.
+    #[serde(default)]
+    pub auth_method: Option<String>,
+    /// Version reported by `<cli> --version`, e.g. `2.81.0`.
+    #[serde(default)]
+    pub cli_version: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -22,6 +36,19 @@ pub struct VcsPrData {
     pub diff_text: String,
     pub title: String,
     pub source: ReviewSource,
+    pub details: VcsPrDetails,
+}
+
+/// Who and what a fetched pull request is about, shown in the preview so the
+/// user can check it's the right one. `None` where the provider doesn't say.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VcsPrDetails {
+    pub author: Option<String>,
+    pub base_ref: Option<String>,
+    pub head_ref: Option<String>,
+    pub commit_count: Option<u32>,
+    pub additions: Option<u32>,
+    pub deletions: Option<u32>,
 }
 
 #[derive(Debug, Clone)]
@@ -36,6 +63,61 @@ pub struct VcsCloneResult {
     pub path: PathBuf,
 }
 
+/// A repository on a provider's host, as named by a git remote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VcsRemoteRepo {
+    /// Lowercased host, without port.
+    pub host: String,
+    /// `owner/repo`, or the full group path on GitLab.
+    pub path: String,
+}
+
+impl VcsRemoteRepo {
+    /// Parses `https://host/owner/repo.git`, `git@host:owner/repo.git` and
+    /// `ssh://git@host:port/owner/repo.git` remotes.
+    pub fn parse(remote_url: &str) -> Option<Self> {
+        let remote = remote_url.trim();
+        let (host, path) = match remote.split_once("://") {
+            Some((_, rest)) => rest.split_once('/')?,
+            None => remote.split_once(':')?,
+        };
+        let host = host.rsplit('@').next()?;
+        let host = host.split(':').next()?.to_lowercase();
+        let path = path
+            .trim_matches('/')
+            .trim_end_matches(".git")
+            .trim_matches('/');
+        if host.is_empty() || !path.contains('/') {
+            return None;
+        }
+        Some(Self {
+            host,
+            path: path.to_string(),
+        })
+    }
+
+    /// Owner and repository name; the last segment is the repository.
+    pub fn owner_and_name(&self) -> (&str, &str) {
+        self.path.rsplit_once('/').unwrap_or(("", &self.path))
+    }
+}
+
+/// Most open pull requests one listing returns.
+pub const OPEN_PR_LIMIT: usize = 100;
+
+/// An open pull or merge request, as listed for batch review.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VcsPrSummary {
+    pub number: u32,
+    pub title: String,
+    pub author: Option<String>,
+    /// Web URL, which [`VcsProvider::parse_ref`] accepts.
+    pub url: String,
+    #[serde(default)]
+    pub draft: bool,
+    pub updated_at: Option<String>,
+}
+
 pub trait VcsRef: Send + Sync {
     fn provider_id(&self) -> &str;
     fn as_any(&self) -> &dyn Any;
@@ -51,6 +133,8 @@ pub struct ReviewPushRequest {
     pub selected_tasks: Vec<String>,
     pub selected_feedbacks: Vec<String>,
     pub merge_confidence: Option<MergeConfidence>,
+    /// Verdict to submit with the review.
+    pub decision: ReviewDecision,
 }
 
 #[derive(Debug, Clone)]
@@ -72,4 +156,14 @@ pub trait VcsProvider: Send + Sync {
     async fn push_feedback(&self, request: FeedbackPushRequest) -> Result<String>;
     async fn clone_repo(&self, request: VcsCloneRequest) -> Result<VcsCloneResult>;
     async fn get_status(&self) -> Result<VcsStatus>;
+
+    /// The repository `remote_url` points at, when this provider hosts it.
+    fn repo_for_remote(&self, _remote_url: &str) -> Option<VcsRemoteRepo> {
+        None
+    }
+
+    /// Up to [`OPEN_PR_LIMIT`] open pull requests of `repo`.
+    async fn list_open_prs(&self, _repo: &VcsRemoteRepo) -> Result<Vec<VcsPrSummary>> {
+        anyhow::bail!("{} can't list pull requests", self.name())
+    }
 }
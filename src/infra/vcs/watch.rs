@@ -0,0 +1,161 @@
+//! Watches linked repos for new commits.
+//!
+//! A background loop polls `git rev-parse HEAD` for every watched repo; when
+//! the SHA moves, the app offers to review the commits in between.
+
+use crate::infra::cli::diff::git_ref_diff;
+use crate::infra::shell;
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Polling interval used when none is configured.
+pub const DEFAULT_WATCH_INTERVAL_SECS: u64 = 30;
+
+/// HEAD of a watched repo moved from `previous` to `current`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeadChange {
+    pub repo_id: String,
+    pub previous: String,
+    pub current: String,
+}
+
+struct WatchedRepo {
+    path: PathBuf,
+    /// Last SHA seen; `None` until the first successful poll.
+    head: Option<String>,
+}
+
+/// Last known HEAD of every watched repo.
+#[derive(Default)]
+pub struct RepoWatcher {
+    repos: HashMap<String, WatchedRepo>,
+}
+
+impl RepoWatcher {
+    /// Starts watching `repo_id`. The first poll records a baseline, so
+    /// commits made before watching started are never reported.
+    pub fn watch(&mut self, repo_id: &str, path: PathBuf) {
+        self.repos
+            .entry(repo_id.to_string())
+            .or_insert(WatchedRepo { path, head: None });
+    }
+
+    pub fn unwatch(&mut self, repo_id: &str) {
+        self.repos.remove(repo_id);
+    }
+
+    /// Repos to poll, so git runs without holding the watcher's lock.
+    pub fn targets(&self) -> Vec<(String, PathBuf)> {
+        self.repos
+            .iter()
+            .map(|(id, repo)| (id.clone(), repo.path.clone()))
+            .collect()
+    }
+
+    /// Records the polled `head` of `repo_id`, returning the change when it
+    /// differs from the last one seen. A failed poll (`None`) keeps the
+    /// previous SHA.
+    pub fn record(&mut self, repo_id: &str, head: Option<String>) -> Option<HeadChange> {
+        let repo = self.repos.get_mut(repo_id)?;
+        let head = head?;
+        match repo.head.replace(head.clone()) {
+            Some(previous) if previous != head => Some(HeadChange {
+                repo_id: repo_id.to_string(),
+                previous,
+                current: head,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Current HEAD SHA of the repo at `repo_path`.
+pub fn read_head(repo_path: &Path) -> Option<String> {
+    let output = Command::new(shell::find_bin("git")?)
+        .args(["-C", &repo_path.to_string_lossy(), "rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let sha = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!sha.is_empty()).then_some(sha)
+}
+
+/// Unified diff of the commits between `from` and `to`. Both come from the
+/// frontend, so they go through [`git_ref_diff`], which only accepts them
+/// as refs and never as git options.
+pub fn commit_range_diff(repo_path: &Path, from: &str, to: &str) -> Result<String> {
+    git_ref_diff(Some(repo_path), from, to)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_poll_sets_baseline() {
+        let mut watcher = RepoWatcher::default();
+        watcher.watch("repo", PathBuf::from("/tmp/repo"));
+
+        assert_eq!(watcher.record("repo", Some("aaa".into())), None);
+        assert_eq!(watcher.record("repo", Some("aaa".into())), None);
+        assert_eq!(
+            watcher.record("repo", Some("bbb".into())),
+            Some(HeadChange {
+                repo_id: "repo".into(),
+                previous: "aaa".into(),
+                current: "bbb".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_failed_polls_and_unwatched_repos_are_ignored() {
+        let mut watcher = RepoWatcher::default();
+        watcher.watch("repo", PathBuf::from("/tmp/repo"));
+        watcher.record("repo", Some("aaa".into()));
+
+        assert_eq!(watcher.record("repo", None), None);
+        assert!(watcher.record("repo", Some("bbb".into())).is_some());
+
+        watcher.unwatch("repo");
+        assert!(watcher.targets().is_empty());
+        assert_eq!(watcher.record("repo", Some("ccc".into())), None);
+    }
+
+    #[test]
+    fn test_read_head_outside_repo() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(read_head(dir.path()), None);
+    }
+
+    #[test]
+    fn test_commit_range_diff_rejects_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .is_ok_and(|o| o.status.success())
+        };
+        if !git(&["init"]) {
+            return; // git is not installed
+        }
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "Test User"]);
+        std::fs::write(repo.join("file.txt"), "one\n").unwrap();
+        git(&["add", "."]);
+        assert!(git(&["commit", "-m", "initial"]));
+
+        let written = repo.join("written.txt");
+        let from = format!("--output={}", written.display());
+        assert!(commit_range_diff(repo, &from, "HEAD").is_err());
+        assert!(!written.exists());
+    }
+}
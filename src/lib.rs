@@ -1,3 +1,13 @@
+//! LaReview core library.
+//!
+//! Besides backing the desktop app, the crate exposes a small headless API for
+//! parsing diffs and rendering markdown exports without a Tauri `State` or the
+//! global runtime:
+//!
+//! - [`parse_unified_diff`] turns a unified diff into a [`ParsedDiff`].
+//! - [`DiffIndex`] offers lower-level queries over a diff's hunks and lines.
+//! - [`export_markdown`] renders an [`ExportData`] bundle to markdown.
+
 pub mod application;
 pub mod commands;
 pub mod domain;
@@ -5,13 +15,106 @@ pub mod infra;
 pub mod prompts;
 pub mod state;
 
+pub use application::review::export::{ExportData, ExportOptions, ExportResult, ReviewExporter};
+pub use infra::diff::combine_diffs;
+pub use infra::diff::index::{ConflictedFile, DiffIndex};
+pub use infra::diff::parsed::{ParsedDiff, ParsedDiffFile, ParsedHunk};
+
 use std::future::Future;
-use tokio::runtime::Runtime;
+use tokio::runtime::{Handle, Runtime, RuntimeFlavor};
 
 lazy_static::lazy_static! {
-    static ref RUNTIME: Runtime = Runtime::new().expect("Failed to create Tokio runtime");
+    /// Runtime used only when a caller has neither an ambient runtime nor an
+    /// injected [`Handle`] (e.g. CLI entry points that run before the GUI).
+    static ref FALLBACK_RUNTIME: Runtime =
+        Runtime::new().expect("Failed to create Tokio runtime");
+}
+
+/// Returns the ambient runtime handle, or the lazily created fallback runtime
+/// when called outside of any runtime. Never panics for lack of a runtime.
+pub fn runtime_handle() -> Handle {
+    Handle::try_current().unwrap_or_else(|_| FALLBACK_RUNTIME.handle().clone())
 }
 
+/// Blocks the current thread on `future` using the given runtime handle.
+///
+/// Safe to call from synchronous code and from worker threads of a
+/// multi-threaded runtime; on the latter the worker is handed off with
+/// `block_in_place` instead of panicking with "cannot start a runtime from
+/// within a runtime".
+///
+/// # Panics
+///
+/// Inside a current-thread runtime, where there is no other thread to hand
+/// the work to. Async code there should `.await` the future instead.
+pub fn block_on_handle<F: Future>(handle: &Handle, future: F) -> F::Output {
+    match Handle::try_current() {
+        Ok(current) if current.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| handle.block_on(future))
+        }
+        _ => handle.block_on(future),
+    }
+}
+
+/// Blocks on `future` using [`runtime_handle`].
+///
+/// Kept for existing callers; new code should thread an explicit [`Handle`]
+/// (see [`state::AppState::runtime`]) and call [`block_on_handle`]. Panics
+/// in the same case.
 pub fn block_on<F: Future>(future: F) -> F::Output {
-    RUNTIME.block_on(future)
+    block_on_handle(&runtime_handle(), future)
+}
+
+/// Parses a unified diff into per-file hunks with addition/deletion totals.
+///
+/// Parsed indexes are cached by content hash, so calling this repeatedly with
+/// the same diff is cheap.
+pub fn parse_unified_diff(diff_text: &str) -> anyhow::Result<ParsedDiff> {
+    ParsedDiff::from_diff_text(diff_text)
+}
+
+/// Renders a review export as markdown.
+///
+/// This is the same renderer the app uses for "Export to Markdown", minus the
+/// database lookups: callers assemble the [`ExportData`] themselves.
+pub fn export_markdown(data: &ExportData, options: &ExportOptions) -> anyhow::Result<ExportResult> {
+    ReviewExporter::render_markdown(data, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_on_outside_runtime_uses_fallback() {
+        assert_eq!(block_on(async { 1 + 1 }), 2);
+    }
+
+    #[test]
+    fn test_block_on_handle_inside_multi_thread_runtime() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+        let handle = runtime.handle().clone();
+
+        let value = runtime.block_on(async move {
+            tokio::spawn(async move { block_on_handle(&handle, async { 42 }) })
+                .await
+                .unwrap()
+        });
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_block_on_handle_inside_current_thread_runtime_panics() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let handle = runtime.handle().clone();
+        runtime.block_on(async move { block_on_handle(&handle, async { 42 }) });
+    }
 }
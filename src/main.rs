@@ -8,10 +8,14 @@ use clap::Parser;
 use log::{error, info};
 
 use lareview::infra;
-use lareview::infra::cli::args::{CliArgs, process_cli_args};
+use lareview::infra::cli::args::{
+    CliArgs, HeadlessReviewRequest, headless_review_request, process_cli_args,
+};
 use lareview::infra::cli::diff::try_read_stdin_diff;
+use lareview::infra::cli::review::run_headless_review;
 use lareview::state::{AppState, DiffRequest, PendingDiff};
 use tauri::{Emitter, Manager};
+use tauri_plugin_window_state::StateFlags;
 
 use std::io::Write;
 
@@ -61,6 +65,11 @@ fn main() -> Result<()> {
             // normal GUI launch.
             let piped_stdin = try_read_stdin_diff().unwrap_or(None);
 
+            // `lareview review` runs to completion without ever opening a window.
+            if let Some(request) = headless_review_request(&parsed_args, piped_stdin.clone())? {
+                return run_headless(request);
+            }
+
             // Process CLI args into data structures, WITHOUT touching DB/AppState yet.
             let (initial_req, initial_pending) = process_cli_args(&parsed_args, piped_stdin)?;
 
@@ -76,9 +85,58 @@ fn main() -> Result<()> {
     }
 }
 
+/// Exit status used when a headless review produced blocking feedback.
+const BLOCKING_FEEDBACK_EXIT_CODE: i32 = 2;
+
+fn run_headless(request: HeadlessReviewRequest) -> Result<()> {
+    let report = lareview::block_on(run_headless_review(request.options))?;
+    println!("{}", report.render(request.format)?);
+
+    if report.has_blocking_feedback() {
+        std::process::exit(BLOCKING_FEEDBACK_EXIT_CODE);
+    }
+    Ok(())
+}
+
+/// Shrinks and moves `window` back onto its monitor when a size restored from
+/// a larger display no longer fits.
+fn fit_to_monitor(window: &tauri::WebviewWindow) {
+    let (Ok(Some(monitor)), Ok(position), Ok(size)) = (
+        window.current_monitor(),
+        window.outer_position(),
+        window.outer_size(),
+    ) else {
+        return;
+    };
+    if window.is_maximized().unwrap_or(false) {
+        return;
+    }
+    let area = monitor.work_area();
+    let width = size.width.min(area.size.width);
+    let height = size.height.min(area.size.height);
+    let max_x = area.position.x + (area.size.width - width) as i32;
+    let max_y = area.position.y + (area.size.height - height) as i32;
+    let x = position.x.clamp(area.position.x, max_x);
+    let y = position.y.clamp(area.position.y, max_y);
+
+    if (width, height) != (size.width, size.height)
+        && let Ok(inner) = window.inner_size()
+    {
+        // `set_size` takes the inner size; trim it by what the frame overflows.
+        let _ = window.set_size(tauri::PhysicalSize::new(
+            inner.width.saturating_sub(size.width - width),
+            inner.height.saturating_sub(size.height - height),
+        ));
+    }
+    if (x, y) != (position.x, position.y) {
+        let _ = window.set_position(tauri::PhysicalPosition::new(x, y));
+    }
+}
+
 fn run_gui(initial_req: Option<DiffRequest>, initial_pending: Option<PendingDiff>) -> Result<()> {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
             debug_log(&format!(
                 "Single Instance Callback triggered! Argv: {:?}",
@@ -105,7 +163,7 @@ fn run_gui(initial_req: Option<DiffRequest>, initial_pending: Option<PendingDiff
                             }
                             if let Some(p) = pending {
                                 debug_log("Updating pending_diff from callback");
-                                *state.pending_diff.lock().unwrap() = Some(p);
+                                state.set_pending_diff(p, None);
                             }
 
                             if let Some(window) = app.get_webview_window("main") {
@@ -131,47 +189,112 @@ fn run_gui(initial_req: Option<DiffRequest>, initial_pending: Option<PendingDiff
                 }
             }
         }))
+        // Restores the last size, position and maximized state; a saved
+        // position that's on no connected monitor is left to the OS.
+        .plugin(
+            tauri_plugin_window_state::Builder::default()
+                .with_state_flags(StateFlags::SIZE | StateFlags::POSITION | StateFlags::MAXIMIZED)
+                .build(),
+        )
         .setup(move |app| {
             // Initialize AppState HERE (only for Primary instance)
             debug_log("Initializing AppState (Primary Instance)...");
-            let app_state = AppState::new();
+            let app_state = AppState::with_runtime(tauri::async_runtime::handle().inner().clone());
 
             // Apply initial args
             if let Some(r) = initial_req {
                 *app_state.diff_request.lock().unwrap() = Some(r);
             }
             if let Some(p) = initial_pending {
-                *app_state.pending_diff.lock().unwrap() = Some(p);
+                app_state.set_pending_diff(p, None);
             }
 
             app.manage(app_state);
             debug_log("AppState initialized and managed.");
+            if let Some(window) = app.get_webview_window("main") {
+                fit_to_monitor(&window);
+                lareview::commands::apply_ui_scale(&window);
+            }
+            lareview::commands::spawn_repo_watch_loop(app.handle().clone());
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             lareview::commands::get_app_version,
             lareview::commands::get_cli_status,
+            lareview::commands::run_diagnostics,
+            lareview::commands::export_bug_report,
+            lareview::commands::load_generate_draft,
+            lareview::commands::save_generate_draft,
+            lareview::commands::clear_generate_draft,
+            lareview::commands::get_data_dir,
+            lareview::commands::set_data_dir,
+            lareview::commands::get_encryption_status,
+            lareview::commands::enable_encryption,
+            lareview::commands::unlock_database,
             lareview::commands::install_cli,
             lareview::commands::get_pending_reviews,
             lareview::commands::get_all_reviews,
+            lareview::commands::list_reviews,
             lareview::commands::get_review_runs,
+            lareview::commands::get_run_diff,
+            lareview::commands::get_review_stats,
+            lareview::commands::get_review_summary_line,
+            lareview::commands::get_file_review_status,
+            lareview::commands::set_file_viewed,
+            lareview::commands::get_viewed_files,
+            lareview::commands::get_run_plan,
+            lareview::commands::get_run_tool_calls,
+            lareview::commands::search_reviews,
+            lareview::commands::add_review_tag,
+            lareview::commands::remove_review_tag,
+            lareview::commands::set_review_decision,
+            lareview::commands::get_checklist,
+            lareview::commands::add_checklist_item,
+            lareview::commands::toggle_checklist_item,
+            lareview::commands::remove_checklist_item,
             lareview::commands::get_linked_repos,
             lareview::commands::parse_diff,
+            lareview::commands::load_patch_files,
             lareview::commands::get_file_content,
             lareview::commands::generate_review,
+            lareview::commands::summarize_review,
             lareview::commands::load_tasks,
+            lareview::commands::list_tasks,
             lareview::commands::update_task_status,
+            lareview::commands::create_task,
+            lareview::commands::update_task,
+            lareview::commands::delete_task,
+            lareview::commands::merge_tasks,
+            lareview::commands::rename_sub_flow,
+            lareview::commands::set_task_sub_flow,
+            lareview::commands::get_sub_flow_order,
+            lareview::commands::set_sub_flow_order,
             lareview::commands::save_feedback,
             lareview::commands::get_feedback_by_review,
+            lareview::commands::reanchor_feedback,
             lareview::commands::get_feedback_diff_snippet,
+            lareview::commands::explain_feedback,
+            lareview::commands::highlight_code,
+            lareview::commands::get_feedback_rule,
             lareview::commands::get_feedback_comments,
             lareview::commands::add_comment,
+            lareview::commands::get_feedback_attachments,
+            lareview::commands::add_feedback_attachment,
+            lareview::commands::paste_clipboard_image_attachment,
+            lareview::commands::delete_feedback_attachment,
             lareview::commands::update_feedback_status,
             lareview::commands::update_feedback_impact,
+            lareview::commands::toggle_feedback_pin,
             lareview::commands::delete_feedback,
             lareview::commands::export_review,
             lareview::commands::fetch_remote_pr,
+            lareview::commands::cancel_pr_fetch,
+            lareview::commands::get_pr_fetch_timeout,
+            lareview::commands::update_pr_fetch_timeout,
+            lareview::commands::list_open_prs,
+            lareview::commands::generate_reviews_for_prs,
             lareview::commands::get_agents,
+            lareview::commands::probe_agent,
             lareview::commands::update_agent_config,
             lareview::commands::add_custom_agent,
             lareview::commands::delete_custom_agent,
@@ -183,6 +306,7 @@ fn run_gui(initial_req: Option<DiffRequest>, initial_pending: Option<PendingDiff
             lareview::commands::clone_and_link_repo,
             lareview::commands::unlink_repo,
             lareview::commands::delete_review,
+            lareview::commands::merge_reviews,
             lareview::commands::get_available_editors,
             lareview::commands::get_editor_config,
             lareview::commands::update_editor_config,
@@ -190,25 +314,69 @@ fn run_gui(initial_req: Option<DiffRequest>, initial_pending: Option<PendingDiff
             lareview::commands::update_feedback_filter_config,
             lareview::commands::get_timeout_config,
             lareview::commands::update_timeout_config,
+            lareview::commands::get_offline_mode,
+            lareview::commands::update_offline_mode,
+            lareview::commands::get_completion_notifications,
+            lareview::commands::update_completion_notifications,
+            lareview::commands::get_rejection_examples,
+            lareview::commands::update_rejection_examples,
+            lareview::commands::get_generated_file_patterns,
+            lareview::commands::update_generated_file_patterns,
+            lareview::commands::get_github_enterprise_hosts,
+            lareview::commands::update_github_enterprise_hosts,
+            lareview::commands::get_vcs_cli_config,
+            lareview::commands::update_vcs_cli_config,
+            lareview::commands::set_gitea_token,
+            lareview::commands::get_auto_refresh_config,
+            lareview::commands::update_auto_refresh_config,
+            lareview::commands::get_repo_watch_interval,
+            lareview::commands::update_repo_watch_interval,
+            lareview::commands::get_user_identity,
+            lareview::commands::update_user_identity,
+            lareview::commands::get_feedback_context_lines,
+            lareview::commands::update_feedback_context_lines,
+            lareview::commands::get_quick_review_max_lines,
+            lareview::commands::update_quick_review_max_lines,
+            lareview::commands::get_appearance_config,
+            lareview::commands::update_appearance_config,
+            lareview::commands::get_language,
+            lareview::commands::update_language,
+            lareview::commands::get_agent_tool_schemas,
+            lareview::commands::get_generation_concurrency,
+            lareview::commands::update_generation_concurrency,
+            lareview::commands::watch_repo,
+            lareview::commands::get_repo_commit_diff,
             lareview::commands::get_review_rules,
             lareview::commands::create_review_rule,
             lareview::commands::update_review_rule,
             lareview::commands::delete_review_rule,
+            lareview::commands::get_issue_check_definitions,
+            lareview::commands::create_issue_check_definition,
+            lareview::commands::update_issue_check_definition,
+            lareview::commands::delete_issue_check_definition,
             lareview::commands::open_in_editor,
             lareview::commands::get_repo_root_for_review,
             lareview::commands::get_cli_status,
             lareview::commands::install_cli,
             lareview::commands::get_pending_review_from_state,
+            lareview::commands::get_recent_pending_diffs,
+            lareview::commands::restore_pending_diff,
             lareview::commands::copy_to_clipboard,
+            lareview::commands::get_task_markdown,
+            lareview::commands::get_feedback_markdown,
             lareview::commands::open_url,
+            lareview::commands::open_diff_window,
+            lareview::commands::open_run_log,
             lareview::commands::clear_pending_diff,
             lareview::commands::get_diff_request,
             lareview::commands::acquire_diff_from_request,
             lareview::commands::push_remote_review,
             lareview::commands::export_review_markdown,
+            lareview::commands::export_review_gh_payload,
             lareview::commands::push_remote_feedback,
             lareview::commands::stop_generation,
             lareview::commands::set_repo_snapshot_access,
+            lareview::commands::set_repo_defaults,
             // Issue checks
             lareview::commands::get_issue_checks_for_run,
             // Merge confidence
@@ -227,6 +395,7 @@ fn run_gui(initial_req: Option<DiffRequest>, initial_pending: Option<PendingDiff
             lareview::commands::delete_learned_pattern,
             lareview::commands::toggle_learned_pattern,
             lareview::commands::get_learning_status,
+            lareview::commands::get_state_snapshot,
             lareview::commands::trigger_learning_compaction,
         ])
         .run(tauri::generate_context!())
@@ -1,18 +1,309 @@
+//! Prompt templates sent to agents.
+//!
+//! Built-in templates are compiled in. A user can replace one by writing a
+//! Handlebars file with the same name to `<app data dir>/prompts/<name>.hbs`,
+//! and a repository can carry its own review prompt in `.lareview/prompt.hbs`,
+//! which wins over both. That one is read from the linked checkout, never from
+//! the head under review, and is ignored when the diff itself changes it;
+//! `lareview review` only reads it with `--repo-prompt`. Custom templates can
+//! include the built-in one with `{{> builtin}}` to add instructions around it
+//! instead of starting over.
+//!
+//! The variables listed below are always in a template's context, so a custom
+//! template that finds one of them missing fails. Any other variable it
+//! references is treated as optional: it renders as an empty string and the
+//! missing names are logged. When a custom template fails to render, the
+//! error is logged and the built-in template is used.
+//!
+//! Context available to `generate_tasks`:
+//! - `review_id`, `source_json` (the review source as JSON), `initial_title`
+//! - `diff` (null for large diffs), `unified_manifest`, `compact_manifest`,
+//!   `is_large_diff`, `diff_size_chars`
+//! - `has_repo_access`, `repo_root`, `repo_access_note`
+//! - `has_generated_files`, `generated_files[]` (paths)
+//! - `has_changed_symbols`, `changed_symbols[]`: `path`, `symbols[]` (`name`,
+//!   `kind`, `start_line`, `end_line`); the functions and types changed in
+//!   large files, when the repository is linked
+//! - `has_rules`, `rules[]`: `category`, `display_name`, `text`, `glob`,
+//!   `scope`, `has_matches`, `matched_files[]`, `rule_id`
+//! - `has_default_categories`, `default_categories[]`: `id`, `name`,
+//!   `description`, `examples[]`
+//! - `has_custom_checks`, `custom_checks[]`: `id`, `name`, `category`, `prompt`
+//! - `has_learned_patterns`, `learned_patterns[]`: `pattern_text`,
+//!   `category`, `file_extension`, `source_count`
+//! - `has_rejection_examples`, `rejection_examples[]`: `title`,
+//!   `file_extension`
+//!
+//! Context available to `generate_tasks_quick` (quick reviews of small
+//! diffs): the same as `generate_tasks`, plus `max_tasks`.
+//!
+//! Context available to `compact_learnings`:
+//! - `rejections[]`: `title`, `impact`, `confidence`, `file_extension`,
+//!   `agent_id`
+//! - `existing_patterns[]`: `id`, `pattern_text`, `category`,
+//!   `file_extension`, `source_count`
+//!
+//! Context available to `explain_feedback`:
+//! - `title`, `impact`, `body` (the first comment), `file_path`,
+//!   `line_number`, `snippet` (the anchored hunk, may be empty)
+//!
+//! Context available to `summarize_review`:
+//! - `title`, `diff` (null for large diffs), `is_large_diff`, `manifest`
+//!   (the compact manifest for large diffs, the unified one otherwise)
+
 use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Partial name custom templates use to include the built-in template.
+const BUILTIN_PARTIAL: &str = "builtin";
+
+/// Review prompt a repository can ship, relative to its root.
+pub const REPO_PROMPT_PATH: &str = ".lareview/prompt.hbs";
+
+/// Top-level variables the caller always passes for each template.
+fn context_variables(name: &str) -> &'static [&'static str] {
+    match name {
+        "generate_tasks" => &[
+            "review_id",
+            "source_json",
+            "initial_title",
+            "diff",
+            "unified_manifest",
+            "compact_manifest",
+            "is_large_diff",
+            "diff_size_chars",
+            "has_repo_access",
+            "repo_root",
+            "repo_access_note",
+            "has_generated_files",
+            "generated_files",
+            "has_changed_symbols",
+            "changed_symbols",
+            "has_rules",
+            "rules",
+            "has_default_categories",
+            "default_categories",
+            "has_custom_checks",
+            "custom_checks",
+            "has_learned_patterns",
+            "learned_patterns",
+            "has_rejection_examples",
+            "rejection_examples",
+        ],
+        "generate_tasks_quick" => &[
+            "review_id",
+            "source_json",
+            "initial_title",
+            "diff",
+            "unified_manifest",
+            "compact_manifest",
+            "is_large_diff",
+            "has_generated_files",
+            "generated_files",
+            "has_rules",
+            "rules",
+            "has_custom_checks",
+            "custom_checks",
+            "has_rejection_examples",
+            "rejection_examples",
+            "max_tasks",
+        ],
+        "compact_learnings" => &["rejections", "existing_patterns"],
+        "explain_feedback" => &[
+            "title",
+            "impact",
+            "body",
+            "file_path",
+            "line_number",
+            "snippet",
+        ],
+        "summarize_review" => &["title", "diff", "is_large_diff", "manifest"],
+        _ => &[],
+    }
+}
+
+fn builtin(name: &str) -> Option<&'static str> {
+    match name {
+        "generate_tasks" => Some(include_str!("generate_tasks.hbs")),
+        "generate_tasks_quick" => Some(include_str!("generate_tasks_quick.hbs")),
+        "compact_learnings" => Some(include_str!("compact_learnings.hbs")),
+        "explain_feedback" => Some(include_str!("explain_feedback.hbs")),
+        "summarize_review" => Some(include_str!("summarize_review.hbs")),
+        _ => None,
+    }
+}
+
+/// Directory users put template overrides in.
+pub fn user_prompts_dir() -> PathBuf {
+    crate::infra::app_config::app_data_dir().join("prompts")
+}
 
+/// Renders template `name`, preferring the user's override when there is one.
 pub fn render(name: &str, data: &Value) -> Result<String, handlebars::RenderError> {
-    let template = match name {
-        "generate_tasks" => include_str!("generate_tasks.hbs"),
-        "compact_learnings" => include_str!("compact_learnings.hbs"),
-        _ => {
-            return Err(handlebars::RenderError::from(
-                handlebars::RenderErrorReason::NestedError(Box::new(std::io::Error::new(
-                    std::io::ErrorKind::NotFound,
-                    format!("unknown template: {}", name),
-                ))),
-            ));
-        }
+    render_for_repo(name, data, None)
+}
+
+/// Like [`render`], but a `generate_tasks` prompt in `repo_root` takes
+/// precedence over the user's. `repo_root` must be a checkout the user
+/// trusts, not the code under review.
+pub fn render_for_repo(
+    name: &str,
+    data: &Value,
+    repo_root: Option<&Path>,
+) -> Result<String, handlebars::RenderError> {
+    let Some(template) = builtin(name) else {
+        return Err(handlebars::RenderError::from(
+            handlebars::RenderErrorReason::NestedError(Box::new(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("unknown template: {}", name),
+            ))),
+        ));
     };
+
+    let repo_template = repo_root
+        .filter(|_| name == "generate_tasks")
+        .map(|root| root.join(REPO_PROMPT_PATH));
+    let candidates = repo_template.into_iter().chain(std::iter::once(
+        user_prompts_dir().join(format!("{name}.hbs")),
+    ));
+    for path in candidates {
+        let Ok(custom) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        match render_custom(name, &custom, template, data) {
+            Ok(rendered) => return Ok(rendered),
+            Err(err) => log::warn!(
+                "Ignoring prompt template {}: {}; using the built-in one",
+                path.display(),
+                err
+            ),
+        }
+    }
+
     let handlebars = handlebars::Handlebars::new();
     handlebars.render_template(template, data)
 }
+
+/// Renders a user template. Variables outside [`context_variables`] that the
+/// context lacks render empty instead of failing the whole prompt.
+fn render_custom(
+    name: &str,
+    custom: &str,
+    builtin: &str,
+    data: &Value,
+) -> Result<String, handlebars::RenderError> {
+    let mut handlebars = handlebars::Handlebars::new();
+    handlebars.set_strict_mode(true);
+    handlebars
+        .register_partial(BUILTIN_PARTIAL, builtin)
+        .map_err(|err| {
+            handlebars::RenderError::from(handlebars::RenderErrorReason::TemplateError(err))
+        })?;
+
+    let mut filled: Option<Value> = None;
+    let mut missing: Vec<String> = Vec::new();
+    let rendered = loop {
+        let context = filled.as_ref().unwrap_or(data);
+        let err = match handlebars.render_template(custom, context) {
+            Ok(rendered) => break rendered,
+            Err(err) => err,
+        };
+        let handlebars::RenderErrorReason::MissingVariable(Some(path)) = err.reason() else {
+            return Err(err);
+        };
+        if context_variables(name).contains(&path.as_str()) {
+            return Err(err);
+        }
+        if missing.contains(path) {
+            // Filling in a top-level value didn't help (the lookup is nested
+            // or inside a block), so let the remaining gaps render empty.
+            handlebars.set_strict_mode(false);
+            break handlebars.render_template(custom, context)?;
+        }
+        let path = path.clone();
+        let mut context = context.clone();
+        if let Value::Object(map) = &mut context {
+            map.insert(path.clone(), Value::String(String::new()));
+        }
+        filled = Some(context);
+        missing.push(path);
+    };
+
+    if !missing.is_empty() {
+        log::warn!(
+            "Prompt template {} references variables not in its context: {}; rendered them empty",
+            name,
+            missing.join(", ")
+        );
+    }
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_custom_template_can_extend_the_builtin() {
+        let rendered = render_custom(
+            "generate_tasks",
+            "{{> builtin}}\nAlso check naming in {{review_id}}.",
+            "Review {{review_id}}.",
+            &json!({ "review_id": "rev-1" }),
+        )
+        .unwrap();
+        assert_eq!(rendered, "Review rev-1.\nAlso check naming in rev-1.");
+    }
+
+    #[test]
+    fn test_unknown_variables_render_empty() {
+        let rendered = render_custom(
+            "generate_tasks",
+            "[{{team}}] {{review_id}}{{#each rules}} {{text}}{{owner}}{{/each}}",
+            "",
+            &json!({ "review_id": "rev-1", "rules": [{ "text": "no unwrap" }] }),
+        )
+        .unwrap();
+        assert_eq!(rendered, "[] rev-1 no unwrap");
+    }
+
+    #[test]
+    fn test_missing_context_variable_is_an_error() {
+        assert!(render_custom("generate_tasks", "{{review_id}}", "", &json!({})).is_err());
+    }
+
+    #[test]
+    fn test_repo_prompt_overrides_the_builtin() {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(repo.path().join(".lareview")).unwrap();
+        std::fs::write(
+            repo.path().join(REPO_PROMPT_PATH),
+            "Repo prompt for {{review_id}}",
+        )
+        .unwrap();
+
+        let rendered = render_for_repo(
+            "generate_tasks",
+            &json!({ "review_id": "rev-1" }),
+            Some(repo.path()),
+        )
+        .unwrap();
+        assert_eq!(rendered, "Repo prompt for rev-1");
+    }
+
+    #[test]
+    fn test_broken_repo_prompt_falls_back_to_the_builtin() {
+        let repo = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(repo.path().join(".lareview")).unwrap();
+        std::fs::write(repo.path().join(REPO_PROMPT_PATH), "{{#if}}").unwrap();
+
+        let rendered = render_for_repo(
+            "generate_tasks",
+            &json!({ "review_id": "rev-1" }),
+            Some(repo.path()),
+        )
+        .unwrap();
+        assert!(rendered.contains("rev-1"));
+    }
+}
@@ -1,8 +1,14 @@
+use crate::domain::{ReviewRunStatus, ReviewSource};
+use crate::infra::acp::GenerationLimiter;
 use crate::infra::app_config::AppConfig;
 use crate::infra::db::Database;
+use crate::infra::db::encryption;
+use crate::infra::db::repository::PendingDiffRecord;
+use crate::infra::vcs::watch::RepoWatcher;
 use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
 use std::sync::{Arc, Mutex};
 use tokio_util::sync::CancellationToken;
 
@@ -23,28 +29,141 @@ pub struct PendingDiff {
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// A generation in progress, either waiting for a slot or running.
+#[derive(Debug, Clone)]
+pub struct ActiveRun {
+    pub cancel_token: CancellationToken,
+    /// `Queued` or `Running`.
+    pub status: ReviewRunStatus,
+}
+
 pub struct AppState {
     pub db: Arc<Mutex<Database>>,
+    /// True while the encrypted database waits for its passphrase; `db` is
+    /// an empty in-memory stand-in until `unlock_database` swaps it out.
+    pub db_locked: Arc<AtomicBool>,
     pub config: Arc<RwLock<AppConfig>>,
     pub diff_request: Arc<Mutex<Option<DiffRequest>>>,
     pub pending_diff: Arc<Mutex<Option<PendingDiff>>>,
-    pub active_runs: Arc<Mutex<HashMap<String, CancellationToken>>>,
+    pub active_runs: Arc<Mutex<HashMap<String, ActiveRun>>>,
+    /// Caps how many generations run at once.
+    pub generation_limiter: Arc<GenerationLimiter>,
+    pub repo_watcher: Arc<Mutex<RepoWatcher>>,
+    /// Cancels the remote review fetch in flight, if any.
+    pub pr_fetch: Arc<Mutex<Option<CancellationToken>>>,
+    /// Runtime that sync commands use to drive async work.
+    pub runtime: tokio::runtime::Handle,
 }
 
 impl AppState {
     pub fn new() -> Self {
-        let db = Database::open().expect("Failed to open database");
+        Self::with_runtime(crate::runtime_handle())
+    }
+
+    /// Creates the state bound to an explicit runtime, e.g. the one Tauri runs on.
+    pub fn with_runtime(runtime: tokio::runtime::Handle) -> Self {
+        let (db, db_locked) = match Database::open() {
+            Ok(db) => (db, false),
+            Err(err) if encryption::needs_unlock(&err) => {
+                log::warn!("{}; waiting for the passphrase", err);
+                let db = Database::open_in_memory().expect("Failed to open database");
+                (db, true)
+            }
+            Err(err) => panic!("Failed to open database: {:?}", err),
+        };
         if let Err(err) = db.mark_stale_runs_failed() {
             log::warn!("Failed to mark stale runs as failed: {}", err);
         }
+        let repo_watcher = watcher_for_config(&db);
+        let generation_limit = crate::infra::app_config::load_config()
+            .max_concurrent_generations
+            .unwrap_or_else(crate::infra::acp::default_generation_limit);
         Self {
             db: Arc::new(Mutex::new(db)),
+            db_locked: Arc::new(AtomicBool::new(db_locked)),
             config: Arc::new(RwLock::new(AppConfig::default())),
             diff_request: Arc::new(Mutex::new(None)),
             pending_diff: Arc::new(Mutex::new(None)),
             active_runs: Arc::new(Mutex::new(HashMap::new())),
+            generation_limiter: Arc::new(GenerationLimiter::new(generation_limit)),
+            repo_watcher: Arc::new(Mutex::new(repo_watcher)),
+            pr_fetch: Arc::new(Mutex::new(None)),
+            runtime,
+        }
+    }
+
+    /// A handle on the database. The state lock is only held to clone it, so
+    /// one command's queries don't hold up the others. Fails while the
+    /// database is locked, rather than handing out the in-memory stand-in.
+    pub fn database(&self) -> Result<Database, String> {
+        if self.db_locked.load(std::sync::atomic::Ordering::SeqCst) {
+            return Err(encryption::EncryptionError::Locked.to_string());
+        }
+        self.db
+            .lock()
+            .map(|db| db.clone())
+            .map_err(|e| e.to_string())
+    }
+
+    /// The file behind the current database, for child processes that open
+    /// it themselves. It moves when the data directory does, so read it here
+    /// rather than from the environment.
+    pub fn db_path(&self) -> Option<PathBuf> {
+        self.database().ok().and_then(|db| db.path())
+    }
+
+    /// Makes `pending` the diff the UI loads next and adds it to the recent
+    /// pending diffs. Returns the id it's kept under there, if saving worked.
+    pub fn set_pending_diff(
+        &self,
+        pending: PendingDiff,
+        review_source: Option<ReviewSource>,
+    ) -> Option<String> {
+        let record = PendingDiffRecord {
+            id: uuid::Uuid::new_v4().to_string(),
+            diff: pending.diff.clone(),
+            repo_root: pending
+                .repo_root
+                .as_ref()
+                .map(|root| root.to_string_lossy().into_owned()),
+            agent: pending.agent.clone(),
+            source: pending.source.clone(),
+            review_source,
+            created_at: pending.created_at.to_rfc3339(),
+        };
+        if let Ok(mut current) = self.pending_diff.lock() {
+            *current = Some(pending);
+        }
+        match self
+            .database()
+            .map_err(anyhow::Error::msg)
+            .and_then(|db| db.pending_diff_repo().record(&record))
+        {
+            Ok(id) => Some(id),
+            Err(err) => {
+                log::warn!("Failed to save pending diff history: {:#}", err);
+                None
+            }
+        }
+    }
+}
+
+/// Resumes watching the repos saved in the config.
+pub(crate) fn watcher_for_config(db: &Database) -> RepoWatcher {
+    let mut watcher = RepoWatcher::default();
+    let watched = crate::infra::app_config::load_config().watched_repo_ids;
+    if watched.is_empty() {
+        return watcher;
+    }
+    match db.repo_repo().find_all() {
+        Ok(repos) => {
+            for repo in repos.into_iter().filter(|repo| watched.contains(&repo.id)) {
+                watcher.watch(&repo.id, repo.path);
+            }
         }
+        Err(err) => log::warn!("Failed to load watched repos: {}", err),
     }
+    watcher
 }
 
 impl Default for AppState {
@@ -31,6 +31,8 @@ fn test_full_database_workflow() -> anyhow::Result<()> {
         remotes: vec!["https://github.com/test/repo".into()],
         created_at: "now".into(),
         allow_snapshot_access: false,
+        default_agent_id: None,
+        default_rule_categories: vec![],
     };
     repo_repo.save(&linked)?;
 
@@ -46,6 +48,8 @@ fn test_full_database_workflow() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".to_string(),
         updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review)?;
 
@@ -95,6 +99,7 @@ fn test_full_database_workflow() -> anyhow::Result<()> {
         impact: FeedbackImpact::Nitpick,
         confidence: 1.0,
         anchor: None,
+        suggested_replacement: None,
         author: "me".into(),
         created_at: "now".into(),
         updated_at: "now".into(),
@@ -162,6 +167,8 @@ fn test_task_diff_refs_serialization_deserialization() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".to_string(),
         updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review)?;
 
@@ -410,6 +417,8 @@ fn test_task_with_null_diff_refs() -> anyhow::Result<()> {
         status: ReviewStatus::Todo,
         created_at: "now".to_string(),
         updated_at: "now".to_string(),
+        tags: vec![],
+        decision: None,
     };
     review_repo.save(&review)?;
 
@@ -451,3 +460,72 @@ fn test_task_with_null_diff_refs() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Lists a review with many large runs, with and without their diffs.
+/// Run with `cargo test --release -- --ignored --nocapture run_listing`.
+#[test]
+#[ignore]
+fn bench_run_listing_without_diffs() -> anyhow::Result<()> {
+    const RUNS: usize = 40;
+    const DIFF_BYTES: usize = 2 * 1024 * 1024;
+
+    let db = Database::open_in_memory()?;
+    let conn = db.connection();
+    let run_repo = ReviewRunRepository::new(conn.clone());
+    ReviewRepository::new(conn.clone()).save(&Review {
+        id: "rev".into(),
+        title: "Large review".into(),
+        summary: None,
+        source: ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        },
+        active_run_id: None,
+        status: ReviewStatus::Todo,
+        created_at: "now".into(),
+        updated_at: "now".into(),
+        tags: vec![],
+        decision: None,
+    })?;
+    let line = "+let value = compute_something_expensive(input);\n";
+    let diff = format!(
+        "diff --git a/src/big.rs b/src/big.rs\n--- a/src/big.rs\n+++ b/src/big.rs\n{}",
+        line.repeat(DIFF_BYTES / line.len())
+    );
+    for i in 0..RUNS {
+        run_repo.save(&ReviewRun {
+            id: format!("run-{i}"),
+            review_id: "rev".into(),
+            agent_id: "agent".into(),
+            input_ref: "input".into(),
+            diff_text: diff.as_str().into(),
+            diff_hash: format!("h{i}"),
+            status: ReviewRunStatus::Completed,
+            created_at: format!("2024-01-01T00:00:{i:02}Z"),
+        })?;
+    }
+
+    let started = std::time::Instant::now();
+    let listed = db.get_review_runs("rev")?;
+    let listing = started.elapsed();
+    let listed_json = serde_json::to_string(&listed)?.len();
+
+    let started = std::time::Instant::now();
+    let full = run_repo.find_by_review_id(&"rev".to_string())?;
+    let full_load = started.elapsed();
+    let full_bytes: usize = full.iter().map(|run| run.diff_text.len()).sum();
+
+    let started = std::time::Instant::now();
+    let one = run_repo.get_diff(&"run-0".to_string())?.expect("diff");
+    let single = started.elapsed();
+
+    eprintln!(
+        "{RUNS} runs of {} KiB: listing {listing:?} ({} bytes as JSON), with diffs {full_load:?} ({} KiB), one diff on demand {single:?}",
+        DIFF_BYTES / 1024,
+        listed_json,
+        full_bytes / 1024,
+    );
+    assert_eq!(listed.len(), RUNS);
+    assert!(listed_json < 64 * 1024);
+    assert_eq!(one.len(), diff.len());
+    Ok(())
+}
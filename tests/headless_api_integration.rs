@@ -0,0 +1,201 @@
+//! Integration tests for the headless library API.
+//! These exercise diff parsing and markdown export without any app state.
+
+use lareview::domain::{
+    Feedback, FeedbackAnchor, FeedbackAttachment, FeedbackImpact, FeedbackSide, Review, ReviewRule,
+    ReviewRun, ReviewRunStatus, ReviewSource, ReviewStatus, ReviewTask, RuleScope, TaskStats,
+};
+use lareview::{
+    DiffIndex, ExportData, ExportOptions, combine_diffs, export_markdown, parse_unified_diff,
+};
+use std::sync::Arc;
+
+const DIFF: &str = r#"diff --git a/src/main.rs b/src/main.rs
+index 0123456..789abcd 100644
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,3 +1,4 @@
+ fn main() {
+-    println!("Hello");
++    println!("Hello, world");
++    println!("Bye");
+ }
+"#;
+
+#[test]
+fn test_parse_unified_diff_without_app_state() -> anyhow::Result<()> {
+    let parsed = parse_unified_diff(DIFF)?;
+
+    assert_eq!(parsed.total_additions, 2);
+    assert_eq!(parsed.total_deletions, 1);
+    assert_eq!(parsed.files.len(), 1);
+    assert_eq!(parsed.files[0].name, "src/main.rs");
+    assert_eq!(parsed.files[0].hunks.len(), 1);
+    assert_eq!(parsed.files[0].hunks[0].new_lines, 4);
+
+    let index = DiffIndex::new(DIFF)?;
+    assert_eq!(index.get_all_file_paths(), vec!["src/main.rs".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn test_export_markdown_without_runtime() -> anyhow::Result<()> {
+    let review = Review {
+        id: "rev-1".into(),
+        title: "Headless Review".into(),
+        summary: Some("Prints a friendlier greeting.".into()),
+        source: ReviewSource::DiffPaste {
+            diff_hash: "h".into(),
+        },
+        active_run_id: Some("run-1".into()),
+        status: ReviewStatus::Todo,
+        created_at: "now".into(),
+        updated_at: "now".into(),
+        tags: vec![],
+        decision: None,
+    };
+    let run = ReviewRun {
+        id: "run-1".into(),
+        review_id: review.id.clone(),
+        agent_id: "agent".into(),
+        input_ref: "input".into(),
+        diff_text: Arc::from(DIFF),
+        diff_hash: "h".into(),
+        status: ReviewRunStatus::Completed,
+        created_at: "now".into(),
+    };
+    let task = ReviewTask {
+        id: "task-1".into(),
+        run_id: run.id.clone(),
+        title: "Greeting change".into(),
+        description: "Check the new output.".into(),
+        files: vec!["src/main.rs".into()],
+        stats: TaskStats::default(),
+        diff_refs: vec![],
+        insight: None,
+        diagram: None,
+        ai_generated: true,
+        status: ReviewStatus::Todo,
+        sub_flow: None,
+    };
+    let feedback = Feedback {
+        id: "fb-1".into(),
+        review_id: review.id.clone(),
+        task_id: Some(task.id.clone()),
+        rule_id: Some("rule-1".into()),
+        finding_id: None,
+        category: None,
+        title: "Extra print".into(),
+        status: ReviewStatus::Todo,
+        impact: FeedbackImpact::NiceToHave,
+        confidence: 0.9,
+        anchor: Some(FeedbackAnchor {
+            file_path: Some("src/main.rs".into()),
+            line_number: Some(3),
+            side: Some(FeedbackSide::New),
+            hunk_ref: None,
+            head_sha: None,
+            diff_hash: None,
+            outdated: false,
+        }),
+        suggested_replacement: None,
+        author: "agent:test".into(),
+        created_at: "now".into(),
+        updated_at: "now".into(),
+    };
+
+    let data = ExportData {
+        review,
+        run,
+        tasks: vec![task],
+        attachments: vec![FeedbackAttachment {
+            id: "att-1".into(),
+            feedback_id: feedback.id.clone(),
+            mime: "image/png".into(),
+            data: b"hi".to_vec(),
+            byte_size: 2,
+            created_at: "now".into(),
+        }],
+        feedbacks: vec![feedback],
+        comments: vec![],
+        rules: vec![ReviewRule {
+            id: "rule-1".into(),
+            scope: RuleScope::Global,
+            repo_id: None,
+            glob: None,
+            category: None,
+            text: "No debug output in main.".into(),
+            enabled: true,
+            created_at: "now".into(),
+            updated_at: "now".into(),
+        }],
+        merge_confidence: None,
+        checklist: vec![],
+    };
+    let options = ExportOptions {
+        include_summary: true,
+        include_tasks: true,
+        include_feedbacks: true,
+        include_context_diff: true,
+        ..Default::default()
+    };
+
+    let result = export_markdown(&data, &options)?;
+
+    assert!(result.markdown.starts_with("# Headless Review"));
+    assert!(result.markdown.contains("Prints a friendlier greeting."));
+    assert!(result.markdown.contains("Greeting change"));
+    assert!(result.markdown.contains("Extra print"));
+    assert!(
+        result
+            .markdown
+            .contains("![Attachment 1](data:image/png;base64,aGk=)")
+    );
+    assert!(
+        result
+            .markdown
+            .contains("> **Triggered by rule:** No debug output in main.")
+    );
+    Ok(())
+}
+
+/// `git format-patch` output: mail headers, a diffstat and a version trailer.
+const FORMAT_PATCH: &str = "From 1a2b3c Mon Sep 17 00:00:00 2001
+From: Dev <dev@example.com>
+Subject: [PATCH] Add helper
+
+---
+ src/lib.rs | 2 ++
+ 1 file changed, 2 insertions(+)
+
+diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,1 +1,3 @@
+ pub mod app;
++
++pub fn helper() {}
+-- \n2.43.0
+";
+
+#[test]
+fn test_combined_patches_parse_as_one_diff() -> anyhow::Result<()> {
+    // The first patch ends right after its last hunk line, with no newline.
+    let combined = combine_diffs(&[DIFF.trim_end(), FORMAT_PATCH]);
+
+    let parsed = parse_unified_diff(&combined)?;
+    let mut names: Vec<_> = parsed.files.iter().map(|f| f.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, vec!["src/lib.rs", "src/main.rs"]);
+    assert_eq!(parsed.total_additions, 4);
+    assert_eq!(parsed.total_deletions, 1);
+    assert!(!combined.contains("2.43.0"));
+
+    let index = DiffIndex::new(&combined)?;
+    let main = index.get_hunk_lines_with_numbers("src/main.rs#H1").unwrap();
+    assert_eq!(main.last().unwrap().content, "}");
+    let lib = index.get_hunk_lines_with_numbers("src/lib.rs#H1").unwrap();
+    assert_eq!(lib.len(), 3);
+    assert_eq!(lib[2].new_line_number, Some(3));
+    Ok(())
+}
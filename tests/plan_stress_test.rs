@@ -63,6 +63,8 @@ index 0000000..1111111 100644\n\
     let input = GenerateTasksInput {
         run_context,
         rules: Vec::new(),
+        issue_checks: Vec::new(),
+        rejection_examples: Vec::new(),
         repo_root: None,
         cleanup_path: None,
         agent_command: agent_path_str.to_string(),
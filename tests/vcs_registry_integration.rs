@@ -26,3 +26,64 @@ fn detects_gitlab_provider_from_shorthand() {
         .expect("gitlab provider");
     assert_eq!(provider.id(), "gitlab");
 }
+
+#[test]
+fn offline_registry_still_detects_providers() {
+    let registry = VcsRegistry::offline();
+    let provider = registry
+        .detect_provider("https://github.com/example/repo/pull/42")
+        .expect("github provider");
+    assert_eq!(provider.id(), "github");
+    assert!(
+        provider
+            .parse_ref("https://github.com/example/repo/pull/42")
+            .is_some()
+    );
+}
+
+#[tokio::test]
+async fn offline_registry_reports_every_provider_offline() {
+    let registry = VcsRegistry::offline();
+    for provider in registry.providers() {
+        let status = provider.get_status().await.expect("status");
+        assert!(status.offline, "{} should be offline", provider.id());
+        assert!(status.login.is_none());
+    }
+}
+
+#[tokio::test]
+async fn offline_registry_refuses_fetch() {
+    let registry = VcsRegistry::offline();
+    let provider = registry
+        .detect_provider("https://gitlab.com/example/repo/-/merge_requests/7")
+        .expect("gitlab provider");
+    let reference = provider
+        .parse_ref("https://gitlab.com/example/repo/-/merge_requests/7")
+        .expect("reference");
+    let err = provider
+        .fetch_pr(reference.as_ref())
+        .await
+        .expect_err("offline fetch must fail");
+    assert!(err.to_string().contains("Offline mode"));
+}
+
+#[test]
+fn github_provider_parses_enterprise_pr_urls() {
+    use lareview::infra::vcs::github::{GitHubPrRef, GitHubProvider};
+    use lareview::infra::vcs::traits::VcsProvider;
+
+    let provider = GitHubProvider::with_enterprise_hosts(vec!["ghe.example.com".to_string()]);
+    assert!(provider.matches_ref("https://ghe.example.com/example/repo/pull/42"));
+    assert!(!provider.matches_ref("https://git.example.org/example/repo/pull/42"));
+
+    let reference = provider
+        .parse_ref("https://ghe.example.com/example/repo/pull/42")
+        .expect("enterprise reference");
+    let pr = reference
+        .as_any()
+        .downcast_ref::<GitHubPrRef>()
+        .expect("pull request reference");
+    assert_eq!(pr.host, "ghe.example.com");
+    assert_eq!(pr.repo_arg(), "ghe.example.com/example/repo");
+    assert_eq!(pr.url, "https://ghe.example.com/example/repo/pull/42");
+}